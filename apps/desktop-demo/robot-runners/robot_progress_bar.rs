@@ -285,10 +285,10 @@ fn main() {
                             Text("Next", Modifier::empty().padding(4.0));
                         },
                     );
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 0.0,
                         height: 8.0,
-                    });
+                    }));
                     AsyncRuntimeTabContent(animation, stats, is_running, reset_signal);
                 },
             );
@@ -205,15 +205,15 @@ pub fn combined_app() {
                 },
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 12.0,
-            });
+            }));
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 12.0,
-            });
+            }));
 
             let active = tab_state_for_content.get();
             cranpose_core::with_key(&active, || match active {
@@ -256,18 +256,18 @@ fn text_input_example() {
                     .rounded_corners(16.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 24.0,
-            });
+            }));
 
             // First text field with label
             Text("Basic Text Field:", Modifier::empty().padding(4.0));
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 8.0,
-            });
+            }));
 
             // Text field with background styling
             {
@@ -282,10 +282,10 @@ fn text_input_example() {
                 );
             }
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             // Show current text value - this now updates when version changes
             {
@@ -300,18 +300,18 @@ fn text_input_example() {
                 );
             }
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 24.0,
-            });
+            }));
 
             // Second text field
             Text("Empty Text Field:", Modifier::empty().padding(4.0));
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 8.0,
-            });
+            }));
 
             {
                 let state = text_state2.clone();
@@ -325,18 +325,18 @@ fn text_input_example() {
                 );
             }
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             // Buttons to manipulate text programmatically
             Text("Programmatic Actions:", Modifier::empty().padding(4.0));
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 8.0,
-            });
+            }));
 
             Row(
                 Modifier::empty().fill_max_width(),
@@ -444,10 +444,10 @@ fn recursive_layout_example() {
                     .rounded_corners(16.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             Row(
                 Modifier::empty().fill_max_width().padding(8.0),
@@ -514,10 +514,10 @@ fn recursive_layout_example() {
                 },
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             let depth = depth_state.get().max(1);
             Column(
@@ -635,10 +635,10 @@ pub fn composition_local_example() {
                     .rounded_corners(16.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             Text(
                 format!("Counter: {}", counter.get()),
@@ -648,10 +648,10 @@ pub fn composition_local_example() {
                     .rounded_corners(12.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 12.0,
-            });
+            }));
 
             Button(
                 Modifier::empty()
@@ -674,10 +674,10 @@ pub fn composition_local_example() {
                 },
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             let local = local_holder();
             let count = counter.get();
@@ -699,17 +699,17 @@ fn composition_local_content() {
             .rounded_corners(12.0),
     );
 
-    Spacer(Size {
+    Spacer(Modifier::empty().size(Size {
         width: 0.0,
         height: 8.0,
-    });
+    }));
 
     composition_local_content_inner();
 
-    Spacer(Size {
+    Spacer(Modifier::empty().size(Size {
         width: 0.0,
         height: 8.0,
-    });
+    }));
 
     Text(
         format!("NOT reading local: rand={}", random()),
@@ -763,10 +763,10 @@ pub fn AsyncRuntimeTabContent(
                         .rounded_corners(16.0),
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 16.0,
-                });
+                }));
 
                 let animation_snapshot = animation.get();
                 let stats_snapshot = stats.get();
@@ -786,10 +786,10 @@ pub fn AsyncRuntimeTabContent(
                                 Modifier::empty().padding(6.0),
                             );
 
-                            Spacer(Size {
+                            Spacer(Modifier::empty().size(Size {
                                 width: 0.0,
                                 height: 8.0,
-                            });
+                            }));
 
                             Row(
                                 Modifier::empty()
@@ -854,10 +854,10 @@ pub fn AsyncRuntimeTabContent(
                     },
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 12.0,
-                });
+                }));
 
                 Text(
                     format!(
@@ -876,10 +876,10 @@ pub fn AsyncRuntimeTabContent(
                         .rounded_corners(14.0),
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 16.0,
-                });
+                }));
 
                 Row(
                     Modifier::empty().fill_max_width().padding(4.0),
@@ -1186,10 +1186,10 @@ fn counter_app() {
                             }),
                     );
 
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 0.0,
                         height: 12.0,
-                    });
+                    }));
 
                     Row(
                         Modifier::empty().fill_max_width().padding(8.0),
@@ -1225,16 +1225,17 @@ fn counter_app() {
                                             scale: 0.85 + wave_value * 0.3,
                                             translation_x: 0.0,
                                             translation_y: (wave_value - 0.5) * 12.0,
+                                            ..Default::default()
                                         }),
                                 );
                             }
                         },
                     );
 
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 0.0,
                         height: 16.0,
-                    });
+                    }));
 
                     let async_message_state = async_message;
                     let fetch_request_state = fetch_request;
@@ -1291,6 +1292,8 @@ fn counter_app() {
                                                     PointerEventKind::Cancel => {
                                                         pointer_down_state.set(false)
                                                     }
+                                                    PointerEventKind::Enter
+                                                    | PointerEventKind::Exit => {}
                                                 }
                                             }
                                         })
@@ -1309,10 +1312,10 @@ fn counter_app() {
                                     .padding(8.0),
                             );
 
-                            Spacer(Size {
+                            Spacer(Modifier::empty().size(Size {
                                 width: 0.0,
                                 height: 16.0,
-                            });
+                            }));
 
                             Row(
                                 Modifier::empty()
@@ -1386,10 +1389,10 @@ fn counter_app() {
                                 },
                             );
 
-                            Spacer(Size {
+                            Spacer(Modifier::empty().size(Size {
                                 width: 0.0,
                                 height: 16.0,
-                            });
+                            }));
 
                             let counter_inc = counter;
                             let counter_dec = counter;
@@ -1448,10 +1451,10 @@ fn counter_app() {
                                 },
                             );
 
-                            Spacer(Size {
+                            Spacer(Modifier::empty().size(Size {
                                 width: 0.0,
                                 height: 20.0,
-                            });
+                            }));
 
                             let async_message_text = async_message_state;
                             Text(
@@ -1462,10 +1465,10 @@ fn counter_app() {
                                     .rounded_corners(14.0),
                             );
 
-                            Spacer(Size {
+                            Spacer(Modifier::empty().size(Size {
                                 width: 0.0,
                                 height: 12.0,
-                            });
+                            }));
 
                             let async_message_button = async_message_state;
                             let fetch_request_button = fetch_request_state;
@@ -1565,10 +1568,10 @@ fn modifier_showcase_tab() {
                                 .rounded_corners(12.0),
                         );
 
-                        Spacer(Size {
+                        Spacer(Modifier::empty().size(Size {
                             width: 0.0,
                             height: 8.0,
-                        });
+                        }));
 
                         let showcase_types = [
                             ShowcaseType::SimpleCard,
@@ -1654,10 +1657,10 @@ pub fn simple_card_showcase() {
                 .rounded_corners(14.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         // Card with border effect (outer box creates border)
         cranpose_ui::Box(
@@ -1690,20 +1693,20 @@ pub fn simple_card_showcase() {
                                         .rounded_corners(8.0),
                                 );
 
-                                Spacer(Size {
+                                Spacer(Modifier::empty().size(Size {
                                     width: 0.0,
                                     height: 8.0,
-                                });
+                                }));
 
                                 Text(
                                     "Card content goes here with padding",
                                     Modifier::empty().padding(4.0),
                                 );
 
-                                Spacer(Size {
+                                Spacer(Modifier::empty().size(Size {
                                     width: 0.0,
                                     height: 12.0,
-                                });
+                                }));
 
                                 // Action buttons row
                                 Row(Modifier::empty(), RowSpec::default(), || {
@@ -1715,10 +1718,10 @@ pub fn simple_card_showcase() {
                                             .rounded_corners(6.0),
                                     );
 
-                                    Spacer(Size {
+                                    Spacer(Modifier::empty().size(Size {
                                         width: 8.0,
                                         height: 0.0,
-                                    });
+                                    }));
 
                                     Text(
                                         "Action 2",
@@ -1748,10 +1751,10 @@ pub fn positioned_boxes_showcase() {
                 .rounded_corners(14.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         // Wrap positioned boxes in a container with explicit size
         // This allows overlapping boxes with offset positioning
@@ -1833,10 +1836,10 @@ pub fn item_list_showcase() {
                 .rounded_corners(14.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         // List with alternating colors and borders
         Column(
@@ -1877,10 +1880,10 @@ pub fn item_list_showcase() {
                                     };
                                     Text(text, Modifier::empty().padding_horizontal(12.0));
 
-                                    Spacer(Size {
+                                    Spacer(Modifier::empty().size(Size {
                                         width: 0.0,
                                         height: 0.0,
-                                    });
+                                    }));
 
                                     // Status indicator
                                     let status_color = if i % 3 == 0 {
@@ -1920,20 +1923,20 @@ pub fn complex_chain_showcase() {
                 .rounded_corners(14.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Text(
             "Nested: Red → Green → Blue layers",
             Modifier::empty().padding(8.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 12.0,
-        });
+        }));
 
         // Nested backgrounds showcase - creates visible colored borders
         // Red outer layer
@@ -1968,20 +1971,20 @@ pub fn complex_chain_showcase() {
             },
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Text(
             "Chain: offset + size + multiple backgrounds",
             Modifier::empty().padding(8.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 12.0,
-        });
+        }));
 
         // Complex modifier chain with offset and sizing - Orange outer, Purple inner
         cranpose_ui::Box(
@@ -2021,10 +2024,10 @@ pub fn dynamic_modifiers_showcase() {
                 .rounded_corners(14.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         let current_frame = frame.get();
         let x = (current_frame as f32 * 10.0) % 200.0;
@@ -2056,10 +2059,10 @@ pub fn dynamic_modifiers_showcase() {
             },
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Text(
             format!("Frame: {}, X: {:.1}", current_frame, x),
@@ -2069,10 +2072,10 @@ pub fn dynamic_modifiers_showcase() {
                 .rounded_corners(10.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 12.0,
-        });
+        }));
 
         Button(
             Modifier::empty()
@@ -2108,10 +2111,10 @@ pub fn long_list_showcase() {
                 .rounded_corners(14.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Column(
             Modifier::empty().padding(16.0),
@@ -153,10 +153,10 @@ fn LifecycleListItem(index: usize, stats: MutableState<LifecycleStats>) {
                         Color(0.9, 0.3, 0.9, 1.0), // Magenta
                     ];
                     for i in 0..box_count {
-                        Spacer(Size {
+                        Spacer(Modifier::empty().size(Size {
                             width: 12.0,
                             height: 12.0,
-                        });
+                        }));
                         // Color each box based on its position
                         let color = colors[i % colors.len()];
                         Text(
@@ -203,10 +203,10 @@ pub fn lazy_list_example() {
                     .rounded_corners(16.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             // Show info
             let count = item_count.get();
@@ -218,35 +218,35 @@ pub fn lazy_list_example() {
                     .rounded_corners(12.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 8.0,
-            });
+            }));
 
             LifecycleStatsDisplay(lifecycle_stats);
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 8.0,
-            });
+            }));
 
             // Stats from LazyListState - in its own isolated composable scope
             // Reactive read happens INSIDE LazyListStatsDisplay, not here
             LazyListStatsDisplay(list_state);
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 8.0,
-            });
+            }));
 
             // First visible item index - in its own isolated composable scope
             // Reactive read happens INSIDE FirstVisibleIndexDisplay, not here
             FirstVisibleIndexDisplay(list_state);
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             // Controls row
             Row(
@@ -296,10 +296,10 @@ pub fn lazy_list_example() {
                     );
                 },
             );
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 8.0,
-            });
+            }));
 
             // Extreme demo row
             Row(
@@ -400,10 +400,10 @@ pub fn lazy_list_example() {
                 },
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             // The actual LazyColumn with virtualization using the DSL
             let count = item_count.get();
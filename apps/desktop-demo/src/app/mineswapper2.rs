@@ -282,10 +282,10 @@ pub fn mineswapper2_tab() {
                                     .rounded_corners(14.0),
                             );
 
-                            Spacer(Size {
+                            Spacer(Modifier::empty().size(Size {
                                 width: 0.0,
                                 height: 0.0,
-                            });
+                            }));
 
                             Row(
                                 Modifier::empty(),
@@ -388,10 +388,10 @@ pub fn mineswapper2_tab() {
                     },
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 12.0,
-                });
+                }));
 
                 let game = content_game_state.get();
                 let flag_mode_value = content_flag_mode.get();
@@ -419,10 +419,10 @@ pub fn mineswapper2_tab() {
                         .rounded_corners(12.0),
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 8.0,
-                });
+                }));
 
                 Text(
                     status_text,
@@ -432,10 +432,10 @@ pub fn mineswapper2_tab() {
                         .rounded_corners(12.0),
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 16.0,
-                });
+                }));
 
                 let grid_width = game.width;
                 let grid_height = game.height;
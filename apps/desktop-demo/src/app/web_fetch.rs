@@ -194,10 +194,10 @@ pub(crate) fn web_fetch_example() {
                         .rounded_corners(16.0),
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 12.0,
-                });
+                }));
 
                 Text(
                     concat!(
@@ -211,10 +211,10 @@ pub(crate) fn web_fetch_example() {
                         .rounded_corners(14.0),
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 16.0,
-                });
+                }));
 
                 Row(
                     Modifier::empty().fill_max_width().padding(4.0),
@@ -256,10 +256,10 @@ pub(crate) fn web_fetch_example() {
                     },
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 12.0,
-                });
+                }));
 
                 let status_snapshot = status_state.get();
                 let (status_label, banner_color) = match &status_snapshot {
@@ -284,10 +284,10 @@ pub(crate) fn web_fetch_example() {
                         .rounded_corners(12.0),
                 );
 
-                Spacer(Size {
+                Spacer(Modifier::empty().size(Size {
                     width: 0.0,
                     height: 8.0,
-                });
+                }));
 
                 match status_snapshot {
                     FetchStatus::Idle => {
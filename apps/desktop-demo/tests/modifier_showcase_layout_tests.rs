@@ -511,10 +511,10 @@ fn dynamic_modifiers_showcase_with_frame(frame: MutableState<i32>) {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         let current_frame = frame.get();
         let x = (current_frame as f32 * 10.0) % 200.0;
@@ -546,10 +546,10 @@ fn dynamic_modifiers_showcase_with_frame(frame: MutableState<i32>) {
             },
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Text(
             format!("Frame: {}, X: {:.1}", current_frame, x),
@@ -19,10 +19,10 @@ fn simple_card_showcase() {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         cranpose_ui::Box(
             Modifier::empty()
@@ -68,10 +68,10 @@ fn positioned_boxes_showcase() {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         cranpose_ui::Box(
             Modifier::empty()
@@ -112,10 +112,10 @@ fn dynamic_modifiers_showcase(frame: i32) {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         let x = (frame as f32 * 10.0) % 200.0;
         let y = 50.0;
@@ -136,10 +136,10 @@ fn dynamic_modifiers_showcase(frame: i32) {
             },
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Text(
             format!("Frame: {}, X: {:.1}", frame, x),
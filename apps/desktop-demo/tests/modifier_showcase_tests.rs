@@ -18,10 +18,10 @@ fn simple_card_showcase() {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         ComposeBox(
             Modifier::empty()
@@ -67,10 +67,10 @@ fn positioned_boxes_showcase() {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         ComposeBox(
             Modifier::empty()
@@ -111,10 +111,10 @@ fn item_list_showcase() {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Column(
             Modifier::empty().padding(16.0),
@@ -157,20 +157,20 @@ fn complex_chain_showcase() {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Text(
             "Deep chain: padding → size → offset → padding",
             Modifier::empty().padding(8.0),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 12.0,
-        });
+        }));
 
         ComposeBox(
             Modifier::empty()
@@ -199,10 +199,10 @@ fn dynamic_modifiers_showcase(frame: i32) {
                 .then(Modifier::empty().rounded_corners(14.0)),
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         let x = (frame as f32 * 10.0) % 200.0;
         let y = 50.0;
@@ -223,10 +223,10 @@ fn dynamic_modifiers_showcase(frame: i32) {
             },
         );
 
-        Spacer(Size {
+        Spacer(Modifier::empty().size(Size {
             width: 0.0,
             height: 16.0,
-        });
+        }));
 
         Text(
             format!("Frame: {}, X: {:.1}", frame, x),
@@ -77,10 +77,10 @@ pub(crate) fn IsolatedDemoApp() {
                 },
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 8.0,
-            });
+            }));
 
             Box(
                 Modifier::empty()
@@ -35,25 +35,6 @@ impl Lerp for f64 {
 pub trait SpringScalar: Lerp + Clone {
     /// Convert the value to `f32` for physics calculations.
     fn to_f32(&self) -> f32;
-
-    /// Compute the current progress between the start and target values.
-    fn spring_progress(start: &Self, target: &Self, current: &Self) -> f32 {
-        let start_val = start.to_f32();
-        let target_val = target.to_f32();
-        let current_val = current.to_f32();
-
-        if (target_val - start_val).abs() < f32::EPSILON {
-            1.0
-        } else {
-            (current_val - start_val) / (target_val - start_val)
-        }
-    }
-
-    /// Determine whether the current value is close enough to the target to
-    /// consider the spring finished.
-    fn is_near_target(current: &Self, target: &Self, threshold: f32) -> bool {
-        (current.to_f32() - target.to_f32()).abs() < threshold
-    }
 }
 
 impl SpringScalar for f32 {
@@ -92,6 +73,9 @@ pub enum Easing {
     /// Fast out, linear in (material design).
     /// Jetpack Compose: FastOutLinearEasing
     FastOutLinearEasing,
+    /// Custom cubic bezier curve through (0, 0), (x1, y1), (x2, y2) and (1, 1).
+    /// Jetpack Compose: CubicBezierEasing(a, b, c, d)
+    CubicBezierEasing { x1: f32, y1: f32, x2: f32, y2: f32 },
 }
 
 impl Easing {
@@ -105,6 +89,9 @@ impl Easing {
             Easing::FastOutSlowInEasing => cubic_bezier(0.4, 0.0, 0.2, 1.0, fraction),
             Easing::LinearOutSlowInEasing => cubic_bezier(0.0, 0.0, 0.2, 1.0, fraction),
             Easing::FastOutLinearEasing => cubic_bezier(0.4, 0.0, 1.0, 1.0, fraction),
+            Easing::CubicBezierEasing { x1, y1, x2, y2 } => {
+                cubic_bezier(*x1, *y1, *x2, *y2, fraction)
+            }
         }
     }
 }
@@ -259,12 +246,95 @@ impl SpringSpec {
     }
 }
 
+impl SpringSpec {
+    /// Create a spring with the given damping ratio and stiffness.
+    ///
+    /// Jetpack Compose: `spring(dampingRatio, stiffness)`
+    pub fn new(damping_ratio: f32, stiffness: f32) -> Self {
+        Self {
+            damping_ratio,
+            stiffness,
+            ..Self::default_spring()
+        }
+    }
+
+    /// Advance a spring from `value`/`velocity` toward `target` by `dt_seconds`.
+    ///
+    /// Uses the closed-form solution of the damped harmonic oscillator, so the
+    /// result depends only on the elapsed time and not on how it is subdivided.
+    pub fn step(&self, value: f32, velocity: f32, target: f32, dt_seconds: f32) -> SpringMotion {
+        let t = f64::from(dt_seconds.max(0.0));
+        let x0 = f64::from(value - target);
+        let v0 = f64::from(velocity);
+        let omega = f64::from(self.stiffness.max(0.0)).sqrt();
+        let zeta = f64::from(self.damping_ratio.max(0.0));
+
+        let (displacement, velocity) = if zeta > 1.0 {
+            // Over-damped: sum of two decaying exponentials.
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let gamma_plus = -zeta * omega + root;
+            let gamma_minus = -zeta * omega - root;
+            let coeff_b = (gamma_minus * x0 - v0) / (gamma_minus - gamma_plus);
+            let coeff_a = x0 - coeff_b;
+            let exp_minus = (gamma_minus * t).exp();
+            let exp_plus = (gamma_plus * t).exp();
+            (
+                coeff_a * exp_minus + coeff_b * exp_plus,
+                coeff_a * gamma_minus * exp_minus + coeff_b * gamma_plus * exp_plus,
+            )
+        } else if (zeta - 1.0).abs() < f64::EPSILON {
+            // Critically damped: fastest return without oscillation.
+            let coeff_a = x0;
+            let coeff_b = v0 + omega * x0;
+            let decay = (-omega * t).exp();
+            let position = (coeff_a + coeff_b * t) * decay;
+            (position, coeff_b * decay - omega * position)
+        } else {
+            // Under-damped: decaying oscillation around the target.
+            let damped_freq = omega * (1.0 - zeta * zeta).sqrt();
+            let cos_coeff = x0;
+            let sin_coeff = if damped_freq > 0.0 {
+                (zeta * omega * x0 + v0) / damped_freq
+            } else {
+                0.0
+            };
+            let decay = (-zeta * omega * t).exp();
+            let (sin, cos) = (damped_freq * t).sin_cos();
+            let position = decay * (cos_coeff * cos + sin_coeff * sin);
+            (
+                position,
+                -zeta * omega * position
+                    + decay * damped_freq * (sin_coeff * cos - cos_coeff * sin),
+            )
+        };
+
+        SpringMotion {
+            value: target + displacement as f32,
+            velocity: velocity as f32,
+        }
+    }
+
+    /// Whether `motion` is close enough to `target` to end the animation.
+    pub fn is_settled(&self, motion: SpringMotion, target: f32) -> bool {
+        (motion.value - target).abs() < self.position_threshold
+            && motion.velocity.abs() < self.velocity_threshold
+    }
+}
+
 impl Default for SpringSpec {
     fn default() -> Self {
         Self::default_spring()
     }
 }
 
+/// Position and velocity of a spring at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringMotion {
+    pub value: f32,
+    /// Velocity in value units per second.
+    pub velocity: f32,
+}
+
 /// Animation type specification.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AnimationType {
@@ -289,13 +359,13 @@ struct AnimatableInner<T: SpringScalar + 'static> {
     state: MutableState<T>,
     runtime: RuntimeHandle,
     current: T,
-    /// Velocity for spring animations (currently unused, reserved for future spring physics)
-    #[allow(dead_code)]
+    /// Velocity in value units per second, carried across spring retargets.
     velocity: f32,
     start: T,
     target: T,
     animation_type: AnimationType,
     start_time_nanos: Option<u64>,
+    last_frame_nanos: Option<u64>,
     registration: Option<FrameCallbackRegistration>,
 }
 
@@ -311,6 +381,7 @@ impl<T: SpringScalar + 'static> Animatable<T> {
             target: initial,
             animation_type: AnimationType::default(),
             start_time_nanos: None,
+            last_frame_nanos: None,
             registration: None,
         };
         Self {
@@ -332,6 +403,7 @@ impl<T: SpringScalar + 'static> Animatable<T> {
             inner.target = target;
             inner.animation_type = animation;
             inner.start_time_nanos = None;
+            inner.last_frame_nanos = None;
 
             true // Always schedule for now
         };
@@ -346,6 +418,16 @@ impl<T: SpringScalar + 'static> Animatable<T> {
         self.inner.borrow().target.clone()
     }
 
+    /// Return the current value without subscribing to it.
+    pub fn value(&self) -> T {
+        self.inner.borrow().current.clone()
+    }
+
+    /// Whether an animation is still advancing the value.
+    pub fn is_running(&self) -> bool {
+        self.inner.borrow().registration.is_some()
+    }
+
     /// Return the animation spec currently driving this animatable.
     pub fn animation_type(&self) -> AnimationType {
         self.inner.borrow().animation_type
@@ -366,6 +448,8 @@ impl<T: SpringScalar + 'static> Animatable<T> {
         inner.start = target.clone();
         inner.target = target.clone();
         inner.start_time_nanos = None;
+        inner.last_frame_nanos = None;
+        inner.velocity = 0.0;
         inner.state.set_value(target);
     }
 
@@ -423,70 +507,35 @@ impl<T: SpringScalar + 'static> Animatable<T> {
                     }
                 }
                 AnimationType::Spring(spec) => {
-                    // Implement spring physics using damped harmonic oscillator
-                    let start_time = inner.start_time_nanos.get_or_insert(frame_time_nanos);
-                    let elapsed_nanos = frame_time_nanos.saturating_sub(*start_time);
-                    let dt = elapsed_nanos as f32 / 1_000_000_000.0; // Convert to seconds
-
-                    // SpringScalar ensures we have scalar values that support the
-                    // physics calculations below (currently f32 and f64).
-                    if dt == 0.0 {
-                        schedule_next = true;
-                    } else {
-                        // Spring physics calculations
-                        // Using semi-implicit Euler integration for stability
-                        let stiffness = spec.stiffness;
-                        let damping = 2.0 * spec.damping_ratio * stiffness.sqrt();
-
-                        // Simulate spring from last frame to current frame
-                        let mut prev_time = 0.0f32;
-                        let timestep: f32 = 0.016; // ~60fps timestep for stability
-
-                        while prev_time < dt {
-                            let step = timestep.min(dt - prev_time);
-
-                            // Spring force: F = -k * displacement - damping * velocity
-                            // For interpolation between start and target:
-                            // We treat position as progress from 0 to 1
-                            let current_progress = <T as SpringScalar>::spring_progress(
-                                &inner.start,
-                                &inner.target,
-                                &inner.current,
-                            );
-
-                            let displacement = current_progress - 1.0; // Target is at 1.0
-                            let spring_force = -stiffness * displacement - damping * inner.velocity;
-
-                            // Update velocity and position
-                            inner.velocity += spring_force * step;
-                            let new_progress = current_progress + inner.velocity * step;
-
-                            // Update current value
-                            inner.current = inner
-                                .start
-                                .lerp(&inner.target, new_progress.clamp(0.0, 2.0));
-
-                            prev_time += step;
-                        }
-
-                        inner.state.set_value(inner.current.clone());
-
-                        // Check if we've settled (velocity and displacement both small)
-                        let at_rest = inner.velocity.abs() < spec.velocity_threshold;
-                        let near_target = <T as SpringScalar>::is_near_target(
-                            &inner.current,
-                            &inner.target,
-                            spec.position_threshold,
-                        );
-
-                        if at_rest && near_target {
-                            inner.current = inner.target.clone();
-                            inner.start = inner.target.clone();
-                            inner.start_time_nanos = None;
-                            inner.velocity = 0.0;
-                            inner.state.set_value(inner.target.clone());
-                        } else {
-                            schedule_next = true;
+                    // The first frame anchors the clock; later frames advance the
+                    // spring by the real time elapsed since the previous frame.
+                    match inner.last_frame_nanos.replace(frame_time_nanos) {
+                        None => schedule_next = true,
+                        Some(last_frame) => {
+                            let dt = frame_time_nanos.saturating_sub(last_frame) as f32
+                                / 1_000_000_000.0;
+                            let start = inner.start.to_f32();
+                            let target = inner.target.to_f32();
+                            let motion =
+                                spec.step(inner.current.to_f32(), inner.velocity, target, dt);
+
+                            if spec.is_settled(motion, target) {
+                                inner.current = inner.target.clone();
+                                inner.start = inner.target.clone();
+                                inner.last_frame_nanos = None;
+                                inner.velocity = 0.0;
+                            } else {
+                                let range = target - start;
+                                let progress = if range.abs() < f32::EPSILON {
+                                    1.0
+                                } else {
+                                    (motion.value - start) / range
+                                };
+                                inner.current = inner.start.lerp(&inner.target, progress);
+                                inner.velocity = motion.velocity;
+                                schedule_next = true;
+                            }
+                            inner.state.set_value(inner.current.clone());
                         }
                     }
                 }
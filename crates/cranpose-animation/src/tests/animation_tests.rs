@@ -89,6 +89,165 @@ fn animate_float_as_state_interpolates_over_time() {
     assert!(!composition.should_render());
 }
 
+const FRAME_NANOS: u64 = 16_000_000;
+
+fn render_animated(
+    composition: &mut Composition<MemoryApplier>,
+    target: f32,
+    animation: AnimationType,
+) -> State<f32> {
+    let root_key = location_key(file!(), line!(), column!());
+    let group_key = location_key(file!(), line!(), column!());
+    let state_slot = Rc::new(RefCell::new(None::<State<f32>>));
+    {
+        let state_slot = Rc::clone(&state_slot);
+        composition
+            .render(root_key, move || {
+                let state_slot = Rc::clone(&state_slot);
+                with_current_composer(|composer| {
+                    composer.with_group(group_key, |_| {
+                        let state = animateFloatAsStateWithSpec(target, animation, "value");
+                        state_slot.borrow_mut().replace(state);
+                    });
+                });
+            })
+            .expect("render succeeds");
+    }
+    let state = state_slot.borrow_mut().take().expect("state available");
+    state
+}
+
+fn advance_frame(composition: &mut Composition<MemoryApplier>, frame_time: u64) {
+    composition
+        .runtime_handle()
+        .drain_frame_callbacks(frame_time);
+    let _ = composition
+        .process_invalid_scopes()
+        .expect("process invalid scopes succeeds");
+}
+
+#[test]
+fn animate_float_as_state_tween_is_monotonic_over_duration() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let animation = AnimationType::Tween(AnimationSpec::linear(160));
+    render_animated(&mut composition, 0.0, animation);
+    let state = render_animated(&mut composition, 1.0, animation);
+
+    // The first frame anchors the start time, so progress is measured from it.
+    let mut frame_time = FRAME_NANOS;
+    advance_frame(&mut composition, frame_time);
+    let mut samples = vec![state.get()];
+    while composition.should_render() {
+        frame_time += FRAME_NANOS;
+        advance_frame(&mut composition, frame_time);
+        samples.push(state.get());
+        assert!(
+            samples.len() <= 16,
+            "tween should finish within its duration"
+        );
+    }
+
+    assert_eq!(samples.first().copied(), Some(0.0));
+    assert_eq!(samples.last().copied(), Some(1.0));
+    assert_eq!(samples.len(), 11, "160ms at 16ms frames takes ten steps");
+    for (index, pair) in samples.windows(2).enumerate() {
+        assert!(
+            pair[1] > pair[0],
+            "sample {index} should increase: {pair:?}"
+        );
+        let expected = (index + 1) as f32 * 0.1;
+        assert!(
+            (pair[1] - expected).abs() < 1e-4,
+            "linear progress at step {index}"
+        );
+    }
+}
+
+#[test]
+fn animate_float_as_state_retargets_from_current_value() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let animation = AnimationType::Tween(AnimationSpec::linear(160));
+    render_animated(&mut composition, 0.0, animation);
+    let state = render_animated(&mut composition, 1.0, animation);
+
+    let mut frame_time = 0;
+    for _ in 0..5 {
+        frame_time += FRAME_NANOS;
+        advance_frame(&mut composition, frame_time);
+    }
+    let interrupted_at = state.get();
+    assert!(interrupted_at > 0.0 && interrupted_at < 1.0);
+
+    let state = render_animated(&mut composition, 0.0, animation);
+    assert_eq!(state.get(), interrupted_at, "retargeting must not jump");
+
+    let mut previous = interrupted_at;
+    while composition.should_render() {
+        frame_time += FRAME_NANOS;
+        advance_frame(&mut composition, frame_time);
+        let value = state.get();
+        assert!(
+            value <= previous,
+            "value should head back toward the new target"
+        );
+        previous = value;
+    }
+    assert_eq!(previous, 0.0);
+}
+
+/// Value of a 0 -> 100 animation after `frames` frames of `frame_nanos` each.
+fn value_after_frames(animation: AnimationType, frame_nanos: u64, frames: u64) -> f32 {
+    let mut composition = Composition::new(MemoryApplier::new());
+    render_animated(&mut composition, 0.0, animation);
+    let state = render_animated(&mut composition, 100.0, animation);
+    // Both runs anchor the animation clock at the same time
+    advance_frame(&mut composition, 0);
+    for frame in 1..=frames {
+        advance_frame(&mut composition, frame * frame_nanos);
+    }
+    state.get()
+}
+
+#[test]
+fn animations_advance_by_elapsed_time_not_frame_count() {
+    let animations = [
+        AnimationType::Tween(AnimationSpec::tween(300, Easing::FastOutSlowInEasing)),
+        AnimationType::Spring(SpringSpec::new(0.5, 200.0)),
+    ];
+    for animation in animations {
+        // 240ms at 60Hz and at 120Hz
+        let at_60hz = value_after_frames(animation, 16_000_000, 15);
+        let at_120hz = value_after_frames(animation, 8_000_000, 30);
+        assert!(
+            at_60hz > 0.0 && at_60hz != 100.0,
+            "{animation:?} still running"
+        );
+        assert!(
+            (at_60hz - at_120hz).abs() < 1e-3,
+            "{animation:?}: {at_60hz} at 60Hz vs {at_120hz} at 120Hz"
+        );
+    }
+}
+
+#[test]
+fn animatable_reports_value_while_running() {
+    let composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let mut animatable = Animatable::new(0.0f32, runtime.clone());
+    assert!(!animatable.is_running());
+
+    animatable.animateTo(10.0, AnimationType::Tween(AnimationSpec::linear(100)));
+    assert!(animatable.is_running());
+    runtime.drain_frame_callbacks(0);
+    runtime.drain_frame_callbacks(50_000_000);
+    assert_eq!(animatable.value(), 5.0);
+    assert!(animatable.is_running());
+
+    runtime.drain_frame_callbacks(100_000_000);
+    assert_eq!(animatable.value(), 10.0);
+    assert!(!animatable.is_running());
+}
+
 #[test]
 fn easing_linear_is_identity() {
     assert_eq!(Easing::LinearEasing.transform(0.0), 0.0);
@@ -104,6 +263,14 @@ fn easing_bounds_are_correct() {
         Easing::EaseOut,
         Easing::EaseInOut,
         Easing::FastOutSlowInEasing,
+        Easing::LinearOutSlowInEasing,
+        Easing::FastOutLinearEasing,
+        Easing::CubicBezierEasing {
+            x1: 0.68,
+            y1: -0.6,
+            x2: 0.32,
+            y2: 1.6,
+        },
     ];
 
     for easing in easings {
@@ -122,6 +289,44 @@ fn easing_bounds_are_correct() {
     }
 }
 
+#[test]
+fn easing_fast_out_slow_in_matches_material_midpoint() {
+    // cubic-bezier(0.4, 0.0, 0.2, 1.0) evaluated at x = 0.5.
+    let value = Easing::FastOutSlowInEasing.transform(0.5);
+    assert!((value - 0.7756).abs() < 1e-3, "got {value}");
+}
+
+#[test]
+fn easing_custom_cubic_bezier_matches_named_curve() {
+    let custom = Easing::CubicBezierEasing {
+        x1: 0.4,
+        y1: 0.0,
+        x2: 0.2,
+        y2: 1.0,
+    };
+    for step in 0..=10 {
+        let fraction = step as f32 / 10.0;
+        assert_eq!(
+            custom.transform(fraction),
+            Easing::FastOutSlowInEasing.transform(fraction)
+        );
+    }
+}
+
+#[test]
+fn easing_cubic_bezier_is_monotonic_for_monotonic_control_points() {
+    let easing = Easing::CubicBezierEasing {
+        x1: 0.25,
+        y1: 0.1,
+        x2: 0.25,
+        y2: 1.0,
+    };
+    let samples: Vec<f32> = (0..=100)
+        .map(|i| easing.transform(i as f32 / 100.0))
+        .collect();
+    assert!(samples.windows(2).all(|pair| pair[1] >= pair[0]));
+}
+
 #[test]
 fn animation_spec_default_has_reasonable_values() {
     let spec = AnimationSpec::default();
@@ -152,3 +357,86 @@ fn spring_spec_stiff_has_high_stiffness() {
     assert_eq!(spec.stiffness, 3000.0);
     assert!(spec.stiffness > SpringSpec::default().stiffness);
 }
+
+fn simulate_spring(spec: SpringSpec, from: f32, to: f32, dt: f32, max_steps: usize) -> Vec<f32> {
+    let mut motion = SpringMotion {
+        value: from,
+        velocity: 0.0,
+    };
+    let mut values = vec![from];
+    for _ in 0..max_steps {
+        motion = spec.step(motion.value, motion.velocity, to, dt);
+        values.push(motion.value);
+        if spec.is_settled(motion, to) {
+            break;
+        }
+    }
+    values
+}
+
+#[test]
+fn spring_critically_damped_settles_without_overshoot() {
+    let spec = SpringSpec::new(1.0, 400.0);
+    let values = simulate_spring(spec, 0.0, 100.0, 1.0 / 60.0, 600);
+    assert!(values.len() < 600, "critically damped spring should settle");
+    assert!(values.iter().all(|value| *value <= 100.0), "no overshoot");
+    assert!(values.windows(2).all(|pair| pair[1] >= pair[0]));
+    assert!((values.last().unwrap() - 100.0).abs() < spec.position_threshold);
+}
+
+#[test]
+fn spring_under_damped_overshoots_then_settles() {
+    let spec = SpringSpec::new(0.2, 400.0);
+    let values = simulate_spring(spec, 0.0, 100.0, 1.0 / 60.0, 1200);
+    assert!(values.len() < 1200, "under-damped spring should settle");
+    let peak = values.iter().copied().fold(f32::MIN, f32::max);
+    assert!(
+        peak > 100.0,
+        "under-damped spring should overshoot, peak {peak}"
+    );
+    assert!((values.last().unwrap() - 100.0).abs() < spec.position_threshold);
+}
+
+#[test]
+fn spring_over_damped_settles_slower_than_critical() {
+    let critical = simulate_spring(SpringSpec::new(1.0, 400.0), 0.0, 100.0, 1.0 / 60.0, 2000);
+    let over = simulate_spring(SpringSpec::new(3.0, 400.0), 0.0, 100.0, 1.0 / 60.0, 2000);
+    assert!(over.len() < 2000, "over-damped spring should settle");
+    assert!(over.iter().all(|value| *value <= 100.0), "no overshoot");
+    assert!(over.len() > critical.len());
+}
+
+#[test]
+fn spring_step_is_deterministic_and_subdivision_independent() {
+    let spec = SpringSpec::bouncy();
+    let whole = spec.step(0.0, 5.0, 1.0, 0.1);
+    let again = spec.step(0.0, 5.0, 1.0, 0.1);
+    assert_eq!(whole, again);
+
+    let half = spec.step(0.0, 5.0, 1.0, 0.05);
+    let split = spec.step(half.value, half.velocity, 1.0, 0.05);
+    assert!((split.value - whole.value).abs() < 1e-4);
+    assert!((split.velocity - whole.velocity).abs() < 1e-3);
+}
+
+#[test]
+fn animate_float_as_state_spring_reaches_target_and_stops() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let animation = AnimationType::Spring(SpringSpec::new(1.0, 400.0));
+    render_animated(&mut composition, 0.0, animation);
+    let state = render_animated(&mut composition, 10.0, animation);
+
+    let mut frame_time = 0;
+    let mut previous = state.get();
+    let mut frames = 0;
+    while composition.should_render() {
+        frame_time += FRAME_NANOS;
+        advance_frame(&mut composition, frame_time);
+        let value = state.get();
+        assert!(value >= previous && value <= 10.0);
+        previous = value;
+        frames += 1;
+        assert!(frames < 200, "spring should settle");
+    }
+    assert_eq!(previous, 10.0);
+}
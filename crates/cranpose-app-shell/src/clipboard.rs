@@ -0,0 +1,72 @@
+//! Desktop clipboard backend built on `arboard`.
+
+use cranpose_ui::Clipboard;
+use std::cell::RefCell;
+
+/// System clipboard for desktop targets.
+///
+/// The `arboard` handle is kept alive for the lifetime of the app because
+/// Linux X11 drops clipboard contents when their owner goes away.
+pub struct DesktopClipboard {
+    inner: RefCell<Option<arboard::Clipboard>>,
+}
+
+impl DesktopClipboard {
+    pub fn new() -> Self {
+        Self {
+            inner: RefCell::new(arboard::Clipboard::new().ok()),
+        }
+    }
+
+    /// Sets the Linux primary selection (for middle-click paste).
+    #[cfg(target_os = "linux")]
+    pub fn set_primary_text(&self, text: &str) {
+        use arboard::{LinuxClipboardKind, SetExtLinux};
+        if let Some(clipboard) = self.inner.borrow_mut().as_mut() {
+            let result = clipboard
+                .set()
+                .clipboard(LinuxClipboardKind::Primary)
+                .text(text.to_string());
+            if let Err(e) = result {
+                // Primary selection may not be available on all systems
+                log::debug!("Primary selection set failed: {:?}", e);
+            }
+        }
+    }
+
+    /// Gets text from the Linux primary selection (for middle-click paste).
+    #[cfg(target_os = "linux")]
+    pub fn get_primary_text(&self) -> Option<String> {
+        use arboard::{GetExtLinux, LinuxClipboardKind};
+        self.inner.borrow_mut().as_mut().and_then(|clipboard| {
+            clipboard
+                .get()
+                .clipboard(LinuxClipboardKind::Primary)
+                .text()
+                .ok()
+        })
+    }
+}
+
+impl Default for DesktopClipboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clipboard for DesktopClipboard {
+    fn get_text(&self) -> Option<String> {
+        self.inner
+            .borrow_mut()
+            .as_mut()
+            .and_then(|clipboard| clipboard.get_text().ok())
+    }
+
+    fn set_text(&self, text: &str) {
+        if let Some(clipboard) = self.inner.borrow_mut().as_mut() {
+            if let Err(e) = clipboard.set_text(text) {
+                log::debug!("Clipboard set failed: {:?}", e);
+            }
+        }
+    }
+}
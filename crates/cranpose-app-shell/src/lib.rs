@@ -1,5 +1,7 @@
 #![allow(clippy::type_complexity)]
 
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+mod clipboard;
 mod fps_monitor;
 mod hit_path_tracker;
 
@@ -8,15 +10,19 @@ pub use fps_monitor::{
     current_fps, fps_display, fps_display_detailed, fps_stats, record_recomposition, FpsStats,
 };
 
+use std::cell::RefCell;
 use std::fmt::Debug;
+use std::rc::Rc;
 // Use web_time for cross-platform time support (native + WASM) - compatible with winit
 use web_time::Instant;
 
 use cranpose_core::{
     enter_event_handler, exit_event_handler, location_key, run_in_mutable_snapshot, Applier,
-    Composition, Key, MemoryApplier, NodeError, NodeId,
+    Composition, CompositionLocalProvider, Key, MemoryApplier, NodeError, NodeId,
+};
+use cranpose_foundation::{
+    CursorIcon, PointerButton, PointerButtons, PointerEvent, PointerEventKind,
 };
-use cranpose_foundation::{PointerButton, PointerButtons, PointerEvent, PointerEventKind};
 use cranpose_render_common::{HitTestTarget, RenderScene, Renderer};
 use cranpose_runtime_std::StdRuntime;
 use cranpose_ui::{
@@ -25,15 +31,19 @@ use cranpose_ui::{
     peek_pointer_invalidation, peek_render_invalidation, process_focus_invalidations,
     process_pointer_repasses, request_render_invalidation, take_draw_repass_nodes,
     take_focus_invalidation, take_layout_invalidation, take_pointer_invalidation,
-    take_render_invalidation, HeadlessRenderer, LayoutNode, LayoutTree, SemanticsTree,
-    SubcomposeLayoutNode,
+    take_render_invalidation, FocusDirection, FocusSource, HeadlessRenderer, LayoutNode,
+    LayoutTree, PopupStack, SemanticsTree, SubcomposeLayoutNode,
 };
 use cranpose_ui_graphics::{Point, Size};
 use hit_path_tracker::{HitPathTracker, PointerId};
 use std::collections::HashSet;
 
 // Re-export key event types for use by cranpose
-pub use cranpose_ui::{KeyCode, KeyEvent, KeyEventType, Modifiers};
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+pub use clipboard::DesktopClipboard;
+#[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+use cranpose_ui::Clipboard as _;
+pub use cranpose_ui::{ImeEvent, KeyCode, KeyEvent, KeyEventType, Modifiers};
 
 pub struct AppShell<R>
 where
@@ -51,6 +61,10 @@ where
     layout_dirty: bool,
     scene_dirty: bool,
     is_dirty: bool,
+    /// Set when the presented frame is stale: the scene content or the surface changed
+    frame_dirty: bool,
+    /// Content hash of the scene as of the last rebuild
+    last_scene_hash: Option<u64>,
     /// Tracks which mouse buttons are currently pressed
     buttons_pressed: PointerButtons,
     /// Tracks which nodes were hit on PointerDown (by stable NodeId).
@@ -60,9 +74,19 @@ where
     /// - On Move/Up/Cancel: resolve fresh HitTargets from current scene
     /// - Handler closures are preserved (same Rc), so internal state survives
     hit_path_tracker: HitPathTracker,
+    /// Whether cursor moves are queued and dispatched once per frame
+    coalesce_pointer_moves: bool,
+    /// Latest cursor position queued while coalescing, not yet dispatched
+    pending_move: Option<(f32, f32)>,
+    /// Nodes under the cursor as of the last hover move, topmost first
+    hovered: Vec<NodeId>,
+    /// Cursor requested by the topmost hovered node, or the default arrow
+    cursor_icon: CursorIcon,
+    /// Popup layers of the app, for closing the topmost popup on Escape
+    popup_stack: PopupStack,
     /// Persistent clipboard for desktop (Linux X11 requires clipboard to stay alive)
     #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
-    clipboard: Option<arboard::Clipboard>,
+    clipboard: std::rc::Rc<DesktopClipboard>,
     /// Dev options for debugging and performance monitoring
     dev_options: DevOptions,
 }
@@ -90,9 +114,30 @@ where
         // Initialize FPS tracking
         fps_monitor::init_fps_tracker();
 
+        // Install the system clipboard before composing so `local_clipboard()` sees it
+        #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+        let clipboard = {
+            let clipboard = std::rc::Rc::new(DesktopClipboard::new());
+            cranpose_ui::set_platform_clipboard(clipboard.clone());
+            clipboard
+        };
+
         let runtime = StdRuntime::new();
         let mut composition = Composition::with_runtime(MemoryApplier::new(), runtime.runtime());
-        let build = content;
+        // The popup host puts every `Popup` of the app above its content
+        let popup_stack = PopupStack::new();
+        let content = Rc::new(RefCell::new(content));
+        let stack = popup_stack.clone();
+        let build = move || {
+            let content = Rc::clone(&content);
+            CompositionLocalProvider(
+                [cranpose_ui::local_popup_stack().provides(Some(stack.clone()))],
+                move || {
+                    let content = Rc::clone(&content);
+                    cranpose_ui::PopupHost(move || (content.borrow_mut())());
+                },
+            );
+        };
         if let Err(err) = composition.render(root_key, build) {
             log::error!("initial render failed: {err}");
         }
@@ -110,10 +155,17 @@ where
             layout_dirty: true,
             scene_dirty: true,
             is_dirty: true,
+            frame_dirty: true,
+            last_scene_hash: None,
             buttons_pressed: PointerButtons::NONE,
             hit_path_tracker: HitPathTracker::new(),
+            coalesce_pointer_moves: false,
+            pending_move: None,
+            hovered: Vec::new(),
+            cursor_icon: CursorIcon::Default,
+            popup_stack,
             #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
-            clipboard: arboard::Clipboard::new().ok(),
+            clipboard,
             dev_options: DevOptions::default(),
         };
         shell.process_frame();
@@ -133,6 +185,7 @@ where
         &self.dev_options
     }
 
+    /// Sets the window size in logical pixels, the unit of layout and input.
     pub fn set_viewport(&mut self, width: f32, height: f32) {
         self.viewport = (width, height);
         self.layout_dirty = true;
@@ -140,8 +193,15 @@ where
         self.process_frame();
     }
 
+    pub fn viewport(&self) -> (f32, f32) {
+        self.viewport
+    }
+
+    /// Sets the surface size in physical pixels.
     pub fn set_buffer_size(&mut self, width: u32, height: u32) {
         self.buffer_size = (width, height);
+        // The surface was (re)configured, so its contents must be drawn again
+        self.frame_dirty = true;
     }
 
     pub fn buffer_size(&self) -> (u32, u32) {
@@ -189,6 +249,21 @@ where
         self.is_dirty || self.layout_dirty || self.has_active_animations()
     }
 
+    /// Returns true if the frame on screen is stale and the scene should be rendered
+    /// and presented.
+    ///
+    /// Stays false across rebuilds that produce an identical scene, so shells can
+    /// skip rendering while nothing visible changes. Animations keep changing the
+    /// scene and therefore keep this set.
+    pub fn needs_present(&self) -> bool {
+        self.frame_dirty
+    }
+
+    /// Records that the current scene has been rendered and presented.
+    pub fn mark_presented(&mut self) {
+        self.frame_dirty = false;
+    }
+
     /// Marks the shell as dirty, indicating a redraw is needed.
     pub fn mark_dirty(&mut self) {
         self.is_dirty = true;
@@ -227,6 +302,7 @@ where
     }
 
     pub fn update(&mut self) {
+        self.flush_pending_move();
         let now = Instant::now();
         let frame_time = now
             .checked_duration_since(self.start_time)
@@ -272,7 +348,43 @@ where
         self.is_dirty = false;
     }
 
+    /// Enables or disables coalescing of cursor moves.
+    ///
+    /// While enabled, [`set_cursor`](Self::set_cursor) only records the latest
+    /// position; a single Move is dispatched for it on the next [`update`](Self::update),
+    /// or right before the next press, release or cancel so those keep their order.
+    /// This keeps high-frequency move streams, like browser `pointermove`, from
+    /// dispatching (and recomposing) once per event.
+    pub fn set_coalesce_pointer_moves(&mut self, enabled: bool) {
+        self.coalesce_pointer_moves = enabled;
+        if !enabled {
+            self.flush_pending_move();
+        }
+    }
+
+    /// Moves the cursor and dispatches a Move event to the nodes under it, or to
+    /// the nodes tracking the current gesture.
+    ///
+    /// `x` and `y` are in logical pixels, like layout and hit regions.
+    ///
+    /// Returns `true` if the event was dispatched, or queued while coalescing moves.
     pub fn set_cursor(&mut self, x: f32, y: f32) -> bool {
+        if self.coalesce_pointer_moves {
+            self.pending_move = Some((x, y));
+            self.mark_dirty();
+            return true;
+        }
+        self.dispatch_cursor_move(x, y)
+    }
+
+    /// Dispatches the move queued while coalescing, if any.
+    fn flush_pending_move(&mut self) {
+        if let Some((x, y)) = self.pending_move.take() {
+            self.dispatch_cursor_move(x, y);
+        }
+    }
+
+    fn dispatch_cursor_move(&mut self, x: f32, y: f32) -> bool {
         self.cursor = (x, y);
 
         // During a gesture (button pressed), ONLY dispatch to the tracked hit path.
@@ -327,6 +439,8 @@ where
 
         // No gesture in progress: regular hover move using hit-test.
         let hits = self.renderer.scene().hit_test(x, y);
+        self.update_hover(&hits, x, y);
+        self.cursor_icon = self.hovered_cursor_icon();
         if !hits.is_empty() {
             let event = PointerEvent::new(PointerEventKind::Move, Point { x, y }, Point { x, y })
                 .with_buttons(self.buttons_pressed); // usually NONE here
@@ -343,7 +457,59 @@ where
         }
     }
 
+    /// Sends Exit to nodes the cursor left and Enter to nodes it moved onto.
+    ///
+    /// Every node under the cursor gets its own Enter/Exit, so consumption
+    /// doesn't stop them the way it stops Move.
+    fn update_hover(&mut self, hits: &[<R::Scene as RenderScene>::HitTarget], x: f32, y: f32) {
+        let hovered: Vec<NodeId> = hits.iter().map(|hit| hit.node_id()).collect();
+        if hovered == self.hovered {
+            return;
+        }
+        let position = Point { x, y };
+        for node_id in &self.hovered {
+            if !hovered.contains(node_id) {
+                if let Some(target) = self.renderer.scene().find_target(*node_id) {
+                    target.dispatch(PointerEvent::new(
+                        PointerEventKind::Exit,
+                        position,
+                        position,
+                    ));
+                }
+            }
+        }
+        for hit in hits {
+            if !self.hovered.contains(&hit.node_id()) {
+                hit.dispatch(PointerEvent::new(
+                    PointerEventKind::Enter,
+                    position,
+                    position,
+                ));
+            }
+        }
+        self.hovered = hovered;
+        self.mark_dirty();
+    }
+
+    /// Cursor the platform should show, as requested by the topmost hovered
+    /// element's `pointer_cursor` modifier.
+    ///
+    /// It updates on moves made without a button pressed, so a drag keeps the
+    /// cursor it started with.
+    pub fn cursor_icon(&self) -> CursorIcon {
+        self.cursor_icon
+    }
+
+    fn hovered_cursor_icon(&mut self) -> CursorIcon {
+        let mut applier = self.composition.applier_mut();
+        self.hovered
+            .iter()
+            .find_map(|node_id| node_pointer_cursor(&mut applier, *node_id))
+            .unwrap_or_default()
+    }
+
     pub fn pointer_pressed(&mut self) -> bool {
+        self.flush_pending_move();
         enter_event_handler();
         let result = self.pointer_pressed_inner();
         exit_event_handler();
@@ -366,7 +532,7 @@ where
         // Cache NodeIds for this pointer
         let node_ids: Vec<_> = hits.iter().map(|h| h.node_id()).collect();
         self.hit_path_tracker
-            .add_hit_path(PointerId::PRIMARY, node_ids);
+            .add_hit_path(PointerId::PRIMARY, node_ids.clone());
 
         if !hits.is_empty() {
             let event = PointerEvent::new(
@@ -389,6 +555,7 @@ where
                     break;
                 }
             }
+            self.focus_pressed_node(&node_ids);
             self.mark_dirty();
             true
         } else {
@@ -396,7 +563,20 @@ where
         }
     }
 
+    /// Focuses the topmost focusable node under a press, if any.
+    ///
+    /// Pointer focus does not draw a focus ring, which is meant for keyboard users.
+    fn focus_pressed_node(&mut self, node_ids: &[NodeId]) {
+        let mut applier = self.composition.applier_mut();
+        let _ = run_in_mutable_snapshot(|| {
+            node_ids.iter().any(|&node_id| {
+                cranpose_ui::request_focus_from(&mut applier, node_id, FocusSource::Pointer)
+            })
+        });
+    }
+
     pub fn pointer_released(&mut self) -> bool {
+        self.flush_pending_move();
         enter_event_handler();
         let result = self.pointer_released_inner();
         exit_event_handler();
@@ -448,6 +628,7 @@ where
     /// - Mouse leaves window while button pressed
     /// - Any other gesture abort scenario
     pub fn cancel_gesture(&mut self) {
+        self.flush_pending_move();
         // Resolve FRESH targets from cached NodeIds
         let targets = self.resolve_hit_path(PointerId::PRIMARY);
 
@@ -476,7 +657,10 @@ where
     }
     /// Routes a keyboard event to the focused text field, if any.
     ///
-    /// Returns `true` if the event was consumed by a text field.
+    /// When no text field is focused, Tab and Shift+Tab move focus between
+    /// focusable nodes instead.
+    ///
+    /// Returns `true` if the event was consumed.
     ///
     /// On desktop, Ctrl+C/X/V are handled here with system clipboard (arboard).
     /// On web, these keys are NOT handled here - they bubble to browser for native copy/paste events.
@@ -493,26 +677,22 @@ where
 
         // Only process KeyDown events for clipboard shortcuts
         if event.event_type == KeyDown && event.modifiers.command_or_ctrl() {
-            // Desktop-only clipboard handling via arboard
-            // Use persistent self.clipboard to keep content alive on Linux X11
+            // Desktop-only clipboard handling via the arboard-backed DesktopClipboard
             #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
             {
                 match event.key_code {
                     // Ctrl+C - Copy
                     KeyCode::C => {
-                        // Get text first, then access clipboard to avoid borrow conflict
-                        let text = self.on_copy();
-                        if let (Some(text), Some(clipboard)) = (text, self.clipboard.as_mut()) {
-                            let _ = clipboard.set_text(&text);
+                        if let Some(text) = self.on_copy() {
+                            self.clipboard.set_text(&text);
                             return true;
                         }
                     }
                     // Ctrl+X - Cut
                     KeyCode::X => {
-                        // Get text first (this also deletes it), then access clipboard
-                        let text = self.on_cut();
-                        if let (Some(text), Some(clipboard)) = (text, self.clipboard.as_mut()) {
-                            let _ = clipboard.set_text(&text);
+                        // Cutting also deletes the selection from the field
+                        if let Some(text) = self.on_cut() {
+                            self.clipboard.set_text(&text);
                             self.mark_dirty();
                             self.layout_dirty = true;
                             return true;
@@ -520,9 +700,7 @@ where
                     }
                     // Ctrl+V - Paste
                     KeyCode::V => {
-                        // Get text from clipboard first, then paste
-                        let text = self.clipboard.as_mut().and_then(|cb| cb.get_text().ok());
-                        if let Some(text) = text {
+                        if let Some(text) = self.clipboard.get_text() {
                             if self.on_paste(&text) {
                                 return true;
                             }
@@ -533,8 +711,33 @@ where
             }
         }
 
-        // Pure O(1) dispatch - no tree walking needed
+        // Escape asks the topmost popup or dialog to close
+        if event.event_type == KeyDown && event.key_code == KeyCode::Escape {
+            let popup_stack = self.popup_stack.clone();
+            match run_in_mutable_snapshot(move || popup_stack.dismiss_topmost()) {
+                Ok(true) => {
+                    self.mark_dirty();
+                    return true;
+                }
+                Ok(false) => {}
+                Err(err) => log::error!("dismissing the topmost popup failed: {err}"),
+            }
+        }
+
+        // Tab / Shift+Tab moves focus between focusable nodes in reading order,
+        // unless an on_key_event handler of the focused node consumes the key
         if !cranpose_ui::text_field_focus::has_focused_field() {
+            if self.dispatch_focused_key_event(event) {
+                return true;
+            }
+            if event.event_type == KeyDown && event.key_code == KeyCode::Tab {
+                let direction = if event.modifiers.shift {
+                    FocusDirection::Previous
+                } else {
+                    FocusDirection::Next
+                };
+                return self.move_focus(direction);
+            }
             return false;
         }
 
@@ -557,6 +760,38 @@ where
         handled
     }
 
+    /// Sends `event` to the `on_key_event` handlers of the focused node and
+    /// its ancestors, returning `true` if one consumed it.
+    fn dispatch_focused_key_event(&mut self, event: &KeyEvent) -> bool {
+        let handled = {
+            let mut applier = self.composition.applier_mut();
+            run_in_mutable_snapshot(|| cranpose_ui::dispatch_focused_key_event(&mut applier, event))
+                .unwrap_or(false)
+        };
+        if handled {
+            self.mark_dirty();
+        }
+        handled
+    }
+
+    /// Moves keyboard focus to the next or previous focusable node.
+    ///
+    /// Returns `true` if a node received focus.
+    pub fn move_focus(&mut self, direction: FocusDirection) -> bool {
+        let Some(root) = self.composition.root() else {
+            return false;
+        };
+        let moved = {
+            let mut applier = self.composition.applier_mut();
+            run_in_mutable_snapshot(|| cranpose_ui::move_focus(&mut applier, root, direction))
+                .unwrap_or(false)
+        };
+        if moved {
+            self.mark_dirty();
+        }
+        moved
+    }
+
     /// Handles paste event from platform clipboard.
     /// Returns `true` if the paste was consumed by a focused text field.
     /// O(1) operation using stored handler.
@@ -577,11 +812,12 @@ where
     }
 
     /// Handles copy request from platform.
-    /// Returns the selected text from focused text field, or None.
+    /// Returns the selected text from the focused text field or, failing that,
+    /// from selectable text. Returns None if nothing is selected.
     /// O(1) operation using stored handler.
     pub fn on_copy(&mut self) -> Option<String> {
-        // Use O(1) dispatch instead of tree scan
-        cranpose_ui::text_field_focus::dispatch_copy()
+        // Use O(1) dispatch instead of tree scan; fall back to read-only text selection
+        cranpose_ui::text_field_focus::dispatch_copy().or_else(cranpose_ui::copy_selected_text)
     }
 
     /// Handles cut request from platform.
@@ -604,33 +840,14 @@ where
     /// On non-Linux platforms, this is a no-op.
     #[cfg(all(target_os = "linux", not(target_arch = "wasm32")))]
     pub fn set_primary_selection(&mut self, text: &str) {
-        use arboard::{LinuxClipboardKind, SetExtLinux};
-        if let Some(ref mut clipboard) = self.clipboard {
-            let result = clipboard
-                .set()
-                .clipboard(LinuxClipboardKind::Primary)
-                .text(text.to_string());
-            if let Err(e) = result {
-                // Primary selection may not be available on all systems
-                log::debug!("Primary selection set failed: {:?}", e);
-            }
-        }
+        self.clipboard.set_primary_text(text);
     }
 
     /// Gets text from the Linux primary selection (for middle-click paste).
     /// On non-Linux platforms, returns None.
     #[cfg(all(target_os = "linux", not(target_arch = "wasm32")))]
     pub fn get_primary_selection(&mut self) -> Option<String> {
-        use arboard::{GetExtLinux, LinuxClipboardKind};
-        if let Some(ref mut clipboard) = self.clipboard {
-            clipboard
-                .get()
-                .clipboard(LinuxClipboardKind::Primary)
-                .text()
-                .ok()
-        } else {
-            None
-        }
+        self.clipboard.get_primary_text()
     }
 
     #[cfg(all(not(target_os = "linux"), not(target_arch = "wasm32")))]
@@ -649,6 +866,24 @@ where
         }
     }
 
+    /// Routes input method text to the focused text field.
+    ///
+    /// A commit first clears any composition in progress, then inserts the text.
+    /// Returns `true` if a text field consumed the event.
+    pub fn on_ime_event(&mut self, event: &ImeEvent) -> bool {
+        match event {
+            ImeEvent::Preedit { text, cursor } => self.on_ime_preedit(text, *cursor),
+            ImeEvent::Commit(text) => {
+                self.on_ime_preedit("", None);
+                self.on_paste(text)
+            }
+            ImeEvent::DeleteSurrounding {
+                before_bytes,
+                after_bytes,
+            } => self.on_ime_delete_surrounding(*before_bytes, *after_bytes),
+        }
+    }
+
     /// Handles IME preedit (composition) events.
     /// Called when the input method is composing text (e.g., typing CJK characters).
     ///
@@ -726,6 +961,7 @@ where
         let _frame_start = Instant::now();
 
         self.run_layout_phase();
+        self.run_bring_into_view_requests();
 
         #[cfg(debug_assertions)]
         let _after_layout = Instant::now();
@@ -892,6 +1128,21 @@ where
         }
     }
 
+    /// Scrolls nodes that asked to be brought into view, now that their
+    /// positions are current. The scrolling itself lands in later frames.
+    fn run_bring_into_view_requests(&mut self) {
+        if !cranpose_ui::has_pending_bring_into_view_requests() {
+            return;
+        }
+        let Some(root) = self.composition.root() else {
+            return;
+        };
+        let mut applier = self.composition.applier_mut();
+        let _ = run_in_mutable_snapshot(|| {
+            cranpose_ui::process_bring_into_view_requests(&mut applier, root)
+        });
+    }
+
     fn run_dispatch_queues(&mut self) {
         // Process pointer input repasses
         // Similar to Jetpack Compose's pointer input invalidation processing,
@@ -998,9 +1249,31 @@ where
             );
             self.renderer.draw_dev_overlay(&text, viewport_size);
         }
+
+        // Scenes that can't hash their content are always presented
+        let scene_hash = self.renderer.scene().content_hash();
+        if scene_hash.is_none() || scene_hash != self.last_scene_hash {
+            self.frame_dirty = true;
+        }
+        self.last_scene_hash = scene_hash;
     }
 }
 
+/// Cursor requested by the node's `pointer_cursor` modifier, if any.
+fn node_pointer_cursor(applier: &mut MemoryApplier, node_id: NodeId) -> Option<CursorIcon> {
+    applier
+        .with_node::<LayoutNode, _>(node_id, |node| {
+            node.modifier_slices_snapshot().pointer_cursor()
+        })
+        .or_else(|_| {
+            applier.with_node::<SubcomposeLayoutNode, _>(node_id, |node| {
+                node.modifier_slices_snapshot().pointer_cursor()
+            })
+        })
+        .ok()
+        .flatten()
+}
+
 fn refresh_layout_box_data(
     applier: &mut MemoryApplier,
     layout: &mut cranpose_ui::layout::LayoutBox,
@@ -2,13 +2,17 @@ use super::*;
 use cranpose_core::{
     __launched_effect_async_impl as launched_effect_async_impl, location_key, useState,
 };
+use cranpose_foundation::text::TextFieldState;
 use cranpose_macros::composable;
+use cranpose_render_common::scene_hash::{hash_primitive, hash_rect};
 use cranpose_ui::{
-    Box, BoxSpec, Brush, Color, Column, ColumnSpec, HeadlessRenderer, Modifier, Rect, RenderOp,
-    Row, RowSpec, Size, Text,
+    BasicTextField, Box, BoxSpec, Brush, Color, Column, ColumnSpec, Dialog, HeadlessRenderer,
+    Modifier, Rect, RenderOp, Row, RowSpec, Size, Text,
 };
+use cranpose_ui::{KeyCode, KeyEvent, KeyEventType, Modifiers};
 use cranpose_ui_graphics::DrawPrimitive;
 use std::cell::{Cell, RefCell};
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
 #[derive(Default, Clone)]
@@ -74,14 +78,56 @@ impl Renderer for TestRenderer {
     }
 }
 
+/// Scene that hashes the operations recorded by the headless renderer.
+#[derive(Default)]
+struct RecordingScene {
+    content_hash: Option<u64>,
+}
+
+impl RecordingScene {
+    fn record(&mut self, recorded: &cranpose_ui::RecordedRenderScene) {
+        let mut state = std::collections::hash_map::DefaultHasher::new();
+        for op in recorded.operations() {
+            match op {
+                RenderOp::Primitive { primitive, .. } => hash_primitive(&mut state, primitive),
+                RenderOp::Text { rect, value, .. } => {
+                    hash_rect(&mut state, *rect);
+                    value.hash(&mut state);
+                }
+            }
+        }
+        self.content_hash = Some(state.finish());
+    }
+}
+
+impl RenderScene for RecordingScene {
+    type HitTarget = TestHitTarget;
+
+    fn clear(&mut self) {
+        self.content_hash = None;
+    }
+
+    fn hit_test(&self, _x: f32, _y: f32) -> Vec<Self::HitTarget> {
+        vec![]
+    }
+
+    fn find_target(&self, _node_id: cranpose_core::NodeId) -> Option<Self::HitTarget> {
+        None
+    }
+
+    fn content_hash(&self) -> Option<u64> {
+        self.content_hash
+    }
+}
+
 #[derive(Default)]
 struct RecordingRenderer {
-    scene: TestScene,
+    scene: RecordingScene,
     last_scene: Option<cranpose_ui::RecordedRenderScene>,
 }
 
 impl Renderer for RecordingRenderer {
-    type Scene = TestScene;
+    type Scene = RecordingScene;
     type Error = ();
 
     fn scene(&self) -> &Self::Scene {
@@ -98,7 +144,9 @@ impl Renderer for RecordingRenderer {
         _viewport: Size,
     ) -> Result<(), Self::Error> {
         let renderer = HeadlessRenderer::new();
-        self.last_scene = Some(renderer.render(layout_tree));
+        let recorded = renderer.render(layout_tree);
+        self.scene.record(&recorded);
+        self.last_scene = Some(recorded);
         Ok(())
     }
 
@@ -109,7 +157,9 @@ impl Renderer for RecordingRenderer {
         _viewport: Size,
     ) -> Result<(), Self::Error> {
         let renderer = HeadlessRenderer::new();
-        self.last_scene = Some(renderer.render_from_applier(applier, root));
+        let recorded = renderer.render_from_applier(applier, root);
+        self.scene.record(&recorded);
+        self.last_scene = Some(recorded);
         Ok(())
     }
 }
@@ -217,6 +267,20 @@ fn draw_width_app(width_state: cranpose_core::MutableState<f32>) {
     );
 }
 
+#[composable]
+fn color_box_app(color_state: cranpose_core::MutableState<Color>) {
+    Box(
+        Modifier::empty()
+            .size(Size {
+                width: 40.0,
+                height: 40.0,
+            })
+            .background(color_state.value()),
+        BoxSpec::default(),
+        || {},
+    );
+}
+
 struct DeleteSurroundingHandler {
     last_delete: Cell<Option<(usize, usize)>>,
 }
@@ -328,6 +392,80 @@ fn draw_repass_updates_render_data_without_layout() {
     );
 }
 
+#[test]
+fn identical_rebuild_skips_present_until_a_color_changes() {
+    let root_key = location_key(file!(), line!(), column!());
+    let state_holder: Rc<RefCell<Option<cranpose_core::MutableState<Color>>>> =
+        Rc::new(RefCell::new(None));
+    let state_holder_for_app = Rc::clone(&state_holder);
+
+    let mut shell = AppShell::new(RecordingRenderer::default(), root_key, move || {
+        let color_state = useState(|| Color(0.2, 0.4, 0.8, 1.0));
+        *state_holder_for_app.borrow_mut() = Some(color_state);
+        color_box_app(color_state);
+    });
+    shell.update();
+    assert!(shell.needs_present(), "first frame must be presented");
+    shell.mark_presented();
+    let first_hash = shell.scene().content_hash();
+
+    // Rebuild the same content: the scene hash is unchanged, so nothing to present
+    shell.scene_dirty = true;
+    shell.run_render_phase();
+    assert_eq!(shell.scene().content_hash(), first_hash);
+    assert!(!shell.needs_present());
+
+    let color_state = state_holder
+        .borrow()
+        .as_ref()
+        .copied()
+        .expect("color state should be captured");
+    color_state.set(Color(0.9, 0.1, 0.1, 1.0));
+    shell.update();
+    assert_ne!(shell.scene().content_hash(), first_hash);
+    assert!(shell.needs_present(), "changed color must be presented");
+
+    shell.mark_presented();
+    shell.set_buffer_size(1024, 768);
+    assert!(shell.needs_present(), "resized surface must be presented");
+}
+
+#[test]
+fn resize_updates_viewport_and_presents_relaid_out_content() {
+    let root_key = location_key(file!(), line!(), column!());
+    let color = Color(0.2, 0.4, 0.8, 1.0);
+    let mut shell = AppShell::new(RecordingRenderer::default(), root_key, move || {
+        Box(
+            Modifier::empty().fill_max_size().background(color),
+            BoxSpec::default(),
+            || {},
+        );
+    });
+    shell.set_buffer_size(800, 600);
+    shell.set_viewport(400.0, 300.0);
+    shell.update();
+    shell.mark_presented();
+    let scene = shell
+        .renderer
+        .last_scene
+        .as_ref()
+        .expect("expected render scene");
+    assert_eq!(find_rect_width(scene, color), Some(400.0));
+
+    shell.set_buffer_size(1280, 960);
+    shell.set_viewport(640.0, 480.0);
+    assert_eq!(shell.viewport(), (640.0, 480.0));
+    assert!(shell.needs_present(), "resized surface must be presented");
+
+    shell.update();
+    let scene = shell
+        .renderer
+        .last_scene
+        .as_ref()
+        .expect("expected render scene");
+    assert_eq!(find_rect_width(scene, color), Some(640.0));
+}
+
 fn find_rect_width(scene: &cranpose_ui::RecordedRenderScene, color: Color) -> Option<f32> {
     for op in scene.operations() {
         if let RenderOp::Primitive {
@@ -342,3 +480,651 @@ fn find_rect_width(scene: &cranpose_ui::RecordedRenderScene, color: Color) -> Op
     }
     None
 }
+
+#[test]
+fn tab_key_moves_focus_in_reading_order_and_wraps() {
+    cranpose_ui::set_active_focus_target(None);
+    let root_key = location_key(file!(), line!(), column!());
+    let focused: Rc<RefCell<Vec<&'static str>>> = Rc::new(RefCell::new(Vec::new()));
+    let focused_for_app = Rc::clone(&focused);
+    let mut shell = AppShell::new(TestRenderer::default(), root_key, move || {
+        let focused = Rc::clone(&focused_for_app);
+        Column(Modifier::empty(), ColumnSpec::default(), move || {
+            for name in ["top", "middle", "bottom"] {
+                let focused = Rc::clone(&focused);
+                Text(
+                    name.to_string(),
+                    Modifier::empty()
+                        .focusable()
+                        .on_focus_changed(move |state| {
+                            if state.is_focused() {
+                                focused.borrow_mut().push(name);
+                            }
+                        }),
+                );
+            }
+        });
+    });
+    shell.update();
+
+    let tab = KeyEvent::key_down(KeyCode::Tab, "\t");
+    for _ in 0..4 {
+        assert!(shell.on_key_event(&tab));
+    }
+    let shift_tab = KeyEvent::key_down_with_modifiers(
+        KeyCode::Tab,
+        "\t",
+        Modifiers {
+            shift: true,
+            ..Modifiers::NONE
+        },
+    );
+    assert!(shell.on_key_event(&shift_tab));
+
+    assert_eq!(
+        *focused.borrow(),
+        vec!["top", "middle", "bottom", "top", "bottom"]
+    );
+    cranpose_ui::set_active_focus_target(None);
+}
+
+type PointerLog = Rc<RefCell<Vec<(PointerEventKind, Point)>>>;
+
+/// Hit target that records every event dispatched to it.
+#[derive(Clone)]
+struct LoggingHitTarget {
+    log: PointerLog,
+}
+
+impl HitTestTarget for LoggingHitTarget {
+    fn dispatch(&self, event: PointerEvent) {
+        self.log.borrow_mut().push((event.kind, event.position));
+    }
+
+    fn node_id(&self) -> cranpose_core::NodeId {
+        0
+    }
+}
+
+/// Scene with a single logging target that covers `region`, or every point
+/// when no region is set.
+#[derive(Default)]
+struct LoggingScene {
+    log: PointerLog,
+    region: Option<Rect>,
+}
+
+impl RenderScene for LoggingScene {
+    type HitTarget = LoggingHitTarget;
+
+    fn clear(&mut self) {}
+
+    fn hit_test(&self, x: f32, y: f32) -> Vec<Self::HitTarget> {
+        if self.region.is_some_and(|region| !region.contains(x, y)) {
+            return Vec::new();
+        }
+        vec![LoggingHitTarget {
+            log: Rc::clone(&self.log),
+        }]
+    }
+
+    fn find_target(&self, _node_id: cranpose_core::NodeId) -> Option<Self::HitTarget> {
+        Some(LoggingHitTarget {
+            log: Rc::clone(&self.log),
+        })
+    }
+}
+
+#[derive(Default)]
+struct LoggingRenderer {
+    scene: LoggingScene,
+}
+
+impl Renderer for LoggingRenderer {
+    type Scene = LoggingScene;
+    type Error = ();
+
+    fn scene(&self) -> &Self::Scene {
+        &self.scene
+    }
+
+    fn scene_mut(&mut self) -> &mut Self::Scene {
+        &mut self.scene
+    }
+
+    fn rebuild_scene(
+        &mut self,
+        _layout_tree: &LayoutTree,
+        _viewport: Size,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn rebuild_scene_from_applier(
+        &mut self,
+        _applier: &mut cranpose_core::MemoryApplier,
+        _root: cranpose_core::NodeId,
+        _viewport: Size,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn coalesced_moves_dispatch_latest_position_before_release() {
+    let root_key = location_key(file!(), line!(), column!());
+    let renderer = LoggingRenderer::default();
+    let log = Rc::clone(&renderer.scene.log);
+    let mut shell = AppShell::new(renderer, root_key, empty_content);
+    shell.set_coalesce_pointer_moves(true);
+    shell.pointer_pressed();
+    log.borrow_mut().clear();
+
+    for step in 1..=10 {
+        assert!(shell.set_cursor(step as f32, 2.0 * step as f32));
+    }
+    assert!(log.borrow().is_empty());
+    shell.pointer_released();
+
+    assert_eq!(
+        *log.borrow(),
+        vec![
+            (PointerEventKind::Move, Point { x: 10.0, y: 20.0 }),
+            (PointerEventKind::Up, Point { x: 10.0, y: 20.0 }),
+        ]
+    );
+}
+
+#[test]
+fn coalesced_move_is_dispatched_on_update() {
+    let root_key = location_key(file!(), line!(), column!());
+    let renderer = LoggingRenderer::default();
+    let log = Rc::clone(&renderer.scene.log);
+    let mut shell = AppShell::new(renderer, root_key, empty_content);
+    shell.set_coalesce_pointer_moves(true);
+
+    shell.set_cursor(3.0, 4.0);
+    shell.set_cursor(5.0, 6.0);
+    shell.update();
+
+    assert_eq!(
+        *log.borrow(),
+        vec![
+            (PointerEventKind::Enter, Point { x: 5.0, y: 6.0 }),
+            (PointerEventKind::Move, Point { x: 5.0, y: 6.0 }),
+        ]
+    );
+}
+
+#[test]
+fn hover_moves_dispatch_enter_and_exit_once() {
+    let root_key = location_key(file!(), line!(), column!());
+    let mut renderer = LoggingRenderer::default();
+    renderer.scene.region = Some(Rect {
+        x: 10.0,
+        y: 10.0,
+        width: 20.0,
+        height: 20.0,
+    });
+    let log = Rc::clone(&renderer.scene.log);
+    let mut shell = AppShell::new(renderer, root_key, empty_content);
+
+    for (x, y) in [
+        (0.0, 0.0),
+        (15.0, 15.0),
+        (20.0, 20.0),
+        (40.0, 40.0),
+        (50.0, 50.0),
+    ] {
+        shell.set_cursor(x, y);
+    }
+
+    let kinds: Vec<PointerEventKind> = log.borrow().iter().map(|(kind, _)| *kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            PointerEventKind::Enter,
+            PointerEventKind::Move,
+            PointerEventKind::Move,
+            PointerEventKind::Exit,
+        ]
+    );
+    assert_eq!(log.borrow()[3].1, Point { x: 40.0, y: 40.0 });
+}
+
+/// Hit target for a layout node with pointer input.
+#[derive(Clone)]
+struct NodeHitTarget {
+    node_id: cranpose_core::NodeId,
+    rect: Rect,
+    handlers: Vec<Rc<dyn Fn(PointerEvent)>>,
+}
+
+impl HitTestTarget for NodeHitTarget {
+    fn dispatch(&self, event: PointerEvent) {
+        let local = event
+            .copy_with_local_position(Point {
+                x: event.global_position.x - self.rect.x,
+                y: event.global_position.y - self.rect.y,
+            })
+            .with_target_size(Size {
+                width: self.rect.width,
+                height: self.rect.height,
+            });
+        for handler in &self.handlers {
+            if local.is_consumed() {
+                break;
+            }
+            handler(local.clone());
+        }
+    }
+
+    fn node_id(&self) -> cranpose_core::NodeId {
+        self.node_id
+    }
+}
+
+/// Scene that hit-tests the laid out nodes carrying pointer input, topmost
+/// first.
+#[derive(Default)]
+struct NodeHitScene {
+    regions: Vec<NodeHitTarget>,
+}
+
+impl NodeHitScene {
+    fn collect(
+        &mut self,
+        applier: &mut cranpose_core::MemoryApplier,
+        node_id: cranpose_core::NodeId,
+        parent_offset: Point,
+    ) {
+        let Ok((state, handlers, children)) = applier.with_node::<LayoutNode, _>(node_id, |node| {
+            let handlers = node.modifier_slices_snapshot().pointer_inputs().to_vec();
+            let children: Vec<_> = node.children.iter().copied().collect();
+            (node.layout_state(), handlers, children)
+        }) else {
+            return;
+        };
+        let x = parent_offset.x + state.position.x;
+        let y = parent_offset.y + state.position.y;
+        if !handlers.is_empty() {
+            self.regions.push(NodeHitTarget {
+                node_id,
+                rect: Rect {
+                    x,
+                    y,
+                    width: state.size.width,
+                    height: state.size.height,
+                },
+                handlers,
+            });
+        }
+        let child_offset = Point {
+            x: x + state.content_offset.x,
+            y: y + state.content_offset.y,
+        };
+        for child in children {
+            self.collect(applier, child, child_offset);
+        }
+    }
+}
+
+impl RenderScene for NodeHitScene {
+    type HitTarget = NodeHitTarget;
+
+    fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    fn hit_test(&self, x: f32, y: f32) -> Vec<Self::HitTarget> {
+        self.regions
+            .iter()
+            .rev()
+            .filter(|target| target.rect.contains(x, y))
+            .cloned()
+            .collect()
+    }
+
+    fn find_target(&self, node_id: cranpose_core::NodeId) -> Option<Self::HitTarget> {
+        self.regions
+            .iter()
+            .find(|target| target.node_id == node_id)
+            .cloned()
+    }
+}
+
+#[derive(Default)]
+struct NodeHitRenderer {
+    scene: NodeHitScene,
+}
+
+impl Renderer for NodeHitRenderer {
+    type Scene = NodeHitScene;
+    type Error = ();
+
+    fn scene(&self) -> &Self::Scene {
+        &self.scene
+    }
+
+    fn scene_mut(&mut self) -> &mut Self::Scene {
+        &mut self.scene
+    }
+
+    fn rebuild_scene(
+        &mut self,
+        _layout_tree: &LayoutTree,
+        _viewport: Size,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn rebuild_scene_from_applier(
+        &mut self,
+        applier: &mut cranpose_core::MemoryApplier,
+        root: cranpose_core::NodeId,
+        _viewport: Size,
+    ) -> Result<(), Self::Error> {
+        self.scene.clear();
+        self.scene.collect(applier, root, Point::default());
+        Ok(())
+    }
+}
+
+#[composable]
+fn nested_cursor_app() {
+    Box(
+        Modifier::empty()
+            .size(Size {
+                width: 100.0,
+                height: 100.0,
+            })
+            .pointer_cursor(CursorIcon::Text),
+        BoxSpec::default(),
+        || {
+            Box(
+                Modifier::empty()
+                    .size(Size {
+                        width: 40.0,
+                        height: 40.0,
+                    })
+                    .pointer_cursor(CursorIcon::Pointer),
+                BoxSpec::default(),
+                || {},
+            );
+        },
+    );
+}
+
+#[test]
+fn topmost_hovered_element_sets_the_cursor() {
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(NodeHitRenderer::default(), root_key, nested_cursor_app);
+    shell.update();
+
+    shell.set_cursor(10.0, 10.0);
+    assert_eq!(shell.cursor_icon(), CursorIcon::Pointer);
+
+    shell.set_cursor(70.0, 70.0);
+    assert_eq!(shell.cursor_icon(), CursorIcon::Text);
+
+    shell.set_cursor(150.0, 150.0);
+    assert_eq!(shell.cursor_icon(), CursorIcon::Default);
+}
+
+#[composable]
+fn dialog_app(
+    show_dialog: cranpose_core::MutableState<bool>,
+    dismissals: Rc<Cell<u32>>,
+    behind_clicks: Rc<Cell<u32>>,
+) {
+    Box(
+        Modifier::empty()
+            .fill_max_size()
+            .clickable(move |_| behind_clicks.set(behind_clicks.get() + 1)),
+        BoxSpec::default(),
+        move || {
+            if show_dialog.get() {
+                let dismissals = Rc::clone(&dismissals);
+                Dialog(
+                    move || dismissals.set(dismissals.get() + 1),
+                    || {
+                        Box(
+                            Modifier::empty()
+                                .size(Size {
+                                    width: 200.0,
+                                    height: 100.0,
+                                })
+                                .background(Color(1.0, 1.0, 1.0, 1.0)),
+                            BoxSpec::default(),
+                            || {},
+                        );
+                    },
+                );
+            }
+        },
+    );
+}
+
+fn click_at(shell: &mut AppShell<NodeHitRenderer>, x: f32, y: f32) {
+    shell.set_cursor(x, y);
+    shell.pointer_pressed();
+    shell.pointer_released();
+    shell.update();
+}
+
+#[test]
+fn dialog_scrim_click_dismisses_and_blocks_content_behind() {
+    let root_key = location_key(file!(), line!(), column!());
+    let dismissals = Rc::new(Cell::new(0));
+    let behind_clicks = Rc::new(Cell::new(0));
+    let state_holder: Rc<RefCell<Option<cranpose_core::MutableState<bool>>>> =
+        Rc::new(RefCell::new(None));
+    let app_dismissals = Rc::clone(&dismissals);
+    let app_behind_clicks = Rc::clone(&behind_clicks);
+    let state_holder_for_app = Rc::clone(&state_holder);
+    let mut shell = AppShell::new(NodeHitRenderer::default(), root_key, move || {
+        let show_dialog = useState(|| true);
+        *state_holder_for_app.borrow_mut() = Some(show_dialog);
+        dialog_app(
+            show_dialog,
+            Rc::clone(&app_dismissals),
+            Rc::clone(&app_behind_clicks),
+        );
+    });
+    shell.update();
+
+    // The 200x100 content is centered in the 800x600 window
+    click_at(&mut shell, 400.0, 300.0);
+    assert_eq!(dismissals.get(), 0, "clicks on the content keep it open");
+
+    click_at(&mut shell, 50.0, 50.0);
+    assert_eq!(dismissals.get(), 1, "clicks on the scrim dismiss");
+    assert_eq!(behind_clicks.get(), 0, "the scrim blocks the app behind");
+
+    assert!(shell.on_key_event(&KeyEvent::new(
+        KeyCode::Escape,
+        String::new(),
+        Modifiers::default(),
+        KeyEventType::KeyDown,
+    )));
+    assert_eq!(dismissals.get(), 2, "Escape dismisses");
+
+    let show_dialog = state_holder
+        .borrow()
+        .as_ref()
+        .copied()
+        .expect("dialog state should be captured");
+    show_dialog.set(false);
+    shell.update();
+    click_at(&mut shell, 50.0, 50.0);
+    assert_eq!(
+        behind_clicks.get(),
+        1,
+        "a closing dialog lets clicks through"
+    );
+}
+
+#[composable]
+fn focusable_button_app() {
+    Box(
+        Modifier::empty()
+            .size(Size {
+                width: 100.0,
+                height: 40.0,
+            })
+            .rounded_corners(8.0)
+            .clickable(|_| {})
+            .focusable(),
+        BoxSpec::default(),
+        || {},
+    );
+}
+
+/// Focus ring strokes drawn for `node_id`: one arc per rounded corner.
+fn focus_ring_arcs(shell: &mut AppShell<NodeHitRenderer>, node_id: NodeId) -> usize {
+    let slices = shell
+        .composition
+        .applier_mut()
+        .with_node::<LayoutNode, _>(node_id, |node| node.modifier_slices_snapshot())
+        .expect("focusable node");
+    let size = Size {
+        width: 100.0,
+        height: 40.0,
+    };
+    slices
+        .draw_commands()
+        .iter()
+        .flat_map(|command| match command {
+            cranpose_ui::DrawCommand::Behind(draw)
+            | cranpose_ui::DrawCommand::Overlay(draw)
+            | cranpose_ui::DrawCommand::OverlayClipped(draw, _) => draw(size),
+        })
+        .filter(|primitive| matches!(primitive, DrawPrimitive::Arc { .. }))
+        .count()
+}
+
+#[test]
+fn focus_ring_shows_for_programmatic_focus_but_not_pointer_clicks() {
+    cranpose_ui::set_active_focus_target(None);
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(NodeHitRenderer::default(), root_key, focusable_button_app);
+    shell.update();
+    let root = shell.composition.root().expect("root");
+    let button = cranpose_ui::focus_traversal_order(&mut shell.composition.applier_mut(), root)[0];
+    assert_eq!(focus_ring_arcs(&mut shell, button), 0, "unfocused");
+
+    assert!(cranpose_ui::request_focus(
+        &mut shell.composition.applier_mut(),
+        button
+    ));
+    assert_eq!(
+        focus_ring_arcs(&mut shell, button),
+        4,
+        "the ring follows all four rounded corners"
+    );
+
+    cranpose_ui::clear_focus(&mut shell.composition.applier_mut());
+    click_at(&mut shell, 50.0, 20.0);
+    assert_eq!(cranpose_ui::active_focus_target(), Some(button));
+    assert_eq!(
+        focus_ring_arcs(&mut shell, button),
+        0,
+        "pointer focus draws no ring"
+    );
+    cranpose_ui::set_active_focus_target(None);
+}
+
+#[derive(Default)]
+struct MockIme {
+    shown: Cell<usize>,
+    hidden: Cell<usize>,
+}
+
+impl cranpose_ui::ImeConnection for MockIme {
+    fn show_keyboard(&self) {
+        self.shown.set(self.shown.get() + 1);
+    }
+
+    fn hide_keyboard(&self) {
+        self.hidden.set(self.hidden.get() + 1);
+    }
+}
+
+#[test]
+fn focusing_a_text_field_shows_the_keyboard_and_ime_commits_reach_its_state() {
+    let ime = Rc::new(MockIme::default());
+    cranpose_ui::set_platform_ime(ime.clone());
+    let state_holder: Rc<RefCell<Option<TextFieldState>>> = Rc::new(RefCell::new(None));
+    let state_holder_for_app = Rc::clone(&state_holder);
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(NodeHitRenderer::default(), root_key, move || {
+        let state = cranpose_core::remember(|| TextFieldState::new("")).with(|state| state.clone());
+        *state_holder_for_app.borrow_mut() = Some(state.clone());
+        BasicTextField(
+            state,
+            Modifier::empty().size(Size {
+                width: 200.0,
+                height: 40.0,
+            }),
+        );
+    });
+    shell.update();
+    let state = state_holder.borrow().clone().expect("text field composed");
+
+    click_at(&mut shell, 20.0, 20.0);
+    assert_eq!(ime.shown.get(), 1);
+
+    assert!(shell.on_ime_event(&ImeEvent::Preedit {
+        text: "h".into(),
+        cursor: Some((1, 1)),
+    }));
+    assert_eq!(state.text(), "h");
+    assert!(shell.on_ime_event(&ImeEvent::Commit("hé".into())));
+    assert_eq!(state.text(), "hé");
+
+    cranpose_ui::text_field_focus::clear_focus();
+    assert_eq!(ime.hidden.get(), 1);
+}
+
+#[composable]
+fn side_by_side_clickables(left_clicks: Rc<Cell<u32>>, right_clicks: Rc<Cell<u32>>) {
+    Row(Modifier::empty(), RowSpec::default(), move || {
+        for clicks in [Rc::clone(&left_clicks), Rc::clone(&right_clicks)] {
+            Box(
+                Modifier::empty()
+                    .size(Size {
+                        width: 100.0,
+                        height: 100.0,
+                    })
+                    .clickable(move |_| clicks.set(clicks.get() + 1)),
+                BoxSpec::default(),
+                || {},
+            );
+        }
+    });
+}
+
+#[test]
+fn clicks_hit_the_element_at_the_same_logical_position_under_density_two() {
+    cranpose_ui::set_density(2.0);
+    let left_clicks = Rc::new(Cell::new(0));
+    let right_clicks = Rc::new(Cell::new(0));
+    let (app_left, app_right) = (Rc::clone(&left_clicks), Rc::clone(&right_clicks));
+    let root_key = location_key(file!(), line!(), column!());
+    let mut shell = AppShell::new(NodeHitRenderer::default(), root_key, move || {
+        side_by_side_clickables(Rc::clone(&app_left), Rc::clone(&app_right));
+    });
+    shell.set_viewport(400.0, 300.0);
+    shell.set_buffer_size(800, 600);
+    shell.update();
+
+    // The boxes span 0..100 and 100..200 logical px, drawn at twice that
+    click_at(&mut shell, 150.0, 50.0);
+    assert_eq!((left_clicks.get(), right_clicks.get()), (0, 1));
+    click_at(&mut shell, 50.0, 50.0);
+    assert_eq!((left_clicks.get(), right_clicks.get()), (1, 1));
+    click_at(&mut shell, 250.0, 50.0);
+    assert_eq!((left_clicks.get(), right_clicks.get()), (1, 1));
+
+    cranpose_ui::set_density(1.0);
+}
@@ -5,9 +5,13 @@ pub extern crate self as cranpose_core;
 pub mod composer_context;
 pub mod frame_clock;
 mod launched_effect;
+mod movable_content;
+mod observe_state;
 pub mod owned;
 pub mod platform;
+mod produce_state;
 pub mod runtime;
+mod saveable;
 pub mod snapshot_double_index_heap;
 pub mod snapshot_id_set;
 pub mod snapshot_pinning;
@@ -21,12 +25,20 @@ pub use frame_clock::{FrameCallbackRegistration, FrameClock};
 pub use launched_effect::{
     CancelToken, LaunchedEffectScope, __launched_effect_async_impl, __launched_effect_impl,
 };
+pub use movable_content::{movableContentOf, movable_content_of, MovableContent};
+pub use observe_state::__observe_as_state_impl;
 pub use owned::Owned;
 pub use platform::{Clock, RuntimeScheduler};
+pub use produce_state::{ProduceStateScope, __produce_state_impl};
 pub use runtime::{
     current_runtime_handle, schedule_frame, schedule_node_update, DefaultScheduler, Runtime,
     RuntimeHandle, StateId, TaskHandle,
 };
+pub use saveable::{
+    consume_restored, local_saveable_state_registry, rememberSaveable, remember_save_provider,
+    remember_saveable, remember_saveable_with_saver, AutoSaver, SaveableStateEntry,
+    SaveableStateRegistry, Saver,
+};
 pub use snapshot_state_observer::SnapshotStateObserver;
 
 /// Runs the provided closure inside a mutable snapshot and applies the result.
@@ -205,6 +217,8 @@ pub(crate) struct RecomposeScopeInner {
     force_reuse: Cell<bool>,
     force_recompose: Cell<bool>,
     parent_hint: Cell<Option<NodeId>>,
+    /// Slot table of the movable content the scope was composed in, if any.
+    slots_host: RefCell<Option<Weak<SlotsHost>>>,
     recompose: RefCell<Option<RecomposeCallback>>,
     local_stack: RefCell<Vec<LocalContext>>,
 }
@@ -221,6 +235,7 @@ impl RecomposeScopeInner {
             force_reuse: Cell::new(false),
             force_recompose: Cell::new(false),
             parent_hint: Cell::new(None),
+            slots_host: RefCell::new(None),
             recompose: RefCell::new(None),
             local_stack: RefCell::new(Vec::new()),
         }
@@ -298,11 +313,15 @@ impl RecomposeScope {
         *self.inner.recompose.borrow_mut() = Some(callback);
     }
 
-    fn run_recompose(&self, composer: &Composer) {
+    /// Runs the scope's recompose callback; returns false if it has none.
+    fn run_recompose(&self, composer: &Composer) -> bool {
         let mut callback_cell = self.inner.recompose.borrow_mut();
         if let Some(mut callback) = callback_cell.take() {
             drop(callback_cell);
             callback(composer);
+            true
+        } else {
+            false
         }
     }
 
@@ -322,6 +341,18 @@ impl RecomposeScope {
         self.inner.parent_hint.get()
     }
 
+    fn set_slots_host(&self, host: Option<&Rc<SlotsHost>>) {
+        *self.inner.slots_host.borrow_mut() = host.map(Rc::downgrade);
+    }
+
+    fn slots_host(&self) -> Option<Rc<SlotsHost>> {
+        self.inner
+            .slots_host
+            .borrow()
+            .as_ref()
+            .and_then(Weak::upgrade)
+    }
+
     pub fn deactivate(&self) {
         if !self.inner.active.replace(false) {
             return;
@@ -407,7 +438,8 @@ impl std::fmt::Display for NodeError {
 impl std::error::Error for NodeError {}
 
 pub use subcompose::{
-    ContentTypeReusePolicy, DefaultSlotReusePolicy, SlotId, SlotReusePolicy, SubcomposeState,
+    ContentTypeReusePolicy, DefaultSlotReusePolicy, SlotId, SlotReuseLimits, SlotReusePolicy,
+    SubcomposeState,
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -428,6 +460,13 @@ fn with_current_composer_opt<R>(f: impl FnOnce(&Composer) -> R) -> Option<R> {
     composer_context::try_with_composer(f)
 }
 
+/// Composes `content` in a group identified by `key` instead of by call position.
+///
+/// Use it for children emitted in a loop: when items are inserted, removed or
+/// reordered, each child keeps its remembered state and nodes, and a child whose
+/// parameters didn't change is skipped on recomposition.
+///
+/// Matches Kotlin: `key(id) { content() }`
 pub fn with_key<K: Hash>(key: &K, content: impl FnOnce()) {
     with_current_composer(|composer| composer.with_key(key, |_| content()));
 }
@@ -745,6 +784,13 @@ impl<T: Clone + 'static> CompositionLocal<T> {
         with_current_composer(|composer| composer.read_composition_local(self))
     }
 
+    /// Like [`current`](Self::current), but returns the default value instead of
+    /// panicking when called outside a composition.
+    pub fn current_or_default(&self) -> T {
+        with_current_composer_opt(|composer| composer.read_composition_local(self))
+            .unwrap_or_else(|| self.default_value())
+    }
+
     pub fn default_value(&self) -> T {
         (self.default)()
     }
@@ -1584,6 +1630,7 @@ impl SlotsHost {
 pub(crate) struct ComposerCore {
     slots: Rc<SlotsHost>,
     slots_override: RefCell<Vec<Rc<SlotsHost>>>,
+    movable_slots: RefCell<Vec<Rc<SlotsHost>>>,
     applier: Rc<dyn ApplierHost>,
     runtime: RuntimeHandle,
     observer: SnapshotStateObserver,
@@ -1628,6 +1675,7 @@ impl ComposerCore {
         Self {
             slots,
             slots_override: RefCell::new(Vec::new()),
+            movable_slots: RefCell::new(Vec::new()),
             applier,
             runtime,
             observer,
@@ -1874,6 +1922,15 @@ impl Composer {
             let parent_hint = self.parent_stack().last().map(|frame| frame.id);
             scope_ref.set_parent_hint(parent_hint);
         }
+        {
+            // Only scopes composed directly into movable content belong to its slot
+            // table; subcompositions nested in it have their own
+            let host = self
+                .in_movable_slots()
+                .then(|| self.core.movable_slots.borrow().last().cloned())
+                .flatten();
+            scope_ref.set_slots_host(host.as_ref());
+        }
 
         let result = self.observe_scope(&scope_ref, || f(self));
 
@@ -2195,14 +2252,20 @@ impl Composer {
             stack.last().map(|frame| frame.id)
         };
 
+        // Skipped movable content may have moved since its nodes were attached;
+        // its top-level nodes still point at the old parent, outside the group
+        let moved_parent_allowed = current_parent.is_some() && self.in_movable_slots();
+
         // Only attach nodes whose parent matches the current parent in the stack.
         // This ensures we only attach direct children of the current parent,
         // not nested nodes that belong to other nodes within the skipped group.
         let mut applier = self.borrow_applier();
-        for id in nodes {
+        for &id in &nodes {
             if let Ok(node) = applier.get_mut(id) {
                 let node_parent = node.parent();
-                if node_parent.is_none() || node_parent == current_parent {
+                let moved = moved_parent_allowed
+                    && node_parent.is_some_and(|parent| !nodes.contains(&parent));
+                if node_parent.is_none() || node_parent == current_parent || moved {
                     drop(applier);
                     self.attach_to_parent(id);
                     applier = self.borrow_applier();
@@ -2273,6 +2336,18 @@ impl Composer {
             scope.mark_recomposed();
             return;
         }
+        if let Some(host) = scope.slots_host() {
+            let already_active = self
+                .core
+                .movable_slots
+                .borrow()
+                .last()
+                .is_some_and(|active| Rc::ptr_eq(active, &host));
+            if !already_active {
+                self.with_movable_slots(host, |composer| composer.recranpose_group(scope));
+                return;
+            }
+        }
         let started = self.with_slots_mut(|slots| slots.begin_recranpose_at_scope(scope.id()));
         if started.is_some() {
             let previous_hint = self
@@ -2304,9 +2379,7 @@ impl Composer {
                 let mut locals = self.local_stack();
                 *locals = scope.local_stack();
             }
-            self.observe_scope(scope, || {
-                scope.run_recompose(self);
-            });
+            let recomposed = self.observe_scope(scope, || scope.run_recompose(self));
             {
                 let mut locals = self.local_stack();
                 *locals = saved_locals;
@@ -2315,6 +2388,11 @@ impl Composer {
                 let mut stack = self.scope_stack();
                 stack.pop();
             }
+            if recomposed {
+                // Drop what the scope composed last time but not this time, as
+                // composing its group from the parent would
+                self.with_slots_mut(|slots| slots.finalize_current_group());
+            }
             self.with_slots_mut(SlotStorage::end_recompose);
             scope.mark_recomposed();
         } else {
@@ -2848,7 +2926,7 @@ impl Composer {
 }
 
 #[derive(Default, Clone)]
-struct ParentChildren {
+pub(crate) struct ParentChildren {
     children: Vec<NodeId>,
 }
 
@@ -0,0 +1,99 @@
+//! Content that keeps its state when it moves to another place in the tree.
+//!
+//! Each [`MovableContent`] composes into its own slot table instead of the one
+//! of its caller, so its remembered values and nodes follow the content rather
+//! than the call position. Scopes inside it remember that slot table and are
+//! recomposed in it, wherever the content was last invoked.
+
+use crate::slot_backend::SlotBackend;
+use crate::{location_key, with_current_composer, Composer, SlotStorage, SlotTable, SlotsHost};
+use std::rc::Rc;
+
+/// Composable content whose state survives being moved between parents.
+///
+/// Create it once with [`movable_content_of`] inside `remember` and call
+/// [`invoke`](Self::invoke) wherever it should appear, at most once per pass.
+/// Moving the call keeps its remembered state and nodes; a pass that doesn't
+/// invoke it removes its nodes from the tree.
+///
+/// Matches Kotlin: `movableContentOf { content() }`
+#[derive(Clone)]
+pub struct MovableContent {
+    slots: Rc<SlotsHost>,
+    content: Rc<dyn Fn()>,
+}
+
+impl MovableContent {
+    /// Composes the content at the current position in the tree.
+    pub fn invoke(&self) {
+        with_current_composer(|composer| composer.compose_movable(&self.slots, &*self.content));
+    }
+}
+
+impl PartialEq for MovableContent {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.slots, &other.slots)
+    }
+}
+
+impl std::fmt::Debug for MovableContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MovableContent").finish_non_exhaustive()
+    }
+}
+
+pub fn movable_content_of(content: impl Fn() + 'static) -> MovableContent {
+    MovableContent {
+        slots: Rc::new(SlotsHost::new(SlotBackend::Baseline(SlotTable::new()))),
+        content: Rc::new(content),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn movableContentOf(content: impl Fn() + 'static) -> MovableContent {
+    movable_content_of(content)
+}
+
+impl Composer {
+    fn compose_movable(&self, slots: &Rc<SlotsHost>, content: &dyn Fn()) {
+        slots.borrow_mut().reset();
+        self.with_movable_slots(Rc::clone(slots), |composer| {
+            composer.with_group(location_key(file!(), line!(), column!()), |_| content());
+        });
+        let mut slots = slots.borrow_mut();
+        slots.finalize_current_group();
+        slots.flush();
+    }
+
+    /// Returns true when the active slot table is that of movable content.
+    pub(crate) fn in_movable_slots(&self) -> bool {
+        let active = self.active_slots_host();
+        self.core
+            .movable_slots
+            .borrow()
+            .last()
+            .is_some_and(|host| Rc::ptr_eq(host, &active))
+    }
+
+    /// Runs `f` with `slots` as the active slot table, tagging the scopes
+    /// created in it so they recompose there later.
+    pub(crate) fn with_movable_slots<R>(
+        &self,
+        slots: Rc<SlotsHost>,
+        f: impl FnOnce(&Composer) -> R,
+    ) -> R {
+        self.core.movable_slots.borrow_mut().push(Rc::clone(&slots));
+        struct Guard {
+            composer: Composer,
+        }
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                self.composer.core.movable_slots.borrow_mut().pop();
+            }
+        }
+        let _guard = Guard {
+            composer: self.clone(),
+        };
+        self.with_slot_override(slots, f)
+    }
+}
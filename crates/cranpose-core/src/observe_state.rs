@@ -0,0 +1,68 @@
+use crate::{
+    __disposable_effect_impl, location_key, with_current_composer, Key, MutableState, State,
+};
+use std::cell::Cell;
+use std::hash::Hash;
+use std::rc::Rc;
+
+pub fn __observe_as_state_impl<T, K, S, U>(
+    group_key: Key,
+    initial: T,
+    keys: K,
+    subscribe: S,
+) -> State<T>
+where
+    T: Clone + 'static,
+    K: Hash,
+    S: FnOnce(Rc<dyn Fn(T)>) -> U + 'static,
+    U: FnOnce() + 'static,
+{
+    with_current_composer(|composer| {
+        composer.with_group(group_key, |composer| {
+            let runtime = composer.runtime_handle();
+            let state = composer
+                .remember(|| MutableState::with_runtime(initial, runtime))
+                .with(|state| *state);
+            __disposable_effect_impl(
+                location_key(file!(), line!(), column!()),
+                keys,
+                move |scope| {
+                    // Stores may still emit while unsubscribing; drop those values
+                    let subscribed = Rc::new(Cell::new(true));
+                    let emit_subscribed = Rc::clone(&subscribed);
+                    let unsubscribe = subscribe(Rc::new(move |value| {
+                        if emit_subscribed.get() {
+                            state.set_value(value);
+                        }
+                    }));
+                    scope.on_dispose(move || {
+                        subscribed.set(false);
+                        unsubscribe();
+                    })
+                },
+            );
+            state.as_state()
+        })
+    })
+}
+
+/// Returns a state holding the latest value emitted by an external store.
+///
+/// `subscribe` is called after composition with an emitter to call with each
+/// new value, and returns the function that unsubscribes it. The store is
+/// unsubscribed when the caller leaves the composition, or before
+/// resubscribing when `keys` change. The state holds `initial` until the
+/// first emission.
+///
+/// Matches Kotlin: `LiveData.observeAsState(initial)` / `Flow.collectAsState(initial)`
+#[macro_export]
+macro_rules! observeAsState {
+    ($initial:expr, $keys:expr, $subscribe:expr) => {
+        $crate::__observe_as_state_impl(
+            $crate::location_key(file!(), line!(), column!()),
+            $initial,
+            $keys,
+            $subscribe,
+        )
+    };
+}
@@ -0,0 +1,118 @@
+use crate::launched_effect::{__launched_effect_impl, LaunchedEffectScope};
+use crate::{
+    location_key, with_current_composer, FrameCallbackRegistration, Key, MutableState, State,
+};
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// Scope handed to a [`produceState!`] producer.
+///
+/// Values pushed with [`set_value`](Self::set_value) land in the produced
+/// state, recomposing its readers. Once the producer is cancelled, because its
+/// keys changed or it left the composition, further values are dropped.
+#[derive(Clone)]
+pub struct ProduceStateScope<T: Clone + 'static> {
+    state: MutableState<T>,
+    effect: LaunchedEffectScope,
+    frame: Rc<RefCell<Option<FrameCallbackRegistration>>>,
+}
+
+impl<T: Clone + 'static> ProduceStateScope<T> {
+    /// Returns the current value without subscribing to it.
+    pub fn value(&self) -> T {
+        self.state.get_non_reactive()
+    }
+
+    /// Publishes `value` unless the producer has been cancelled.
+    pub fn set_value(&self, value: T) {
+        if self.is_active() {
+            self.state.set_value(value);
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.effect.is_active()
+    }
+
+    /// The underlying effect scope, e.g. for
+    /// [`launch_background`](LaunchedEffectScope::launch_background) loads.
+    pub fn effect_scope(&self) -> &LaunchedEffectScope {
+        &self.effect
+    }
+
+    /// Calls `poll` with each frame's time until it returns `false` or the
+    /// producer is cancelled.
+    pub fn poll_frames(&self, poll: impl FnMut(&Self, u64) -> bool + 'static) {
+        if self.is_active() {
+            self.schedule_poll(poll);
+        }
+    }
+
+    fn schedule_poll<P>(&self, mut poll: P)
+    where
+        P: FnMut(&Self, u64) -> bool + 'static,
+    {
+        let scope = self.clone();
+        let registration =
+            self.effect
+                .runtime()
+                .frame_clock()
+                .with_frame_nanos(move |frame_time_nanos| {
+                    if scope.is_active() && poll(&scope, frame_time_nanos) && scope.is_active() {
+                        scope.schedule_poll(poll);
+                    } else {
+                        scope.frame.borrow_mut().take();
+                    }
+                });
+        *self.frame.borrow_mut() = Some(registration);
+    }
+}
+
+pub fn __produce_state_impl<T, K, F>(group_key: Key, initial: T, keys: K, producer: F) -> State<T>
+where
+    T: Clone + 'static,
+    K: Hash,
+    F: FnOnce(ProduceStateScope<T>) + 'static,
+{
+    with_current_composer(|composer| {
+        composer.with_group(group_key, |composer| {
+            let runtime = composer.runtime_handle();
+            let state = composer
+                .remember(|| MutableState::with_runtime(initial, runtime))
+                .with(|state| *state);
+            __launched_effect_impl(
+                location_key(file!(), line!(), column!()),
+                keys,
+                move |effect| {
+                    producer(ProduceStateScope {
+                        state,
+                        effect,
+                        frame: Rc::new(RefCell::new(None)),
+                    })
+                },
+            );
+            state.as_state()
+        })
+    })
+}
+
+/// Returns a state whose value is pushed by `producer` over time.
+///
+/// The state starts at `initial`. The producer runs after composition and
+/// again whenever `keys` change, with the previous run cancelled; the value
+/// it last produced is kept. It can publish from frame polling
+/// ([`ProduceStateScope::poll_frames`]) or from background work.
+///
+/// Matches Kotlin: `produceState(initialValue, key) { ... }`
+#[macro_export]
+macro_rules! produceState {
+    ($initial:expr, $keys:expr, $producer:expr) => {
+        $crate::__produce_state_impl(
+            $crate::location_key(file!(), line!(), column!()),
+            $initial,
+            $keys,
+            $producer,
+        )
+    };
+}
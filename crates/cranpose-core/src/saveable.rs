@@ -0,0 +1,202 @@
+//! State that outlives the composition holding it.
+//!
+//! A [`SaveableStateRegistry`] provided through
+//! [`local_saveable_state_registry`] collects the current value of every
+//! [`remember_saveable`] call when [`SaveableStateRegistry::perform_save`] runs.
+//! Values are saved as strings so the host can persist them anywhere (a file,
+//! browser storage, an Android bundle) and hand them to a new registry when the
+//! UI is recreated, where each call picks its value back up by key.
+
+use crate::{compositionLocalOf, remember, useState, CompositionLocal, MutableState};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::str::FromStr;
+
+/// Converts values to and from their saved string form.
+///
+/// Matches Kotlin: `Saver<Original, Saveable>`
+pub trait Saver<T> {
+    /// Returns the saved form of `value`, or `None` to skip saving it.
+    fn save(&self, value: &T) -> Option<String>;
+
+    /// Rebuilds a value from its saved form, or `None` if it can't be read.
+    fn restore(&self, saved: &str) -> Option<T>;
+}
+
+/// Saves values through their `Display` and `FromStr` implementations.
+///
+/// Covers numbers, `bool`, `char` and `String`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AutoSaver;
+
+impl<T: Display + FromStr> Saver<T> for AutoSaver {
+    fn save(&self, value: &T) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    fn restore(&self, saved: &str) -> Option<T> {
+        saved.parse().ok()
+    }
+}
+
+type SaveProvider = Rc<dyn Fn() -> Option<String>>;
+
+#[derive(Default)]
+struct RegistryInner {
+    restored: HashMap<String, String>,
+    providers: HashMap<String, (u64, SaveProvider)>,
+    next_provider_id: u64,
+}
+
+/// Collects saveable values by key and hands restored ones back.
+///
+/// Cloning shares the registry.
+///
+/// Matches Kotlin: `SaveableStateRegistry`
+#[derive(Clone, Default)]
+pub struct SaveableStateRegistry {
+    inner: Rc<RefCell<RegistryInner>>,
+}
+
+impl SaveableStateRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry that restores values from an earlier
+    /// [`perform_save`](Self::perform_save).
+    pub fn with_restored(restored: HashMap<String, String>) -> Self {
+        let registry = Self::new();
+        registry.inner.borrow_mut().restored = restored;
+        registry
+    }
+
+    /// Takes the restored value for `key`; later calls with the same key get
+    /// `None`.
+    pub fn consume_restored(&self, key: &str) -> Option<String> {
+        self.inner.borrow_mut().restored.remove(key)
+    }
+
+    /// Registers `provider` to supply the value saved under `key`.
+    ///
+    /// The provider stays registered until the returned entry is dropped. A
+    /// later registration under the same key replaces it.
+    pub fn register_provider(
+        &self,
+        key: &str,
+        provider: impl Fn() -> Option<String> + 'static,
+    ) -> SaveableStateEntry {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_provider_id;
+        inner.next_provider_id += 1;
+        inner
+            .providers
+            .insert(key.to_string(), (id, Rc::new(provider)));
+        SaveableStateEntry {
+            registry: self.clone(),
+            key: key.to_string(),
+            id,
+        }
+    }
+
+    /// Returns the current value of every registered provider by key.
+    pub fn perform_save(&self) -> HashMap<String, String> {
+        let providers: Vec<(String, SaveProvider)> = self
+            .inner
+            .borrow()
+            .providers
+            .iter()
+            .map(|(key, (_, provider))| (key.clone(), Rc::clone(provider)))
+            .collect();
+        providers
+            .into_iter()
+            .filter_map(|(key, provider)| provider().map(|saved| (key, saved)))
+            .collect()
+    }
+}
+
+/// Registration returned by [`SaveableStateRegistry::register_provider`];
+/// dropping it unregisters the provider.
+pub struct SaveableStateEntry {
+    registry: SaveableStateRegistry,
+    key: String,
+    id: u64,
+}
+
+impl Drop for SaveableStateEntry {
+    fn drop(&mut self) {
+        let mut inner = self.registry.inner.borrow_mut();
+        if inner
+            .providers
+            .get(&self.key)
+            .is_some_and(|(id, _)| *id == self.id)
+        {
+            inner.providers.remove(&self.key);
+        }
+    }
+}
+
+thread_local! {
+    static LOCAL_SAVEABLE_STATE_REGISTRY: CompositionLocal<Option<SaveableStateRegistry>> =
+        compositionLocalOf(|| None);
+}
+
+/// Registry used by [`remember_saveable`]; without one, values are only
+/// remembered.
+pub fn local_saveable_state_registry() -> CompositionLocal<Option<SaveableStateRegistry>> {
+    LOCAL_SAVEABLE_STATE_REGISTRY.with(Clone::clone)
+}
+
+/// Takes the value restored for `key` from the nearest registry.
+///
+/// Building block for saveable state that isn't a single `MutableState`; call
+/// it once, from inside `remember`.
+pub fn consume_restored(key: &str) -> Option<String> {
+    local_saveable_state_registry()
+        .current_or_default()
+        .and_then(|registry| registry.consume_restored(key))
+}
+
+/// Registers `provider` with the nearest registry for as long as the calling
+/// composable stays in the composition.
+pub fn remember_save_provider(key: &str, provider: impl Fn() -> Option<String> + 'static) {
+    let registry = local_saveable_state_registry().current_or_default();
+    remember(|| registry.map(|registry| registry.register_provider(key, provider)));
+}
+
+/// Like [`useState`], but the value is saved under `key` and restored when
+/// the composition is recreated with the saved values.
+///
+/// Matches Kotlin: `rememberSaveable { mutableStateOf(init()) }`
+pub fn remember_saveable<T>(key: &str, init: impl FnOnce() -> T) -> MutableState<T>
+where
+    T: Clone + Display + FromStr + 'static,
+{
+    remember_saveable_with_saver(key, AutoSaver, init)
+}
+
+#[allow(non_snake_case)]
+pub fn rememberSaveable<T>(key: &str, init: impl FnOnce() -> T) -> MutableState<T>
+where
+    T: Clone + Display + FromStr + 'static,
+{
+    remember_saveable(key, init)
+}
+
+/// [`remember_saveable`] for types saved through a custom [`Saver`].
+pub fn remember_saveable_with_saver<T: Clone + 'static>(
+    key: &str,
+    saver: impl Saver<T> + 'static,
+    init: impl FnOnce() -> T,
+) -> MutableState<T> {
+    let saver = Rc::new(saver);
+    let state = useState(|| {
+        consume_restored(key)
+            .and_then(|saved| saver.restore(&saved))
+            .unwrap_or_else(init)
+    });
+    remember_save_provider(key, move || saver.save(&state.get_non_reactive()));
+    state
+}
@@ -237,6 +237,23 @@ impl SlotStorage for SlotBackend {
     }
 }
 
+impl SlotBackend {
+    /// Drops remembered values but keeps groups and nodes, so a later
+    /// composition recycles the nodes with fresh state.
+    ///
+    /// Returns false if the backend cannot recycle its nodes; callers should
+    /// then discard the storage instead.
+    pub fn forget_remembered_values(&mut self) -> bool {
+        match self {
+            Self::Baseline(s) => {
+                s.forget_remembered_values();
+                true
+            }
+            Self::Chunked(_) | Self::Hierarchical(_) | Self::Split(_) => false,
+        }
+    }
+}
+
 // Additional debug methods not in the SlotStorage trait
 impl SlotBackend {
     pub fn debug_dump_groups(&self) -> Vec<(usize, Key, Option<ScopeId>, usize)> {
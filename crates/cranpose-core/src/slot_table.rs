@@ -11,7 +11,7 @@
 
 use crate::{
     slot_storage::{GroupId, SlotStorage, StartGroup, ValueSlotId},
-    AnchorId, Key, NodeId, Owned, ScopeId,
+    AnchorId, Key, NodeId, Owned, ParentChildren, ScopeId,
 };
 use std::any::Any;
 use std::cell::Cell;
@@ -1444,6 +1444,27 @@ impl SlotTable {
         self.cursor = self.cursor.saturating_sub(1);
     }
 
+    /// Drops every remembered value while keeping groups and nodes in place.
+    ///
+    /// The next composition over this table finds its nodes again and updates
+    /// them, but re-runs every composable and re-initializes all remembered
+    /// state (Jetpack Compose's `ReusableContent`). Child lists of parent nodes
+    /// are node bookkeeping rather than state, so they are kept.
+    pub fn forget_remembered_values(&mut self) {
+        for slot in &mut self.slots {
+            let anchor = match slot {
+                Slot::Value { anchor, data } if !data.is::<Owned<ParentChildren>>() => *anchor,
+                _ => continue,
+            };
+            *slot = Slot::Gap {
+                anchor,
+                group_key: None,
+                group_scope: None,
+                group_len: 0,
+            };
+        }
+    }
+
     /// Trim slots by marking unreachable slots as gaps.
     ///
     /// Instead of blindly truncating at cursor position, this method:
@@ -428,6 +428,19 @@ pub fn take_mutable_snapshot(
     }
 }
 
+/// Takes a read-only snapshot, matching Kotlin's `Snapshot.takeSnapshot`.
+///
+/// Reads made inside [`AnySnapshot::enter`] see state as it was when the
+/// snapshot was taken; writes applied afterwards stay invisible to it. Inside
+/// another snapshot the new one is nested in it, otherwise it is taken from the
+/// global snapshot. Call [`AnySnapshot::dispose`] once done so old records can
+/// be reused.
+pub fn take_snapshot(read_observer: Option<ReadObserver>) -> AnySnapshot {
+    current_snapshot()
+        .unwrap_or_else(|| AnySnapshot::Global(GlobalSnapshot::get_or_create()))
+        .take_nested_snapshot(read_observer)
+}
+
 /// Take a transparent observer mutable snapshot with optional observers.
 ///
 /// This type of snapshot is used for read observation during composition,
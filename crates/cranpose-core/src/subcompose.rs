@@ -5,6 +5,8 @@
 //! two-phase lookup: first [`SlotId`]s that match exactly are preferred. If no
 //! exact match exists, the [`SlotReusePolicy`] is consulted to determine whether
 //! a node produced for another slot is compatible with the requested slot.
+//! A node taken over from another slot brings that slot's composition along,
+//! so its content nodes are updated in place rather than composed again.
 
 use crate::collections::map::HashMap; // FUTURE(no_std): replace HashMap/HashSet with arena-backed maps.
 use crate::collections::map::HashSet;
@@ -98,6 +100,34 @@ impl SlotReusePolicy for DefaultSlotReusePolicy {
     }
 }
 
+/// Size limits for the reusable node pools of a [`SubcomposeState`].
+///
+/// Slots that leave the active set are cached up to these limits; older
+/// entries beyond them are disposed. Larger pools avoid recomposing expensive
+/// items at the cost of keeping more nodes alive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SlotReuseLimits {
+    /// Maximum number of reusable slots cached per content type.
+    pub max_slots_per_type: usize,
+    /// Maximum number of reusable slots without a content type.
+    pub max_untyped_slots: usize,
+}
+
+impl SlotReuseLimits {
+    pub fn new(max_slots_per_type: usize, max_untyped_slots: usize) -> Self {
+        Self {
+            max_slots_per_type,
+            max_untyped_slots,
+        }
+    }
+}
+
+impl Default for SlotReuseLimits {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_REUSABLE_PER_TYPE, DEFAULT_MAX_REUSABLE_UNTYPED)
+    }
+}
+
 /// Reuse policy that allows cross-slot reuse when content types match.
 ///
 /// This policy enables efficient recycling of layout nodes across different
@@ -315,10 +345,10 @@ pub struct SubcomposeState {
     /// Each SlotId gets its own slot table, avoiding cursor-based conflicts
     /// when items are subcomposed in different orders.
     slot_compositions: HashMap<SlotId, Rc<SlotsHost>>,
-    /// Maximum number of reusable slots to keep cached per content type.
-    max_reusable_per_type: usize,
-    /// Maximum number of reusable slots for the untyped pool.
-    max_reusable_untyped: usize,
+    /// Maximum number of reusable slots to keep cached per pool.
+    limits: SlotReuseLimits,
+    /// Nodes disposed so far because their pool was full.
+    pool_overflow_count: usize,
     /// Whether the last slot registered via register_active was reused.
     /// Set during register_active, read via was_last_slot_reused().
     last_slot_reused: Option<bool>,
@@ -370,8 +400,8 @@ impl SubcomposeState {
             reusable_count: 0,
             precomposed_count: 0,
             slot_compositions: HashMap::default(),
-            max_reusable_per_type: DEFAULT_MAX_REUSABLE_PER_TYPE,
-            max_reusable_untyped: DEFAULT_MAX_REUSABLE_UNTYPED,
+            limits: SlotReuseLimits::default(),
+            pool_overflow_count: 0,
             last_slot_reused: None,
         }
     }
@@ -381,6 +411,26 @@ impl SubcomposeState {
         self.policy = policy;
     }
 
+    /// Sets the reusable pool sizes.
+    ///
+    /// Pools larger than the new limits are trimmed at the end of the next pass.
+    pub fn set_reuse_limits(&mut self, limits: SlotReuseLimits) {
+        self.limits = limits;
+    }
+
+    /// Returns the current reusable pool sizes.
+    pub fn reuse_limits(&self) -> SlotReuseLimits {
+        self.limits
+    }
+
+    /// Returns how many nodes were disposed because their pool was full.
+    ///
+    /// The count only grows; compare it across passes to spot pools that are
+    /// too small for the layout.
+    pub fn pool_overflow_count(&self) -> usize {
+        self.pool_overflow_count
+    }
+
     /// Registers a content type for a slot.
     ///
     /// Stores the content type locally for efficient pool-based reuse lookup,
@@ -531,6 +581,7 @@ impl SubcomposeState {
             if let Some(pool) = self.reusable_by_type.get_mut(&ct) {
                 if let Some((old_slot, node_id)) = pool.pop_front() {
                     self.migrate_node_to_slot(node_id, old_slot, slot_id);
+                    self.recycle_slot_composition(old_slot, slot_id);
                     self.update_reusable_count();
                     return Some(node_id);
                 }
@@ -546,6 +597,7 @@ impl SubcomposeState {
         if let Some(index) = position {
             if let Some((old_slot, node_id)) = self.reusable_nodes_untyped.remove(index) {
                 self.migrate_node_to_slot(node_id, old_slot, slot_id);
+                self.recycle_slot_composition(old_slot, slot_id);
                 self.update_reusable_count();
                 return Some(node_id);
             }
@@ -587,6 +639,23 @@ impl SubcomposeState {
         }
     }
 
+    /// Hands the composition of `old_slot` to `new_slot`.
+    ///
+    /// The next subcompose of `new_slot` then updates the recycled nodes in
+    /// place instead of composing its content from scratch. Remembered values
+    /// are dropped first so no state leaks between items, matching
+    /// `ReusableContent` in Jetpack Compose.
+    fn recycle_slot_composition(&mut self, old_slot: SlotId, new_slot: SlotId) {
+        if old_slot == new_slot {
+            return;
+        }
+        if let Some(slots) = self.slot_compositions.remove(&old_slot) {
+            if slots.borrow_mut().forget_remembered_values() {
+                self.slot_compositions.insert(new_slot, slots);
+            }
+        }
+    }
+
     /// Updates the reusable_count from all pools.
     fn update_reusable_count(&mut self) {
         self.reusable_count = self
@@ -599,7 +668,7 @@ impl SubcomposeState {
 
     /// Moves active slots starting from `start_index` to the reusable bucket.
     /// Returns the list of node ids that were DISPOSED (not just moved to reusable).
-    /// Nodes that exceed max_reusable_per_type are disposed instead of cached.
+    /// Nodes that exceed the pool limits are disposed instead of cached.
     pub fn dispose_or_reuse_starting_from_index(&mut self, start_index: usize) -> Vec<NodeId> {
         // FUTURE(no_std): return iterator over bounded node buffer.
         if start_index >= self.active_order.len() {
@@ -636,12 +705,12 @@ impl SubcomposeState {
         retained.reverse();
         self.active_order.extend(retained);
 
-        // Enforce max_reusable_per_type limit per pool - dispose oldest nodes first (FIFO)
+        // Enforce the per-type limit on each pool - dispose oldest nodes first (FIFO)
         let mut disposed = Vec::new();
 
         // Enforce limit on typed pools
         for pool in self.reusable_by_type.values_mut() {
-            while pool.len() > self.max_reusable_per_type {
+            while pool.len() > self.limits.max_slots_per_type {
                 if let Some((_, node_id)) = pool.pop_front() {
                     self.mapping.remove_by_node(&node_id);
                     disposed.push(node_id);
@@ -650,13 +719,14 @@ impl SubcomposeState {
         }
 
         // Enforce limit on untyped pool (uses separate, larger limit)
-        while self.reusable_nodes_untyped.len() > self.max_reusable_untyped {
+        while self.reusable_nodes_untyped.len() > self.limits.max_untyped_slots {
             if let Some((_, node_id)) = self.reusable_nodes_untyped.pop_front() {
                 self.mapping.remove_by_node(&node_id);
                 disposed.push(node_id);
             }
         }
 
+        self.pool_overflow_count += disposed.len();
         self.update_reusable_count();
         disposed
     }
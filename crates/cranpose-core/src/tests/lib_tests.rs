@@ -1,8 +1,8 @@
 use super::*;
 use crate as cranpose_core;
-use crate::snapshot_v2::take_mutable_snapshot;
 #[cfg(test)]
 use crate::snapshot_v2::{reset_runtime_for_tests, TestRuntimeGuard};
+use crate::snapshot_v2::{take_mutable_snapshot, take_snapshot};
 use crate::state::{MutationPolicy, SnapshotMutableState};
 use crate::SnapshotStateObserver;
 use cranpose_macros::composable;
@@ -62,6 +62,7 @@ thread_local! {
         const { RefCell::new(None) };
     static SIDE_EFFECT_STATE: RefCell<Option<cranpose_core::MutableState<i32>>> =
         const { RefCell::new(None) };
+    static PRODUCED_VALUES: RefCell<Vec<i32>> = const { RefCell::new(Vec::new()) };
 }
 
 thread_local! {
@@ -1246,6 +1247,118 @@ fn disposable_effect_host() -> NodeId {
     cranpose_test_node(TestTextNode::default)
 }
 
+/// Reads a state that its producer fills in on the third frame.
+#[composable]
+fn produced_state_host() -> NodeId {
+    let loaded = produceState!(0, (), |scope| {
+        let mut frames = 0;
+        scope.poll_frames(move |scope, _| {
+            frames += 1;
+            if frames < 3 {
+                return true;
+            }
+            scope.set_value(42);
+            false
+        });
+    });
+    PRODUCED_VALUES.with(|values| values.borrow_mut().push(loaded.value()));
+    cranpose_test_node(TestTextNode::default)
+}
+
+#[test]
+fn produce_state_publishes_from_frames_and_recomposes_readers() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    PRODUCED_VALUES.with(|values| values.borrow_mut().clear());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, || {
+            produced_state_host();
+        })
+        .expect("render succeeds");
+    let runtime = composition.runtime_handle();
+
+    for frame in 0..3 {
+        runtime.drain_frame_callbacks(frame * 16_000_000);
+        let _ = composition
+            .process_invalid_scopes()
+            .expect("process invalid scopes succeeds");
+    }
+    // Composed once with the initial value, then recomposed once with the result
+    PRODUCED_VALUES.with(|values| assert_eq!(*values.borrow(), vec![0, 42]));
+
+    // The producer stopped polling
+    assert!(!runtime.has_frame_callbacks());
+}
+
+type StoreListeners = Rc<RefCell<Vec<(usize, Rc<dyn Fn(i32)>)>>>;
+
+/// External store notifying its listeners of each new value.
+#[derive(Clone, Default)]
+struct MockStore {
+    listeners: StoreListeners,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl PartialEq for MockStore {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.listeners, &other.listeners)
+    }
+}
+
+impl MockStore {
+    fn subscribe(&self, listener: Rc<dyn Fn(i32)>) -> impl FnOnce() {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.listeners.borrow_mut().push((id, listener));
+        let listeners = Rc::clone(&self.listeners);
+        move || listeners.borrow_mut().retain(|(entry, _)| *entry != id)
+    }
+
+    fn emit(&self, value: i32) {
+        let listeners: Vec<_> = self
+            .listeners
+            .borrow()
+            .iter()
+            .map(|(_, listener)| Rc::clone(listener))
+            .collect();
+        for listener in listeners {
+            listener(value);
+        }
+    }
+}
+
+#[composable]
+fn store_reader(store: MockStore, seen: Rc<RefCell<Vec<i32>>>) {
+    let value = observeAsState!(0, (), move |emit| store.subscribe(emit));
+    seen.borrow_mut().push(value.value());
+}
+
+#[test]
+fn observe_as_state_recomposes_on_emissions_and_unsubscribes_on_teardown() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let store = MockStore::default();
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let key = location_key(file!(), line!(), column!());
+    {
+        let store = store.clone();
+        let seen = Rc::clone(&seen);
+        composition
+            .render(key, move || store_reader(store.clone(), Rc::clone(&seen)))
+            .expect("render succeeds");
+    }
+    assert_eq!(store.listeners.borrow().len(), 1);
+
+    store.emit(5);
+    assert!(composition.should_render());
+    let _ = composition
+        .process_invalid_scopes()
+        .expect("process invalid scopes succeeds");
+    assert_eq!(*seen.borrow(), vec![0, 5]);
+
+    drop(composition);
+    assert!(store.listeners.borrow().is_empty());
+}
+
 #[test]
 fn frame_callbacks_fire_in_registration_order() {
     let runtime = Runtime::new(Arc::new(TestScheduler));
@@ -1493,6 +1606,180 @@ fn state_update_schedules_render() {
     assert!(composition.should_render());
 }
 
+#[test]
+fn remember_saveable_round_trips_through_registry() {
+    thread_local! {
+        static COUNT: Cell<Option<MutableState<i32>>> = const { Cell::new(None) };
+    }
+
+    #[composable]
+    fn saveable_counter() {
+        let count = remember_saveable("count", || 0);
+        COUNT.with(|slot| slot.set(Some(count)));
+    }
+
+    fn compose_with(registry: SaveableStateRegistry) -> Composition<MemoryApplier> {
+        let mut composition = Composition::new(MemoryApplier::new());
+        composition
+            .render(location_key(file!(), line!(), column!()), move || {
+                CompositionLocalProvider(
+                    vec![local_saveable_state_registry().provides(Some(registry.clone()))],
+                    saveable_counter,
+                );
+            })
+            .expect("render succeeds");
+        composition
+    }
+    let count = || COUNT.with(|slot| slot.get()).expect("counter composed");
+
+    let registry = SaveableStateRegistry::new();
+    let composition = compose_with(registry.clone());
+    assert_eq!(count().get(), 0);
+    count().set(7);
+
+    let saved = registry.perform_save();
+    assert_eq!(saved.get("count").map(String::as_str), Some("7"));
+    drop(composition);
+    assert!(
+        registry.perform_save().is_empty(),
+        "teardown unregisters the provider"
+    );
+
+    let _composition = compose_with(SaveableStateRegistry::with_restored(saved));
+    assert_eq!(count().get(), 7);
+}
+
+#[test]
+fn movable_content_keeps_state_when_moved_between_containers() {
+    thread_local! {
+        static COUNT: Cell<Option<MutableState<i32>>> = const { Cell::new(None) };
+        static CONTAINERS: RefCell<Vec<NodeId>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[composable]
+    fn movable_counter() {
+        let count = useState(|| 0);
+        COUNT.with(|slot| slot.set(Some(count)));
+        let value = count.value();
+        let id = cranpose_test_node(TrackingChild::default);
+        with_node_mut(id, |node: &mut TrackingChild| {
+            node.label = value.to_string()
+        })
+        .expect("update counter node");
+    }
+
+    #[composable]
+    fn counter_container(content: Option<MovableContent>) {
+        let id = cranpose_test_node(RecordingNode::default);
+        CONTAINERS.with(|containers| containers.borrow_mut().push(id));
+        push_parent(id);
+        if let Some(content) = content {
+            content.invoke();
+        }
+        pop_parent();
+    }
+
+    #[composable]
+    fn movable_root(in_first: MutableState<bool>) {
+        let counter = remember(|| movable_content_of(movable_counter)).with(|c| c.clone());
+        let first = in_first.value();
+        let root = cranpose_test_node(RecordingNode::default);
+        push_parent(root);
+        counter_container(first.then(|| counter.clone()));
+        counter_container((!first).then(|| counter.clone()));
+        pop_parent();
+    }
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let in_first = MutableState::with_runtime(true, runtime);
+    composition
+        .render(location_key(file!(), line!(), column!()), || {
+            movable_root(in_first)
+        })
+        .expect("initial render");
+
+    let containers = CONTAINERS.with(|containers| containers.take());
+    let children = |composition: &mut Composition<MemoryApplier>, container: NodeId| {
+        composition
+            .applier_mut()
+            .with_node(container, |node: &mut RecordingNode| node.children.clone())
+            .expect("container exists")
+    };
+    let counter_label = |composition: &mut Composition<MemoryApplier>, id: NodeId| {
+        composition
+            .applier_mut()
+            .with_node(id, |node: &mut TrackingChild| node.label.clone())
+            .expect("counter exists")
+    };
+    let counter_id = children(&mut composition, containers[0])[0];
+    assert!(children(&mut composition, containers[1]).is_empty());
+
+    let count = COUNT.with(|slot| slot.get()).expect("counter composed");
+    count.set(1);
+    composition
+        .process_invalid_scopes()
+        .expect("recompose counter");
+    assert_eq!(counter_label(&mut composition, counter_id), "1");
+
+    in_first.set(false);
+    composition.process_invalid_scopes().expect("move counter");
+    assert!(children(&mut composition, containers[0]).is_empty());
+    assert_eq!(children(&mut composition, containers[1]), vec![counter_id]);
+    assert_eq!(counter_label(&mut composition, counter_id), "1");
+    assert_eq!(COUNT.with(|slot| slot.get()), Some(count));
+
+    count.set(2);
+    composition
+        .process_invalid_scopes()
+        .expect("recompose moved counter");
+    assert_eq!(counter_label(&mut composition, counter_id), "2");
+}
+
+#[test]
+fn keyed_children_rerun_only_the_child_whose_input_changed() {
+    thread_local! {
+        static CHILD_RUNS: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[composable]
+    fn keyed_child(id: u32, label: String) {
+        CHILD_RUNS.with(|runs| runs.borrow_mut().push(id));
+        let _ = label;
+    }
+
+    #[composable]
+    fn keyed_parent(items: MutableState<Vec<(u32, String)>>) {
+        for (id, label) in items.value() {
+            with_key(&id, || keyed_child(id, label.clone()));
+        }
+    }
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let items = MutableState::with_runtime(
+        vec![
+            (1, "a".to_string()),
+            (2, "b".to_string()),
+            (3, "c".to_string()),
+        ],
+        runtime,
+    );
+    composition
+        .render(location_key(file!(), line!(), column!()), || {
+            keyed_parent(items)
+        })
+        .expect("initial render");
+    assert_eq!(CHILD_RUNS.with(|runs| runs.take()), vec![1, 2, 3]);
+
+    items.update(|items| items[1].1 = "changed".to_string());
+    composition
+        .process_invalid_scopes()
+        .expect("recompose parent");
+
+    assert_eq!(CHILD_RUNS.with(|runs| runs.take()), vec![2]);
+}
+
 #[test]
 fn recranpose_does_not_use_stale_indices_when_prior_scope_changes_length() {
     thread_local! {
@@ -1695,6 +1982,38 @@ fn disposable_effect_reacts_to_key_changes() {
     });
 }
 
+#[composable]
+fn conditional_effect_host(show: MutableState<bool>, log: Rc<RefCell<Vec<&'static str>>>) {
+    if show.value() {
+        DisposableEffect!((), move |scope| {
+            log.borrow_mut().push("start");
+            let log = Rc::clone(&log);
+            scope.on_dispose(move || log.borrow_mut().push("dispose"))
+        });
+    }
+}
+
+#[test]
+fn recomposed_scope_disposes_branch_it_no_longer_composes() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let show = MutableState::with_runtime(true, composition.runtime_handle());
+    let log: Rc<RefCell<Vec<&'static str>>> = Rc::new(RefCell::new(Vec::new()));
+    let key = location_key(file!(), line!(), column!());
+    {
+        let log = Rc::clone(&log);
+        composition
+            .render(key, move || conditional_effect_host(show, Rc::clone(&log)))
+            .expect("render succeeds");
+    }
+    assert_eq!(&*log.borrow(), &["start"]);
+
+    show.set_value(false);
+    composition
+        .process_invalid_scopes()
+        .expect("process invalid scopes succeeds");
+    assert_eq!(&*log.borrow(), &["start", "dispose"]);
+}
+
 #[test]
 fn state_invalidation_skips_parent_scope() {
     PARENT_RECOMPOSITIONS.with(|calls| calls.set(0));
@@ -2035,6 +2354,66 @@ fn composition_local_provider_scopes_values() {
     assert_eq!(LAST_VALUE.with(|slot| slot.get()), 5);
 }
 
+#[test]
+fn composition_local_nested_provider_overrides_outer_value() {
+    thread_local! {
+        static READS: RefCell<Vec<(&'static str, i32)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    let local_counter = compositionLocalOf(|| 0);
+    let mut composition = Composition::new(MemoryApplier::new());
+    let runtime = composition.runtime_handle();
+    let inner_state = MutableState::with_runtime(20, runtime.clone());
+
+    #[composable]
+    fn reader(label: &'static str, local_counter: CompositionLocal<i32>) {
+        let value = local_counter.current();
+        READS.with(|reads| reads.borrow_mut().push((label, value)));
+    }
+
+    #[composable]
+    fn tree(local_counter: CompositionLocal<i32>, inner_state: MutableState<i32>) {
+        reader("root", local_counter.clone());
+        CompositionLocalProvider(vec![local_counter.provides(10)], || {
+            reader("outer", local_counter.clone());
+            CompositionLocalProvider(vec![local_counter.provides(inner_state.value())], || {
+                reader("inner", local_counter.clone());
+            });
+            reader("outer_after", local_counter.clone());
+        });
+    }
+
+    composition
+        .render(1, || tree(local_counter.clone(), inner_state))
+        .expect("initial composition");
+
+    assert_eq!(
+        READS.with(|reads| reads.borrow().clone()),
+        vec![
+            ("root", 0),
+            ("outer", 10),
+            ("inner", 20),
+            ("outer_after", 10)
+        ]
+    );
+
+    READS.with(|reads| reads.borrow_mut().clear());
+    inner_state.set_value(30);
+    let _ = composition
+        .process_invalid_scopes()
+        .expect("process local change");
+
+    let reads = READS.with(|reads| reads.borrow().clone());
+    assert!(reads.contains(&("inner", 30)));
+    for (label, value) in reads {
+        match label {
+            "root" => assert_eq!(value, 0),
+            "inner" => assert_eq!(value, 30),
+            _ => assert_eq!(value, 10, "{label} should still see the outer value"),
+        }
+    }
+}
+
 #[test]
 fn composition_local_default_value_used_outside_provider() {
     thread_local! {
@@ -2520,6 +2899,49 @@ fn snapshot_state_child_apply_after_parent_history() {
     assert_eq!(state.get(), 42);
 }
 
+#[test]
+fn readonly_snapshot_ignores_writes_applied_after_it_was_taken() {
+    let _guard = reset_snapshot_runtime();
+    let (runtime_handle, _runtime) = runtime_handle();
+    let state = MutableState::with_runtime(1, runtime_handle);
+
+    let snapshot = take_snapshot(None);
+    state.set(2);
+    run_in_mutable_snapshot(|| state.set(3)).expect("write applies");
+
+    snapshot.enter(|| assert_eq!(state.get(), 1));
+    snapshot.dispose();
+    assert_eq!(state.get(), 3);
+}
+
+#[test]
+fn mutable_snapshot_reads_stay_consistent_until_applied() {
+    let _guard = reset_snapshot_runtime();
+    let (runtime_handle, _runtime) = runtime_handle();
+    let width = MutableState::with_runtime(10, runtime_handle.clone());
+    let height = MutableState::with_runtime(20, runtime_handle);
+
+    let pass = take_mutable_snapshot(None, None);
+    let first_read = pass.enter(|| width.get());
+    run_in_mutable_snapshot(|| {
+        width.set(11);
+        height.set(21);
+    })
+    .expect("concurrent write applies");
+    let second_read = pass.enter(|| (width.get(), height.get()));
+
+    assert_eq!(first_read, 10);
+    assert_eq!(second_read, (10, 20));
+
+    pass.enter(|| height.set(30));
+    assert_eq!(height.get(), 21, "pass writes stay private until applied");
+    assert!(
+        pass.apply().is_failure(),
+        "the pass wrote a state that changed after it was taken"
+    );
+    assert_eq!((width.get(), height.get()), (11, 21));
+}
+
 // Note: Tests for ComposeTestRule and run_test_composition have been moved to
 // the cranpose-testing crate to avoid circular dependencies.
 
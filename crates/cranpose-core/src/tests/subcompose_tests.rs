@@ -281,3 +281,22 @@ fn content_type_none_clears_policy() {
         "Untyped slot should not match typed slot 2 (type 100)"
     );
 }
+
+#[test]
+fn reuse_limits_cap_each_pool() {
+    let mut state = SubcomposeState::new(Box::new(ContentTypeReusePolicy::new()));
+    state.set_reuse_limits(SlotReuseLimits::new(2, 1));
+    for raw in 1..=4 {
+        state.register_content_type(SlotId::new(raw), 7);
+        state.register_active(SlotId::new(raw), &[raw as NodeId * 10], &[]);
+    }
+    state.register_active(SlotId::new(5), &[50], &[]);
+    state.register_active(SlotId::new(6), &[60], &[]);
+
+    let disposed = state.dispose_or_reuse_starting_from_index(0);
+
+    // Oldest typed entries and the extra untyped entry are disposed
+    assert_eq!(state.reusable_slots_count(), 3);
+    assert_eq!(disposed.len(), 3);
+    assert_eq!(state.pool_overflow_count(), 3);
+}
@@ -0,0 +1,87 @@
+use cranpose_foundation::lazy::test_helpers::{new_lazy_list_state, with_test_runtime};
+use cranpose_foundation::lazy::{
+    measure_lazy_list, LazyListMeasureConfig, LazyListMeasuredItem, LazyListState,
+};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const ITEM_COUNT_SAMPLES: &[usize] = &[1_000, 10_000];
+const ITEM_SIZE: f32 = 48.0;
+const VIEWPORT_SIZE: f32 = 800.0;
+const CROSS_AXIS_SIZE: f32 = 400.0;
+
+/// Runs one measure pass, caching item sizes the way the lazy list widget does.
+fn measure(state: &LazyListState, items_count: usize, config: &LazyListMeasureConfig) -> bool {
+    let result = measure_lazy_list(
+        items_count,
+        state,
+        VIEWPORT_SIZE,
+        CROSS_AXIS_SIZE,
+        config,
+        |index| index as u64,
+        |index| {
+            state.cache_item_size(index as u64, ITEM_SIZE);
+            LazyListMeasuredItem::new(index, index as u64, None, ITEM_SIZE, CROSS_AXIS_SIZE)
+        },
+    );
+    result.can_scroll_forward
+}
+
+/// Scrolls from the first item to the last one viewport at a time.
+fn bench_scroll_through(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lazy_measure_scroll_through");
+    group.sample_size(20);
+    for &items_count in ITEM_COUNT_SAMPLES {
+        group.bench_with_input(
+            BenchmarkId::new("items", items_count),
+            &items_count,
+            |b, &items_count| {
+                with_test_runtime(|| {
+                    let state = new_lazy_list_state();
+                    let config = LazyListMeasureConfig::default();
+                    measure(&state, items_count, &config);
+
+                    b.iter(|| {
+                        state.scroll_to_item(0, 0.0);
+                        let mut passes = 0;
+                        while measure(&state, items_count, &config) {
+                            state.dispatch_scroll_delta(-VIEWPORT_SIZE);
+                            passes += 1;
+                        }
+                        black_box(passes);
+                    });
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Jumps between both ends of the list; the cost should not depend on the list size.
+fn bench_jump(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lazy_measure_jump");
+    for &items_count in ITEM_COUNT_SAMPLES {
+        group.bench_with_input(
+            BenchmarkId::new("items", items_count),
+            &items_count,
+            |b, &items_count| {
+                with_test_runtime(|| {
+                    let state = new_lazy_list_state();
+                    let config = LazyListMeasureConfig::default();
+                    measure(&state, items_count, &config);
+                    let mut to_end = true;
+
+                    b.iter(|| {
+                        let target = if to_end { items_count - 1 } else { 0 };
+                        to_end = !to_end;
+                        state.scroll_to_item(target, 0.0);
+                        black_box(measure(&state, items_count, &config));
+                    });
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(lazy_measure, bench_scroll_through, bench_jump);
+criterion_main!(lazy_measure);
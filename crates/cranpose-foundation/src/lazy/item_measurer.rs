@@ -79,6 +79,12 @@ where
         let (mut visible_items, current_index, current_offset) =
             self.measure_visible(first_item_index, start_offset, viewport_end, start_time);
 
+        // Scrolled past the end: rest the last item on the after-padding and
+        // fill the space that opens up before the first item
+        if current_index >= self.items_count {
+            self.align_last_item_to_end(&mut visible_items, viewport_end);
+        }
+
         // Measure beyond-bounds items after visible
         self.measure_beyond_after(
             current_index,
@@ -88,9 +94,13 @@ where
         );
 
         // Measure beyond-bounds items before visible
-        if first_item_index > 0 && !visible_items.is_empty() {
-            let before_items =
-                self.measure_beyond_before(first_item_index, visible_items[0].offset, start_time);
+        let first_visible_index = visible_items.first().map_or(0, |item| item.index);
+        if first_visible_index > 0 {
+            let before_items = self.measure_beyond_before(
+                first_visible_index,
+                visible_items[0].offset,
+                start_time,
+            );
             if !before_items.is_empty() {
                 let mut combined = before_items;
                 combined.append(&mut visible_items);
@@ -151,6 +161,54 @@ where
         (items, current_index, current_offset)
     }
 
+    /// Moves items forward so the last item ends at `viewport_end`.
+    ///
+    /// Items before the first one are measured and prepended until the
+    /// before-content padding is covered again, so the viewport has no gap at
+    /// the start. A list too short to fill the viewport stays pinned to the
+    /// start instead.
+    fn align_last_item_to_end(&mut self, items: &mut Vec<LazyListMeasuredItem>, viewport_end: f32) {
+        let Some(last) = items.last() else {
+            return;
+        };
+        let gap = viewport_end - (last.offset + last.main_axis_size);
+        if gap <= 0.0 {
+            return;
+        }
+        for item in items.iter_mut() {
+            item.offset += gap;
+        }
+
+        let mut prepended = Vec::new();
+        let mut first_index = items[0].index;
+        let mut first_offset = items[0].offset;
+        while first_index > 0
+            && first_offset > self.config.before_content_padding
+            && items.len() + prepended.len() < MAX_VISIBLE_ITEMS_SAFETY
+        {
+            first_index -= 1;
+            let mut item = self
+                .take_pre_measured(first_index)
+                .unwrap_or_else(|| (self.measure_fn)(first_index));
+            first_offset -= item.main_axis_size + self.config.spacing;
+            item.offset = first_offset;
+            prepended.push(item);
+        }
+
+        if !prepended.is_empty() {
+            prepended.reverse();
+            prepended.append(items);
+            *items = prepended;
+        }
+
+        let overshoot = first_offset - self.config.before_content_padding;
+        if first_index == 0 && overshoot > 0.0 {
+            for item in items.iter_mut() {
+                item.offset -= overshoot;
+            }
+        }
+    }
+
     /// Measures beyond-bounds items after visible items.
     fn measure_beyond_after(
         &mut self,
@@ -290,4 +348,44 @@ mod tests {
         assert_eq!(items[0].offset, 0.0);
         assert_eq!(items[1].offset, 60.0); // 50 + 10 spacing
     }
+
+    #[test]
+    fn test_measure_past_end_fills_gap_before_first_item() {
+        let config = LazyListMeasureConfig {
+            before_content_padding: 16.0,
+            after_content_padding: 16.0,
+            spacing: 8.0,
+            ..Default::default()
+        };
+        let mut measure = |i| create_test_item(i, 50.0);
+        let mut measurer = ItemMeasurer::new(&mut measure, &config, 10, 200.0, VecDeque::new());
+
+        // Anchor on the last item far past the end of the list
+        let items = measurer.measure_all(9, 500.0);
+
+        let last = items.last().unwrap();
+        assert_eq!(last.index, 9);
+        assert_eq!(last.offset + last.main_axis_size, 184.0);
+        // Items are contiguous and reach back past the top padding
+        for pair in items.windows(2) {
+            assert_eq!(pair[1].index, pair[0].index + 1);
+            assert_eq!(pair[1].offset, pair[0].offset + 58.0);
+        }
+        assert!(items[0].offset <= 16.0);
+    }
+
+    #[test]
+    fn test_measure_past_end_of_short_list_stays_at_start() {
+        let config = LazyListMeasureConfig {
+            before_content_padding: 16.0,
+            ..Default::default()
+        };
+        let mut measure = |i| create_test_item(i, 50.0);
+        let mut measurer = ItemMeasurer::new(&mut measure, &config, 3, 1000.0, VecDeque::new());
+
+        let items = measurer.measure_all(2, 120.0);
+
+        let offsets: Vec<f32> = items.iter().map(|item| item.offset).collect();
+        assert_eq!(offsets, vec![16.0, 66.0, 116.0]);
+    }
 }
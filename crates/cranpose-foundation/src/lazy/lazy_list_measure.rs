@@ -10,7 +10,8 @@ use super::lazy_list_measured_item::{LazyListMeasureResult, LazyListMeasuredItem
 use super::lazy_list_state::{LazyListLayoutInfo, LazyListState};
 use super::scroll_position_resolver::ScrollPositionResolver;
 use super::viewport::ViewportHandler;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
 
 /// Default estimated item size for scroll calculations.
 /// Used when no measured sizes are cached.
@@ -49,6 +50,12 @@ pub struct LazyListMeasureConfig {
     /// Horizontal arrangement for distributing items.
     /// Used when `is_vertical` is false.
     pub horizontal_arrangement: Option<cranpose_ui_layout::LinearArrangement>,
+
+    /// Part of the list that is actually on screen when the viewport is unbounded,
+    /// in the list's own coordinates (e.g. derived from a scrolling parent's offset).
+    ///
+    /// Only items overlapping this window are measured. Ignored for bounded viewports.
+    pub known_viewport: Option<Range<f32>>,
 }
 
 impl Default for LazyListMeasureConfig {
@@ -62,6 +69,7 @@ impl Default for LazyListMeasureConfig {
             beyond_bounds_item_count: 2,
             vertical_arrangement: None,
             horizontal_arrangement: None,
+            known_viewport: None,
         }
     }
 }
@@ -81,19 +89,23 @@ impl Default for LazyListMeasureConfig {
 /// * `viewport_size` - Size of the viewport in main axis
 /// * `cross_axis_size` - Size of the viewport in cross axis
 /// * `config` - Measurement configuration
+/// * `item_key` - Returns the key of the item at given index, used to look up
+///   cached sizes of items that are not measured
 /// * `measure_item` - Callback to compose and measure an item at given index
 ///
 /// # Returns
 /// A [`LazyListMeasureResult`] containing the items to place.
-pub fn measure_lazy_list<F>(
+pub fn measure_lazy_list<K, F>(
     items_count: usize,
     state: &LazyListState,
     viewport_size: f32,
     _cross_axis_size: f32,
     config: &LazyListMeasureConfig,
+    item_key: K,
     mut measure_item: F,
 ) -> LazyListMeasureResult
 where
+    K: Fn(usize) -> u64,
     F: FnMut(usize) -> LazyListMeasuredItem,
 {
     // reverse_layout is handled during placement (create_lazy_list_placements)
@@ -132,9 +144,24 @@ where
         return LazyListMeasureResult::default();
     }
 
-    // 1. Viewport handling - detect and handle infinite viewports
+    // 1. Viewport handling - measure the known window or fall back to an estimate
+    if let Some(window) = config
+        .known_viewport
+        .clone()
+        .filter(|_| ViewportHandler::is_unbounded(viewport_size))
+    {
+        return measure_known_window(items_count, state, config, window, item_key, measure_item);
+    }
     let viewport = ViewportHandler::new(viewport_size, state.average_item_size(), config.spacing);
     let effective_viewport_size = viewport.effective_size();
+    if viewport.is_infinite() && state.record_infinite_viewport() {
+        log::warn!(
+            "LazyList: unbounded viewport ({viewport_size}) and no known viewport, \
+             measuring an estimated {effective_viewport_size}px window instead. \
+             Give the list a bounded size (e.g. Modifier::height) or pass the \
+             on-screen window with known_viewport."
+        );
+    }
 
     // 2. Resolve and normalize scroll position
     let resolver = ScrollPositionResolver::new(state, config, items_count, effective_viewport_size);
@@ -173,12 +200,16 @@ where
     adjuster.clamp(&mut visible_items);
 
     // 5. Calculate total content size and finalize result
-    let total_content_size = estimate_total_content_size(
-        items_count,
-        &visible_items,
-        config,
-        state.average_item_size(),
-    );
+    let average_item_size = state.average_item_size();
+    let total_content_size = state.with_cached_item_sizes(|cached_sizes| {
+        estimate_total_content_size(
+            items_count,
+            &visible_items,
+            config,
+            cached_sizes,
+            average_item_size,
+        )
+    });
 
     // Update scroll position - find actual first visible item
     let viewport_end = effective_viewport_size - config.after_content_padding;
@@ -254,35 +285,163 @@ where
     }
 }
 
-/// Estimates total content size based on measured items.
+/// Measures only the items overlapping `window` in an unbounded viewport.
+///
+/// The list lays out its full content and a scrolling parent decides which part
+/// is visible, so item offsets are in content coordinates and the list itself
+/// never scrolls. The first item is located by walking from where the previous
+/// pass started, or from the list start when that is closer, using cached sizes
+/// and the running average for items that were never measured. A pass costs
+/// time proportional to the scroll distance rather than to the item count.
+fn measure_known_window<K, F>(
+    items_count: usize,
+    state: &LazyListState,
+    config: &LazyListMeasureConfig,
+    window: Range<f32>,
+    item_key: K,
+    mut measure_item: F,
+) -> LazyListMeasureResult
+where
+    K: Fn(usize) -> u64,
+    F: FnMut(usize) -> LazyListMeasuredItem,
+{
+    let average_item_size = state.average_item_size();
+    let size_of = |index: usize| {
+        state
+            .get_cached_size(item_key(index))
+            .unwrap_or(average_item_size)
+    };
+    // Start from the previous pass unless the list start is closer
+    let (mut index, mut offset) = state
+        .known_window_anchor()
+        .filter(|&(index, offset)| {
+            index < items_count
+                && (offset - window.start).abs() < window.start - config.before_content_padding
+        })
+        .unwrap_or((0, config.before_content_padding));
+    while index > 0 && offset > window.start {
+        index -= 1;
+        offset -= size_of(index) + config.spacing;
+    }
+    if index == 0 {
+        offset = config.before_content_padding;
+    }
+    while index + 1 < items_count {
+        let size = size_of(index);
+        if offset + size + config.spacing > window.start {
+            break;
+        }
+        offset += size + config.spacing;
+        index += 1;
+    }
+
+    let mut visible_items = Vec::new();
+    while index < items_count && offset < window.end {
+        let mut item = measure_item(index);
+        item.offset = offset;
+        offset += item.main_axis_size + config.spacing;
+        visible_items.push(item);
+        index += 1;
+    }
+
+    let total_content_size = state.with_cached_item_sizes(|cached_sizes| {
+        estimate_total_content_size(
+            items_count,
+            &visible_items,
+            config,
+            cached_sizes,
+            average_item_size,
+        )
+    });
+
+    let (first_index, first_offset) = match visible_items.first() {
+        Some(first) => {
+            let first_offset = (window.start - first.offset).max(0.0);
+            state.set_known_window_anchor((first.index, first.offset));
+            state.update_scroll_position_with_key(first.index, first_offset, first.key);
+            (first.index, first_offset)
+        }
+        None => (0, 0.0),
+    };
+    state.update_layout_info(LazyListLayoutInfo {
+        visible_items_info: visible_items.iter().map(|i| i.to_item_info()).collect(),
+        total_items_count: items_count,
+        viewport_size: window.end - window.start,
+        viewport_start_offset: config.before_content_padding,
+        viewport_end_offset: config.after_content_padding,
+        before_content_padding: config.before_content_padding,
+        after_content_padding: config.after_content_padding,
+    });
+    state.update_scroll_bounds();
+
+    LazyListMeasureResult {
+        visible_items,
+        first_visible_item_index: first_index,
+        first_visible_item_scroll_offset: first_offset,
+        viewport_size: total_content_size,
+        total_content_size,
+        can_scroll_forward: false,
+        can_scroll_backward: false,
+    }
+}
+
+/// Estimates total content size based on measured and cached item sizes.
+///
+/// Items measured in this pass and items with a cached size contribute their
+/// exact size; only the remaining items use the average. The total therefore
+/// stays stable while scrolling and converges as more items are measured.
+/// The average is taken over the known sizes, falling back to the state's
+/// running average when nothing is known yet.
 ///
-/// Uses the average size of measured items to estimate the total.
-/// Falls back to state's running average if no items are currently measured.
+/// `cached_sizes` is keyed by item key. Sizes of removed items may linger in
+/// it until evicted, so it never counts for more items than were not measured.
 fn estimate_total_content_size(
     items_count: usize,
     measured_items: &[LazyListMeasuredItem],
     config: &LazyListMeasureConfig,
+    cached_sizes: &HashMap<u64, f32>,
     state_average_size: f32,
 ) -> f32 {
     if items_count == 0 {
         return 0.0;
     }
 
-    // Use measured items' average if available, otherwise use state's accumulated average
-    let avg_size = if !measured_items.is_empty() {
-        let total_measured_size: f32 = measured_items.iter().map(|i| i.main_axis_size).sum();
-        total_measured_size / measured_items.len() as f32
+    let measured_size: f32 = measured_items.iter().map(|i| i.main_axis_size).sum();
+    let (cached_size, cached_count) = cached_sizes
+        .iter()
+        .filter(|(key, _)| !measured_items.iter().any(|item| item.key == **key))
+        .fold((0.0, 0usize), |(sum, count), (_, size)| {
+            (sum + size, count + 1)
+        });
+    let unmeasured_count = items_count.saturating_sub(measured_items.len());
+    let (cached_size, cached_count) = if cached_count > unmeasured_count {
+        (
+            cached_size / cached_count as f32 * unmeasured_count as f32,
+            unmeasured_count,
+        )
+    } else {
+        (cached_size, cached_count)
+    };
+
+    let known_count = measured_items.len() + cached_count;
+    let avg_size = if known_count > 0 {
+        (measured_size + cached_size) / known_count as f32
     } else {
         state_average_size
     };
+    let unknown_count = items_count.saturating_sub(known_count);
 
-    config.before_content_padding + (avg_size + config.spacing) * items_count as f32
-        - config.spacing
+    config.before_content_padding
+        + measured_size
+        + cached_size
+        + avg_size * unknown_count as f32
+        + config.spacing * (items_count - 1) as f32
         + config.after_content_padding
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::lazy_list_scope::{DuplicateLazyKey, LazyListIntervalContent, LazyListScope};
     use super::super::lazy_list_state::test_helpers::{
         new_lazy_list_state, new_lazy_list_state_with_position, with_test_runtime,
     };
@@ -298,9 +457,17 @@ mod tests {
             let state = new_lazy_list_state();
             let config = LazyListMeasureConfig::default();
 
-            let result = measure_lazy_list(0, &state, 500.0, 300.0, &config, |_| {
-                panic!("Should not measure any items");
-            });
+            let result = measure_lazy_list(
+                0,
+                &state,
+                500.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |_| {
+                    panic!("Should not measure any items");
+                },
+            );
 
             assert!(result.visible_items.is_empty());
         });
@@ -312,9 +479,15 @@ mod tests {
             let state = new_lazy_list_state();
             let config = LazyListMeasureConfig::default();
 
-            let result = measure_lazy_list(1, &state, 500.0, 300.0, &config, |i| {
-                create_test_item(i, 50.0)
-            });
+            let result = measure_lazy_list(
+                1,
+                &state,
+                500.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| create_test_item(i, 50.0),
+            );
 
             assert_eq!(result.visible_items.len(), 1);
             assert_eq!(result.visible_items[0].index, 0);
@@ -330,9 +503,15 @@ mod tests {
             let config = LazyListMeasureConfig::default();
 
             // 10 items of 50px each, viewport of 200px should show 4+ items
-            let result = measure_lazy_list(10, &state, 200.0, 300.0, &config, |i| {
-                create_test_item(i, 50.0)
-            });
+            let result = measure_lazy_list(
+                10,
+                &state,
+                200.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| create_test_item(i, 50.0),
+            );
 
             // Should have visible items plus beyond-bounds buffer
             assert!(result.visible_items.len() >= 4);
@@ -347,9 +526,15 @@ mod tests {
             let state = new_lazy_list_state_with_position(3, 25.0);
             let config = LazyListMeasureConfig::default();
 
-            let result = measure_lazy_list(20, &state, 200.0, 300.0, &config, |i| {
-                create_test_item(i, 50.0)
-            });
+            let result = measure_lazy_list(
+                20,
+                &state,
+                200.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| create_test_item(i, 50.0),
+            );
 
             assert_eq!(result.first_visible_item_index, 3);
             assert!(result.can_scroll_forward);
@@ -364,13 +549,21 @@ mod tests {
             state.dispatch_scroll_delta(1.0);
             let config = LazyListMeasureConfig::default();
 
-            let result = measure_lazy_list(2, &state, 100.0, 300.0, &config, |i| {
-                if i == 0 {
-                    create_test_item(i, 10.0)
-                } else {
-                    create_test_item(i, 100.0)
-                }
-            });
+            let result = measure_lazy_list(
+                2,
+                &state,
+                100.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| {
+                    if i == 0 {
+                        create_test_item(i, 10.0)
+                    } else {
+                        create_test_item(i, 100.0)
+                    }
+                },
+            );
 
             assert_eq!(result.first_visible_item_index, 0);
             assert!((result.first_visible_item_scroll_offset - 9.0).abs() < 0.001);
@@ -387,15 +580,339 @@ mod tests {
             };
             state.dispatch_scroll_delta(2.0);
 
-            let result = measure_lazy_list(2, &state, 40.0, 300.0, &config, |i| {
-                create_test_item(i, 50.0)
-            });
+            let result = measure_lazy_list(
+                2,
+                &state,
+                40.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| create_test_item(i, 50.0),
+            );
 
             assert_eq!(result.first_visible_item_index, 0);
             assert!((result.first_visible_item_scroll_offset - 52.0).abs() < 0.001);
         });
     }
 
+    #[test]
+    fn test_spaced_items_start_after_content_padding_while_scrolled() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state_with_position(3, 10.0);
+            let config = LazyListMeasureConfig {
+                before_content_padding: 16.0,
+                after_content_padding: 16.0,
+                spacing: 8.0,
+                ..Default::default()
+            };
+
+            let result = measure_lazy_list(
+                20,
+                &state,
+                200.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| create_test_item(i, 50.0),
+            );
+
+            // Item k starts at padding + k * (size + spacing) - scroll distance
+            let scrolled = 3.0 * 58.0 + 10.0;
+            for item in &result.visible_items {
+                let expected = 16.0 + item.index as f32 * 58.0 - scrolled;
+                assert!(
+                    (item.offset - expected).abs() < 0.001,
+                    "item {} at {} expected {}",
+                    item.index,
+                    item.offset,
+                    expected
+                );
+            }
+            assert_eq!(result.first_visible_item_index, 3);
+            assert!((result.first_visible_item_scroll_offset - 10.0).abs() < 0.001);
+        });
+    }
+
+    #[test]
+    fn test_max_scroll_exposes_after_content_padding() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            let config = LazyListMeasureConfig {
+                before_content_padding: 16.0,
+                after_content_padding: 16.0,
+                ..Default::default()
+            };
+            let measure = |state: &LazyListState| {
+                measure_lazy_list(
+                    10,
+                    state,
+                    200.0,
+                    300.0,
+                    &config,
+                    |i| i as u64,
+                    |i| create_test_item(i, 50.0),
+                )
+            };
+
+            // Fling far past the end
+            state.dispatch_scroll_delta(-10_000.0);
+            let result = measure(&state);
+
+            let last = result.visible_items.last().unwrap();
+            assert_eq!(last.index, 9);
+            assert_eq!(last.offset + last.main_axis_size, 200.0 - 16.0);
+            assert!(!result.can_scroll_forward);
+            // Content above the last items is laid out up to the top padding
+            assert_eq!(result.first_visible_item_index, 6);
+            assert!((result.first_visible_item_scroll_offset - 32.0).abs() < 0.001);
+            let first = result
+                .visible_items
+                .iter()
+                .find(|item| item.index == 6)
+                .unwrap();
+            assert_eq!(first.offset, -16.0);
+
+            // The resting position is stable across measure passes
+            let result = measure(&state);
+            let last = result.visible_items.last().unwrap();
+            assert_eq!(last.offset + last.main_axis_size, 184.0);
+
+            // Scrolling back moves the full after-padding gap below the last item
+            state.dispatch_scroll_delta(10.0);
+            let result = measure(&state);
+            let last = result.visible_items.last().unwrap();
+            assert_eq!(last.offset + last.main_axis_size, 194.0);
+            assert!(result.can_scroll_forward);
+        });
+    }
+
+    #[test]
+    fn test_total_content_size_counts_padding_once() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            let config = LazyListMeasureConfig {
+                before_content_padding: 16.0,
+                after_content_padding: 24.0,
+                spacing: 8.0,
+                ..Default::default()
+            };
+
+            let result = measure_lazy_list(
+                10,
+                &state,
+                200.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| create_test_item(i, 50.0),
+            );
+
+            // 16 + 10 * 50 + 9 * 8 + 24
+            assert_eq!(result.total_content_size, 612.0);
+        });
+    }
+
+    #[test]
+    fn test_total_content_size_sums_cached_sizes() {
+        let config = LazyListMeasureConfig {
+            spacing: 4.0,
+            ..Default::default()
+        };
+        let measured = vec![create_test_item(10, 48.0), create_test_item(11, 56.0)];
+        // Key 11 is also measured this pass
+        let cached = HashMap::from([(0, 64.0), (3, 80.0), (11, 1000.0)]);
+
+        let total = estimate_total_content_size(20, &measured, &config, &cached, 48.0);
+
+        // Exact sizes for 4 known items, their average (62) for the other 16
+        let expected = (48.0 + 56.0 + 64.0 + 80.0) + 62.0 * 16.0 + 4.0 * 19.0;
+        assert_eq!(total, expected);
+
+        // Stale sizes of removed items never outnumber the unmeasured items
+        let total = estimate_total_content_size(3, &measured, &config, &cached, 48.0);
+        assert_eq!(total, 48.0 + 56.0 + 72.0 + 4.0 * 2.0);
+    }
+
+    #[test]
+    fn test_known_viewport_measures_only_window_items() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            for index in 0..10 {
+                state.cache_item_size(index as u64, 50.0);
+            }
+            let config = LazyListMeasureConfig {
+                beyond_bounds_item_count: 0,
+                known_viewport: Some(320.0..480.0),
+                ..Default::default()
+            };
+            let mut measured = Vec::new();
+
+            let result = measure_lazy_list(
+                100,
+                &state,
+                f32::INFINITY,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| {
+                    measured.push(i);
+                    create_test_item(i, 50.0)
+                },
+            );
+
+            // Items 6..=9 span 300..500 and overlap the window
+            assert_eq!(measured, vec![6, 7, 8, 9]);
+            let offsets: Vec<f32> = result.visible_items.iter().map(|i| i.offset).collect();
+            assert_eq!(offsets, vec![300.0, 350.0, 400.0, 450.0]);
+            assert_eq!(result.total_content_size, 5000.0);
+            assert_eq!(state.first_visible_item_index(), 6);
+            assert!(!result.can_scroll_forward);
+        });
+    }
+
+    #[test]
+    fn test_known_viewport_walks_from_the_previous_window() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            let key_lookups = std::cell::Cell::new(0);
+            let measure_window = |window: Range<f32>| {
+                let config = LazyListMeasureConfig {
+                    beyond_bounds_item_count: 0,
+                    known_viewport: Some(window),
+                    ..Default::default()
+                };
+                key_lookups.set(0);
+                measure_lazy_list(
+                    10_000,
+                    &state,
+                    f32::INFINITY,
+                    300.0,
+                    &config,
+                    |i| {
+                        key_lookups.set(key_lookups.get() + 1);
+                        i as u64
+                    },
+                    |i| {
+                        state.cache_item_size(i as u64, 50.0);
+                        create_test_item(i, 50.0)
+                    },
+                )
+            };
+
+            // Items before the window were never measured, so their offsets are estimated
+            let result = measure_window(400_000.0..400_160.0);
+            let first = result.visible_items[0].index;
+            let start = result.visible_items[0].offset;
+
+            // Scrolling a few items only walks over those items
+            let result = measure_window(start + 500.0..start + 660.0);
+            assert_eq!(state.first_visible_item_index(), first + 10);
+            assert_eq!(result.visible_items[0].offset, start + 500.0);
+            assert!(
+                key_lookups.get() < 20,
+                "{} keys looked up",
+                key_lookups.get()
+            );
+
+            let result = measure_window(0.0..160.0);
+            assert_eq!(state.first_visible_item_index(), 0);
+            assert_eq!(result.visible_items[0].offset, 0.0);
+        });
+    }
+
+    #[test]
+    fn test_cached_sizes_follow_keys_across_inserts() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            let config = LazyListMeasureConfig::default();
+            let size_of = |key: u64| {
+                if key == 999 {
+                    100.0
+                } else {
+                    40.0 + (key % 3) as f32 * 10.0
+                }
+            };
+            // Measures like the widget does, caching sizes of the measured items
+            let measure = |keys: &[u64]| {
+                let result = measure_lazy_list(
+                    keys.len(),
+                    &state,
+                    200.0,
+                    300.0,
+                    &config,
+                    |i| keys[i],
+                    |i| LazyListMeasuredItem::new(i, keys[i], None, size_of(keys[i]), 100.0),
+                );
+                for item in &result.visible_items {
+                    state.cache_item_size(item.key, item.main_axis_size);
+                }
+            };
+
+            let keys: Vec<u64> = (0..20).collect();
+            measure(&keys);
+            // Insert a taller item before the first visible one
+            let inserted: Vec<u64> = std::iter::once(999).chain(keys.iter().copied()).collect();
+            measure(&inserted);
+
+            for key in keys.iter().take(5) {
+                assert_eq!(state.get_cached_size(*key), Some(size_of(*key)));
+            }
+            assert_eq!(state.get_cached_size(999), Some(100.0));
+        });
+    }
+
+    #[test]
+    fn test_unknown_infinite_viewport_warns_once_and_uses_fallback_window() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            let config = LazyListMeasureConfig {
+                beyond_bounds_item_count: 0,
+                ..Default::default()
+            };
+
+            for _ in 0..3 {
+                let result = measure_lazy_list(
+                    100,
+                    &state,
+                    f32::INFINITY,
+                    300.0,
+                    &config,
+                    |i| i as u64,
+                    |i| create_test_item(i, 50.0),
+                );
+                // Fallback window is 20 items of the 50px average
+                assert_eq!(result.visible_items.len(), 20);
+            }
+
+            assert!(state.stats().warned_infinite_viewport);
+            // Already warned, so later passes stay quiet
+            assert!(!state.record_infinite_viewport());
+        });
+    }
+
+    #[test]
+    fn test_duplicate_key_is_reported_once_when_enabled() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            let mut content = LazyListIntervalContent::new();
+            content.items(3, Some(|_| 7u64), None::<fn(usize) -> u64>, |_| {});
+
+            assert_eq!(state.report_duplicate_key(&content), None);
+
+            state.set_check_duplicate_keys(true);
+            assert_eq!(
+                state.report_duplicate_key(&content),
+                Some(DuplicateLazyKey {
+                    key: 7,
+                    first_index: 0,
+                    duplicate_index: 1,
+                })
+            );
+            assert_eq!(state.report_duplicate_key(&content), None);
+            assert!(state.stats().warned_duplicate_key);
+        });
+    }
+
     #[test]
     fn test_scroll_to_item() {
         with_test_runtime(|| {
@@ -403,11 +920,68 @@ mod tests {
             state.scroll_to_item(5, 0.0);
 
             let config = LazyListMeasureConfig::default();
-            let result = measure_lazy_list(20, &state, 200.0, 300.0, &config, |i| {
-                create_test_item(i, 50.0)
-            });
+            let result = measure_lazy_list(
+                20,
+                &state,
+                200.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| create_test_item(i, 50.0),
+            );
 
             assert_eq!(result.first_visible_item_index, 5);
         });
     }
+
+    /// Scrolls forward by `distance` items from index 1000 and returns how many
+    /// items the pass measured.
+    fn measure_scroll_by(state: &LazyListState, distance: usize) -> usize {
+        let config = LazyListMeasureConfig::default();
+        let mut measured = 0;
+        state.scroll_to_item(1_000, 0.0);
+        state.dispatch_scroll_delta(-(distance as f32) * 48.0);
+        measure_lazy_list(
+            10_000,
+            state,
+            480.0,
+            300.0,
+            &config,
+            |i| i as u64,
+            |i| {
+                measured += 1;
+                state.cache_item_size(i as u64, 48.0);
+                create_test_item(i, 48.0)
+            },
+        );
+        measured
+    }
+
+    #[test]
+    fn test_scroll_cost_does_not_grow_with_distance() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            measure_scroll_by(&state, 0);
+
+            let near_measured = measure_scroll_by(&state, 5);
+            let far_measured = measure_scroll_by(&state, 8_000);
+
+            // At most two viewports (10 items each) plus the beyond-bounds buffer are
+            // measured, however far the jump
+            assert!(
+                near_measured <= 30,
+                "near scroll measured {near_measured} items"
+            );
+            assert!(
+                far_measured <= 30,
+                "far scroll measured {far_measured} items"
+            );
+            assert_eq!(state.first_visible_item_index(), 9_000);
+            // A pass linear in the distance would measure the ~8000 skipped items
+            assert!(
+                far_measured <= near_measured + 10,
+                "far scroll measured {far_measured} items, near scroll {near_measured}"
+            );
+        });
+    }
 }
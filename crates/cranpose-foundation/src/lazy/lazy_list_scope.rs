@@ -5,7 +5,7 @@
 //!
 //! Based on JC's `LazyLayoutIntervalContent` pattern.
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -195,6 +195,21 @@ impl std::fmt::Debug for LazyListInterval {
     }
 }
 
+/// Two items of a lazy list that share a user key.
+///
+/// Keys identify items across data changes, so a repeated key makes key
+/// lookups resolve to the first item and lets the later one take over its
+/// state when the list is reordered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DuplicateLazyKey {
+    /// The repeated user key.
+    pub key: u64,
+    /// Index of the first item with the key.
+    pub first_index: usize,
+    /// Index of the later item that reuses it.
+    pub duplicate_index: usize,
+}
+
 /// Builder that collects intervals during scope execution.
 ///
 /// Based on JC's `LazyLayoutIntervalContent` with `IntervalList`.
@@ -204,6 +219,9 @@ pub struct LazyListIntervalContent {
     /// Cached slot_id→index mapping for O(1) key lookups.
     /// Built lazily on first key lookup, invalidated when content changes.
     key_cache: RefCell<Option<HashMap<u64, usize>>>,
+    /// Slot ID the last full expanding search failed to find.
+    /// Lets later passes skip the scan, invalidated when content changes.
+    missing_slot_id: Cell<Option<u64>>,
 }
 
 impl LazyListIntervalContent {
@@ -213,12 +231,14 @@ impl LazyListIntervalContent {
             intervals: Vec::new(),
             total_count: 0,
             key_cache: RefCell::new(None),
+            missing_slot_id: Cell::new(None),
         }
     }
 
-    /// Invalidates the key cache. Called when content is modified.
+    /// Invalidates the key lookup caches. Called when content is modified.
     fn invalidate_cache(&self) {
         *self.key_cache.borrow_mut() = None;
+        self.missing_slot_id.set(None);
     }
 
     /// Builds the key→index cache for O(1) lookups.
@@ -235,7 +255,8 @@ impl LazyListIntervalContent {
         let mut map = HashMap::with_capacity(self.total_count);
         for index in 0..self.total_count {
             let slot_id = self.get_key(index).to_slot_id();
-            map.insert(slot_id, index);
+            // The first item keeps a repeated key, matching the linear search
+            map.entry(slot_id).or_insert(index);
         }
         *cache = Some(map);
     }
@@ -305,8 +326,9 @@ impl LazyListIntervalContent {
     /// the scroll position can be maintained by finding the new index of the
     /// item that was previously at the scroll position (identified by key).
     ///
-    /// Uses cached HashMap for O(1) lookup when the list has <= 10000 items.
-    /// For larger lists, use [`get_index_by_key_in_range`] with a [`NearestRangeState`].
+    /// Uses a cached HashMap for O(1) lookup, built on first use after the content
+    /// changes. When a nearby position is known, [`get_index_by_slot_id_near`]
+    /// avoids building the cache for huge lists.
     #[must_use]
     pub fn get_index_by_key(&self, key: LazyLayoutKey) -> Option<usize> {
         // Convert key to slot_id and use the cache
@@ -370,6 +392,76 @@ impl LazyListIntervalContent {
         (start..end).find(|&index| self.get_key(index).to_slot_id() == slot_id)
     }
 
+    /// Returns the index of an item with the given slot ID, searching outward from `range`.
+    ///
+    /// The range is searched first, then the search widens on both sides, doubling
+    /// the margin each step until the key is found or the whole list is covered.
+    /// Items that moved a short distance are found in time proportional to that
+    /// distance, without building the key cache. Pass the [`NearestRangeState`] range.
+    /// A key the whole list lacks is only scanned for once per content change.
+    ///
+    /// [`NearestRangeState`]: crate::lazy::NearestRangeState
+    pub fn get_index_by_slot_id_near(
+        &self,
+        slot_id: u64,
+        range: std::ops::Range<usize>,
+    ) -> Option<usize> {
+        if self.missing_slot_id.get() == Some(slot_id) {
+            return None;
+        }
+        let matches = |index: &usize| self.get_key(*index).to_slot_id() == slot_id;
+        let mut start = range.start.min(self.total_count);
+        let mut end = range.end.min(self.total_count).max(start);
+        if let Some(index) = (start..end).find(matches) {
+            return Some(index);
+        }
+
+        let mut margin = (end - start).max(1);
+        while start > 0 || end < self.total_count {
+            let next_start = start.saturating_sub(margin);
+            let next_end = end.saturating_add(margin).min(self.total_count);
+            if let Some(index) = (next_start..start).rev().find(matches) {
+                return Some(index);
+            }
+            if let Some(index) = (end..next_end).find(matches) {
+                return Some(index);
+            }
+            start = next_start;
+            end = next_end;
+            margin = margin.saturating_mul(2);
+        }
+        self.missing_slot_id.set(Some(slot_id));
+        None
+    }
+
+    /// Returns the first user key given to more than one item, if any.
+    ///
+    /// Items without a key function use their index as key and never collide.
+    /// Checking walks every keyed item, so call it from diagnostics or tests,
+    /// e.g. `debug_assert_eq!(content.find_duplicate_key(), None)`. Lists run
+    /// it when enabled with [`LazyListState::set_check_duplicate_keys`](super::LazyListState::set_check_duplicate_keys).
+    pub fn find_duplicate_key(&self) -> Option<DuplicateLazyKey> {
+        let mut seen = HashMap::new();
+        for interval in &self.intervals {
+            let Some(key_fn) = &interval.key else {
+                continue;
+            };
+            for local_index in 0..interval.count {
+                let index = interval.start_index + local_index;
+                let key = key_fn(local_index);
+                if let Some(&first_index) = seen.get(&key) {
+                    return Some(DuplicateLazyKey {
+                        key,
+                        first_index,
+                        duplicate_index: index,
+                    });
+                }
+                seen.insert(key, index);
+            }
+        }
+        None
+    }
+
     /// Finds the interval containing the given global index.
     /// Returns the interval and the local index within it.
     /// P2 FIX: Uses binary search for O(log n) instead of linear O(n).
@@ -746,7 +838,6 @@ impl<T: LazyListScope + ?Sized> LazyListScopeExt for T {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::cell::Cell;
 
     #[test]
     fn test_single_item() {
@@ -1134,4 +1225,115 @@ mod tests {
         let found_mid = content.get_index_by_slot_id(slot_id_mid);
         assert_eq!(found_mid, Some(10000));
     }
+
+    #[test]
+    fn test_large_list_key_just_outside_nearest_range_is_found() {
+        let mut content = LazyListIntervalContent::new();
+        content.items(
+            20_000,
+            Some(|i| (i * 7) as u64),
+            None::<fn(usize) -> u64>,
+            |_| {},
+        );
+
+        let range = crate::lazy::NearestRangeState::new(10_000).range();
+        let outside = range.end + 5;
+        let slot_id = content.get_key(outside).to_slot_id();
+        assert_eq!(
+            content.get_index_by_slot_id_in_range(slot_id, range.clone()),
+            None
+        );
+        assert_eq!(
+            content.get_index_by_slot_id_near(slot_id, range.clone()),
+            Some(outside)
+        );
+
+        let before = range.start - 1;
+        let slot_id = content.get_key(before).to_slot_id();
+        assert_eq!(
+            content.get_index_by_slot_id_near(slot_id, range.clone()),
+            Some(before)
+        );
+
+        // Far items and the list ends are still reached; missing keys return None
+        let slot_id = content.get_key(0).to_slot_id();
+        assert_eq!(
+            content.get_index_by_slot_id_near(slot_id, range.clone()),
+            Some(0)
+        );
+        let slot_id = content.get_key(19_999).to_slot_id();
+        assert_eq!(
+            content.get_index_by_slot_id_near(slot_id, range.clone()),
+            Some(19_999)
+        );
+        let missing = LazyLayoutKey::User(3).to_slot_id();
+        assert_eq!(content.get_index_by_slot_id_near(missing, range), None);
+        // The expanding search does not build the key cache
+        assert!(content.key_cache.borrow().is_none());
+    }
+
+    #[test]
+    fn test_missing_key_is_searched_once_per_content_change() {
+        let key_calls = Rc::new(Cell::new(0));
+        let counter = key_calls.clone();
+        let mut content = LazyListIntervalContent::new();
+        content.items(
+            20_000,
+            Some(move |i| {
+                counter.set(counter.get() + 1);
+                i as u64
+            }),
+            None::<fn(usize) -> u64>,
+            |_| {},
+        );
+        let missing = LazyLayoutKey::User(50_000).to_slot_id();
+
+        assert_eq!(content.get_index_by_slot_id_near(missing, 100..200), None);
+        assert_eq!(key_calls.get(), 20_000);
+
+        // Later passes skip the full scan until the content changes
+        assert_eq!(content.get_index_by_slot_id_near(missing, 100..200), None);
+        assert_eq!(key_calls.get(), 20_000);
+
+        content.item(Some(50_000), None, || {});
+        assert_eq!(
+            content.get_index_by_slot_id_near(missing, 100..200),
+            Some(20_000)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_user_keys_are_reported() {
+        let mut content = LazyListIntervalContent::new();
+        content.item(None, None, || {});
+        content.items(100, Some(|i| i as u64), None::<fn(usize) -> u64>, |_| {});
+        content.item(Some(7), None, || {});
+        content.items(
+            3,
+            None::<fn(usize) -> u64>,
+            None::<fn(usize) -> u64>,
+            |_| {},
+        );
+
+        assert_eq!(
+            content.find_duplicate_key(),
+            Some(DuplicateLazyKey {
+                key: 7,
+                first_index: 8,
+                duplicate_index: 101,
+            })
+        );
+        // Lookups agree on the first item for large lists too
+        assert_eq!(content.get_index_by_key(LazyLayoutKey::User(7)), Some(8));
+
+        let mut unique = LazyListIntervalContent::new();
+        unique.items(100, Some(|i| i as u64), None::<fn(usize) -> u64>, |_| {});
+        unique.items(
+            100,
+            None::<fn(usize) -> u64>,
+            None::<fn(usize) -> u64>,
+            |_| {},
+        );
+        assert_eq!(unique.find_duplicate_key(), None);
+    }
 }
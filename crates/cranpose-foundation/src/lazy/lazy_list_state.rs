@@ -12,9 +12,11 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use cranpose_core::MutableState;
+use cranpose_core::{MutableState, Saver};
 use cranpose_macros::composable;
 
+use super::lazy_list_scope::{DuplicateLazyKey, LazyLayoutKey, LazyListIntervalContent};
+use super::lazy_staggered_grid_measure::LaneInfo;
 use super::nearest_range::NearestRangeState;
 use super::prefetch::{PrefetchScheduler, PrefetchStrategy};
 
@@ -34,6 +36,15 @@ pub struct LazyLayoutStats {
 
     /// Number of items that were reused instead of newly composed.
     pub reuse_count: usize,
+
+    /// Whether the list has warned about an unbounded viewport.
+    pub warned_infinite_viewport: bool,
+
+    /// Whether the list has reported a duplicate user key.
+    pub warned_duplicate_key: bool,
+
+    /// Whether the list has warned that its reuse pool overflowed.
+    pub warned_pool_overflow: bool,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -62,6 +73,8 @@ struct ScrollPositionInner {
     /// The last known key of the item at index position.
     /// Used for scroll position stability across data changes.
     last_known_first_item_key: Option<u64>,
+    /// Key the next layout pass should bring to the top, if it is present.
+    requested_key: Option<u64>,
     /// Sliding window range for optimized key lookups.
     nearest_range_state: NearestRangeState,
 }
@@ -127,6 +140,12 @@ impl LazyListScrollPosition {
         });
     }
 
+    /// Asks the next layout pass to bring the item with `key` to the top.
+    pub(crate) fn request_key(&self, key: u64) {
+        self.inner
+            .with(|rc| rc.borrow_mut().requested_key = Some(key));
+    }
+
     /// Adjusts scroll position if the first visible item was moved.
     /// Returns the adjusted index.
     pub(crate) fn update_if_first_item_moved<F>(
@@ -137,6 +156,12 @@ impl LazyListScrollPosition {
     where
         F: Fn(u64) -> Option<usize>,
     {
+        let requested_key = self.inner.with(|rc| rc.borrow_mut().requested_key.take());
+        if let Some(index) = requested_key.and_then(&find_by_key) {
+            self.request_position_and_forget_last_known_key(index, 0.0);
+            return index;
+        }
+
         let current_index = self.index.get();
         let last_key = self.inner.with(|rc| rc.borrow().last_known_first_item_key);
 
@@ -160,6 +185,19 @@ impl LazyListScrollPosition {
         self.inner
             .with(|rc| rc.borrow().nearest_range_state.range())
     }
+
+    /// Changes the window sizes used to compute the nearest range.
+    pub(crate) fn set_nearest_range_sizes(
+        &self,
+        sliding_window_size: usize,
+        extra_item_count: usize,
+    ) {
+        self.inner.with(|rc| {
+            rc.borrow_mut()
+                .nearest_range_state
+                .set_sizes(sliding_window_size, extra_item_count);
+        });
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -241,11 +279,17 @@ struct LazyListStateInner {
     /// Diagnostic counters (non-reactive - not typically displayed in UI).
     total_composed: usize,
     reuse_count: usize,
+    warned_infinite_viewport: bool,
+    warned_duplicate_key: bool,
+    warned_pool_overflow: bool,
+
+    /// Whether compositions check the user keys for duplicates.
+    check_duplicate_keys: bool,
 
-    /// Cache of recently measured item sizes (index -> main_axis_size).
-    item_size_cache: std::collections::HashMap<usize, f32>,
+    /// Cache of recently measured item sizes (item key -> main_axis_size).
+    item_size_cache: std::collections::HashMap<u64, f32>,
     /// LRU order tracking - front is oldest, back is newest.
-    item_size_lru: std::collections::VecDeque<usize>,
+    item_size_lru: std::collections::VecDeque<u64>,
 
     /// Running average of measured item sizes for estimation.
     average_item_size: f32,
@@ -259,6 +303,12 @@ struct LazyListStateInner {
 
     /// Last scroll delta direction for prefetch.
     last_scroll_direction: f32,
+
+    /// Lanes and offsets of the items a staggered grid has walked.
+    staggered_lanes: LaneInfo,
+
+    /// Index and content offset of the first item measured in a known window.
+    known_window_anchor: Option<(usize, f32)>,
 }
 
 /// Creates a remembered [`LazyListState`] with default initial position.
@@ -295,6 +345,7 @@ pub fn remember_lazy_list_state_with_position(
         inner: cranpose_core::useState(|| {
             Rc::new(RefCell::new(ScrollPositionInner {
                 last_known_first_item_key: None,
+                requested_key: None,
                 nearest_range_state: NearestRangeState::new(initial_first_visible_item_index),
             }))
         }),
@@ -311,6 +362,10 @@ pub fn remember_lazy_list_state_with_position(
             has_layout_invalidation_callback: false,
             total_composed: 0,
             reuse_count: 0,
+            warned_infinite_viewport: false,
+            warned_duplicate_key: false,
+            warned_pool_overflow: false,
+            check_duplicate_keys: false,
             item_size_cache: std::collections::HashMap::new(),
             item_size_lru: std::collections::VecDeque::new(),
             average_item_size: super::DEFAULT_ITEM_SIZE_ESTIMATE,
@@ -318,6 +373,8 @@ pub fn remember_lazy_list_state_with_position(
             prefetch_scheduler: PrefetchScheduler::new(),
             prefetch_strategy: PrefetchStrategy::default(),
             last_scroll_direction: 0.0,
+            staggered_lanes: LaneInfo::default(),
+            known_window_anchor: None,
         }))
     });
 
@@ -335,6 +392,43 @@ pub fn remember_lazy_list_state_with_position(
     }
 }
 
+/// Creates a remembered [`LazyListState`] whose scroll position is saved under `key`.
+///
+/// The position comes back from the nearest
+/// [`SaveableStateRegistry`](cranpose_core::SaveableStateRegistry) when the UI
+/// is recreated; without a registry this behaves like [`remember_lazy_list_state`].
+///
+/// Matches Kotlin: `rememberLazyListState()`, which is saveable by default.
+pub fn remember_saveable_lazy_list_state(key: &str) -> LazyListState {
+    let (index, offset) = cranpose_core::remember(|| {
+        cranpose_core::consume_restored(key).and_then(|saved| ScrollPositionSaver.restore(&saved))
+    })
+    .with(|restored| restored.unwrap_or((0, 0.0)));
+    let state = remember_lazy_list_state_with_position(index, offset);
+    cranpose_core::remember_save_provider(key, move || {
+        let position = state.scroll_position;
+        ScrollPositionSaver.save(&(
+            position.index.get_non_reactive(),
+            position.scroll_offset.get_non_reactive(),
+        ))
+    });
+    state
+}
+
+/// Saves a first visible item index and offset as `"index,offset"`.
+struct ScrollPositionSaver;
+
+impl Saver<(usize, f32)> for ScrollPositionSaver {
+    fn save(&self, &(index, offset): &(usize, f32)) -> Option<String> {
+        Some(format!("{index},{offset}"))
+    }
+
+    fn restore(&self, saved: &str) -> Option<(usize, f32)> {
+        let (index, offset) = saved.split_once(',')?;
+        Some((index.parse().ok()?, offset.parse().ok()?))
+    }
+}
+
 impl LazyListState {
     /// Returns a pointer to the inner state for unique identification.
     /// Used by scroll gesture detection to create unique keys.
@@ -374,15 +468,30 @@ impl LazyListState {
     pub fn stats(&self) -> LazyLayoutStats {
         // Read reactive state (creates subscription) and combine with non-reactive counters
         let reactive = self.stats_state.get();
-        let (total_composed, reuse_count) = self.inner.with(|rc| {
+        let (
+            total_composed,
+            reuse_count,
+            warned_infinite_viewport,
+            warned_duplicate_key,
+            warned_pool_overflow,
+        ) = self.inner.with(|rc| {
             let inner = rc.borrow();
-            (inner.total_composed, inner.reuse_count)
+            (
+                inner.total_composed,
+                inner.reuse_count,
+                inner.warned_infinite_viewport,
+                inner.warned_duplicate_key,
+                inner.warned_pool_overflow,
+            )
         });
         LazyLayoutStats {
             items_in_use: reactive.items_in_use,
             items_in_pool: reactive.items_in_pool,
             total_composed,
             reuse_count,
+            warned_infinite_viewport,
+            warned_duplicate_key,
+            warned_pool_overflow,
         }
     }
 
@@ -436,6 +545,85 @@ impl LazyListState {
         });
     }
 
+    /// Records that a measure pass fell back to an estimated viewport.
+    ///
+    /// Returns `true` only the first time, so the list warns once rather than
+    /// on every frame. Does NOT trigger recomposition.
+    pub(crate) fn record_infinite_viewport(&self) -> bool {
+        self.inner.with(|rc| {
+            let mut inner = rc.borrow_mut();
+            !std::mem::replace(&mut inner.warned_infinite_viewport, true)
+        })
+    }
+
+    /// Records that the reuse pool dropped items because it was full.
+    ///
+    /// Returns `true` only the first time, so the list warns once rather than
+    /// on every frame. Does NOT trigger recomposition.
+    pub fn record_pool_overflow(&self) -> bool {
+        self.inner.with(|rc| {
+            let mut inner = rc.borrow_mut();
+            !std::mem::replace(&mut inner.warned_pool_overflow, true)
+        })
+    }
+
+    /// Enables checking the list's user keys for duplicates on each composition.
+    ///
+    /// Off by default since the check walks every keyed item. Useful while
+    /// developing, e.g. `state.set_check_duplicate_keys(cfg!(debug_assertions))`.
+    pub fn set_check_duplicate_keys(&self, enabled: bool) {
+        self.inner
+            .with(|rc| rc.borrow_mut().check_duplicate_keys = enabled);
+    }
+
+    /// Returns the first user key shared by two items of `content`.
+    ///
+    /// Only checks when enabled with [`Self::set_check_duplicate_keys`], and
+    /// reports at most one duplicate per state so the list logs it once.
+    pub fn report_duplicate_key(
+        &self,
+        content: &LazyListIntervalContent,
+    ) -> Option<DuplicateLazyKey> {
+        let should_check = self.inner.with(|rc| {
+            let inner = rc.borrow();
+            inner.check_duplicate_keys && !inner.warned_duplicate_key
+        });
+        if !should_check {
+            return None;
+        }
+        let duplicate = content.find_duplicate_key()?;
+        self.inner
+            .with(|rc| rc.borrow_mut().warned_duplicate_key = true);
+        Some(duplicate)
+    }
+
+    /// Takes the staggered grid lane assignments, leaving none behind.
+    ///
+    /// Taken for the duration of a measure pass so composing items cannot
+    /// observe a borrowed state; put back with [`Self::set_staggered_lanes`].
+    pub(crate) fn take_staggered_lanes(&self) -> LaneInfo {
+        self.inner
+            .with(|rc| std::mem::take(&mut rc.borrow_mut().staggered_lanes))
+    }
+
+    /// Stores the staggered grid lane assignments for the next measure pass.
+    pub(crate) fn set_staggered_lanes(&self, lanes: LaneInfo) {
+        self.inner
+            .with(|rc| rc.borrow_mut().staggered_lanes = lanes);
+    }
+
+    /// Returns where the last known-window pass started measuring, as the
+    /// item index and its offset in the list's content coordinates.
+    pub(crate) fn known_window_anchor(&self) -> Option<(usize, f32)> {
+        self.inner.with(|rc| rc.borrow().known_window_anchor)
+    }
+
+    /// Stores where a known-window pass started measuring.
+    pub(crate) fn set_known_window_anchor(&self, anchor: (usize, f32)) {
+        self.inner
+            .with(|rc| rc.borrow_mut().known_window_anchor = Some(anchor));
+    }
+
     /// Records the scroll direction for prefetch calculations.
     /// Positive = scrolling forward (content moving up), negative = backward.
     pub fn record_scroll_direction(&self, delta: f32) {
@@ -499,6 +687,19 @@ impl LazyListState {
         self.invalidate();
     }
 
+    /// Scrolls so the item with the user `key` is first on the next layout pass.
+    ///
+    /// Use it to re-anchor the list after its data is replaced: once the
+    /// previously first visible key is gone, the list would otherwise only
+    /// keep its index. The request wins over key-based position restoration
+    /// for that pass; if no item has `key`, it is dropped and the list
+    /// restores its position as usual.
+    pub fn request_scroll_to_key(&self, key: u64) {
+        self.scroll_position
+            .request_key(LazyLayoutKey::User(key).to_slot_id());
+        self.invalidate();
+    }
+
     /// Dispatches a raw scroll delta.
     ///
     /// Returns the amount of scroll actually consumed.
@@ -547,6 +748,9 @@ impl LazyListState {
 
     /// Caches the measured size of an item for scroll estimation.
     ///
+    /// Sizes are keyed by the item's key (`LazyListMeasuredItem::key`), so they
+    /// stay with their item when items are inserted or removed before it.
+    ///
     /// Uses a HashMap + VecDeque LRU pattern with O(1) insertion and eviction.
     /// Re-measurement of existing items (uncommon during normal scrolling)
     /// requires O(n) VecDeque position lookup, but the cache is small (100 items).
@@ -554,21 +758,21 @@ impl LazyListState {
     /// # Performance Note
     /// If profiling shows this as a bottleneck, consider using the `lru` crate
     /// for O(1) update-in-place operations, or a linked hash map.
-    pub fn cache_item_size(&self, index: usize, size: f32) {
+    pub fn cache_item_size(&self, key: u64, size: f32) {
         use std::collections::hash_map::Entry;
         self.inner.with(|rc| {
             let mut inner = rc.borrow_mut();
             const MAX_CACHE_SIZE: usize = 100;
 
             // Check if already in cache (update existing)
-            if let Entry::Occupied(mut entry) = inner.item_size_cache.entry(index) {
+            if let Entry::Occupied(mut entry) = inner.item_size_cache.entry(key) {
                 // Update value and move to back of LRU
                 entry.insert(size);
                 // Remove old position from LRU (O(n) but rare - only on re-measurement)
-                if let Some(pos) = inner.item_size_lru.iter().position(|&k| k == index) {
+                if let Some(pos) = inner.item_size_lru.iter().position(|&k| k == key) {
                     inner.item_size_lru.remove(pos);
                 }
-                inner.item_size_lru.push_back(index);
+                inner.item_size_lru.push_back(key);
                 return;
             }
 
@@ -585,8 +789,8 @@ impl LazyListState {
             }
 
             // Add new entry
-            inner.item_size_cache.insert(index, size);
-            inner.item_size_lru.push_back(index);
+            inner.item_size_cache.insert(key, size);
+            inner.item_size_lru.push_back(key);
 
             // Update running average
             inner.total_measured_items += 1;
@@ -595,10 +799,18 @@ impl LazyListState {
         });
     }
 
-    /// Gets a cached item size if available.
-    pub fn get_cached_size(&self, index: usize) -> Option<f32> {
+    /// Gets the cached size of the item with `key` if available.
+    pub fn get_cached_size(&self, key: u64) -> Option<f32> {
         self.inner
-            .with(|rc| rc.borrow().item_size_cache.get(&index).copied())
+            .with(|rc| rc.borrow().item_size_cache.get(&key).copied())
+    }
+
+    /// Runs `f` with the cached item sizes (item key -> main_axis_size).
+    pub(crate) fn with_cached_item_sizes<R>(
+        &self,
+        f: impl FnOnce(&std::collections::HashMap<u64, f32>) -> R,
+    ) -> R {
+        self.inner.with(|rc| f(&rc.borrow().item_size_cache))
     }
 
     /// Returns the running average of measured item sizes.
@@ -612,6 +824,16 @@ impl LazyListState {
         self.scroll_position.nearest_range()
     }
 
+    /// Tunes the window used for key lookups when items are added or removed.
+    ///
+    /// Defaults to [`NEAREST_ITEMS_SLIDING_WINDOW_SIZE`](super::NEAREST_ITEMS_SLIDING_WINDOW_SIZE)
+    /// items plus [`NEAREST_ITEMS_EXTRA_COUNT`](super::NEAREST_ITEMS_EXTRA_COUNT) on each side.
+    /// Lookups start in this window and widen outward when the key is not found.
+    pub fn set_nearest_range_sizes(&self, sliding_window_size: usize, extra_item_count: usize) {
+        self.scroll_position
+            .set_nearest_range_sizes(sliding_window_size, extra_item_count);
+    }
+
     /// Updates the scroll position from a layout pass.
     ///
     /// Called by the layout after measurement.
@@ -671,7 +893,8 @@ impl LazyListState {
     /// Returns whether we can scroll forward (more items below/right).
     ///
     /// When called during composition, this creates a reactive subscription
-    /// so that changes will trigger recomposition.
+    /// so that changes will trigger recomposition. This makes it suitable for
+    /// scroll affordances such as a "more below" chevron or an edge fade.
     pub fn can_scroll_forward(&self) -> bool {
         self.can_scroll_forward_state.get()
     }
@@ -813,7 +1036,7 @@ pub struct LazyListItemInfo {
 }
 
 /// Test helpers for creating LazyListState without composition context.
-#[cfg(test)]
+#[cfg(any(test, feature = "test-helpers"))]
 pub mod test_helpers {
     use super::*;
     use cranpose_core::{DefaultScheduler, Runtime};
@@ -844,6 +1067,7 @@ pub mod test_helpers {
             scroll_offset: cranpose_core::mutableStateOf(initial_first_visible_item_scroll_offset),
             inner: cranpose_core::mutableStateOf(Rc::new(RefCell::new(ScrollPositionInner {
                 last_known_first_item_key: None,
+                requested_key: None,
                 nearest_range_state: NearestRangeState::new(initial_first_visible_item_index),
             }))),
         };
@@ -858,6 +1082,10 @@ pub mod test_helpers {
             has_layout_invalidation_callback: false,
             total_composed: 0,
             reuse_count: 0,
+            warned_infinite_viewport: false,
+            warned_duplicate_key: false,
+            warned_pool_overflow: false,
+            check_duplicate_keys: false,
             item_size_cache: std::collections::HashMap::new(),
             item_size_lru: std::collections::VecDeque::new(),
             average_item_size: super::super::DEFAULT_ITEM_SIZE_ESTIMATE,
@@ -865,6 +1093,8 @@ pub mod test_helpers {
             prefetch_scheduler: PrefetchScheduler::new(),
             prefetch_strategy: PrefetchStrategy::default(),
             last_scroll_direction: 0.0,
+            staggered_lanes: LaneInfo::default(),
+            known_window_anchor: None,
         })));
 
         // Reactive state
@@ -881,3 +1111,128 @@ pub mod test_helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::{LazyListIntervalContent, LazyListScope};
+    use cranpose_core::{
+        local_saveable_state_registry, location_key, Composition, CompositionLocalProvider,
+        MemoryApplier, SaveableStateRegistry,
+    };
+    use std::cell::Cell;
+    use std::collections::HashMap;
+
+    #[test]
+    fn saveable_lazy_list_state_restores_scroll_position() {
+        let restored = HashMap::from([("list".to_string(), "5,12.5".to_string())]);
+        let registry = SaveableStateRegistry::with_restored(restored);
+        let state = Rc::new(Cell::new(None));
+
+        let mut composition = Composition::new(MemoryApplier::new());
+        {
+            let registry = registry.clone();
+            let state = Rc::clone(&state);
+            composition
+                .render(location_key(file!(), line!(), column!()), move || {
+                    let state = Rc::clone(&state);
+                    CompositionLocalProvider(
+                        vec![local_saveable_state_registry().provides(Some(registry.clone()))],
+                        move || state.set(Some(remember_saveable_lazy_list_state("list"))),
+                    );
+                })
+                .expect("render succeeds");
+        }
+
+        let state = state.get().expect("state composed");
+        assert_eq!(state.first_visible_item_index(), 5);
+        assert_eq!(state.first_visible_item_scroll_offset(), 12.5);
+        assert_eq!(
+            registry.perform_save().get("list").map(String::as_str),
+            Some("5,12.5")
+        );
+    }
+
+    #[test]
+    fn requested_key_anchors_list_after_dataset_swap() {
+        test_helpers::with_test_runtime(|| {
+            let state = test_helpers::new_lazy_list_state();
+            let items_with_keys = |first_key: u64| {
+                let mut content = LazyListIntervalContent::new();
+                content.items(
+                    100,
+                    Some(move |i| first_key + i as u64),
+                    None::<fn(usize) -> u64>,
+                    |_| {},
+                );
+                content
+            };
+            let old = items_with_keys(0);
+            state.update_scroll_position_with_key(40, 12.0, old.get_key(40).to_slot_id());
+
+            // None of the old keys survive the swap
+            let new = items_with_keys(1_000);
+            state.request_scroll_to_key(1_070);
+            let index = state.update_scroll_position_if_item_moved(100, |slot_id| {
+                new.get_index_by_slot_id(slot_id)
+            });
+
+            assert_eq!(index, 70);
+            assert_eq!(state.first_visible_item_index(), 70);
+            assert_eq!(state.first_visible_item_scroll_offset(), 0.0);
+
+            // The request is used up by the pass that applied it
+            state.update_scroll_position_with_key(70, 0.0, new.get_key(70).to_slot_id());
+            let index = state.update_scroll_position_if_item_moved(100, |slot_id| {
+                new.get_index_by_slot_id(slot_id)
+            });
+            assert_eq!(index, 70);
+        });
+    }
+
+    #[composable]
+    fn can_scroll_forward_reader(state: LazyListState, reads: Rc<RefCell<Vec<bool>>>) {
+        reads.borrow_mut().push(state.can_scroll_forward());
+    }
+
+    #[test]
+    fn can_scroll_forward_read_in_composition_follows_scrolling() {
+        let state_slot = Rc::new(Cell::new(None));
+        let reads = Rc::new(RefCell::new(Vec::new()));
+        let mut composition = Composition::new(MemoryApplier::new());
+        {
+            let state_slot = Rc::clone(&state_slot);
+            let reads = Rc::clone(&reads);
+            composition
+                .render(location_key(file!(), line!(), column!()), move || {
+                    let state = remember_lazy_list_state();
+                    state_slot.set(Some(state));
+                    can_scroll_forward_reader(state, Rc::clone(&reads));
+                })
+                .expect("render succeeds");
+        }
+        let state = state_slot.get().expect("state composed");
+        let config = crate::lazy::LazyListMeasureConfig::default();
+        let mut measure_and_recompose = || {
+            crate::lazy::measure_lazy_list(
+                10,
+                &state,
+                200.0,
+                300.0,
+                &config,
+                |i| i as u64,
+                |i| crate::lazy::LazyListMeasuredItem::new(i, i as u64, None, 50.0, 100.0),
+            );
+            composition
+                .process_invalid_scopes()
+                .expect("recompose readers");
+        };
+
+        measure_and_recompose();
+        assert_eq!(*reads.borrow(), vec![false, true]);
+
+        state.dispatch_scroll_delta(-10_000.0);
+        measure_and_recompose();
+        assert_eq!(*reads.borrow(), vec![false, true, false]);
+    }
+}
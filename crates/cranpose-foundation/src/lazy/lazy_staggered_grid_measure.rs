@@ -0,0 +1,582 @@
+//! Measurement algorithm for lazy staggered grids.
+//!
+//! Items flow into a fixed number of lanes (columns of a vertical grid), each
+//! with its own running size: every item goes to the lane that currently ends
+//! first, ties going to the lowest lane. Item starts therefore never decrease
+//! with the index, so a walk can stop at the first item starting past the
+//! viewport.
+//!
+//! The grid shares [`LazyListState`] with lazy lists. Its position is stored as
+//! the first visible item and the scroll offset into it. The lane and offset of
+//! every item walked so far are kept in the state (like Compose's `LaneInfo`),
+//! so a pass resumes the walk just before the viewport instead of at the first
+//! item. Items outside the viewport keep their cached size (or the running
+//! average); only the items overlapping it are measured.
+
+use super::lazy_list_measured_item::LazyListMeasuredItem;
+use super::lazy_list_state::{LazyListLayoutInfo, LazyListState};
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Configuration for lazy staggered grid measurement.
+#[derive(Clone, Debug)]
+pub struct LazyStaggeredGridMeasureConfig {
+    /// Number of lanes items are distributed over; at least one is used.
+    pub lanes: usize,
+
+    /// Spacing between consecutive items of a lane.
+    pub main_axis_spacing: f32,
+
+    /// Content padding before the first items.
+    pub before_content_padding: f32,
+
+    /// Content padding after the last items.
+    pub after_content_padding: f32,
+}
+
+impl Default for LazyStaggeredGridMeasureConfig {
+    fn default() -> Self {
+        Self {
+            lanes: 2,
+            main_axis_spacing: 0.0,
+            before_content_padding: 0.0,
+            after_content_padding: 0.0,
+        }
+    }
+}
+
+/// A measured item and the lane it was assigned to.
+#[derive(Clone, Debug)]
+pub struct LazyStaggeredGridMeasuredItem {
+    /// Lane index, counted from the start of the cross axis.
+    pub lane: usize,
+
+    /// The measured item; its `offset` is relative to the viewport start.
+    pub item: LazyListMeasuredItem,
+}
+
+/// Result of measuring a lazy staggered grid.
+#[derive(Clone, Debug, Default)]
+pub struct LazyStaggeredGridMeasureResult {
+    /// Items overlapping the viewport, in index order.
+    pub visible_items: Vec<LazyStaggeredGridMeasuredItem>,
+
+    /// Index of the first visible item.
+    pub first_visible_item_index: usize,
+
+    /// Scroll offset within the first visible item.
+    pub first_visible_item_scroll_offset: f32,
+
+    /// Size of the longest lane including padding, estimated for the items
+    /// after the viewport.
+    pub total_content_size: f32,
+}
+
+/// Where an item was placed by a lane walk.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PlacedItem {
+    lane: usize,
+    /// Start in content coordinates, including the content padding before it.
+    start: f32,
+    size: f32,
+}
+
+/// Lanes and offsets of the items walked by previous passes, kept in
+/// [`LazyListState`] between passes.
+///
+/// Entries are a consistent prefix of the items: walking again from any entry
+/// reproduces the ones after it as long as item sizes do not change. A pass
+/// drops the entries after the last item it walked.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LaneInfo {
+    /// Lane count, spacing and padding the entries were walked with.
+    layout: Option<(usize, f32, f32)>,
+    items: Vec<PlacedItem>,
+}
+
+impl LaneInfo {
+    /// Drops entries walked with another layout or past the last item.
+    fn validate(&mut self, lanes: usize, config: &LazyStaggeredGridMeasureConfig, count: usize) {
+        let layout = Some((
+            lanes,
+            config.main_axis_spacing,
+            config.before_content_padding,
+        ));
+        if self.layout != layout {
+            self.layout = layout;
+            self.items.clear();
+        }
+        self.items.truncate(count);
+    }
+
+    /// Walk state just before the item at `index`, which must be recorded or
+    /// directly follow the recorded items.
+    fn walk_from(&self, index: usize, config: &LazyStaggeredGridMeasureConfig) -> LaneWalk {
+        let lanes = self.layout.map_or(1, |(lanes, _, _)| lanes);
+        let mut walk = LaneWalk::new(lanes, config);
+        walk.walked = index;
+        // The last item of each lane before `index` sets where the lane ends
+        let mut found = vec![false; lanes];
+        let mut missing = lanes;
+        for item in self.items[..index].iter().rev() {
+            if !found[item.lane] {
+                found[item.lane] = true;
+                walk.lane_ends[item.lane] = item.start + item.size + config.main_axis_spacing;
+                missing -= 1;
+                if missing == 0 {
+                    break;
+                }
+            }
+        }
+        walk
+    }
+
+    /// Index of the first item of the lanes that may overlap content from
+    /// `window_start` on, looking back from `anchor`.
+    fn first_reaching(&self, anchor: usize, window_start: f32) -> usize {
+        let lanes = self.layout.map_or(1, |(lanes, _, _)| lanes);
+        let mut first = anchor;
+        // Items of a lane end in index order, so each lane is done at its
+        // first item ending before the window
+        let mut done = vec![false; lanes];
+        let mut remaining = lanes;
+        for (index, item) in self.items[..anchor].iter().enumerate().rev() {
+            if done[item.lane] {
+                continue;
+            }
+            if item.start + item.size > window_start {
+                first = index;
+            } else {
+                done[item.lane] = true;
+                remaining -= 1;
+                if remaining == 0 {
+                    break;
+                }
+            }
+        }
+        first
+    }
+
+    fn record(&mut self, index: usize, item: PlacedItem) {
+        if index < self.items.len() {
+            self.items[index] = item;
+        } else {
+            self.items.push(item);
+        }
+    }
+}
+
+/// Lane assignment of the items walked so far.
+struct LaneWalk {
+    /// Running end of each lane, including the spacing after its last item.
+    lane_ends: Vec<f32>,
+    /// Number of items assigned to lanes.
+    walked: usize,
+    /// Items measured because they overlap the window.
+    visible_items: Vec<LazyStaggeredGridMeasuredItem>,
+}
+
+impl LaneWalk {
+    fn new(lanes: usize, config: &LazyStaggeredGridMeasureConfig) -> Self {
+        Self {
+            lane_ends: vec![config.before_content_padding; lanes],
+            walked: 0,
+            visible_items: Vec::new(),
+        }
+    }
+
+    /// Lane that ends first; ties go to the lowest lane.
+    fn shortest_lane(&self) -> usize {
+        let mut shortest = 0;
+        for (lane, &end) in self.lane_ends.iter().enumerate() {
+            if end < self.lane_ends[shortest] {
+                shortest = lane;
+            }
+        }
+        shortest
+    }
+}
+
+/// Measures a lazy staggered grid and returns the items to place.
+///
+/// `item_key` returns the key of the item at an index, used to look up cached
+/// sizes of items that are not measured; `measure_item` composes and measures
+/// an item. Scroll deltas and scroll-to-item requests pending on `state` are
+/// applied, and the resulting position and layout info are written back.
+pub fn measure_lazy_staggered_grid<K, F>(
+    items_count: usize,
+    state: &LazyListState,
+    viewport_size: f32,
+    config: &LazyStaggeredGridMeasureConfig,
+    item_key: K,
+    mut measure_item: F,
+) -> LazyStaggeredGridMeasureResult
+where
+    K: Fn(usize) -> u64,
+    F: FnMut(usize) -> LazyListMeasuredItem,
+{
+    if !viewport_size.is_finite() && state.record_infinite_viewport() {
+        log::warn!(
+            "LazyStaggeredGrid: unbounded viewport ({viewport_size}), no items are measured. \
+             Give the grid a bounded size (e.g. Modifier::height)."
+        );
+    }
+    if items_count == 0 || viewport_size <= 0.0 || !viewport_size.is_finite() {
+        if items_count == 0 {
+            state.update_scroll_position(0, 0.0);
+        }
+        update_layout_info(state, items_count, viewport_size, config, &[]);
+        return LazyStaggeredGridMeasureResult::default();
+    }
+
+    let lanes = config.lanes.max(1);
+    // Clamping at the end walks the lanes again; items are measured once
+    let mut measured: HashMap<usize, LazyListMeasuredItem> = HashMap::new();
+    let mut measure_item = |index: usize| {
+        measured
+            .entry(index)
+            .or_insert_with(|| measure_item(index))
+            .clone()
+    };
+    let average_item_size = state.average_item_size();
+    let estimated_size = |index: usize| {
+        state
+            .get_cached_size(item_key(index))
+            .unwrap_or(average_item_size)
+    };
+    let mut lane_info = state.take_staggered_lanes();
+    lane_info.validate(lanes, config, items_count);
+
+    // Scroll is the distance from the content start to the viewport start
+    let (first_index, first_offset) = state.consume_scroll_to_index().unwrap_or((
+        state.first_visible_item_index(),
+        state.first_visible_item_scroll_offset(),
+    ));
+    let anchor = first_index.min(items_count - 1);
+    if anchor >= lane_info.items.len() {
+        // Jumped past the items walked so far: place the ones in between
+        let mut walk = lane_info.walk_from(lane_info.items.len(), config);
+        walk_lanes(
+            &mut walk,
+            &mut lane_info,
+            config,
+            anchor + 1,
+            None,
+            &estimated_size,
+            &mut measure_item,
+        );
+    }
+    // Negative deltas scroll toward the end
+    let mut scroll = (lane_info.items[anchor].start - config.before_content_padding + first_offset
+        - state.consume_scroll_delta())
+    .max(0.0);
+
+    let walk_window = |lane_info: &mut LaneInfo,
+                       measure_item: &mut dyn FnMut(usize) -> LazyListMeasuredItem,
+                       scroll: f32| {
+        let window = scroll..scroll + viewport_size;
+        let first = lane_info.first_reaching(anchor, window.start);
+        let mut walk = lane_info.walk_from(first, config);
+        walk_lanes(
+            &mut walk,
+            lane_info,
+            config,
+            items_count,
+            Some(window),
+            &estimated_size,
+            measure_item,
+        );
+        walk
+    };
+    let mut walk = walk_window(&mut lane_info, &mut measure_item, scroll);
+    let mut content_size = total_content_size(&walk, items_count, average_item_size, config);
+
+    // Scrolled past the end: align the content end with the viewport end
+    let max_scroll = (content_size - viewport_size).max(0.0);
+    if scroll > max_scroll {
+        scroll = max_scroll;
+        walk = walk_window(&mut lane_info, &mut measure_item, scroll);
+        content_size = total_content_size(&walk, items_count, average_item_size, config);
+    }
+    lane_info.items.truncate(walk.walked);
+    state.set_staggered_lanes(lane_info);
+
+    for visible in &walk.visible_items {
+        state.cache_item_size(visible.item.key, visible.item.main_axis_size);
+    }
+
+    // The first item reaching into the viewport anchors the position
+    let first_visible = walk.visible_items.iter().find(|visible| {
+        visible.item.offset + visible.item.main_axis_size + config.main_axis_spacing > 0.0
+    });
+    let (first_index, first_offset) = match first_visible {
+        Some(visible) => {
+            let offset = config.before_content_padding - visible.item.offset;
+            state.update_scroll_position_with_key(visible.item.index, offset, visible.item.key);
+            (visible.item.index, offset)
+        }
+        None => {
+            state.update_scroll_position(0, 0.0);
+            (0, 0.0)
+        }
+    };
+    update_layout_info(
+        state,
+        items_count,
+        viewport_size,
+        config,
+        &walk.visible_items,
+    );
+
+    LazyStaggeredGridMeasureResult {
+        visible_items: walk.visible_items,
+        first_visible_item_index: first_index,
+        first_visible_item_scroll_offset: first_offset,
+        total_content_size: content_size,
+    }
+}
+
+/// Assigns items to lanes until `count` items are walked or an item starts
+/// past the end of `window`.
+///
+/// Items overlapping `window` (in content coordinates) at their estimated size
+/// are measured and recorded with offsets relative to the window start; all
+/// others keep their estimated size. Every walked item is recorded in
+/// `lane_info`.
+fn walk_lanes(
+    walk: &mut LaneWalk,
+    lane_info: &mut LaneInfo,
+    config: &LazyStaggeredGridMeasureConfig,
+    count: usize,
+    window: Option<Range<f32>>,
+    estimated_size: &impl Fn(usize) -> f32,
+    measure_item: &mut dyn FnMut(usize) -> LazyListMeasuredItem,
+) {
+    while walk.walked < count {
+        let lane = walk.shortest_lane();
+        let start = walk.lane_ends[lane];
+        let mut size = estimated_size(walk.walked);
+        if let Some(window) = &window {
+            if start >= window.end {
+                break;
+            }
+            if start + size > window.start {
+                let mut item = measure_item(walk.walked);
+                size = item.main_axis_size;
+                item.offset = start - window.start;
+                walk.visible_items
+                    .push(LazyStaggeredGridMeasuredItem { lane, item });
+            }
+        }
+        lane_info.record(walk.walked, PlacedItem { lane, start, size });
+        walk.lane_ends[lane] = start + size + config.main_axis_spacing;
+        walk.walked += 1;
+    }
+}
+
+/// Size of the longest lane, with the items after the walk spread evenly over
+/// the lanes at the average size.
+fn total_content_size(
+    walk: &LaneWalk,
+    items_count: usize,
+    average_item_size: f32,
+    config: &LazyStaggeredGridMeasureConfig,
+) -> f32 {
+    let lanes = walk.lane_ends.len() as f32;
+    let longest_lane = walk.lane_ends.iter().copied().fold(0.0, f32::max);
+    let remaining = items_count - walk.walked;
+    let estimated_end = if remaining == 0 {
+        longest_lane
+    } else {
+        let mean_lane = walk.lane_ends.iter().sum::<f32>() / lanes;
+        let remaining_per_lane =
+            remaining as f32 * (average_item_size + config.main_axis_spacing) / lanes;
+        longest_lane.max(mean_lane + remaining_per_lane)
+    };
+    // The last item of a lane has no spacing after it
+    estimated_end - config.main_axis_spacing + config.after_content_padding
+}
+
+fn update_layout_info(
+    state: &LazyListState,
+    items_count: usize,
+    viewport_size: f32,
+    config: &LazyStaggeredGridMeasureConfig,
+    visible_items: &[LazyStaggeredGridMeasuredItem],
+) {
+    let viewport_end = viewport_size - config.after_content_padding;
+    state.update_layout_info(LazyListLayoutInfo {
+        visible_items_info: visible_items
+            .iter()
+            .map(|visible| &visible.item)
+            .filter(|item| {
+                item.offset + item.main_axis_size > config.before_content_padding
+                    && item.offset < viewport_end
+            })
+            .map(LazyListMeasuredItem::to_item_info)
+            .collect(),
+        total_items_count: items_count,
+        viewport_size,
+        viewport_start_offset: config.before_content_padding,
+        viewport_end_offset: config.after_content_padding,
+        before_content_padding: config.before_content_padding,
+        after_content_padding: config.after_content_padding,
+    });
+    state.update_scroll_bounds();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::lazy_list_state::test_helpers::{new_lazy_list_state, with_test_runtime};
+    use super::*;
+    use std::cell::RefCell;
+
+    const SIZES: [f32; 7] = [100.0, 50.0, 80.0, 30.0, 60.0, 40.0, 70.0];
+
+    fn config() -> LazyStaggeredGridMeasureConfig {
+        LazyStaggeredGridMeasureConfig {
+            lanes: 3,
+            main_axis_spacing: 10.0,
+            ..Default::default()
+        }
+    }
+
+    /// Measures the `SIZES` grid, returning the result and the measured indices.
+    fn measure(
+        state: &LazyListState,
+        viewport_size: f32,
+    ) -> (LazyStaggeredGridMeasureResult, Vec<usize>) {
+        let measured = RefCell::new(Vec::new());
+        let result = measure_lazy_staggered_grid(
+            SIZES.len(),
+            state,
+            viewport_size,
+            &config(),
+            |i| i as u64,
+            |i| {
+                measured.borrow_mut().push(i);
+                LazyListMeasuredItem::new(i, i as u64, None, SIZES[i], 50.0)
+            },
+        );
+        (result, measured.into_inner())
+    }
+
+    fn lanes_and_offsets(result: &LazyStaggeredGridMeasureResult) -> Vec<(usize, usize, f32)> {
+        result
+            .visible_items
+            .iter()
+            .map(|visible| (visible.item.index, visible.lane, visible.item.offset))
+            .collect()
+    }
+
+    #[test]
+    fn items_go_to_the_shortest_lane() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+
+            let (result, _) = measure(&state, 400.0);
+
+            // Lane ends before each item: [0, 0, 0] -> [110, 0, 0] -> [110, 60, 0]
+            // -> [110, 60, 90] -> [110, 100, 90] -> [110, 100, 160] -> [110, 150, 160]
+            assert_eq!(
+                lanes_and_offsets(&result),
+                vec![
+                    (0, 0, 0.0),
+                    (1, 1, 0.0),
+                    (2, 2, 0.0),
+                    (3, 1, 60.0),
+                    (4, 2, 90.0),
+                    (5, 1, 100.0),
+                    (6, 0, 110.0),
+                ]
+            );
+            assert_eq!(result.total_content_size, 180.0);
+        });
+    }
+
+    #[test]
+    fn scrolling_measures_only_overlapping_items_and_stops_at_the_end() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            measure(&state, 100.0);
+
+            // Asks for 120 but the content ends 80 below the viewport
+            state.dispatch_scroll_delta(-120.0);
+            let (result, measured) = measure(&state, 100.0);
+
+            // Items 4..=6 overlap the unclamped window; none is measured twice
+            assert_eq!(measured, vec![4, 5, 6, 0, 3]);
+            assert_eq!(
+                lanes_and_offsets(&result),
+                vec![
+                    (0, 0, -80.0),
+                    (3, 1, -20.0),
+                    (4, 2, 10.0),
+                    (5, 1, 20.0),
+                    (6, 0, 30.0),
+                ]
+            );
+            assert_eq!(result.first_visible_item_index, 0);
+            assert_eq!(result.first_visible_item_scroll_offset, 80.0);
+        });
+    }
+
+    /// Measures `count` items of varying size, returning the result and how
+    /// many item sizes were looked up while walking the lanes.
+    fn measure_many(
+        state: &LazyListState,
+        count: usize,
+    ) -> (LazyStaggeredGridMeasureResult, usize) {
+        let lookups = RefCell::new(0);
+        let result = measure_lazy_staggered_grid(
+            count,
+            state,
+            200.0,
+            &config(),
+            |i| {
+                *lookups.borrow_mut() += 1;
+                i as u64
+            },
+            |i| LazyListMeasuredItem::new(i, i as u64, None, 20.0 + (i % 5) as f32 * 15.0, 50.0),
+        );
+        (result, lookups.into_inner())
+    }
+
+    #[test]
+    fn later_passes_resume_the_walk_near_the_viewport() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            state.scroll_to_item(5_000, 0.0);
+            let (_, jump_lookups) = measure_many(&state, 10_000);
+            // The jump places every item before the target once
+            assert!(jump_lookups >= 5_000);
+
+            state.dispatch_scroll_delta(-30.0);
+            let (_, lookups) = measure_many(&state, 10_000);
+            assert!(lookups < 50, "walked {lookups} items");
+
+            // Scrolling back reaches into items placed by the jump
+            state.dispatch_scroll_delta(300.0);
+            let (result, lookups) = measure_many(&state, 10_000);
+            assert!(lookups < 50, "walked {lookups} items");
+            assert!(result.first_visible_item_index < 5_000);
+        });
+    }
+
+    #[test]
+    fn items_keep_their_lanes_when_scrolled_back() {
+        with_test_runtime(|| {
+            let state = new_lazy_list_state();
+            measure_many(&state, 200);
+            state.dispatch_scroll_delta(-400.0);
+            let (before, _) = measure_many(&state, 200);
+
+            state.dispatch_scroll_delta(-1_000.0);
+            measure_many(&state, 200);
+            state.dispatch_scroll_delta(1_000.0);
+            let (after, _) = measure_many(&state, 200);
+
+            assert_eq!(lanes_and_offsets(&after), lanes_and_offsets(&before));
+        });
+    }
+}
@@ -11,6 +11,7 @@
 //! - [`LazyLayoutItemProvider`] - Item factory trait (JC: `LazyLayoutItemProvider`)
 //! - [`LazyListScope`] - DSL builder (JC: `LazyListScope`)
 //! - [`measure_lazy_list`] - Virtualized measurement (JC: `measureLazyList`)
+//! - [`measure_lazy_staggered_grid`] - Staggered grid measurement (JC: `measureStaggeredGrid`)
 //!
 //! # Example
 //!
@@ -48,6 +49,7 @@ mod lazy_list_measure;
 mod lazy_list_measured_item;
 mod lazy_list_scope;
 mod lazy_list_state;
+mod lazy_staggered_grid_measure;
 mod nearest_range;
 mod prefetch;
 mod scroll_position_resolver;
@@ -58,5 +60,6 @@ pub use lazy_list_measure::*;
 pub use lazy_list_measured_item::*;
 pub use lazy_list_scope::*;
 pub use lazy_list_state::*;
+pub use lazy_staggered_grid_measure::*;
 pub use nearest_range::*;
 pub use prefetch::*;
@@ -6,6 +6,11 @@
 //! Based on JC's `LazyLayoutNearestRangeState`. Uses a sliding window
 //! to limit key lookup to items near the current scroll position,
 //! providing O(1) lookup instead of O(n).
+//!
+//! For huge lists the window is only a starting point: callers search it
+//! first and then widen the search outward (see
+//! `LazyListIntervalContent::get_index_by_slot_id_near`), so an item that moved
+//! just past the window is still found without scanning the whole list.
 
 use std::ops::Range;
 
@@ -53,11 +58,14 @@ impl NearestRangeState {
     }
 
     /// Creates a NearestRangeState with custom window sizes.
+    ///
+    /// A `sliding_window_size` of zero is treated as one.
     pub fn with_sizes(
         first_visible_item: usize,
         sliding_window_size: usize,
         extra_item_count: usize,
     ) -> Self {
+        let sliding_window_size = sliding_window_size.max(1);
         let value =
             Self::calculate_range(first_visible_item, sliding_window_size, extra_item_count);
         Self {
@@ -73,6 +81,31 @@ impl NearestRangeState {
         self.value.clone()
     }
 
+    /// Returns the size of the sliding window.
+    pub fn sliding_window_size(&self) -> usize {
+        self.sliding_window_size
+    }
+
+    /// Returns the number of extra items included on each side of the window.
+    pub fn extra_item_count(&self) -> usize {
+        self.extra_item_count
+    }
+
+    /// Changes the window sizes and recalculates the range around the last
+    /// known first visible item.
+    ///
+    /// Larger windows make in-range lookups hit more often at the cost of a
+    /// longer scan per lookup. A `sliding_window_size` of zero is treated as one.
+    pub fn set_sizes(&mut self, sliding_window_size: usize, extra_item_count: usize) {
+        self.sliding_window_size = sliding_window_size.max(1);
+        self.extra_item_count = extra_item_count;
+        self.value = Self::calculate_range(
+            self.last_first_visible_item,
+            self.sliding_window_size,
+            self.extra_item_count,
+        );
+    }
+
     /// Updates the range based on the new first visible item.
     /// Only recalculates when crossing a window boundary.
     pub fn update(&mut self, first_visible_item: usize) {
@@ -141,4 +174,29 @@ mod tests {
         // End: 990 + 30 + 100 = 1120
         assert_eq!(state.range(), 890..1120);
     }
+
+    #[test]
+    fn test_custom_sizes() {
+        let state = NearestRangeState::with_sizes(1000, 50, 10);
+        // Window: 1000..1050, plus 10 on each side
+        assert_eq!(state.range(), 990..1060);
+        assert_eq!(state.sliding_window_size(), 50);
+        assert_eq!(state.extra_item_count(), 10);
+    }
+
+    #[test]
+    fn test_set_sizes_recalculates_range() {
+        let mut state = NearestRangeState::new(1000);
+        state.set_sizes(200, 0);
+        // Window: 1000..1200 around the last known first visible item
+        assert_eq!(state.range(), 1000..1200);
+    }
+
+    #[test]
+    fn test_zero_window_size_is_clamped() {
+        let mut state = NearestRangeState::with_sizes(5, 0, 2);
+        assert_eq!(state.range(), 3..8);
+        state.set_sizes(0, 0);
+        assert_eq!(state.range(), 5..6);
+    }
 }
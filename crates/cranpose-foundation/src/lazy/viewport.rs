@@ -1,6 +1,8 @@
 //! Viewport handling for lazy list measurement.
 //!
 //! This module handles viewport size validation and infinite viewport fallback logic.
+//! Lists that know their on-screen window (`LazyListMeasureConfig::known_viewport`)
+//! measure that window instead of using the fallback.
 
 use super::lazy_list_measure::DEFAULT_ITEM_SIZE_ESTIMATE;
 
@@ -13,7 +15,6 @@ pub struct ViewportHandler {
     /// The effective viewport size to use for measurement.
     effective_size: f32,
     /// Whether the viewport was detected as infinite.
-    #[allow(dead_code)] // Useful for debugging and future use
     is_infinite: bool,
 }
 
@@ -32,19 +33,12 @@ impl ViewportHandler {
     /// * `average_item_size` - Current average item size from state
     /// * `spacing` - Spacing between items
     pub fn new(viewport_size: f32, average_item_size: f32, spacing: f32) -> Self {
-        let is_infinite = viewport_size.is_infinite() || viewport_size > MAX_REASONABLE_VIEWPORT;
+        let is_infinite = Self::is_unbounded(viewport_size);
 
         let effective_size = if is_infinite {
             // Use estimated viewport based on average item size
             let avg_size = average_item_size.max(DEFAULT_ITEM_SIZE_ESTIMATE);
-            let estimated_size = (avg_size + spacing) * INFINITE_VIEWPORT_ITEM_COUNT;
-            log::warn!(
-                "LazyList: Detected infinite viewport ({}), using fallback size {}. \
-                 Consider wrapping LazyList in a constrained container.",
-                viewport_size,
-                estimated_size
-            );
-            estimated_size
+            (avg_size + spacing) * INFINITE_VIEWPORT_ITEM_COUNT
         } else {
             viewport_size
         };
@@ -55,6 +49,12 @@ impl ViewportHandler {
         }
     }
 
+    /// Returns whether `viewport_size` is too large to be a real viewport.
+    #[inline]
+    pub fn is_unbounded(viewport_size: f32) -> bool {
+        viewport_size.is_infinite() || viewport_size > MAX_REASONABLE_VIEWPORT
+    }
+
     /// Returns the effective viewport size to use for measurement.
     #[inline]
     pub fn effective_size(&self) -> f32 {
@@ -63,7 +63,6 @@ impl ViewportHandler {
 
     /// Returns whether the viewport was detected as infinite.
     #[inline]
-    #[allow(dead_code)] // Used in tests and kept for debugging/future use
     pub fn is_infinite(&self) -> bool {
         self.is_infinite
     }
@@ -13,7 +13,7 @@ pub mod velocity_tracker;
 
 // Re-export gesture constants at crate root for convenience
 pub use gesture_constants::{DRAG_THRESHOLD, MAX_FLING_VELOCITY};
-pub use velocity_tracker::VelocityTracker1D;
+pub use velocity_tracker::{Velocity, VelocityTracker, VelocityTracker1D, VelocityTrackerStrategy};
 
 // Re-export commonly used items
 pub use measurement_proxy::*;
@@ -21,7 +21,8 @@ pub use modifier::*;
 #[allow(unused_imports)] // Module exists for API compatibility
 pub use modifier_helpers::*;
 pub use nodes::input::{
-    PointerButton, PointerButtons, PointerEvent, PointerEventKind, PointerId, PointerPhase,
+    CursorIcon, PointerButton, PointerButtons, PointerEvent, PointerEventKind, PointerId,
+    PointerPhase,
 };
 
 pub mod prelude {
@@ -647,12 +647,31 @@ pub trait FocusNode: ModifierNode {
     }
 }
 
+/// Kind of control a node presents to accessibility services.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    Button,
+    Checkbox,
+    Switch,
+    RadioButton,
+    Tab,
+    Image,
+}
+
 /// Semantics configuration for accessibility.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct SemanticsConfiguration {
     pub content_description: Option<String>,
+    pub role: Option<Role>,
+    /// Current state announced with the node, e.g. "Checked" for a checkbox.
+    pub state_description: Option<String>,
     pub is_button: bool,
     pub is_clickable: bool,
+    /// Set when the node's interactions are currently disabled.
+    pub is_disabled: bool,
+    /// Folds the semantics of descendants into this node, so a composite
+    /// component like a button with a label is announced as one element.
+    pub merge_descendants: bool,
 }
 
 impl SemanticsConfiguration {
@@ -660,8 +679,16 @@ impl SemanticsConfiguration {
         if let Some(description) = &other.content_description {
             self.content_description = Some(description.clone());
         }
+        if let Some(role) = other.role {
+            self.role = Some(role);
+        }
+        if let Some(state) = &other.state_description {
+            self.state_description = Some(state.clone());
+        }
         self.is_button |= other.is_button;
         self.is_clickable |= other.is_clickable;
+        self.is_disabled |= other.is_disabled;
+        self.merge_descendants |= other.merge_descendants;
     }
 }
 
@@ -4,11 +4,13 @@ pub mod gestures;
 pub mod types;
 
 pub use types::{
-    PointerButton, PointerButtons, PointerEvent, PointerEventKind, PointerId, PointerPhase,
+    CursorIcon, PointerButton, PointerButtons, PointerEvent, PointerEventKind, PointerId,
+    PointerPhase,
 };
 
 pub mod prelude {
     pub use super::types::{
-        PointerButton, PointerButtons, PointerEvent, PointerEventKind, PointerId, PointerPhase,
+        CursorIcon, PointerButton, PointerButtons, PointerEvent, PointerEventKind, PointerId,
+        PointerPhase,
     };
 }
@@ -1,4 +1,4 @@
-use cranpose_ui_graphics::Point;
+use cranpose_ui_graphics::{Point, Size};
 use std::cell::Cell;
 use std::rc::Rc;
 
@@ -18,6 +18,50 @@ pub enum PointerEventKind {
     Move,
     Up,
     Cancel,
+    /// The pointer moved onto the element without a button pressed.
+    Enter,
+    /// The pointer moved off the element without a button pressed.
+    Exit,
+}
+
+/// Mouse cursor shape an element asks for while hovered.
+///
+/// Matches Kotlin: `PointerIcon` in `androidx.compose.ui.input.pointer`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CursorIcon {
+    /// The platform's arrow cursor.
+    #[default]
+    Default,
+    /// Hand shown over links and buttons.
+    Pointer,
+    /// I-beam shown over editable or selectable text.
+    Text,
+    Crosshair,
+    /// Open hand shown over something that can be dragged.
+    Grab,
+    /// Closed hand shown while dragging.
+    Grabbing,
+    /// Shown over elements that can't be interacted with.
+    NotAllowed,
+    ResizeHorizontal,
+    ResizeVertical,
+}
+
+impl CursorIcon {
+    /// Name of the matching CSS `cursor` value.
+    pub fn css_name(self) -> &'static str {
+        match self {
+            CursorIcon::Default => "default",
+            CursorIcon::Pointer => "pointer",
+            CursorIcon::Text => "text",
+            CursorIcon::Crosshair => "crosshair",
+            CursorIcon::Grab => "grab",
+            CursorIcon::Grabbing => "grabbing",
+            CursorIcon::NotAllowed => "not-allowed",
+            CursorIcon::ResizeHorizontal => "ew-resize",
+            CursorIcon::ResizeVertical => "ns-resize",
+        }
+    }
 }
 
 #[repr(u8)]
@@ -77,6 +121,8 @@ pub struct PointerEvent {
     pub position: Point,
     pub global_position: Point,
     pub buttons: PointerButtons,
+    /// Size of the hit region the event was dispatched to, when known.
+    target_size: Option<Size>,
     /// Tracks whether this event has been consumed by a handler.
     /// Shared via Rc<Cell> so consumption can be tracked across copies.
     consumed: Rc<Cell<bool>>,
@@ -89,13 +135,16 @@ impl PointerEvent {
             kind,
             phase: match kind {
                 PointerEventKind::Down => PointerPhase::Start,
-                PointerEventKind::Move => PointerPhase::Move,
+                PointerEventKind::Move | PointerEventKind::Enter | PointerEventKind::Exit => {
+                    PointerPhase::Move
+                }
                 PointerEventKind::Up => PointerPhase::End,
                 PointerEventKind::Cancel => PointerPhase::Cancel,
             },
             position,
             global_position,
             buttons: PointerButtons::NONE,
+            target_size: None,
             consumed: Rc::new(Cell::new(false)),
         }
     }
@@ -106,6 +155,30 @@ impl PointerEvent {
         self
     }
 
+    /// Records the size of the hit region receiving this event.
+    ///
+    /// Renderers set it when dispatching so handlers can tell whether
+    /// `position` is still inside their bounds.
+    pub fn with_target_size(mut self, size: Size) -> Self {
+        self.target_size = Some(size);
+        self
+    }
+
+    /// Size of the hit region receiving this event, if the dispatcher knows it.
+    pub fn target_size(&self) -> Option<Size> {
+        self.target_size
+    }
+
+    /// Whether the local `position` lies inside the target's bounds.
+    ///
+    /// Returns true when the target size is unknown.
+    pub fn is_within_target(&self) -> bool {
+        self.target_size.is_none_or(|size| {
+            (0.0..=size.width).contains(&self.position.x)
+                && (0.0..=size.height).contains(&self.position.y)
+        })
+    }
+
     /// Mark this event as consumed, preventing other handlers from processing it.
     ///
     /// Example: Scroll gestures consume events once dragging starts to prevent
@@ -131,6 +204,7 @@ impl PointerEvent {
             position,
             global_position: self.global_position,
             buttons: self.buttons,
+            target_size: self.target_size,
             consumed: self.consumed.clone(),
         }
     }
@@ -1237,11 +1237,7 @@ fn semantics_delegates_are_visited_by_forward_matching() {
     });
     assert_eq!(visits, 1, "delegated semantics node should be visited");
 
-    let mut config = SemanticsConfiguration {
-        content_description: None,
-        is_button: false,
-        is_clickable: false,
-    };
+    let mut config = SemanticsConfiguration::default();
     chain.for_each_forward_matching(NodeCapabilities::SEMANTICS, |node_ref| {
         node_ref.with_node(|node| {
             if let Some(semantics_node) = node.as_semantics_node() {
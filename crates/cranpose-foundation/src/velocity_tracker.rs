@@ -1,7 +1,10 @@
 //! Velocity tracking for fling gesture support.
 //!
-//! Port of Jetpack Compose's VelocityTracker1D using the Impulse strategy.
-//! This calculates velocity based on kinetic energy principles.
+//! Port of Jetpack Compose's VelocityTracker1D with its Impulse and Lsq2
+//! strategies, plus the two-axis [`VelocityTracker`] that gestures feed with
+//! pointer positions.
+
+use cranpose_ui_graphics::Point;
 
 /// Ring buffer size for velocity tracking samples.
 const HISTORY_SIZE: usize = 20;
@@ -12,6 +15,18 @@ const HORIZON_MS: i64 = 100;
 /// If no movement for this duration, assume the pointer has stopped.
 pub const ASSUME_STOPPED_MS: i64 = 40;
 
+/// How [`VelocityTracker1D`] turns recent samples into a velocity.
+///
+/// Matches Kotlin: `VelocityTracker1D.Strategy`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VelocityTrackerStrategy {
+    /// Kinetic energy imparted by each movement; robust to uneven sampling.
+    #[default]
+    Impulse,
+    /// Slope of a least-squares quadratic fit over the recent samples.
+    Lsq2,
+}
+
 /// A data point with timestamp.
 #[derive(Clone, Copy, Default)]
 struct DataPointAtTime {
@@ -40,6 +55,8 @@ pub struct VelocityTracker1D {
     index: usize,
     /// Whether data points are differential (change in position) vs absolute positions.
     is_differential: bool,
+    /// Algorithm used by `calculate_velocity`.
+    strategy: VelocityTrackerStrategy,
 }
 
 impl Default for VelocityTracker1D {
@@ -51,23 +68,37 @@ impl Default for VelocityTracker1D {
 impl VelocityTracker1D {
     /// Creates a new velocity tracker for absolute position data.
     pub fn new() -> Self {
+        Self::with_strategy(VelocityTrackerStrategy::Impulse)
+    }
+
+    /// Creates a new velocity tracker for absolute position data using `strategy`.
+    pub fn with_strategy(strategy: VelocityTrackerStrategy) -> Self {
         Self {
             samples: [None; HISTORY_SIZE],
             index: 0,
             is_differential: false,
+            strategy,
         }
     }
 
     /// Creates a new velocity tracker for differential (delta) data.
+    ///
+    /// Differential data always uses the impulse strategy, like Compose.
     #[allow(dead_code)]
     pub fn differential() -> Self {
         Self {
             samples: [None; HISTORY_SIZE],
             index: 0,
             is_differential: true,
+            strategy: VelocityTrackerStrategy::Impulse,
         }
     }
 
+    /// Returns the algorithm used by [`calculate_velocity`](Self::calculate_velocity).
+    pub fn strategy(&self) -> VelocityTrackerStrategy {
+        self.strategy
+    }
+
     /// Adds a data point at the given time (milliseconds).
     ///
     /// For absolute tracking, `data_point` is the position.
@@ -124,12 +155,16 @@ impl VelocityTracker1D {
             }
         }
 
-        if sample_count < 2 {
-            return 0.0;
-        }
-
-        let velocity_per_ms =
-            calculate_impulse_velocity(&data_points, &times, sample_count, self.is_differential);
+        let velocity_per_ms = match self.strategy {
+            VelocityTrackerStrategy::Impulse if sample_count >= 2 => {
+                calculate_impulse_velocity(&data_points, &times, sample_count, self.is_differential)
+            }
+            // A quadratic needs three samples to be determined
+            VelocityTrackerStrategy::Lsq2 if sample_count >= 3 => {
+                calculate_least_squares_velocity(&data_points, &times, sample_count)
+            }
+            _ => return 0.0,
+        };
 
         velocity_per_ms * 1000.0
     }
@@ -193,12 +228,156 @@ fn calculate_impulse_velocity(
     kinetic_energy_to_velocity(work)
 }
 
+/// Calculates velocity as the slope at the newest sample of a quadratic fitted
+/// to the samples by least squares.
+///
+/// Times are relative to the newest sample, so the slope there is the fit's
+/// linear coefficient. The fit uses a QR decomposition, like Compose's
+/// `polyFitLeastSquares`.
+fn calculate_least_squares_velocity(
+    data_points: &[f32; HISTORY_SIZE],
+    times: &[f32; HISTORY_SIZE],
+    sample_count: usize,
+) -> f32 {
+    const DEGREE: usize = 2;
+    const TERMS: usize = DEGREE + 1;
+    let m = sample_count;
+
+    // Vandermonde matrix, one row per power of time
+    let mut a = [[0.0f64; HISTORY_SIZE]; TERMS];
+    for h in 0..m {
+        a[0][h] = 1.0;
+        for i in 1..TERMS {
+            a[i][h] = a[i - 1][h] * f64::from(times[h]);
+        }
+    }
+
+    // Gram-Schmidt orthonormalization into Q, with R upper triangular
+    let mut q = [[0.0f64; HISTORY_SIZE]; TERMS];
+    let mut r = [[0.0f64; TERMS]; TERMS];
+    let dot = |u: &[f64; HISTORY_SIZE], v: &[f64; HISTORY_SIZE]| -> f64 {
+        u[..m].iter().zip(&v[..m]).map(|(a, b)| a * b).sum()
+    };
+    for j in 0..TERMS {
+        let mut column = a[j];
+        for basis in &q[..j] {
+            let projection = dot(&column, basis);
+            for (value, b) in column[..m].iter_mut().zip(&basis[..m]) {
+                *value -= projection * b;
+            }
+        }
+        let norm = dot(&column, &column).sqrt();
+        if norm < 1e-6 {
+            // Samples too close together in time to fit a curve
+            return 0.0;
+        }
+        for value in &mut column[..m] {
+            *value /= norm;
+        }
+        for i in j..TERMS {
+            r[j][i] = dot(&column, &a[i]);
+        }
+        q[j] = column;
+    }
+
+    // Solve R * coefficients = Q^T * y by back substitution
+    let mut coefficients = [0.0f64; TERMS];
+    for i in (0..TERMS).rev() {
+        let mut value: f64 = q[i][..m]
+            .iter()
+            .zip(&data_points[..m])
+            .map(|(q, y)| q * f64::from(*y))
+            .sum();
+        for j in i + 1..TERMS {
+            value -= r[i][j] * coefficients[j];
+        }
+        coefficients[i] = value / r[i][i];
+    }
+    coefficients[1] as f32
+}
+
 /// Converts kinetic energy to velocity using E = 0.5 * m * v^2 (with m = 1).
 #[inline]
 fn kinetic_energy_to_velocity(kinetic_energy: f32) -> f32 {
     kinetic_energy.signum() * (2.0 * kinetic_energy.abs()).sqrt()
 }
 
+/// Velocity on both axes, in units/second.
+///
+/// Matches Kotlin: `Velocity`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Velocity {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Velocity {
+    pub const ZERO: Velocity = Velocity { x: 0.0, y: 0.0 };
+}
+
+/// Tracks pointer positions on both axes to compute a gesture's velocity.
+///
+/// Gestures record each pointer position with its timestamp and ask for the
+/// velocity when the pointer is released, e.g. to start a fling.
+///
+/// # Usage
+/// ```ignore
+/// let mut tracker = VelocityTracker::new();
+/// tracker.add_position(time_ms, event.position);
+/// // ... more positions ...
+/// let velocity = tracker.calculate_velocity(); // px/sec on each axis
+/// ```
+///
+/// Matches Kotlin: `VelocityTracker`
+#[derive(Clone, Default)]
+pub struct VelocityTracker {
+    x: VelocityTracker1D,
+    y: VelocityTracker1D,
+}
+
+impl VelocityTracker {
+    /// Creates a tracker using the impulse strategy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a tracker using `strategy` on both axes.
+    pub fn with_strategy(strategy: VelocityTrackerStrategy) -> Self {
+        Self {
+            x: VelocityTracker1D::with_strategy(strategy),
+            y: VelocityTracker1D::with_strategy(strategy),
+        }
+    }
+
+    /// Records the pointer `position` at `time_ms`.
+    pub fn add_position(&mut self, time_ms: i64, position: Point) {
+        self.x.add_data_point(time_ms, position.x);
+        self.y.add_data_point(time_ms, position.y);
+    }
+
+    /// Calculates the velocity from the recent positions.
+    pub fn calculate_velocity(&self) -> Velocity {
+        Velocity {
+            x: self.x.calculate_velocity(),
+            y: self.y.calculate_velocity(),
+        }
+    }
+
+    /// Calculates the velocity with each axis capped to `max_velocity`.
+    pub fn calculate_velocity_with_max(&self, max_velocity: f32) -> Velocity {
+        Velocity {
+            x: self.x.calculate_velocity_with_max(max_velocity),
+            y: self.y.calculate_velocity_with_max(max_velocity),
+        }
+    }
+
+    /// Clears all tracked positions.
+    pub fn reset(&mut self) {
+        self.x.reset();
+        self.y.reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,4 +484,79 @@ mod tests {
         let velocity = tracker.calculate_velocity();
         assert_eq!(velocity, 0.0);
     }
+
+    #[test]
+    fn test_lsq2_constant_velocity() {
+        let mut tracker = VelocityTracker1D::with_strategy(VelocityTrackerStrategy::Lsq2);
+        // 2.5 px/ms = 2500 px/s, sampled at a steady 8ms
+        for step in 0..10 {
+            tracker.add_data_point(step * 8, step as f32 * 20.0);
+        }
+
+        let velocity = tracker.calculate_velocity();
+        assert!(
+            (velocity - 2500.0).abs() < 1.0,
+            "Expected ~2500, got {}",
+            velocity
+        );
+    }
+
+    #[test]
+    fn test_lsq2_needs_three_samples() {
+        let mut tracker = VelocityTracker1D::with_strategy(VelocityTrackerStrategy::Lsq2);
+        tracker.add_data_point(0, 0.0);
+        tracker.add_data_point(10, 100.0);
+        assert_eq!(tracker.calculate_velocity(), 0.0);
+
+        tracker.add_data_point(20, 200.0);
+        assert!((tracker.calculate_velocity() - 10_000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_tracker_matches_constant_velocity_on_both_axes() {
+        for strategy in [
+            VelocityTrackerStrategy::Impulse,
+            VelocityTrackerStrategy::Lsq2,
+        ] {
+            let mut tracker = VelocityTracker::with_strategy(strategy);
+            // 1000 px/s right and 500 px/s up
+            for step in 0..8 {
+                let time_ms = step * 10;
+                tracker.add_position(
+                    time_ms,
+                    Point::new(time_ms as f32, 200.0 - time_ms as f32 * 0.5),
+                );
+            }
+
+            let velocity = tracker.calculate_velocity();
+            assert!(
+                (velocity.x - 1000.0).abs() < 10.0 && (velocity.y + 500.0).abs() < 10.0,
+                "{strategy:?}: got {velocity:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tracker_follows_direction_reversal() {
+        for strategy in [
+            VelocityTrackerStrategy::Impulse,
+            VelocityTrackerStrategy::Lsq2,
+        ] {
+            let mut tracker = VelocityTracker::with_strategy(strategy);
+            // Drag right for 50ms, then back left for 50ms at the same speed
+            for step in 0..=5 {
+                tracker.add_position(step * 10, Point::new(step as f32 * 10.0, 0.0));
+            }
+            for step in 6..=10 {
+                tracker.add_position(step * 10, Point::new((10 - step) as f32 * 10.0, 0.0));
+            }
+
+            let velocity = tracker.calculate_velocity();
+            assert!(velocity.x < 0.0, "{strategy:?}: got {velocity:?}");
+            assert_eq!(velocity.y, 0.0);
+
+            tracker.reset();
+            assert_eq!(tracker.calculate_velocity(), Velocity::ZERO);
+        }
+    }
 }
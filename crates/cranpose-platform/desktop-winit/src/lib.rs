@@ -1,4 +1,4 @@
-use cranpose_foundation::{PointerEvent, PointerEventKind};
+use cranpose_foundation::{CursorIcon, PointerEvent, PointerEventKind};
 use cranpose_ui_graphics::Point;
 use winit::dpi::PhysicalPosition;
 
@@ -15,6 +15,7 @@ impl DesktopWinitPlatform {
         self.scale_factor = factor;
     }
 
+    /// Converts a window position in physical pixels to logical pixels.
     pub fn pointer_position(&self, position: PhysicalPosition<f64>) -> Point {
         Point {
             x: (position.x / self.scale_factor) as f32,
@@ -32,6 +33,22 @@ impl DesktopWinitPlatform {
     }
 }
 
+/// Maps a cursor requested by the UI to the matching winit cursor.
+pub fn winit_cursor_icon(icon: CursorIcon) -> winit::cursor::CursorIcon {
+    use winit::cursor::CursorIcon as Winit;
+    match icon {
+        CursorIcon::Default => Winit::Default,
+        CursorIcon::Pointer => Winit::Pointer,
+        CursorIcon::Text => Winit::Text,
+        CursorIcon::Crosshair => Winit::Crosshair,
+        CursorIcon::Grab => Winit::Grab,
+        CursorIcon::Grabbing => Winit::Grabbing,
+        CursorIcon::NotAllowed => Winit::NotAllowed,
+        CursorIcon::ResizeHorizontal => Winit::EwResize,
+        CursorIcon::ResizeVertical => Winit::NsResize,
+    }
+}
+
 impl Default for DesktopWinitPlatform {
     fn default() -> Self {
         Self::new(1.0)
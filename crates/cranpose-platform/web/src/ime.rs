@@ -0,0 +1,43 @@
+//! Translation of the hidden IME element's input events into text field input.
+
+/// Text field input carried by an event of the hidden editable element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebTextInput {
+    /// Text being composed, with the cursor at its end.
+    Preedit(String),
+    /// Final text, replacing any composition in progress.
+    Commit(String),
+    /// Editing done through a key, e.g. a soft keyboard's backspace.
+    Key(WebEditKey),
+}
+
+/// Editing keys that soft keyboards send as input events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebEditKey {
+    Enter,
+    Backspace,
+    Delete,
+}
+
+/// Translates a `beforeinput` event with the given `inputType` and `data`.
+///
+/// Composition text is left to [`composition_input`]; unknown input types
+/// return `None` and should not be cancelled.
+pub fn before_input(input_type: &str, data: Option<String>) -> Option<WebTextInput> {
+    match input_type {
+        "insertText" | "insertReplacementText" => data.map(WebTextInput::Commit),
+        "insertLineBreak" | "insertParagraph" => Some(WebTextInput::Key(WebEditKey::Enter)),
+        "deleteContentBackward" => Some(WebTextInput::Key(WebEditKey::Backspace)),
+        "deleteContentForward" => Some(WebTextInput::Key(WebEditKey::Delete)),
+        _ => None,
+    }
+}
+
+/// Translates a composition event of the given type carrying `data`.
+pub fn composition_input(event_type: &str, data: String) -> Option<WebTextInput> {
+    match event_type {
+        "compositionupdate" => Some(WebTextInput::Preedit(data)),
+        "compositionend" => Some(WebTextInput::Commit(data)),
+        _ => None,
+    }
+}
@@ -1,22 +1,77 @@
+mod ime;
+
+pub use ime::{before_input, composition_input, WebEditKey, WebTextInput};
+
 use cranpose_foundation::{PointerEvent, PointerEventKind};
 use cranpose_ui_graphics::Point;
 
+/// Canvas size to apply after a resize.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CanvasSize {
+    /// Backing buffer width in physical pixels.
+    pub physical_width: u32,
+    /// Backing buffer height in physical pixels.
+    pub physical_height: u32,
+    /// Viewport width in CSS pixels (logical dp).
+    pub logical_width: f32,
+    /// Viewport height in CSS pixels (logical dp).
+    pub logical_height: f32,
+    pub scale_factor: f64,
+}
+
 pub struct WebPlatform {
     scale_factor: f64,
+    pending_resize: Option<(f64, f64)>,
 }
 
 impl WebPlatform {
     pub fn new(scale_factor: f64) -> Self {
-        Self { scale_factor }
+        Self {
+            scale_factor,
+            pending_resize: None,
+        }
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
     }
 
     pub fn set_scale_factor(&mut self, factor: f64) {
         self.scale_factor = factor;
     }
 
+    /// Records the canvas size in CSS pixels after a resize event.
+    ///
+    /// Only the latest size is kept until [`take_resize`](Self::take_resize)
+    /// runs, so a burst of resize events between two frames reconfigures the
+    /// surface once.
+    pub fn request_resize(&mut self, css_width: f64, css_height: f64) {
+        self.pending_resize = Some((css_width, css_height));
+    }
+
+    /// Takes the size requested since the last call, scaled by the current
+    /// scale factor. Empty sizes (a hidden or collapsed canvas) are dropped.
+    pub fn take_resize(&mut self) -> Option<CanvasSize> {
+        let (css_width, css_height) = self.pending_resize.take()?;
+        let physical_width = (css_width * self.scale_factor) as u32;
+        let physical_height = (css_height * self.scale_factor) as u32;
+        if physical_width == 0 || physical_height == 0 {
+            return None;
+        }
+        Some(CanvasSize {
+            physical_width,
+            physical_height,
+            logical_width: css_width as f32,
+            logical_height: css_height as f32,
+            scale_factor: self.scale_factor,
+        })
+    }
+
+    /// Converts an event's `offsetX`/`offsetY` to logical pixels.
+    ///
+    /// CSS pixels already are logical pixels, so the scale factor is not
+    /// applied.
     pub fn pointer_position(&self, x: f64, y: f64) -> Point {
-        // offset_x/offset_y are already in CSS pixels (logical coordinates)
-        // so we don't need to divide by scale_factor
         Point {
             x: x as f32,
             y: y as f32,
@@ -34,3 +89,63 @@ impl Default for WebPlatform {
         Self::new(1.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resize_burst_yields_the_latest_size_once() {
+        let mut platform = WebPlatform::new(2.0);
+        platform.request_resize(400.0, 300.0);
+        platform.request_resize(640.0, 480.0);
+
+        assert_eq!(
+            platform.take_resize(),
+            Some(CanvasSize {
+                physical_width: 1280,
+                physical_height: 960,
+                logical_width: 640.0,
+                logical_height: 480.0,
+                scale_factor: 2.0,
+            })
+        );
+        assert_eq!(platform.take_resize(), None);
+
+        platform.request_resize(0.0, 480.0);
+        assert_eq!(platform.take_resize(), None);
+    }
+
+    #[test]
+    fn pointer_positions_stay_in_css_pixels() {
+        let platform = WebPlatform::new(2.0);
+        assert_eq!(
+            platform.pointer_position(150.0, 50.0),
+            Point { x: 150.0, y: 50.0 }
+        );
+    }
+
+    #[test]
+    fn soft_keyboard_input_becomes_commits_and_editing_keys() {
+        assert_eq!(
+            before_input("insertText", Some("é".into())),
+            Some(WebTextInput::Commit("é".into()))
+        );
+        assert_eq!(
+            before_input("deleteContentBackward", None),
+            Some(WebTextInput::Key(WebEditKey::Backspace))
+        );
+        assert_eq!(
+            before_input("insertCompositionText", Some("k".into())),
+            None
+        );
+        assert_eq!(
+            composition_input("compositionupdate", "かな".into()),
+            Some(WebTextInput::Preedit("かな".into()))
+        );
+        assert_eq!(
+            composition_input("compositionend", "仮名".into()),
+            Some(WebTextInput::Commit("仮名".into()))
+        );
+    }
+}
@@ -1,11 +1,38 @@
 //! Common rendering contracts shared between renderer backends.
 
+pub mod scene_hash;
+pub mod snapshot;
+
+use cranpose_core::{MemoryApplier, NodeId};
 use cranpose_foundation::nodes::input::PointerEvent;
-use cranpose_ui::LayoutTree;
-use cranpose_ui_graphics::Size;
+use cranpose_ui::{LayoutNode, LayoutTree, SubcomposeLayoutNode};
+use cranpose_ui_graphics::{CornerRadii, Rect, Size};
 
 pub use cranpose_ui_graphics::Brush;
 
+/// Rounded rectangle a shape is clipped to on top of its rectangular clip.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoundedClip {
+    pub rect: Rect,
+    pub radii: CornerRadii,
+}
+
+/// Returns the `z_index` modifier value of a layout or subcompose layout node,
+/// or `0.0` for other nodes.
+pub fn node_z_index(applier: &mut MemoryApplier, node_id: NodeId) -> f32 {
+    if let Ok(z_index) = applier
+        .with_node::<LayoutNode, _>(node_id, |node| node.modifier_slices_snapshot().z_index())
+    {
+        z_index
+    } else {
+        applier
+            .with_node::<SubcomposeLayoutNode, _>(node_id, |node| {
+                node.modifier_slices_snapshot().z_index()
+            })
+            .unwrap_or(0.0)
+    }
+}
+
 /// Trait implemented by hit-test targets stored inside a [`RenderScene`].
 pub trait HitTestTarget {
     /// Dispatches a pointer event to this target's handlers.
@@ -45,6 +72,14 @@ pub trait RenderScene {
     ///
     /// Returns None if the node no longer exists in the scene (e.g., removed during gesture).
     fn find_target(&self, node_id: cranpose_core::NodeId) -> Option<Self::HitTarget>;
+
+    /// Hash of everything the scene draws, or None when the scene can't tell.
+    ///
+    /// Shells compare it across rebuilds to skip presenting frames that would
+    /// look identical. Hit regions don't affect pixels and are left out.
+    fn content_hash(&self) -> Option<u64> {
+        None
+    }
 }
 
 /// Abstraction implemented by concrete renderer backends.
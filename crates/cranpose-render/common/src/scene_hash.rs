@@ -0,0 +1,172 @@
+//! Hashing helpers for [`RenderScene::content_hash`](crate::RenderScene::content_hash).
+//!
+//! Floats are hashed by their bit patterns, so any visible change to a value
+//! changes the hash while identical rebuilds hash the same.
+
+use std::hash::{Hash, Hasher};
+
+use crate::RoundedClip;
+
+use cranpose_ui_graphics::{
+    ArcShape, Brush, Color, CornerRadii, DrawPrimitive, LineShape, Rect, RoundedCornerShape,
+    TextStyle,
+};
+
+pub fn hash_f32<H: Hasher>(state: &mut H, value: f32) {
+    state.write_u32(value.to_bits());
+}
+
+pub fn hash_rect<H: Hasher>(state: &mut H, rect: Rect) {
+    hash_f32(state, rect.x);
+    hash_f32(state, rect.y);
+    hash_f32(state, rect.width);
+    hash_f32(state, rect.height);
+}
+
+pub fn hash_option_rect<H: Hasher>(state: &mut H, rect: Option<Rect>) {
+    match rect {
+        Some(rect) => {
+            state.write_u8(1);
+            hash_rect(state, rect);
+        }
+        None => state.write_u8(0),
+    }
+}
+
+pub fn hash_color<H: Hasher>(state: &mut H, color: Color) {
+    hash_f32(state, color.0);
+    hash_f32(state, color.1);
+    hash_f32(state, color.2);
+    hash_f32(state, color.3);
+}
+
+pub fn hash_brush<H: Hasher>(state: &mut H, brush: &Brush) {
+    match brush {
+        Brush::Solid(color) => {
+            state.write_u8(0);
+            hash_color(state, *color);
+        }
+        Brush::LinearGradient {
+            colors,
+            color_space,
+        } => {
+            state.write_u8(1);
+            state.write_usize(colors.len());
+            for color in colors {
+                hash_color(state, *color);
+            }
+            color_space.hash(state);
+        }
+        Brush::RadialGradient {
+            colors,
+            center,
+            radius,
+            color_space,
+        } => {
+            state.write_u8(2);
+            state.write_usize(colors.len());
+            for color in colors {
+                hash_color(state, *color);
+            }
+            hash_f32(state, center.x);
+            hash_f32(state, center.y);
+            hash_f32(state, *radius);
+            color_space.hash(state);
+        }
+    }
+}
+
+pub fn hash_corner_radii<H: Hasher>(state: &mut H, radii: CornerRadii) {
+    hash_f32(state, radii.top_left);
+    hash_f32(state, radii.top_right);
+    hash_f32(state, radii.bottom_right);
+    hash_f32(state, radii.bottom_left);
+}
+
+pub fn hash_option_rounded_clip<H: Hasher>(state: &mut H, clip: Option<RoundedClip>) {
+    match clip {
+        Some(clip) => {
+            state.write_u8(1);
+            hash_rect(state, clip.rect);
+            hash_corner_radii(state, clip.radii);
+        }
+        None => state.write_u8(0),
+    }
+}
+
+pub fn hash_option_shape<H: Hasher>(state: &mut H, shape: Option<RoundedCornerShape>) {
+    match shape {
+        Some(shape) => {
+            state.write_u8(1);
+            hash_corner_radii(state, shape.radii());
+        }
+        None => state.write_u8(0),
+    }
+}
+
+pub fn hash_option_arc<H: Hasher>(state: &mut H, arc: Option<ArcShape>) {
+    match arc {
+        Some(arc) => {
+            state.write_u8(1);
+            hash_f32(state, arc.start_angle);
+            hash_f32(state, arc.sweep_angle);
+            hash_f32(state, arc.stroke_width);
+        }
+        None => state.write_u8(0),
+    }
+}
+
+pub fn hash_option_line<H: Hasher>(state: &mut H, line: Option<LineShape>) {
+    match line {
+        Some(line) => {
+            state.write_u8(1);
+            hash_f32(state, line.start.x);
+            hash_f32(state, line.start.y);
+            hash_f32(state, line.end.x);
+            hash_f32(state, line.end.y);
+            hash_f32(state, line.stroke_width);
+        }
+        None => state.write_u8(0),
+    }
+}
+
+/// Hashes the style fields text is laid out with: line height and letter spacing.
+pub fn hash_text_style<H: Hasher>(state: &mut H, style: &TextStyle) {
+    for spacing in [style.line_height, style.letter_spacing] {
+        match spacing {
+            Some(spacing) => {
+                state.write_u8(1);
+                hash_f32(state, spacing.0);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+pub fn hash_primitive<H: Hasher>(state: &mut H, primitive: &DrawPrimitive) {
+    match primitive {
+        DrawPrimitive::Rect { rect, brush } => {
+            state.write_u8(0);
+            hash_rect(state, *rect);
+            hash_brush(state, brush);
+        }
+        DrawPrimitive::RoundRect { rect, brush, radii } => {
+            state.write_u8(1);
+            hash_rect(state, *rect);
+            hash_brush(state, brush);
+            hash_corner_radii(state, *radii);
+        }
+        DrawPrimitive::Arc { rect, brush, arc } => {
+            state.write_u8(2);
+            hash_rect(state, *rect);
+            hash_brush(state, brush);
+            hash_option_arc(state, Some(*arc));
+        }
+        DrawPrimitive::Line { rect, brush, line } => {
+            state.write_u8(3);
+            hash_rect(state, *rect);
+            hash_brush(state, brush);
+            hash_option_line(state, Some(*line));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Text snapshots of render scenes for golden tests.
+//!
+//! Backends format their draw lists with these helpers so snapshots read the
+//! same everywhere. Numbers are rounded to two decimals and colors are written
+//! as `#rrggbbaa`, so float noise below what a pixel can show doesn't break a
+//! golden.
+
+use cranpose_ui_graphics::{
+    ArcShape, Brush, Color, CornerRadii, GradientColorSpace, LineShape, Point, Rect,
+};
+
+/// Formats a number with at most two decimals and no trailing zeros.
+pub fn snapshot_f32(value: f32) -> String {
+    let text = format!("{value:.2}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    match text {
+        "-0" => "0".to_string(),
+        _ => text.to_string(),
+    }
+}
+
+pub fn snapshot_rect(rect: Rect) -> String {
+    format!(
+        "({}, {}, {}, {})",
+        snapshot_f32(rect.x),
+        snapshot_f32(rect.y),
+        snapshot_f32(rect.width),
+        snapshot_f32(rect.height)
+    )
+}
+
+pub fn snapshot_point(point: Point) -> String {
+    format!("({}, {})", snapshot_f32(point.x), snapshot_f32(point.y))
+}
+
+pub fn snapshot_color(color: Color) -> String {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        channel(color.0),
+        channel(color.1),
+        channel(color.2),
+        channel(color.3)
+    )
+}
+
+fn snapshot_colors(colors: &[Color]) -> String {
+    let colors: Vec<String> = colors.iter().copied().map(snapshot_color).collect();
+    colors.join(", ")
+}
+
+/// Gradients blended in the default linear color space omit it.
+pub fn snapshot_brush(brush: &Brush) -> String {
+    match brush {
+        Brush::Solid(color) => snapshot_color(*color),
+        Brush::LinearGradient {
+            colors,
+            color_space,
+        } => format!(
+            "linear[{}]{}",
+            snapshot_colors(colors),
+            snapshot_color_space(*color_space)
+        ),
+        Brush::RadialGradient {
+            colors,
+            center,
+            radius,
+            color_space,
+        } => format!(
+            "radial[{}] center={} radius={}{}",
+            snapshot_colors(colors),
+            snapshot_point(*center),
+            snapshot_f32(*radius),
+            snapshot_color_space(*color_space)
+        ),
+    }
+}
+
+fn snapshot_color_space(color_space: GradientColorSpace) -> &'static str {
+    match color_space {
+        GradientColorSpace::Srgb => " space=srgb",
+        GradientColorSpace::Linear => "",
+        GradientColorSpace::Oklab => " space=oklab",
+    }
+}
+
+/// Formats radii clockwise from the top-left corner.
+pub fn snapshot_radii(radii: CornerRadii) -> String {
+    format!(
+        "({}, {}, {}, {})",
+        snapshot_f32(radii.top_left),
+        snapshot_f32(radii.top_right),
+        snapshot_f32(radii.bottom_right),
+        snapshot_f32(radii.bottom_left)
+    )
+}
+
+pub fn snapshot_arc(arc: ArcShape) -> String {
+    format!(
+        "(start={}, sweep={}, stroke={})",
+        snapshot_f32(arc.start_angle),
+        snapshot_f32(arc.sweep_angle),
+        snapshot_f32(arc.stroke_width)
+    )
+}
+
+pub fn snapshot_line(line: LineShape) -> String {
+    format!(
+        "(start={}, end={}, stroke={})",
+        snapshot_point(line.start),
+        snapshot_point(line.end),
+        snapshot_f32(line.stroke_width)
+    )
+}
+
+/// Panics with a line diff when `actual` doesn't match the `expected` golden.
+///
+/// Leading and trailing whitespace is ignored on every line, as are blank
+/// lines, so goldens can be written as indented raw strings inside tests.
+pub fn assert_snapshot(actual: &str, expected: &str) {
+    let actual_lines = snapshot_lines(actual);
+    let expected_lines = snapshot_lines(expected);
+    if actual_lines == expected_lines {
+        return;
+    }
+
+    let mut diff = String::new();
+    for index in 0..actual_lines.len().max(expected_lines.len()) {
+        match (expected_lines.get(index), actual_lines.get(index)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                diff.push_str(&format!("  {expected}\n"));
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    diff.push_str(&format!("- {expected}\n"));
+                }
+                if let Some(actual) = actual {
+                    diff.push_str(&format!("+ {actual}\n"));
+                }
+            }
+        }
+    }
+    panic!("snapshot mismatch (- expected, + actual):\n{diff}");
+}
+
+fn snapshot_lines(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "tests/snapshot_tests.rs"]
+mod tests;
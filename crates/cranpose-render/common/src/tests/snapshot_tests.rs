@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn numbers_drop_trailing_zeros_and_negative_zero() {
+    assert_eq!(snapshot_f32(10.0), "10");
+    assert_eq!(snapshot_f32(0.5), "0.5");
+    assert_eq!(snapshot_f32(1.0 / 3.0), "0.33");
+    assert_eq!(snapshot_f32(-0.001), "0");
+}
+
+#[test]
+fn colors_are_written_as_hex() {
+    assert_eq!(snapshot_color(Color(1.0, 0.0, 0.5, 1.0)), "#ff0080ff");
+    assert_eq!(
+        snapshot_brush(&Brush::linear_gradient(vec![
+            Color(0.0, 0.0, 0.0, 1.0),
+            Color(1.0, 1.0, 1.0, 1.0),
+        ])),
+        "linear[#000000ff, #ffffffff]"
+    );
+    assert_eq!(
+        snapshot_brush(
+            &Brush::linear_gradient(vec![Color(0.0, 0.0, 0.0, 1.0)])
+                .with_color_space(GradientColorSpace::Oklab)
+        ),
+        "linear[#000000ff] space=oklab"
+    );
+}
+
+#[test]
+fn snapshot_ignores_indentation_and_blank_lines() {
+    assert_snapshot(
+        "shape z=0\ntext z=1\n",
+        r#"
+            shape z=0
+            text z=1
+        "#,
+    );
+}
+
+#[test]
+#[should_panic(expected = "+ text z=2")]
+fn snapshot_mismatch_reports_diff() {
+    assert_snapshot("shape z=0\ntext z=2", "shape z=0\ntext z=1");
+}
@@ -8,7 +8,7 @@ use std::rc::Rc;
 use std::sync::Mutex;
 
 use cranpose_ui::{Brush, TextMeasurer, TextMetrics};
-use cranpose_ui_graphics::{Color, Rect};
+use cranpose_ui_graphics::{Color, Rect, TextStyle};
 
 use crate::scene::{Scene, TextDraw};
 use crate::style::point_in_resolved_rounded_rect;
@@ -150,11 +150,15 @@ fn clip_bounds_from_clip(clip: Option<Rect>, width: u32, height: u32) -> Option<
 }
 
 impl TextMeasurer for CachedRusttypeTextMeasurer {
-    fn measure(&self, text: &str) -> TextMetrics {
+    fn measure(&self, text: &str, style: &TextStyle) -> TextMetrics {
+        // The cache is keyed by text alone, so only default spacing goes through it
+        if style.line_height.is_some() || style.letter_spacing.is_some() {
+            return measure_text_impl(text, style);
+        }
         self.cache
             .lock()
             .expect("text metrics cache poisoned")
-            .get_or_measure(text, measure_text_impl)
+            .get_or_measure(text, |text| measure_text_impl(text, style))
     }
 
     fn get_offset_for_position(&self, text: &str, x: f32, _y: f32) -> usize {
@@ -215,7 +219,7 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
         }
 
         // Also check end of text
-        let total_width = measure_text_impl(text).width;
+        let total_width = measure_text_impl(text, &TextStyle::default()).width;
         let end_dist = (x - total_width).abs();
         if end_dist < best_distance {
             best_offset = text.len();
@@ -232,7 +236,7 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
 
         // Measure text up to offset
         let prefix = &text[..clamped_offset];
-        measure_text_impl(prefix).width
+        measure_text_impl(prefix, &TextStyle::default()).width
     }
 
     fn layout(&self, text: &str) -> cranpose_ui::text_layout_result::TextLayoutResult {
@@ -285,7 +289,7 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
             height: line_height,
         });
 
-        let metrics = measure_text_impl(text);
+        let metrics = measure_text_impl(text, &TextStyle::default());
         TextLayoutResult::new(
             metrics.width,
             metrics.height,
@@ -298,11 +302,13 @@ impl TextMeasurer for CachedRusttypeTextMeasurer {
     }
 }
 
-fn measure_text_impl(text: &str) -> TextMetrics {
+fn measure_text_impl(text: &str, style: &TextStyle) -> TextMetrics {
     let scale = Scale::uniform(TEXT_SIZE);
     let font = &*FONT;
     let v_metrics = font.v_metrics(scale);
-    let line_height = (v_metrics.ascent - v_metrics.descent).ceil();
+    let glyph_height = (v_metrics.ascent - v_metrics.descent).ceil();
+    let line_height = style.line_height.map_or(glyph_height, |sp| sp.0);
+    let letter_spacing = style.letter_spacing.map_or(0.0, |sp| sp.0);
 
     // Split by newlines for multiline support
     let lines: Vec<&str> = text.split('\n').collect();
@@ -331,7 +337,7 @@ fn measure_text_impl(text: &str) -> TextMetrics {
         } else {
             (line_max_x - min_x).max(0.0)
         };
-        max_width = max_width.max(line_width);
+        max_width = max_width.max(line_width + glyph_count as f32 * letter_spacing);
     }
 
     TextMetrics {
@@ -339,6 +345,8 @@ fn measure_text_impl(text: &str) -> TextMetrics {
         height: line_count as f32 * line_height,
         line_height,
         line_count,
+        // Extra line height is split above and below the glyphs
+        first_baseline: v_metrics.ascent + (line_height - glyph_height) / 2.0,
     }
 }
 
@@ -383,11 +391,24 @@ fn draw_shape(frame: &mut [u8], width: u32, height: u32, draw: crate::scene::Dra
             }
             let center_x = px as f32 + 0.5;
             let center_y = py as f32 + 0.5;
-            if let Some(ref radii) = resolved_shape {
+            if let Some(arc) = draw.arc {
+                if !arc.contains(draw.rect, center_x, center_y) {
+                    continue;
+                }
+            } else if let Some(line) = draw.line {
+                if !line.contains(draw.rect, center_x, center_y) {
+                    continue;
+                }
+            } else if let Some(ref radii) = resolved_shape {
                 if !point_in_resolved_rounded_rect(center_x, center_y, draw.rect, radii) {
                     continue;
                 }
             }
+            if let Some(clip) = draw.rounded_clip {
+                if !point_in_resolved_rounded_rect(center_x, center_y, clip.rect, &clip.radii) {
+                    continue;
+                }
+            }
             let sample = sample_brush(&draw.brush, draw.rect, center_x, center_y);
             let alpha = sample[3];
             if alpha <= 0.0 {
@@ -474,18 +495,22 @@ fn color_to_rgba(color: Color) -> [f32; 4] {
 fn sample_brush(brush: &Brush, rect: Rect, x: f32, y: f32) -> [f32; 4] {
     match brush {
         Brush::Solid(color) => color_to_rgba(*color),
-        Brush::LinearGradient(colors) => {
+        Brush::LinearGradient {
+            colors,
+            color_space,
+        } => {
             let t = if rect.height.abs() <= f32::EPSILON {
                 0.0
             } else {
                 ((y - rect.y) / rect.height).clamp(0.0, 1.0)
             };
-            color_to_rgba(interpolate_colors(colors, t))
+            color_to_rgba(color_space.interpolate(colors, t))
         }
         Brush::RadialGradient {
             colors,
             center,
             radius,
+            color_space,
         } => {
             let cx = rect.x + center.x;
             let cy = rect.y + center.y;
@@ -494,35 +519,7 @@ fn sample_brush(brush: &Brush, rect: Rect, x: f32, y: f32) -> [f32; 4] {
             let dy = y - cy;
             let distance = (dx * dx + dy * dy).sqrt();
             let t = (distance / radius).clamp(0.0, 1.0);
-            color_to_rgba(interpolate_colors(colors, t))
+            color_to_rgba(color_space.interpolate(colors, t))
         }
     }
 }
-
-fn interpolate_colors(colors: &[Color], t: f32) -> Color {
-    if colors.is_empty() {
-        return Color(0.0, 0.0, 0.0, 0.0);
-    }
-    if colors.len() == 1 {
-        return colors[0];
-    }
-    let clamped = t.clamp(0.0, 1.0);
-    let segments = (colors.len() - 1) as f32;
-    let scaled = clamped * segments;
-    let index = scaled.floor() as usize;
-    if index >= colors.len() - 1 {
-        return *colors.last().unwrap();
-    }
-    let frac = scaled - index as f32;
-    lerp_color(colors[index], colors[index + 1], frac)
-}
-
-fn lerp_color(a: Color, b: Color, t: f32) -> Color {
-    let lerp = |start: f32, end: f32| start + (end - start) * t;
-    Color(
-        lerp(a.0, b.0),
-        lerp(a.1, b.1),
-        lerp(a.2, b.2),
-        lerp(a.3, b.3),
-    )
-}
@@ -15,6 +15,9 @@ pub enum PixelsRendererError {
     Layout(String),
 }
 
+/// Software renderer drawing the scene into a pixel frame buffer.
+///
+/// `Modifier::blur` is a no-op here: blurred subtrees are drawn unchanged.
 pub struct PixelsRenderer {
     scene: Scene,
 }
@@ -1,8 +1,11 @@
 use std::rc::Rc;
 
 use cranpose_core::{MemoryApplier, NodeId};
-use cranpose_render_common::Brush;
-use cranpose_ui::{measure_text, LayoutBox, LayoutNode, LayoutNodeKind, SubcomposeLayoutNode};
+use cranpose_render_common::{node_z_index, Brush};
+use cranpose_ui::{
+    measure_text_with_style, sort_by_z_index, LayoutBox, LayoutNode, LayoutNodeKind,
+    SubcomposeLayoutNode,
+};
 use cranpose_ui_graphics::{Color, GraphicsLayer, Point, Rect, RoundedCornerShape, Size};
 
 use crate::scene::{ClickAction, Scene};
@@ -120,8 +123,9 @@ fn render_container(
 
     // Render text content if present in modifier slices.
     // Text is now handled via TextModifierNode in the modifier chain.
-    if let Some(value) = layout.node_data.modifier_slices().text_content_rc() {
-        let metrics = measure_text(value.as_ref());
+    let modifier_slices = layout.node_data.modifier_slices();
+    if let Some(value) = modifier_slices.text_content_rc() {
+        let metrics = measure_text_with_style(value.as_ref(), modifier_slices.text_style());
         let padding = style.padding;
         let text_rect = Rect {
             x: rect.x + padding.left,
@@ -153,7 +157,13 @@ fn render_container(
         hit_clip,
     );
 
-    for child_layout in &layout.children {
+    let mut children: Vec<(&LayoutBox, f32)> = layout
+        .children
+        .iter()
+        .map(|child| (child, child.node_data.modifier_slices().z_index()))
+        .collect();
+    sort_by_z_index(&mut children);
+    for (child_layout, _) in children {
         render_layout_node(child_layout, node_layer, scene, visual_clip, hit_clip);
     }
 
@@ -361,7 +371,7 @@ fn render_node_from_applier(
 
     // Render text content if present
     if let Some(value) = modifier_slices.text_content_rc() {
-        let metrics = measure_text(value.as_ref());
+        let metrics = measure_text_with_style(value.as_ref(), modifier_slices.text_style());
         let padding = style.padding;
         let text_rect = Rect {
             x: rect.x + padding.left,
@@ -401,7 +411,12 @@ fn render_node_from_applier(
         x: abs_x + layout_state.content_offset.x,
         y: abs_y + layout_state.content_offset.y,
     };
-    for child_id in children {
+    let mut children: Vec<(NodeId, f32)> = children
+        .into_iter()
+        .map(|child_id| (child_id, node_z_index(applier, child_id)))
+        .collect();
+    sort_by_z_index(&mut children);
+    for (child_id, _) in children {
         render_node_from_applier(
             applier,
             child_id,
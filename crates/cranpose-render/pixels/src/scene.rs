@@ -1,19 +1,31 @@
 use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
 use cranpose_core::{run_in_mutable_snapshot, NodeId};
 use cranpose_foundation::{PointerEvent, PointerEventKind};
-use cranpose_render_common::{HitTestTarget, RenderScene};
-use cranpose_ui_graphics::{Brush, Color, Rect, RoundedCornerShape};
+use cranpose_render_common::scene_hash::{
+    hash_brush, hash_color, hash_f32, hash_option_arc, hash_option_line, hash_option_rect,
+    hash_option_rounded_clip, hash_option_shape, hash_rect,
+};
+use cranpose_render_common::{HitTestTarget, RenderScene, RoundedClip};
+use cranpose_ui_graphics::{ArcShape, Brush, Color, LineShape, Rect, RoundedCornerShape, Size};
 
 #[derive(Clone)]
 pub struct DrawShape {
     pub rect: Rect,
     pub brush: Brush,
     pub shape: Option<RoundedCornerShape>,
+    /// Draws only this arc of the circle inscribed in `rect`; overrides `shape`.
+    pub arc: Option<ArcShape>,
+    /// Draws only this line inside `rect`; overrides `shape`.
+    pub line: Option<LineShape>,
     pub z_index: usize,
     pub clip: Option<Rect>,
+    /// Rounded rectangle the shape is additionally clipped to.
+    pub rounded_clip: Option<RoundedClip>,
 }
 
 #[derive(Clone)]
@@ -71,7 +83,12 @@ impl HitTestTarget for HitRegion {
             y: y - self.rect.y,
         };
 
-        let local_event = event.copy_with_local_position(local);
+        let local_event = event
+            .copy_with_local_position(local)
+            .with_target_size(Size {
+                width: self.rect.width,
+                height: self.rect.height,
+            });
 
         let has_pointer_inputs = !self.pointer_inputs.is_empty();
         let has_click_actions = kind == PointerEventKind::Down && !self.click_actions.is_empty();
@@ -89,11 +106,16 @@ impl HitTestTarget for HitRegion {
                 handler(local_event.clone());
             }
 
-            // Only perform click actions if NOT consumed
-            if kind == PointerEventKind::Down && !local_event.is_consumed() {
+            // Only perform click actions if NOT consumed. A click is handled by the
+            // topmost clickable region, so regions underneath don't see it.
+            if kind == PointerEventKind::Down
+                && !local_event.is_consumed()
+                && !self.click_actions.is_empty()
+            {
                 for action in &self.click_actions {
                     action.invoke(self.rect, x, y);
                 }
+                local_event.consume();
             }
         }) {
             eprintln!(
@@ -156,11 +178,51 @@ impl Scene {
             rect,
             brush,
             shape,
+            arc: None,
+            line: None,
             z_index,
             clip,
+            rounded_clip: None,
         });
     }
 
+    pub fn push_arc(&mut self, rect: Rect, brush: Brush, arc: ArcShape, clip: Option<Rect>) {
+        let z_index = self.next_z;
+        self.next_z += 1;
+        self.shapes.push(DrawShape {
+            rect,
+            brush,
+            shape: None,
+            arc: Some(arc),
+            line: None,
+            z_index,
+            clip,
+            rounded_clip: None,
+        });
+    }
+
+    pub fn push_line(&mut self, rect: Rect, brush: Brush, line: LineShape, clip: Option<Rect>) {
+        let z_index = self.next_z;
+        self.next_z += 1;
+        self.shapes.push(DrawShape {
+            rect,
+            brush,
+            shape: None,
+            arc: None,
+            line: Some(line),
+            z_index,
+            clip,
+            rounded_clip: None,
+        });
+    }
+
+    /// Clips the shapes pushed since `first_shape` to a rounded rectangle.
+    pub fn clip_shapes_to(&mut self, first_shape: usize, clip: RoundedClip) {
+        for shape in &mut self.shapes[first_shape..] {
+            shape.rounded_clip = Some(clip);
+        }
+    }
+
     pub fn push_text(
         &mut self,
         node_id: NodeId,
@@ -230,20 +292,43 @@ impl RenderScene for Scene {
     }
 
     fn hit_test(&self, x: f32, y: f32) -> Vec<Self::HitTarget> {
-        let mut hits: Vec<_> = self
-            .hits
+        // Regions are pushed in draw order after children are sorted by z-index,
+        // so walking them backwards yields the region drawn on top first. Regions
+        // outside their ancestors' clip don't match.
+        self.hits
             .iter()
+            .rev()
             .filter(|hit| hit.contains(x, y))
             .cloned()
-            .collect();
-
-        // Sort by z-index descending (top to bottom)
-        hits.sort_by(|a, b| b.z_index.cmp(&a.z_index));
-        hits
+            .collect()
     }
 
     fn find_target(&self, node_id: NodeId) -> Option<Self::HitTarget> {
         // O(1) lookup using the node index
         self.node_index.get(&node_id).cloned()
     }
+
+    fn content_hash(&self) -> Option<u64> {
+        let mut state = DefaultHasher::new();
+        state.write_usize(self.shapes.len());
+        for shape in &self.shapes {
+            hash_rect(&mut state, shape.rect);
+            hash_brush(&mut state, &shape.brush);
+            hash_option_shape(&mut state, shape.shape);
+            hash_option_arc(&mut state, shape.arc);
+            hash_option_line(&mut state, shape.line);
+            hash_option_rounded_clip(&mut state, shape.rounded_clip);
+            hash_option_rect(&mut state, shape.clip);
+        }
+        state.write_usize(self.texts.len());
+        for text in &self.texts {
+            text.node_id.hash(&mut state);
+            hash_rect(&mut state, text.rect);
+            text.text.hash(&mut state);
+            hash_color(&mut state, text.color);
+            hash_f32(&mut state, text.scale);
+            hash_option_rect(&mut state, text.clip);
+        }
+        Some(state.finish())
+    }
 }
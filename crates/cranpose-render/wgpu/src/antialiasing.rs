@@ -0,0 +1,182 @@
+//! Antialiasing configuration for shape edges.
+//!
+//! Shapes are drawn as signed distance fields, so their edges are smoothed
+//! analytically in the fragment shader. MSAA additionally multisamples the frame
+//! and resolves it into the surface, at the cost of an extra render target.
+
+/// How shape edges are antialiased.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AntialiasingMode {
+    /// Hard edges: a pixel is either inside a shape or not.
+    None,
+    /// Edges fade over one screen pixel using the distance field's derivative.
+    #[default]
+    Analytic,
+    /// Analytic edges drawn into a multisampled target with the given sample count.
+    ///
+    /// Counts the device cannot render fall back to a single sample.
+    Msaa(u32),
+}
+
+impl AntialiasingMode {
+    /// Edge softness passed to the shape shader.
+    pub(crate) fn edge_softness(self) -> f32 {
+        match self {
+            AntialiasingMode::None => 0.0,
+            AntialiasingMode::Analytic | AntialiasingMode::Msaa(_) => 1.0,
+        }
+    }
+
+    /// Sample count requested by this mode, before validation against the device.
+    fn requested_sample_count(self) -> u32 {
+        match self {
+            AntialiasingMode::None | AntialiasingMode::Analytic => 1,
+            AntialiasingMode::Msaa(count) => count,
+        }
+    }
+}
+
+/// Features of `format` the renderer may use on `device`.
+///
+/// The adapter's own features only apply when the device was created with
+/// [`wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`]; otherwise only
+/// the features WebGPU guarantees for the format are valid.
+pub(crate) fn surface_format_features(
+    adapter: &wgpu::Adapter,
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+) -> wgpu::TextureFormatFeatures {
+    if device
+        .features()
+        .contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES)
+    {
+        adapter.get_texture_format_features(format)
+    } else {
+        format.guaranteed_format_features(device.features())
+    }
+}
+
+/// Returns the sample count to render with for `mode` on a surface format with
+/// `format_features`.
+///
+/// Counts the format cannot be multisampled and resolved with fall back to a
+/// single sample.
+pub(crate) fn supported_sample_count(
+    mode: AntialiasingMode,
+    format_features: wgpu::TextureFormatFeatures,
+) -> u32 {
+    let requested = mode.requested_sample_count();
+    let flags = format_features.flags;
+    let supported = requested == 1
+        || (flags.sample_count_supported(requested)
+            && flags.contains(wgpu::TextureFormatFeatureFlags::MULTISAMPLE_RESOLVE));
+    if supported {
+        requested
+    } else {
+        log::warn!("{requested}x MSAA is not supported for the surface format; using 1 sample");
+        1
+    }
+}
+
+/// A color attachment, optionally multisampled and resolved into the real target.
+#[derive(Clone, Copy)]
+pub(crate) struct ColorTarget<'a> {
+    view: &'a wgpu::TextureView,
+    resolve_target: Option<&'a wgpu::TextureView>,
+}
+
+impl<'a> ColorTarget<'a> {
+    /// Draws into `msaa` and resolves into `target`, or into `target` directly
+    /// when rendering single-sampled.
+    pub fn new(msaa: Option<&'a wgpu::TextureView>, target: &'a wgpu::TextureView) -> Self {
+        match msaa {
+            Some(view) => Self {
+                view,
+                resolve_target: Some(target),
+            },
+            None => Self {
+                view: target,
+                resolve_target: None,
+            },
+        }
+    }
+
+    pub fn attachment(
+        self,
+        load: wgpu::LoadOp<wgpu::Color>,
+    ) -> wgpu::RenderPassColorAttachment<'a> {
+        wgpu::RenderPassColorAttachment {
+            view: self.view,
+            resolve_target: self.resolve_target,
+            ops: wgpu::Operations {
+                load,
+                store: wgpu::StoreOp::Store,
+            },
+        }
+    }
+}
+
+/// Multisampled texture that a frame is drawn into before resolving.
+pub(crate) struct MsaaTarget {
+    width: u32,
+    height: u32,
+    view: wgpu::TextureView,
+}
+
+impl MsaaTarget {
+    /// Returns the multisampled view for a `width` x `height` frame, reallocating
+    /// `slot` when the size changes, or None when rendering single-sampled.
+    pub fn view(
+        slot: &mut Option<MsaaTarget>,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+        width: u32,
+        height: u32,
+    ) -> Option<wgpu::TextureView> {
+        if sample_count <= 1 {
+            return None;
+        }
+        let stale = slot
+            .as_ref()
+            .is_none_or(|msaa| msaa.width != width || msaa.height != height);
+        if stale {
+            *slot = Some(Self::new(device, format, sample_count, width, height));
+        }
+        slot.as_ref().map(|msaa| msaa.view.clone())
+    }
+
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        let view = device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("MSAA Target"),
+                size: wgpu::Extent3d {
+                    width: width.max(1),
+                    height: height.max(1),
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            })
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        Self {
+            width,
+            height,
+            view,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/antialiasing_tests.rs"]
+mod tests;
@@ -0,0 +1,470 @@
+//! Offscreen gaussian blur for `Modifier::blur` layers.
+//!
+//! A blurred subtree's shapes and text are drawn into an offscreen texture,
+//! blurred horizontally into a second texture, then blurred vertically while
+//! compositing back onto the frame. [`plan_render_steps`] decides what goes
+//! offscreen and [`BlurRenderer`] owns the targets and the two blur pipelines.
+
+use crate::antialiasing::{ColorTarget, MsaaTarget};
+use crate::scene::BlurLayer;
+use crate::shaders;
+use bytemuck::{Pod, Zeroable};
+use cranpose_ui_graphics::Rect;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Blur radii below this many physical pixels are drawn without blurring.
+const MIN_BLUR_RADIUS_PX: f32 = 0.5;
+/// Upper bound on the kernel half-size, keeping the per-fragment loop bounded.
+const MAX_KERNEL_RADIUS_PX: f32 = 128.0;
+
+/// Set once a blur nested in another blurred subtree has been reported.
+static NESTED_BLUR_WARNED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BlurDirection {
+    Horizontal,
+    Vertical,
+}
+
+/// One direction of the separable blur.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct BlurPass {
+    pub direction: BlurDirection,
+    /// Number of taps; the kernel reaches `kernel_size / 2` texels to each side.
+    pub kernel_size: u32,
+    pub sigma: f32,
+}
+
+/// Returns the horizontal and vertical passes for a blur of `radius_px` physical
+/// pixels, or None when the radius is too small to be visible.
+pub(crate) fn blur_passes(radius_px: f32) -> Option<[BlurPass; 2]> {
+    if radius_px.is_nan() || radius_px < MIN_BLUR_RADIUS_PX {
+        return None;
+    }
+    let radius = radius_px.min(MAX_KERNEL_RADIUS_PX).ceil();
+    let kernel_size = radius as u32 * 2 + 1;
+    // The kernel spans three standard deviations on each side
+    let sigma = radius / 3.0;
+    let pass = |direction| BlurPass {
+        direction,
+        kernel_size,
+        sigma,
+    };
+    Some([
+        pass(BlurDirection::Horizontal),
+        pass(BlurDirection::Vertical),
+    ])
+}
+
+/// A contiguous run of shapes and where to draw it.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum RenderStep {
+    /// Shapes drawn straight onto the frame.
+    Direct(Range<usize>),
+    /// Shapes and texts drawn into an offscreen target, then blurred and
+    /// composited within `region`.
+    Blurred {
+        shapes: Range<usize>,
+        texts: Range<usize>,
+        region: Rect,
+        passes: [BlurPass; 2],
+    },
+}
+
+/// Splits `shape_count` shapes into direct and blurred steps, in draw order.
+///
+/// A blurred step also draws the texts of its layer; every other text is drawn
+/// after all steps.
+///
+/// Nested blur layers are drawn as part of their outermost layer, which is
+/// blurred once with its own radius; the inner radii are dropped with a
+/// warning the first time it happens. Layer radii are in logical pixels and
+/// are scaled by `root_scale`.
+pub(crate) fn plan_render_steps(
+    shape_count: usize,
+    layers: &[BlurLayer],
+    root_scale: f32,
+) -> Vec<RenderStep> {
+    // Layers are recorded when their subtree ends, so outer layers follow inner ones
+    let mut outermost: Vec<(&BlurLayer, [BlurPass; 2])> = Vec::new();
+    for layer in layers {
+        let Some(passes) = blur_passes(layer.radius * root_scale) else {
+            continue;
+        };
+        let count = outermost.len();
+        outermost.retain(|(inner, _)| !layer.contains(inner));
+        if outermost.len() < count && !NESTED_BLUR_WARNED.swap(true, Ordering::Relaxed) {
+            log::warn!(
+                "nested Modifier::blur layers are blurred once with the outermost radius; \
+                 inner blurs are ignored"
+            );
+        }
+        outermost.push((layer, passes));
+    }
+    outermost.sort_by_key(|(layer, _)| layer.shapes.start);
+
+    let mut steps = Vec::new();
+    let mut next = 0;
+    for (layer, passes) in outermost {
+        if layer.shapes.start > next {
+            steps.push(RenderStep::Direct(next..layer.shapes.start));
+        }
+        steps.push(RenderStep::Blurred {
+            shapes: layer.shapes.clone(),
+            texts: layer.texts.clone(),
+            region: Rect {
+                x: layer.rect.x - layer.radius,
+                y: layer.rect.y - layer.radius,
+                width: layer.rect.width + layer.radius * 2.0,
+                height: layer.rect.height + layer.radius * 2.0,
+            },
+            passes,
+        });
+        next = layer.shapes.end;
+    }
+    if next < shape_count {
+        steps.push(RenderStep::Direct(next..shape_count));
+    }
+    steps
+}
+
+/// Converts a logical region into a physical scissor rect `[x, y, width, height]`
+/// clamped to the frame, or None when nothing of it is visible.
+pub(crate) fn scissor_rect(
+    region: Rect,
+    root_scale: f32,
+    width: u32,
+    height: u32,
+) -> Option<[u32; 4]> {
+    let left = (region.x * root_scale).floor().clamp(0.0, width as f32) as u32;
+    let top = (region.y * root_scale).floor().clamp(0.0, height as f32) as u32;
+    let right = ((region.x + region.width) * root_scale)
+        .ceil()
+        .clamp(0.0, width as f32) as u32;
+    let bottom = ((region.y + region.height) * root_scale)
+        .ceil()
+        .clamp(0.0, height as f32) as u32;
+    (right > left && bottom > top).then_some([left, top, right - left, bottom - top])
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+struct BlurParams {
+    direction: [f32; 2],
+    sigma: f32,
+    radius: f32,
+}
+
+/// Offscreen textures for one frame size, with bind groups sampling them.
+struct BlurTargets {
+    width: u32,
+    height: u32,
+    /// Receives the blurred subtree's shapes.
+    content_view: wgpu::TextureView,
+    /// Receives the horizontal pass.
+    intermediate_view: wgpu::TextureView,
+    /// Samples `content_view` for the horizontal pass.
+    horizontal_bind_group: wgpu::BindGroup,
+    /// Samples `intermediate_view` for the vertical pass.
+    vertical_bind_group: wgpu::BindGroup,
+}
+
+/// GPU resources for blurring layers.
+pub(crate) struct BlurRenderer {
+    format: wgpu::TextureFormat,
+    /// Sample count of the frame; content is drawn multisampled to match it.
+    sample_count: u32,
+    horizontal_pipeline: wgpu::RenderPipeline,
+    vertical_pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    horizontal_params: wgpu::Buffer,
+    vertical_params: wgpu::Buffer,
+    targets: Option<BlurTargets>,
+    content_msaa: Option<MsaaTarget>,
+}
+
+impl BlurRenderer {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat, sample_count: u32) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Blur Shader"),
+            source: wgpu::ShaderSource::Wgsl(shaders::BLUR_SHADER.into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Blur Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Blur Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // Offscreen content is premultiplied (drawn with alpha blending onto
+        // transparent black), so the horizontal pass overwrites its target and
+        // the vertical pass composites with premultiplied blending onto the
+        // (possibly multisampled) frame.
+        let create_pipeline = |label, blend, count| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_fullscreen"),
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_blur"),
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count,
+                    ..Default::default()
+                },
+                multiview: None,
+                cache: None,
+            })
+        };
+        let horizontal_pipeline = create_pipeline("Horizontal Blur Pipeline", None, 1);
+        let vertical_pipeline = create_pipeline(
+            "Vertical Blur Pipeline",
+            Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            sample_count,
+        );
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Blur Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let create_params = |label| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size: std::mem::size_of::<BlurParams>() as u64,
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            })
+        };
+
+        Self {
+            format,
+            sample_count,
+            horizontal_pipeline,
+            vertical_pipeline,
+            bind_group_layout,
+            sampler,
+            horizontal_params: create_params("Horizontal Blur Params"),
+            vertical_params: create_params("Vertical Blur Params"),
+            targets: None,
+            content_msaa: None,
+        }
+    }
+
+    /// Returns the offscreen view that blurred content is resolved into, and the
+    /// multisampled view it is drawn into when rendering with MSAA. Targets are
+    /// allocated on first use and whenever the frame size changes.
+    pub fn content_target(
+        &mut self,
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+    ) -> (wgpu::TextureView, Option<wgpu::TextureView>) {
+        let stale = self
+            .targets
+            .as_ref()
+            .is_none_or(|targets| targets.width != width || targets.height != height);
+        if stale {
+            self.targets = Some(self.create_targets(device, width, height));
+        }
+        let msaa = MsaaTarget::view(
+            &mut self.content_msaa,
+            device,
+            self.format,
+            self.sample_count,
+            width,
+            height,
+        );
+        let content = self
+            .targets
+            .as_ref()
+            .expect("blur targets")
+            .content_view
+            .clone();
+        (content, msaa)
+    }
+
+    fn create_targets(&self, device: &wgpu::Device, width: u32, height: u32) -> BlurTargets {
+        let create_view = |label| {
+            device
+                .create_texture(&wgpu::TextureDescriptor {
+                    label: Some(label),
+                    size: wgpu::Extent3d {
+                        width: width.max(1),
+                        height: height.max(1),
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: self.format,
+                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                        | wgpu::TextureUsages::TEXTURE_BINDING,
+                    view_formats: &[],
+                })
+                .create_view(&wgpu::TextureViewDescriptor::default())
+        };
+        let content_view = create_view("Blur Content Target");
+        let intermediate_view = create_view("Blur Intermediate Target");
+
+        let create_bind_group = |label, view: &wgpu::TextureView, params: &wgpu::Buffer| {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(label),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&self.sampler),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: params.as_entire_binding(),
+                    },
+                ],
+            })
+        };
+        let horizontal_bind_group = create_bind_group(
+            "Horizontal Blur Bind Group",
+            &content_view,
+            &self.horizontal_params,
+        );
+        let vertical_bind_group = create_bind_group(
+            "Vertical Blur Bind Group",
+            &intermediate_view,
+            &self.vertical_params,
+        );
+
+        BlurTargets {
+            width,
+            height,
+            content_view,
+            intermediate_view,
+            horizontal_bind_group,
+            vertical_bind_group,
+        }
+    }
+
+    /// Encodes both blur passes over the content target: horizontally into the
+    /// intermediate target, then vertically onto `output` within `scissor`.
+    ///
+    /// The parameters are written through `queue`, so the encoder must be
+    /// submitted before the next layer is encoded.
+    pub fn encode(
+        &self,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        passes: &[BlurPass; 2],
+        output: ColorTarget,
+        output_load: wgpu::LoadOp<wgpu::Color>,
+        scissor: [u32; 4],
+    ) {
+        let Some(targets) = &self.targets else {
+            return;
+        };
+        for pass in passes {
+            let (params, texel) = match pass.direction {
+                BlurDirection::Horizontal => {
+                    (&self.horizontal_params, [1.0 / targets.width as f32, 0.0])
+                }
+                BlurDirection::Vertical => {
+                    (&self.vertical_params, [0.0, 1.0 / targets.height as f32])
+                }
+            };
+            let params_data = BlurParams {
+                direction: texel,
+                sigma: pass.sigma.max(f32::EPSILON),
+                radius: (pass.kernel_size / 2) as f32,
+            };
+            queue.write_buffer(params, 0, bytemuck::bytes_of(&params_data));
+        }
+
+        {
+            let mut horizontal = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Horizontal Blur Pass"),
+                color_attachments: &[Some(
+                    ColorTarget::new(None, &targets.intermediate_view)
+                        .attachment(wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT)),
+                )],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            horizontal.set_pipeline(&self.horizontal_pipeline);
+            horizontal.set_bind_group(0, &targets.horizontal_bind_group, &[]);
+            horizontal.draw(0..3, 0..1);
+        }
+
+        let mut vertical = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Vertical Blur Pass"),
+            color_attachments: &[Some(output.attachment(output_load))],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        let [x, y, width, height] = scissor;
+        vertical.set_scissor_rect(x, y, width, height);
+        vertical.set_pipeline(&self.vertical_pipeline);
+        vertical.set_bind_group(0, &targets.vertical_bind_group, &[]);
+        vertical.draw(0..3, 0..1);
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/blur_tests.rs"]
+mod tests;
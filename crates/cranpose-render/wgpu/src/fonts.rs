@@ -0,0 +1,119 @@
+//! Runtime font loading into the shared [`FontSystem`].
+//!
+//! Fonts given to [`WgpuRenderer::new_with_fonts`](crate::WgpuRenderer::new_with_fonts)
+//! are loaded once at startup; these helpers add and remove fonts afterwards,
+//! e.g. for an icon font fetched on demand. When startup leaves no faces at
+//! all, an embedded fallback font is loaded so text still renders.
+
+use crate::{BASE_FONT_SIZE, TEXT_LAYOUT_OPTIONS};
+use glyphon::fontdb::{self, Source};
+use glyphon::{Attrs, Buffer, FontSystem, Metrics};
+use std::sync::Arc;
+
+/// Last-resort font (Roboto Regular) for when no other face is available.
+static FALLBACK_FONT: &[u8] = include_bytes!("../../../../assets/Roboto-Regular.ttf");
+
+/// Handle to a font loaded with [`WgpuRenderer::add_font`](crate::WgpuRenderer::add_font).
+///
+/// A font file can hold several faces (e.g. a collection); the handle covers
+/// all of them so they are removed together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FontId {
+    faces: Vec<fontdb::ID>,
+}
+
+impl FontId {
+    /// Number of faces the font file contributed.
+    pub fn face_count(&self) -> usize {
+        self.faces.len()
+    }
+}
+
+/// Creates the font system with the platform's fonts plus `fonts`, using the
+/// locale of [`TEXT_LAYOUT_OPTIONS`].
+///
+/// The family of the first app font becomes the default (sans-serif) family.
+/// Otherwise a host that happens to have the font system's own default
+/// installed would lay text out with it instead of the app's font.
+pub(crate) fn create_font_system(fonts: &[&[u8]]) -> FontSystem {
+    let (_, mut db) = FontSystem::new().into_locale_and_db();
+    let mut default_family = None;
+    for (i, data) in fonts.iter().enumerate() {
+        log::info!("Loading font #{}, size: {} bytes", i, data.len());
+        let faces = db.load_font_source(Source::Binary(Arc::new(data.to_vec())));
+        if default_family.is_none() {
+            default_family = faces
+                .first()
+                .and_then(|&face| db.face(face))
+                .and_then(|face| face.families.first())
+                .map(|(family, _)| family.clone());
+        }
+    }
+    if let Some(family) = default_family {
+        db.set_sans_serif_family(family);
+    }
+    FontSystem::new_with_locale_and_db(TEXT_LAYOUT_OPTIONS.locale.to_string(), db)
+}
+
+/// Loads `data` into `font_system` and returns the handle to its faces.
+///
+/// Going through `db_mut` drops the font system's cached family matches, so
+/// text shaped afterwards can fall back to the new faces.
+pub(crate) fn load_font(font_system: &mut FontSystem, data: &[u8]) -> FontId {
+    let faces = font_system
+        .db_mut()
+        .load_font_source(Source::Binary(Arc::new(data.to_vec())));
+    if faces.is_empty() {
+        log::warn!("Font data of {} bytes contained no faces", data.len());
+    }
+    FontId {
+        faces: faces.to_vec(),
+    }
+}
+
+/// Loads the embedded fallback font when `font_system` has no faces.
+///
+/// Returns the fallback's handle when it had to be loaded.
+pub(crate) fn ensure_fallback_font(font_system: &mut FontSystem) -> Option<FontId> {
+    if font_system.db().faces().next().is_some() {
+        return None;
+    }
+    log::warn!("No fonts loaded, using the embedded fallback font");
+    Some(load_font(font_system, FALLBACK_FONT))
+}
+
+/// Whether `font_system` shapes `text` into at least one glyph.
+pub(crate) fn can_shape(font_system: &mut FontSystem, text: &str) -> bool {
+    // Shaping without any face panics in cosmic-text
+    if font_system.db().faces().next().is_none() {
+        return false;
+    }
+    let mut buffer = Buffer::new(font_system, Metrics::new(BASE_FONT_SIZE, BASE_FONT_SIZE));
+    buffer.set_text(
+        font_system,
+        text,
+        &Attrs::new(),
+        TEXT_LAYOUT_OPTIONS.shaping,
+    );
+    buffer.shape_until_scroll(font_system, false);
+    buffer.layout_runs().any(|run| !run.glyphs.is_empty())
+}
+
+/// Removes the faces of `font` from `font_system`.
+///
+/// Returns false when none of them were loaded, e.g. after a second removal.
+pub(crate) fn unload_font(font_system: &mut FontSystem, font: &FontId) -> bool {
+    let db = font_system.db_mut();
+    let mut removed = false;
+    for &face in &font.faces {
+        if db.face(face).is_some() {
+            db.remove_face(face);
+            removed = true;
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+#[path = "tests/fonts_tests.rs"]
+mod tests;
@@ -3,36 +3,50 @@
 //! This renderer uses WGPU for cross-platform GPU support across
 //! desktop (Windows/Mac/Linux), web (WebGPU), and mobile (Android/iOS).
 
+mod antialiasing;
+mod blur;
+mod fonts;
 mod pipeline;
 mod render;
 mod scene;
 mod shaders;
+mod stats;
+mod text_cache;
 
-pub use scene::{ClickAction, DrawShape, HitRegion, Scene, TextDraw};
+pub use antialiasing::AntialiasingMode;
+pub use fonts::FontId;
+pub use scene::{BlurLayer, ClickAction, DrawShape, HitRegion, Scene, TextDraw, TextParams};
+pub use stats::RenderStats;
+pub use text_cache::TextCacheStats;
 
 use cranpose_core::{MemoryApplier, NodeId};
 use cranpose_render_common::{RenderScene, Renderer};
-use cranpose_ui::{set_text_measurer, LayoutTree, TextMeasurer};
-use cranpose_ui_graphics::Size;
-use glyphon::{Attrs, Buffer, FontSystem, Metrics, Shaping};
+use cranpose_ui::{
+    request_layout_invalidation, request_render_invalidation, set_text_measurer, LayoutTree,
+    TextMeasurer,
+};
+use cranpose_ui_graphics::{Size, TextStyle};
+use glyphon::{Attrs, Buffer, FontSystem, Metrics, Shaping, Wrap};
 use lru::LruCache;
 use render::GpuRenderer;
 use rustc_hash::FxHasher;
-use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use text_cache::{SharedTextCache, TextCache};
 
-/// Size-only cache for ultra-fast text measurement lookups.
+/// Metrics-only cache for ultra-fast text measurement lookups.
 /// Key: (text_hash, font_size_fixed_point)
-/// Value: (text_content, size) - text stored to handle hash collisions
-type TextSizeCache = Arc<Mutex<LruCache<(u64, i32), (String, Size)>>>;
+/// Value: (text_content, metrics) - text stored to handle hash collisions
+type TextSizeCache = Arc<Mutex<LruCache<(u64, i32), (String, cranpose_ui::TextMetrics)>>>;
 
 #[derive(Debug)]
 pub enum WgpuRendererError {
     Layout(String),
     Wgpu(String),
+    /// No loaded font can shape text.
+    Font(String),
 }
 
 /// Unified hash key for text caching - shared between measurement and rendering.
@@ -46,20 +60,34 @@ pub(crate) enum TextKey {
 pub(crate) struct TextCacheKey {
     key: TextKey,
     scale_bits: u32, // f32 as bits for hashing
+    spacing_bits: [u32; 2],
+    /// Width content keys wrap at; node buffers reshape when theirs changes
+    wrap_bits: Option<u32>,
 }
 
 impl TextCacheKey {
-    fn new(text: &str, font_size: f32) -> Self {
+    fn new(text: &str, font_size: f32, spacing: TextSpacing) -> Self {
         Self {
             key: TextKey::Content(text.to_string()),
             scale_bits: font_size.to_bits(),
+            spacing_bits: spacing.to_bits(),
+            wrap_bits: None,
         }
     }
 
-    fn for_node(node_id: NodeId, font_size: f32) -> Self {
+    fn wrapped(text: &str, font_size: f32, spacing: TextSpacing, wrap_width: f32) -> Self {
+        Self {
+            wrap_bits: Some(wrap_width.to_bits()),
+            ..Self::new(text, font_size, spacing)
+        }
+    }
+
+    fn for_node(node_id: NodeId, font_size: f32, spacing: TextSpacing) -> Self {
         Self {
             key: TextKey::Node(node_id),
             scale_bits: font_size.to_bits(),
+            spacing_bits: spacing.to_bits(),
+            wrap_bits: None,
         }
     }
 }
@@ -68,6 +96,90 @@ impl Hash for TextCacheKey {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.key.hash(state);
         self.scale_bits.hash(state);
+        self.spacing_bits.hash(state);
+        self.wrap_bits.hash(state);
+    }
+}
+
+/// Settings text is shaped and measured with, the same on every backend.
+///
+/// Left to the font system, the host locale and default font family differ
+/// between desktop and web, so the same string could measure differently.
+/// Measurement always happens at [`BASE_FONT_SIZE`] in logical pixels, so the
+/// display's DPI never affects layout; glyph advances are unhinted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextLayoutOptions {
+    /// Locale used to pick fallback fonts instead of the host's.
+    pub locale: &'static str,
+    /// Shaping with the font's kerning and ligatures.
+    pub shaping: Shaping,
+    /// Measured widths are rounded to multiples of this many logical pixels,
+    /// hiding float differences between platforms.
+    pub width_granularity: f32,
+}
+
+/// The options every [`WgpuRenderer`] lays text out with.
+pub const TEXT_LAYOUT_OPTIONS: TextLayoutOptions = TextLayoutOptions {
+    locale: "en-US",
+    shaping: Shaping::Advanced,
+    width_granularity: 1.0 / 64.0,
+};
+
+impl TextLayoutOptions {
+    fn round_width(&self, width: f32) -> f32 {
+        (width / self.width_granularity).round() * self.width_granularity
+    }
+
+    /// Width to wrap text at so it breaks into the lines it was laid out with
+    /// when it measured `measured_width`.
+    ///
+    /// Measured widths are rounded, so the widest line may be slightly wider.
+    pub(crate) fn wrap_width(&self, measured_width: f32) -> f32 {
+        measured_width + self.width_granularity
+    }
+}
+
+/// Line height and letter spacing of a [`TextStyle`], as multiples of the font size
+/// so they scale with it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct TextSpacing {
+    line_height: f32,
+    letter_spacing: f32,
+}
+
+impl Default for TextSpacing {
+    fn default() -> Self {
+        Self {
+            line_height: 1.4,
+            letter_spacing: 0.0,
+        }
+    }
+}
+
+impl TextSpacing {
+    pub(crate) fn from_style(style: &TextStyle) -> Self {
+        let default = Self::default();
+        Self {
+            line_height: style
+                .line_height
+                .map_or(default.line_height, |sp| sp.0 / BASE_FONT_SIZE),
+            letter_spacing: style
+                .letter_spacing
+                .map_or(default.letter_spacing, |sp| sp.0 / BASE_FONT_SIZE),
+        }
+    }
+
+    fn metrics(self, font_size: f32) -> Metrics {
+        Metrics::new(font_size, font_size * self.line_height)
+    }
+
+    fn attrs(self) -> Attrs<'static> {
+        // glyphon takes letter spacing in ems, which is what we store
+        Attrs::new().letter_spacing(self.letter_spacing)
+    }
+
+    fn to_bits(self) -> [u32; 2] {
+        [self.line_height.to_bits(), self.letter_spacing.to_bits()]
     }
 }
 
@@ -76,43 +188,77 @@ pub(crate) struct SharedTextBuffer {
     pub(crate) buffer: Buffer,
     text: String,
     font_size: f32,
+    spacing: TextSpacing,
+    /// Width lines wrap at between words, or None to only break at newlines
+    wrap_width: Option<f32>,
     /// Cached size to avoid recalculating on every access
     cached_size: Option<Size>,
 }
 
 impl SharedTextBuffer {
-    /// Ensure the buffer has the correct text and font_size, only reshaping if needed
+    /// Creates an empty buffer; the first [`ensure`](Self::ensure) shapes its text.
+    pub(crate) fn new(font_system: &mut FontSystem, font_size: f32, spacing: TextSpacing) -> Self {
+        let mut buffer = Buffer::new(font_system, spacing.metrics(font_size));
+        // Words wider than the wrap width keep a line of their own
+        buffer.set_wrap(font_system, Wrap::Word);
+        Self {
+            buffer,
+            text: String::new(),
+            font_size: 0.0,
+            spacing,
+            wrap_width: None,
+            cached_size: None,
+        }
+    }
+
+    /// Ensure the buffer has the correct text, font_size, spacing and wrap
+    /// width, only reshaping if needed
+    ///
+    /// Returns true if the text was reshaped.
     pub(crate) fn ensure(
         &mut self,
         font_system: &mut FontSystem,
         text: &str,
         font_size: f32,
-        attrs: Attrs,
-    ) {
+        spacing: TextSpacing,
+        wrap_width: Option<f32>,
+    ) -> bool {
         let text_changed = self.text != text;
         let font_changed = (self.font_size - font_size).abs() > 0.1;
+        let spacing_changed = self.spacing != spacing;
+        let wrap_changed = self.wrap_width != wrap_width;
 
         // Only reshape if something actually changed
-        if !text_changed && !font_changed {
-            return; // Nothing changed, skip reshape
+        if !text_changed && !font_changed && !spacing_changed && !wrap_changed {
+            return false; // Nothing changed, skip reshape
         }
 
-        // Set metrics and size for unlimited layout
-        let metrics = Metrics::new(font_size, font_size * 1.4);
-        self.buffer.set_metrics(font_system, metrics);
+        // Set metrics and size; without a wrap width the layout is unlimited
         self.buffer
-            .set_size(font_system, Some(f32::MAX), Some(f32::MAX));
+            .set_metrics(font_system, spacing.metrics(font_size));
+        self.buffer.set_size(
+            font_system,
+            Some(wrap_width.unwrap_or(f32::MAX)),
+            Some(f32::MAX),
+        );
 
         // Set text and shape
-        self.buffer
-            .set_text(font_system, text, &attrs, Shaping::Advanced);
+        self.buffer.set_text(
+            font_system,
+            text,
+            &spacing.attrs(),
+            TEXT_LAYOUT_OPTIONS.shaping,
+        );
         self.buffer.shape_until_scroll(font_system, false);
 
         // Update cached values
         self.text.clear();
         self.text.push_str(text);
         self.font_size = font_size;
+        self.spacing = spacing;
+        self.wrap_width = wrap_width;
         self.cached_size = None; // Invalidate size cache
+        true
     }
 
     /// Get or calculate the size of the shaped text
@@ -123,14 +269,18 @@ impl SharedTextBuffer {
 
         // Calculate size from buffer
         let mut max_width = 0.0f32;
+        let mut line_count = 0;
         let layout_runs = self.buffer.layout_runs();
         for run in layout_runs {
             max_width = max_width.max(run.line_w);
+            line_count += 1;
         }
-        let total_height = self.buffer.lines.len() as f32 * font_size * 1.4;
+        // Wrapped paragraphs take several lines; empty text still takes one
+        let line_count = line_count.max(self.buffer.lines.len());
+        let total_height = line_count as f32 * font_size * self.spacing.line_height;
 
         let size = Size {
-            width: max_width,
+            width: TEXT_LAYOUT_OPTIONS.round_width(max_width),
             height: total_height,
         };
 
@@ -139,34 +289,6 @@ impl SharedTextBuffer {
     }
 }
 
-/// Shared cache for text buffers used by both measurement and rendering
-pub(crate) type SharedTextCache = Arc<Mutex<HashMap<TextCacheKey, SharedTextBuffer>>>;
-
-/// Trim text cache if it exceeds MAX_CACHE_ITEMS.
-/// Removes the oldest half of entries when limit is reached.
-pub(crate) fn trim_text_cache(cache: &mut HashMap<TextCacheKey, SharedTextBuffer>) {
-    if cache.len() > MAX_CACHE_ITEMS {
-        let target_size = MAX_CACHE_ITEMS / 2;
-        let to_remove = cache.len() - target_size;
-
-        // Remove oldest entries (arbitrary keys from the front)
-        let keys_to_remove: Vec<TextCacheKey> = cache.keys().take(to_remove).cloned().collect();
-
-        for key in keys_to_remove {
-            cache.remove(&key);
-        }
-
-        log::debug!(
-            "Trimmed text cache from {} to {} entries",
-            cache.len() + to_remove,
-            cache.len()
-        );
-    }
-}
-
-/// Maximum number of cached text buffers before trimming occurs
-const MAX_CACHE_ITEMS: usize = 256;
-
 /// WGPU-based renderer for GPU-accelerated 2D rendering.
 ///
 /// This renderer supports:
@@ -180,8 +302,14 @@ pub struct WgpuRenderer {
     font_system: Arc<Mutex<FontSystem>>,
     /// Shared text buffer cache used by both measurement and rendering
     text_cache: SharedTextCache,
+    /// Measurer installed for layout, kept to drop its sizes when fonts change
+    text_measurer: WgpuTextMeasurer,
     /// Root scale factor for text rendering (use for density scaling)
     root_scale: f32,
+    /// Edge antialiasing applied when GPU resources are created
+    antialiasing: AntialiasingMode,
+    /// What the last rendered frame took
+    render_stats: RenderStats,
 }
 
 impl WgpuRenderer {
@@ -198,8 +326,6 @@ impl WgpuRenderer {
     /// let renderer = WgpuRenderer::new_with_fonts(&[font_light, font_regular]);
     /// ```
     pub fn new_with_fonts(fonts: &[&[u8]]) -> Self {
-        let mut font_system = FontSystem::new();
-
         // On Android, DO NOT load system fonts
         // Modern Android uses Variable Fonts for Roboto which can cause
         // rasterization corruption or font ID conflicts with glyphon.
@@ -211,72 +337,165 @@ impl WgpuRenderer {
         }
 
         // Load application-provided fonts
-        for (i, font_data) in fonts.iter().enumerate() {
-            log::info!("Loading font #{}, size: {} bytes", i, font_data.len());
-            font_system.db_mut().load_font_data(font_data.to_vec());
-        }
+        let font_system = fonts::create_font_system(fonts);
 
         let face_count = font_system.db().faces().count();
         log::info!("Total font faces loaded: {}", face_count);
 
-        if face_count == 0 {
-            log::error!("No fonts loaded! Text rendering will fail!");
-        }
+        Self::with_font_system(font_system)
+    }
+
+    /// Create a new WGPU renderer without application fonts.
+    ///
+    /// **Warning:** This is for internal use only. Applications should use `new_with_fonts()`.
+    /// Text uses the platform's fonts, or the embedded fallback font if there are none.
+    pub fn new() -> Self {
+        Self::with_font_system(fonts::create_font_system(&[]))
+    }
+
+    fn with_font_system(mut font_system: FontSystem) -> Self {
+        fonts::ensure_fallback_font(&mut font_system);
 
         let font_system = Arc::new(Mutex::new(font_system));
 
         // Create shared text cache for both measurement and rendering
-        let text_cache = Arc::new(Mutex::new(HashMap::new()));
+        let text_cache = TextCache::shared();
 
         let text_measurer = WgpuTextMeasurer::new(font_system.clone(), text_cache.clone());
         set_text_measurer(text_measurer.clone());
 
-        Self {
+        let renderer = Self {
             scene: Scene::new(),
             gpu_renderer: None,
             font_system,
             text_cache,
+            text_measurer,
             root_scale: 1.0,
+            antialiasing: AntialiasingMode::default(),
+            render_stats: RenderStats::default(),
+        };
+        if let Err(err) = renderer.check_fonts() {
+            log::error!("{:?}", err);
         }
+        renderer
     }
 
-    /// Create a new WGPU renderer without any fonts.
+    /// Checks that the loaded fonts can shape text.
     ///
-    /// **Warning:** This is for internal use only. Applications should use `new_with_fonts()`.
-    /// Text rendering will fail without fonts.
-    pub fn new() -> Self {
-        let font_system = FontSystem::new();
-        let font_system = Arc::new(Mutex::new(font_system));
-        let text_cache = Arc::new(Mutex::new(HashMap::new()));
-
-        let text_measurer = WgpuTextMeasurer::new(font_system.clone(), text_cache.clone());
-        set_text_measurer(text_measurer.clone());
-
-        Self {
-            scene: Scene::new(),
-            gpu_renderer: None,
-            font_system,
-            text_cache,
-            root_scale: 1.0,
+    /// Fails with [`WgpuRendererError::Font`] when not a single glyph can be
+    /// shaped, e.g. because every loaded font was removed again.
+    pub fn check_fonts(&self) -> Result<(), WgpuRendererError> {
+        let mut font_system = self.font_system.lock().unwrap();
+        let face_count = font_system.db().faces().count();
+        if fonts::can_shape(&mut font_system, "A") {
+            Ok(())
+        } else {
+            Err(WgpuRendererError::Font(format!(
+                "no glyphs could be shaped with {face_count} font faces"
+            )))
         }
     }
 
+    /// Number of font faces text can be shaped with.
+    pub fn font_face_count(&self) -> usize {
+        self.font_system.lock().unwrap().db().faces().count()
+    }
+
     /// Initialize GPU resources with a WGPU device and queue.
+    ///
+    /// `adapter` is the one `device` was requested from; it tells which MSAA
+    /// sample counts the surface format supports.
     pub fn init_gpu(
+        &mut self,
+        adapter: &wgpu::Adapter,
+        device: Arc<wgpu::Device>,
+        queue: Arc<wgpu::Queue>,
+        surface_format: wgpu::TextureFormat,
+    ) {
+        let format_features =
+            antialiasing::surface_format_features(adapter, &device, surface_format);
+        self.init_gpu_with_format_features(device, queue, surface_format, format_features);
+    }
+
+    fn init_gpu_with_format_features(
         &mut self,
         device: Arc<wgpu::Device>,
         queue: Arc<wgpu::Queue>,
         surface_format: wgpu::TextureFormat,
+        format_features: wgpu::TextureFormatFeatures,
     ) {
         self.gpu_renderer = Some(GpuRenderer::new(
             device,
             queue,
             surface_format,
+            format_features,
             self.font_system.clone(),
             self.text_cache.clone(),
+            self.antialiasing,
         ));
     }
 
+    /// Set how shape edges are antialiased.
+    ///
+    /// MSAA sample counts the surface format does not support fall back to a single sample
+    /// with analytic edges. Changing the mode after `init_gpu` recreates the GPU
+    /// resources on the same device.
+    pub fn set_antialiasing(&mut self, mode: AntialiasingMode) {
+        if self.antialiasing == mode {
+            return;
+        }
+        self.antialiasing = mode;
+        if let Some(gpu_renderer) = self.gpu_renderer.take() {
+            self.init_gpu_with_format_features(
+                gpu_renderer.device.clone(),
+                gpu_renderer.queue.clone(),
+                gpu_renderer.surface_format,
+                gpu_renderer.format_features,
+            );
+        }
+    }
+
+    /// Load a font at runtime, e.g. an icon font fetched after startup.
+    ///
+    /// Text shaped with the previous fonts is dropped from the caches and a
+    /// layout pass is requested, so measured and drawn text picks up the new
+    /// faces on the next frame.
+    pub fn add_font(&self, data: &[u8]) -> FontId {
+        self.text_measurer
+            .update_fonts(|font_system| fonts::load_font(font_system, data))
+    }
+
+    /// Remove a font loaded with [`add_font`](Self::add_font).
+    ///
+    /// Returns false when the font was already removed. Keep at least one font
+    /// loaded; text cannot be shaped without any.
+    pub fn remove_font(&self, font: &FontId) -> bool {
+        self.text_measurer
+            .update_fonts(|font_system| fonts::unload_font(font_system, font))
+    }
+
+    /// Set how many shaped text buffers are cached before the oldest half is dropped.
+    ///
+    /// Text-heavy apps that keep reshaping the same strings (see the misses in
+    /// [`text_cache_stats`](Self::text_cache_stats)) benefit from a larger cache.
+    /// Defaults to 256.
+    pub fn set_text_cache_capacity(&self, capacity: usize) {
+        self.text_cache.lock().unwrap().set_capacity(capacity);
+    }
+
+    /// Hit, miss and eviction counts of the shaped text cache since startup.
+    pub fn text_cache_stats(&self) -> TextCacheStats {
+        self.text_cache.lock().unwrap().stats()
+    }
+
+    /// Shapes, text areas, draw calls and GPU time of the last rendered frame.
+    ///
+    /// GPU time is only measured on devices created with
+    /// `wgpu::Features::TIMESTAMP_QUERY`.
+    pub fn render_stats(&self) -> RenderStats {
+        self.render_stats
+    }
+
     /// Set root scale factor for text rendering (e.g., density scaling on Android)
     pub fn set_root_scale(&mut self, scale: f32) {
         self.root_scale = scale;
@@ -290,16 +509,10 @@ impl WgpuRenderer {
         height: u32,
     ) -> Result<(), WgpuRendererError> {
         if let Some(gpu_renderer) = &mut self.gpu_renderer {
-            gpu_renderer
-                .render(
-                    view,
-                    &self.scene.shapes,
-                    &self.scene.texts,
-                    width,
-                    height,
-                    self.root_scale,
-                )
-                .map_err(WgpuRendererError::Wgpu)
+            self.render_stats = gpu_renderer
+                .render(view, &self.scene, width, height, self.root_scale)
+                .map_err(WgpuRendererError::Wgpu)?;
+            Ok(())
         } else {
             Err(WgpuRendererError::Wgpu(
                 "GPU renderer not initialized. Call init_gpu() first.".to_string(),
@@ -398,9 +611,12 @@ impl Renderer for WgpuRenderer {
         self.scene.push_text(
             NodeId::MAX,
             text_rect,
-            Rc::from(text),
-            Color(0.0, 1.0, 0.0, 1.0),  // Green
-            font_size / BASE_FONT_SIZE, // Scale relative to base
+            TextParams {
+                text: Rc::from(text),
+                color: Color(0.0, 1.0, 0.0, 1.0),  // Green
+                scale: font_size / BASE_FONT_SIZE, // Scale relative to base
+                style: TextStyle::default(),
+            },
             None,
         );
     }
@@ -427,86 +643,107 @@ impl WgpuTextMeasurer {
             text_cache,
         }
     }
+
+    /// Applies `update` to the font system and drops text shaped with the old fonts.
+    ///
+    /// Locks are taken in the order measurement and rendering use (font system,
+    /// then buffer cache, with the size cache never held alongside them), so an
+    /// update cannot deadlock against a measurement on another thread.
+    fn update_fonts<R>(&self, update: impl FnOnce(&mut FontSystem) -> R) -> R {
+        let result = {
+            let mut font_system = self.font_system.lock().unwrap();
+            let mut text_cache = self.text_cache.lock().unwrap();
+            let result = update(&mut font_system);
+            text_cache.clear();
+            result
+        };
+        self.size_cache.lock().unwrap().clear();
+        request_layout_invalidation();
+        request_render_invalidation();
+        result
+    }
 }
 
 // Base font size in logical units (dp) - shared between measurement and rendering
 pub(crate) const BASE_FONT_SIZE: f32 = 14.0;
 
 impl TextMeasurer for WgpuTextMeasurer {
-    fn measure(&self, text: &str) -> cranpose_ui::TextMetrics {
+    fn measure(&self, text: &str, style: &TextStyle) -> cranpose_ui::TextMetrics {
         let size_int = (BASE_FONT_SIZE * 100.0) as i32;
+        let spacing = TextSpacing::from_style(style);
 
         // Calculate hash to avoid allocating String for lookup
         // FxHasher is ~3x faster than DefaultHasher for short strings
         let mut hasher = FxHasher::default();
         text.hash(&mut hasher);
+        spacing.to_bits().hash(&mut hasher);
         let text_hash = hasher.finish();
         let cache_key = (text_hash, size_int);
 
         // Check size cache first (fastest path)
-        {
+        let cached = {
             let mut cache = self.size_cache.lock().unwrap();
-            if let Some((cached_text, size)) = cache.get(&cache_key) {
+            cache
+                .get(&cache_key)
                 // Verify partial collision
-                if cached_text == text {
-                    return cranpose_ui::TextMetrics {
-                        width: size.width,
-                        height: size.height,
-                        line_height: BASE_FONT_SIZE * 1.4,
-                        line_count: 1, // Cached entries are single-line simplified
-                    };
-                }
-            }
+                .filter(|(cached_text, _)| cached_text == text)
+                .map(|(_, metrics)| *metrics)
+        };
+        if let Some(metrics) = cached {
+            // Taken after the size cache is released, see `update_fonts`
+            self.text_cache.lock().unwrap().record_hit();
+            return metrics;
         }
 
         // Get or create text buffer
-        let text_buffer_key = TextCacheKey::new(text, BASE_FONT_SIZE);
+        let text_buffer_key = TextCacheKey::new(text, BASE_FONT_SIZE, spacing);
         let mut font_system = self.font_system.lock().unwrap();
         let mut text_cache = self.text_cache.lock().unwrap();
 
-        // Get or create buffer and calculate size
-        let size = {
-            let buffer = text_cache.entry(text_buffer_key).or_insert_with(|| {
-                let buffer = Buffer::new(
-                    &mut font_system,
-                    Metrics::new(BASE_FONT_SIZE, BASE_FONT_SIZE * 1.4),
-                );
-                SharedTextBuffer {
-                    buffer,
-                    text: String::new(),
-                    font_size: 0.0,
-                    cached_size: None,
-                }
-            });
-
-            // Ensure buffer has the correct text
-            buffer.ensure(&mut font_system, text, BASE_FONT_SIZE, Attrs::new());
+        // Get or create buffer and calculate size and first baseline
+        let (size, first_baseline) = {
+            let buffer = text_cache.shape(
+                &mut font_system,
+                text_buffer_key,
+                text,
+                BASE_FONT_SIZE,
+                spacing,
+                None,
+            );
 
             // Calculate size if not cached
-            buffer.size(BASE_FONT_SIZE)
+            let size = buffer.size(BASE_FONT_SIZE);
+            let first_baseline = buffer
+                .buffer
+                .layout_runs()
+                .next()
+                .map_or(BASE_FONT_SIZE, |run| run.line_y);
+            (size, first_baseline)
         };
 
         // Trim cache if needed (after we're done with buffer reference)
-        trim_text_cache(&mut text_cache);
+        text_cache.trim();
 
         drop(font_system);
         drop(text_cache);
 
-        // Cache the size result
-        let mut size_cache = self.size_cache.lock().unwrap();
-        // Only allocate string on cache miss
-        size_cache.put(cache_key, (text.to_string(), size));
-
         // Calculate line info for multiline support
-        let line_height = BASE_FONT_SIZE * 1.4;
+        let line_height = BASE_FONT_SIZE * spacing.line_height;
         let line_count = text.split('\n').count().max(1);
 
-        cranpose_ui::TextMetrics {
+        let metrics = cranpose_ui::TextMetrics {
             width: size.width,
             height: size.height,
             line_height,
             line_count,
-        }
+            first_baseline,
+        };
+
+        // Cache the result; only allocate the string on a cache miss
+        let mut size_cache = self.size_cache.lock().unwrap();
+        size_cache.put(cache_key, (text.to_string(), metrics));
+
+        metrics
     }
 
     fn get_offset_for_position(&self, text: &str, x: f32, y: f32) -> usize {
@@ -535,24 +772,19 @@ impl TextMeasurer for WgpuTextMeasurer {
         }
 
         // Use glyphon's hit testing for the specific line
-        let cache_key = TextCacheKey::new(line_text, BASE_FONT_SIZE);
+        let spacing = TextSpacing::default();
+        let cache_key = TextCacheKey::new(line_text, BASE_FONT_SIZE, spacing);
         let mut font_system = self.font_system.lock().unwrap();
         let mut text_cache = self.text_cache.lock().unwrap();
 
-        let buffer = text_cache.entry(cache_key).or_insert_with(|| {
-            let buffer = Buffer::new(
-                &mut font_system,
-                Metrics::new(BASE_FONT_SIZE, BASE_FONT_SIZE * 1.4),
-            );
-            SharedTextBuffer {
-                buffer,
-                text: String::new(),
-                font_size: 0.0,
-                cached_size: None,
-            }
-        });
-
-        buffer.ensure(&mut font_system, line_text, BASE_FONT_SIZE, Attrs::new());
+        let buffer = text_cache.shape(
+            &mut font_system,
+            cache_key,
+            line_text,
+            BASE_FONT_SIZE,
+            spacing,
+            None,
+        );
 
         // Find closest glyph position using layout runs
         let mut best_offset = 0;
@@ -593,7 +825,49 @@ impl TextMeasurer for WgpuTextMeasurer {
 
         // Measure text up to offset
         let prefix = &text[..clamped_offset];
-        self.measure(prefix).width
+        self.measure(prefix, &TextStyle::default()).width
+    }
+
+    fn measure_wrapped(
+        &self,
+        text: &str,
+        style: &TextStyle,
+        max_width: f32,
+        max_lines: Option<usize>,
+    ) -> cranpose_ui::TextMetrics {
+        let unwrapped = self.measure(text, style);
+        let mut metrics = if unwrapped.width <= max_width {
+            unwrapped
+        } else {
+            // Shape at the wrap width so lines break where they will be drawn
+            let spacing = TextSpacing::from_style(style);
+            let key = TextCacheKey::wrapped(text, BASE_FONT_SIZE, spacing, max_width);
+            let mut font_system = self.font_system.lock().unwrap();
+            let mut text_cache = self.text_cache.lock().unwrap();
+            let size = text_cache
+                .shape(
+                    &mut font_system,
+                    key,
+                    text,
+                    BASE_FONT_SIZE,
+                    spacing,
+                    Some(max_width),
+                )
+                .size(BASE_FONT_SIZE);
+            text_cache.trim();
+            let line_count = (size.height / unwrapped.line_height).round() as usize;
+            cranpose_ui::TextMetrics {
+                width: size.width,
+                height: line_count as f32 * unwrapped.line_height,
+                line_count,
+                ..unwrapped
+            }
+        };
+        if let Some(max_lines) = max_lines {
+            metrics.line_count = metrics.line_count.min(max_lines.max(1));
+            metrics.height = metrics.line_count as f32 * metrics.line_height;
+        }
+        metrics
     }
 
     fn layout(&self, text: &str) -> cranpose_ui::text_layout_result::TextLayoutResult {
@@ -602,20 +876,19 @@ impl TextMeasurer for WgpuTextMeasurer {
         let line_height = BASE_FONT_SIZE * 1.4;
 
         // Get buffer to extract glyph positions
-        let cache_key = TextCacheKey::new(text, BASE_FONT_SIZE);
+        let spacing = TextSpacing::default();
+        let cache_key = TextCacheKey::new(text, BASE_FONT_SIZE, spacing);
         let mut font_system = self.font_system.lock().unwrap();
         let mut text_cache = self.text_cache.lock().unwrap();
 
-        let buffer = text_cache.entry(cache_key.clone()).or_insert_with(|| {
-            let buffer = Buffer::new(&mut font_system, Metrics::new(BASE_FONT_SIZE, line_height));
-            SharedTextBuffer {
-                buffer,
-                text: String::new(),
-                font_size: 0.0,
-                cached_size: None,
-            }
-        });
-        buffer.ensure(&mut font_system, text, BASE_FONT_SIZE, Attrs::new());
+        let buffer = text_cache.shape(
+            &mut font_system,
+            cache_key,
+            text,
+            BASE_FONT_SIZE,
+            spacing,
+            None,
+        );
 
         // Extract glyph positions from layout runs
         let mut glyph_x_positions = Vec::new();
@@ -651,8 +924,12 @@ impl TextMeasurer for WgpuTextMeasurer {
             }
         }
 
+        // measure() takes the same locks
+        drop(text_cache);
+        drop(font_system);
+
         // Add end position
-        let total_width = self.measure(text).width;
+        let total_width = self.measure(text, &TextStyle::default()).width;
         glyph_x_positions.push(total_width);
         char_to_byte.push(text.len());
 
@@ -686,7 +963,7 @@ impl TextMeasurer for WgpuTextMeasurer {
             });
         }
 
-        let metrics = self.measure(text);
+        let metrics = self.measure(text, &TextStyle::default());
         TextLayoutResult::new(
             metrics.width,
             metrics.height,
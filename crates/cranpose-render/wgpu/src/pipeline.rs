@@ -4,11 +4,15 @@
 use std::rc::Rc;
 
 use cranpose_core::{MemoryApplier, NodeId};
-use cranpose_render_common::Brush;
-use cranpose_ui::{measure_text, LayoutBox, LayoutNode, LayoutNodeKind, SubcomposeLayoutNode};
+use cranpose_render_common::{node_z_index, Brush};
+use cranpose_ui::{
+    measure_text_wrapped, sort_by_z_index, LayoutBox, LayoutNode, LayoutNodeKind,
+    SubcomposeLayoutNode,
+};
 use cranpose_ui_graphics::{Color, GraphicsLayer, Point, Rect, RoundedCornerShape, Size};
 
-use crate::scene::{ClickAction, Scene};
+use crate::scene::{ClickAction, Scene, TextParams};
+use crate::TEXT_LAYOUT_OPTIONS;
 
 // Re-use style functions from a local copy
 mod style;
@@ -24,10 +28,8 @@ pub(crate) fn render_layout_tree(root: &LayoutBox, scene: &mut Scene) {
 
 pub(crate) fn render_layout_tree_with_scale(root: &LayoutBox, scene: &mut Scene, scale: f32) {
     let root_layer = GraphicsLayer {
-        alpha: 1.0,
         scale,
-        translation_x: 0.0,
-        translation_y: 0.0,
+        ..GraphicsLayer::default()
     };
     render_layout_node(root, root_layer, scene, None, None);
 }
@@ -114,6 +116,9 @@ fn render_container(
         (None, None) => None,
     };
 
+    let first_shape = scene.shapes.len();
+    let first_text = scene.texts.len();
+
     apply_draw_commands(
         &style.draw_commands,
         DrawPlacement::Behind,
@@ -137,9 +142,16 @@ fn render_container(
 
     // Render text content if present in modifier slices.
     // Text is now handled via TextModifierNode in the modifier chain.
-    if let Some(value) = layout.node_data.modifier_slices().text_content_rc() {
-        let metrics = measure_text(value.as_ref());
+    let modifier_slices = layout.node_data.modifier_slices();
+    if let Some(value) = modifier_slices.text_content_rc() {
         let padding = style.padding;
+        // Wrapped the way the text was laid out, within the node's content width
+        let metrics = measure_text_wrapped(
+            value.as_ref(),
+            modifier_slices.text_style(),
+            TEXT_LAYOUT_OPTIONS.wrap_width(rect.width - padding.horizontal_sum()),
+            None,
+        );
         let text_rect = Rect {
             x: rect.x + padding.left,
             y: rect.y + padding.top,
@@ -150,9 +162,12 @@ fn render_container(
         scene.push_text(
             layout.node_id,
             transformed_text_rect,
-            value,
-            apply_layer_to_color(Color(1.0, 1.0, 1.0, 1.0), node_layer),
-            node_layer.scale,
+            TextParams {
+                text: value,
+                color: apply_layer_to_color(Color(1.0, 1.0, 1.0, 1.0), node_layer),
+                scale: node_layer.scale,
+                style: modifier_slices.text_style().clone(),
+            },
             visual_clip,
         );
     }
@@ -170,7 +185,13 @@ fn render_container(
         hit_clip,
     );
 
-    for child_layout in &layout.children {
+    let mut children: Vec<(&LayoutBox, f32)> = layout
+        .children
+        .iter()
+        .map(|child| (child, child.node_data.modifier_slices().z_index()))
+        .collect();
+    sort_by_z_index(&mut children);
+    for (child_layout, _) in children {
         render_layout_node(child_layout, node_layer, scene, visual_clip, hit_clip);
     }
 
@@ -184,6 +205,13 @@ fn render_container(
         visual_clip,
         scene,
     );
+
+    scene.push_blur_layer(
+        transformed_rect,
+        style.blur_radius * node_layer.scale,
+        first_shape,
+        first_text,
+    );
 }
 
 fn render_spacer(
@@ -254,10 +282,8 @@ pub(crate) fn render_from_applier(
     scale: f32,
 ) {
     let root_layer = GraphicsLayer {
-        alpha: 1.0,
         scale,
-        translation_x: 0.0,
-        translation_y: 0.0,
+        ..GraphicsLayer::default()
     };
     render_node_from_applier(
         applier,
@@ -327,6 +353,7 @@ fn render_node_from_applier(
         shape: None,      // Now encoded in draw command round rects
         padding: resolved_modifiers.padding(),
         clip_to_bounds: modifier_slices.clip_to_bounds(),
+        blur_radius: modifier_slices.blur_radius(),
         draw_commands: modifier_slices.draw_commands().to_vec(),
         click_actions: modifier_slices.click_handlers().to_vec(),
         pointer_inputs: modifier_slices.pointer_inputs().to_vec(),
@@ -364,6 +391,9 @@ fn render_node_from_applier(
         (None, None) => None,
     };
 
+    let first_shape = scene.shapes.len();
+    let first_text = scene.texts.len();
+
     // Draw behind layer
     apply_draw_commands(
         &style.draw_commands,
@@ -388,8 +418,14 @@ fn render_node_from_applier(
 
     // Render text content if present
     if let Some(value) = modifier_slices.text_content_rc() {
-        let metrics = measure_text(value.as_ref());
         let padding = style.padding;
+        // Wrapped the way the text was laid out, within the node's content width
+        let metrics = measure_text_wrapped(
+            value.as_ref(),
+            modifier_slices.text_style(),
+            TEXT_LAYOUT_OPTIONS.wrap_width(rect.width - padding.horizontal_sum()),
+            None,
+        );
         let text_rect = Rect {
             x: rect.x + padding.left,
             y: rect.y + padding.top,
@@ -400,9 +436,12 @@ fn render_node_from_applier(
         scene.push_text(
             node_id,
             transformed_text_rect,
-            value,
-            apply_layer_to_color(Color(1.0, 1.0, 1.0, 1.0), node_layer),
-            node_layer.scale,
+            TextParams {
+                text: value,
+                color: apply_layer_to_color(Color(1.0, 1.0, 1.0, 1.0), node_layer),
+                scale: node_layer.scale,
+                style: modifier_slices.text_style().clone(),
+            },
             visual_clip,
         );
     }
@@ -428,7 +467,12 @@ fn render_node_from_applier(
         x: abs_x + layout_state.content_offset.x,
         y: abs_y + layout_state.content_offset.y,
     };
-    for child_id in children {
+    let mut children: Vec<(NodeId, f32)> = children
+        .into_iter()
+        .map(|child_id| (child_id, node_z_index(applier, child_id)))
+        .collect();
+    sort_by_z_index(&mut children);
+    for (child_id, _) in children {
         render_node_from_applier(
             applier,
             child_id,
@@ -451,4 +495,15 @@ fn render_node_from_applier(
         visual_clip,
         scene,
     );
+
+    scene.push_blur_layer(
+        transformed_rect,
+        style.blur_radius * node_layer.scale,
+        first_shape,
+        first_text,
+    );
 }
+
+#[cfg(test)]
+#[path = "tests/pipeline_tests.rs"]
+mod tests;
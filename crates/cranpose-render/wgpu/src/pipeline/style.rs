@@ -1,9 +1,11 @@
 use std::rc::Rc;
 
 use cranpose_foundation::PointerEvent;
+use cranpose_render_common::RoundedClip;
 use cranpose_ui::{Brush, DrawCommand, LayoutNodeData, ModifierNodeSlices};
 use cranpose_ui_graphics::{
-    Color, CornerRadii, DrawPrimitive, GraphicsLayer, Point, Rect, RoundedCornerShape, Size,
+    ArcShape, Color, CornerRadii, DrawPrimitive, GraphicsLayer, Point, Rect, RoundedCornerShape,
+    Size,
 };
 
 use crate::scene::Scene;
@@ -17,6 +19,7 @@ pub(crate) struct NodeStyle {
     pub draw_commands: Vec<DrawCommand>,
     pub graphics_layer: Option<GraphicsLayer>,
     pub clip_to_bounds: bool,
+    pub blur_radius: f32,
 }
 
 impl NodeStyle {
@@ -36,6 +39,7 @@ impl NodeStyle {
             draw_commands: slices.draw_commands().to_vec(),
             graphics_layer: slices.graphics_layer(), // Extracted from GraphicsLayerNode
             clip_to_bounds: slices.clip_to_bounds(),
+            blur_radius: slices.blur_radius(),
         }
     }
 }
@@ -50,6 +54,8 @@ pub(crate) fn combine_layers(
             scale: current.scale * layer.scale,
             translation_x: current.translation_x + layer.translation_x,
             translation_y: current.translation_y + layer.translation_y,
+            // Clip and shadow apply to the layer's own node through its modifier slices
+            ..current
         }
     } else {
         current
@@ -79,16 +85,21 @@ pub(crate) fn apply_layer_to_color(color: Color, layer: GraphicsLayer) -> Color
 pub(crate) fn apply_layer_to_brush(brush: Brush, layer: GraphicsLayer) -> Brush {
     match brush {
         Brush::Solid(color) => Brush::solid(apply_layer_to_color(color, layer)),
-        Brush::LinearGradient(colors) => Brush::LinearGradient(
-            colors
+        Brush::LinearGradient {
+            colors,
+            color_space,
+        } => Brush::LinearGradient {
+            colors: colors
                 .into_iter()
                 .map(|c| apply_layer_to_color(c, layer))
                 .collect(),
-        ),
+            color_space,
+        },
         Brush::RadialGradient {
             colors,
             mut center,
             mut radius,
+            color_space,
         } => {
             center.x *= layer.scale;
             center.y *= layer.scale;
@@ -100,6 +111,7 @@ pub(crate) fn apply_layer_to_brush(brush: Brush, layer: GraphicsLayer) -> Brush
                     .collect(),
                 center,
                 radius,
+                color_space,
             }
         }
     }
@@ -132,11 +144,24 @@ pub(crate) fn apply_draw_commands(
     scene: &mut Scene,
 ) {
     for command in commands {
-        let primitives = match (placement, command) {
-            (DrawPlacement::Behind, DrawCommand::Behind(func)) => func(size),
-            (DrawPlacement::Overlay, DrawCommand::Overlay(func)) => func(size),
+        let (primitives, rounded_clip) = match (placement, command) {
+            (DrawPlacement::Behind, DrawCommand::Behind(func)) => (func(size), None),
+            (DrawPlacement::Overlay, DrawCommand::Overlay(func)) => (func(size), None),
+            (DrawPlacement::Overlay, DrawCommand::OverlayClipped(func, shape)) => {
+                let radii = shape
+                    .map(|shape| {
+                        scale_corner_radii(shape.resolve(size.width, size.height), layer.scale)
+                    })
+                    .unwrap_or_default();
+                let rounded_clip = RoundedClip {
+                    rect: apply_layer_to_rect(rect, origin, layer),
+                    radii,
+                };
+                (func(size), Some(rounded_clip))
+            }
             _ => continue,
         };
+        let first_shape = scene.shapes.len();
         for primitive in primitives {
             match primitive {
                 DrawPrimitive::Rect {
@@ -160,8 +185,35 @@ pub(crate) fn apply_draw_commands(
                     let brush = apply_layer_to_brush(brush, layer);
                     scene.push_shape(transformed, brush, Some(shape), clip);
                 }
+                DrawPrimitive::Arc {
+                    rect: local_rect,
+                    brush,
+                    arc,
+                } => {
+                    let draw_rect = local_rect.translate(rect.x, rect.y);
+                    let transformed = apply_layer_to_rect(draw_rect, origin, layer);
+                    let arc = ArcShape {
+                        stroke_width: arc.stroke_width * layer.scale,
+                        ..arc
+                    };
+                    let brush = apply_layer_to_brush(brush, layer);
+                    scene.push_arc(transformed, brush, arc, clip);
+                }
+                DrawPrimitive::Line {
+                    rect: local_rect,
+                    brush,
+                    line,
+                } => {
+                    let draw_rect = local_rect.translate(rect.x, rect.y);
+                    let transformed = apply_layer_to_rect(draw_rect, origin, layer);
+                    let brush = apply_layer_to_brush(brush, layer);
+                    scene.push_line(transformed, brush, line.scaled(layer.scale), clip);
+                }
             }
         }
+        if let Some(rounded_clip) = rounded_clip {
+            scene.clip_shapes_to(first_shape, rounded_clip);
+        }
     }
 }
 
@@ -1,20 +1,23 @@
 //! GPU rendering implementation using WGPU
 
-use crate::scene::{DrawShape, TextDraw};
+use crate::antialiasing::{self, AntialiasingMode, ColorTarget, MsaaTarget};
+use crate::blur::{self, BlurRenderer, RenderStep};
+use crate::scene::{DrawShape, Scene, TextDraw};
 use crate::shaders;
-use crate::{SharedTextBuffer, SharedTextCache, TextCacheKey, BASE_FONT_SIZE};
+use crate::stats::{GpuFrameTimer, RenderStats};
+use crate::text_cache::{SharedTextCache, TextCache};
+use crate::{TextCacheKey, TextSpacing, BASE_FONT_SIZE, TEXT_LAYOUT_OPTIONS};
 use bytemuck::{Pod, Zeroable};
-use cranpose_ui_graphics::{Brush, Color};
+use cranpose_render_common::RoundedClip;
+use cranpose_ui_graphics::{Brush, Color, GradientColorSpace, Rect};
 use glyphon::{
-    Attrs, Cache, Color as GlyphonColor, FontSystem, Metrics, Resolution, SwashCache, TextArea,
-    TextAtlas, TextBounds, TextRenderer, Viewport,
+    Buffer, Cache, Color as GlyphonColor, FontSystem, PrepareError, Resolution, SwashCache,
+    TextArea, TextAtlas, TextBounds, TextRenderer, Viewport,
 };
+use std::ops::Range;
 use std::sync::{Arc, Mutex};
 
-// Chunked rendering constants for robustness with large scenes
-// Note: Limited to 256 for WebGL compatibility (uniform buffer size limit)
-// WebGL guarantees 16KB uniform buffers, ShapeData is 64 bytes = 256 max shapes
-const MAX_SHAPES_PER_DRAW: usize = 200; // ShapeData is 80 bytes, 16KB uniform limit = ~200 shapes
+// Every shape is one instance of a four-vertex quad, so a render step is a single draw
 const HARD_MAX_BUFFER_MB: usize = 64; // Maximum 64MB per buffer
 const CLEAR_COLOR: wgpu::Color = wgpu::Color {
     r: 18.0 / 255.0,
@@ -25,44 +28,56 @@ const CLEAR_COLOR: wgpu::Color = wgpu::Color {
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
-struct Vertex {
-    position: [f32; 2],
-    color: [f32; 4],
-    uv: [f32; 2],
+struct Uniforms {
+    viewport: [f32; 2],
+    edge_softness: f32,
+    _padding: f32,
+}
+
+/// Per-instance data for one shape, in physical pixels.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+struct ShapeInstance {
+    rect: [f32; 4],               // x, y, width, height
+    radii: [f32; 4], // top_left, top_right, bottom_left, bottom_right (arc: stroke, start, sweep, unused; line: half vector, half stroke, unused)
+    gradient_params: [f32; 4], // center.x, center.y, radius, color space
+    clip_rect: [f32; 4], // clip_x, clip_y, clip_width, clip_height (0,0,0,0 = no clip)
+    color: [f32; 4], // solid color, or the first gradient stop
+    brush_type: u32, // 0=solid, 1=linear_gradient, 2=radial_gradient
+    gradient_start: u32, // Starting index in gradient buffer
+    gradient_count: u32, // Number of gradient stops
+    shape_kind: u32, // 0=rounded_rect, 1=arc, 2=line
+    rounded_clip_rect: [f32; 4], // x, y, width, height (0,0,0,0 = no rounded clip)
+    rounded_clip_radii: [f32; 4], // top_left, top_right, bottom_left, bottom_right
 }
 
-impl Vertex {
-    const ATTRIBS: [wgpu::VertexAttribute; 3] =
-        wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x4, 2 => Float32x2];
+impl ShapeInstance {
+    const ATTRIBS: [wgpu::VertexAttribute; 11] = wgpu::vertex_attr_array![
+        0 => Float32x4,
+        1 => Float32x4,
+        2 => Float32x4,
+        3 => Float32x4,
+        4 => Float32x4,
+        5 => Uint32,
+        6 => Uint32,
+        7 => Uint32,
+        8 => Uint32,
+        9 => Float32x4,
+        10 => Float32x4,
+    ];
 
     fn desc() -> wgpu::VertexBufferLayout<'static> {
         wgpu::VertexBufferLayout {
-            array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
-            step_mode: wgpu::VertexStepMode::Vertex,
+            array_stride: std::mem::size_of::<ShapeInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
             attributes: &Self::ATTRIBS,
         }
     }
 }
 
-#[repr(C)]
-#[derive(Copy, Clone, Debug, Pod, Zeroable)]
-struct Uniforms {
-    viewport: [f32; 2],
-    _padding: [f32; 2],
-}
-
-#[repr(C)]
-#[derive(Copy, Clone, Debug, Pod, Zeroable)]
-struct ShapeData {
-    rect: [f32; 4],            // x, y, width, height
-    radii: [f32; 4],           // top_left, top_right, bottom_left, bottom_right
-    gradient_params: [f32; 4], // center.x, center.y, radius, unused
-    clip_rect: [f32; 4],       // clip_x, clip_y, clip_width, clip_height (0,0,0,0 = no clip)
-    brush_type: u32,           // 0=solid, 1=linear_gradient, 2=radial_gradient
-    gradient_start: u32,       // Starting index in gradient buffer
-    gradient_count: u32,       // Number of gradient stops
-    _padding: u32,
-}
+/// Most shapes a frame can draw before the instance buffer hits its size cap.
+const MAX_SHAPE_INSTANCES: usize =
+    HARD_MAX_BUFFER_MB * 1024 * 1024 / std::mem::size_of::<ShapeInstance>();
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
@@ -73,87 +88,66 @@ struct GradientStop {
 // Cached text buffer is now defined in lib.rs as SharedTextBuffer and shared
 // between measurement and rendering to eliminate duplicate text shaping
 
-/// Persistent GPU buffers for batched shape rendering
+/// Persistent GPU buffers for instanced shape rendering
 struct ShapeBatchBuffers {
-    vertex_buffer: wgpu::Buffer,
-    index_buffer: wgpu::Buffer,
-    shape_buffer: wgpu::Buffer,
+    instance_buffer: wgpu::Buffer,
     gradient_buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
-    vertex_capacity: usize,
-    index_capacity: usize,
-    shape_capacity: usize,
+    instance_capacity: usize,
     gradient_capacity: usize,
 }
 
 impl ShapeBatchBuffers {
     fn new(device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout) -> Self {
-        // For WebGL uniform buffers, size MUST match shader declaration (200 shapes)
-        // Shader declares: var<uniform> shape_data: array<ShapeData, 200>
-        // ShapeData is 80 bytes (with clip_rect), 16KB/80 = 200 shapes
-        const WEBGL_UNIFORM_SHAPE_COUNT: usize = 200;
+        // For WebGL uniform buffers, size MUST match shader declaration (256 stops)
         const WEBGL_UNIFORM_GRADIENT_COUNT: usize = 256;
+        const INITIAL_INSTANCE_COUNT: usize = 256;
 
-        let initial_vertex_cap = WEBGL_UNIFORM_SHAPE_COUNT * 4; // 4 vertices per shape
-        let initial_index_cap = WEBGL_UNIFORM_SHAPE_COUNT * 6; // 6 indices per shape
-        let initial_shape_cap = WEBGL_UNIFORM_SHAPE_COUNT;
-        let initial_gradient_cap = WEBGL_UNIFORM_GRADIENT_COUNT;
+        let instance_buffer = Self::create_instance_buffer(device, INITIAL_INSTANCE_COUNT);
+        let gradient_buffer = Self::create_gradient_buffer(device, WEBGL_UNIFORM_GRADIENT_COUNT);
+        let bind_group = Self::create_bind_group(device, bind_group_layout, &gradient_buffer);
 
-        let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Shape Vertex Buffer"),
-            size: (std::mem::size_of::<Vertex>() * initial_vertex_cap) as u64,
-            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
-        let index_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Shape Index Buffer"),
-            size: (std::mem::size_of::<u32>() * initial_index_cap) as u64,
-            usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
+        Self {
+            instance_buffer,
+            gradient_buffer,
+            bind_group,
+            instance_capacity: INITIAL_INSTANCE_COUNT,
+            gradient_capacity: WEBGL_UNIFORM_GRADIENT_COUNT,
+        }
+    }
 
-        // Use UNIFORM for WebGL compatibility (storage buffers not supported)
-        let shape_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Shape Data Buffer"),
-            size: (std::mem::size_of::<ShapeData>() * initial_shape_cap) as u64,
-            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+    fn create_instance_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Shape Instance Buffer"),
+            size: (std::mem::size_of::<ShapeInstance>() * capacity) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
-        });
+        })
+    }
 
-        let gradient_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+    // Use UNIFORM for WebGL compatibility (storage buffers not supported)
+    fn create_gradient_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Gradient Buffer"),
-            size: (std::mem::size_of::<GradientStop>() * initial_gradient_cap) as u64,
+            size: (std::mem::size_of::<GradientStop>() * capacity) as u64,
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
-        });
+        })
+    }
 
-        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+    fn create_bind_group(
+        device: &wgpu::Device,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        gradient_buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Shape Bind Group"),
             layout: bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: shape_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: gradient_buffer.as_entire_binding(),
-                },
-            ],
-        });
-
-        Self {
-            vertex_buffer,
-            index_buffer,
-            shape_buffer,
-            gradient_buffer,
-            bind_group,
-            vertex_capacity: initial_vertex_cap,
-            index_capacity: initial_index_cap,
-            shape_capacity: initial_shape_cap,
-            gradient_capacity: initial_gradient_cap,
-        }
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: gradient_buffer.as_entire_binding(),
+            }],
+        })
     }
 
     /// Ensure buffers have enough capacity, resizing if needed.
@@ -162,114 +156,250 @@ impl ShapeBatchBuffers {
         &mut self,
         device: &wgpu::Device,
         bind_group_layout: &wgpu::BindGroupLayout,
-        vertices_needed: usize,
-        indices_needed: usize,
-        shapes_needed: usize,
+        instances_needed: usize,
         gradients_needed: usize,
     ) {
-        let mut need_bind_group_update = false;
         let hard_max_bytes = HARD_MAX_BUFFER_MB * 1024 * 1024;
 
-        if vertices_needed > self.vertex_capacity {
-            let desired = vertices_needed.next_power_of_two();
-            let max_count = hard_max_bytes / std::mem::size_of::<Vertex>();
-            let new_cap = desired.min(max_count);
-            self.vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("Shape Vertex Buffer"),
-                size: (std::mem::size_of::<Vertex>() * new_cap) as u64,
-                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-                mapped_at_creation: false,
-            });
-            self.vertex_capacity = new_cap;
-        }
-
-        if indices_needed > self.index_capacity {
-            let desired = indices_needed.next_power_of_two();
-            let max_count = hard_max_bytes / std::mem::size_of::<u32>();
-            let new_cap = desired.min(max_count);
-            self.index_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("Shape Index Buffer"),
-                size: (std::mem::size_of::<u32>() * new_cap) as u64,
-                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
-                mapped_at_creation: false,
-            });
-            self.index_capacity = new_cap;
-        }
-
-        if shapes_needed > self.shape_capacity {
-            let desired = shapes_needed.next_power_of_two();
-            let max_count = hard_max_bytes / std::mem::size_of::<ShapeData>();
-            let new_cap = desired.min(max_count);
-            self.shape_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("Shape Data Buffer"),
-                size: (std::mem::size_of::<ShapeData>() * new_cap) as u64,
-                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-                mapped_at_creation: false,
-            });
-            self.shape_capacity = new_cap;
-            need_bind_group_update = true;
+        if instances_needed > self.instance_capacity {
+            let new_cap = instances_needed
+                .next_power_of_two()
+                .min(MAX_SHAPE_INSTANCES);
+            self.instance_buffer = Self::create_instance_buffer(device, new_cap);
+            self.instance_capacity = new_cap;
         }
 
         if gradients_needed > self.gradient_capacity {
             let desired = gradients_needed.max(1).next_power_of_two();
             let max_count = hard_max_bytes / std::mem::size_of::<GradientStop>();
             let new_cap = desired.min(max_count);
-            self.gradient_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("Gradient Buffer"),
-                size: (std::mem::size_of::<GradientStop>() * new_cap) as u64,
-                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-                mapped_at_creation: false,
-            });
+            self.gradient_buffer = Self::create_gradient_buffer(device, new_cap);
             self.gradient_capacity = new_cap;
-            need_bind_group_update = true;
+            self.bind_group =
+                Self::create_bind_group(device, bind_group_layout, &self.gradient_buffer);
         }
+    }
+}
 
-        if need_bind_group_update {
-            self.bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("Shape Bind Group"),
-                layout: bind_group_layout,
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: self.shape_buffer.as_entire_binding(),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 1,
-                        resource: self.gradient_buffer.as_entire_binding(),
-                    },
-                ],
-            });
+/// Positions in the frame's instance buffer of the shapes in `range`, given the
+/// scene index of every shape that survived clip culling (in ascending order).
+///
+/// Each range is drawn with a single instanced draw call.
+pub(crate) fn instance_range(drawn_indices: &[usize], range: Range<usize>) -> Range<usize> {
+    drawn_indices.partition_point(|&index| index < range.start)
+        ..drawn_indices.partition_point(|&index| index < range.end)
+}
+
+/// Color space a gradient blends in, as the shader's `gradient_params.w`.
+pub(crate) fn color_space_index(color_space: GradientColorSpace) -> f32 {
+    match color_space {
+        GradientColorSpace::Srgb => 0.0,
+        GradientColorSpace::Linear => 1.0,
+        GradientColorSpace::Oklab => 2.0,
+    }
+}
+
+/// Whether any part of `rect` can be seen: inside its `clip` (if any) and the
+/// `width` x `height` viewport.
+///
+/// `rect` and `clip` are scene rects, already placed by any layer transform,
+/// and are scaled by `root_scale` into the viewport's physical pixels.
+pub(crate) fn is_visible(
+    rect: Rect,
+    clip: Option<Rect>,
+    width: f32,
+    height: f32,
+    root_scale: f32,
+) -> bool {
+    let left = rect.x * root_scale;
+    let top = rect.y * root_scale;
+    let right = left + rect.width * root_scale;
+    let bottom = top + rect.height * root_scale;
+    let (clip_left, clip_top, clip_right, clip_bottom) = match clip {
+        Some(clip) => (
+            (clip.x * root_scale).max(0.0),
+            (clip.y * root_scale).max(0.0),
+            ((clip.x + clip.width) * root_scale).min(width),
+            ((clip.y + clip.height) * root_scale).min(height),
+        ),
+        None => (0.0, 0.0, width, height),
+    };
+    right > clip_left && left < clip_right && bottom > clip_top && top < clip_bottom
+}
+
+/// Scene indices of the shapes that can be seen in the `width` x `height`
+/// viewport, in draw order. Only these are uploaded and drawn.
+pub(crate) fn visible_shapes(
+    shapes: &[DrawShape],
+    width: f32,
+    height: f32,
+    root_scale: f32,
+) -> impl Iterator<Item = usize> + '_ {
+    shapes
+        .iter()
+        .enumerate()
+        .filter(move |(_, shape)| is_visible(shape.rect, shape.clip, width, height, root_scale))
+        .map(|(index, _)| index)
+}
+
+/// Shapes the `texts` of a frame and collects the ones that can be seen in
+/// the `width` x `height` viewport into `entries`, with their cache keys, in
+/// draw order.
+pub(crate) fn visible_texts(
+    texts: &[TextDraw],
+    font_system: &mut FontSystem,
+    text_cache: &mut TextCache,
+    width: f32,
+    height: f32,
+    root_scale: f32,
+    entries: &mut Vec<(usize, TextCacheKey)>,
+) {
+    entries.clear();
+    entries.reserve(texts.len());
+
+    for (text_index, text_draw) in texts.iter().enumerate() {
+        // Skip empty text or zero-sized rects
+        if text_draw.text.is_empty() || text_draw.rect.width <= 0.0 || text_draw.rect.height <= 0.0
+        {
+            continue;
         }
+
+        // Scale font size to physical pixels: BASE_FONT_SIZE is in dp, scale by text zoom and DPI
+        let font_size_px = BASE_FONT_SIZE * text_draw.scale * root_scale;
+        let spacing = TextSpacing::from_style(&text_draw.style);
+        let key = TextCacheKey::for_node(text_draw.node_id, font_size_px, spacing);
+        // Lines wrap where layout wrapped them; the rect is as wide as the widest
+        let wrap_width = TEXT_LAYOUT_OPTIONS.wrap_width(text_draw.rect.width) * root_scale;
+
+        // Create or update buffer in cache, reshaping only changed text
+        let shaped_size = text_cache
+            .shape(
+                font_system,
+                key.clone(),
+                text_draw.text.as_ref(),
+                font_size_px,
+                spacing,
+                Some(wrap_width),
+            )
+            .size(font_size_px);
+
+        // Glyphs may overflow the text's rect, so cull against whichever is larger
+        let extent = Rect {
+            width: text_draw.rect.width.max(shaped_size.width / root_scale),
+            height: text_draw.rect.height.max(shaped_size.height / root_scale),
+            ..text_draw.rect
+        };
+        if !is_visible(extent, text_draw.clip, width, height, root_scale) {
+            continue;
+        }
+
+        entries.push((text_index, key));
+    }
+}
+
+/// Prepares every text area of a frame with a single call to `prepare`.
+///
+/// Preparing areas one by one redoes atlas work per text; one batch uploads
+/// all glyphs together. If the atlas runs full, `prepare` is called once more
+/// with `atlas_full` set so it can free space first. Returns the number of
+/// prepare calls made.
+pub(crate) fn prepare_text_batch<A>(
+    areas: &[A],
+    mut prepare: impl FnMut(&[A], bool) -> Result<(), PrepareError>,
+) -> Result<usize, PrepareError> {
+    if areas.is_empty() {
+        return Ok(0);
+    }
+    match prepare(areas, false) {
+        Err(PrepareError::AtlasFull) => prepare(areas, true).map(|()| 2),
+        result => result.map(|()| 1),
     }
 }
 
 // TextCacheKey is now defined in lib.rs and shared between measurement and rendering
 
+/// Which text renderer a batch of text goes through.
+///
+/// Glyphon draws what was prepared last, so blurred layers prepare their text
+/// on a renderer of their own, leaving the main one for the rest of the frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TextPass {
+    Main,
+    Blurred,
+}
+
+/// Places `buffer`, the shaped `text_draw`, in physical pixels within its clip.
+fn text_area<'a>(
+    text_draw: &TextDraw,
+    buffer: &'a Buffer,
+    root_scale: f32,
+    width: u32,
+    height: u32,
+) -> TextArea<'a> {
+    let color = GlyphonColor::rgba(
+        (text_draw.color.r() * 255.0) as u8,
+        (text_draw.color.g() * 255.0) as u8,
+        (text_draw.color.b() * 255.0) as u8,
+        (text_draw.color.a() * 255.0) as u8,
+    );
+    let bounds = match text_draw.clip {
+        Some(clip) => TextBounds {
+            left: (clip.x * root_scale) as i32,
+            top: (clip.y * root_scale) as i32,
+            right: ((clip.x + clip.width) * root_scale) as i32,
+            bottom: ((clip.y + clip.height) * root_scale) as i32,
+        },
+        None => TextBounds {
+            left: 0,
+            top: 0,
+            right: width as i32,
+            bottom: height as i32,
+        },
+    };
+    TextArea {
+        buffer,
+        left: text_draw.rect.x * root_scale,
+        top: text_draw.rect.y * root_scale,
+        // Use scale 1.0 since font_size and position are already in physical pixels
+        scale: 1.0,
+        bounds,
+        default_color: color,
+        custom_glyphs: &[],
+    }
+}
+
 pub struct GpuRenderer {
     pub(crate) device: Arc<wgpu::Device>,
     pub(crate) queue: Arc<wgpu::Queue>,
-    #[allow(dead_code)] // Kept for potential future use (e.g., recreating text atlas)
-    surface_format: wgpu::TextureFormat,
+    pub(crate) surface_format: wgpu::TextureFormat,
+    pub(crate) format_features: wgpu::TextureFormatFeatures,
+    // Validated sample count of the frame and the multisampled target it is drawn into
+    sample_count: u32,
+    msaa: Option<MsaaTarget>,
+    edge_softness: f32,
     pipeline: wgpu::RenderPipeline,
     shape_bind_group_layout: wgpu::BindGroupLayout,
     font_system: Arc<Mutex<FontSystem>>,
     text_renderer: TextRenderer,
+    // Draws the text of blurred layers into their offscreen target
+    blur_text_renderer: TextRenderer,
     text_atlas: TextAtlas,
     swash_cache: SwashCache,
     // Persistent GPU buffers (reused across frames)
     uniform_buffer: wgpu::Buffer,
     uniform_bind_group: wgpu::BindGroup,
     shape_buffers: ShapeBatchBuffers,
+    // Offscreen targets and pipelines for blurred layers
+    blur: BlurRenderer,
+    // GPU frame timing, when the device supports timestamp queries
+    timer: Option<GpuFrameTimer>,
     // Shared text cache used by both measurement and rendering
     text_cache: SharedTextCache,
     text_viewport: Viewport,
-    scratch_shape_data: Vec<ShapeData>,
+    scratch_instances: Vec<ShapeInstance>,
     scratch_gradients: Vec<GradientStop>,
     scratch_filtered_indices: Vec<usize>,
-    scratch_vertices: Vec<Vertex>,
-    scratch_indices: Vec<u32>,
     scratch_text_entries: Vec<(usize, TextCacheKey)>,
-    last_shape_chunk_count: usize,
 }
 
 impl GpuRenderer {
@@ -277,9 +407,17 @@ impl GpuRenderer {
         device: Arc<wgpu::Device>,
         queue: Arc<wgpu::Queue>,
         surface_format: wgpu::TextureFormat,
+        format_features: wgpu::TextureFormatFeatures,
         font_system: Arc<Mutex<FontSystem>>,
         text_cache: SharedTextCache,
+        antialiasing: AntialiasingMode,
     ) -> Self {
+        let sample_count = antialiasing::supported_sample_count(antialiasing, format_features);
+        let multisample = wgpu::MultisampleState {
+            count: sample_count,
+            ..Default::default()
+        };
+
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Shape Shader"),
             source: wgpu::ShaderSource::Wgsl(shaders::SHADER.into()),
@@ -290,7 +428,7 @@ impl GpuRenderer {
                 label: Some("Uniform Bind Group Layout"),
                 entries: &[wgpu::BindGroupLayoutEntry {
                     binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Uniform,
                         has_dynamic_offset: false,
@@ -300,33 +438,21 @@ impl GpuRenderer {
                 }],
             });
 
-        // Use uniform buffers for WebGL compatibility
+        // Gradient stops use a uniform buffer for WebGL compatibility
         // Storage buffers aren't supported in WebGL fragment shaders
         let shape_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("Shape Bind Group Layout"),
-                entries: &[
-                    wgpu::BindGroupLayoutEntry {
-                        binding: 0,
-                        visibility: wgpu::ShaderStages::FRAGMENT,
-                        ty: wgpu::BindingType::Buffer {
-                            ty: wgpu::BufferBindingType::Uniform,
-                            has_dynamic_offset: false,
-                            min_binding_size: None,
-                        },
-                        count: None,
-                    },
-                    wgpu::BindGroupLayoutEntry {
-                        binding: 1,
-                        visibility: wgpu::ShaderStages::FRAGMENT,
-                        ty: wgpu::BindingType::Buffer {
-                            ty: wgpu::BufferBindingType::Uniform,
-                            has_dynamic_offset: false,
-                            min_binding_size: None,
-                        },
-                        count: None,
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
                     },
-                ],
+                    count: None,
+                }],
             });
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -342,7 +468,7 @@ impl GpuRenderer {
                 module: &shader,
                 entry_point: Some("vs_main"),
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
-                buffers: &[Vertex::desc()],
+                buffers: &[ShapeInstance::desc()],
             },
             fragment: Some(wgpu::FragmentState {
                 module: &shader,
@@ -355,7 +481,7 @@ impl GpuRenderer {
                 })],
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
                 cull_mode: None,
@@ -364,7 +490,7 @@ impl GpuRenderer {
                 conservative: false,
             },
             depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
+            multisample,
             multiview: None,
             cache: None,
         });
@@ -378,12 +504,8 @@ impl GpuRenderer {
             surface_format
         );
 
-        let text_renderer = TextRenderer::new(
-            &mut text_atlas,
-            &device,
-            wgpu::MultisampleState::default(),
-            None,
-        );
+        let text_renderer = TextRenderer::new(&mut text_atlas, &device, multisample, None);
+        let blur_text_renderer = TextRenderer::new(&mut text_atlas, &device, multisample, None);
         let text_viewport = Viewport::new(&device, &glyphon_cache);
 
         // Create persistent uniform buffer
@@ -405,41 +527,49 @@ impl GpuRenderer {
 
         // Create persistent shape buffers
         let shape_buffers = ShapeBatchBuffers::new(&device, &shape_bind_group_layout);
+        let blur = BlurRenderer::new(&device, surface_format, sample_count);
+        let timer = GpuFrameTimer::new(&device, &queue);
 
         Self {
             device,
             queue,
             surface_format,
+            format_features,
+            sample_count,
+            msaa: None,
+            edge_softness: antialiasing.edge_softness(),
             pipeline,
             shape_bind_group_layout,
             font_system,
             text_renderer,
+            blur_text_renderer,
             text_atlas,
             swash_cache,
             uniform_buffer,
             uniform_bind_group,
             shape_buffers,
+            blur,
+            timer,
             text_cache,
             text_viewport,
-            scratch_shape_data: Vec::new(),
+            scratch_instances: Vec::new(),
             scratch_gradients: Vec::new(),
             scratch_filtered_indices: Vec::new(),
-            scratch_vertices: Vec::new(),
-            scratch_indices: Vec::new(),
             scratch_text_entries: Vec::new(),
-            last_shape_chunk_count: 0,
         }
     }
 
+    /// Draws `scene` into `view`, returning what the frame took.
     pub fn render(
         &mut self,
         view: &wgpu::TextureView,
-        shapes: &[DrawShape],
-        texts: &[TextDraw],
+        scene: &Scene,
         width: u32,
         height: u32,
         root_scale: f32,
-    ) -> Result<(), String> {
+    ) -> Result<RenderStats, String> {
+        let shapes = &scene.shapes;
+        let texts = &scene.texts;
         log::trace!(
             "🎨 Rendering: {} shapes, {} texts (size: {}x{})",
             shapes.len(),
@@ -464,39 +594,25 @@ impl GpuRenderer {
         // Update uniform buffer with viewport dimensions
         let uniforms = Uniforms {
             viewport: [width as f32, height as f32],
-            _padding: [0.0, 0.0],
+            edge_softness: self.edge_softness,
+            _padding: 0.0,
         };
         self.queue
             .write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
 
-        // Chunked rendering for robustness with large scenes
         let total_shape_count = shapes.len();
 
-        if total_shape_count > MAX_SHAPES_PER_DRAW {
-            let chunk_count = total_shape_count.div_ceil(MAX_SHAPES_PER_DRAW);
-            if self.last_shape_chunk_count != chunk_count {
-                log::debug!(
-                    "Rendering {} shapes in {} chunks (max {} per draw)",
-                    total_shape_count,
-                    chunk_count,
-                    MAX_SHAPES_PER_DRAW
-                );
-                self.last_shape_chunk_count = chunk_count;
-            }
-        } else if self.last_shape_chunk_count != 0 {
-            self.last_shape_chunk_count = 0;
-        }
-
-        // First pass: collect all shape data and gradients across entire scene
-        // Also collect filtered shapes (ones that pass clip test) to stay in sync
+        // First pass: build one instance per visible shape and collect gradients across the scene.
+        // Shapes outside the viewport or their clip are culled; the drawn indices stay in sync
         self.scratch_gradients.clear();
-        self.scratch_shape_data.clear();
+        self.scratch_instances.clear();
         self.scratch_filtered_indices.clear();
         self.scratch_gradients.reserve(total_shape_count);
-        self.scratch_shape_data.reserve(total_shape_count);
+        self.scratch_instances.reserve(total_shape_count);
         self.scratch_filtered_indices.reserve(total_shape_count);
 
-        for (shape_index, shape) in shapes.iter().enumerate() {
+        for shape_index in visible_shapes(shapes, width as f32, height as f32, root_scale) {
+            let shape = &shapes[shape_index];
             let rect = shape.rect;
 
             // Scale to physical pixels
@@ -505,22 +621,8 @@ impl GpuRenderer {
             let w = rect.width * root_scale;
             let h = rect.height * root_scale;
 
-            // Calculate clip rect (scaled to physical pixels) and skip early if fully clipped
+            // Clip rect scaled to physical pixels
             let clip_rect = if let Some(clip) = shape.clip {
-                let clip_right = (clip.x + clip.width) * root_scale;
-                let clip_bottom = (clip.y + clip.height) * root_scale;
-                let shape_right = x + w;
-                let shape_bottom = y + h;
-
-                // Skip shapes that are entirely outside the clip rect
-                if shape_right <= clip.x * root_scale
-                    || x >= clip_right
-                    || shape_bottom <= clip.y * root_scale
-                    || y >= clip_bottom
-                {
-                    continue;
-                }
-
                 [
                     clip.x * root_scale,
                     clip.y * root_scale,
@@ -535,19 +637,24 @@ impl GpuRenderer {
             let mut gradient_params = [0.0f32; 4];
             let (brush_type, gradient_start, gradient_count) = match &shape.brush {
                 Brush::Solid(_) => (0u32, 0u32, 0u32),
-                Brush::LinearGradient(colors) => {
+                Brush::LinearGradient {
+                    colors,
+                    color_space,
+                } => {
                     let start = self.scratch_gradients.len() as u32;
                     for c in colors {
                         self.scratch_gradients.push(GradientStop {
                             color: [c.r(), c.g(), c.b(), c.a()],
                         });
                     }
+                    gradient_params[3] = color_space_index(*color_space);
                     (1u32, start, colors.len() as u32)
                 }
                 Brush::RadialGradient {
                     colors,
                     center,
                     radius,
+                    color_space,
                 } => {
                     let start = self.scratch_gradients.len() as u32;
                     for c in colors {
@@ -560,50 +667,117 @@ impl GpuRenderer {
                         x + center.x * root_scale,
                         y + center.y * root_scale,
                         (radius * root_scale).max(f32::EPSILON),
-                        0.0,
+                        color_space_index(*color_space),
                     ];
                     (2u32, start, colors.len() as u32)
                 }
             };
 
             // Shape data (radii scaled to physical pixels)
-            let radii = if let Some(rounded) = shape.shape {
+            let (radii, shape_kind) = if let Some(arc) = shape.arc {
+                (
+                    [
+                        arc.stroke_width * root_scale,
+                        arc.start_angle,
+                        arc.sweep_angle,
+                        0.0,
+                    ],
+                    1u32,
+                )
+            } else if let Some(line) = shape.line {
+                // The stroke is symmetric about the line's midpoint, the center of `rect`
+                (
+                    [
+                        (line.end.x - line.start.x) * 0.5 * root_scale,
+                        (line.end.y - line.start.y) * 0.5 * root_scale,
+                        line.stroke_width * 0.5 * root_scale,
+                        0.0,
+                    ],
+                    2u32,
+                )
+            } else if let Some(rounded) = shape.shape {
                 let resolved = rounded.resolve(rect.width, rect.height);
-                [
-                    resolved.top_left * root_scale,
-                    resolved.top_right * root_scale,
-                    resolved.bottom_left * root_scale,
-                    resolved.bottom_right * root_scale,
-                ]
+                (
+                    [
+                        resolved.top_left * root_scale,
+                        resolved.top_right * root_scale,
+                        resolved.bottom_left * root_scale,
+                        resolved.bottom_right * root_scale,
+                    ],
+                    0u32,
+                )
             } else {
-                [0.0, 0.0, 0.0, 0.0]
+                ([0.0, 0.0, 0.0, 0.0], 0u32)
             };
 
-            self.scratch_shape_data.push(ShapeData {
+            let (rounded_clip_rect, rounded_clip_radii) = match shape.rounded_clip {
+                Some(RoundedClip { rect: clip, radii }) => (
+                    [
+                        clip.x * root_scale,
+                        clip.y * root_scale,
+                        clip.width * root_scale,
+                        clip.height * root_scale,
+                    ],
+                    [
+                        radii.top_left * root_scale,
+                        radii.top_right * root_scale,
+                        radii.bottom_left * root_scale,
+                        radii.bottom_right * root_scale,
+                    ],
+                ),
+                None => ([0.0; 4], [0.0; 4]),
+            };
+
+            // Solid color, or the first stop for gradients
+            let color = match &shape.brush {
+                Brush::Solid(c) => *c,
+                Brush::LinearGradient { colors, .. } | Brush::RadialGradient { colors, .. } => {
+                    colors.first().copied().unwrap_or(Color(1.0, 1.0, 1.0, 1.0))
+                }
+            };
+
+            self.scratch_instances.push(ShapeInstance {
                 rect: [x, y, w, h],
                 radii,
                 gradient_params,
                 clip_rect,
+                color: [color.r(), color.g(), color.b(), color.a()],
                 brush_type,
                 gradient_start,
                 gradient_count,
-                _padding: 0,
+                shape_kind,
+                rounded_clip_rect,
+                rounded_clip_radii,
             });
 
             self.scratch_filtered_indices.push(shape_index);
         }
 
-        // Ensure buffers can hold at least one chunk
+        if self.scratch_instances.len() > MAX_SHAPE_INSTANCES {
+            log::warn!(
+                "Scene has {} shapes; drawing the first {}",
+                self.scratch_instances.len(),
+                MAX_SHAPE_INSTANCES
+            );
+            self.scratch_instances.truncate(MAX_SHAPE_INSTANCES);
+            self.scratch_filtered_indices.truncate(MAX_SHAPE_INSTANCES);
+        }
+
         self.shape_buffers.ensure_capacity(
             &self.device,
             &self.shape_bind_group_layout,
-            MAX_SHAPES_PER_DRAW * 4,             // vertices
-            MAX_SHAPES_PER_DRAW * 6,             // indices
-            MAX_SHAPES_PER_DRAW,                 // shapes
+            self.scratch_instances.len(),
             self.scratch_gradients.len().max(1), // all gradients (written once)
         );
 
-        // Write gradients once for all chunks
+        // Write every instance and gradient once; render steps draw ranges of them
+        if !self.scratch_instances.is_empty() {
+            self.queue.write_buffer(
+                &self.shape_buffers.instance_buffer,
+                0,
+                bytemuck::cast_slice(&self.scratch_instances),
+            );
+        }
         if !self.scratch_gradients.is_empty() {
             self.queue.write_buffer(
                 &self.shape_buffers.gradient_buffer,
@@ -612,354 +786,278 @@ impl GpuRenderer {
             );
         }
 
-        // Second pass: render shapes in chunks with proper synchronization
-        // Each chunk gets its own encoder+submit to ensure buffer writes complete before next chunk
-        // Use filtered indices (after clip culling) to stay in sync with shape data
-        let filtered_shape_count = self.scratch_filtered_indices.len();
-        let chunk_count = filtered_shape_count.div_ceil(MAX_SHAPES_PER_DRAW);
-        let mut has_shape_pass = false;
-        let mut pending_shape_encoder: Option<wgpu::CommandEncoder> = None;
-
-        for (chunk_idx, chunk) in self
-            .scratch_filtered_indices
-            .chunks(MAX_SHAPES_PER_DRAW)
-            .enumerate()
-        {
-            let chunk_len = chunk.len();
-            let chunk_start = chunk_idx * MAX_SHAPES_PER_DRAW;
-
-            self.scratch_vertices.clear();
-            self.scratch_indices.clear();
-            self.scratch_vertices.reserve(chunk_len * 4);
-            self.scratch_indices.reserve(chunk_len * 6);
-
-            // Build vertices and indices for this chunk
-            for (shape_idx, shape_index) in chunk.iter().enumerate() {
-                let shape = &shapes[*shape_index];
-                let rect = shape.rect;
-                let base_vertex = (shape_idx * 4) as u32;
-
-                // Get color from brush for vertex data
-                let color = match &shape.brush {
-                    Brush::Solid(c) => [c.r(), c.g(), c.b(), c.a()],
-                    Brush::LinearGradient(colors) => {
-                        let first = colors.first().unwrap_or(&Color(1.0, 1.0, 1.0, 1.0));
-                        [first.r(), first.g(), first.b(), first.a()]
-                    }
-                    Brush::RadialGradient { colors, .. } => {
-                        let first = colors.first().unwrap_or(&Color(1.0, 1.0, 1.0, 1.0));
-                        [first.r(), first.g(), first.b(), first.a()]
-                    }
-                };
-
-                // Scale logical dp to physical pixels for GPU rendering
-                let x = rect.x * root_scale;
-                let y = rect.y * root_scale;
-                let w = rect.width * root_scale;
-                let h = rect.height * root_scale;
-
-                // Vertices for quad (in physical pixels)
-                self.scratch_vertices.extend_from_slice(&[
-                    Vertex {
-                        position: [x, y],
-                        color,
-                        uv: [0.0, 0.0],
-                    },
-                    Vertex {
-                        position: [x + w, y],
-                        color,
-                        uv: [1.0, 0.0],
-                    },
-                    Vertex {
-                        position: [x, y + h],
-                        color,
-                        uv: [0.0, 1.0],
-                    },
-                    Vertex {
-                        position: [x + w, y + h],
-                        color,
-                        uv: [1.0, 1.0],
-                    },
-                ]);
-
-                // Indices for two triangles
-                self.scratch_indices.extend_from_slice(&[
-                    base_vertex,
-                    base_vertex + 1,
-                    base_vertex + 2,
-                    base_vertex + 2,
-                    base_vertex + 1,
-                    base_vertex + 3,
-                ]);
-            }
-
-            // Get shape data slice for this chunk
-            let chunk_shape_data = &self.scratch_shape_data[chunk_start..chunk_start + chunk_len];
-
-            // Write chunk data and render in one encoder (submit after to ensure synchronization)
-            if !self.scratch_vertices.is_empty() {
-                // Write this chunk's data to buffers
-                self.queue.write_buffer(
-                    &self.shape_buffers.vertex_buffer,
-                    0,
-                    bytemuck::cast_slice(&self.scratch_vertices),
-                );
-                self.queue.write_buffer(
-                    &self.shape_buffers.index_buffer,
-                    0,
-                    bytemuck::cast_slice(&self.scratch_indices),
-                );
-                self.queue.write_buffer(
-                    &self.shape_buffers.shape_buffer,
-                    0,
-                    bytemuck::cast_slice(chunk_shape_data),
-                );
-
-                // Create encoder for this chunk
-                has_shape_pass = true;
-                let mut encoder =
-                    self.device
-                        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                            label: Some("Shape Chunk Encoder"),
-                        });
-
-                // Create render pass for this chunk (Clear on first chunk, Load on subsequent)
-                {
-                    let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some("Shape Render Pass"),
-                        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                            view,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: if chunk_idx == 0 {
-                                    wgpu::LoadOp::Clear(CLEAR_COLOR)
-                                } else {
-                                    wgpu::LoadOp::Load // Preserve previous chunks
-                                },
-                                store: wgpu::StoreOp::Store,
-                            },
-                        })],
-                        depth_stencil_attachment: None,
-                        timestamp_writes: None,
-                        occlusion_query_set: None,
-                    });
-
-                    render_pass.set_pipeline(&self.pipeline);
-                    render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
-                    render_pass.set_bind_group(1, &self.shape_buffers.bind_group, &[]);
-
-                    // Draw this chunk
-                    render_pass.set_vertex_buffer(0, self.shape_buffers.vertex_buffer.slice(..));
-                    render_pass.set_index_buffer(
-                        self.shape_buffers.index_buffer.slice(..),
-                        wgpu::IndexFormat::Uint32,
-                    );
-                    render_pass.draw_indexed(0..(chunk_len as u32 * 6), 0, 0..1);
-                }
-
-                let is_last_chunk = chunk_idx + 1 == chunk_count;
-                if is_last_chunk {
-                    pending_shape_encoder = Some(encoder);
-                } else {
-                    // Submit this chunk immediately to ensure synchronization before next chunk.
-                    self.queue.submit(std::iter::once(encoder.finish()));
-                }
-            }
-        }
-
-        // Prepare text rendering - create buffers and text areas (with caching)
-        let mut font_system = self.font_system.lock().unwrap();
-        let mut text_cache = self.text_cache.lock().unwrap();
-
-        // Prepare text buffers (with caching for performance)
-        // Font size in physical pixels for glyphon
-        self.scratch_text_entries.clear();
-        self.scratch_text_entries.reserve(texts.len());
-
-        for (text_index, text_draw) in texts.iter().enumerate() {
-            // Skip empty text or zero-sized rects
-            if text_draw.text.is_empty()
-                || text_draw.rect.width <= 0.0
-                || text_draw.rect.height <= 0.0
-            {
-                continue;
-            }
-
-            // Scale font size to physical pixels: BASE_FONT_SIZE is in dp, scale by text zoom and DPI
-            let font_size_px = BASE_FONT_SIZE * text_draw.scale * root_scale;
-            let key = TextCacheKey::for_node(text_draw.node_id, font_size_px);
-
-            // Create or update buffer in cache
-            let buffer = text_cache.entry(key.clone()).or_insert_with(|| {
-                let buffer = glyphon::Buffer::new(
-                    &mut font_system,
-                    Metrics::new(font_size_px, font_size_px * 1.4),
-                );
-                SharedTextBuffer {
-                    buffer,
-                    text: String::new(),
-                    font_size: 0.0,
-                    cached_size: None,
-                }
-            });
+        // With MSAA every pass draws into the multisampled frame and resolves into `view`
+        let msaa_view = MsaaTarget::view(
+            &mut self.msaa,
+            &self.device,
+            self.surface_format,
+            self.sample_count,
+            width,
+            height,
+        );
+        let main_target = ColorTarget::new(msaa_view.as_ref(), view);
 
-            // Ensure buffer has the correct text
-            buffer.ensure(
-                &mut font_system,
-                text_draw.text.as_ref(),
-                font_size_px,
-                Attrs::new(),
-            );
+        let mut stats = RenderStats {
+            shapes: self.scratch_instances.len(),
+            ..RenderStats::default()
+        };
 
-            self.scratch_text_entries.push((text_index, key));
+        // Shape the frame's text up front; blurred layers draw theirs offscreen
+        let font_system = Arc::clone(&self.font_system);
+        let text_cache = Arc::clone(&self.text_cache);
+        let mut font_system = font_system.lock().unwrap();
+        let mut text_cache = text_cache.lock().unwrap();
+        visible_texts(
+            texts,
+            &mut font_system,
+            &mut text_cache,
+            width as f32,
+            height as f32,
+            root_scale,
+            &mut self.scratch_text_entries,
+        );
+        if !self.scratch_text_entries.is_empty() {
+            self.text_viewport
+                .update(&self.queue, Resolution { width, height });
         }
-
-        // Create text areas using cached buffers
-        let mut text_areas = Vec::with_capacity(self.scratch_text_entries.len());
-
-        for (text_index, key) in self.scratch_text_entries.iter() {
-            let text_draw = &texts[*text_index];
+        let area_for = |&(text_index, ref key): &(usize, TextCacheKey)| {
             let cached = text_cache.get(key).expect("Text should be in cache");
+            text_area(
+                &texts[text_index],
+                &cached.buffer,
+                root_scale,
+                width,
+                height,
+            )
+        };
 
-            let color = GlyphonColor::rgba(
-                (text_draw.color.r() * 255.0) as u8,
-                (text_draw.color.g() * 255.0) as u8,
-                (text_draw.color.b() * 255.0) as u8,
-                (text_draw.color.a() * 255.0) as u8,
-            );
+        // Second pass: render shapes step by step, one instanced draw per step.
+        // Blurred layers are drawn offscreen with their text and composited
+        // back; their blur parameters and text are written through the queue,
+        // so the encoder is submitted after each of them.
+        let steps = blur::plan_render_steps(shapes.len(), &scene.blur_layers, root_scale);
+        let mut main_cleared = false;
+        let mut encoder = self.create_frame_encoder();
+        if let Some(timer) = &mut self.timer {
+            timer.begin_frame(&mut encoder);
+        }
 
-            // Scale text position and bounds to physical pixels
-            let left_px = text_draw.rect.x * root_scale;
-            let top_px = text_draw.rect.y * root_scale;
-
-            let bounds = TextBounds {
-                left: text_draw
-                    .clip
-                    .map(|c| (c.x * root_scale) as i32)
-                    .unwrap_or(0),
-                top: text_draw
-                    .clip
-                    .map(|c| (c.y * root_scale) as i32)
-                    .unwrap_or(0),
-                right: text_draw
-                    .clip
-                    .map(|c| ((c.x + c.width) * root_scale) as i32)
-                    .unwrap_or(width as i32),
-                bottom: text_draw
-                    .clip
-                    .map(|c| ((c.y + c.height) * root_scale) as i32)
-                    .unwrap_or(height as i32),
+        for step in &steps {
+            let main_load = if main_cleared {
+                wgpu::LoadOp::Load
+            } else {
+                wgpu::LoadOp::Clear(CLEAR_COLOR)
             };
-
-            text_areas.push(TextArea {
-                buffer: &cached.buffer,
-                left: left_px,
-                top: top_px,
-                // Use scale 1.0 since font_size and position are already in physical pixels
-                scale: 1.0,
-                bounds,
-                default_color: color,
-                custom_glyphs: &[],
-            });
+            match step {
+                RenderStep::Direct(range) => {
+                    let instances = instance_range(&self.scratch_filtered_indices, range.clone());
+                    if instances.is_empty() {
+                        continue;
+                    }
+                    stats.record_shape_batch(instances.len());
+                    self.draw_shapes(&mut encoder, instances, main_target, main_load);
+                    main_cleared = true;
+                }
+                RenderStep::Blurred {
+                    shapes: range,
+                    texts: text_range,
+                    region,
+                    passes,
+                } => {
+                    let instances = instance_range(&self.scratch_filtered_indices, range.clone());
+                    let text_areas: Vec<TextArea> = self
+                        .scratch_text_entries
+                        .iter()
+                        .filter(|(text_index, _)| text_range.contains(text_index))
+                        .map(area_for)
+                        .collect();
+                    let Some(scissor) = blur::scissor_rect(*region, root_scale, width, height)
+                    else {
+                        continue;
+                    };
+                    if instances.is_empty() && text_areas.is_empty() {
+                        continue;
+                    }
+                    stats.record_blur();
+                    let (content, content_msaa) =
+                        self.blur.content_target(&self.device, width, height);
+                    let content_target = ColorTarget::new(content_msaa.as_ref(), &content);
+                    let mut content_load = wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT);
+                    if !instances.is_empty() {
+                        stats.record_shape_batch(instances.len());
+                        self.draw_shapes(&mut encoder, instances, content_target, content_load);
+                        content_load = wgpu::LoadOp::Load;
+                    }
+                    if !text_areas.is_empty() {
+                        let prepares =
+                            self.prepare_texts(&mut font_system, &text_areas, TextPass::Blurred)?;
+                        stats.record_text(text_areas.len(), prepares);
+                        self.draw_texts(
+                            &mut encoder,
+                            content_target,
+                            content_load,
+                            TextPass::Blurred,
+                        )?;
+                    }
+                    self.blur.encode(
+                        &self.queue,
+                        &mut encoder,
+                        passes,
+                        main_target,
+                        main_load,
+                        scissor,
+                    );
+                    let blurred = std::mem::replace(&mut encoder, self.create_frame_encoder());
+                    self.queue.submit(std::iter::once(blurred.finish()));
+                    main_cleared = true;
+                }
+            }
         }
 
-        let has_text = !text_areas.is_empty();
+        // Remaining text is drawn above every shape
+        let blurred_texts: Vec<&Range<usize>> = steps
+            .iter()
+            .filter_map(|step| match step {
+                RenderStep::Blurred { texts, .. } => Some(texts),
+                RenderStep::Direct(_) => None,
+            })
+            .collect();
+        let text_areas: Vec<TextArea> = self
+            .scratch_text_entries
+            .iter()
+            .filter(|(text_index, _)| !blurred_texts.iter().any(|texts| texts.contains(text_index)))
+            .map(area_for)
+            .collect();
 
-        // Prepare all text at once
+        let has_text = !text_areas.is_empty();
         if has_text {
-            self.text_viewport
-                .update(&self.queue, Resolution { width, height });
-            self.text_renderer
-                .prepare(
-                    &self.device,
-                    &self.queue,
-                    &mut font_system,
-                    &mut self.text_atlas,
-                    &self.text_viewport,
-                    text_areas.iter().cloned(),
-                    &mut self.swash_cache,
-                )
-                .map_err(|e| format!("Text prepare error: {:?}", e))?;
-
+            let prepares = self.prepare_texts(&mut font_system, &text_areas, TextPass::Main)?;
+            stats.record_text(text_areas.len(), prepares);
+        }
+        if !self.scratch_text_entries.is_empty() {
             self.text_atlas.trim();
         }
-
+        drop(text_areas);
         drop(font_system);
         drop(text_cache);
 
-        let mut submitted = false;
+        let main_load = if main_cleared {
+            wgpu::LoadOp::Load
+        } else {
+            wgpu::LoadOp::Clear(CLEAR_COLOR)
+        };
         if has_text {
-            let mut text_encoder = pending_shape_encoder.take().unwrap_or_else(|| {
-                self.device
-                    .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                        label: Some("Text Encoder"),
-                    })
+            self.draw_texts(&mut encoder, main_target, main_load, TextPass::Main)?;
+        } else if !main_cleared {
+            let _clear_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Clear Render Pass"),
+                color_attachments: &[Some(main_target.attachment(main_load))],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
             });
-
-            {
-                let mut text_pass = text_encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("Text Render Pass"),
-                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                        view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: if has_shape_pass {
-                                wgpu::LoadOp::Load
-                            } else {
-                                wgpu::LoadOp::Clear(CLEAR_COLOR)
-                            },
-                            store: wgpu::StoreOp::Store,
-                        },
-                    })],
-                    depth_stencil_attachment: None,
-                    timestamp_writes: None,
-                    occlusion_query_set: None,
-                });
-
-                self.text_renderer
-                    .render(&self.text_atlas, &self.text_viewport, &mut text_pass)
-                    .map_err(|e| format!("Text render error: {:?}", e))?;
-            }
-
-            self.queue.submit(std::iter::once(text_encoder.finish()));
-            submitted = true;
         }
-
-        if !submitted {
-            if let Some(shape_encoder) = pending_shape_encoder.take() {
-                self.queue.submit(std::iter::once(shape_encoder.finish()));
-            } else if !has_shape_pass {
-                let mut clear_encoder =
-                    self.device
-                        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                            label: Some("Clear Encoder"),
-                        });
-                {
-                    let _clear_pass =
-                        clear_encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                            label: Some("Clear Render Pass"),
-                            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                                view,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Clear(CLEAR_COLOR),
-                                    store: wgpu::StoreOp::Store,
-                                },
-                            })],
-                            depth_stencil_attachment: None,
-                            timestamp_writes: None,
-                            occlusion_query_set: None,
-                        });
-                }
-                self.queue.submit(std::iter::once(clear_encoder.finish()));
-            }
+        if let Some(timer) = &mut self.timer {
+            timer.end_frame(&mut encoder);
+        }
+        self.queue.submit(std::iter::once(encoder.finish()));
+        if let Some(timer) = &mut self.timer {
+            timer.frame_submitted();
+            stats.gpu_frame_time = timer.latest();
         }
 
         if !self.scratch_text_entries.is_empty() {
-            let mut text_cache = self.text_cache.lock().unwrap();
-            crate::trim_text_cache(&mut text_cache);
+            self.text_cache.lock().unwrap().trim();
         }
 
-        Ok(())
+        Ok(stats)
+    }
+
+    fn create_frame_encoder(&self) -> wgpu::CommandEncoder {
+        self.device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Frame Encoder"),
+            })
+    }
+
+    /// Uploads the glyphs of `areas` for `pass`, returning the prepare calls made.
+    fn prepare_texts(
+        &mut self,
+        font_system: &mut FontSystem,
+        areas: &[TextArea],
+        pass: TextPass,
+    ) -> Result<usize, String> {
+        prepare_text_batch(areas, |areas, atlas_full| {
+            if atlas_full {
+                // Drop glyphs no text used last frame, then retry the whole batch
+                self.text_atlas.trim();
+            }
+            let renderer = match pass {
+                TextPass::Main => &mut self.text_renderer,
+                TextPass::Blurred => &mut self.blur_text_renderer,
+            };
+            renderer.prepare(
+                &self.device,
+                &self.queue,
+                font_system,
+                &mut self.text_atlas,
+                &self.text_viewport,
+                areas.iter().cloned(),
+                &mut self.swash_cache,
+            )
+        })
+        .map_err(|e| format!("Text prepare error: {:?}", e))
+    }
+
+    /// Records a pass drawing the text last prepared for `pass` onto `target`.
+    fn draw_texts(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: ColorTarget,
+        load: wgpu::LoadOp<wgpu::Color>,
+        pass: TextPass,
+    ) -> Result<(), String> {
+        let mut text_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Text Render Pass"),
+            color_attachments: &[Some(target.attachment(load))],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        let renderer = match pass {
+            TextPass::Main => &self.text_renderer,
+            TextPass::Blurred => &self.blur_text_renderer,
+        };
+        renderer
+            .render(&self.text_atlas, &self.text_viewport, &mut text_pass)
+            .map_err(|e| format!("Text render error: {:?}", e))
+    }
+
+    /// Records a pass drawing the shape `instances` onto `target` with one instanced draw.
+    fn draw_shapes(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        instances: Range<usize>,
+        target: ColorTarget,
+        load: wgpu::LoadOp<wgpu::Color>,
+    ) {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Shape Render Pass"),
+            color_attachments: &[Some(target.attachment(load))],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+        render_pass.set_bind_group(1, &self.shape_buffers.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.shape_buffers.instance_buffer.slice(..));
+        // Four strip vertices per quad, expanded from each instance's rect in the shader
+        render_pass.draw(0..4, instances.start as u32..instances.end as u32);
     }
 }
+
+#[cfg(test)]
+#[path = "tests/render_tests.rs"]
+mod tests;
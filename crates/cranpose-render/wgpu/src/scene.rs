@@ -2,10 +2,24 @@
 
 use cranpose_core::{run_in_mutable_snapshot, NodeId};
 use cranpose_foundation::{PointerEvent, PointerEventKind};
-use cranpose_render_common::{HitTestTarget, RenderScene};
-use cranpose_ui_graphics::{Brush, Color, Point, Rect, RoundedCornerShape};
+use cranpose_render_common::scene_hash::{
+    hash_brush, hash_color, hash_f32, hash_option_arc, hash_option_line, hash_option_rect,
+    hash_option_rounded_clip, hash_option_shape, hash_rect, hash_text_style,
+};
+use cranpose_render_common::snapshot::{
+    snapshot_arc, snapshot_brush, snapshot_color, snapshot_f32, snapshot_line, snapshot_radii,
+    snapshot_rect,
+};
+use cranpose_render_common::{HitTestTarget, RenderScene, RoundedClip};
+use cranpose_ui_graphics::{
+    ArcShape, Brush, Color, LineShape, Point, Rect, RoundedCornerShape, Size, TextStyle,
+};
 use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::fmt::Write;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
 use std::rc::Rc;
 
 #[derive(Clone)]
@@ -13,8 +27,37 @@ pub struct DrawShape {
     pub rect: Rect,
     pub brush: Brush,
     pub shape: Option<RoundedCornerShape>,
+    /// Draws only this arc of the circle inscribed in `rect`; overrides `shape`.
+    pub arc: Option<ArcShape>,
+    /// Draws only this line inside `rect`; overrides `shape`.
+    pub line: Option<LineShape>,
     pub z_index: usize,
     pub clip: Option<Rect>,
+    /// Rounded rectangle the shape is additionally clipped to.
+    pub rounded_clip: Option<RoundedClip>,
+}
+
+/// Subtree whose shapes and texts are rendered offscreen and blurred before
+/// compositing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlurLayer {
+    /// Bounds of the blurred node; the blur may spread up to `radius` beyond them.
+    pub rect: Rect,
+    pub radius: f32,
+    /// Indices into [`Scene::shapes`] drawn by the subtree.
+    pub shapes: Range<usize>,
+    /// Indices into [`Scene::texts`] drawn by the subtree.
+    pub texts: Range<usize>,
+}
+
+impl BlurLayer {
+    /// Whether `other` is drawn within this layer's subtree.
+    pub fn contains(&self, other: &BlurLayer) -> bool {
+        let within = |outer: &Range<usize>, inner: &Range<usize>| {
+            outer.start <= inner.start && inner.end <= outer.end
+        };
+        within(&self.shapes, &other.shapes) && within(&self.texts, &other.texts)
+    }
 }
 
 #[derive(Clone)]
@@ -24,10 +67,22 @@ pub struct TextDraw {
     pub text: Rc<str>,
     pub color: Color,
     pub scale: f32,
+    /// Line height and letter spacing the text is laid out with
+    pub style: TextStyle,
     pub z_index: usize,
     pub clip: Option<Rect>,
 }
 
+/// Content and look of a text passed to [`Scene::push_text`].
+#[derive(Clone)]
+pub struct TextParams {
+    pub text: Rc<str>,
+    pub color: Color,
+    pub scale: f32,
+    /// Line height and letter spacing the text is laid out with
+    pub style: TextStyle,
+}
+
 #[derive(Clone)]
 pub enum ClickAction {
     Simple(Rc<RefCell<dyn FnMut()>>),
@@ -68,7 +123,12 @@ impl HitTestTarget for HitRegion {
             y: y - self.rect.y,
         };
 
-        let local_event = event.copy_with_local_position(local);
+        let local_event = event
+            .copy_with_local_position(local)
+            .with_target_size(Size {
+                width: self.rect.width,
+                height: self.rect.height,
+            });
 
         let has_pointer_inputs = !self.pointer_inputs.is_empty();
         let has_click_actions = kind == PointerEventKind::Down && !self.click_actions.is_empty();
@@ -86,11 +146,16 @@ impl HitTestTarget for HitRegion {
                 handler(local_event.clone());
             }
 
-            // Only perform click actions if NOT consumed
-            if kind == PointerEventKind::Down && !local_event.is_consumed() {
+            // Only perform click actions if NOT consumed. A click is handled by the
+            // topmost clickable region, so regions underneath don't see it.
+            if kind == PointerEventKind::Down
+                && !local_event.is_consumed()
+                && !self.click_actions.is_empty()
+            {
                 for action in &self.click_actions {
                     action.invoke(self.rect, x, y);
                 }
+                local_event.consume();
             }
         }) {
             log::error!(
@@ -127,6 +192,7 @@ pub struct Scene {
     pub shapes: Vec<DrawShape>,
     pub texts: Vec<TextDraw>,
     pub hits: Vec<HitRegion>,
+    pub blur_layers: Vec<BlurLayer>,
     /// Index for O(1) node lookup by NodeId
     node_index: HashMap<NodeId, HitRegion>,
     next_z: usize,
@@ -138,6 +204,7 @@ impl Scene {
             shapes: Vec::new(),
             texts: Vec::new(),
             hits: Vec::new(),
+            blur_layers: Vec::new(),
             node_index: HashMap::new(),
             next_z: 0,
         }
@@ -156,18 +223,77 @@ impl Scene {
             rect,
             brush,
             shape,
+            arc: None,
+            line: None,
+            z_index,
+            clip,
+            rounded_clip: None,
+        });
+    }
+
+    pub fn push_arc(&mut self, rect: Rect, brush: Brush, arc: ArcShape, clip: Option<Rect>) {
+        let z_index = self.next_z;
+        self.next_z += 1;
+        self.shapes.push(DrawShape {
+            rect,
+            brush,
+            shape: None,
+            arc: Some(arc),
+            line: None,
+            z_index,
+            clip,
+            rounded_clip: None,
+        });
+    }
+
+    pub fn push_line(&mut self, rect: Rect, brush: Brush, line: LineShape, clip: Option<Rect>) {
+        let z_index = self.next_z;
+        self.next_z += 1;
+        self.shapes.push(DrawShape {
+            rect,
+            brush,
+            shape: None,
+            arc: None,
+            line: Some(line),
             z_index,
             clip,
+            rounded_clip: None,
         });
     }
 
+    /// Clips the shapes pushed since `first_shape` to a rounded rectangle.
+    pub fn clip_shapes_to(&mut self, first_shape: usize, clip: RoundedClip) {
+        for shape in &mut self.shapes[first_shape..] {
+            shape.rounded_clip = Some(clip);
+        }
+    }
+
+    /// Marks the shapes and texts pushed since `first_shape` and `first_text`
+    /// as a blurred subtree.
+    pub fn push_blur_layer(
+        &mut self,
+        rect: Rect,
+        radius: f32,
+        first_shape: usize,
+        first_text: usize,
+    ) {
+        let shapes = first_shape..self.shapes.len();
+        let texts = first_text..self.texts.len();
+        if radius > 0.0 && !(shapes.is_empty() && texts.is_empty()) {
+            self.blur_layers.push(BlurLayer {
+                rect,
+                radius,
+                shapes,
+                texts,
+            });
+        }
+    }
+
     pub fn push_text(
         &mut self,
         node_id: NodeId,
         rect: Rect,
-        text: Rc<str>,
-        color: Color,
-        scale: f32,
+        params: TextParams,
         clip: Option<Rect>,
     ) {
         let z_index = self.next_z;
@@ -175,14 +301,88 @@ impl Scene {
         self.texts.push(TextDraw {
             node_id,
             rect,
-            text,
-            color,
-            scale,
+            text: params.text,
+            color: params.color,
+            scale: params.scale,
+            style: params.style,
             z_index,
             clip,
         });
     }
 
+    /// Lists shapes and texts in draw order, one per line, for golden tests.
+    ///
+    /// Shapes show their bounds, brush and resolved corner radii; texts show
+    /// their content, bounds and color. Hit regions are left out since they
+    /// don't draw anything.
+    pub fn to_snapshot(&self) -> String {
+        enum Entry<'a> {
+            Shape(&'a DrawShape),
+            Text(&'a TextDraw),
+        }
+
+        let mut entries: Vec<(usize, Entry)> = self
+            .shapes
+            .iter()
+            .map(|shape| (shape.z_index, Entry::Shape(shape)))
+            .chain(
+                self.texts
+                    .iter()
+                    .map(|text| (text.z_index, Entry::Text(text))),
+            )
+            .collect();
+        entries.sort_by_key(|(z_index, _)| *z_index);
+
+        let mut snapshot = String::new();
+        for (z_index, entry) in entries {
+            let clip = match entry {
+                Entry::Shape(shape) => {
+                    let _ = write!(
+                        snapshot,
+                        "shape z={} rect={} brush={}",
+                        z_index,
+                        snapshot_rect(shape.rect),
+                        snapshot_brush(&shape.brush)
+                    );
+                    if let Some(arc) = shape.arc {
+                        let _ = write!(snapshot, " arc={}", snapshot_arc(arc));
+                    } else if let Some(line) = shape.line {
+                        let _ = write!(snapshot, " line={}", snapshot_line(line));
+                    } else if let Some(corners) = shape.shape {
+                        let radii = corners.resolve(shape.rect.width, shape.rect.height);
+                        let _ = write!(snapshot, " radii={}", snapshot_radii(radii));
+                    }
+                    if let Some(rounded) = shape.rounded_clip {
+                        let _ = write!(
+                            snapshot,
+                            " rounded_clip={} radii={}",
+                            snapshot_rect(rounded.rect),
+                            snapshot_radii(rounded.radii)
+                        );
+                    }
+                    shape.clip
+                }
+                Entry::Text(text) => {
+                    let _ = write!(
+                        snapshot,
+                        "text z={} {:?} rect={} color={} scale={}",
+                        z_index,
+                        &*text.text,
+                        snapshot_rect(text.rect),
+                        snapshot_color(text.color),
+                        snapshot_f32(text.scale)
+                    );
+                    text.clip
+                }
+            };
+            if let Some(clip) = clip {
+                let _ = write!(snapshot, " clip={}", snapshot_rect(clip));
+            }
+            snapshot.push('\n');
+        }
+        snapshot
+    }
+
     pub fn push_hit(
         &mut self,
         node_id: NodeId,
@@ -225,27 +425,58 @@ impl RenderScene for Scene {
         self.shapes.clear();
         self.texts.clear();
         self.hits.clear();
+        self.blur_layers.clear();
         self.node_index.clear();
         self.next_z = 0;
     }
 
     fn hit_test(&self, x: f32, y: f32) -> Vec<Self::HitTarget> {
-        let mut hits: Vec<_> = self
-            .hits
+        // Regions are pushed in draw order after children are sorted by z-index,
+        // so walking them backwards yields the region drawn on top first. Regions
+        // outside their ancestors' clip don't match.
+        self.hits
             .iter()
+            .rev()
             .filter(|hit| hit.contains(x, y))
             .cloned()
-            .collect();
-
-        // Sort by z-index descending (top to bottom)
-        hits.sort_by(|a, b| b.z_index.cmp(&a.z_index));
-        hits
+            .collect()
     }
 
     fn find_target(&self, node_id: NodeId) -> Option<Self::HitTarget> {
         // O(1) lookup using the node index
         self.node_index.get(&node_id).cloned()
     }
+
+    fn content_hash(&self) -> Option<u64> {
+        let mut state = DefaultHasher::new();
+        state.write_usize(self.shapes.len());
+        for shape in &self.shapes {
+            hash_rect(&mut state, shape.rect);
+            hash_brush(&mut state, &shape.brush);
+            hash_option_shape(&mut state, shape.shape);
+            hash_option_arc(&mut state, shape.arc);
+            hash_option_line(&mut state, shape.line);
+            hash_option_rounded_clip(&mut state, shape.rounded_clip);
+            hash_option_rect(&mut state, shape.clip);
+        }
+        state.write_usize(self.texts.len());
+        for text in &self.texts {
+            text.node_id.hash(&mut state);
+            hash_rect(&mut state, text.rect);
+            text.text.hash(&mut state);
+            hash_color(&mut state, text.color);
+            hash_f32(&mut state, text.scale);
+            hash_text_style(&mut state, &text.style);
+            hash_option_rect(&mut state, text.clip);
+        }
+        for layer in &self.blur_layers {
+            hash_rect(&mut state, layer.rect);
+            hash_f32(&mut state, layer.radius);
+            layer.shapes.hash(&mut state);
+            layer.texts.hash(&mut state);
+        }
+        Some(state.finish())
+    }
 }
 
 // Helper function for rounded rectangle hit testing
@@ -294,3 +525,7 @@ fn point_in_rounded_rect(x: f32, y: f32, rect: Rect, shape: RoundedCornerShape)
 
     true
 }
+
+#[cfg(test)]
+#[path = "tests/scene_tests.rs"]
+mod tests;
@@ -2,72 +2,155 @@
 
 pub const SHADER: &str = r#"
 // Shared structs
-struct VertexInput {
-    @location(0) position: vec2<f32>,
-    @location(1) color: vec4<f32>,
-    @location(2) uv: vec2<f32>,
+// One instance per shape; the quad's corners come from the vertex index
+struct ShapeInstance {
+    @location(0) rect: vec4<f32>,            // x, y, width, height
+    @location(1) radii: vec4<f32>,           // top_left, top_right, bottom_left, bottom_right (arc: stroke, start, sweep, unused; line: half vector, half stroke, unused)
+    @location(2) gradient_params: vec4<f32>, // center.x, center.y, radius, color space (0=srgb, 1=linear, 2=oklab)
+    @location(3) clip_rect: vec4<f32>,       // clip_x, clip_y, clip_width, clip_height (0,0,0,0 = no clip)
+    @location(4) color: vec4<f32>,
+    @location(5) brush_type: u32,            // 0=solid, 1=linear_gradient, 2=radial_gradient
+    @location(6) gradient_start: u32,
+    @location(7) gradient_count: u32,
+    @location(8) shape_kind: u32,            // 0=rounded_rect, 1=arc, 2=line
+    @location(9) rounded_clip_rect: vec4<f32>,  // x, y, width, height (0,0,0,0 = no rounded clip)
+    @location(10) rounded_clip_radii: vec4<f32>, // top_left, top_right, bottom_left, bottom_right
 }
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
-    @location(0) color: vec4<f32>,
-    @location(1) uv: vec2<f32>,
-    @location(2) rect_pos: vec2<f32>,
-    @location(3) @interpolate(flat) shape_idx: u32,
+    @location(0) @interpolate(flat) color: vec4<f32>,
+    @location(1) rect_pos: vec2<f32>,
+    @location(2) @interpolate(flat) rect: vec4<f32>,
+    @location(3) @interpolate(flat) radii: vec4<f32>,
+    @location(4) @interpolate(flat) gradient_params: vec4<f32>,
+    @location(5) @interpolate(flat) clip_rect: vec4<f32>,
+    // brush_type, gradient_start, gradient_count, shape_kind
+    @location(6) @interpolate(flat) brush: vec4<u32>,
+    @location(7) @interpolate(flat) rounded_clip_rect: vec4<f32>,
+    @location(8) @interpolate(flat) rounded_clip_radii: vec4<f32>,
 }
 
 struct Uniforms {
     viewport: vec2<f32>,
-    _padding: vec2<f32>,
+    edge_softness: f32,         // 0 = hard edges, 1 = one pixel of analytic antialiasing
+    _padding: f32,
 }
 
 @group(0) @binding(0)
 var<uniform> uniforms: Uniforms;
 
-// Vertex shader
+// Vertex shader: expands each instance into a quad drawn as a four-vertex strip
 @vertex
-fn vs_main(input: VertexInput, @builtin(vertex_index) vertex_idx: u32) -> VertexOutput {
+fn vs_main(instance: ShapeInstance, @builtin(vertex_index) vertex_idx: u32) -> VertexOutput {
     var output: VertexOutput;
 
+    let corner = vec2<f32>(f32(vertex_idx & 1u), f32(vertex_idx >> 1u));
+    let position = instance.rect.xy + corner * instance.rect.zw;
+
     // Convert from pixel coordinates to clip space
-    let x = (input.position.x / uniforms.viewport.x) * 2.0 - 1.0;
-    let y = 1.0 - (input.position.y / uniforms.viewport.y) * 2.0;
+    let x = (position.x / uniforms.viewport.x) * 2.0 - 1.0;
+    let y = 1.0 - (position.y / uniforms.viewport.y) * 2.0;
 
     output.clip_position = vec4<f32>(x, y, 0.0, 1.0);
-    output.color = input.color;
-    output.uv = input.uv;
-    output.rect_pos = input.position;
-    // Each shape has 4 vertices, so divide by 4 to get shape index
-    output.shape_idx = vertex_idx / 4u;
+    output.color = instance.color;
+    output.rect_pos = position;
+    output.rect = instance.rect;
+    output.radii = instance.radii;
+    output.gradient_params = instance.gradient_params;
+    output.clip_rect = instance.clip_rect;
+    output.brush = vec4<u32>(
+        instance.brush_type,
+        instance.gradient_start,
+        instance.gradient_count,
+        instance.shape_kind,
+    );
+    output.rounded_clip_rect = instance.rounded_clip_rect;
+    output.rounded_clip_radii = instance.rounded_clip_radii;
 
     return output;
 }
 
 // Fragment shader structs and data
-struct ShapeData {
-    rect: vec4<f32>,            // x, y, width, height
-    radii: vec4<f32>,           // top_left, top_right, bottom_left, bottom_right
-    gradient_params: vec4<f32>, // center.x, center.y, radius, unused
-    clip_rect: vec4<f32>,       // clip_x, clip_y, clip_width, clip_height (0,0,0,0 = no clip)
-    brush_type: u32,            // 0=solid, 1=linear_gradient, 2=radial_gradient
-    gradient_start: u32,
-    gradient_count: u32,
-    _padding: u32,
-}
-
 struct GradientStop {
     color: vec4<f32>,
 }
 
-// Use uniform buffers for WebGL compatibility
+// Use a uniform buffer for WebGL compatibility
 // Note: WebGL has a minimum uniform buffer size of 16KB
-// ShapeData is 80 bytes now (with clip_rect), so ~200 shapes = 16KB
 @group(1) @binding(0)
-var<uniform> shape_data: array<ShapeData, 200>;
-
-@group(1) @binding(1)
 var<uniform> gradient_stops: array<GradientStop, 256>;
 
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let v = clamp(c, vec3<f32>(0.0), vec3<f32>(1.0));
+    return select(pow((v + 0.055) / 1.055, vec3<f32>(2.4)), v / 12.92, v <= vec3<f32>(0.04045));
+}
+
+fn linear_to_srgb(c: vec3<f32>) -> vec3<f32> {
+    let v = clamp(c, vec3<f32>(0.0), vec3<f32>(1.0));
+    return select(1.055 * pow(v, vec3<f32>(1.0 / 2.4)) - 0.055, v * 12.92, v <= vec3<f32>(0.0031308));
+}
+
+// Oklab matrices from https://bottosson.github.io/posts/oklab/
+fn linear_to_oklab(c: vec3<f32>) -> vec3<f32> {
+    let lms = vec3<f32>(
+        dot(vec3<f32>(0.4122214708, 0.5363325363, 0.0514459929), c),
+        dot(vec3<f32>(0.2119034982, 0.6806995451, 0.1073969566), c),
+        dot(vec3<f32>(0.0883024619, 0.2817188376, 0.6299787005), c),
+    );
+    // Cube root; the channels are non-negative for in-gamut colors
+    let r = pow(max(lms, vec3<f32>(0.0)), vec3<f32>(1.0 / 3.0));
+    return vec3<f32>(
+        dot(vec3<f32>(0.2104542553, 0.7936177850, -0.0040720468), r),
+        dot(vec3<f32>(1.9779984951, -2.4285922050, 0.4505937099), r),
+        dot(vec3<f32>(0.0259040371, 0.7827717662, -0.8086757660), r),
+    );
+}
+
+fn oklab_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let r = vec3<f32>(
+        dot(vec3<f32>(1.0, 0.3963377774, 0.2158037573), c),
+        dot(vec3<f32>(1.0, -0.1055613458, -0.0638541728), c),
+        dot(vec3<f32>(1.0, -0.0894841775, -1.2914855480), c),
+    );
+    let lms = r * r * r;
+    return vec3<f32>(
+        dot(vec3<f32>(4.0767416621, -3.3077115913, 0.2309699292), lms),
+        dot(vec3<f32>(-1.2684380046, 2.6097574011, -0.3413193965), lms),
+        dot(vec3<f32>(-0.0041960863, -0.7034186147, 1.7076147010), lms),
+    );
+}
+
+// Blends two stops in the gradient's color space; alpha always blends linearly.
+fn mix_gradient(c1: vec4<f32>, c2: vec4<f32>, t: f32, color_space: u32) -> vec4<f32> {
+    var rgb = mix(c1.rgb, c2.rgb, t);
+    if (color_space == 1u) {
+        rgb = linear_to_srgb(mix(srgb_to_linear(c1.rgb), srgb_to_linear(c2.rgb), t));
+    } else if (color_space == 2u) {
+        let lab = mix(linear_to_oklab(srgb_to_linear(c1.rgb)), linear_to_oklab(srgb_to_linear(c2.rgb)), t);
+        rgb = linear_to_srgb(oklab_to_linear(lab));
+    }
+    return vec4<f32>(rgb, mix(c1.a, c2.a, t));
+}
+
+// Color at t in 0..1 along `count` evenly spaced stops starting at `start`.
+fn sample_gradient(start: u32, count: u32, t: f32, color_space: u32) -> vec4<f32> {
+    if (count <= 1u) {
+        return gradient_stops[start].color;
+    }
+    let segments = count - 1u;
+    let scaled = t * f32(segments);
+    let idx = min(u32(scaled), segments);
+    let next_idx = min(idx + 1u, segments);
+    let local_t = fract(scaled);
+
+    let c1 = gradient_stops[start + idx].color;
+    let c2 = gradient_stops[start + next_idx].color;
+    return mix_gradient(c1, c2, local_t, color_space);
+}
+
+// r: top_left, top_right, bottom_left, bottom_right; the quadrant of p picks the corner.
+// y grows downward, so p.y > 0 is the bottom half.
 fn sdf_rounded_rect(p: vec2<f32>, b: vec2<f32>, r: vec4<f32>) -> f32 {
     var radius = r.x;
     if (p.x > 0.0) {
@@ -84,17 +167,80 @@ fn sdf_rounded_rect(p: vec2<f32>, b: vec2<f32>, r: vec4<f32>) -> f32 {
     return min(max(q.x, q.y), 0.0) + length(max(q, vec2<f32>(0.0, 0.0))) - radius;
 }
 
+// Distance to a stroked arc of the circle inscribed in the shape bounds.
+// params: stroke width, start angle and sweep in degrees (clockwise from +x).
+fn sdf_arc(p: vec2<f32>, outer_radius: f32, params: vec4<f32>) -> f32 {
+    let stroke = params.x;
+    let center_radius = outer_radius - stroke * 0.5;
+    let ring = abs(length(p) - center_radius) - stroke * 0.5;
+    if (params.z >= 360.0) {
+        return ring;
+    }
+    let angle = degrees(atan2(p.y, p.x));
+    let relative = angle - params.y;
+    let wrapped = relative - 360.0 * floor(relative / 360.0);
+    if (wrapped > params.z) {
+        return max(ring, 1.0);
+    }
+    return ring;
+}
+
+// Distance to a straight stroke with butt caps centered in the shape bounds.
+// params: half of the vector from start to end, and half the stroke width.
+fn sdf_line(p: vec2<f32>, params: vec4<f32>) -> f32 {
+    let half_length = max(length(params.xy), 0.0001);
+    let direction = params.xy / half_length;
+    let along = abs(dot(p, direction)) - half_length;
+    let across = abs(dot(p, vec2<f32>(-direction.y, direction.x))) - params.z;
+    let d = vec2<f32>(along, across);
+    return length(max(d, vec2<f32>(0.0))) + min(max(d.x, d.y), 0.0);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    let shape = shape_data[input.shape_idx];
     let rect_pos = input.rect_pos;
     
-    // Apply clipping: if clip_rect has non-zero size, clip to it
-    let clip_w = shape.clip_rect.z;
-    let clip_h = shape.clip_rect.w;
+    let rect_center = input.rect.xy + input.rect.zw * 0.5;
+    let half_size = input.rect.zw * 0.5;
+    let local_pos = rect_pos - rect_center;
+
+    // Compute SDF for the shape outline
+    var dist: f32;
+    if (input.brush.w == 1u) {
+        dist = sdf_arc(local_pos, min(half_size.x, half_size.y), input.radii);
+    } else if (input.brush.w == 2u) {
+        dist = sdf_line(local_pos, input.radii);
+    } else {
+        dist = sdf_rounded_rect(local_pos, half_size, input.radii);
+    }
+
+    // Intersect with the rounded clip so both edges are antialiased together
+    let rounded_clip = input.rounded_clip_rect;
+    let clip_half_size = rounded_clip.zw * 0.5;
+    let clip_dist = sdf_rounded_rect(
+        rect_pos - (rounded_clip.xy + clip_half_size),
+        clip_half_size,
+        input.rounded_clip_radii,
+    );
+    dist = select(dist, max(dist, clip_dist), rounded_clip.z > 0.0 && rounded_clip.w > 0.0);
+
+    // Anti-aliasing: fade over one screen pixel of distance, whatever the shape's scale
+    let pixel = max(fwidth(dist), 0.0001);
+    var alpha: f32;
+    if (uniforms.edge_softness > 0.0) {
+        let half_width = 0.5 * pixel * uniforms.edge_softness;
+        alpha = 1.0 - smoothstep(-half_width, half_width, dist);
+    } else {
+        alpha = select(0.0, 1.0, dist <= 0.0);
+    }
+
+    // Apply clipping: if clip_rect has non-zero size, clip to it.
+    // Done after the derivatives above, which need uniform control flow.
+    let clip_w = input.clip_rect.z;
+    let clip_h = input.clip_rect.w;
     if (clip_w > 0.0 && clip_h > 0.0) {
-        let clip_left = shape.clip_rect.x;
-        let clip_top = shape.clip_rect.y;
+        let clip_left = input.clip_rect.x;
+        let clip_top = input.clip_rect.y;
         let clip_right = clip_left + clip_w;
         let clip_bottom = clip_top + clip_h;
         
@@ -105,16 +251,6 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
         }
     }
     
-    let rect_center = shape.rect.xy + shape.rect.zw * 0.5;
-    let half_size = shape.rect.zw * 0.5;
-    let local_pos = rect_pos - rect_center;
-
-    // Compute SDF for rounded rectangle
-    let dist = sdf_rounded_rect(local_pos, half_size, shape.radii);
-
-    // Anti-aliasing
-    let alpha = 1.0 - smoothstep(-0.5, 0.5, dist);
-
     if (alpha < 0.001) {
         discard;
     }
@@ -122,49 +258,70 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     var color = input.color;
 
     // Apply gradient if needed
-    if (shape.brush_type == 1u) {
+    let color_space = u32(input.gradient_params.w);
+    if (input.brush.x == 1u) {
         // Linear gradient (top to bottom)
-        let height = max(shape.rect.w, 0.00001);
-        let t = clamp((rect_pos.y - shape.rect.y) / height, 0.0, 1.0);
-        let count = shape.gradient_count;
-
-        if (count <= 1u) {
-            color = gradient_stops[shape.gradient_start].color;
-        } else {
-            let segments = count - 1u;
-            let scaled = t * f32(segments);
-            let idx = min(u32(scaled), segments);
-            let next_idx = min(idx + 1u, segments);
-            let local_t = fract(scaled);
-
-            let c1 = gradient_stops[shape.gradient_start + idx].color;
-            let c2 = gradient_stops[shape.gradient_start + next_idx].color;
-            color = mix(c1, c2, local_t);
-        }
-    } else if (shape.brush_type == 2u) {
+        let height = max(input.rect.w, 0.00001);
+        let t = clamp((rect_pos.y - input.rect.y) / height, 0.0, 1.0);
+        color = sample_gradient(input.brush.y, input.brush.z, t, color_space);
+    } else if (input.brush.x == 2u) {
         // Radial gradient - use explicit center and radius from gradient_params
-        let center = shape.gradient_params.xy;
-        let radius = max(shape.gradient_params.z, 0.00001);
+        let center = input.gradient_params.xy;
+        let radius = max(input.gradient_params.z, 0.00001);
         let dist_from_center = length(rect_pos - center);
         let t = clamp(dist_from_center / radius, 0.0, 1.0);
-
-        let count = shape.gradient_count;
-
-        if (count <= 1u) {
-            color = gradient_stops[shape.gradient_start].color;
-        } else {
-            let segments = count - 1u;
-            let scaled = t * f32(segments);
-            let idx = min(u32(scaled), segments);
-            let next_idx = min(idx + 1u, segments);
-            let local_t = fract(scaled);
-
-            let c1 = gradient_stops[shape.gradient_start + idx].color;
-            let c2 = gradient_stops[shape.gradient_start + next_idx].color;
-            color = mix(c1, c2, local_t);
-        }
+        color = sample_gradient(input.brush.y, input.brush.z, t, color_space);
     }
 
     return vec4<f32>(color.rgb, color.a * alpha);
 }
 "#;
+
+/// Separable gaussian blur. Each pass samples along `params.direction`; the
+/// horizontal and vertical pipelines share this module and differ only in the
+/// direction they are given and how they blend into their target.
+pub const BLUR_SHADER: &str = r#"
+struct BlurParams {
+    direction: vec2<f32>,  // one texel along the blur axis, in UV units
+    sigma: f32,
+    radius: f32,           // kernel half-size in texels
+}
+
+struct BlurVertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var source_texture: texture_2d<f32>;
+@group(0) @binding(1)
+var source_sampler: sampler;
+@group(0) @binding(2)
+var<uniform> params: BlurParams;
+
+// Single triangle covering the whole target
+@vertex
+fn vs_fullscreen(@builtin(vertex_index) vertex_idx: u32) -> BlurVertexOutput {
+    let uv = vec2<f32>(f32((vertex_idx << 1u) & 2u), f32(vertex_idx & 2u));
+    var output: BlurVertexOutput;
+    output.clip_position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    output.uv = uv;
+    return output;
+}
+
+@fragment
+fn fs_blur(input: BlurVertexOutput) -> @location(0) vec4<f32> {
+    let taps = i32(params.radius);
+    let denominator = 2.0 * params.sigma * params.sigma;
+    var sum = vec4<f32>(0.0);
+    var total = 0.0;
+    for (var i = -taps; i <= taps; i = i + 1) {
+        let offset = f32(i);
+        let weight = exp(-(offset * offset) / denominator);
+        let uv = input.uv + params.direction * offset;
+        sum = sum + textureSampleLevel(source_texture, source_sampler, uv, 0.0) * weight;
+        total = total + weight;
+    }
+    return sum / total;
+}
+"#;
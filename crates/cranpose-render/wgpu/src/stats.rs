@@ -0,0 +1,210 @@
+//! Per-frame renderer statistics for profiling.
+//!
+//! Counts are taken while a frame is encoded. GPU time needs timestamp
+//! queries, which are read back asynchronously, so it reports an earlier frame
+//! and is missing on devices without `TIMESTAMP_QUERY` (e.g. WebGL).
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Work done to draw the last frame.
+///
+/// Returned by [`WgpuRenderer::render_stats`](crate::WgpuRenderer::render_stats).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    /// Shapes drawn, after culling.
+    pub shapes: usize,
+    /// Text areas drawn, after culling.
+    pub text_areas: usize,
+    /// Instanced shape draws; every blurred layer splits the shapes into more.
+    pub shape_batches: usize,
+    /// Two-pass blurs composited onto the frame.
+    pub blurred_layers: usize,
+    /// Draw calls issued for shapes, blurs and text passes.
+    pub draw_calls: usize,
+    /// Triangles of shape quads and blur passes; glyphs are not counted.
+    pub triangles: usize,
+    /// Times text preparation ran: once per text pass, plus a retry whenever the
+    /// glyph atlas was full.
+    pub text_prepares: usize,
+    /// GPU time of the latest frame read back, if timestamp queries are supported.
+    pub gpu_frame_time: Option<Duration>,
+}
+
+impl RenderStats {
+    /// Counts an instanced draw of `shapes` shape quads.
+    pub(crate) fn record_shape_batch(&mut self, shapes: usize) {
+        self.shape_batches += 1;
+        self.draw_calls += 1;
+        self.triangles += shapes * 2;
+    }
+
+    /// Counts a blurred layer: one fullscreen triangle per blur direction.
+    pub(crate) fn record_blur(&mut self) {
+        self.blurred_layers += 1;
+        self.draw_calls += 2;
+        self.triangles += 2;
+    }
+
+    /// Counts a text pass drawing `text_areas` areas.
+    pub(crate) fn record_text(&mut self, text_areas: usize, prepares: usize) {
+        self.text_areas += text_areas;
+        self.text_prepares += prepares;
+        self.draw_calls += 1;
+    }
+}
+
+const TIMESTAMP_BYTES: wgpu::BufferAddress = 2 * wgpu::QUERY_SIZE as wgpu::BufferAddress;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Readback {
+    Idle,
+    Pending,
+    Ready,
+}
+
+/// Measures the GPU time of frames with timestamp queries.
+///
+/// A timed frame writes a timestamp before its first pass and after its last
+/// one. The pair is mapped back once a later submission completes the copy;
+/// frames encoded while a readback is pending are not timed.
+pub(crate) struct GpuFrameTimer {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    readback_buffer: wgpu::Buffer,
+    period_ns: f32,
+    readback: Arc<Mutex<Readback>>,
+    timing_frame: bool,
+    latest: Option<Duration>,
+}
+
+impl GpuFrameTimer {
+    /// Returns `None` when `device` cannot write timestamps.
+    pub(crate) fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Option<Self> {
+        if !device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            return None;
+        }
+        let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("Frame Timestamps"),
+            ty: wgpu::QueryType::Timestamp,
+            count: 2,
+        });
+        let resolve_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Frame Timestamp Resolve Buffer"),
+            size: TIMESTAMP_BYTES,
+            usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Frame Timestamp Readback Buffer"),
+            size: TIMESTAMP_BYTES,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Some(Self {
+            query_set,
+            resolve_buffer,
+            readback_buffer,
+            period_ns: queue.get_timestamp_period(),
+            readback: Arc::new(Mutex::new(Readback::Idle)),
+            timing_frame: false,
+            latest: None,
+        })
+    }
+
+    /// GPU time of the latest frame read back.
+    pub(crate) fn latest(&self) -> Option<Duration> {
+        self.latest
+    }
+
+    /// Collects a finished readback, then times the frame starting on
+    /// `encoder` if the readback buffer is free.
+    pub(crate) fn begin_frame(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        self.collect();
+        self.timing_frame = *self.readback.lock().unwrap() == Readback::Idle;
+        if self.timing_frame {
+            self.write_timestamp(encoder, 0);
+        }
+    }
+
+    /// Ends the timed frame on `encoder`, its last encoder.
+    pub(crate) fn end_frame(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if !self.timing_frame {
+            return;
+        }
+        self.write_timestamp(encoder, 1);
+        encoder.resolve_query_set(&self.query_set, 0..2, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.readback_buffer,
+            0,
+            TIMESTAMP_BYTES,
+        );
+    }
+
+    /// Starts reading back the timed frame; call after submitting its last encoder.
+    pub(crate) fn frame_submitted(&mut self) {
+        if !std::mem::take(&mut self.timing_frame) {
+            return;
+        }
+        *self.readback.lock().unwrap() = Readback::Pending;
+        let readback = Arc::clone(&self.readback);
+        self.readback_buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                *readback.lock().unwrap() = if result.is_ok() {
+                    Readback::Ready
+                } else {
+                    Readback::Idle
+                };
+            });
+    }
+
+    fn collect(&mut self) {
+        let mut readback = self.readback.lock().unwrap();
+        if *readback != Readback::Ready {
+            return;
+        }
+        {
+            let data = self.readback_buffer.slice(..).get_mapped_range();
+            let timestamp = |index: usize| {
+                let start = index * wgpu::QUERY_SIZE as usize;
+                let mut bytes = [0; 8];
+                bytes.copy_from_slice(&data[start..start + 8]);
+                u64::from_le_bytes(bytes)
+            };
+            self.latest = Some(timestamp_duration(
+                timestamp(0),
+                timestamp(1),
+                self.period_ns,
+            ));
+        }
+        self.readback_buffer.unmap();
+        *readback = Readback::Idle;
+    }
+
+    /// Writes timestamp `index` with an empty compute pass, which only needs
+    /// `TIMESTAMP_QUERY` rather than timestamps inside encoders.
+    fn write_timestamp(&self, encoder: &mut wgpu::CommandEncoder, index: u32) {
+        let _timestamp_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Frame Timestamp Pass"),
+            timestamp_writes: Some(wgpu::ComputePassTimestampWrites {
+                query_set: &self.query_set,
+                beginning_of_pass_write_index: Some(index),
+                end_of_pass_write_index: None,
+            }),
+        });
+    }
+}
+
+/// Time between two GPU timestamps `period_ns` nanoseconds per tick apart.
+///
+/// Timestamps can go backwards when the GPU changes clocks; that reads as zero.
+pub(crate) fn timestamp_duration(begin: u64, end: u64, period_ns: f32) -> Duration {
+    Duration::from_nanos((end.saturating_sub(begin) as f64 * period_ns as f64) as u64)
+}
+
+#[cfg(test)]
+#[path = "tests/stats_tests.rs"]
+mod tests;
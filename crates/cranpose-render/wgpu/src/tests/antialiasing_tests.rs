@@ -0,0 +1,93 @@
+use super::*;
+use wgpu::{TextureFormatFeatureFlags as Flags, TextureFormatFeatures, TextureUsages};
+
+fn format_features(flags: Flags) -> TextureFormatFeatures {
+    TextureFormatFeatures {
+        allowed_usages: TextureUsages::RENDER_ATTACHMENT,
+        flags,
+    }
+}
+
+/// What WebGPU guarantees for the usual surface formats.
+fn guaranteed() -> TextureFormatFeatures {
+    wgpu::TextureFormat::Bgra8UnormSrgb.guaranteed_format_features(wgpu::Features::empty())
+}
+
+#[test]
+fn guaranteed_sample_counts_are_kept() {
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(4), guaranteed()),
+        4
+    );
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(1), guaranteed()),
+        1
+    );
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Analytic, guaranteed()),
+        1
+    );
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::None, guaranteed()),
+        1
+    );
+}
+
+#[test]
+fn unsupported_sample_counts_fall_back_to_one() {
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(8), guaranteed()),
+        1
+    );
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(2), guaranteed()),
+        1
+    );
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(0), guaranteed()),
+        1
+    );
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(3), guaranteed()),
+        1
+    );
+}
+
+#[test]
+fn counts_follow_the_surface_format_features() {
+    let features =
+        format_features(Flags::MULTISAMPLE_X2 | Flags::MULTISAMPLE_X8 | Flags::MULTISAMPLE_RESOLVE);
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(8), features),
+        8
+    );
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(2), features),
+        2
+    );
+    // Not even 4x is assumed when the format lacks it
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(4), features),
+        1
+    );
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(16), features),
+        1
+    );
+}
+
+#[test]
+fn formats_that_cannot_resolve_are_not_multisampled() {
+    let features = format_features(Flags::MULTISAMPLE_X4);
+    assert_eq!(
+        supported_sample_count(AntialiasingMode::Msaa(4), features),
+        1
+    );
+}
+
+#[test]
+fn only_hard_edges_disable_edge_softness() {
+    assert_eq!(AntialiasingMode::None.edge_softness(), 0.0);
+    assert_eq!(AntialiasingMode::Analytic.edge_softness(), 1.0);
+    assert_eq!(AntialiasingMode::Msaa(4).edge_softness(), 1.0);
+}
@@ -0,0 +1,141 @@
+use super::*;
+use crate::scene::{Scene, TextParams};
+use cranpose_ui_graphics::{Color, TextStyle};
+use std::rc::Rc;
+
+fn layer(x: f32, radius: f32, shapes: Range<usize>) -> BlurLayer {
+    layer_with_texts(x, radius, shapes, 0..0)
+}
+
+fn layer_with_texts(x: f32, radius: f32, shapes: Range<usize>, texts: Range<usize>) -> BlurLayer {
+    BlurLayer {
+        rect: Rect {
+            x,
+            y: 0.0,
+            width: 20.0,
+            height: 20.0,
+        },
+        radius,
+        shapes,
+        texts,
+    }
+}
+
+#[test]
+fn blurred_subtree_is_drawn_offscreen_with_requested_kernel() {
+    let steps = plan_render_steps(5, &[layer(10.0, 8.0, 1..4)], 1.0);
+
+    assert_eq!(steps.len(), 3);
+    assert_eq!(steps[0], RenderStep::Direct(0..1));
+    assert_eq!(steps[2], RenderStep::Direct(4..5));
+    match &steps[1] {
+        RenderStep::Blurred {
+            shapes,
+            region,
+            passes,
+            ..
+        } => {
+            assert_eq!(*shapes, 1..4);
+            // The composite region leaves room for the blur to spread
+            assert_eq!((region.x, region.width), (2.0, 36.0));
+            assert_eq!(passes[0].direction, BlurDirection::Horizontal);
+            assert_eq!(passes[1].direction, BlurDirection::Vertical);
+            assert!(passes.iter().all(|pass| pass.kernel_size == 17));
+        }
+        other => panic!("expected a blurred step, got {other:?}"),
+    }
+}
+
+#[test]
+fn kernel_scales_with_root_scale_and_skips_tiny_radii() {
+    let steps = plan_render_steps(2, &[layer(0.0, 4.0, 0..2)], 2.0);
+    assert!(matches!(
+        &steps[..],
+        [RenderStep::Blurred { passes, .. }] if passes[0].kernel_size == 17
+    ));
+
+    let steps = plan_render_steps(2, &[layer(0.0, 0.1, 0..2)], 1.0);
+    assert_eq!(steps, vec![RenderStep::Direct(0..2)]);
+    assert!(blur_passes(f32::NAN).is_none());
+}
+
+#[test]
+fn nested_layers_blur_once_with_outermost_radius() {
+    // The inner layer ends first, so it is recorded before the outer one
+    let steps = plan_render_steps(4, &[layer(0.0, 2.0, 1..2), layer(0.0, 6.0, 0..3)], 1.0);
+
+    assert_eq!(steps.len(), 2);
+    assert!(matches!(
+        &steps[0],
+        RenderStep::Blurred { shapes, passes, .. } if *shapes == (0..3) && passes[0].kernel_size == 13
+    ));
+    assert_eq!(steps[1], RenderStep::Direct(3..4));
+}
+
+#[test]
+fn blurred_steps_carry_the_texts_of_their_layer() {
+    let steps = plan_render_steps(
+        3,
+        &[
+            layer_with_texts(0.0, 4.0, 0..1, 0..2),
+            // Text-only subtree, e.g. a blurred label
+            layer_with_texts(40.0, 4.0, 1..1, 2..3),
+        ],
+        1.0,
+    );
+
+    assert!(matches!(
+        &steps[..],
+        [
+            RenderStep::Blurred { shapes: first, texts: first_texts, .. },
+            RenderStep::Blurred { shapes: second, texts: second_texts, .. },
+            RenderStep::Direct(rest),
+        ] if *first == (0..1)
+            && *first_texts == (0..2)
+            && second.is_empty()
+            && *second_texts == (2..3)
+            && *rest == (1..3)
+    ));
+}
+
+#[test]
+fn scene_records_texts_of_blurred_subtree() {
+    let mut scene = Scene::new();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 10.0,
+        height: 10.0,
+    };
+    let params = || TextParams {
+        text: Rc::from("Blurred"),
+        color: Color::BLACK,
+        scale: 1.0,
+        style: TextStyle::default(),
+    };
+    scene.push_text(1, rect, params(), None);
+    let (first_shape, first_text) = (scene.shapes.len(), scene.texts.len());
+    scene.push_text(2, rect, params(), None);
+    scene.push_blur_layer(rect, 4.0, first_shape, first_text);
+    // Nothing was drawn since, so there is nothing to blur
+    scene.push_blur_layer(rect, 4.0, scene.shapes.len(), scene.texts.len());
+
+    assert_eq!(scene.blur_layers.len(), 1);
+    assert_eq!(scene.blur_layers[0].shapes, 0..0);
+    assert_eq!(scene.blur_layers[0].texts, 1..2);
+}
+
+#[test]
+fn scissor_is_clamped_to_frame() {
+    let region = Rect {
+        x: -5.0,
+        y: 10.0,
+        width: 30.0,
+        height: 500.0,
+    };
+    assert_eq!(scissor_rect(region, 2.0, 100, 100), Some([0, 20, 50, 80]));
+    assert_eq!(
+        scissor_rect(Rect { x: 200.0, ..region }, 1.0, 100, 100),
+        None
+    );
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::text_cache::TextCache;
+use crate::{WgpuRenderer, WgpuTextMeasurer};
+use cranpose_ui::TextMeasurer;
+use cranpose_ui_graphics::TextStyle;
+use glyphon::Shaping;
+use std::sync::Mutex;
+
+static ROBOTO_LIGHT: &[u8] = include_bytes!("../../../../../assets/Roboto-Light.ttf");
+static ROBOTO_REGULAR: &[u8] = include_bytes!("../../../../../assets/Roboto-Regular.ttf");
+
+/// Font system holding only Roboto Light, without system fonts.
+///
+/// Shaping needs at least one face, so the light face stands in for the fonts
+/// an app loads at startup.
+fn startup_font_system() -> FontSystem {
+    let mut db = fontdb::Database::new();
+    db.load_font_data(ROBOTO_LIGHT.to_vec());
+    FontSystem::new_with_locale_and_db("en-US".to_string(), db)
+}
+
+/// Faces the glyphs of `text` were shaped with, for regular-weight text.
+fn shaping_faces(font_system: &mut FontSystem, text: &str) -> Vec<fontdb::ID> {
+    let mut buffer = Buffer::new(font_system, Metrics::new(14.0, 20.0));
+    buffer.set_text(font_system, text, &Attrs::new(), Shaping::Advanced);
+    buffer.shape_until_scroll(font_system, false);
+    buffer
+        .layout_runs()
+        .flat_map(|run| run.glyphs.iter().map(|glyph| glyph.font_id))
+        .collect()
+}
+
+#[test]
+fn runtime_font_adds_faces_and_shapes_text() {
+    let mut font_system = startup_font_system();
+    let startup_face = shaping_faces(&mut font_system, "A")[0];
+
+    let font = load_font(&mut font_system, ROBOTO_REGULAR);
+
+    assert_eq!(font.face_count(), 1);
+    assert_eq!(font_system.db().faces().count(), 2);
+    let faces = shaping_faces(&mut font_system, "Ab");
+    assert_eq!(faces.len(), 2);
+    assert!(faces.iter().all(|&face| face != startup_face));
+    assert!(faces.iter().all(|face| font.faces.contains(face)));
+}
+
+#[test]
+fn removed_font_falls_back_to_startup_fonts() {
+    let mut font_system = startup_font_system();
+    let startup_face = shaping_faces(&mut font_system, "A")[0];
+    let font = load_font(&mut font_system, ROBOTO_REGULAR);
+    assert_ne!(shaping_faces(&mut font_system, "A")[0], startup_face);
+
+    assert!(unload_font(&mut font_system, &font));
+    assert!(!unload_font(&mut font_system, &font));
+
+    assert_eq!(font_system.db().faces().count(), 1);
+    assert_eq!(shaping_faces(&mut font_system, "A"), vec![startup_face]);
+}
+
+#[test]
+fn empty_font_system_gets_the_fallback_font() {
+    let mut font_system =
+        FontSystem::new_with_locale_and_db("en-US".to_string(), fontdb::Database::new());
+    assert!(!can_shape(&mut font_system, "A"));
+
+    let fallback = ensure_fallback_font(&mut font_system).expect("fallback font loaded");
+
+    assert_eq!(fallback.face_count(), 1);
+    assert!(can_shape(&mut font_system, "A"));
+    assert_eq!(ensure_fallback_font(&mut font_system), None);
+}
+
+#[test]
+fn renderer_without_app_fonts_still_measures_text() {
+    let renderer = WgpuRenderer::new_with_fonts(&[]);
+
+    assert!(renderer.font_face_count() > 0);
+    assert!(renderer.check_fonts().is_ok());
+    let metrics = renderer
+        .text_measurer
+        .measure("Hello", &TextStyle::default());
+    assert!(metrics.width > 0.0 && metrics.height > 0.0);
+}
+
+#[test]
+fn reference_string_measures_to_a_fixed_size() {
+    let font_system = create_font_system(&[ROBOTO_LIGHT, ROBOTO_REGULAR]);
+    let measurer = WgpuTextMeasurer::new(Arc::new(Mutex::new(font_system)), TextCache::shared());
+
+    let metrics = measurer.measure("Hello, World!", &TextStyle::default());
+
+    // Roboto Regular at 14px, whatever fonts the host has installed
+    assert!((metrics.width - 78.28125).abs() < 1e-3, "{}", metrics.width);
+    assert!((metrics.height - 19.6).abs() < 1e-3, "{}", metrics.height);
+}
@@ -0,0 +1,156 @@
+use super::*;
+use cranpose_core::location_key;
+use cranpose_render_common::snapshot::assert_snapshot;
+use cranpose_ui::{
+    Box, BoxSpec, BoxWithConstraints, Column, ColumnSpec, Composition, LayoutEngine, Modifier,
+    Size, Text,
+};
+
+fn render_scene(content: impl FnMut() + 'static) -> Scene {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition.render(key, content).expect("initial render");
+    let root = composition.root().expect("root");
+    let handle = composition.runtime_handle();
+    let mut applier = composition.applier_mut();
+    applier.set_runtime_handle(handle);
+    let layout = applier
+        .compute_layout(root, Size::new(200.0, 200.0))
+        .expect("layout");
+    applier.clear_runtime_handle();
+
+    let mut scene = Scene::new();
+    render_layout_tree(layout.root(), &mut scene);
+    scene
+}
+
+#[test]
+fn small_layout_matches_snapshot() {
+    let scene = render_scene(|| {
+        Column(
+            Modifier::empty().padding(8.0),
+            ColumnSpec::default(),
+            || {
+                Box(
+                    Modifier::empty()
+                        .size(Size::new(40.0, 20.0))
+                        .rounded_corners(4.0)
+                        .background(Color(1.0, 0.0, 0.0, 1.0)),
+                    BoxSpec::default(),
+                    || {},
+                );
+                Text(
+                    "Hi".to_string(),
+                    Modifier::empty().background(Color(0.0, 0.0, 1.0, 1.0)),
+                );
+            },
+        );
+    });
+
+    assert_snapshot(
+        &scene.to_snapshot(),
+        r#"
+            shape z=0 rect=(8, 8, 40, 20) brush=#ff0000ff radii=(4, 4, 4, 4)
+            shape z=1 rect=(8, 28, 16, 20) brush=#0000ffff
+            text z=2 "Hi" rect=(8, 28, 16, 20) color=#ffffffff scale=1
+        "#,
+    );
+}
+
+#[test]
+fn subcompose_siblings_draw_in_z_index_order() {
+    let front = Color(1.0, 0.0, 0.0, 1.0);
+    let back = Color(0.0, 0.0, 1.0, 1.0);
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, move || {
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                BoxWithConstraints(
+                    Modifier::empty()
+                        .size(Size::new(20.0, 20.0))
+                        .background(front)
+                        .z_index(1.0),
+                    |_| {},
+                );
+                Box(
+                    Modifier::empty()
+                        .size(Size::new(20.0, 20.0))
+                        .background(back),
+                    BoxSpec::default(),
+                    || {},
+                );
+            });
+        })
+        .expect("initial render");
+    let root = composition.root().expect("root");
+    let handle = composition.runtime_handle();
+    let mut applier = composition.applier_mut();
+    applier.set_runtime_handle(handle);
+    applier
+        .compute_layout(root, Size::new(200.0, 200.0))
+        .expect("layout");
+
+    let mut scene = Scene::new();
+    render_from_applier(&mut applier, root, &mut scene, 1.0);
+    applier.clear_runtime_handle();
+
+    let colors: Vec<_> = scene
+        .shapes
+        .iter()
+        .filter_map(|shape| match shape.brush {
+            Brush::Solid(color) => Some(color),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(colors, vec![back, front]);
+}
+
+#[test]
+fn overlay_clipped_shapes_carry_the_rounded_clip() {
+    let command = cranpose_ui::DrawCommand::OverlayClipped(
+        Rc::new(|_| {
+            vec![cranpose_ui_graphics::DrawPrimitive::Rect {
+                rect: Rect {
+                    x: -10.0,
+                    y: -10.0,
+                    width: 60.0,
+                    height: 60.0,
+                },
+                brush: Brush::solid(Color(1.0, 1.0, 1.0, 1.0)),
+            }]
+        }),
+        Some(RoundedCornerShape::uniform(8.0)),
+    );
+    let rect = Rect {
+        x: 10.0,
+        y: 20.0,
+        width: 40.0,
+        height: 30.0,
+    };
+    let layer = GraphicsLayer {
+        scale: 2.0,
+        ..GraphicsLayer::default()
+    };
+
+    let mut scene = Scene::new();
+    apply_draw_commands(
+        &[command],
+        DrawPlacement::Overlay,
+        rect,
+        (0.0, 0.0),
+        Size::new(rect.width, rect.height),
+        layer,
+        None,
+        &mut scene,
+    );
+
+    assert_eq!(scene.shapes.len(), 1);
+    assert_eq!(
+        scene.shapes[0].rounded_clip,
+        Some(cranpose_render_common::RoundedClip {
+            rect: apply_layer_to_rect(rect, (0.0, 0.0), layer),
+            radii: cranpose_ui_graphics::CornerRadii::uniform(16.0),
+        })
+    );
+}
@@ -0,0 +1,196 @@
+use super::*;
+use crate::scene::{BlurLayer, Scene, TextParams};
+use crate::stats::RenderStats;
+use cranpose_ui_graphics::{Brush, Color, Rect, TextStyle};
+use glyphon::fontdb;
+use std::rc::Rc;
+
+static ROBOTO_REGULAR: &[u8] = include_bytes!("../../../../../assets/Roboto-Regular.ttf");
+
+/// Instance ranges the frame draws, one instanced draw call each.
+fn draw_calls(shape_count: usize, drawn: &[usize], layers: &[BlurLayer]) -> Vec<Range<usize>> {
+    blur::plan_render_steps(shape_count, layers, 1.0)
+        .into_iter()
+        .map(|step| match step {
+            RenderStep::Direct(range) | RenderStep::Blurred { shapes: range, .. } => {
+                instance_range(drawn, range)
+            }
+        })
+        .filter(|instances| !instances.is_empty())
+        .collect()
+}
+
+#[test]
+fn many_rects_are_drawn_with_one_instanced_draw() {
+    let count = 10_000;
+    let drawn: Vec<usize> = (0..count).collect();
+
+    assert_eq!(draw_calls(count, &drawn, &[]), vec![0..count]);
+}
+
+#[test]
+fn culled_shapes_are_left_out_of_instance_ranges() {
+    // Shapes 2 and 5 were clipped away before upload
+    let drawn = [0, 1, 3, 4, 6];
+
+    assert_eq!(instance_range(&drawn, 0..7), 0..5);
+    assert_eq!(instance_range(&drawn, 2..6), 2..4);
+    assert_eq!(instance_range(&drawn, 5..6), 4..4);
+}
+
+#[test]
+fn blurred_layers_split_the_batch_in_draw_order() {
+    let count = 1_000;
+    let drawn: Vec<usize> = (0..count).collect();
+    let layer = BlurLayer {
+        rect: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 10.0,
+            height: 10.0,
+        },
+        radius: 4.0,
+        shapes: 400..600,
+        texts: 0..0,
+    };
+
+    assert_eq!(
+        draw_calls(count, &drawn, &[layer]),
+        vec![0..400, 400..600, 600..count]
+    );
+}
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> Rect {
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+#[test]
+fn shapes_outside_the_viewport_are_culled() {
+    let mut scene = Scene::new();
+    let brush = Brush::solid(Color::BLACK);
+    scene.push_shape(rect(0.0, 100.0, 50.0, 50.0), brush.clone(), None, None);
+    scene.push_shape(rect(0.0, 10_000.0, 50.0, 50.0), brush.clone(), None, None);
+    // Inside the viewport but entirely outside its clip
+    scene.push_shape(
+        rect(0.0, 200.0, 50.0, 50.0),
+        brush.clone(),
+        None,
+        Some(rect(0.0, 0.0, 100.0, 100.0)),
+    );
+    // Partly on screen
+    scene.push_shape(rect(-25.0, 590.0, 50.0, 50.0), brush, None, None);
+
+    let drawn: Vec<usize> = visible_shapes(&scene.shapes, 800.0, 600.0, 1.0).collect();
+
+    assert_eq!(drawn, vec![0, 3]);
+}
+
+#[test]
+fn culling_uses_the_physical_viewport() {
+    // At 2x the 600px viewport shows 300 logical pixels
+    let visible_at_2x = |y| is_visible(rect(0.0, y, 10.0, 10.0), None, 800.0, 600.0, 2.0);
+
+    assert!(visible_at_2x(250.0));
+    assert!(!visible_at_2x(350.0));
+}
+
+#[test]
+fn all_texts_of_a_frame_are_prepared_in_one_call() {
+    let areas: Vec<usize> = (0..50).collect();
+    let mut calls = Vec::new();
+
+    let prepared = prepare_text_batch(&areas, |batch, _| {
+        calls.push(batch.len());
+        Ok(())
+    });
+
+    assert_eq!(prepared, Ok(1));
+    assert_eq!(calls, vec![50]);
+    assert_eq!(
+        prepare_text_batch::<usize>(&[], |_, _| unreachable!()),
+        Ok(0)
+    );
+}
+
+#[test]
+fn full_atlas_retries_the_batch_once() {
+    let areas = [0, 1, 2];
+    let mut calls = Vec::new();
+
+    let prepared = prepare_text_batch(&areas, |_, atlas_full| {
+        calls.push(atlas_full);
+        if atlas_full {
+            Ok(())
+        } else {
+            Err(PrepareError::AtlasFull)
+        }
+    });
+
+    assert_eq!(prepared, Ok(2));
+    assert_eq!(calls, vec![false, true]);
+}
+
+#[test]
+fn stats_count_the_shapes_and_texts_a_scene_draws() {
+    let mut scene = Scene::new();
+    let brush = Brush::solid(Color::BLACK);
+    scene.push_shape(rect(0.0, 0.0, 50.0, 50.0), brush.clone(), None, None);
+    scene.push_shape(rect(60.0, 0.0, 50.0, 50.0), brush.clone(), None, None);
+    scene.push_shape(rect(0.0, 5_000.0, 50.0, 50.0), brush, None, None);
+    let text = |scene: &mut Scene, node_id, y, text: &str| {
+        scene.push_text(
+            node_id,
+            rect(0.0, y, 200.0, 20.0),
+            TextParams {
+                text: Rc::from(text),
+                color: Color::BLACK,
+                scale: 1.0,
+                style: TextStyle::default(),
+            },
+            None,
+        );
+    };
+    text(&mut scene, 1, 100.0, "Shown");
+    text(&mut scene, 2, 120.0, "");
+    text(&mut scene, 3, 5_000.0, "Below the viewport");
+
+    let mut db = fontdb::Database::new();
+    db.load_font_data(ROBOTO_REGULAR.to_vec());
+    let mut font_system = FontSystem::new_with_locale_and_db("en-US".to_string(), db);
+    let mut text_cache = TextCache::new();
+    let drawn: Vec<usize> = visible_shapes(&scene.shapes, 800.0, 600.0, 1.0).collect();
+    let mut texts = Vec::new();
+    visible_texts(
+        &scene.texts,
+        &mut font_system,
+        &mut text_cache,
+        800.0,
+        600.0,
+        1.0,
+        &mut texts,
+    );
+
+    let mut stats = RenderStats {
+        shapes: drawn.len(),
+        ..RenderStats::default()
+    };
+    for range in draw_calls(scene.shapes.len(), &drawn, &scene.blur_layers) {
+        stats.record_shape_batch(range.len());
+    }
+    stats.record_text(texts.len(), 1);
+
+    assert_eq!(
+        texts.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+        vec![0]
+    );
+    assert_eq!(
+        (stats.shapes, stats.text_areas, stats.shape_batches),
+        (2, 1, 1)
+    );
+    assert_eq!((stats.draw_calls, stats.triangles), (2, 4));
+}
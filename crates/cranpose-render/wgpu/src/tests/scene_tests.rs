@@ -0,0 +1,93 @@
+use super::*;
+use std::cell::Cell;
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> Rect {
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+fn click_counter(clicks: &Rc<Cell<u32>>) -> Vec<ClickAction> {
+    let clicks = clicks.clone();
+    vec![ClickAction::Simple(Rc::new(RefCell::new(move || {
+        clicks.set(clicks.get() + 1)
+    })))]
+}
+
+/// Dispatches a press the way the app shell does: topmost region first,
+/// stopping once a region consumes the event.
+fn press(scene: &Scene, x: f32, y: f32) {
+    let event = PointerEvent::new(PointerEventKind::Down, Point { x, y }, Point { x, y });
+    for hit in scene.hit_test(x, y) {
+        hit.dispatch(event.clone());
+        if event.is_consumed() {
+            break;
+        }
+    }
+}
+
+/// Node of the region the app shell dispatches a press to first.
+fn topmost(scene: &Scene, x: f32, y: f32) -> Option<NodeId> {
+    scene.hit_test(x, y).first().map(|hit| hit.node_id)
+}
+
+#[test]
+fn overlapping_click_goes_to_region_drawn_on_top() {
+    let mut scene = Scene::new();
+    let below = Rc::new(Cell::new(0));
+    let above = Rc::new(Cell::new(0));
+    scene.push_hit(
+        1,
+        rect(0.0, 0.0, 100.0, 100.0),
+        None,
+        click_counter(&below),
+        Vec::new(),
+        None,
+    );
+    scene.push_hit(
+        2,
+        rect(50.0, 50.0, 100.0, 100.0),
+        None,
+        click_counter(&above),
+        Vec::new(),
+        None,
+    );
+
+    assert_eq!(topmost(&scene, 75.0, 75.0), Some(2));
+    press(&scene, 75.0, 75.0);
+    assert_eq!((below.get(), above.get()), (0, 1));
+
+    press(&scene, 25.0, 25.0);
+    assert_eq!((below.get(), above.get()), (1, 1));
+}
+
+#[test]
+fn point_outside_clip_does_not_hit_clipped_child() {
+    let mut scene = Scene::new();
+    let parent = Rc::new(Cell::new(0));
+    let child = Rc::new(Cell::new(0));
+    scene.push_hit(
+        1,
+        rect(0.0, 0.0, 200.0, 200.0),
+        None,
+        click_counter(&parent),
+        Vec::new(),
+        None,
+    );
+    // The child overflows its parent's 50x50 clip
+    scene.push_hit(
+        2,
+        rect(0.0, 0.0, 100.0, 100.0),
+        None,
+        click_counter(&child),
+        Vec::new(),
+        Some(rect(0.0, 0.0, 50.0, 50.0)),
+    );
+
+    assert_eq!(topmost(&scene, 25.0, 25.0), Some(2));
+    assert_eq!(topmost(&scene, 75.0, 75.0), Some(1));
+    assert_eq!(topmost(&scene, 250.0, 250.0), None);
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn blurred_layers_add_their_passes_to_the_draw_calls() {
+    let mut stats = RenderStats::default();
+    stats.record_shape_batch(400);
+    stats.record_shape_batch(200);
+    stats.record_blur();
+    stats.record_shape_batch(400);
+    stats.record_text(3, 2);
+
+    assert_eq!(stats.shape_batches, 3);
+    assert_eq!(stats.blurred_layers, 1);
+    assert_eq!(stats.draw_calls, 6);
+    assert_eq!(stats.triangles, 2_002);
+    assert_eq!((stats.text_areas, stats.text_prepares), (3, 2));
+}
+
+#[test]
+fn timestamps_convert_with_the_tick_period() {
+    assert_eq!(
+        timestamp_duration(1_000, 3_000, 2.5),
+        Duration::from_micros(5)
+    );
+    assert_eq!(timestamp_duration(3_000, 1_000, 1.0), Duration::ZERO);
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::{WgpuTextMeasurer, TEXT_LAYOUT_OPTIONS};
+use cranpose_ui::TextMeasurer;
+use cranpose_ui_graphics::TextStyle;
+use glyphon::fontdb;
+
+static ROBOTO_REGULAR: &[u8] = include_bytes!("../../../../../assets/Roboto-Regular.ttf");
+
+/// Measurer shaping with Roboto only, and the text cache it fills.
+fn measurer() -> (WgpuTextMeasurer, SharedTextCache) {
+    let mut db = fontdb::Database::new();
+    db.load_font_data(ROBOTO_REGULAR.to_vec());
+    let font_system = FontSystem::new_with_locale_and_db("en-US".to_string(), db);
+    let text_cache = TextCache::shared();
+    let measurer = WgpuTextMeasurer::new(Arc::new(Mutex::new(font_system)), text_cache.clone());
+    (measurer, text_cache)
+}
+
+fn stats(text_cache: &SharedTextCache) -> TextCacheStats {
+    text_cache.lock().unwrap().stats()
+}
+
+#[test]
+fn repeated_measurements_hit_and_new_strings_miss() {
+    let (measurer, text_cache) = measurer();
+    let style = TextStyle::default();
+
+    measurer.measure("Hello", &style);
+    assert_eq!((stats(&text_cache).hits, stats(&text_cache).misses), (0, 1));
+
+    measurer.measure("Hello", &style);
+    measurer.measure("Hello", &style);
+    assert_eq!((stats(&text_cache).hits, stats(&text_cache).misses), (2, 1));
+
+    measurer.measure("World", &style);
+    let stats = stats(&text_cache);
+    assert_eq!((stats.hits, stats.misses, stats.len), (2, 2, 2));
+}
+
+#[test]
+fn capacity_bounds_cached_buffers_and_counts_evictions() {
+    let (measurer, text_cache) = measurer();
+    text_cache.lock().unwrap().set_capacity(2);
+
+    for text in ["one", "two", "three"] {
+        measurer.measure(text, &TextStyle::default());
+    }
+
+    let stats = stats(&text_cache);
+    assert_eq!(stats.evictions, 2);
+    assert_eq!(stats.len, 1);
+}
+
+#[test]
+fn wrapped_measurement_shapes_at_the_wrap_width() {
+    let (measurer, _) = measurer();
+    let style = TextStyle::default();
+    let text = "the quick brown fox jumps over the lazy dog";
+    let one_line = measurer.measure(text, &style);
+
+    let wrapped = measurer.measure_wrapped(text, &style, one_line.width / 2.0, None);
+    assert!(wrapped.line_count >= 2, "{wrapped:?}");
+    assert!(wrapped.width <= one_line.width / 2.0, "{wrapped:?}");
+    assert_eq!(
+        wrapped.height,
+        wrapped.line_count as f32 * one_line.line_height
+    );
+
+    // Wrapping again at the measured width keeps the same lines
+    let rewrapped = measurer.measure_wrapped(
+        text,
+        &style,
+        TEXT_LAYOUT_OPTIONS.wrap_width(wrapped.width),
+        None,
+    );
+    assert_eq!(rewrapped, wrapped);
+
+    let capped = measurer.measure_wrapped(text, &style, one_line.width / 2.0, Some(1));
+    assert_eq!(
+        (capped.line_count, capped.height),
+        (1, one_line.line_height)
+    );
+    assert_eq!(
+        measurer.measure_wrapped(text, &style, f32::INFINITY, None),
+        one_line
+    );
+}
@@ -0,0 +1,133 @@
+//! Cache of shaped text buffers shared by measurement and rendering.
+//!
+//! Shaping is the expensive part of text handling, so buffers are kept per
+//! string (for measurement) and per node (for rendering) and only reshaped
+//! when their text, size or spacing changes. The cache is bounded; once it
+//! holds more than its capacity, it drops down to half of it.
+
+use crate::{SharedTextBuffer, TextCacheKey, TextSpacing};
+use glyphon::FontSystem;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Default number of cached text buffers before trimming occurs.
+pub(crate) const DEFAULT_TEXT_CACHE_CAPACITY: usize = 256;
+
+/// Counters describing how well the text cache avoids reshaping.
+///
+/// Returned by [`WgpuRenderer::text_cache_stats`](crate::WgpuRenderer::text_cache_stats).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TextCacheStats {
+    /// Lookups served by already shaped text.
+    pub hits: u64,
+    /// Lookups that had to shape text.
+    pub misses: u64,
+    /// Buffers dropped to keep the cache within its capacity.
+    pub evictions: u64,
+    /// Buffers currently cached.
+    pub len: usize,
+}
+
+/// Shaped text buffers with hit, miss and eviction counters.
+pub(crate) struct TextCache {
+    entries: HashMap<TextCacheKey, SharedTextBuffer>,
+    capacity: usize,
+    stats: TextCacheStats,
+}
+
+/// Shared cache for text buffers used by both measurement and rendering.
+pub(crate) type SharedTextCache = Arc<Mutex<TextCache>>;
+
+impl TextCache {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: DEFAULT_TEXT_CACHE_CAPACITY,
+            stats: TextCacheStats::default(),
+        }
+    }
+
+    pub(crate) fn shared() -> SharedTextCache {
+        Arc::new(Mutex::new(Self::new()))
+    }
+
+    /// Returns the buffer for `key` holding `text` wrapped at `wrap_width`,
+    /// shaping it if needed.
+    ///
+    /// Counts a hit when the cached buffer already held the shaped text and a
+    /// miss when it had to be created or reshaped.
+    pub(crate) fn shape(
+        &mut self,
+        font_system: &mut FontSystem,
+        key: TextCacheKey,
+        text: &str,
+        font_size: f32,
+        spacing: TextSpacing,
+        wrap_width: Option<f32>,
+    ) -> &mut SharedTextBuffer {
+        let buffer = self
+            .entries
+            .entry(key)
+            .or_insert_with(|| SharedTextBuffer::new(font_system, font_size, spacing));
+        if buffer.ensure(font_system, text, font_size, spacing, wrap_width) {
+            self.stats.misses += 1;
+        } else {
+            self.stats.hits += 1;
+        }
+        buffer
+    }
+
+    /// Returns the buffer for `key` without counting a lookup.
+    pub(crate) fn get(&self, key: &TextCacheKey) -> Option<&SharedTextBuffer> {
+        self.entries.get(key)
+    }
+
+    /// Counts a lookup answered from measurements made with cached buffers.
+    pub(crate) fn record_hit(&mut self) {
+        self.stats.hits += 1;
+    }
+
+    /// Drops every buffer, e.g. after the fonts changed. Counters are kept.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Sets how many buffers are kept before trimming, trimming right away.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.trim();
+    }
+
+    /// Removes half of the buffers once the cache exceeds its capacity.
+    pub(crate) fn trim(&mut self) {
+        if self.entries.len() <= self.capacity {
+            return;
+        }
+        let to_remove = self.entries.len() - self.capacity / 2;
+
+        // Remove arbitrary entries; live text is reshaped on its next lookup
+        let keys_to_remove: Vec<TextCacheKey> =
+            self.entries.keys().take(to_remove).cloned().collect();
+        for key in keys_to_remove {
+            self.entries.remove(&key);
+        }
+        self.stats.evictions += to_remove as u64;
+
+        log::debug!(
+            "Trimmed text cache from {} to {} entries",
+            self.entries.len() + to_remove,
+            self.entries.len()
+        );
+    }
+
+    pub(crate) fn stats(&self) -> TextCacheStats {
+        TextCacheStats {
+            len: self.entries.len(),
+            ..self.stats
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/text_cache_tests.rs"]
+mod tests;
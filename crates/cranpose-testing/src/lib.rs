@@ -2,6 +2,7 @@
 
 #![allow(non_snake_case)]
 
+pub mod matchers;
 pub mod robot;
 pub mod robot_assertions;
 pub mod testing;
@@ -10,6 +11,7 @@ pub mod testing;
 pub mod robot_helpers;
 
 // Re-export testing utilities
+pub use matchers::*;
 pub use robot::*;
 pub use robot_assertions::{Bounds, SemanticElementLike};
 pub use testing::*;
@@ -18,6 +20,7 @@ pub use testing::*;
 pub use robot_helpers::*;
 
 pub mod prelude {
+    pub use crate::matchers::*;
     pub use crate::robot::*;
     pub use crate::robot_assertions;
     pub use crate::robot_assertions::{Bounds, SemanticElementLike};
@@ -0,0 +1,47 @@
+//! Semantics matchers for finding nodes in tests
+//!
+//! Matchers are predicates over [`AccessibilityNode`]s, the flattened semantics
+//! produced by [`SemanticsTree::to_accessibility_nodes`](cranpose_ui::SemanticsTree::to_accessibility_nodes).
+//! Get the nodes from [`ComposeTestRule::accessibility_nodes`](crate::ComposeTestRule::accessibility_nodes)
+//! or [`RobotTestRule::accessibility_nodes`](crate::RobotTestRule::accessibility_nodes).
+//!
+//! # Example
+//! ```ignore
+//! let nodes = rule.accessibility_nodes(Size::new(400.0, 400.0))?;
+//! let close = find_node(&nodes, has_content_description("Close")).expect("close button");
+//! assert!(close.clickable);
+//! ```
+
+use cranpose_ui::{AccessibilityNode, SemanticsRole};
+
+/// Matches nodes whose content description equals `description`.
+pub fn has_content_description(description: &str) -> impl Fn(&AccessibilityNode) -> bool + '_ {
+    move |node| node.content_description.as_deref() == Some(description)
+}
+
+/// Matches nodes whose announced label (text or content description) contains `text`.
+pub fn has_label_containing(text: &str) -> impl Fn(&AccessibilityNode) -> bool + '_ {
+    move |node| {
+        node.label
+            .as_deref()
+            .is_some_and(|label| label.contains(text))
+    }
+}
+
+/// Matches nodes with the given role.
+pub fn has_role(role: SemanticsRole) -> impl Fn(&AccessibilityNode) -> bool {
+    move |node| node.role == role
+}
+
+/// Matches nodes whose state description equals `state`.
+pub fn has_state_description(state: &str) -> impl Fn(&AccessibilityNode) -> bool + '_ {
+    move |node| node.state_description.as_deref() == Some(state)
+}
+
+/// Returns the first node, in depth-first order, accepted by `matcher`.
+pub fn find_node(
+    nodes: &[AccessibilityNode],
+    matcher: impl Fn(&AccessibilityNode) -> bool,
+) -> Option<&AccessibilityNode> {
+    nodes.iter().find(|node| matcher(node))
+}
@@ -26,8 +26,8 @@ use cranpose_app_shell::AppShell;
 use cranpose_core::{location_key, Key};
 use cranpose_foundation::PointerEvent;
 use cranpose_render_common::{HitTestTarget, RenderScene, Renderer};
-use cranpose_ui::LayoutTree;
-use cranpose_ui_graphics::{Point, Rect, Size};
+use cranpose_ui::{AccessibilityNode, HeadlessRenderer, LayoutTree, RecordedRenderScene, RenderOp};
+use cranpose_ui_graphics::{DrawPrimitive, Point, Rect, Size};
 
 /// Main robot testing rule that provides programmatic control over a real app.
 ///
@@ -201,6 +201,16 @@ where
         }
     }
 
+    /// Get the semantics of the current screen, flattened for lookups with the
+    /// [`matchers`](crate::matchers).
+    pub fn accessibility_nodes(&mut self) -> Vec<AccessibilityNode> {
+        self.wait_for_idle();
+        self.shell
+            .semantics_tree()
+            .map(|tree| tree.to_accessibility_nodes())
+            .unwrap_or_default()
+    }
+
     /// Get all rectangles (bounds) of UI elements on screen.
     ///
     /// Returns a list of (bounds, optional_text) tuples.
@@ -415,13 +425,40 @@ fn extract_rects_from_layout(layout: &LayoutTree) -> Vec<(Rect, Option<String>)>
     results
 }
 
-/// A simple test renderer for robot tests.
+/// A headless renderer for robot tests.
 ///
-/// This renderer doesn't actually render anything, but provides the
-/// Renderer trait implementation needed for testing.
+/// Nothing reaches the screen; each rebuild records the shapes and texts the
+/// scene would draw so tests can assert on them.
 #[derive(Default)]
 pub struct TestRenderer {
     scene: TestScene,
+    recorded: RecordedRenderScene,
+}
+
+impl TestRenderer {
+    /// Shapes drawn by the last rebuild, in draw order, in window coordinates.
+    pub fn recorded_shapes(&self) -> Vec<&DrawPrimitive> {
+        self.recorded
+            .operations()
+            .iter()
+            .filter_map(|op| match op {
+                RenderOp::Primitive { primitive, .. } => Some(primitive),
+                RenderOp::Text { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Texts drawn by the last rebuild, in draw order, with their bounds.
+    pub fn recorded_texts(&self) -> Vec<(Rect, &str)> {
+        self.recorded
+            .operations()
+            .iter()
+            .filter_map(|op| match op {
+                RenderOp::Text { rect, value, .. } => Some((*rect, value.as_str())),
+                RenderOp::Primitive { .. } => None,
+            })
+            .collect()
+    }
 }
 
 impl Renderer for TestRenderer {
@@ -438,18 +475,20 @@ impl Renderer for TestRenderer {
 
     fn rebuild_scene(
         &mut self,
-        _layout_tree: &LayoutTree,
+        layout_tree: &LayoutTree,
         _viewport: Size,
     ) -> Result<(), Self::Error> {
+        self.recorded = HeadlessRenderer::new().render(layout_tree);
         Ok(())
     }
 
     fn rebuild_scene_from_applier(
         &mut self,
-        _applier: &mut cranpose_core::MemoryApplier,
-        _root: cranpose_core::NodeId,
+        applier: &mut cranpose_core::MemoryApplier,
+        root: cranpose_core::NodeId,
         _viewport: Size,
     ) -> Result<(), Self::Error> {
+        self.recorded = HeadlessRenderer::new().render_from_applier(applier, root);
         Ok(())
     }
 }
@@ -1,7 +1,10 @@
+use crate::robot_assertions::assert_approx_eq;
 use cranpose_core::{
     location_key, ApplierGuard, Composition, Key, MemoryApplier, NodeError, NodeId, RuntimeHandle,
 };
-use cranpose_ui::request_render_invalidation;
+use cranpose_ui::{
+    measure_layout, request_render_invalidation, AccessibilityNode, LayoutBox, Rect, Size,
+};
 
 #[cfg(test)]
 use cranpose_core::{
@@ -130,6 +133,81 @@ impl ComposeTestRule {
         &mut self.composition
     }
 
+    /// Measures the content within `max_size` and returns its semantics,
+    /// flattened for lookups with the [`matchers`](crate::matchers).
+    pub fn accessibility_nodes(
+        &mut self,
+        max_size: Size,
+    ) -> Result<Vec<AccessibilityNode>, NodeError> {
+        let Some(root) = self.composition.root() else {
+            return Ok(Vec::new());
+        };
+        let mut applier = self.composition.applier_mut();
+        let measurements = measure_layout(&mut applier, root, max_size)?;
+        Ok(measurements.semantics_tree().to_accessibility_nodes())
+    }
+
+    /// Measures the content within `max_size` and returns the bounds, relative
+    /// to the root, of the first node accepted by `matcher`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no node matches.
+    pub fn bounds_of(
+        &mut self,
+        max_size: Size,
+        matcher: impl Fn(&AccessibilityNode) -> bool,
+    ) -> Result<Rect, NodeError> {
+        let root = self.composition.root().expect("no content to measure");
+        let mut applier = self.composition.applier_mut();
+        let measurements = measure_layout(&mut applier, root, max_size)?;
+        let node_id = measurements
+            .semantics_tree()
+            .to_accessibility_nodes()
+            .into_iter()
+            .find(|node| matcher(node))
+            .expect("no node matches")
+            .node_id;
+        let layout_tree = measurements.into_layout_tree();
+        let layout_box = find_layout_box(layout_tree.root(), node_id)
+            .expect("matched node has no layout bounds");
+        Ok(layout_box.rect)
+    }
+
+    /// Measured size of the first node accepted by `matcher`; see [`bounds_of`](Self::bounds_of).
+    pub fn measured_size_of(
+        &mut self,
+        max_size: Size,
+        matcher: impl Fn(&AccessibilityNode) -> bool,
+    ) -> Result<Size, NodeError> {
+        let bounds = self.bounds_of(max_size, matcher)?;
+        Ok(Size::new(bounds.width, bounds.height))
+    }
+
+    /// Asserts the first node accepted by `matcher` is `width` wide, within half a dp.
+    pub fn assert_width_is_equal_to(
+        &mut self,
+        max_size: Size,
+        matcher: impl Fn(&AccessibilityNode) -> bool,
+        width: f32,
+    ) -> Result<(), NodeError> {
+        let size = self.measured_size_of(max_size, matcher)?;
+        assert_approx_eq(size.width, width, 0.5, "node width");
+        Ok(())
+    }
+
+    /// Asserts the first node accepted by `matcher` is `height` tall, within half a dp.
+    pub fn assert_height_is_equal_to(
+        &mut self,
+        max_size: Size,
+        matcher: impl Fn(&AccessibilityNode) -> bool,
+        height: f32,
+    ) -> Result<(), NodeError> {
+        let size = self.measured_size_of(max_size, matcher)?;
+        assert_approx_eq(size.height, height, 0.5, "node height");
+        Ok(())
+    }
+
     fn render(&mut self) -> Result<(), NodeError> {
         if let Some(content) = self.content.as_mut() {
             self.composition.render(self.root_key, &mut **content)?;
@@ -147,6 +225,16 @@ impl Default for ComposeTestRule {
     }
 }
 
+fn find_layout_box(layout_box: &LayoutBox, node_id: NodeId) -> Option<&LayoutBox> {
+    if layout_box.node_id == node_id {
+        return Some(layout_box);
+    }
+    layout_box
+        .children
+        .iter()
+        .find_map(|child| find_layout_box(child, node_id))
+}
+
 /// Convenience helper for tests that only need temporary access to a
 /// `ComposeTestRule`.
 pub fn run_test_composition<R>(f: impl FnOnce(&mut ComposeTestRule) -> R) -> R {
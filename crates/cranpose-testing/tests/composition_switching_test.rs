@@ -107,10 +107,10 @@ fn combined_switching_app(
                         },
                     );
 
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 8.0,
                         height: 0.0,
-                    });
+                    }));
 
                     Button(
                         Modifier::empty().padding(10.0),
@@ -127,10 +127,10 @@ fn combined_switching_app(
                 },
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 12.0,
-            });
+            }));
 
             // Conditionally show one view or the other
             if show_counter_inner.get() {
@@ -848,17 +848,17 @@ fn test_composition_local_content(local_holder: cranpose_core::CompositionLocal<
         Modifier::empty().padding(8.0),
     );
 
-    Spacer(Size {
+    Spacer(Modifier::empty().size(Size {
         width: 0.0,
         height: 8.0,
-    });
+    }));
 
     test_composition_local_content_inner(local_holder.clone());
 
-    Spacer(Size {
+    Spacer(Modifier::empty().size(Size {
         width: 0.0,
         height: 8.0,
-    });
+    }));
 
     Text("NOT reading local", Modifier::empty().padding(8.0));
 }
@@ -877,20 +877,20 @@ fn test_composition_local_demo(
                 Modifier::empty().padding(8.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 12.0,
-            });
+            }));
 
             Text(
                 format!("Counter: {}", counter.get()),
                 Modifier::empty().padding(8.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 12.0,
-            });
+            }));
 
             Button(
                 Modifier::empty().padding(10.0),
@@ -904,10 +904,10 @@ fn test_composition_local_demo(
                 },
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 12.0,
-            });
+            }));
 
             let current_count = counter.get();
             CompositionLocalProvider(vec![local_holder.provides(current_count)], {
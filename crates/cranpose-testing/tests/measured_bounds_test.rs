@@ -0,0 +1,75 @@
+//! Tests that measured layout bounds can be queried through the test rule.
+
+use cranpose_macros::composable;
+use cranpose_testing::{has_content_description, ComposeTestRule};
+use cranpose_ui::*;
+
+#[composable]
+fn padded_card() {
+    Column(
+        Modifier::empty().padding(10.0),
+        ColumnSpec::default(),
+        || {
+            Box(
+                Modifier::empty()
+                    .size(Size::new(30.0, 20.0))
+                    .semantics(|config| {
+                        config.content_description = Some("Header".into());
+                    }),
+                BoxSpec::default(),
+                || {},
+            );
+            Box(
+                Modifier::empty()
+                    .size(Size::new(100.0, 50.0))
+                    .semantics(|config| {
+                        config.content_description = Some("Card".into());
+                    }),
+                BoxSpec::default(),
+                || {},
+            );
+        },
+    );
+}
+
+#[test]
+fn sized_box_reports_measured_bounds() {
+    let mut rule = ComposeTestRule::new();
+    rule.set_content(padded_card).expect("initial render");
+    let viewport = Size::new(400.0, 400.0);
+
+    let bounds = rule
+        .bounds_of(viewport, has_content_description("Card"))
+        .expect("layout");
+    assert_eq!(
+        bounds,
+        Rect {
+            x: 10.0,
+            y: 30.0,
+            width: 100.0,
+            height: 50.0,
+        }
+    );
+    assert_eq!(
+        rule.measured_size_of(viewport, has_content_description("Card"))
+            .expect("layout"),
+        Size::new(100.0, 50.0)
+    );
+    rule.assert_width_is_equal_to(viewport, has_content_description("Card"), 100.0)
+        .expect("layout");
+    rule.assert_height_is_equal_to(viewport, has_content_description("Card"), 50.0)
+        .expect("layout");
+}
+
+#[test]
+#[should_panic(expected = "node width")]
+fn width_assertion_fails_on_mismatch() {
+    let mut rule = ComposeTestRule::new();
+    rule.set_content(padded_card).expect("initial render");
+
+    let _ = rule.assert_width_is_equal_to(
+        Size::new(400.0, 400.0),
+        has_content_description("Header"),
+        100.0,
+    );
+}
@@ -0,0 +1,54 @@
+//! Tests that the headless test renderer records what a frame would draw.
+
+use cranpose_macros::composable;
+use cranpose_testing::create_headless_robot_test;
+use cranpose_ui::*;
+use cranpose_ui_graphics::DrawPrimitive;
+
+const RED: Color = Color(1.0, 0.0, 0.0, 1.0);
+
+#[composable]
+fn red_card() {
+    Column(
+        Modifier::empty().padding(12.0),
+        ColumnSpec::default(),
+        || {
+            Box(
+                Modifier::empty()
+                    .size(Size::new(60.0, 30.0))
+                    .rounded_corners(6.0)
+                    .background(RED),
+                BoxSpec::default(),
+                || {},
+            );
+            Text("Caption", Modifier::empty());
+        },
+    );
+}
+
+#[test]
+fn background_box_is_recorded_as_red_rounded_rect() {
+    let mut robot = create_headless_robot_test(200, 200, red_card);
+    robot.wait_for_idle();
+
+    let renderer = robot.shell_mut().renderer();
+    assert_eq!(
+        renderer.recorded_shapes(),
+        vec![&DrawPrimitive::RoundRect {
+            rect: Rect {
+                x: 12.0,
+                y: 12.0,
+                width: 60.0,
+                height: 30.0,
+            },
+            brush: Brush::solid(RED),
+            radii: CornerRadii::uniform(6.0),
+        }]
+    );
+
+    let texts = renderer.recorded_texts();
+    assert_eq!(texts.len(), 1);
+    let (rect, text) = texts[0];
+    assert_eq!(text, "Caption");
+    assert_eq!((rect.x, rect.y), (12.0, 42.0));
+}
@@ -0,0 +1,114 @@
+//! Tests that accessibility semantics can be found with the semantics matchers.
+
+use cranpose_macros::composable;
+use cranpose_testing::{
+    find_node, has_content_description, has_label_containing, has_role, has_state_description,
+    ComposeTestRule,
+};
+use cranpose_ui::*;
+
+#[composable]
+fn icon_toolbar() {
+    Row(Modifier::empty(), RowSpec::default(), || {
+        // An icon button has no text, only a content description
+        Box(
+            Modifier::empty()
+                .size(Size::new(24.0, 24.0))
+                .clickable(|_| {})
+                .semantics(|config| {
+                    config.content_description = Some("Close".into());
+                    config.role = Some(Role::Button);
+                    config.is_clickable = true;
+                }),
+            BoxSpec::default(),
+            || {},
+        );
+        Box(
+            Modifier::empty()
+                .size(Size::new(24.0, 24.0))
+                .semantics(|config| {
+                    config.content_description = Some("Wi-Fi".into());
+                    config.role = Some(Role::Switch);
+                    config.state_description = Some("On".into());
+                }),
+            BoxSpec::default(),
+            || {},
+        );
+    });
+}
+
+#[test]
+fn icon_button_is_found_by_content_description() {
+    let mut rule = ComposeTestRule::new();
+    rule.set_content(icon_toolbar).expect("initial render");
+
+    let nodes = rule
+        .accessibility_nodes(Size::new(200.0, 100.0))
+        .expect("semantics");
+
+    let close = find_node(&nodes, has_content_description("Close")).expect("close button");
+    assert_eq!(close.role, SemanticsRole::Button);
+    assert_eq!(close.label.as_deref(), Some("Close"));
+    assert!(close.clickable);
+    let parent = close.parent.expect("button has a parent");
+    let row = nodes
+        .iter()
+        .find(|node| node.node_id == parent)
+        .expect("row");
+    assert!(row.children.contains(&close.node_id));
+
+    assert!(find_node(&nodes, has_content_description("Open")).is_none());
+}
+
+#[test]
+fn role_and_state_are_exported() {
+    let mut rule = ComposeTestRule::new();
+    rule.set_content(icon_toolbar).expect("initial render");
+
+    let nodes = rule
+        .accessibility_nodes(Size::new(200.0, 100.0))
+        .expect("semantics");
+
+    let switch = find_node(&nodes, has_role(SemanticsRole::Switch)).expect("switch");
+    assert_eq!(switch.content_description.as_deref(), Some("Wi-Fi"));
+    assert!(has_state_description("On")(switch));
+    assert!(!switch.clickable);
+}
+
+#[composable]
+fn submit_form() {
+    Column(Modifier::empty(), ColumnSpec::default(), || {
+        Text("Name".to_string(), Modifier::empty());
+        Button(
+            Modifier::empty(),
+            || {},
+            || {
+                Text("Submit".to_string(), Modifier::empty());
+            },
+        );
+    });
+}
+
+#[test]
+fn button_merges_its_label_into_one_node() {
+    let mut rule = ComposeTestRule::new();
+    rule.set_content(submit_form).expect("initial render");
+
+    let nodes = rule
+        .accessibility_nodes(Size::new(200.0, 200.0))
+        .expect("semantics");
+
+    let button = find_node(&nodes, has_label_containing("Submit")).expect("button");
+    assert_eq!(button.role, SemanticsRole::Button);
+    assert_eq!(button.label.as_deref(), Some("Submit"));
+    assert!(button.clickable);
+    assert!(button.children.is_empty());
+
+    // The label text is part of the button, not a node of its own
+    let submit_nodes = nodes
+        .iter()
+        .filter(|node| has_label_containing("Submit")(node))
+        .count();
+    assert_eq!(submit_nodes, 1);
+    assert!(find_node(&nodes, has_label_containing("Name")).is_some());
+}
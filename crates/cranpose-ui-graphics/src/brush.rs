@@ -3,14 +3,120 @@
 use crate::color::Color;
 use crate::geometry::Point;
 
+/// Color space in which a gradient blends between its stops.
+///
+/// Matches Kotlin: the `ColorSpace` of gradient colors in `Brush.linearGradient`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GradientColorSpace {
+    /// Blends the sRGB-encoded channels directly; midpoints come out dark and muddy.
+    Srgb,
+    /// Blends linear light, so a blend is as bright as the light it mixes.
+    #[default]
+    Linear,
+    /// Blends in Oklab, keeping perceived lightness and hue even across the gradient.
+    Oklab,
+}
+
+impl GradientColorSpace {
+    /// Color at `t` in `0.0..=1.0` along evenly spaced `colors`.
+    pub fn interpolate(self, colors: &[Color], t: f32) -> Color {
+        match colors {
+            [] => Color(0.0, 0.0, 0.0, 0.0),
+            [only] => *only,
+            _ => {
+                let scaled = t.clamp(0.0, 1.0) * (colors.len() - 1) as f32;
+                let index = (scaled.floor() as usize).min(colors.len() - 2);
+                self.mix(colors[index], colors[index + 1], scaled - index as f32)
+            }
+        }
+    }
+
+    /// Blends `start` into `end` by `t`; alpha is always blended linearly.
+    pub fn mix(self, start: Color, end: Color, t: f32) -> Color {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        let alpha = lerp(start.3, end.3);
+        let [r, g, b] = match self {
+            Self::Srgb => [
+                lerp(start.0, end.0),
+                lerp(start.1, end.1),
+                lerp(start.2, end.2),
+            ],
+            Self::Linear => {
+                let (a, b) = (srgb_to_linear(start), srgb_to_linear(end));
+                linear_to_srgb([lerp(a[0], b[0]), lerp(a[1], b[1]), lerp(a[2], b[2])])
+            }
+            Self::Oklab => {
+                let a = linear_to_oklab(srgb_to_linear(start));
+                let b = linear_to_oklab(srgb_to_linear(end));
+                linear_to_srgb(oklab_to_linear([
+                    lerp(a[0], b[0]),
+                    lerp(a[1], b[1]),
+                    lerp(a[2], b[2]),
+                ]))
+            }
+        };
+        Color(r, g, b, alpha)
+    }
+}
+
+fn srgb_to_linear(color: Color) -> [f32; 3] {
+    [color.0, color.1, color.2].map(|c| {
+        let c = c.clamp(0.0, 1.0);
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    })
+}
+
+fn linear_to_srgb(rgb: [f32; 3]) -> [f32; 3] {
+    rgb.map(|c| {
+        let c = c.clamp(0.0, 1.0);
+        if c <= 0.003_130_8 {
+            c * 12.92
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
+    })
+}
+
+// Oklab matrices from https://bottosson.github.io/posts/oklab/
+fn linear_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+fn oklab_to_linear([lightness, a, b]: [f32; 3]) -> [f32; 3] {
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Brush {
     Solid(Color),
-    LinearGradient(Vec<Color>),
+    /// Top-to-bottom gradient through evenly spaced colors.
+    LinearGradient {
+        colors: Vec<Color>,
+        color_space: GradientColorSpace,
+    },
     RadialGradient {
         colors: Vec<Color>,
         center: Point,
         radius: f32,
+        color_space: GradientColorSpace,
     },
 }
 
@@ -19,15 +125,136 @@ impl Brush {
         Brush::Solid(color)
     }
 
+    /// Top-to-bottom gradient blended in [`GradientColorSpace::Linear`].
     pub fn linear_gradient(colors: Vec<Color>) -> Self {
-        Brush::LinearGradient(colors)
+        Brush::LinearGradient {
+            colors,
+            color_space: GradientColorSpace::default(),
+        }
     }
 
+    /// Radial gradient blended in [`GradientColorSpace::Linear`].
     pub fn radial_gradient(colors: Vec<Color>, center: Point, radius: f32) -> Self {
         Brush::RadialGradient {
             colors,
             center,
             radius,
+            color_space: GradientColorSpace::default(),
         }
     }
+
+    /// Returns the brush with its gradient blended in `space`; solid brushes are unchanged.
+    pub fn with_color_space(mut self, space: GradientColorSpace) -> Self {
+        match &mut self {
+            Brush::Solid(_) => {}
+            Brush::LinearGradient { color_space, .. }
+            | Brush::RadialGradient { color_space, .. } => *color_space = space,
+        }
+        self
+    }
+
+    /// Returns the brush with every color's alpha multiplied by `alpha`.
+    pub fn multiply_alpha(self, alpha: f32) -> Self {
+        let fade = |color: Color| color.with_alpha((color.a() * alpha).clamp(0.0, 1.0));
+        match self {
+            Brush::Solid(color) => Brush::Solid(fade(color)),
+            Brush::LinearGradient {
+                colors,
+                color_space,
+            } => Brush::LinearGradient {
+                colors: colors.into_iter().map(fade).collect(),
+                color_space,
+            },
+            Brush::RadialGradient {
+                colors,
+                center,
+                radius,
+                color_space,
+            } => Brush::RadialGradient {
+                colors: colors.into_iter().map(fade).collect(),
+                center,
+                radius,
+                color_space,
+            },
+        }
+    }
+}
+
+impl From<Color> for Brush {
+    fn from(color: Color) -> Self {
+        Brush::Solid(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_gray(color: Color, expected: f32) {
+        for channel in [color.r(), color.g(), color.b()] {
+            assert!(
+                (channel - expected).abs() < 0.002,
+                "{color:?} is not gray {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn gradient_midpoint_depends_on_color_space() {
+        let colors = [Color::BLACK, Color::WHITE];
+
+        // Halfway in sRGB is the encoded midpoint.
+        assert_gray(GradientColorSpace::Srgb.interpolate(&colors, 0.5), 0.5);
+        // Half the light encodes brighter than 0.5.
+        assert_gray(GradientColorSpace::Linear.interpolate(&colors, 0.5), 0.735);
+        // Oklab lightness 0.5 cubes to 0.125 linear light.
+        assert_gray(GradientColorSpace::Oklab.interpolate(&colors, 0.5), 0.389);
+    }
+
+    #[test]
+    fn gradient_hits_its_stops_in_every_color_space() {
+        let colors = [
+            Color(1.0, 0.0, 0.0, 1.0),
+            Color(0.0, 0.0, 1.0, 0.0),
+            Color(0.2, 0.8, 0.4, 1.0),
+        ];
+        for space in [
+            GradientColorSpace::Srgb,
+            GradientColorSpace::Linear,
+            GradientColorSpace::Oklab,
+        ] {
+            for (t, expected) in [(0.0, colors[0]), (0.5, colors[1]), (1.0, colors[2])] {
+                let color = space.interpolate(&colors, t);
+                let channels = [color.0, color.1, color.2, color.3];
+                let expected = [expected.0, expected.1, expected.2, expected.3];
+                for (channel, expected) in channels.into_iter().zip(expected) {
+                    assert!((channel - expected).abs() < 1e-3, "{space:?} at {t}");
+                }
+            }
+        }
+        // Alpha blends linearly whatever the color space.
+        assert_eq!(
+            GradientColorSpace::Oklab.interpolate(&colors, 0.25).a(),
+            0.5
+        );
+    }
+
+    #[test]
+    fn gradient_constructors_default_to_linear() {
+        let brush = Brush::linear_gradient(vec![Color::BLACK, Color::WHITE]);
+        assert!(matches!(
+            brush,
+            Brush::LinearGradient {
+                color_space: GradientColorSpace::Linear,
+                ..
+            }
+        ));
+        assert!(matches!(
+            brush.with_color_space(GradientColorSpace::Oklab),
+            Brush::LinearGradient {
+                color_space: GradientColorSpace::Oklab,
+                ..
+            }
+        ));
+    }
 }
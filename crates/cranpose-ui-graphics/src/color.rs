@@ -1,5 +1,7 @@
 //! Color representation and color space utilities
 
+use std::fmt;
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Color(pub f32, pub f32, pub f32, pub f32);
 
@@ -25,6 +27,35 @@ impl Color {
         Self::from_rgba_u8(r, g, b, 255)
     }
 
+    /// Opaque color from 8-bit channels, e.g. `Color::rgb8(255, 136, 0)`.
+    pub const fn rgb8(r: u8, g: u8, b: u8) -> Self {
+        Self::from_rgb_u8(r, g, b)
+    }
+
+    /// Color from 8-bit channels, e.g. `Color::rgba8(255, 136, 0, 128)`.
+    pub const fn rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::from_rgba_u8(r, g, b, a)
+    }
+
+    /// Parses `#RRGGBB` or `#RRGGBBAA` hex notation; digits are case-insensitive.
+    ///
+    /// Matches Kotlin: `Color(0xFFRRGGBB)`, with the alpha last as in CSS.
+    pub fn hex(hex: &str) -> Result<Self, ColorParseError> {
+        let digits = hex.strip_prefix('#').ok_or(ColorParseError::MissingHash)?;
+        if let Some(invalid) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidDigit(invalid));
+        }
+        // All digits are ASCII, so every byte pair is a channel
+        let channel = |index: usize| {
+            u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16).expect("checked hex digits")
+        };
+        match digits.len() {
+            6 => Ok(Self::rgb8(channel(0), channel(1), channel(2))),
+            8 => Ok(Self::rgba8(channel(0), channel(1), channel(2), channel(3))),
+            len => Err(ColorParseError::InvalidLength(len)),
+        }
+    }
+
     pub fn r(&self) -> f32 {
         self.0
     }
@@ -53,3 +84,58 @@ impl Color {
     pub const BLUE: Color = Color(0.0, 0.0, 1.0, 1.0);
     pub const TRANSPARENT: Color = Color(0.0, 0.0, 0.0, 0.0);
 }
+
+/// Why [`Color::hex`] rejected a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorParseError {
+    /// The string does not start with `#`.
+    MissingHash,
+    /// The string has this many digits instead of 6 or 8.
+    InvalidLength(usize),
+    /// The string contains a character that is not a hex digit.
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorParseError::MissingHash => write!(f, "hex color must start with '#'"),
+            ColorParseError::InvalidLength(len) => {
+                write!(f, "hex color has {len} digits; expected 6 or 8")
+            }
+            ColorParseError::InvalidDigit(c) => write!(f, "invalid hex digit {c:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_parses_to_normalized_channels() {
+        assert_eq!(
+            Color::hex("#FF8800"),
+            Ok(Color(1.0, 136.0 / 255.0, 0.0, 1.0))
+        );
+        assert_eq!(Color::hex("#ff880080"), Ok(Color::rgba8(255, 136, 0, 128)));
+        assert_eq!(Color::hex("#000000"), Ok(Color::BLACK));
+    }
+
+    #[test]
+    fn hex_rejects_malformed_strings() {
+        assert_eq!(Color::hex("FF8800"), Err(ColorParseError::MissingHash));
+        assert_eq!(Color::hex("#F80"), Err(ColorParseError::InvalidLength(3)));
+        assert_eq!(Color::hex("#"), Err(ColorParseError::InvalidLength(0)));
+        assert_eq!(
+            Color::hex("#FF880G"),
+            Err(ColorParseError::InvalidDigit('G'))
+        );
+        assert_eq!(
+            Color::hex("#FF88é0"),
+            Err(ColorParseError::InvalidDigit('é'))
+        );
+    }
+}
@@ -212,12 +212,126 @@ impl RoundedCornerShape {
     }
 }
 
+/// A stroked arc of the circle inscribed in a shape's bounds.
+///
+/// Angles are in degrees, measured clockwise from the positive x axis (3 o'clock),
+/// so -90 starts at the top. A sweep of 360 or more draws a full ring.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ArcShape {
+    pub start_angle: f32,
+    pub sweep_angle: f32,
+    pub stroke_width: f32,
+}
+
+impl ArcShape {
+    /// Creates an arc, turning a negative sweep into the equivalent positive one.
+    pub fn new(start_angle: f32, sweep_angle: f32, stroke_width: f32) -> Self {
+        let (start_angle, sweep_angle) = if sweep_angle < 0.0 {
+            (start_angle + sweep_angle, -sweep_angle)
+        } else {
+            (start_angle, sweep_angle)
+        };
+        Self {
+            start_angle,
+            sweep_angle,
+            stroke_width,
+        }
+    }
+
+    /// Returns true if `(x, y)` lies on the arc drawn inside `rect`.
+    pub fn contains(&self, rect: Rect, x: f32, y: f32) -> bool {
+        let outer = rect.width.min(rect.height) / 2.0;
+        let inner = (outer - self.stroke_width).max(0.0);
+        let dx = x - (rect.x + rect.width / 2.0);
+        let dy = y - (rect.y + rect.height / 2.0);
+        let distance = (dx * dx + dy * dy).sqrt();
+        if distance > outer || distance < inner {
+            return false;
+        }
+        if self.sweep_angle >= 360.0 {
+            return true;
+        }
+        let angle = dy.atan2(dx).to_degrees();
+        (angle - self.start_angle).rem_euclid(360.0) <= self.sweep_angle
+    }
+}
+
+/// A straight stroke with butt caps inside a shape's bounds.
+///
+/// `start` and `end` are relative to the top-left corner of the bounds, which
+/// hold the whole stroke.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LineShape {
+    pub start: Point,
+    pub end: Point,
+    pub stroke_width: f32,
+}
+
+impl LineShape {
+    /// Returns the bounds of the stroke from `start` to `end` and the line
+    /// relative to them, or `None` when nothing would be drawn.
+    pub fn between(start: Point, end: Point, stroke_width: f32) -> Option<(Rect, Self)> {
+        let dx = end.x - start.x;
+        let dy = end.y - start.y;
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0.0 || stroke_width <= 0.0 {
+            return None;
+        }
+        // Half extents of the stroke perpendicular to the line.
+        let nx = (dy / length * stroke_width / 2.0).abs();
+        let ny = (dx / length * stroke_width / 2.0).abs();
+        let x = start.x.min(end.x) - nx;
+        let y = start.y.min(end.y) - ny;
+        let rect = Rect {
+            x,
+            y,
+            width: dx.abs() + nx * 2.0,
+            height: dy.abs() + ny * 2.0,
+        };
+        let line = Self {
+            start: Point::new(start.x - x, start.y - y),
+            end: Point::new(end.x - x, end.y - y),
+            stroke_width,
+        };
+        Some((rect, line))
+    }
+
+    /// Returns the line drawn in bounds scaled by `scale`.
+    pub fn scaled(self, scale: f32) -> Self {
+        Self {
+            start: Point::new(self.start.x * scale, self.start.y * scale),
+            end: Point::new(self.end.x * scale, self.end.y * scale),
+            stroke_width: self.stroke_width * scale,
+        }
+    }
+
+    /// Returns true if `(x, y)` lies on the line drawn inside `rect`.
+    pub fn contains(&self, rect: Rect, x: f32, y: f32) -> bool {
+        let dx = self.end.x - self.start.x;
+        let dy = self.end.y - self.start.y;
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0.0 {
+            return false;
+        }
+        let px = x - (rect.x + self.start.x);
+        let py = y - (rect.y + self.start.y);
+        let along = (px * dx + py * dy) / length;
+        let across = (px * dy - py * dx) / length;
+        (0.0..=length).contains(&along) && across.abs() <= self.stroke_width / 2.0
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct GraphicsLayer {
+    /// Opacity multiplied into every draw of the node and its descendants.
     pub alpha: f32,
     pub scale: f32,
     pub translation_x: f32,
     pub translation_y: f32,
+    /// Clips the node and its descendants to the node bounds.
+    pub clip: bool,
+    /// Elevation of the drop shadow drawn behind the node; `0.0` draws none.
+    pub shadow_elevation: f32,
 }
 
 impl Default for GraphicsLayer {
@@ -227,6 +341,8 @@ impl Default for GraphicsLayer {
             scale: 1.0,
             translation_x: 0.0,
             translation_y: 0.0,
+            clip: false,
+            shadow_elevation: 0.0,
         }
     }
 }
@@ -242,6 +358,18 @@ pub enum DrawPrimitive {
         brush: Brush,
         radii: CornerRadii,
     },
+    /// Arc stroked along the circle inscribed in `rect`.
+    Arc {
+        rect: Rect,
+        brush: Brush,
+        arc: ArcShape,
+    },
+    /// Straight stroke inside `rect`, which holds the whole stroke.
+    Line {
+        rect: Rect,
+        brush: Brush,
+        line: LineShape,
+    },
 }
 
 pub trait DrawScope {
@@ -251,6 +379,24 @@ pub trait DrawScope {
     /// Draws a rectangle at the specified position and size.
     fn draw_rect_at(&mut self, rect: Rect, brush: Brush);
     fn draw_round_rect(&mut self, brush: Brush, radii: CornerRadii);
+    /// Draws a filled circle.
+    fn draw_circle(&mut self, center: Point, radius: f32, brush: Brush);
+    /// Strokes an arc of the circle around `center`. The stroke is centered on `radius`.
+    ///
+    /// Matches Kotlin: `DrawScope.drawArc(brush, startAngle, sweepAngle, useCenter = false, style = Stroke(width))`.
+    fn draw_arc(
+        &mut self,
+        center: Point,
+        radius: f32,
+        start_angle: f32,
+        sweep_angle: f32,
+        stroke_width: f32,
+        brush: Brush,
+    );
+    /// Draws a line of the given stroke `width` from `start` to `end` with butt caps.
+    ///
+    /// Matches Kotlin: `DrawScope.drawLine(brush, start, end, strokeWidth)`.
+    fn draw_line(&mut self, start: Point, end: Point, width: f32, brush: Brush);
     fn into_primitives(self) -> Vec<DrawPrimitive>;
 }
 
@@ -295,6 +441,54 @@ impl DrawScope for DrawScopeDefault {
         });
     }
 
+    fn draw_circle(&mut self, center: Point, radius: f32, brush: Brush) {
+        self.primitives.push(DrawPrimitive::RoundRect {
+            rect: Rect {
+                x: center.x - radius,
+                y: center.y - radius,
+                width: radius * 2.0,
+                height: radius * 2.0,
+            },
+            brush,
+            radii: CornerRadii::uniform(radius),
+        });
+    }
+
+    fn draw_arc(
+        &mut self,
+        center: Point,
+        radius: f32,
+        start_angle: f32,
+        sweep_angle: f32,
+        stroke_width: f32,
+        brush: Brush,
+    ) {
+        let outer = radius + stroke_width / 2.0;
+        self.primitives.push(DrawPrimitive::Arc {
+            rect: Rect {
+                x: center.x - outer,
+                y: center.y - outer,
+                width: outer * 2.0,
+                height: outer * 2.0,
+            },
+            brush,
+            arc: ArcShape::new(start_angle, sweep_angle, stroke_width),
+        });
+    }
+
+    fn draw_line(&mut self, start: Point, end: Point, width: f32, brush: Brush) {
+        let Some((rect, line)) = LineShape::between(start, end, width) else {
+            return;
+        };
+        if start.x == end.x || start.y == end.y {
+            // Axis-aligned strokes fill their bounds exactly.
+            self.primitives.push(DrawPrimitive::Rect { rect, brush });
+        } else {
+            self.primitives
+                .push(DrawPrimitive::Line { rect, brush, line });
+        }
+    }
+
     fn into_primitives(self) -> Vec<DrawPrimitive> {
         self.primitives
     }
@@ -21,5 +21,5 @@ pub mod prelude {
     pub use crate::brush::Brush;
     pub use crate::color::Color;
     pub use crate::geometry::{CornerRadii, EdgeInsets, Point, Rect, RoundedCornerShape, Size};
-    pub use crate::unit::{Dp, Sp};
+    pub use crate::unit::{Density, Dp, Sp, UnitExt};
 }
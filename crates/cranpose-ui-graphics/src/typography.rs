@@ -1,5 +1,7 @@
 //! Typography data structures (font styles, weights, text styles)
 
+use crate::unit::Sp;
+
 /// Font style (normal, italic, oblique)
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FontStyle {
@@ -24,13 +26,25 @@ impl FontWeight {
     pub const BLACK: FontWeight = FontWeight(900);
 }
 
+/// Line drawn along text.
+///
+/// Matches Kotlin: `TextDecoration.Underline` / `TextDecoration.LineThrough`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextDecoration {
+    /// A line just below the baseline.
+    Underline,
+    /// A line through the middle of lowercase letters.
+    LineThrough,
+}
+
 /// Text style (data only, no rendering)
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct TextStyle {
     pub font_family: Option<String>,
-    pub font_size: Option<f32>,
+    pub font_size: Option<Sp>,
     pub font_weight: Option<FontWeight>,
     pub font_style: Option<FontStyle>,
-    pub letter_spacing: Option<f32>,
-    pub line_height: Option<f32>,
+    pub letter_spacing: Option<Sp>,
+    pub line_height: Option<Sp>,
+    pub text_decoration: Option<TextDecoration>,
 }
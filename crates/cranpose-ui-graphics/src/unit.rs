@@ -1,5 +1,7 @@
 //! Unit types: Dp, Sp, and conversions
 
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
 /// Density-independent pixels
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 pub struct Dp(pub f32);
@@ -28,6 +30,132 @@ impl Sp {
     }
 }
 
+impl From<f32> for Dp {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<f32> for Sp {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+macro_rules! impl_unit_ops {
+    ($unit:ident) => {
+        impl Add for $unit {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl Mul<f32> for $unit {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Div<f32> for $unit {
+            type Output = Self;
+
+            fn div(self, rhs: f32) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+
+        impl Neg for $unit {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+impl_unit_ops!(Dp);
+impl_unit_ops!(Sp);
+
+/// Builds [`Dp`] and [`Sp`] values from number literals, like Kotlin's `16.dp`.
+///
+/// Example: `16.dp().to_px(2.0) == 32.0`
+pub trait UnitExt {
+    fn dp(self) -> Dp;
+    fn sp(self) -> Sp;
+}
+
+impl UnitExt for f32 {
+    fn dp(self) -> Dp {
+        Dp(self)
+    }
+
+    fn sp(self) -> Sp {
+        Sp(self)
+    }
+}
+
+impl UnitExt for i32 {
+    fn dp(self) -> Dp {
+        Dp(self as f32)
+    }
+
+    fn sp(self) -> Sp {
+        Sp(self as f32)
+    }
+}
+
 /// Raw pixels
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 pub struct Px(pub f32);
+
+/// Density scaling applied when converting [`Dp`] and [`Sp`] values to pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Density {
+    /// Logical pixels per dp.
+    pub density: f32,
+    /// Additional multiplier applied to [`Sp`] values on top of `density`.
+    pub font_scale: f32,
+}
+
+impl Density {
+    pub const fn new(density: f32) -> Self {
+        Self {
+            density,
+            font_scale: 1.0,
+        }
+    }
+
+    pub const fn with_font_scale(density: f32, font_scale: f32) -> Self {
+        Self {
+            density,
+            font_scale,
+        }
+    }
+
+    pub fn dp_to_px(&self, dp: Dp) -> f32 {
+        dp.to_px(self.density)
+    }
+
+    pub fn sp_to_px(&self, sp: Sp) -> f32 {
+        sp.to_px(self.density, self.font_scale)
+    }
+}
+
+impl Default for Density {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
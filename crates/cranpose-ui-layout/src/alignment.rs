@@ -74,3 +74,52 @@ impl VerticalAlignment {
         }
     }
 }
+
+/// Horizontal line inside a layout that siblings can be aligned by instead of
+/// their bounds, such as the baseline of a text.
+///
+/// Matches Kotlin: `FirstBaseline` / `LastBaseline` in `AlignmentLine.kt`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlignmentLine {
+    /// Baseline of the first line of text.
+    FirstBaseline,
+    /// Baseline of the last line of text.
+    LastBaseline,
+}
+
+/// Alignment line positions reported by a measured layout, measured from its top edge.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AlignmentLines {
+    pub first_baseline: Option<f32>,
+    pub last_baseline: Option<f32>,
+}
+
+impl AlignmentLines {
+    /// Lines of a text whose first and last baselines sit at the given offsets.
+    pub fn baselines(first: f32, last: f32) -> Self {
+        Self {
+            first_baseline: Some(first),
+            last_baseline: Some(last),
+        }
+    }
+
+    /// Returns the position of `line`, if the layout provides it.
+    pub fn get(&self, line: AlignmentLine) -> Option<f32> {
+        match line {
+            AlignmentLine::FirstBaseline => self.first_baseline,
+            AlignmentLine::LastBaseline => self.last_baseline,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first_baseline.is_none() && self.last_baseline.is_none()
+    }
+
+    /// Returns the lines as seen from a parent that places this layout `dy` lower.
+    pub fn offset_by(self, dy: f32) -> Self {
+        Self {
+            first_baseline: self.first_baseline.map(|y| y + dy),
+            last_baseline: self.last_baseline.map(|y| y + dy),
+        }
+    }
+}
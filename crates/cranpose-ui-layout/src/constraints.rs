@@ -40,11 +40,28 @@ impl Constraints {
         self.max_width.is_finite() && self.max_height.is_finite()
     }
 
+    /// Returns these constraints with `0 <= min <= max` on both axes.
+    ///
+    /// Negative or NaN minimums become zero and a maximum below its minimum is
+    /// raised to it, so arithmetic like subtracting padding can't produce
+    /// bounds that panic in `clamp` or yield negative sizes.
+    pub fn normalized(self) -> Self {
+        let min_width = self.min_width.max(0.0);
+        let min_height = self.min_height.max(0.0);
+        Self {
+            min_width,
+            max_width: self.max_width.max(min_width),
+            min_height,
+            max_height: self.max_height.max(min_height),
+        }
+    }
+
     /// Constrains the provided width and height to fit within these constraints.
     pub fn constrain(&self, width: f32, height: f32) -> (f32, f32) {
+        let bounds = self.normalized();
         (
-            width.clamp(self.min_width, self.max_width),
-            height.clamp(self.min_height, self.max_height),
+            width.clamp(bounds.min_width, bounds.max_width),
+            height.clamp(bounds.min_height, bounds.max_height),
         )
     }
 
@@ -112,11 +129,12 @@ impl Constraints {
     /// This is useful for applying padding before measuring children.
     pub fn deflate(self, horizontal: f32, vertical: f32) -> Self {
         Self {
-            min_width: (self.min_width - horizontal).max(0.0),
-            max_width: (self.max_width - horizontal).max(0.0),
-            min_height: (self.min_height - vertical).max(0.0),
-            max_height: (self.max_height - vertical).max(0.0),
+            min_width: self.min_width - horizontal,
+            max_width: self.max_width - horizontal,
+            min_height: self.min_height - vertical,
+            max_height: self.max_height - vertical,
         }
+        .normalized()
     }
 
     /// Creates new constraints with loosened minimums (min = 0).
@@ -138,3 +156,7 @@ impl Constraints {
         }
     }
 }
+
+#[cfg(test)]
+#[path = "tests/constraints_tests.rs"]
+mod tests;
@@ -1,5 +1,6 @@
 //! Core layout traits and types shared by Compose UI widgets.
 
+use crate::alignment::{Alignment, AlignmentLine, AlignmentLines};
 use crate::constraints::Constraints;
 use cranpose_core::NodeId;
 use cranpose_ui_graphics::Size;
@@ -48,6 +49,24 @@ pub trait Measurable {
     fn flex_parent_data(&self) -> Option<FlexParentData> {
         None
     }
+
+    /// Returns the alignment requested via `Modifier::align` inside a Box.
+    /// Default implementation returns None (use the Box's content alignment).
+    fn box_alignment(&self) -> Option<Alignment> {
+        None
+    }
+
+    /// Returns whether `Modifier::match_parent_size` asks this Box child to take
+    /// the size of the Box measured from its other children.
+    fn matches_parent_size(&self) -> bool {
+        false
+    }
+
+    /// Returns the alignment line requested via `Modifier::align_by` inside a Row.
+    /// Default implementation returns None (use the Row's vertical alignment).
+    fn align_by(&self) -> Option<AlignmentLine> {
+        None
+    }
 }
 
 /// Result of running a measurement pass for a single child.
@@ -71,6 +90,12 @@ pub trait Placeable {
     fn content_offset(&self) -> (f32, f32) {
         (0.0, 0.0)
     }
+
+    /// Returns the position of `line` from the child's top edge, if it has one.
+    /// Default is None, for layouts without text baselines.
+    fn alignment_line(&self, _line: AlignmentLine) -> Option<f32> {
+        None
+    }
 }
 
 /// Scope for measurement operations.
@@ -113,11 +138,22 @@ pub trait MeasurePolicy {
 pub struct MeasureResult {
     pub size: Size,
     pub placements: Vec<Placement>,
+    /// Alignment lines this layout exposes to its parent.
+    pub alignment_lines: AlignmentLines,
 }
 
 impl MeasureResult {
     pub fn new(size: Size, placements: Vec<Placement>) -> Self {
-        Self { size, placements }
+        Self {
+            size,
+            placements,
+            alignment_lines: AlignmentLines::default(),
+        }
+    }
+
+    pub fn with_alignment_lines(mut self, alignment_lines: AlignmentLines) -> Self {
+        self.alignment_lines = alignment_lines;
+        self
     }
 }
 
@@ -156,6 +192,9 @@ pub struct LayoutModifierMeasureResult {
     /// to offset the child by the padding amount.
     pub placement_offset_x: f32,
     pub placement_offset_y: f32,
+    /// Alignment lines this modifier provides, such as a text's baselines.
+    /// When empty, the wrapped content's lines are used, shifted by the placement offset.
+    pub alignment_lines: AlignmentLines,
 }
 
 impl LayoutModifierMeasureResult {
@@ -164,6 +203,7 @@ impl LayoutModifierMeasureResult {
             size,
             placement_offset_x,
             placement_offset_y,
+            alignment_lines: AlignmentLines::default(),
         }
     }
 
@@ -173,6 +213,12 @@ impl LayoutModifierMeasureResult {
             size,
             placement_offset_x: 0.0,
             placement_offset_y: 0.0,
+            alignment_lines: AlignmentLines::default(),
         }
     }
+
+    pub fn with_alignment_lines(mut self, alignment_lines: AlignmentLines) -> Self {
+        self.alignment_lines = alignment_lines;
+        self
+    }
 }
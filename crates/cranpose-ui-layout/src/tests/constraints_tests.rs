@@ -0,0 +1,57 @@
+use super::Constraints;
+
+#[test]
+fn normalized_raises_max_to_min() {
+    let constraints = Constraints {
+        min_width: 80.0,
+        max_width: 60.0,
+        min_height: 10.0,
+        max_height: 5.0,
+    }
+    .normalized();
+    assert_eq!(constraints.min_width, 80.0);
+    assert_eq!(constraints.max_width, 80.0);
+    assert_eq!(constraints.min_height, 10.0);
+    assert_eq!(constraints.max_height, 10.0);
+}
+
+#[test]
+fn normalized_clears_negative_and_nan_minimums() {
+    let constraints = Constraints {
+        min_width: -20.0,
+        max_width: -5.0,
+        min_height: f32::NAN,
+        max_height: f32::INFINITY,
+    }
+    .normalized();
+    assert_eq!(constraints.min_width, 0.0);
+    assert_eq!(constraints.max_width, 0.0);
+    assert_eq!(constraints.min_height, 0.0);
+    assert_eq!(constraints.max_height, f32::INFINITY);
+}
+
+#[test]
+fn deflate_by_more_than_max_stays_consistent() {
+    let constraints = Constraints {
+        min_width: 50.0,
+        max_width: 60.0,
+        min_height: 0.0,
+        max_height: f32::INFINITY,
+    }
+    .deflate(100.0, 100.0);
+    assert_eq!(constraints.min_width, 0.0);
+    assert_eq!(constraints.max_width, 0.0);
+    assert_eq!(constraints.min_height, 0.0);
+    assert_eq!(constraints.max_height, f32::INFINITY);
+}
+
+#[test]
+fn constrain_tolerates_inverted_bounds() {
+    let constraints = Constraints {
+        min_width: 80.0,
+        max_width: 60.0,
+        min_height: 0.0,
+        max_height: 40.0,
+    };
+    assert_eq!(constraints.constrain(100.0, 100.0), (80.0, 40.0));
+}
@@ -0,0 +1,64 @@
+use cranpose_ui::{Color, Modifier, ModifierChainHandle};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const CHAIN_LENGTH_SAMPLES: &[usize] = &[8, 64, 256];
+
+fn modifier_chain(length: usize, color: Color) -> Modifier {
+    (0..length).fold(Modifier::empty(), |modifier, index| {
+        if index % 2 == 0 {
+            modifier.padding(1.0)
+        } else {
+            modifier.background(color)
+        }
+    })
+}
+
+/// Reconciling an unchanged chain should reuse every node.
+fn bench_update_unchanged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("modifier_chain_update_unchanged");
+    for &length in CHAIN_LENGTH_SAMPLES {
+        group.bench_with_input(
+            BenchmarkId::new("elements", length),
+            &length,
+            |b, &length| {
+                let modifier = modifier_chain(length, Color(1.0, 0.0, 0.0, 1.0));
+                let mut handle = ModifierChainHandle::new();
+                handle.update(&modifier);
+
+                b.iter(|| {
+                    black_box(handle.update(&modifier));
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Alternating between chains that differ in every background element updates
+/// nodes in place; the cost should stay linear in the chain length.
+fn bench_update_changed(c: &mut Criterion) {
+    let mut group = c.benchmark_group("modifier_chain_update_changed");
+    for &length in CHAIN_LENGTH_SAMPLES {
+        group.bench_with_input(
+            BenchmarkId::new("elements", length),
+            &length,
+            |b, &length| {
+                let red = modifier_chain(length, Color(1.0, 0.0, 0.0, 1.0));
+                let blue = modifier_chain(length, Color(0.0, 0.0, 1.0, 1.0));
+                let mut handle = ModifierChainHandle::new();
+                handle.update(&red);
+                let mut use_blue = true;
+
+                b.iter(|| {
+                    let modifier = if use_blue { &blue } else { &red };
+                    use_blue = !use_blue;
+                    black_box(handle.update(modifier));
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_update_unchanged, bench_update_changed);
+criterion_main!(benches);
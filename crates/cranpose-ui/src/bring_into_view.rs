@@ -0,0 +1,346 @@
+//! Scrolling nodes into view inside their scroll containers.
+//!
+//! A request names a layout node; every scroll container between that node and
+//! the root then animates by the smallest amount that makes the node fully
+//! visible, innermost container first. Lazy lists count as containers too and
+//! jump through their `LazyListState` instead of animating. Requests are queued and served by the
+//! app shell after layout, when node positions are current. Focusing a node or
+//! a text field queues a request for it.
+
+use crate::modifier::{Point, Rect, Size};
+use crate::render_state::request_render_invalidation;
+use crate::scroll::{LazyScrollNode, ScrollNode, ScrollState};
+use crate::subcompose_layout::SubcomposeLayoutNode;
+use crate::widgets::LayoutNode;
+use cranpose_core::{MemoryApplier, NodeId};
+use cranpose_foundation::lazy::LazyListState;
+use cranpose_foundation::{
+    DelegatableNode, ModifierNode, ModifierNodeContext, ModifierNodeElement, NodeState,
+};
+use std::cell::{Cell, RefCell};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+thread_local! {
+    static PENDING_REQUESTS: RefCell<Vec<NodeId>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A handle for scrolling a component into view.
+///
+/// Attach it with [`Modifier::bring_into_view_requester`](crate::Modifier::bring_into_view_requester).
+///
+/// Matches Kotlin: `BringIntoViewRequester` in `androidx.compose.foundation.relocation`.
+#[derive(Clone, Debug, Default)]
+pub struct BringIntoViewRequester {
+    node_id: Rc<Cell<Option<NodeId>>>,
+}
+
+impl BringIntoViewRequester {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scrolls the attached component into view.
+    ///
+    /// Returns `false` when the requester is not attached to a component.
+    pub fn bring_into_view(&self) -> bool {
+        match self.node_id.get() {
+            Some(node_id) => {
+                request_bring_into_view(node_id);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl PartialEq for BringIntoViewRequester {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.node_id, &other.node_id)
+    }
+}
+
+impl Eq for BringIntoViewRequester {}
+
+impl Hash for BringIntoViewRequester {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.node_id).hash(state);
+    }
+}
+
+/// Node recording which layout node a [`BringIntoViewRequester`] is attached to.
+pub struct BringIntoViewRequesterNode {
+    state: NodeState,
+    requester: BringIntoViewRequester,
+}
+
+impl DelegatableNode for BringIntoViewRequesterNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for BringIntoViewRequesterNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        self.state.set_attached(true);
+        self.requester.node_id.set(context.node_id());
+    }
+
+    fn on_detach(&mut self) {
+        self.state.set_attached(false);
+        self.requester.node_id.set(None);
+    }
+}
+
+/// Modifier element for bring-into-view requesters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BringIntoViewRequesterElement {
+    requester: BringIntoViewRequester,
+}
+
+impl BringIntoViewRequesterElement {
+    pub fn new(requester: BringIntoViewRequester) -> Self {
+        Self { requester }
+    }
+}
+
+impl ModifierNodeElement for BringIntoViewRequesterElement {
+    type Node = BringIntoViewRequesterNode;
+
+    fn create(&self) -> Self::Node {
+        BringIntoViewRequesterNode {
+            state: NodeState::new(),
+            requester: self.requester.clone(),
+        }
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        if node.requester != self.requester {
+            let node_id = node.requester.node_id.take();
+            node.requester = self.requester.clone();
+            node.requester.node_id.set(node_id);
+        }
+    }
+
+    fn inspector_name(&self) -> &'static str {
+        "bringIntoViewRequester"
+    }
+}
+
+/// Queues a request to scroll `node_id` into view after the next layout.
+pub fn request_bring_into_view(node_id: NodeId) {
+    PENDING_REQUESTS.with(|pending| {
+        let mut pending = pending.borrow_mut();
+        if !pending.contains(&node_id) {
+            pending.push(node_id);
+        }
+    });
+    request_render_invalidation();
+}
+
+/// Returns true if bring-into-view requests are waiting for the app shell.
+pub fn has_pending_bring_into_view_requests() -> bool {
+    PENDING_REQUESTS.with(|pending| !pending.borrow().is_empty())
+}
+
+/// Serves the queued bring-into-view requests for nodes under `root`.
+pub fn process_bring_into_view_requests(applier: &mut MemoryApplier, root: NodeId) {
+    let requests = PENDING_REQUESTS.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    for node_id in requests {
+        bring_into_view(applier, root, node_id);
+    }
+}
+
+/// Scrolls every scroll container around `node_id` so the node becomes fully
+/// visible, animating each container by the smallest possible amount.
+///
+/// Returns `true` if any container scrolls.
+pub fn bring_into_view(applier: &mut MemoryApplier, root: NodeId, node_id: NodeId) -> bool {
+    let mut containers = Vec::new();
+    let Some(mut rect) = find_node(applier, root, node_id, Point::default(), &mut containers)
+    else {
+        return false;
+    };
+
+    let mut scrolled = false;
+    for container in containers.iter().rev() {
+        for scroller in &container.scrollers {
+            let (start, end, viewport) = if scroller.is_vertical {
+                let top = rect.y - container.origin.y;
+                (top, top + rect.height, container.size.height)
+            } else {
+                let left = rect.x - container.origin.x;
+                (left, left + rect.width, container.size.width)
+            };
+            let delta = bring_into_view_delta(start, end, viewport);
+            let Some(content_shift) = scroller.scroll_by(delta) else {
+                continue;
+            };
+            scrolled = true;
+
+            // Outer containers see the node where this one scrolls it to
+            if scroller.is_vertical {
+                rect.y -= content_shift;
+            } else {
+                rect.x -= content_shift;
+            }
+        }
+    }
+    scrolled
+}
+
+/// Distance to scroll content so that `start..end` lies within `0..viewport`.
+///
+/// Content already in view, or covering the whole viewport, does not move;
+/// otherwise the closer edge is aligned with the viewport.
+///
+/// Matches Kotlin: `BringIntoViewSpec.calculateScrollDistance` (default spec).
+fn bring_into_view_delta(start: f32, end: f32, viewport: f32) -> f32 {
+    let fits = start >= 0.0 && end <= viewport;
+    let covers = start < 0.0 && end > viewport;
+    if fits || covers {
+        return 0.0;
+    }
+    let trailing = end - viewport;
+    if start.abs() < trailing.abs() {
+        start
+    } else {
+        trailing
+    }
+}
+
+/// State that scrolls a container.
+enum ScrollResponder {
+    Scroll(ScrollState),
+    LazyList(LazyListState),
+}
+
+struct Scroller {
+    responder: ScrollResponder,
+    is_vertical: bool,
+    reverse: bool,
+}
+
+impl Scroller {
+    fn from_scroll_node(node: &ScrollNode) -> Self {
+        Self {
+            responder: ScrollResponder::Scroll(node.state().clone()),
+            is_vertical: node.is_vertical(),
+            reverse: node.reverse_scrolling(),
+        }
+    }
+
+    fn from_lazy_node(node: &LazyScrollNode) -> Self {
+        Self {
+            responder: ScrollResponder::LazyList(node.state()),
+            is_vertical: node.is_vertical(),
+            reverse: node.reverse_scrolling(),
+        }
+    }
+
+    /// Scrolls so the content moves `delta` toward the start, returning how
+    /// far it moves, or `None` if it stays where it is.
+    ///
+    /// Scroll containers animate to their target; lazy lists jump there on
+    /// their next layout pass and clamp the distance to their content.
+    fn scroll_by(&self, delta: f32) -> Option<f32> {
+        match &self.responder {
+            ScrollResponder::Scroll(state) => {
+                let state_delta = if self.reverse { -delta } else { delta };
+                let current = state.value_non_reactive();
+                let target = (current + state_delta).clamp(0.0, state.max_value());
+                if (target - current).abs() <= 0.001 {
+                    return None;
+                }
+                state.animate_scroll_to(target);
+                Some(if self.reverse {
+                    current - target
+                } else {
+                    target - current
+                })
+            }
+            ScrollResponder::LazyList(state) => {
+                if delta.abs() <= 0.001 {
+                    return None;
+                }
+                // Negative deltas move a list toward its end
+                state.dispatch_scroll_delta(if self.reverse { delta } else { -delta });
+                Some(delta)
+            }
+        }
+    }
+}
+
+/// A scroll container on the path to the requested node, in root coordinates.
+struct ScrollContainer {
+    origin: Point,
+    size: Size,
+    scrollers: Vec<Scroller>,
+}
+
+/// Finds `target` under `node_id`, returning its bounds in root coordinates
+/// and leaving the scroll containers around it in `containers`, outermost first.
+fn find_node(
+    applier: &mut MemoryApplier,
+    node_id: NodeId,
+    target: NodeId,
+    parent_offset: Point,
+    containers: &mut Vec<ScrollContainer>,
+) -> Option<Rect> {
+    let node_data = if let Ok(data) = applier.with_node::<LayoutNode, _>(node_id, |node| {
+        let children: Vec<NodeId> = node.children.iter().copied().collect();
+        let mut scrollers = Vec::new();
+        node.for_each_scroll_node(|scroll| scrollers.push(Scroller::from_scroll_node(scroll)));
+        node.for_each_lazy_scroll_node(|scroll| scrollers.push(Scroller::from_lazy_node(scroll)));
+        (node.layout_state(), scrollers, children)
+    }) {
+        data
+    } else if let Ok(data) = applier.with_node::<SubcomposeLayoutNode, _>(node_id, |node| {
+        let mut scrollers = Vec::new();
+        node.for_each_lazy_scroll_node(|scroll| scrollers.push(Scroller::from_lazy_node(scroll)));
+        (node.layout_state(), scrollers, node.active_children())
+    }) {
+        data
+    } else {
+        return None;
+    };
+
+    let (layout_state, scrollers, children) = node_data;
+    if !layout_state.is_placed {
+        return None;
+    }
+
+    let position = Point {
+        x: parent_offset.x + layout_state.position.x,
+        y: parent_offset.y + layout_state.position.y,
+    };
+    if node_id == target {
+        return Some(Rect::from_origin_size(position, layout_state.size));
+    }
+
+    let is_container = !scrollers.is_empty();
+    if is_container {
+        containers.push(ScrollContainer {
+            origin: position,
+            size: layout_state.size,
+            scrollers,
+        });
+    }
+    let child_offset = Point {
+        x: position.x + layout_state.content_offset.x,
+        y: position.y + layout_state.content_offset.y,
+    };
+    for child_id in children {
+        if let Some(rect) = find_node(applier, child_id, target, child_offset, containers) {
+            return Some(rect);
+        }
+    }
+    if is_container {
+        containers.pop();
+    }
+    None
+}
+
+#[cfg(test)]
+#[path = "tests/bring_into_view_tests.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+//! Platform-agnostic clipboard access.
+//!
+//! Platforms install their backend with [`set_platform_clipboard`] before the
+//! first composition. Composables read it through
+//! [`local_clipboard`](crate::local_clipboard), which defaults to the installed
+//! backend and can be overridden (e.g. with a [`MemoryClipboard`] in tests).
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Plain-text clipboard backend.
+///
+/// Matches Kotlin: `ClipboardManager` in `androidx.compose.ui.platform`.
+pub trait Clipboard {
+    /// Returns the clipboard text, or None if it is empty or unavailable.
+    fn get_text(&self) -> Option<String>;
+    /// Replaces the clipboard contents with `text`.
+    fn set_text(&self, text: &str);
+}
+
+/// In-process clipboard used when no platform backend is installed.
+#[derive(Debug, Default)]
+pub struct MemoryClipboard {
+    text: RefCell<Option<String>>,
+}
+
+impl MemoryClipboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Clipboard for MemoryClipboard {
+    fn get_text(&self) -> Option<String> {
+        self.text.borrow().clone()
+    }
+
+    fn set_text(&self, text: &str) {
+        *self.text.borrow_mut() = Some(text.to_owned());
+    }
+}
+
+thread_local! {
+    static PLATFORM_CLIPBOARD: RefCell<Rc<dyn Clipboard>> =
+        RefCell::new(Rc::new(MemoryClipboard::new()));
+}
+
+/// Installs the clipboard backend for the current platform.
+pub fn set_platform_clipboard(clipboard: Rc<dyn Clipboard>) {
+    PLATFORM_CLIPBOARD.with(|current| *current.borrow_mut() = clipboard);
+}
+
+/// Returns the installed clipboard backend.
+pub fn platform_clipboard() -> Rc<dyn Clipboard> {
+    PLATFORM_CLIPBOARD.with(|current| current.borrow().clone())
+}
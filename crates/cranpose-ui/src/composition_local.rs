@@ -0,0 +1,155 @@
+//! Ambient values shared with the UI tree through composition locals.
+//!
+//! Composition locals are keyed per instance, so each local lives in a
+//! thread-local slot and is handed out by an accessor function. Readers call
+//! `.current()` inside a composable to subscribe to the nearest provided value.
+
+use crate::clipboard::{platform_clipboard, Clipboard};
+use crate::modifier::{ElevationCurve, FocusIndication};
+use crate::render_state::current_density;
+use crate::text_selection::TextSelectionColors;
+use crate::widgets::{PopupLayer, PopupStack};
+use cranpose_core::{
+    compositionLocalOf, staticCompositionLocalOf, CompositionLocal, StaticCompositionLocal,
+};
+use cranpose_ui_graphics::{Density, Dp};
+use std::rc::Rc;
+
+thread_local! {
+    static LOCAL_DENSITY: CompositionLocal<Density> =
+        compositionLocalOf(|| Density::new(current_density()));
+    static LOCAL_CLIPBOARD: CompositionLocal<Rc<dyn Clipboard>> =
+        compositionLocalOf(platform_clipboard);
+    static LOCAL_ELEVATION_CURVE: CompositionLocal<ElevationCurve> =
+        compositionLocalOf(ElevationCurve::default);
+    static LOCAL_FOCUS_INDICATION: CompositionLocal<Option<FocusIndication>> =
+        compositionLocalOf(|| Some(FocusIndication::default()));
+    static LOCAL_TEXT_SELECTION_COLORS: CompositionLocal<TextSelectionColors> =
+        compositionLocalOf(TextSelectionColors::default);
+    static LOCAL_POPUP_LAYER: StaticCompositionLocal<Option<PopupLayer>> =
+        staticCompositionLocalOf(|| None);
+    static LOCAL_POPUP_STACK: StaticCompositionLocal<Option<PopupStack>> =
+        staticCompositionLocalOf(|| None);
+}
+
+/// Density used to convert `Dp`/`Sp` values into logical pixels.
+///
+/// Defaults to the platform density reported through [`crate::set_density`]
+/// when no `CompositionLocalProvider` overrides it.
+pub fn local_density() -> CompositionLocal<Density> {
+    LOCAL_DENSITY.with(Clone::clone)
+}
+
+/// Converts `dp` to layout units under the nearest [`local_density`].
+///
+/// Layout runs in logical pixels that renderers scale by the platform density
+/// from [`crate::set_density`], so only density provided on top of the
+/// platform's changes the result.
+pub(crate) fn resolve_dp(dp: Dp) -> f32 {
+    let density = local_density().current_or_default().density;
+    dp.to_px(density / current_density())
+}
+
+/// Clipboard used by text components for copy and paste.
+///
+/// Defaults to the backend installed with [`crate::set_platform_clipboard`].
+pub fn local_clipboard() -> CompositionLocal<Rc<dyn Clipboard>> {
+    LOCAL_CLIPBOARD.with(Clone::clone)
+}
+
+/// Curve mapping elevations to shadows for [`Modifier::shadow`](crate::Modifier::shadow).
+///
+/// Defaults to [`ElevationCurve::default`].
+pub fn local_elevation_curve() -> CompositionLocal<ElevationCurve> {
+    LOCAL_ELEVATION_CURVE.with(Clone::clone)
+}
+
+/// Focus ring drawn by [`Modifier::focusable`](crate::Modifier::focusable).
+///
+/// Defaults to [`FocusIndication::default`]; provide `None` to suppress focus
+/// rings in a subtree.
+pub fn local_focus_indication() -> CompositionLocal<Option<FocusIndication>> {
+    LOCAL_FOCUS_INDICATION.with(Clone::clone)
+}
+
+/// Cursor and selection colors used by text fields and selectable text.
+///
+/// Defaults to [`TextSelectionColors::default`].
+pub fn local_text_selection_colors() -> CompositionLocal<TextSelectionColors> {
+    LOCAL_TEXT_SELECTION_COLORS.with(Clone::clone)
+}
+
+/// Layer of the nearest [`PopupHost`](crate::PopupHost), where
+/// [`Popup`](crate::Popup)s are shown.
+///
+/// Static: a host provides the same layer for its whole lifetime.
+pub fn local_popup_layer() -> StaticCompositionLocal<Option<PopupLayer>> {
+    LOCAL_POPUP_LAYER.with(Clone::clone)
+}
+
+/// Popup layers of the app, where each [`PopupHost`](crate::PopupHost)
+/// registers its layer.
+///
+/// Provided by the app host, which closes the topmost popup through it.
+pub fn local_popup_stack() -> StaticCompositionLocal<Option<PopupStack>> {
+    LOCAL_POPUP_STACK.with(Clone::clone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_test_composition;
+    use cranpose_core::CompositionLocalProvider;
+    use std::cell::RefCell;
+
+    #[test]
+    fn local_density_returns_nearest_provided_value() {
+        let seen = RefCell::new(Vec::new());
+        run_test_composition(|| {
+            CompositionLocalProvider(vec![local_density().provides(Density::new(2.0))], || {
+                seen.borrow_mut().push(local_density().current().density);
+                CompositionLocalProvider(
+                    vec![local_density().provides(Density::with_font_scale(3.0, 1.5))],
+                    || {
+                        let density = local_density().current();
+                        seen.borrow_mut().push(density.density);
+                        assert_eq!(density.sp_to_px(cranpose_ui_graphics::Sp(10.0)), 45.0);
+                    },
+                );
+            });
+        });
+        assert_eq!(*seen.borrow(), vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn dp_converts_with_density() {
+        use cranpose_ui_graphics::UnitExt;
+
+        assert_eq!(16.dp().to_px(2.0), 32.0);
+        assert_eq!((16.dp() + 4.0.dp()) * 0.5, Dp(10.0));
+
+        let resolved = RefCell::new(Vec::new());
+        run_test_composition(|| {
+            resolved.borrow_mut().push(resolve_dp(Dp(8.0)));
+            CompositionLocalProvider(vec![local_density().provides(Density::new(2.0))], || {
+                resolved.borrow_mut().push(resolve_dp(Dp(8.0)));
+            });
+        });
+        assert_eq!(*resolved.borrow(), vec![8.0, 16.0]);
+    }
+
+    #[test]
+    fn local_clipboard_round_trips_text_through_provided_clipboard() {
+        let mock: Rc<dyn Clipboard> = Rc::new(crate::MemoryClipboard::new());
+        let read_back = RefCell::new(None);
+        run_test_composition(|| {
+            CompositionLocalProvider(vec![local_clipboard().provides(mock.clone())], || {
+                let clipboard = local_clipboard().current();
+                clipboard.set_text("copied");
+                *read_back.borrow_mut() = clipboard.get_text();
+            });
+        });
+        assert_eq!(read_back.borrow().as_deref(), Some("copied"));
+        assert_eq!(mock.get_text().as_deref(), Some("copied"));
+    }
+}
@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use crate::modifier::Size;
-use cranpose_ui_graphics::{DrawPrimitive, DrawScope, DrawScopeDefault};
+use cranpose_ui_graphics::{DrawPrimitive, DrawScope, DrawScopeDefault, RoundedCornerShape};
 
 pub type DrawCommandFn = Rc<dyn Fn(Size) -> Vec<DrawPrimitive>>;
 
@@ -9,6 +9,9 @@ pub type DrawCommandFn = Rc<dyn Fn(Size) -> Vec<DrawPrimitive>>;
 pub enum DrawCommand {
     Behind(DrawCommandFn),
     Overlay(DrawCommandFn),
+    /// Drawn over the content and clipped to the node's bounds, rounded by the
+    /// shape if there is one.
+    OverlayClipped(DrawCommandFn, Option<RoundedCornerShape>),
 }
 
 #[derive(Default, Clone)]
@@ -48,7 +51,9 @@ pub fn execute_draw_commands(commands: &[DrawCommand], size: Size) -> Vec<DrawPr
     let mut primitives = Vec::new();
     for command in commands {
         match command {
-            DrawCommand::Behind(f) | DrawCommand::Overlay(f) => {
+            DrawCommand::Behind(f)
+            | DrawCommand::Overlay(f)
+            | DrawCommand::OverlayClipped(f, _) => {
                 primitives.extend(f(size).into_iter());
             }
         }
@@ -1,8 +1,11 @@
 //! Fling animation driver for scroll containers.
 //!
 //! Drives decay animation using the runtime's frame callback system.
+//! Positions are sampled at the time elapsed since the first frame, so a fling
+//! covers the same distance at any refresh rate. A fling that would stop past
+//! the end of bounded content settles onto the bound with a spring instead.
 
-use cranpose_animation::{FloatDecayAnimationSpec, SplineBasedDecaySpec};
+use cranpose_animation::{FloatDecayAnimationSpec, SplineBasedDecaySpec, SpringSpec};
 use cranpose_core::{FrameCallbackRegistration, FrameClock, RuntimeHandle};
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
@@ -17,6 +20,14 @@ const DEFAULT_FLING_FRICTION: f32 = 0.015;
 /// Minimum unconsumed delta (in pixels) to consider a boundary hit.
 const BOUNDARY_EPSILON: f32 = 0.5;
 
+/// Spring that settles a fling onto a scroll bound (Compose's `StiffnessMediumLow`).
+pub const FLING_SETTLE_SPRING: SpringSpec = SpringSpec {
+    damping_ratio: 1.0,
+    stiffness: 400.0,
+    velocity_threshold: 0.01,
+    position_threshold: 0.001,
+};
+
 /// Schedules the next fling animation frame without creating a FlingAnimation instance.
 /// This is called recursively to drive the animation forward.
 fn schedule_next_frame<F, G>(
@@ -54,8 +65,7 @@ fn schedule_next_frame<F, G>(
             };
 
             let play_time_nanos = frame_time_nanos.saturating_sub(start_time) as i64;
-
-            let new_value = anim_state.decay_spec.get_value_from_nanos(
+            let (new_value, is_finished) = anim_state.motion.sample(
                 play_time_nanos,
                 anim_state.initial_value,
                 anim_state.initial_velocity,
@@ -68,19 +78,6 @@ fn schedule_next_frame<F, G>(
                 .total_delta
                 .set(anim_state.total_delta.get() + delta);
 
-            let duration_nanos = anim_state
-                .decay_spec
-                .get_duration_nanos(anim_state.initial_value, anim_state.initial_velocity);
-
-            let current_velocity = anim_state.decay_spec.get_velocity_from_nanos(
-                play_time_nanos,
-                anim_state.initial_value,
-                anim_state.initial_velocity,
-            );
-
-            let is_finished = play_time_nanos >= duration_nanos
-                || current_velocity.abs() < anim_state.decay_spec.abs_velocity_threshold();
-
             if is_finished {
                 anim_state.is_running.set(false);
             }
@@ -119,6 +116,52 @@ fn schedule_next_frame<F, G>(
     }
 }
 
+/// How a fling moves away from its initial value.
+enum FlingMotion {
+    /// Decelerates along the fling curve.
+    Decay(SplineBasedDecaySpec),
+    /// Springs onto `target`, starting with the fling's velocity.
+    Settle { spec: SpringSpec, target: f32 },
+}
+
+impl FlingMotion {
+    /// Returns the value after `play_time_nanos` and whether the motion has ended.
+    fn sample(
+        &self,
+        play_time_nanos: i64,
+        initial_value: f32,
+        initial_velocity: f32,
+    ) -> (f32, bool) {
+        match self {
+            FlingMotion::Decay(decay_spec) => {
+                let value = decay_spec.get_value_from_nanos(
+                    play_time_nanos,
+                    initial_value,
+                    initial_velocity,
+                );
+                let velocity = decay_spec.get_velocity_from_nanos(
+                    play_time_nanos,
+                    initial_value,
+                    initial_velocity,
+                );
+                let duration_nanos = decay_spec.get_duration_nanos(initial_value, initial_velocity);
+                let is_finished = play_time_nanos >= duration_nanos
+                    || velocity.abs() < decay_spec.abs_velocity_threshold();
+                (value, is_finished)
+            }
+            FlingMotion::Settle { spec, target } => {
+                let seconds = play_time_nanos as f32 / 1_000_000_000.0;
+                let motion = spec.step(initial_value, initial_velocity, *target, seconds);
+                if spec.is_settled(motion, *target) {
+                    (*target, true)
+                } else {
+                    (motion.value, false)
+                }
+            }
+        }
+    }
+}
+
 /// State for an active fling animation.
 struct FlingAnimationState {
     /// Initial position when fling started (used as reference for decay calc).
@@ -129,8 +172,8 @@ struct FlingAnimationState {
     initial_velocity: f32,
     /// Frame time when the animation started (used for deterministic timing).
     start_frame_time_nanos: Cell<Option<u64>>,
-    /// Decay animation spec for computing position/velocity.
-    decay_spec: SplineBasedDecaySpec,
+    /// Curve the fling follows.
+    motion: FlingMotion,
     /// Current frame callback registration (kept alive to continue animation).
     registration: Option<FrameCallbackRegistration>,
     /// Whether the animation is still active.
@@ -175,6 +218,47 @@ impl FlingAnimation {
     ) where
         F: Fn(f32) -> f32 + 'static, // Returns consumed amount
         G: FnOnce() + 'static,
+    {
+        self.start(initial_value, velocity, density, None, on_scroll, on_end);
+    }
+
+    /// Starts a fling on content that scrolls between `bounds.0` and `bounds.1`.
+    ///
+    /// A fling that would stop past a bound settles onto it with
+    /// [`FLING_SETTLE_SPRING`] rather than running into it at full speed.
+    pub fn start_fling_in_bounds<F, G>(
+        &self,
+        initial_value: f32,
+        velocity: f32,
+        density: f32,
+        bounds: (f32, f32),
+        on_scroll: F,
+        on_end: G,
+    ) where
+        F: Fn(f32) -> f32 + 'static,
+        G: FnOnce() + 'static,
+    {
+        self.start(
+            initial_value,
+            velocity,
+            density,
+            Some(bounds),
+            on_scroll,
+            on_end,
+        );
+    }
+
+    fn start<F, G>(
+        &self,
+        initial_value: f32,
+        velocity: f32,
+        density: f32,
+        bounds: Option<(f32, f32)>,
+        on_scroll: F,
+        on_end: G,
+    ) where
+        F: Fn(f32) -> f32 + 'static,
+        G: FnOnce() + 'static,
     {
         // Cancel any existing animation
         self.cancel();
@@ -189,13 +273,21 @@ impl FlingAnimation {
         let friction = DEFAULT_FLING_FRICTION;
         let calc = cranpose_animation::FlingCalculator::new(friction, density);
         let decay_spec = SplineBasedDecaySpec::with_calculator(calc);
+        let target = decay_spec.get_target_value(initial_value, velocity);
+        let motion = match bounds {
+            Some((min, max)) if target < min || target > max => FlingMotion::Settle {
+                spec: FLING_SETTLE_SPRING,
+                target: target.clamp(min, max),
+            },
+            _ => FlingMotion::Decay(decay_spec),
+        };
 
         let anim_state = FlingAnimationState {
             initial_value,
             last_value: Cell::new(initial_value),
             initial_velocity: velocity,
             start_frame_time_nanos: Cell::new(None),
-            decay_spec,
+            motion,
             registration: None,
             is_running: Cell::new(true),
             total_delta: Cell::new(0.0),
@@ -268,4 +360,81 @@ mod tests {
 
         assert!(finished.get());
     }
+
+    /// Distance a fling scrolls in `frames` frames of `frame_nanos` each.
+    fn fling_distance_after_frames(frame_nanos: u64, frames: u64) -> f32 {
+        let runtime = Runtime::new(Arc::new(DefaultScheduler));
+        let handle = runtime.handle();
+        let fling = FlingAnimation::new(handle.clone());
+        let scrolled = Rc::new(Cell::new(0.0));
+        let total = Rc::clone(&scrolled);
+
+        fling.start_fling(
+            0.0,
+            2_000.0,
+            1.0,
+            move |delta| {
+                total.set(total.get() + delta);
+                delta
+            },
+            || {},
+        );
+        for frame in 0..=frames {
+            handle.drain_frame_callbacks(frame * frame_nanos);
+        }
+        scrolled.get()
+    }
+
+    #[test]
+    fn fling_distance_depends_on_elapsed_time_not_frame_rate() {
+        // 240ms at 60Hz and at 120Hz
+        let at_60hz = fling_distance_after_frames(16_000_000, 15);
+        let at_120hz = fling_distance_after_frames(8_000_000, 30);
+        assert!(at_60hz > 0.0);
+        assert!(
+            (at_60hz - at_120hz).abs() < 0.01,
+            "{at_60hz} at 60Hz vs {at_120hz} at 120Hz"
+        );
+    }
+
+    #[test]
+    fn fling_past_the_bound_settles_onto_it_with_a_spring() {
+        let runtime = Runtime::new(Arc::new(DefaultScheduler));
+        let handle = runtime.handle();
+        let fling = FlingAnimation::new(handle.clone());
+        let offset = Rc::new(Cell::new(0.0f32));
+        let finished = Rc::new(Cell::new(false));
+        let scrolled = Rc::clone(&offset);
+        let finished_flag = Rc::clone(&finished);
+
+        // A 2000px/s fling travels much further than the 50px of content
+        fling.start_fling_in_bounds(
+            0.0,
+            2_000.0,
+            1.0,
+            (0.0, 50.0),
+            move |delta| {
+                let next = (scrolled.get() + delta).clamp(0.0, 50.0);
+                let consumed = next - scrolled.get();
+                scrolled.set(next);
+                consumed
+            },
+            move || finished_flag.set(true),
+        );
+        handle.drain_frame_callbacks(0);
+        handle.drain_frame_callbacks(16_000_000);
+
+        let expected = FLING_SETTLE_SPRING.step(0.0, 2_000.0, 50.0, 0.016).value;
+        assert!(
+            (offset.get() - expected).abs() < 1e-3,
+            "{} after one frame, spring gives {expected}",
+            offset.get()
+        );
+
+        for frame in 2..200 {
+            handle.drain_frame_callbacks(frame * 16_000_000);
+        }
+        assert!(finished.get());
+        assert_eq!(offset.get(), 50.0);
+    }
 }
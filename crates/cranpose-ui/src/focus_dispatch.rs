@@ -13,6 +13,28 @@ thread_local! {
         RefCell::new(FocusInvalidationManager::new());
 }
 
+/// How the currently focused node received focus.
+///
+/// Focus indication is only drawn for focus that did not come from a pointer,
+/// mirroring Jetpack Compose's keyboard `InputMode`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FocusSource {
+    /// Tab/Shift-Tab traversal or another key press.
+    Keyboard,
+    /// A pointer press on the focused node.
+    Pointer,
+    /// A request from application code.
+    #[default]
+    Programmatic,
+}
+
+impl FocusSource {
+    /// Returns true if focus from this source should be visibly indicated.
+    pub fn shows_indication(self) -> bool {
+        self != FocusSource::Pointer
+    }
+}
+
 /// Manages focus invalidations across the UI tree.
 ///
 /// Similar to Kotlin's `FocusInvalidationManager`, this tracks which
@@ -22,6 +44,7 @@ struct FocusInvalidationManager {
     dirty_nodes: HashSet<NodeId>,
     is_processing: bool,
     active_focus_target: Option<NodeId>,
+    active_focus_source: FocusSource,
 }
 
 impl FocusInvalidationManager {
@@ -30,6 +53,7 @@ impl FocusInvalidationManager {
             dirty_nodes: HashSet::new(),
             is_processing: false,
             active_focus_target: None,
+            active_focus_source: FocusSource::default(),
         }
     }
 
@@ -103,6 +127,18 @@ pub fn active_focus_target() -> Option<NodeId> {
     FOCUS_INVALIDATION_MANAGER.with(|manager| manager.borrow().active_focus_target())
 }
 
+/// Records how the active focus target received focus.
+pub fn set_active_focus_source(source: FocusSource) {
+    FOCUS_INVALIDATION_MANAGER.with(|manager| {
+        manager.borrow_mut().active_focus_source = source;
+    });
+}
+
+/// Returns how the active focus target received focus.
+pub fn active_focus_source() -> FocusSource {
+    FOCUS_INVALIDATION_MANAGER.with(|manager| manager.borrow().active_focus_source)
+}
+
 /// Processes all pending focus invalidations.
 ///
 /// The host (e.g., app shell or layout engine) should call this after
@@ -0,0 +1,206 @@
+//! Keyboard focus traversal across focusable layout nodes.
+//!
+//! Focus targets are collected from the placed layout tree and visited in
+//! reading order: targets whose vertical extents overlap form a row, rows go
+//! top-to-bottom and targets within a row left-to-right. This mirrors the
+//! one-dimensional `FocusDirection.Next`/`Previous` search of Jetpack Compose's
+//! `FocusOwner.moveFocus`; the currently focused node is tracked by
+//! [`active_focus_target`](crate::active_focus_target).
+
+use crate::bring_into_view::request_bring_into_view;
+use crate::focus_dispatch::{
+    active_focus_source, active_focus_target, set_active_focus_source, set_active_focus_target,
+    FocusSource,
+};
+use crate::key_event::KeyEvent;
+use crate::modifier::{FocusDirection, FocusState, Point, Rect};
+use crate::render_state::{request_focus_invalidation, request_render_invalidation};
+use crate::subcompose_layout::SubcomposeLayoutNode;
+use crate::widgets::LayoutNode;
+use cranpose_core::{MemoryApplier, Node, NodeId};
+
+/// Moves focus to the next or previous focus target in reading order.
+///
+/// Traversal wraps around at either end. When nothing is focused, `Next`
+/// focuses the first target and `Previous` the last. Two-dimensional
+/// directions are not supported yet and return `false`.
+pub fn move_focus(applier: &mut MemoryApplier, root: NodeId, direction: FocusDirection) -> bool {
+    let targets = focus_traversal_order(applier, root);
+    if targets.is_empty() {
+        return false;
+    }
+    let count = targets.len();
+    let current = active_focus_target().and_then(|id| targets.iter().position(|&t| t == id));
+    let index = match (direction, current) {
+        (FocusDirection::Next, Some(index)) => (index + 1) % count,
+        (FocusDirection::Next, None) => 0,
+        (FocusDirection::Previous, Some(index)) => (index + count - 1) % count,
+        (FocusDirection::Previous, None) => count - 1,
+        _ => return false,
+    };
+    request_focus_from(applier, targets[index], FocusSource::Keyboard)
+}
+
+/// Moves focus to `node_id`, clearing it from the previously focused node.
+///
+/// Returns `false` if the node has no focus target.
+pub fn request_focus(applier: &mut MemoryApplier, node_id: NodeId) -> bool {
+    request_focus_from(applier, node_id, FocusSource::Programmatic)
+}
+
+/// Moves focus to `node_id` on behalf of `source`.
+///
+/// Refocusing the focused node only records the new source, so a click on a
+/// keyboard-focused node hides its focus indication.
+pub fn request_focus_from(
+    applier: &mut MemoryApplier,
+    node_id: NodeId,
+    source: FocusSource,
+) -> bool {
+    let is_target = applier
+        .with_node::<LayoutNode, _>(node_id, |node| node.is_focus_target())
+        .unwrap_or(false);
+    if !is_target {
+        return false;
+    }
+    if active_focus_target() == Some(node_id) {
+        if active_focus_source() != source {
+            set_active_focus_source(source);
+            request_render_invalidation();
+        }
+        return true;
+    }
+    clear_focus(applier);
+    let _ = applier
+        .with_node::<LayoutNode, _>(node_id, |node| node.set_focus_state(FocusState::Active));
+    set_active_focus_target(Some(node_id));
+    set_active_focus_source(source);
+    request_bring_into_view(node_id);
+    request_focus_invalidation();
+    request_render_invalidation();
+    true
+}
+
+/// Clears focus from the currently focused node, if any.
+pub fn clear_focus(applier: &mut MemoryApplier) {
+    if let Some(previous) = active_focus_target() {
+        let _ = applier.with_node::<LayoutNode, _>(previous, |node| {
+            node.set_focus_state(FocusState::Inactive)
+        });
+        set_active_focus_target(None);
+        request_focus_invalidation();
+        request_render_invalidation();
+    }
+}
+
+/// Sends `event` to the focused node's key handlers, then to its ancestors'.
+///
+/// Handlers set with [`Modifier::on_key_event`](crate::Modifier::on_key_event)
+/// run innermost first, and the first one returning `true` consumes the event.
+/// Returns whether the event was consumed; `false` when nothing is focused.
+pub fn dispatch_focused_key_event(applier: &mut MemoryApplier, event: &KeyEvent) -> bool {
+    let mut current = active_focus_target();
+    while let Some(node_id) = current {
+        let node_data = if let Ok(data) = applier
+            .with_node::<LayoutNode, _>(node_id, |node| (node.key_event_handlers(), node.parent()))
+        {
+            data
+        } else if let Ok(data) = applier.with_node::<SubcomposeLayoutNode, _>(node_id, |node| {
+            (node.key_event_handlers(), node.parent())
+        }) {
+            data
+        } else {
+            return false;
+        };
+
+        // Handlers run outside the node borrow so they can touch the tree
+        let (handlers, parent) = node_data;
+        if handlers.iter().any(|handler| handler(event)) {
+            return true;
+        }
+        current = parent;
+    }
+    false
+}
+
+/// Returns the placed focus targets under `root` in reading order.
+pub fn focus_traversal_order(applier: &mut MemoryApplier, root: NodeId) -> Vec<NodeId> {
+    let mut targets = Vec::new();
+    collect_focus_targets(applier, root, Point::default(), &mut targets);
+    reading_order(&mut targets);
+    targets.into_iter().map(|(id, _)| id).collect()
+}
+
+/// Sorts targets into rows, top-to-bottom, and each row left-to-right.
+///
+/// A row starts at its topmost target and takes every following target that
+/// begins above that target's bottom edge, so bottom-aligned items of
+/// different heights share a row. Stable sorts keep tree order for ties.
+fn reading_order(targets: &mut [(NodeId, Rect)]) {
+    targets.sort_by(|(_, a), (_, b)| a.y.total_cmp(&b.y));
+    let mut row_start = 0;
+    while row_start < targets.len() {
+        let row_bottom = targets[row_start].1.y + targets[row_start].1.height;
+        let row_len = targets[row_start + 1..]
+            .iter()
+            .take_while(|(_, rect)| rect.y < row_bottom)
+            .count()
+            + 1;
+        let row_end = row_start + row_len;
+        targets[row_start..row_end].sort_by(|(_, a), (_, b)| a.x.total_cmp(&b.x));
+        row_start = row_end;
+    }
+}
+
+fn collect_focus_targets(
+    applier: &mut MemoryApplier,
+    node_id: NodeId,
+    parent_offset: Point,
+    targets: &mut Vec<(NodeId, Rect)>,
+) {
+    let node_data = if let Ok(data) = applier.with_node::<LayoutNode, _>(node_id, |node| {
+        let children: Vec<NodeId> = node.children.iter().copied().collect();
+        (node.layout_state(), node.is_focus_target(), children)
+    }) {
+        data
+    } else if let Ok(data) = applier.with_node::<SubcomposeLayoutNode, _>(node_id, |node| {
+        (node.layout_state(), false, node.active_children())
+    }) {
+        data
+    } else {
+        return;
+    };
+
+    let (layout_state, is_focus_target, children) = node_data;
+    if !layout_state.is_placed {
+        return;
+    }
+
+    let position = Point {
+        x: parent_offset.x + layout_state.position.x,
+        y: parent_offset.y + layout_state.position.y,
+    };
+    if is_focus_target {
+        targets.push((
+            node_id,
+            Rect {
+                x: position.x,
+                y: position.y,
+                width: layout_state.size.width,
+                height: layout_state.size.height,
+            },
+        ));
+    }
+
+    let child_offset = Point {
+        x: position.x + layout_state.content_offset.x,
+        y: position.y + layout_state.content_offset.y,
+    };
+    for child_id in children {
+        collect_focus_targets(applier, child_id, child_offset, targets);
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/focus_traversal_tests.rs"]
+mod tests;
@@ -0,0 +1,64 @@
+//! Soft keyboard and input method (IME) integration.
+//!
+//! Platforms with an on-screen keyboard install a backend with
+//! [`set_platform_ime`]. Focusing a text field shows the keyboard and clearing
+//! text field focus hides it. Text typed through the input method flows the
+//! other way: platforms translate it into [`ImeEvent`]s for the app shell,
+//! which hands them to the focused text field.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Connection to the platform's input method.
+///
+/// Matches Kotlin: `PlatformTextInputService` in `androidx.compose.ui.text.input`.
+pub trait ImeConnection {
+    /// Shows the soft keyboard, if the platform has one.
+    fn show_keyboard(&self);
+    /// Hides the soft keyboard.
+    fn hide_keyboard(&self);
+}
+
+/// Text input from the platform's input method.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImeEvent {
+    /// Text being composed; an empty `text` ends the composition.
+    /// `cursor` is the byte range of the cursor within `text`.
+    Preedit {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+    /// Final text, replacing any composition in progress.
+    Commit(String),
+    /// Deletes bytes before and after the cursor or selection.
+    DeleteSurrounding {
+        before_bytes: usize,
+        after_bytes: usize,
+    },
+}
+
+thread_local! {
+    static PLATFORM_IME: RefCell<Option<Rc<dyn ImeConnection>>> = const { RefCell::new(None) };
+}
+
+/// Installs the input method backend for the current platform.
+pub fn set_platform_ime(ime: Rc<dyn ImeConnection>) {
+    PLATFORM_IME.with(|current| *current.borrow_mut() = Some(ime));
+}
+
+/// Returns the installed input method backend, if any.
+pub fn platform_ime() -> Option<Rc<dyn ImeConnection>> {
+    PLATFORM_IME.with(|current| current.borrow().clone())
+}
+
+pub(crate) fn show_keyboard() {
+    if let Some(ime) = platform_ime() {
+        ime.show_keyboard();
+    }
+}
+
+pub(crate) fn hide_keyboard() {
+    if let Some(ime) = platform_ime() {
+        ime.hide_keyboard();
+    }
+}
@@ -10,7 +10,7 @@
 
 use cranpose_core::NodeId;
 use cranpose_foundation::ModifierNodeContext;
-use cranpose_ui_layout::{Constraints, Measurable, Placeable};
+use cranpose_ui_layout::{AlignmentLines, Constraints, Measurable, Placeable};
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
@@ -25,6 +25,10 @@ pub trait NodeCoordinator: Measurable {
     /// Returns the accumulated placement offset from this coordinator
     /// down through the wrapped chain (inner-most coordinator).
     fn total_content_offset(&self) -> Point;
+
+    /// Returns the alignment lines from the last measure pass, relative to
+    /// this coordinator's top edge.
+    fn alignment_lines(&self) -> AlignmentLines;
 }
 
 /// Coordinator that wraps a single LayoutModifierNode from the reconciled chain.
@@ -43,6 +47,8 @@ pub struct LayoutModifierCoordinator<'a> {
     /// The ACCUMULATED placement offset from this coordinator through the entire chain.
     /// This is local_offset + wrapped.total_content_offset(), stored for O(1) access.
     accumulated_offset: Cell<Point>,
+    /// Alignment lines from the last measure pass, relative to this coordinator.
+    alignment_lines: Cell<AlignmentLines>,
     /// Shared context for invalidation tracking.
     context: Rc<RefCell<LayoutNodeContext>>,
 }
@@ -60,6 +66,7 @@ impl<'a> LayoutModifierCoordinator<'a> {
             wrapped,
             measured_size: Cell::new(Size::default()),
             accumulated_offset: Cell::new(Point::default()),
+            alignment_lines: Cell::new(AlignmentLines::default()),
             context,
         }
     }
@@ -70,6 +77,10 @@ impl<'a> NodeCoordinator for LayoutModifierCoordinator<'a> {
         // O(1): just return the pre-computed accumulated offset
         self.accumulated_offset.get()
     }
+
+    fn alignment_lines(&self) -> AlignmentLines {
+        self.alignment_lines.get()
+    }
 }
 
 impl<'a> Measurable for LayoutModifierCoordinator<'a> {
@@ -105,6 +116,7 @@ impl<'a> Measurable for LayoutModifierCoordinator<'a> {
                 // Pass through the child's accumulated offset (stored from its measure())
                 let child_accumulated = self.wrapped.total_content_offset();
                 self.accumulated_offset.set(child_accumulated);
+                self.alignment_lines.set(self.wrapped.alignment_lines());
                 return Box::new(CoordinatorPlaceable {
                     size: Size {
                         width: placeable.width(),
@@ -135,6 +147,15 @@ impl<'a> Measurable for LayoutModifierCoordinator<'a> {
         };
         self.accumulated_offset.set(accumulated);
 
+        // Lines the node reports itself (text baselines) win; otherwise the
+        // wrapped content's lines move with it, e.g. down by top padding
+        let alignment_lines = if result.alignment_lines.is_empty() {
+            self.wrapped.alignment_lines().offset_by(local_offset.y)
+        } else {
+            result.alignment_lines
+        };
+        self.alignment_lines.set(alignment_lines);
+
         Box::new(CoordinatorPlaceable {
             size: result.size,
             content_offset: accumulated,
@@ -188,6 +209,8 @@ pub struct InnerCoordinator<'a> {
     measurables: &'a [Box<dyn Measurable>],
     /// Measured size from last measure pass.
     measured_size: Cell<Size>,
+    /// Alignment lines reported by the measure policy in the last pass.
+    alignment_lines: Cell<AlignmentLines>,
     /// Position relative to parent.
     /// Shared result holder to store the measure result for placement.
     result_holder: Rc<RefCell<Option<MeasureResult>>>,
@@ -204,6 +227,7 @@ impl<'a> InnerCoordinator<'a> {
             measure_policy,
             measurables,
             measured_size: Cell::new(Size::ZERO),
+            alignment_lines: Cell::new(AlignmentLines::default()),
             result_holder,
         }
     }
@@ -213,6 +237,10 @@ impl<'a> NodeCoordinator for InnerCoordinator<'a> {
     fn total_content_offset(&self) -> Point {
         Point::default()
     }
+
+    fn alignment_lines(&self) -> AlignmentLines {
+        self.alignment_lines.get()
+    }
 }
 
 impl<'a> Measurable for InnerCoordinator<'a> {
@@ -223,6 +251,7 @@ impl<'a> Measurable for InnerCoordinator<'a> {
         // Store measured size
         let size = result.size;
         self.measured_size.set(size);
+        self.alignment_lines.set(result.alignment_lines);
 
         // Store the result in the shared holder for placement extraction
         *self.result_holder.borrow_mut() = Some(result);
@@ -1,4 +1,4 @@
 pub use cranpose_ui_layout::{
-    Alignment, Arrangement, HorizontalAlignment, LinearArrangement, Measurable, Placeable,
-    VerticalAlignment,
+    Alignment, AlignmentLine, Arrangement, HorizontalAlignment, LinearArrangement, Measurable,
+    MeasurePolicy, Placeable, VerticalAlignment,
 };
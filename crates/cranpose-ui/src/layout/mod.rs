@@ -19,6 +19,7 @@ use cranpose_core::{
 };
 
 use self::coordinator::NodeCoordinator;
+use self::core::Alignment;
 use self::core::Measurable;
 use self::core::Placeable;
 #[cfg(test)]
@@ -32,8 +33,10 @@ use crate::subcompose_layout::SubcomposeLayoutNode;
 use crate::widgets::nodes::{IntrinsicKind, LayoutNode, LayoutNodeCacheHandles};
 use cranpose_foundation::InvalidationKind;
 use cranpose_foundation::ModifierNodeContext;
-use cranpose_foundation::{NodeCapabilities, SemanticsConfiguration};
-use cranpose_ui_layout::{Constraints, MeasurePolicy, MeasureResult};
+use cranpose_foundation::{NodeCapabilities, Role, SemanticsConfiguration};
+use cranpose_ui_layout::{
+    AlignmentLine, AlignmentLines, Constraints, MeasurePolicy, MeasureResult,
+};
 
 /// Runtime context for modifier nodes during measurement.
 ///
@@ -225,10 +228,33 @@ pub enum SemanticsRole {
     Spacer,
     /// Button (derived from is_button semantics flag)
     Button,
+    /// Checkbox (declared via the semantics role)
+    Checkbox,
+    /// Switch (declared via the semantics role)
+    Switch,
+    /// Radio button (declared via the semantics role)
+    RadioButton,
+    /// Tab (declared via the semantics role)
+    Tab,
+    /// Image or icon (declared via the semantics role)
+    Image,
     /// Unknown or unspecified role
     Unknown,
 }
 
+impl From<Role> for SemanticsRole {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Button => SemanticsRole::Button,
+            Role::Checkbox => SemanticsRole::Checkbox,
+            Role::Switch => SemanticsRole::Switch,
+            Role::RadioButton => SemanticsRole::RadioButton,
+            Role::Tab => SemanticsRole::Tab,
+            Role::Image => SemanticsRole::Image,
+        }
+    }
+}
+
 /// A single node within the semantics tree.
 #[derive(Clone, Debug)]
 pub struct SemanticsNode {
@@ -237,6 +263,8 @@ pub struct SemanticsNode {
     pub actions: Vec<SemanticsAction>,
     pub children: Vec<SemanticsNode>,
     pub description: Option<String>,
+    pub state_description: Option<String>,
+    pub disabled: bool,
 }
 
 impl SemanticsNode {
@@ -246,6 +274,8 @@ impl SemanticsNode {
         actions: Vec<SemanticsAction>,
         children: Vec<SemanticsNode>,
         description: Option<String>,
+        state_description: Option<String>,
+        disabled: bool,
     ) -> Self {
         Self {
             node_id,
@@ -253,8 +283,35 @@ impl SemanticsNode {
             actions,
             children,
             description,
+            state_description,
+            disabled,
         }
     }
+
+    /// Text announced for the node: its text content, else its content description.
+    pub fn label(&self) -> Option<&str> {
+        match &self.role {
+            SemanticsRole::Text { value } => Some(value),
+            _ => self.description.as_deref(),
+        }
+    }
+}
+
+/// Flattened semantics node handed to platform accessibility bridges.
+///
+/// Nodes reference their parent and children by id so a bridge can mirror the
+/// tree without walking nested structures.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessibilityNode {
+    pub node_id: NodeId,
+    pub parent: Option<NodeId>,
+    pub children: Vec<NodeId>,
+    pub role: SemanticsRole,
+    pub label: Option<String>,
+    pub content_description: Option<String>,
+    pub state_description: Option<String>,
+    pub clickable: bool,
+    pub disabled: bool,
 }
 
 /// Rooted semantics tree extracted after layout.
@@ -271,6 +328,33 @@ impl SemanticsTree {
     pub fn root(&self) -> &SemanticsNode {
         &self.root
     }
+
+    /// Flattens the tree into depth-first order, parents before their children.
+    pub fn to_accessibility_nodes(&self) -> Vec<AccessibilityNode> {
+        fn visit(node: &SemanticsNode, parent: Option<NodeId>, out: &mut Vec<AccessibilityNode>) {
+            out.push(AccessibilityNode {
+                node_id: node.node_id,
+                parent,
+                children: node.children.iter().map(|child| child.node_id).collect(),
+                role: node.role.clone(),
+                label: node.label().map(str::to_owned),
+                content_description: node.description.clone(),
+                state_description: node.state_description.clone(),
+                clickable: node
+                    .actions
+                    .iter()
+                    .any(|action| matches!(action, SemanticsAction::Click { .. })),
+                disabled: node.disabled,
+            });
+            for child in &node.children {
+                visit(child, Some(node.node_id), out);
+            }
+        }
+
+        let mut nodes = Vec::new();
+        visit(&self.root, None, &mut nodes);
+        nodes
+    }
 }
 
 /// Caches semantics configurations for layout nodes, similar to Jetpack Compose's SemanticsOwner.
@@ -566,7 +650,7 @@ pub fn measure_layout(
     // If measurement fails, the guard will restore slots from the shared handle
     // on drop - this is safe because the handle always contains valid slots.
 
-    let measured = builder.measure_node(root, normalize_constraints(constraints))?;
+    let measured = builder.measure_node(root, constraints.normalized())?;
 
     // Root node has no parent to place it, so we must explicitly place it at (0,0).
     // This ensures is_placed=true, allowing the renderer to traverse the tree.
@@ -824,7 +908,7 @@ impl LayoutBuilderState {
         let props = resolved_modifiers.layout_properties();
         let padding = resolved_modifiers.padding();
         let offset = resolved_modifiers.offset();
-        let mut inner_constraints = normalize_constraints(subtract_padding(constraints, padding));
+        let mut inner_constraints = subtract_padding(constraints, padding);
 
         if let DimensionConstraint::Points(width) = props.width() {
             let constrained_width = width - padding.horizontal_sum();
@@ -853,10 +937,17 @@ impl LayoutBuilderState {
         let state_rc_clone = Rc::clone(&state_rc);
         let measure_error: Rc<RefCell<Option<NodeError>>> = Rc::new(RefCell::new(None));
         let error_for_measurer = Rc::clone(&measure_error);
+        // Placed children keep the measurement the policy made, as with Placeables
+        let measured_children: Rc<RefCell<HashMap<NodeId, Rc<MeasuredNode>>>> = Rc::default();
+        let measured_by_policy = Rc::clone(&measured_children);
         let measurer = Box::new(
             move |child_id: NodeId, child_constraints: Constraints| -> Size {
                 match Self::measure_node(Rc::clone(&state_rc_clone), child_id, child_constraints) {
-                    Ok(measured) => measured.size,
+                    Ok(measured) => {
+                        let size = measured.size;
+                        measured_by_policy.borrow_mut().insert(child_id, measured);
+                        size
+                    }
                     Err(err) => {
                         let mut slot = error_for_measurer.borrow_mut();
                         if slot.is_none() {
@@ -915,8 +1006,13 @@ impl LayoutBuilderState {
         }
 
         for placement in measure_result.placements {
-            let child =
-                Self::measure_node(Rc::clone(&state_rc), placement.node_id, inner_constraints)?;
+            let measured = measured_children.borrow_mut().remove(&placement.node_id);
+            let child = match measured {
+                Some(child) => child,
+                None => {
+                    Self::measure_node(Rc::clone(&state_rc), placement.node_id, inner_constraints)?
+                }
+            };
             let position = Point {
                 x: padding.left + placement.x,
                 y: padding.top + placement.y,
@@ -1016,14 +1112,9 @@ impl LayoutBuilderState {
         // This saves 3 allocations (shared_context, policy_result, InnerCoordinator box).
         if layout_node_data.is_empty() {
             let result = measure_policy.measure(measurables, constraints);
-            let final_size = result.size;
-            let placements = result.placements;
 
             return ModifierChainMeasurement {
-                result: MeasureResult {
-                    size: final_size,
-                    placements,
-                },
+                result,
                 content_offset: Point::default(),
                 offset,
             };
@@ -1105,11 +1196,13 @@ impl LayoutBuilderState {
             .ok();
         }
 
+        // Like content_offset, alignment lines are reported relative to where the
+        // node is laid out, before its own offset moves it
+        let alignment_lines = current_coordinator.alignment_lines().offset_by(-offset.y);
+
         ModifierChainMeasurement {
-            result: MeasureResult {
-                size: final_size,
-                placements,
-            },
+            result: MeasureResult::new(final_size, placements)
+                .with_alignment_lines(alignment_lines),
             content_offset,
             offset,
         }
@@ -1297,13 +1390,16 @@ impl LayoutBuilderState {
             }
         }
 
-        let measured = Rc::new(MeasuredNode::new(
-            node_id,
-            Size { width, height },
-            offset,
-            content_offset,
-            measured_children,
-        ));
+        let measured = Rc::new(
+            MeasuredNode::new(
+                node_id,
+                Size { width, height },
+                offset,
+                content_offset,
+                measured_children,
+            )
+            .with_alignment_lines(policy_result.alignment_lines),
+        );
 
         cache.store_measurement(constraints, Rc::clone(&measured));
 
@@ -1468,6 +1564,8 @@ pub(crate) struct MeasuredNode {
     offset: Point,
     /// Content offset for scroll/inner transforms (NOT node position)
     content_offset: Point,
+    /// Alignment lines (text baselines) relative to the node's top edge
+    alignment_lines: AlignmentLines,
     children: Vec<MeasuredChild>,
 }
 
@@ -1484,9 +1582,15 @@ impl MeasuredNode {
             size,
             offset,
             content_offset,
+            alignment_lines: AlignmentLines::default(),
             children,
         }
     }
+
+    fn with_alignment_lines(mut self, alignment_lines: AlignmentLines) -> Self {
+        self.alignment_lines = alignment_lines;
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1732,6 +1836,53 @@ impl Measurable for LayoutChildMeasurable {
             .ok()
             .flatten()
     }
+
+    fn box_alignment(&self) -> Option<Alignment> {
+        let Ok(mut applier) = self.applier.try_borrow_typed() else {
+            return None;
+        };
+
+        applier
+            .with_node::<LayoutNode, _>(self.node_id, |layout_node| {
+                layout_node
+                    .resolved_modifiers()
+                    .layout_properties()
+                    .box_alignment()
+            })
+            .ok()
+            .flatten()
+    }
+
+    fn matches_parent_size(&self) -> bool {
+        let Ok(mut applier) = self.applier.try_borrow_typed() else {
+            return false;
+        };
+
+        applier
+            .with_node::<LayoutNode, _>(self.node_id, |layout_node| {
+                layout_node
+                    .resolved_modifiers()
+                    .layout_properties()
+                    .match_parent_size()
+            })
+            .unwrap_or(false)
+    }
+
+    fn align_by(&self) -> Option<AlignmentLine> {
+        let Ok(mut applier) = self.applier.try_borrow_typed() else {
+            return None;
+        };
+
+        applier
+            .with_node::<LayoutNode, _>(self.node_id, |layout_node| {
+                layout_node
+                    .resolved_modifiers()
+                    .layout_properties()
+                    .align_by()
+            })
+            .ok()
+            .flatten()
+    }
 }
 
 struct LayoutChildPlaceable {
@@ -1818,6 +1969,13 @@ impl Placeable for LayoutChildPlaceable {
     fn node_id(&self) -> NodeId {
         self.node_id
     }
+
+    fn alignment_line(&self, line: AlignmentLine) -> Option<f32> {
+        self.measured
+            .borrow()
+            .as_ref()
+            .and_then(|node| node.alignment_lines.get(line))
+    }
 }
 
 fn measure_node_with_host(
@@ -2031,11 +2189,16 @@ fn build_semantics_node(
     let mut role = info.role.clone();
     let mut actions = Vec::new();
     let mut description = None;
+    let mut state_description = None;
+    let mut disabled = false;
+    let mut merge_descendants = false;
 
     // Override with semantics configuration if present
     if let Some(config) = semantics.get(&node.node_id).cloned().flatten() {
-        // Role synthesis: prefer semantics flags over widget type
-        if config.is_button {
+        // Role synthesis: prefer an explicit role, then semantics flags, over widget type
+        if let Some(explicit) = config.role {
+            role = explicit.into();
+        } else if config.is_button {
             role = SemanticsRole::Button;
         }
 
@@ -2050,15 +2213,72 @@ fn build_semantics_node(
         if let Some(desc) = config.content_description {
             description = Some(desc);
         }
+        state_description = config.state_description;
+        disabled = config.is_disabled;
+        merge_descendants = config.merge_descendants;
     }
 
-    let children = node
+    let mut children: Vec<SemanticsNode> = node
         .children
         .iter()
         .map(|child| build_semantics_node(&child.node, metadata, semantics))
         .collect();
 
-    SemanticsNode::new(node.node_id, role, actions, children, description)
+    if merge_descendants {
+        let mut labels: Vec<String> = description.into_iter().collect();
+        let mut kept = Vec::new();
+        for child in children {
+            merge_semantics_descendant(
+                child,
+                semantics,
+                &mut labels,
+                &mut state_description,
+                &mut kept,
+            );
+        }
+        description = (!labels.is_empty()).then(|| labels.join(", "));
+        children = kept;
+    }
+
+    SemanticsNode::new(
+        node.node_id,
+        role,
+        actions,
+        children,
+        description,
+        state_description,
+        disabled,
+    )
+}
+
+/// Folds `node` and its subtree into a merging ancestor.
+///
+/// Labels are collected in reading order and the first state description is
+/// kept. Nodes merging their own descendants are not folded and land in `kept`.
+fn merge_semantics_descendant(
+    node: SemanticsNode,
+    semantics: &HashMap<NodeId, Option<SemanticsConfiguration>>,
+    labels: &mut Vec<String>,
+    state_description: &mut Option<String>,
+    kept: &mut Vec<SemanticsNode>,
+) {
+    let merges_own = semantics
+        .get(&node.node_id)
+        .and_then(Option::as_ref)
+        .is_some_and(|config| config.merge_descendants);
+    if merges_own {
+        kept.push(node);
+        return;
+    }
+    if let Some(label) = node.label() {
+        labels.push(label.to_owned());
+    }
+    if state_description.is_none() {
+        *state_description = node.state_description;
+    }
+    for child in node.children {
+        merge_semantics_descendant(child, semantics, labels, state_description, kept);
+    }
 }
 
 fn build_layout_tree_from_metadata(
@@ -2125,6 +2345,11 @@ fn layout_kind_from_metadata(_node_id: NodeId, info: &RuntimeNodeMetadata) -> La
                 .unwrap_or_else(|| Rc::new(RefCell::new(|| {})));
             LayoutNodeKind::Button { on_click: handler }
         }
+        SemanticsRole::Checkbox
+        | SemanticsRole::Switch
+        | SemanticsRole::RadioButton
+        | SemanticsRole::Tab
+        | SemanticsRole::Image => LayoutNodeKind::Layout,
         SemanticsRole::Unknown => LayoutNodeKind::Unknown,
     }
 }
@@ -2142,12 +2367,13 @@ fn subtract_padding(constraints: Constraints, padding: EdgeInsets) -> Constraint
     if max_height.is_finite() {
         max_height = (max_height - vertical).max(0.0);
     }
-    normalize_constraints(Constraints {
+    Constraints {
         min_width,
         max_width,
         min_height,
         max_height,
-    })
+    }
+    .normalized()
 }
 
 #[cfg(test)]
@@ -2225,16 +2451,6 @@ fn clamp_dimension(value: f32, min: f32, max: f32) -> f32 {
     result
 }
 
-fn normalize_constraints(mut constraints: Constraints) -> Constraints {
-    if constraints.max_width < constraints.min_width {
-        constraints.max_width = constraints.min_width;
-    }
-    if constraints.max_height < constraints.min_height {
-        constraints.max_height = constraints.min_height;
-    }
-    constraints
-}
-
 #[cfg(test)]
 #[path = "tests/layout_tests.rs"]
 mod tests;
@@ -5,6 +5,7 @@ use cranpose_ui_layout::{
     Axis, Constraints, FlexParentData, MeasurePolicy, MeasureResult, Placement,
 };
 use smallvec::SmallVec;
+use std::ops::Range;
 
 /// MeasurePolicy for Box layout - overlays children according to alignment.
 #[derive(Clone, Debug, PartialEq)]
@@ -43,32 +44,30 @@ impl MeasurePolicy for BoxMeasurePolicy {
         let mut max_height = 0.0_f32;
         let mut placeables = Vec::with_capacity(measurables.len());
 
+        // Children matching the parent size wait until the box size is known
         for measurable in measurables {
+            if measurable.matches_parent_size() {
+                placeables.push(None);
+                continue;
+            }
             let placeable = measurable.measure(child_constraints);
             max_width = max_width.max(placeable.width());
             max_height = max_height.max(placeable.height());
-            placeables.push(placeable);
+            placeables.push(Some(placeable));
         }
 
         let width = max_width.clamp(constraints.min_width, constraints.max_width);
         let height = max_height.clamp(constraints.min_height, constraints.max_height);
 
+        // Placing in composition order keeps later children on top
         let mut placements = Vec::with_capacity(placeables.len());
-        for placeable in placeables {
-            let child_width = placeable.width();
-            let child_height = placeable.height();
-
-            let x = match self.content_alignment.horizontal {
-                HorizontalAlignment::Start => 0.0,
-                HorizontalAlignment::CenterHorizontally => ((width - child_width) / 2.0).max(0.0),
-                HorizontalAlignment::End => (width - child_width).max(0.0),
-            };
-
-            let y = match self.content_alignment.vertical {
-                VerticalAlignment::Top => 0.0,
-                VerticalAlignment::CenterVertically => ((height - child_height) / 2.0).max(0.0),
-                VerticalAlignment::Bottom => (height - child_height).max(0.0),
-            };
+        for (measurable, placeable) in measurables.iter().zip(placeables) {
+            let placeable =
+                placeable.unwrap_or_else(|| measurable.measure(Constraints::tight(width, height)));
+            // Per-child `Modifier::align` overrides the box's content alignment
+            let alignment = measurable.box_alignment().unwrap_or(self.content_alignment);
+            let x = alignment.horizontal.align(width, placeable.width());
+            let y = alignment.vertical.align(height, placeable.height());
 
             placeable.place(x, y);
             placements.push(Placement::new(placeable.node_id(), x, y, 0));
@@ -140,9 +139,11 @@ impl MeasurePolicy for BoxMeasurePolicy {
 ///
 /// When the main axis is bounded and children have weights:
 /// 1. Fixed children (no weight) are measured first
-/// 2. Remaining space is distributed proportionally to weights
-/// 3. Each weighted child gets: `remaining * (weight / total_weight)`
-/// 4. If `fill=true`, child gets tight constraints; if `fill=false`, loose constraints
+/// 2. Their main-axis sizes and the arrangement spacing are subtracted from the available space
+/// 3. The remainder is distributed proportionally to weights: `remaining * (weight / total_weight)`
+/// 4. A child whose min intrinsic main-axis size exceeds its share is clamped to that minimum,
+///    and the other weighted children split what is left
+/// 5. If `fill=true`, child gets tight constraints; if `fill=false`, loose constraints
 ///
 /// When the main axis is unbounded, weights are ignored (all children wrap content).
 #[derive(Clone, Debug, PartialEq)]
@@ -300,6 +301,139 @@ impl FlexMeasurePolicy {
             _ => 0.0,
         }
     }
+
+    /// Total spacing between `child_count` children.
+    fn total_spacing(&self, child_count: usize) -> f32 {
+        self.get_spacing() * child_count.saturating_sub(1) as f32
+    }
+
+    /// Intrinsic size along the main axis for the given cross-axis size.
+    ///
+    /// Fixed children are summed. Weighted children need enough space per unit of
+    /// weight for every one of them to reach its own intrinsic size.
+    ///
+    /// Matches Kotlin: `intrinsicMainAxisSize` in RowColumnImpl.kt
+    fn intrinsic_main_axis_size(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        cross_axis_available: f32,
+        main_axis_size: impl Fn(&dyn Measurable, f32) -> f32,
+    ) -> f32 {
+        let mut fixed_space = 0.0_f32;
+        let mut total_weight = 0.0_f32;
+        let mut weight_unit_space = 0.0_f32;
+        for measurable in measurables {
+            let size = main_axis_size(measurable.as_ref(), cross_axis_available);
+            match measurable
+                .flex_parent_data()
+                .filter(FlexParentData::has_weight)
+            {
+                Some(data) => {
+                    total_weight += data.weight;
+                    weight_unit_space = weight_unit_space.max(size / data.weight);
+                }
+                None => fixed_space += size,
+            }
+        }
+        weight_unit_space * total_weight + fixed_space + self.total_spacing(measurables.len())
+    }
+
+    /// Intrinsic size along the cross axis for the given main-axis size.
+    ///
+    /// Each child is asked for its cross-axis size at the main-axis space it would
+    /// get during measurement: fixed children take their max intrinsic size, and
+    /// weighted children share what remains.
+    ///
+    /// Matches Kotlin: `intrinsicCrossAxisSize` in RowColumnImpl.kt
+    fn intrinsic_cross_axis_size(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        main_axis_available: f32,
+        main_axis_size: impl Fn(&dyn Measurable, f32) -> f32,
+        cross_axis_size: impl Fn(&dyn Measurable, f32) -> f32,
+    ) -> f32 {
+        let mut fixed_space = 0.0_f32;
+        let mut total_weight = 0.0_f32;
+        let mut cross_axis_max = 0.0_f32;
+        for measurable in measurables {
+            match measurable
+                .flex_parent_data()
+                .filter(FlexParentData::has_weight)
+            {
+                Some(data) => total_weight += data.weight,
+                None => {
+                    let remaining = (main_axis_available - fixed_space).max(0.0);
+                    let main_size =
+                        main_axis_size(measurable.as_ref(), f32::INFINITY).min(remaining);
+                    fixed_space += main_size;
+                    cross_axis_max =
+                        cross_axis_max.max(cross_axis_size(measurable.as_ref(), main_size));
+                }
+            }
+        }
+
+        if total_weight > 0.0 {
+            let weight_unit_space = if main_axis_available.is_finite() {
+                (main_axis_available - fixed_space - self.total_spacing(measurables.len())).max(0.0)
+                    / total_weight
+            } else {
+                f32::INFINITY
+            };
+            for measurable in measurables {
+                if let Some(data) = measurable
+                    .flex_parent_data()
+                    .filter(FlexParentData::has_weight)
+                {
+                    let main_size = weight_unit_space * data.weight;
+                    cross_axis_max =
+                        cross_axis_max.max(cross_axis_size(measurable.as_ref(), main_size));
+                }
+            }
+        }
+        cross_axis_max
+    }
+}
+
+/// Splits `remaining` between weighted children given as `(weight, minimum)` pairs.
+///
+/// Children whose proportional share is below their minimum are frozen at the
+/// minimum and removed from the pool; the others are redistributed until every
+/// share satisfies its minimum.
+fn distribute_weighted_space(remaining: f32, children: &[(f32, f32)]) -> SmallVec<[f32; 8]> {
+    let mut frozen: SmallVec<[Option<f32>; 8]> = SmallVec::from_elem(None, children.len());
+    loop {
+        let frozen_space: f32 = frozen.iter().flatten().sum();
+        let free_space = (remaining - frozen_space).max(0.0);
+        let free_weight: f32 = children
+            .iter()
+            .zip(&frozen)
+            .filter(|(_, size)| size.is_none())
+            .map(|(&(weight, _), _)| weight)
+            .sum();
+        let share = |weight: f32| {
+            if free_weight > 0.0 {
+                free_space * (weight / free_weight)
+            } else {
+                0.0
+            }
+        };
+
+        let mut froze_any = false;
+        for (&(weight, minimum), size) in children.iter().zip(frozen.iter_mut()) {
+            if size.is_none() && minimum > share(weight) {
+                *size = Some(minimum);
+                froze_any = true;
+            }
+        }
+
+        if !froze_any {
+            return children
+                .iter()
+                .zip(&frozen)
+                .map(|(&(weight, _), size)| size.unwrap_or_else(|| share(weight)))
+                .collect();
+        }
+    }
 }
 
 impl MeasurePolicy for FlexMeasurePolicy {
@@ -315,7 +449,6 @@ impl MeasurePolicy for FlexMeasurePolicy {
 
         let (min_main, max_main, min_cross, max_cross) = self.get_axis_constraints(constraints);
         let main_axis_bounded = max_main.is_finite();
-        let spacing = self.get_spacing();
 
         // Separate children into fixed and weighted
         let mut fixed_children: SmallVec<[usize; 8]> = SmallVec::new();
@@ -352,12 +485,7 @@ impl MeasurePolicy for FlexMeasurePolicy {
         }
 
         // Calculate spacing
-        let num_children = measurables.len();
-        let total_spacing = if num_children > 1 {
-            spacing * (num_children - 1) as f32
-        } else {
-            0.0
-        };
+        let total_spacing = self.total_spacing(measurables.len());
 
         // Measure weighted children
         if !weighted_children.is_empty() {
@@ -366,18 +494,23 @@ impl MeasurePolicy for FlexMeasurePolicy {
                 let used_main = fixed_main_size + total_spacing;
                 let remaining_main = (max_main - used_main).max(0.0);
 
-                // Calculate total weight
-                let total_weight: f32 = weighted_children.iter().map(|(_, data)| data.weight).sum();
+                // Each weighted child's minimum is its min intrinsic main-axis size
+                let weights_and_minimums: SmallVec<[(f32, f32); 8]> = weighted_children
+                    .iter()
+                    .map(|&(idx, parent_data)| {
+                        let minimum = match self.axis {
+                            Axis::Horizontal => measurables[idx].min_intrinsic_width(max_cross),
+                            Axis::Vertical => measurables[idx].min_intrinsic_height(max_cross),
+                        };
+                        (parent_data.weight, minimum)
+                    })
+                    .collect();
+                let allocations = distribute_weighted_space(remaining_main, &weights_and_minimums);
 
                 // Measure each weighted child with its allocated space
-                for &(idx, parent_data) in &weighted_children {
+                for (&(idx, parent_data), &allocated) in weighted_children.iter().zip(&allocations)
+                {
                     let measurable = &measurables[idx];
-                    let allocated = if total_weight > 0.0 {
-                        remaining_main * (parent_data.weight / total_weight)
-                    } else {
-                        0.0
-                    };
-
                     let weighted_constraints = if parent_data.fill {
                         // fill=true: child gets tight constraints on main axis
                         self.make_constraints(allocated, allocated, 0.0, max_cross)
@@ -411,6 +544,30 @@ impl MeasurePolicy for FlexMeasurePolicy {
             .map(|p| p.expect("placeable missing"))
             .collect();
 
+        // In a Row, children aligned by a line (e.g. a text baseline) are shifted so their
+        // lines meet at the lowest of them; children without the line keep the row alignment
+        let child_lines: SmallVec<[Option<f32>; 8]> = measurables
+            .iter()
+            .zip(&placeables)
+            .map(|(measurable, placeable)| match self.axis {
+                Axis::Horizontal => measurable
+                    .align_by()
+                    .and_then(|line| placeable.alignment_line(line)),
+                Axis::Vertical => None,
+            })
+            .collect();
+        let mut line_position: Option<f32> = None;
+        let mut below_line = 0.0_f32;
+        for (line, placeable) in child_lines.iter().zip(&placeables) {
+            if let Some(line) = *line {
+                line_position = Some(line_position.map_or(line, |max| max.max(line)));
+                below_line = below_line.max(placeable.height() - line);
+            }
+        }
+        if let Some(line_position) = line_position {
+            max_cross_size = max_cross_size.max(line_position + below_line);
+        }
+
         // Calculate total main size
         let total_main: f32 = placeables
             .iter()
@@ -442,11 +599,18 @@ impl MeasurePolicy for FlexMeasurePolicy {
 
         // Place children
         let mut placements: SmallVec<[Placement; 8]> = SmallVec::with_capacity(placeables.len());
-        for (placeable, main_pos) in placeables.into_iter().zip(main_positions.into_iter()) {
+        for ((placeable, main_pos), line) in placeables
+            .into_iter()
+            .zip(main_positions.into_iter())
+            .zip(child_lines)
+        {
             let child_cross = self.get_cross_axis_size(placeable.width(), placeable.height());
-            let cross_pos = self
-                .cross_axis_alignment
-                .align(container_cross, child_cross);
+            let cross_pos = match (line, line_position) {
+                (Some(line), Some(line_position)) => line_position - line,
+                _ => self
+                    .cross_axis_alignment
+                    .align(container_cross, child_cross),
+            };
 
             let (x, y) = match self.axis {
                 Axis::Horizontal => (main_pos, cross_pos),
@@ -470,109 +634,339 @@ impl MeasurePolicy for FlexMeasurePolicy {
     }
 
     fn min_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
-        let spacing = self.get_spacing();
-        let total_spacing = if measurables.len() > 1 {
-            spacing * (measurables.len() - 1) as f32
-        } else {
-            0.0
-        };
+        match self.axis {
+            // Row: widths add up along the main axis
+            Axis::Horizontal => {
+                self.intrinsic_main_axis_size(measurables, height, |m, h| m.min_intrinsic_width(h))
+            }
+            // Column: widest child at the height each child would receive
+            Axis::Vertical => self.intrinsic_cross_axis_size(
+                measurables,
+                height,
+                |m, w| m.max_intrinsic_height(w),
+                |m, h| m.min_intrinsic_width(h),
+            ),
+        }
+    }
 
+    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
         match self.axis {
             Axis::Horizontal => {
-                // Row: sum of children's min intrinsic widths + spacing
-                measurables
-                    .iter()
-                    .map(|m| m.min_intrinsic_width(height))
-                    .sum::<f32>()
-                    + total_spacing
+                self.intrinsic_main_axis_size(measurables, height, |m, h| m.max_intrinsic_width(h))
             }
+            Axis::Vertical => self.intrinsic_cross_axis_size(
+                measurables,
+                height,
+                |m, w| m.max_intrinsic_height(w),
+                |m, h| m.max_intrinsic_width(h),
+            ),
+        }
+    }
+
+    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        match self.axis {
+            // Row: tallest child at the width each child would receive
+            Axis::Horizontal => self.intrinsic_cross_axis_size(
+                measurables,
+                width,
+                |m, h| m.max_intrinsic_width(h),
+                |m, w| m.min_intrinsic_height(w),
+            ),
+            // Column: heights add up along the main axis
             Axis::Vertical => {
-                // Column: max of children's min intrinsic widths
-                measurables
-                    .iter()
-                    .map(|m| m.min_intrinsic_width(height))
-                    .fold(0.0, f32::max)
+                self.intrinsic_main_axis_size(measurables, width, |m, w| m.min_intrinsic_height(w))
             }
         }
     }
 
-    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
-        let spacing = self.get_spacing();
-        let total_spacing = if measurables.len() > 1 {
-            spacing * (measurables.len() - 1) as f32
-        } else {
-            0.0
-        };
+    fn max_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        match self.axis {
+            Axis::Horizontal => self.intrinsic_cross_axis_size(
+                measurables,
+                width,
+                |m, h| m.max_intrinsic_width(h),
+                |m, w| m.max_intrinsic_height(w),
+            ),
+            Axis::Vertical => {
+                self.intrinsic_main_axis_size(measurables, width, |m, w| m.max_intrinsic_height(w))
+            }
+        }
+    }
+}
+
+/// MeasurePolicy for FlowRow/FlowColumn - wraps children onto new lines.
+///
+/// Children are placed along the main axis until the next one does not fit or
+/// the line already holds `max_items_in_each_line`; the rest continue on a new
+/// line below (FlowRow) or beside (FlowColumn) it. Each line is arranged with
+/// `main_axis_arrangement` and the lines with `cross_axis_arrangement`; children
+/// sit at the start of their line's cross axis.
+///
+/// Matches Kotlin: `FlowRow` / `FlowColumn` in FlowLayout.kt
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowMeasurePolicy {
+    /// Main axis direction (Horizontal for FlowRow, Vertical for FlowColumn)
+    pub axis: Axis,
+    /// Arrangement of the children within each line
+    pub main_axis_arrangement: LinearArrangement,
+    /// Arrangement of the lines along the cross axis
+    pub cross_axis_arrangement: LinearArrangement,
+    /// Maximum number of children in a line
+    pub max_items_in_each_line: usize,
+}
+
+impl FlowMeasurePolicy {
+    pub fn new(
+        axis: Axis,
+        main_axis_arrangement: LinearArrangement,
+        cross_axis_arrangement: LinearArrangement,
+        max_items_in_each_line: usize,
+    ) -> Self {
+        Self {
+            axis,
+            main_axis_arrangement,
+            cross_axis_arrangement,
+            max_items_in_each_line,
+        }
+    }
 
+    /// Converts between (width, height) and (main, cross); the mapping is its own inverse.
+    fn swap_for_axis(&self, width: f32, height: f32) -> (f32, f32) {
         match self.axis {
-            Axis::Horizontal => {
-                // Row: sum of children's max intrinsic widths + spacing
-                measurables
-                    .iter()
-                    .map(|m| m.max_intrinsic_width(height))
-                    .sum::<f32>()
-                    + total_spacing
+            Axis::Horizontal => (width, height),
+            Axis::Vertical => (height, width),
+        }
+    }
+
+    fn spacing(arrangement: LinearArrangement) -> f32 {
+        match arrangement {
+            LinearArrangement::SpacedBy(value) => value.max(0.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Splits children of the given main-axis sizes into lines that fit in `max_main`.
+    ///
+    /// A line always takes at least one child, even one longer than `max_main`.
+    fn break_lines(&self, main_sizes: &[f32], max_main: f32) -> SmallVec<[Range<usize>; 4]> {
+        let spacing = Self::spacing(self.main_axis_arrangement);
+        let max_items = self.max_items_in_each_line.max(1);
+        let mut lines = SmallVec::new();
+        let mut start = 0;
+        let mut line_main = 0.0_f32;
+        for (index, &size) in main_sizes.iter().enumerate() {
+            let count = index - start;
+            if count > 0 && (count == max_items || line_main + spacing + size > max_main) {
+                lines.push(start..index);
+                start = index;
             }
-            Axis::Vertical => {
-                // Column: max of children's max intrinsic widths
-                measurables
+            line_main = if index == start {
+                size
+            } else {
+                line_main + spacing + size
+            };
+        }
+        if start < main_sizes.len() {
+            lines.push(start..main_sizes.len());
+        }
+        lines
+    }
+
+    /// Length of `line` along the main axis, spacing included.
+    fn line_main_size(&self, main_sizes: &[f32], line: &Range<usize>) -> f32 {
+        main_sizes[line.clone()].iter().sum::<f32>()
+            + Self::spacing(self.main_axis_arrangement) * line.len().saturating_sub(1) as f32
+    }
+
+    /// Intrinsic size along the main axis.
+    ///
+    /// The minimum puts every child on its own line; the maximum only wraps
+    /// at `max_items_in_each_line`.
+    fn intrinsic_main_axis_size(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        wrap_every_child: bool,
+        main_axis_size: impl Fn(&dyn Measurable) -> f32,
+    ) -> f32 {
+        let sizes: SmallVec<[f32; 8]> = measurables
+            .iter()
+            .map(|measurable| main_axis_size(measurable.as_ref()))
+            .collect();
+        if wrap_every_child {
+            return sizes.iter().copied().fold(0.0, f32::max);
+        }
+        self.break_lines(&sizes, f32::INFINITY)
+            .iter()
+            .map(|line| self.line_main_size(&sizes, line))
+            .fold(0.0, f32::max)
+    }
+
+    /// Intrinsic size along the cross axis when lines wrap at `main_axis_available`.
+    ///
+    /// Children take their max intrinsic main-axis size to break the lines,
+    /// then each line is as thick as its thickest child.
+    fn intrinsic_cross_axis_size(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        main_axis_available: f32,
+        main_axis_size: impl Fn(&dyn Measurable) -> f32,
+        cross_axis_size: impl Fn(&dyn Measurable, f32) -> f32,
+    ) -> f32 {
+        let sizes: SmallVec<[f32; 8]> = measurables
+            .iter()
+            .map(|measurable| main_axis_size(measurable.as_ref()).min(main_axis_available))
+            .collect();
+        let lines = self.break_lines(&sizes, main_axis_available);
+        let lines_cross: f32 = lines
+            .iter()
+            .map(|line| {
+                line.clone()
+                    .map(|index| cross_axis_size(measurables[index].as_ref(), sizes[index]))
+                    .fold(0.0, f32::max)
+            })
+            .sum();
+        lines_cross
+            + Self::spacing(self.cross_axis_arrangement) * lines.len().saturating_sub(1) as f32
+    }
+}
+
+impl MeasurePolicy for FlowMeasurePolicy {
+    fn measure(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        constraints: Constraints,
+    ) -> MeasureResult {
+        if measurables.is_empty() {
+            let (width, height) = constraints.constrain(0.0, 0.0);
+            return MeasureResult::new(crate::modifier::Size { width, height }, vec![]);
+        }
+
+        let (min_main, min_cross) =
+            self.swap_for_axis(constraints.min_width, constraints.min_height);
+        let (max_main, max_cross) =
+            self.swap_for_axis(constraints.max_width, constraints.max_height);
+
+        // Children get loose constraints on both axes (min = 0)
+        let child_constraints = Constraints::loose(constraints.max_width, constraints.max_height);
+        let placeables: SmallVec<[Box<dyn cranpose_ui_layout::Placeable>; 8]> = measurables
+            .iter()
+            .map(|measurable| measurable.measure(child_constraints))
+            .collect();
+        let (main_sizes, cross_sizes): (SmallVec<[f32; 8]>, SmallVec<[f32; 8]>) = placeables
+            .iter()
+            .map(|placeable| self.swap_for_axis(placeable.width(), placeable.height()))
+            .unzip();
+
+        let lines = self.break_lines(&main_sizes, max_main);
+        let line_main_sizes: SmallVec<[f32; 4]> = lines
+            .iter()
+            .map(|line| self.line_main_size(&main_sizes, line))
+            .collect();
+        let line_cross_sizes: SmallVec<[f32; 4]> = lines
+            .iter()
+            .map(|line| {
+                cross_sizes[line.clone()]
                     .iter()
-                    .map(|m| m.max_intrinsic_width(height))
+                    .copied()
                     .fold(0.0, f32::max)
+            })
+            .collect();
+
+        let content_main = line_main_sizes.iter().copied().fold(0.0, f32::max);
+        let content_cross = line_cross_sizes.iter().sum::<f32>()
+            + Self::spacing(self.cross_axis_arrangement) * lines.len().saturating_sub(1) as f32;
+        let container_main = content_main.clamp(min_main, max_main);
+        let container_cross = content_cross.clamp(min_cross, max_cross);
+
+        // If we overflow, use Start arrangement to avoid negative spacing
+        let lines_arrangement = if content_cross > container_cross {
+            LinearArrangement::Start
+        } else {
+            self.cross_axis_arrangement
+        };
+        let mut line_positions: SmallVec<[f32; 4]> = SmallVec::from_elem(0.0, lines.len());
+        lines_arrangement.arrange(container_cross, &line_cross_sizes, &mut line_positions);
+
+        let mut main_positions: SmallVec<[f32; 8]> = SmallVec::from_elem(0.0, placeables.len());
+        for (line, &line_main) in lines.iter().zip(&line_main_sizes) {
+            let arrangement = if line_main > container_main {
+                LinearArrangement::Start
+            } else {
+                self.main_axis_arrangement
+            };
+            arrangement.arrange(
+                container_main,
+                &main_sizes[line.clone()],
+                &mut main_positions[line.clone()],
+            );
+        }
+
+        let mut placements = Vec::with_capacity(placeables.len());
+        for (line, &cross_pos) in lines.iter().zip(&line_positions) {
+            for index in line.clone() {
+                let (x, y) = self.swap_for_axis(main_positions[index], cross_pos);
+                let placeable = &placeables[index];
+                placeable.place(x, y);
+                placements.push(Placement::new(placeable.node_id(), x, y, 0));
             }
         }
+
+        let (width, height) = self.swap_for_axis(container_main, container_cross);
+        MeasureResult::new(crate::modifier::Size { width, height }, placements)
     }
 
-    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
-        let spacing = self.get_spacing();
-        let total_spacing = if measurables.len() > 1 {
-            spacing * (measurables.len() - 1) as f32
-        } else {
-            0.0
-        };
+    fn min_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
+        match self.axis {
+            Axis::Horizontal => {
+                self.intrinsic_main_axis_size(measurables, true, |m| m.min_intrinsic_width(height))
+            }
+            Axis::Vertical => self.intrinsic_cross_axis_size(
+                measurables,
+                height,
+                |m| m.max_intrinsic_height(f32::INFINITY),
+                |m, h| m.min_intrinsic_width(h),
+            ),
+        }
+    }
 
+    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
         match self.axis {
             Axis::Horizontal => {
-                // Row: max of children's min intrinsic heights
-                measurables
-                    .iter()
-                    .map(|m| m.min_intrinsic_height(width))
-                    .fold(0.0, f32::max)
+                self.intrinsic_main_axis_size(measurables, false, |m| m.max_intrinsic_width(height))
             }
+            Axis::Vertical => self.intrinsic_cross_axis_size(
+                measurables,
+                height,
+                |m| m.max_intrinsic_height(f32::INFINITY),
+                |m, h| m.max_intrinsic_width(h),
+            ),
+        }
+    }
+
+    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        match self.axis {
+            Axis::Horizontal => self.intrinsic_cross_axis_size(
+                measurables,
+                width,
+                |m| m.max_intrinsic_width(f32::INFINITY),
+                |m, w| m.min_intrinsic_height(w),
+            ),
             Axis::Vertical => {
-                // Column: sum of children's min intrinsic heights + spacing
-                measurables
-                    .iter()
-                    .map(|m| m.min_intrinsic_height(width))
-                    .sum::<f32>()
-                    + total_spacing
+                self.intrinsic_main_axis_size(measurables, true, |m| m.min_intrinsic_height(width))
             }
         }
     }
 
     fn max_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
-        let spacing = self.get_spacing();
-        let total_spacing = if measurables.len() > 1 {
-            spacing * (measurables.len() - 1) as f32
-        } else {
-            0.0
-        };
-
         match self.axis {
-            Axis::Horizontal => {
-                // Row: max of children's max intrinsic heights
-                measurables
-                    .iter()
-                    .map(|m| m.max_intrinsic_height(width))
-                    .fold(0.0, f32::max)
-            }
+            Axis::Horizontal => self.intrinsic_cross_axis_size(
+                measurables,
+                width,
+                |m| m.max_intrinsic_width(f32::INFINITY),
+                |m, w| m.max_intrinsic_height(w),
+            ),
             Axis::Vertical => {
-                // Column: sum of children's max intrinsic heights + spacing
-                measurables
-                    .iter()
-                    .map(|m| m.max_intrinsic_height(width))
-                    .sum::<f32>()
-                    + total_spacing
+                self.intrinsic_main_axis_size(measurables, false, |m| m.max_intrinsic_height(width))
             }
         }
     }
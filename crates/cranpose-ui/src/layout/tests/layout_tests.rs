@@ -915,6 +915,34 @@ fn flex_parent_data_uses_resolved_weight() {
     assert!(parent_data.fill);
 }
 
+#[test]
+fn size_and_fill_max_width_measure_outer_first() -> Result<(), NodeError> {
+    let cases = [
+        (
+            Modifier::empty()
+                .size(Size::new(100.0, 50.0))
+                .fill_max_width(),
+            Size::new(100.0, 50.0),
+        ),
+        (
+            Modifier::empty()
+                .fill_max_width()
+                .size(Size::new(100.0, 50.0)),
+            Size::new(300.0, 50.0),
+        ),
+    ];
+    for (modifier, expected) in cases {
+        let mut applier = MemoryApplier::new();
+        let node_id = applier.create(Box::new(LayoutNode::new(
+            modifier,
+            Rc::new(LeafMeasurePolicy::new(Size::new(10.0, 10.0))),
+        )));
+        let measurements = measure_layout(&mut applier, node_id, Size::new(300.0, 300.0))?;
+        assert_eq!(measurements.root_size(), expected);
+    }
+    Ok(())
+}
+
 #[test]
 fn semantics_tree_derives_roles_from_configuration() -> Result<(), NodeError> {
     use crate::layout::SemanticsRole;
@@ -953,6 +981,29 @@ fn semantics_tree_derives_roles_from_configuration() -> Result<(), NodeError> {
     Ok(())
 }
 
+#[test]
+fn disabled_clickable_reports_disabled_semantics() -> Result<(), NodeError> {
+    let mut applier = MemoryApplier::new();
+
+    let enabled_id = applier.create(Box::new(LayoutNode::new(
+        Modifier::empty().clickable(|_| {}),
+        Rc::new(MaxSizePolicy),
+    )));
+    let measurements = measure_layout(&mut applier, enabled_id, Size::new(100.0, 100.0))?;
+    assert!(!measurements.semantics_tree().root().disabled);
+
+    let disabled_id = applier.create(Box::new(LayoutNode::new(
+        Modifier::empty().clickable_enabled(false, |_| {}),
+        Rc::new(MaxSizePolicy),
+    )));
+    let measurements = measure_layout(&mut applier, disabled_id, Size::new(100.0, 100.0))?;
+    let nodes = measurements.semantics_tree().to_accessibility_nodes();
+    assert!(nodes[0].disabled);
+    assert!(nodes[0].clickable);
+
+    Ok(())
+}
+
 #[test]
 fn semantics_configuration_merges_multiple_modifiers() -> Result<(), NodeError> {
     let mut applier = MemoryApplier::new();
@@ -1205,3 +1256,111 @@ fn measure_layout_error_preserves_applier_and_slots() -> Result<(), NodeError> {
 
     Ok(())
 }
+
+#[test]
+fn dp_padding_scales_with_provided_density() -> Result<(), NodeError> {
+    use crate::{local_density, run_test_composition, Density, Dp};
+    use cranpose_core::CompositionLocalProvider;
+
+    let measure_padded = |density: f32| -> Result<Size, NodeError> {
+        let modifier = RefCell::new(None);
+        run_test_composition(|| {
+            CompositionLocalProvider(
+                vec![local_density().provides(Density::new(density))],
+                || {
+                    *modifier.borrow_mut() = Some(Modifier::empty().padding(Dp(5.0)));
+                },
+            );
+        });
+        let mut applier = MemoryApplier::new();
+        let node = LayoutNode::new(
+            modifier.take().expect("modifier"),
+            Rc::new(LeafMeasurePolicy::new(Size {
+                width: 10.0,
+                height: 10.0,
+            })),
+        );
+        let node_id = applier.create(Box::new(node));
+        let measurements = measure_layout(
+            &mut applier,
+            node_id,
+            Size {
+                width: 100.0,
+                height: 100.0,
+            },
+        )?;
+        Ok(measurements.root_size())
+    };
+
+    let base = measure_padded(1.0)?;
+    let doubled = measure_padded(2.0)?;
+    assert_eq!(base.width - 10.0, 10.0);
+    assert_eq!(doubled.width - 10.0, 2.0 * (base.width - 10.0));
+    assert_eq!(doubled.height - 10.0, 2.0 * (base.height - 10.0));
+    Ok(())
+}
+
+#[test]
+fn subcomposed_children_keep_the_size_their_policy_measured() -> Result<(), NodeError> {
+    use crate::primitives::{Box as BoxLayout, BoxSpec};
+    use crate::subcompose_layout::{SubcomposeLayoutScope, SubcomposeMeasureScope};
+    use crate::widgets::SubcomposeLayout;
+    use cranpose_core::{location_key, Composition, SlotId};
+
+    let child_holder: Rc<RefCell<Option<NodeId>>> = Rc::default();
+    let child_out = Rc::clone(&child_holder);
+    let mut composition = Composition::new(MemoryApplier::new());
+    composition
+        .render(location_key(file!(), line!(), column!()), move || {
+            let child_out = Rc::clone(&child_out);
+            SubcomposeLayout(Modifier::empty(), move |scope, constraints| {
+                let child_out = Rc::clone(&child_out);
+                let children = scope.subcompose(SlotId::new(0), move || {
+                    let child = BoxLayout(
+                        Modifier::empty().fill_max_width().height(10.0),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                    *child_out.borrow_mut() = Some(child);
+                });
+                // A lane narrower than the layout, as lazy grids measure their items
+                let placements = children
+                    .into_iter()
+                    .map(|child| {
+                        let node_id = child.node_id();
+                        let lane = Constraints {
+                            min_width: 0.0,
+                            max_width: 40.0,
+                            min_height: 0.0,
+                            max_height: constraints.max_height,
+                        };
+                        scope.measure(child, lane);
+                        Placement::new(node_id, 0.0, 0.0, 0)
+                    })
+                    .collect::<Vec<_>>();
+                scope.layout(constraints.max_width, 10.0, placements)
+            });
+        })
+        .expect("initial render");
+    let root = composition.root().expect("subcompose root");
+    let handle = composition.runtime_handle();
+    composition.applier_mut().set_runtime_handle(handle);
+
+    let layout = composition.applier_mut().compute_layout(
+        root,
+        Size {
+            width: 200.0,
+            height: 100.0,
+        },
+    )?;
+
+    let child = child_holder.borrow().expect("subcomposed child");
+    let placed = layout
+        .root()
+        .children
+        .iter()
+        .find(|node| node.node_id == child)
+        .expect("placed child");
+    assert_eq!(placed.rect.width, 40.0);
+    Ok(())
+}
@@ -137,3 +137,284 @@ fn row_measure_policy_sums_widths() {
     assert_eq!(result.placements[0].x, 0.0);
     assert_eq!(result.placements[1].x, 40.0);
 }
+
+/// Weighted child that takes the main-axis size it is given, down to a minimum width.
+struct WeightedMeasurable {
+    weight: f32,
+    min_width: f32,
+    node_id: usize,
+}
+
+impl WeightedMeasurable {
+    fn new(weight: f32, min_width: f32, node_id: usize) -> Self {
+        Self {
+            weight,
+            min_width,
+            node_id,
+        }
+    }
+}
+
+impl Measurable for WeightedMeasurable {
+    fn measure(&self, constraints: Constraints) -> Box<dyn Placeable> {
+        Box::new(MockPlaceable {
+            width: constraints.min_width.max(self.min_width),
+            height: 10.0,
+            node_id: self.node_id,
+        })
+    }
+
+    fn min_intrinsic_width(&self, _height: f32) -> f32 {
+        self.min_width
+    }
+
+    fn max_intrinsic_width(&self, _height: f32) -> f32 {
+        self.min_width
+    }
+
+    fn min_intrinsic_height(&self, _width: f32) -> f32 {
+        10.0
+    }
+
+    fn max_intrinsic_height(&self, _width: f32) -> f32 {
+        10.0
+    }
+
+    fn flex_parent_data(&self) -> Option<FlexParentData> {
+        Some(FlexParentData::new(self.weight, true))
+    }
+}
+
+fn measure_weighted_row(measurables: &[Box<dyn Measurable>], max_width: f32) -> MeasureResult {
+    FlexMeasurePolicy::row(LinearArrangement::SpacedBy(10.0), VerticalAlignment::Top).measure(
+        measurables,
+        Constraints {
+            min_width: 0.0,
+            max_width,
+            min_height: 0.0,
+            max_height: 100.0,
+        },
+    )
+}
+
+#[test]
+fn row_equal_weights_split_remaining_space_evenly() {
+    let measurables: Vec<Box<dyn Measurable>> = vec![
+        Box::new(MockMeasurable::new(40.0, 10.0, 1)),
+        Box::new(WeightedMeasurable::new(1.0, 0.0, 2)),
+        Box::new(WeightedMeasurable::new(1.0, 0.0, 3)),
+    ];
+
+    // 200 - 40 fixed - 2 * 10 spacing leaves 140 for the weighted children.
+    let result = measure_weighted_row(&measurables, 200.0);
+
+    assert_eq!(result.size.width, 200.0);
+    let xs: Vec<f32> = result.placements.iter().map(|p| p.x).collect();
+    assert_eq!(xs, vec![0.0, 50.0, 130.0]);
+}
+
+#[test]
+fn row_weights_split_remaining_space_proportionally() {
+    let measurables: Vec<Box<dyn Measurable>> = vec![
+        Box::new(WeightedMeasurable::new(2.0, 0.0, 1)),
+        Box::new(WeightedMeasurable::new(1.0, 0.0, 2)),
+    ];
+
+    let result = measure_weighted_row(&measurables, 310.0);
+
+    assert_eq!(result.size.width, 310.0);
+    assert_eq!(result.placements[0].x, 0.0);
+    assert_eq!(result.placements[1].x, 210.0);
+}
+
+#[test]
+fn row_weighted_child_minimum_is_respected_and_others_absorb_rest() {
+    let measurables: Vec<Box<dyn Measurable>> = vec![
+        Box::new(WeightedMeasurable::new(1.0, 150.0, 1)),
+        Box::new(WeightedMeasurable::new(1.0, 0.0, 2)),
+        Box::new(WeightedMeasurable::new(1.0, 0.0, 3)),
+    ];
+
+    // Even thirds of 220 would be ~73; the first child needs 150, leaving 35 each.
+    let result = measure_weighted_row(&measurables, 240.0);
+
+    assert_eq!(result.size.width, 240.0);
+    let xs: Vec<f32> = result.placements.iter().map(|p| p.x).collect();
+    assert_eq!(xs, vec![0.0, 160.0, 205.0]);
+}
+
+/// Child whose height depends on the width it gets, like wrapped text.
+struct ReflowMeasurable {
+    area: f32,
+    natural_width: f32,
+    weight: Option<f32>,
+}
+
+impl Measurable for ReflowMeasurable {
+    fn measure(&self, constraints: Constraints) -> Box<dyn Placeable> {
+        let width = self.natural_width.min(constraints.max_width);
+        Box::new(MockPlaceable {
+            width,
+            height: self.min_intrinsic_height(width),
+            node_id: 0,
+        })
+    }
+
+    fn min_intrinsic_width(&self, _height: f32) -> f32 {
+        self.natural_width
+    }
+
+    fn max_intrinsic_width(&self, _height: f32) -> f32 {
+        self.natural_width
+    }
+
+    fn min_intrinsic_height(&self, width: f32) -> f32 {
+        self.area / width.min(self.natural_width).max(1.0)
+    }
+
+    fn max_intrinsic_height(&self, width: f32) -> f32 {
+        self.min_intrinsic_height(width)
+    }
+
+    fn flex_parent_data(&self) -> Option<FlexParentData> {
+        self.weight.map(|weight| FlexParentData::new(weight, true))
+    }
+}
+
+#[test]
+fn column_intrinsic_height_sums_children_at_given_width() {
+    let policy =
+        FlexMeasurePolicy::column(LinearArrangement::SpacedBy(5.0), HorizontalAlignment::Start);
+    let measurables: Vec<Box<dyn Measurable>> = vec![
+        Box::new(MockMeasurable::new(40.0, 20.0, 1)),
+        Box::new(ReflowMeasurable {
+            area: 2000.0,
+            natural_width: 200.0,
+            weight: None,
+        }),
+    ];
+
+    // At width 100 the reflowing child is 20 tall: 20 + 5 + 20.
+    assert_eq!(policy.min_intrinsic_height(&measurables, 100.0), 45.0);
+    assert_eq!(policy.max_intrinsic_height(&measurables, 100.0), 45.0);
+    // Width intrinsics take the widest child.
+    assert_eq!(
+        policy.min_intrinsic_width(&measurables, f32::INFINITY),
+        200.0
+    );
+}
+
+#[test]
+fn row_intrinsic_height_is_tallest_child_at_its_share_of_width() {
+    let policy = FlexMeasurePolicy::row(LinearArrangement::Start, VerticalAlignment::Top);
+    let measurables: Vec<Box<dyn Measurable>> = vec![
+        Box::new(MockMeasurable::new(40.0, 30.0, 1)),
+        Box::new(ReflowMeasurable {
+            area: 1200.0,
+            natural_width: 400.0,
+            weight: Some(1.0),
+        }),
+    ];
+
+    // The weighted child only gets 100 - 40 = 60 wide, making it 20 tall.
+    assert_eq!(policy.min_intrinsic_height(&measurables, 100.0), 30.0);
+    // With 20 wide it becomes the tallest child.
+    assert_eq!(policy.min_intrinsic_height(&measurables, 60.0), 60.0);
+    // A weighted child's intrinsic width is added on top of the fixed children.
+    assert_eq!(policy.max_intrinsic_width(&measurables, 0.0), 440.0);
+}
+
+fn flow_row(max_items_in_each_row: usize) -> FlowMeasurePolicy {
+    FlowMeasurePolicy::new(
+        Axis::Horizontal,
+        LinearArrangement::SpacedBy(8.0),
+        LinearArrangement::SpacedBy(4.0),
+        max_items_in_each_row,
+    )
+}
+
+#[test]
+fn flow_row_wraps_child_that_does_not_fit() {
+    let measurables: Vec<Box<dyn Measurable>> = vec![
+        Box::new(MockMeasurable::new(40.0, 20.0, 1)),
+        Box::new(MockMeasurable::new(40.0, 30.0, 2)),
+        Box::new(MockMeasurable::new(40.0, 20.0, 3)),
+    ];
+
+    let result = flow_row(usize::MAX).measure(&measurables, Constraints::loose(100.0, 200.0));
+
+    // 40 + 8 + 40 fits in 100, a third chip would need 136.
+    let positions: Vec<_> = result
+        .placements
+        .iter()
+        .map(|placement| (placement.node_id, placement.x, placement.y))
+        .collect();
+    // The second row starts below the tallest chip of the first plus spacing.
+    assert_eq!(
+        positions,
+        vec![(1, 0.0, 0.0), (2, 48.0, 0.0), (3, 0.0, 34.0)]
+    );
+    assert_eq!(result.size.width, 88.0);
+    assert_eq!(result.size.height, 54.0);
+}
+
+#[test]
+fn flow_row_limits_items_in_each_row() {
+    let measurables: Vec<Box<dyn Measurable>> = (1..=3)
+        .map(|node_id| Box::new(MockMeasurable::new(10.0, 10.0, node_id)) as Box<dyn Measurable>)
+        .collect();
+    let policy = flow_row(2);
+
+    let result = policy.measure(&measurables, Constraints::loose(100.0, 200.0));
+
+    let positions: Vec<_> = result
+        .placements
+        .iter()
+        .map(|placement| (placement.x, placement.y))
+        .collect();
+    assert_eq!(positions, vec![(0.0, 0.0), (18.0, 0.0), (0.0, 14.0)]);
+    // Max intrinsic width only wraps at the item limit; min puts one child per row.
+    assert_eq!(
+        policy.max_intrinsic_width(&measurables, f32::INFINITY),
+        28.0
+    );
+    assert_eq!(
+        policy.min_intrinsic_width(&measurables, f32::INFINITY),
+        10.0
+    );
+    assert_eq!(policy.max_intrinsic_height(&measurables, 10.0), 38.0);
+}
+
+#[test]
+fn flow_column_arranges_children_within_each_column() {
+    let policy = FlowMeasurePolicy::new(
+        Axis::Vertical,
+        LinearArrangement::End,
+        LinearArrangement::Start,
+        usize::MAX,
+    );
+    let measurables: Vec<Box<dyn Measurable>> = vec![
+        Box::new(MockMeasurable::new(20.0, 30.0, 1)),
+        Box::new(MockMeasurable::new(10.0, 30.0, 2)),
+        Box::new(MockMeasurable::new(10.0, 20.0, 3)),
+    ];
+
+    let result = policy.measure(
+        &measurables,
+        Constraints {
+            min_width: 0.0,
+            max_width: 100.0,
+            min_height: 70.0,
+            max_height: 70.0,
+        },
+    );
+
+    let positions: Vec<_> = result
+        .placements
+        .iter()
+        .map(|placement| (placement.x, placement.y))
+        .collect();
+    // The column is 60 tall within 70, so End pushes both children down by 10.
+    assert_eq!(positions, vec![(0.0, 10.0), (0.0, 40.0), (20.0, 50.0)]);
+    assert_eq!((result.size.width, result.size.height), (30.0, 70.0));
+}
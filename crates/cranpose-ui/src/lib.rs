@@ -4,11 +4,16 @@ use cranpose_core::{location_key, MemoryApplier};
 pub use cranpose_core::{Composition, Key};
 pub use cranpose_macros::composable;
 
+mod bring_into_view;
+mod clipboard;
+mod composition_local;
 mod cursor_animation;
 mod debug;
 mod draw;
 pub mod fling_animation;
 mod focus_dispatch;
+mod focus_traversal;
+mod ime;
 mod key_event;
 pub mod layout;
 mod modifier;
@@ -17,6 +22,7 @@ mod pointer_dispatch;
 mod primitives;
 mod render_state;
 mod renderer;
+mod ripple;
 pub mod scroll;
 mod subcompose_layout;
 mod text;
@@ -26,6 +32,7 @@ mod text_field_input;
 mod text_field_modifier_node;
 pub mod text_layout_result;
 mod text_modifier_node;
+mod text_selection;
 pub mod widgets;
 mod word_boundaries;
 
@@ -37,33 +44,56 @@ pub use cursor_animation::{
     stop_cursor_blink, tick_cursor_blink,
 };
 
-pub use cranpose_ui_graphics::Dp;
+pub use bring_into_view::{
+    bring_into_view, has_pending_bring_into_view_requests, process_bring_into_view_requests,
+    request_bring_into_view, BringIntoViewRequester, BringIntoViewRequesterElement,
+    BringIntoViewRequesterNode,
+};
+pub use clipboard::{platform_clipboard, set_platform_clipboard, Clipboard, MemoryClipboard};
+pub use composition_local::{
+    local_clipboard, local_density, local_elevation_curve, local_focus_indication,
+    local_popup_layer, local_popup_stack, local_text_selection_colors,
+};
+pub use cranpose_ui_graphics::{Density, Dp, Sp, TextDecoration, TextStyle, UnitExt};
 pub use cranpose_ui_layout::IntrinsicSize;
 pub use draw::{execute_draw_commands, DrawCacheBuilder, DrawCommand};
 pub use focus_dispatch::{
-    active_focus_target, clear_focus_invalidations, has_pending_focus_invalidations,
-    process_focus_invalidations, schedule_focus_invalidation, set_active_focus_target,
+    active_focus_source, active_focus_target, clear_focus_invalidations,
+    has_pending_focus_invalidations, process_focus_invalidations, schedule_focus_invalidation,
+    set_active_focus_source, set_active_focus_target, FocusSource,
+};
+pub use focus_traversal::{
+    clear_focus, dispatch_focused_key_event, focus_traversal_order, move_focus, request_focus,
+    request_focus_from,
 };
+pub use ime::{platform_ime, set_platform_ime, ImeConnection, ImeEvent};
 // Re-export FocusManager from cranpose-foundation to avoid duplication
 pub use cranpose_foundation::nodes::input::focus::FocusManager;
 pub use layout::{
     core::{
-        Alignment, Arrangement, HorizontalAlignment, LinearArrangement, Measurable, Placeable,
-        VerticalAlignment,
+        Alignment, AlignmentLine, Arrangement, HorizontalAlignment, LinearArrangement, Measurable,
+        MeasurePolicy, Placeable, VerticalAlignment,
     },
-    measure_layout, tree_needs_layout, LayoutBox, LayoutEngine, LayoutMeasurements, LayoutNodeData,
-    LayoutNodeKind, LayoutTree, SemanticsAction, SemanticsCallback, SemanticsNode, SemanticsRole,
-    SemanticsTree,
+    measure_layout, tree_needs_layout, AccessibilityNode, LayoutBox, LayoutEngine,
+    LayoutMeasurements, LayoutNodeData, LayoutNodeKind, LayoutTree, SemanticsAction,
+    SemanticsCallback, SemanticsNode, SemanticsRole, SemanticsTree,
 };
 pub use modifier::{
-    collect_modifier_slices, collect_slices_from_modifier, Brush, Color, CornerRadii, EdgeInsets,
-    GraphicsLayer, Modifier, ModifierNodeSlices, Point, PointerEvent, PointerEventKind,
-    PointerInputScope, Rect, ResolvedBackground, ResolvedModifiers, RoundedCornerShape, Size,
+    collect_modifier_slices, collect_slices_from_modifier, BlockPointerInputElement,
+    BlockPointerInputNode, Brush, Color, ColorParseError, CornerRadii, CursorIcon, EdgeInsets,
+    ElevationCurve, FocusDirection, FocusIndication, FocusRequester, FocusState, GraphicsLayer,
+    KeyEventHandler, Modifier, ModifierChainHandle, ModifierNodeSlices, Point,
+    PointerCursorElement, PointerCursorNode, PointerEvent, PointerEventKind, PointerInputScope,
+    Rect, ResolvedBackground, ResolvedModifiers, Role, RoundedCornerShape, SemanticsConfiguration,
+    ShadowStyle, Size, TransformChange, TransformableState,
 };
 pub use modifier_nodes::{
-    AlphaElement, AlphaNode, BackgroundElement, BackgroundNode, ClickableElement, ClickableNode,
-    CornerShapeElement, CornerShapeNode, FillDirection, FillElement, FillNode, OffsetElement,
-    OffsetNode, PaddingElement, PaddingNode, SizeElement, SizeNode,
+    AlphaElement, AlphaNode, AspectRatioElement, AspectRatioNode, BackgroundElement,
+    BackgroundNode, BlurElement, BlurNode, BorderElement, BorderNode, BorderStyle,
+    ClickableElement, ClickableNode, CornerShapeElement, CornerShapeNode, DefaultMinSizeElement,
+    DefaultMinSizeNode, FillDirection, FillElement, FillNode, OffsetElement, OffsetNode,
+    PaddingElement, PaddingNode, ShadowElement, ShadowNode, SizeElement, SizeNode,
+    VisualOffsetElement, VisualOffsetNode, WrapContentElement, WrapContentNode, MAX_BLUR_RADIUS,
 };
 pub use pointer_dispatch::{
     clear_pointer_repasses, has_pending_pointer_repasses, process_pointer_repasses,
@@ -71,9 +101,12 @@ pub use pointer_dispatch::{
 };
 pub use primitives::{
     BasicTextField, BasicTextFieldOptions, Box, BoxScope, BoxSpec, BoxWithConstraints,
-    BoxWithConstraintsScope, BoxWithConstraintsScopeImpl, Button, Column, ColumnSpec, ForEach,
-    Layout, LayoutNode, Row, RowSpec, Spacer, SubcomposeLayout, Text,
+    BoxWithConstraintsScope, BoxWithConstraintsScopeImpl, Button, CircularProgressIndicator,
+    Column, ColumnSpec, Dialog, Divider, FlowColumn, FlowColumnSpec, FlowRow, FlowRowSpec,
+    FnMeasurePolicy, ForEach, Layout, LayoutNode, Popup, PopupHost, PopupLayer, Row, RowSpec,
+    ScrollableTabRow, Spacer, SubcomposeLayout, Tab, TabRow, Text,
 };
+pub use widgets::popup::PopupStack;
 // Lazy list exports - single source from cranpose-foundation
 pub use cranpose_foundation::lazy::{LazyListItemInfo, LazyListLayoutInfo, LazyListState};
 pub use key_event::{KeyCode, KeyEvent, KeyEventType, Modifiers};
@@ -86,8 +119,9 @@ pub use render_state::{
     take_layout_invalidation, take_layout_repass_nodes, take_pointer_invalidation,
     take_render_invalidation,
 };
-pub use renderer::{HeadlessRenderer, PaintLayer, RecordedRenderScene, RenderOp};
-pub use scroll::{ScrollElement, ScrollNode, ScrollState};
+pub use renderer::{sort_by_z_index, HeadlessRenderer, PaintLayer, RecordedRenderScene, RenderOp};
+pub use ripple::{Ripple, DEFAULT_RIPPLE_COLOR};
+pub use scroll::{ScrollElement, ScrollNode, ScrollState, ScrollableState};
 // Test utilities for fling velocity verification (only with test-helpers feature)
 #[cfg(feature = "test-helpers")]
 pub use modifier::{last_fling_velocity, reset_last_fling_velocity};
@@ -96,12 +130,20 @@ pub use subcompose_layout::{
     SubcomposeMeasureScope, SubcomposeMeasureScopeImpl,
 };
 pub use text::{
-    get_cursor_x_for_offset, get_offset_for_position, layout_text, measure_text, set_text_measurer,
-    TextMeasurer, TextMetrics,
+    get_cursor_x_for_offset, get_offset_for_position, layout_text, measure_text,
+    measure_text_with_style, measure_text_wrapped, set_text_measurer, TextMeasurer, TextMetrics,
 };
 pub use text_field_modifier_node::{TextFieldElement, TextFieldModifierNode};
 pub use text_modifier_node::{TextModifierElement, TextModifierNode};
-pub use widgets::lazy_list::{LazyColumn, LazyColumnSpec, LazyRow, LazyRowSpec};
+pub use text_selection::{
+    copy_selected_text, SelectableTextElement, SelectableTextNode, TextSelectionColors,
+};
+pub use widgets::lazy_list::{
+    KnownViewport, LazyColumn, LazyColumnSpec, LazyRow, LazyRowSpec, SlotReuseLimits,
+};
+pub use widgets::lazy_staggered_grid::{
+    LazyVerticalStaggeredGrid, LazyVerticalStaggeredGridSpec, StaggeredGridCells,
+};
 
 // Debug utilities
 pub use debug::{
@@ -1,7 +1,12 @@
 use super::{inspector_metadata, Alignment, HorizontalAlignment, Modifier, VerticalAlignment};
 use crate::modifier_nodes::AlignmentElement;
+use cranpose_ui_layout::AlignmentLine;
 
 impl Modifier {
+    /// Positions this child inside its parent `Box`, overriding the box's
+    /// `content_alignment`.
+    ///
+    /// Matches Kotlin: `BoxScope.align(alignment)`
     pub fn align(self, alignment: Alignment) -> Self {
         self.then(
             Self::with_element(AlignmentElement::box_alignment(alignment)).with_inspector_metadata(
@@ -12,6 +17,17 @@ impl Modifier {
         )
     }
 
+    /// Sizes this child to its parent `Box` once the box's size is known from
+    /// the other children; the child itself does not grow the box.
+    ///
+    /// Matches Kotlin: `BoxScope.matchParentSize()`
+    pub fn match_parent_size(self) -> Self {
+        self.then(
+            Self::with_element(AlignmentElement::match_parent_size())
+                .with_inspector_metadata(inspector_metadata("matchParentSize", |_| {})),
+        )
+    }
+
     pub fn alignInBox(self, alignment: Alignment) -> Self {
         self.align(alignment)
     }
@@ -31,4 +47,27 @@ impl Modifier {
             }));
         self.then(modifier)
     }
+
+    /// Aligns this child inside a `Row` so that its `line` coincides with the
+    /// same line of the other children aligned this way, e.g. to line up the
+    /// baselines of texts with different padding.
+    ///
+    /// Children without the line (layouts that contain no text) keep the
+    /// row's vertical alignment.
+    ///
+    /// Matches Kotlin: `RowScope.alignBy(alignmentLine)`
+    pub fn align_by(self, line: AlignmentLine) -> Self {
+        let modifier = Self::with_element(AlignmentElement::align_by(line))
+            .with_inspector_metadata(inspector_metadata("alignBy", move |info| {
+                info.add_alignment("alignmentLine", line);
+            }));
+        self.then(modifier)
+    }
+
+    /// Aligns this child inside a `Row` by its first baseline.
+    ///
+    /// Matches Kotlin: `RowScope.alignByBaseline()`
+    pub fn align_by_baseline(self) -> Self {
+        self.align_by(AlignmentLine::FirstBaseline)
+    }
 }
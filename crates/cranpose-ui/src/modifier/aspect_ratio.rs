@@ -0,0 +1,43 @@
+//! Aspect ratio modifier implementation following Jetpack Compose's layout/AspectRatio.kt
+//!
+//! Reference: /media/huge/composerepo/compose/foundation/foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/AspectRatio.kt
+
+use super::{inspector_metadata, Modifier};
+use crate::modifier_nodes::AspectRatioElement;
+
+impl Modifier {
+    /// Size the content to match `ratio` (width / height).
+    ///
+    /// The size is the first of the incoming max width, max height, min width and
+    /// min height that yields a ratio-preserving size within the constraints. If
+    /// none fits, the constraints on the derived axis are ignored, and if that
+    /// fails too, the content is measured with the incoming constraints.
+    ///
+    /// Panics if `ratio` is not greater than zero.
+    ///
+    /// Matches Kotlin: `Modifier.aspectRatio(ratio)`
+    ///
+    /// Example: `Modifier::empty().fill_max_width().aspect_ratio(16.0 / 9.0)`
+    pub fn aspect_ratio(self, ratio: f32) -> Self {
+        self.aspect_ratio_with(ratio, false)
+    }
+
+    /// Like [`aspect_ratio`](Self::aspect_ratio), but tries the height
+    /// constraints before the width constraints.
+    ///
+    /// Matches Kotlin: `Modifier.aspectRatio(ratio, matchHeightConstraintsFirst = true)`
+    pub fn aspect_ratio_with(self, ratio: f32, match_height_constraints_first: bool) -> Self {
+        let modifier = Self::with_element(AspectRatioElement::new(
+            ratio,
+            match_height_constraints_first,
+        ))
+        .with_inspector_metadata(inspector_metadata("aspectRatio", move |info| {
+            info.add_property("ratio", ratio.to_string());
+            info.add_property(
+                "matchHeightConstraintsFirst",
+                match_height_constraints_first.to_string(),
+            );
+        }));
+        self.then(modifier)
+    }
+}
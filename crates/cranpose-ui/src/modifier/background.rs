@@ -1,13 +1,16 @@
-use super::{inspector_metadata, Color, Modifier, RoundedCornerShape};
+use super::{inspector_metadata, Brush, Modifier, RoundedCornerShape};
 use crate::modifier_nodes::{BackgroundElement, CornerShapeElement};
 
 impl Modifier {
-    /// Set the background color.
+    /// Fill the background with a color or a gradient [`Brush`].
     ///
     /// Example: `Modifier::empty().background(Color::rgb(1.0, 0.0, 0.0))`
-    pub fn background(self, color: Color) -> Self {
-        let modifier = Self::with_element(BackgroundElement::new(color))
-            .with_inspector_metadata(background_metadata(color));
+    /// or `Modifier::empty().background(Brush::linear_gradient(colors))`
+    pub fn background(self, brush: impl Into<Brush>) -> Self {
+        let brush = brush.into();
+        let metadata = background_metadata(&brush);
+        let modifier =
+            Self::with_element(BackgroundElement::new(brush)).with_inspector_metadata(metadata);
         self.then(modifier)
     }
 
@@ -20,6 +23,25 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Add rounded corners with a separate radius for each corner.
+    ///
+    /// Example: `Modifier::empty().rounded_corners_each(16.0, 16.0, 0.0, 0.0)` rounds
+    /// only the top corners.
+    pub fn rounded_corners_each(
+        self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        self.rounded_corner_shape(RoundedCornerShape::new(
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        ))
+    }
+
     /// Add rounded corners with a custom shape.
     ///
     /// Example: `Modifier::empty().rounded_corner_shape(shape)`
@@ -29,8 +51,12 @@ impl Modifier {
     }
 }
 
-fn background_metadata(color: Color) -> super::InspectorMetadata {
-    inspector_metadata("background", |info| {
-        info.add_property("backgroundColor", format!("{color:?}"));
+fn background_metadata(brush: &Brush) -> super::InspectorMetadata {
+    let (name, value) = match brush {
+        Brush::Solid(color) => ("backgroundColor", format!("{color:?}")),
+        brush => ("backgroundBrush", format!("{brush:?}")),
+    };
+    inspector_metadata("background", move |info| {
+        info.add_property(name, value);
     })
 }
@@ -0,0 +1,65 @@
+//! Pointer input barrier.
+//!
+//! Consumes every pointer event that reaches the element, so elements drawn
+//! underneath never see it. Modal surfaces such as dialog scrims use it.
+
+use super::{inspector_metadata, Modifier, PointerEvent};
+use cranpose_foundation::{
+    impl_pointer_input_node, DelegatableNode, ModifierNode, ModifierNodeElement, NodeCapabilities,
+    NodeState, PointerInputNode,
+};
+use std::rc::Rc;
+
+impl Modifier {
+    /// Keeps pointer events over the element from reaching elements below it.
+    ///
+    /// Pointer handlers earlier in the same chain still see each event, so
+    /// `clickable(..).block_pointer_input()` clicks and then blocks.
+    ///
+    /// Example: `Modifier::empty().fill_max_size().block_pointer_input()`
+    pub fn block_pointer_input(self) -> Self {
+        let modifier = Self::with_element(BlockPointerInputElement)
+            .with_inspector_metadata(inspector_metadata("blockPointerInput", |_info| {}));
+        self.then(modifier)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockPointerInputElement;
+
+impl ModifierNodeElement for BlockPointerInputElement {
+    type Node = BlockPointerInputNode;
+
+    fn create(&self) -> Self::Node {
+        BlockPointerInputNode {
+            state: NodeState::new(),
+        }
+    }
+
+    fn update(&self, _node: &mut Self::Node) {}
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::POINTER_INPUT
+    }
+}
+
+/// Node that consumes every pointer event of its element.
+pub struct BlockPointerInputNode {
+    state: NodeState,
+}
+
+impl ModifierNode for BlockPointerInputNode {
+    impl_pointer_input_node!();
+}
+
+impl DelegatableNode for BlockPointerInputNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl PointerInputNode for BlockPointerInputNode {
+    fn pointer_input_handler(&self) -> Option<Rc<dyn Fn(PointerEvent)>> {
+        Some(Rc::new(|event: PointerEvent| event.consume()))
+    }
+}
@@ -0,0 +1,26 @@
+use super::{inspector_metadata, Color, Modifier};
+use crate::modifier_nodes::{BorderElement, BorderStyle};
+
+impl Modifier {
+    /// Stroke a solid border of `width` inside the bounds of this layout.
+    ///
+    /// Matches Kotlin: `Modifier.border(width, color)`
+    ///
+    /// Example: `Modifier::empty().border(1.0, Color::rgb(0.0, 0.0, 0.0))`
+    pub fn border(self, width: f32, color: Color) -> Self {
+        self.border_with_style(width, color, BorderStyle::Solid)
+    }
+
+    /// Stroke a border of `width` using a dash pattern.
+    ///
+    /// Example: `Modifier::empty().border_with_style(1.0, color, BorderStyle::Dashed { dash: 4.0, gap: 2.0 })`
+    pub fn border_with_style(self, width: f32, color: Color, style: BorderStyle) -> Self {
+        let modifier = Self::with_element(BorderElement::new(width, color, style))
+            .with_inspector_metadata(inspector_metadata("border", move |info| {
+                info.add_property("width", width.to_string());
+                info.add_property("color", format!("{color:?}"));
+                info.add_property("style", format!("{style:?}"));
+            }));
+        self.then(modifier)
+    }
+}
@@ -10,9 +10,11 @@ use cranpose_foundation::{
 };
 
 use super::{
-    local::ModifierLocalManager, DimensionConstraint, EdgeInsets, LayoutProperties, Modifier,
-    ModifierInspectorRecord, ModifierLocalAncestorResolver, ModifierLocalToken, Point,
-    ResolvedModifierLocal, ResolvedModifiers,
+    focus::{FocusTargetNode, KeyEventHandler, KeyInputNode},
+    local::ModifierLocalManager,
+    DimensionConstraint, EdgeInsets, LayoutProperties, Modifier, ModifierInspectorRecord,
+    ModifierLocalAncestorResolver, ModifierLocalToken, Point, ResolvedModifierLocal,
+    ResolvedModifiers,
 };
 use crate::modifier_nodes::{
     AlignmentNode, FillDirection, FillNode, IntrinsicAxis, IntrinsicSizeNode, OffsetNode,
@@ -45,6 +47,8 @@ pub type ModifierLocalsHandle = Rc<RefCell<ModifierLocalManager>>;
 #[allow(dead_code)]
 pub struct ModifierChainHandle {
     chain: ModifierNodeChain,
+    /// Modifier the chain was last reconciled with
+    applied: Modifier,
     context: RefCell<BasicModifierNodeContext>,
     resolved: ResolvedModifiers,
     capabilities: NodeCapabilities,
@@ -53,12 +57,15 @@ pub struct ModifierChainHandle {
     inspector_snapshot: Vec<ModifierChainInspectorNode>,
     inspector_entry_scratch: Vec<Option<ModifierInspectorRecord>>,
     debug_logging: bool,
+    #[cfg(test)]
+    resolved_computations: usize,
 }
 
 impl Default for ModifierChainHandle {
     fn default() -> Self {
         Self {
             chain: ModifierNodeChain::new(),
+            applied: Modifier::empty(),
             context: RefCell::new(BasicModifierNodeContext::new()),
             resolved: ResolvedModifiers::default(),
             capabilities: NodeCapabilities::default(),
@@ -67,6 +74,8 @@ impl Default for ModifierChainHandle {
             inspector_snapshot: Vec::new(),
             inspector_entry_scratch: Vec::new(),
             debug_logging: false,
+            #[cfg(test)]
+            resolved_computations: 0,
         }
     }
 }
@@ -78,6 +87,9 @@ impl ModifierChainHandle {
     }
 
     /// Reconciles the underlying [`ModifierNodeChain`] with the elements stored in `modifier`.
+    ///
+    /// A modifier equal to the last one leaves the chain and the resolved
+    /// modifiers untouched.
     pub fn update(&mut self, modifier: &Modifier) -> Vec<ModifierInvalidation> {
         let mut resolver = |_: ModifierLocalToken| None;
         self.update_with_resolver(modifier, &mut resolver)
@@ -88,15 +100,33 @@ impl ModifierChainHandle {
         modifier: &Modifier,
         resolver: &mut ModifierLocalAncestorResolver<'_>,
     ) -> Vec<ModifierInvalidation> {
+        // Equal modifiers hold equal elements and the very same instances of
+        // elements that always update, so reconciling would not change any node
+        if *modifier != self.applied {
+            self.reconcile(modifier);
+        }
+        let modifier_local_invalidations = self
+            .modifier_locals
+            .borrow_mut()
+            .sync(&self.chain, resolver);
+
+        // Only collect inspector snapshot when debugging is enabled (lazy collection)
+        let should_log = self.debug_logging || global_modifier_debug_flag();
+        if should_log {
+            self.collect_inspector_snapshot(modifier);
+            crate::debug::log_modifier_chain(self.chain(), self.inspector_snapshot());
+            crate::debug::emit_modifier_chain_trace(self.inspector_snapshot());
+        }
+        modifier_local_invalidations
+    }
+
+    fn reconcile(&mut self, modifier: &Modifier) {
         // Use iterator-based update to avoid allocation entirely
         self.chain
             .update_from_ref_iter(modifier.iter_elements(), &mut *self.context.borrow_mut());
+        self.applied = modifier.clone();
         self.capabilities = self.chain.capabilities();
         self.aggregate_child_capabilities = self.chain.head().aggregate_child_capabilities();
-        let modifier_local_invalidations = self
-            .modifier_locals
-            .borrow_mut()
-            .sync(&self.chain, resolver);
 
         // Only recompute resolved modifiers if layout-affecting nodes changed.
         // Check if any LAYOUT invalidation was produced during update.
@@ -108,16 +138,11 @@ impl ModifierChainHandle {
         };
         if needs_resolved_update {
             self.resolved = self.compute_resolved();
+            #[cfg(test)]
+            {
+                self.resolved_computations += 1;
+            }
         }
-
-        // Only collect inspector snapshot when debugging is enabled (lazy collection)
-        let should_log = self.debug_logging || global_modifier_debug_flag();
-        if should_log {
-            self.collect_inspector_snapshot(modifier);
-            crate::debug::log_modifier_chain(self.chain(), self.inspector_snapshot());
-            crate::debug::emit_modifier_chain_trace(self.inspector_snapshot());
-        }
-        modifier_local_invalidations
     }
 
     /// Enables or disables per-handle modifier debug logging.
@@ -263,12 +288,18 @@ impl ModifierChainHandle {
                     if let Some(alignment) = alignment_node.box_alignment() {
                         layout.box_alignment = Some(alignment);
                     }
+                    if alignment_node.match_parent_size() {
+                        layout.match_parent_size = true;
+                    }
                     if let Some(alignment) = alignment_node.column_alignment() {
                         layout.column_alignment = Some(alignment);
                     }
                     if let Some(alignment) = alignment_node.row_alignment() {
                         layout.row_alignment = Some(alignment);
                     }
+                    if let Some(line) = alignment_node.align_by() {
+                        layout.align_by = Some(line);
+                    }
                 } else if let Some(offset_node) = any.downcast_ref::<OffsetNode>() {
                     let delta = offset_node.offset();
                     offset.x += delta.x;
@@ -332,6 +363,59 @@ impl ModifierChainHandle {
         });
     }
 
+    /// Applies `f` to every focus target node in the chain.
+    ///
+    /// Returns `false` when the chain has no focus target.
+    pub(crate) fn for_each_focus_target(&self, mut f: impl FnMut(&FocusTargetNode)) -> bool {
+        let mut found = false;
+        self.chain
+            .for_each_node_with_capability(NodeCapabilities::FOCUS, |_ref, node| {
+                if let Some(target) = node.as_any().downcast_ref::<FocusTargetNode>() {
+                    found = true;
+                    f(target);
+                }
+            });
+        found
+    }
+
+    /// Returns the key event handlers in the chain, innermost first.
+    pub(crate) fn key_event_handlers(&self) -> Vec<KeyEventHandler> {
+        let mut handlers = Vec::new();
+        self.chain
+            .for_each_node_with_capability(NodeCapabilities::FOCUS, |_ref, node| {
+                if let Some(key_input) = node.as_any().downcast_ref::<KeyInputNode>() {
+                    handlers.push(key_input.handler());
+                }
+            });
+        handlers.reverse();
+        handlers
+    }
+
+    /// Applies `f` to every scroll node in the chain.
+    pub(crate) fn for_each_scroll_node(&self, mut f: impl FnMut(&crate::scroll::ScrollNode)) {
+        self.chain
+            .for_each_node_with_capability(NodeCapabilities::LAYOUT, |_ref, node| {
+                if let Some(scroll) = node.as_any().downcast_ref::<crate::scroll::ScrollNode>() {
+                    f(scroll);
+                }
+            });
+    }
+
+    /// Applies `f` to every lazy list scroll node in the chain.
+    pub(crate) fn for_each_lazy_scroll_node(
+        &self,
+        mut f: impl FnMut(&crate::scroll::LazyScrollNode),
+    ) {
+        self.chain.visit_nodes(|node, _capabilities| {
+            if let Some(scroll) = node
+                .as_any()
+                .downcast_ref::<crate::scroll::LazyScrollNode>()
+            {
+                f(scroll);
+            }
+        });
+    }
+
     /// Access a text field modifier node in the chain with a mutable callback.
     ///
     /// Searches for `TextFieldModifierNode` and calls the callback if found.
@@ -355,14 +439,26 @@ impl ModifierChainHandle {
     }
 }
 
-fn apply_size_node(layout: &mut LayoutProperties, node: &SizeNode) {
-    if let Some(width) = node.max_width().or(node.min_width()) {
-        layout.width = DimensionConstraint::Points(width);
-    }
-    if let Some(height) = node.max_height().or(node.min_height()) {
-        layout.height = DimensionConstraint::Points(height);
+/// Writes a size constraint unless an outer modifier already decided this axis.
+///
+/// Modifiers are visited outermost first, and like per-node measurement the
+/// outer modifier constrains the inner one: `size(..).fill_max_width()` keeps
+/// the fixed width while `fill_max_width().size(..)` fills it.
+fn constrain_axis(slot: &mut DimensionConstraint, constraint: DimensionConstraint) {
+    if matches!(slot, DimensionConstraint::Unspecified) {
+        *slot = constraint;
     }
+}
+
+fn apply_size_node(layout: &mut LayoutProperties, node: &SizeNode) {
     if !node.enforce_incoming() {
+        // Required sizes ignore the incoming constraints, so they win over outer modifiers
+        if let Some(width) = node.max_width().or(node.min_width()) {
+            layout.width = DimensionConstraint::Points(width);
+        }
+        if let Some(height) = node.max_height().or(node.min_height()) {
+            layout.height = DimensionConstraint::Points(height);
+        }
         if let Some(min_width) = node.min_width() {
             layout.min_width = Some(min_width);
         }
@@ -375,21 +471,69 @@ fn apply_size_node(layout: &mut LayoutProperties, node: &SizeNode) {
         if let Some(max_height) = node.max_height() {
             layout.max_height = Some(max_height);
         }
+        return;
+    }
+    if node.min_width() == node.max_width() {
+        if let Some(width) = node.max_width() {
+            constrain_axis(&mut layout.width, DimensionConstraint::Points(width));
+        }
+    } else {
+        constrain_axis_range(
+            layout.width,
+            (&mut layout.min_width, &mut layout.max_width),
+            (node.min_width(), node.max_width()),
+        );
+    }
+    if node.min_height() == node.max_height() {
+        if let Some(height) = node.max_height() {
+            constrain_axis(&mut layout.height, DimensionConstraint::Points(height));
+        }
+    } else {
+        constrain_axis_range(
+            layout.height,
+            (&mut layout.min_height, &mut layout.max_height),
+            (node.min_height(), node.max_height()),
+        );
+    }
+}
+
+/// Narrows an axis's size range, coerced into the range outer modifiers set.
+///
+/// An axis an outer modifier already sized exactly keeps that size.
+fn constrain_axis_range(
+    size: DimensionConstraint,
+    (min_slot, max_slot): (&mut Option<f32>, &mut Option<f32>),
+    (min, max): (Option<f32>, Option<f32>),
+) {
+    if !matches!(size, DimensionConstraint::Unspecified) {
+        return;
+    }
+    let (outer_min, outer_max) = (*min_slot, *max_slot);
+    let coerce = |value: f32| {
+        value
+            .max(outer_min.unwrap_or(0.0))
+            .min(outer_max.unwrap_or(f32::INFINITY))
+    };
+    if let Some(min) = min {
+        *min_slot = Some(coerce(min));
+    }
+    if let Some(max) = max {
+        *max_slot = Some(coerce(max));
     }
 }
 
 fn apply_fill_node(layout: &mut LayoutProperties, node: &FillNode) {
-    let fraction = node.fraction();
+    let constraint = DimensionConstraint::Fraction(node.fraction());
     match node.direction() {
         FillDirection::Horizontal => {
-            layout.width = DimensionConstraint::Fraction(fraction);
+            constrain_axis(&mut layout.width, constraint);
         }
         FillDirection::Vertical => {
-            layout.height = DimensionConstraint::Fraction(fraction);
+            constrain_axis(&mut layout.height, constraint);
         }
         FillDirection::Both => {
-            layout.width = DimensionConstraint::Fraction(fraction);
-            layout.height = DimensionConstraint::Fraction(fraction);
+            constrain_axis(&mut layout.width, constraint);
+            constrain_axis(&mut layout.height, constraint);
         }
     }
 }
@@ -398,10 +542,10 @@ fn apply_intrinsic_size_node(layout: &mut LayoutProperties, node: &IntrinsicSize
     let constraint = DimensionConstraint::Intrinsic(node.intrinsic_size());
     match node.axis() {
         IntrinsicAxis::Width => {
-            layout.width = constraint;
+            constrain_axis(&mut layout.width, constraint);
         }
         IntrinsicAxis::Height => {
-            layout.height = constraint;
+            constrain_axis(&mut layout.height, constraint);
         }
     }
 }
@@ -421,7 +565,8 @@ fn global_modifier_debug_flag() -> bool {
 #[cfg(test)]
 mod tests {
     use cranpose_foundation::{
-        InvalidationKind, ModifierInvalidation, ModifierNode, NodeCapabilities,
+        DelegatableNode, InvalidationKind, ModifierInvalidation, ModifierNode, ModifierNodeElement,
+        NodeCapabilities, NodeState,
     };
 
     use super::*;
@@ -534,6 +679,77 @@ mod tests {
         );
     }
 
+    /// Layout element that counts the updates of its nodes.
+    #[derive(Debug, PartialEq, Hash)]
+    struct UpdateSpyElement;
+
+    struct UpdateSpyNode {
+        state: NodeState,
+    }
+
+    impl DelegatableNode for UpdateSpyNode {
+        fn node_state(&self) -> &NodeState {
+            &self.state
+        }
+    }
+
+    impl ModifierNode for UpdateSpyNode {}
+
+    thread_local! {
+        static SPY_UPDATES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    impl ModifierNodeElement for UpdateSpyElement {
+        type Node = UpdateSpyNode;
+
+        fn create(&self) -> Self::Node {
+            UpdateSpyNode {
+                state: NodeState::new(),
+            }
+        }
+
+        fn update(&self, _node: &mut Self::Node) {
+            SPY_UPDATES.with(|updates| updates.set(updates.get() + 1));
+        }
+
+        fn capabilities(&self) -> NodeCapabilities {
+            NodeCapabilities::LAYOUT
+        }
+
+        fn always_update(&self) -> bool {
+            true
+        }
+    }
+
+    fn spy_modifier() -> Modifier {
+        Modifier::empty()
+            .padding(8.0)
+            .then(Modifier::from_parts(vec![
+                cranpose_foundation::modifier_element(UpdateSpyElement),
+            ]))
+    }
+
+    #[test]
+    fn equal_modifier_skips_reconciliation() {
+        let spy_updates = || SPY_UPDATES.with(std::cell::Cell::get);
+        let mut handle = ModifierChainHandle::new();
+        let modifier = spy_modifier();
+        let _ = handle.update(&modifier);
+        handle.take_invalidations();
+        let (updates, computations) = (spy_updates(), handle.resolved_computations);
+
+        let _ = handle.update(&modifier.clone());
+
+        assert_eq!(spy_updates(), updates, "no node is updated");
+        assert_eq!(handle.resolved_computations, computations);
+        assert!(handle.take_invalidations().is_empty());
+
+        // A rebuilt modifier holds a new instance of the always-updating element
+        let _ = handle.update(&spy_modifier());
+        assert_eq!(spy_updates(), updates + 1);
+        assert_eq!(handle.resolved_computations, computations + 1);
+    }
+
     fn node_ptr<N: ModifierNode + 'static>(handle: &ModifierChainHandle) -> *const N {
         handle
             .chain()
@@ -1,20 +1,59 @@
 use super::{inspector_metadata, Modifier, Point, SemanticsConfiguration};
 use crate::modifier_nodes::ClickableElement;
+use crate::ripple::Ripple;
 use std::rc::Rc;
 
 impl Modifier {
     /// Make the component clickable.
     ///
+    /// Presses show the default bounded [`Ripple`] indication.
+    ///
     /// Example: `Modifier::empty().clickable(|pt| println!("Clicked at {:?}", pt))`
     pub fn clickable(self, handler: impl Fn(Point) + 'static) -> Self {
+        self.clickable_with_indication(Some(Ripple::default()), handler)
+    }
+
+    /// Make the component clickable, or inert while `enabled` is false.
+    ///
+    /// A disabled clickable ignores pointer input, shows no ripple, and is
+    /// reported as disabled in semantics.
+    ///
+    /// Matches Kotlin: `Modifier.clickable(enabled, onClick)`
+    pub fn clickable_enabled(self, enabled: bool, handler: impl Fn(Point) + 'static) -> Self {
+        self.clickable_with_options(enabled, Some(Ripple::default()), handler)
+    }
+
+    /// Make the component clickable with a custom indication, or none with `None`.
+    ///
+    /// Matches Kotlin: `Modifier.clickable(interactionSource, indication, onClick)`
+    ///
+    /// Example: `Modifier::empty().clickable_with_indication(Some(Ripple::unbounded()), |_| {})`
+    pub fn clickable_with_indication(
+        self,
+        indication: Option<Ripple>,
+        handler: impl Fn(Point) + 'static,
+    ) -> Self {
+        self.clickable_with_options(true, indication, handler)
+    }
+
+    fn clickable_with_options(
+        self,
+        enabled: bool,
+        indication: Option<Ripple>,
+        handler: impl Fn(Point) + 'static,
+    ) -> Self {
         let handler = Rc::new(handler);
-        let modifier = Self::with_element(ClickableElement::with_handler(handler))
-            .with_inspector_metadata(inspector_metadata("clickable", |info| {
+        let element = ClickableElement::with_indication(handler, indication).with_enabled(enabled);
+        let modifier = Self::with_element(element)
+            .with_inspector_metadata(inspector_metadata("clickable", move |info| {
                 info.add_property("onClick", "provided");
+                info.add_property("indication", format!("{indication:?}"));
+                info.add_property("enabled", enabled.to_string());
             }))
             .then(
-                Modifier::empty().semantics(|config: &mut SemanticsConfiguration| {
+                Modifier::empty().semantics(move |config: &mut SemanticsConfiguration| {
                     config.is_clickable = true;
+                    config.is_disabled = !enabled;
                 }),
             );
         self.then(modifier)
@@ -0,0 +1,66 @@
+use super::{Brush, Color, Modifier, Rect};
+use crate::scroll::ScrollableState;
+
+impl Modifier {
+    /// Fade the top and bottom edges of vertically scrolling content into `color`.
+    ///
+    /// Each fade is drawn over the content only while `state` can scroll
+    /// toward that edge, so the top stays crisp at scroll position 0. The
+    /// can-scroll flags are read when the modifier is built; call it from the
+    /// composable that owns the scroll state so it recomposes as they change.
+    ///
+    /// Example: `Modifier::empty().vertical_scroll(state.clone(), false).fading_edges(&state, 24.0, 24.0, Color::WHITE)`
+    pub fn fading_edges(
+        self,
+        state: &impl ScrollableState,
+        start_length: f32,
+        end_length: f32,
+        color: Color,
+    ) -> Self {
+        let start_length = if state.can_scroll_backward() {
+            start_length.max(0.0)
+        } else {
+            0.0
+        };
+        let end_length = if state.can_scroll_forward() {
+            end_length.max(0.0)
+        } else {
+            0.0
+        };
+        if start_length == 0.0 && end_length == 0.0 {
+            return self;
+        }
+        let clear = Color(color.0, color.1, color.2, 0.0);
+        self.draw_with_content(move |scope| {
+            let size = scope.size();
+            let start_length = start_length.min(size.height);
+            let end_length = end_length.min(size.height);
+            if start_length > 0.0 {
+                scope.draw_rect_at(
+                    Rect {
+                        x: 0.0,
+                        y: 0.0,
+                        width: size.width,
+                        height: start_length,
+                    },
+                    Brush::linear_gradient(vec![color, clear]),
+                );
+            }
+            if end_length > 0.0 {
+                scope.draw_rect_at(
+                    Rect {
+                        x: 0.0,
+                        y: size.height - end_length,
+                        width: size.width,
+                        height: end_length,
+                    },
+                    Brush::linear_gradient(vec![clear, color]),
+                );
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/fading_edges_tests.rs"]
+mod tests;
@@ -9,13 +9,18 @@ use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
 use cranpose_foundation::{
-    impl_focus_node, DelegatableNode, FocusNode, FocusState, ModifierNode, ModifierNodeContext,
-    ModifierNodeElement, NodeCapabilities, NodeState,
+    impl_focus_node, DelegatableNode, DrawModifierNode, FocusNode, FocusState, ModifierNode,
+    ModifierNodeContext, ModifierNodeElement, NodeCapabilities, NodeState,
 };
+use cranpose_ui_graphics::{
+    ArcShape, Brush, Color, CornerRadii, DrawPrimitive, Rect, RoundedCornerShape, Size,
+};
+
+use crate::focus_dispatch::active_focus_source;
+use crate::key_event::KeyEvent;
 
 /// Focus direction for navigation.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-#[allow(dead_code)] // TODO: used in future focus manager integration
 pub enum FocusDirection {
     /// Enter focus from outside.
     Enter,
@@ -35,22 +40,199 @@ pub enum FocusDirection {
     Right,
 }
 
+/// Style of the ring drawn around a focused element.
+///
+/// The ring is a stroke around the element's bounds that follows its corner
+/// shape. It is only drawn while focus did not come from a pointer press (see
+/// [`FocusSource`](crate::FocusSource)), so it shows for keyboard users only.
+///
+/// Provided through [`crate::local_focus_indication`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FocusIndication {
+    /// Color of the ring.
+    pub color: Color,
+    /// Stroke width of the ring.
+    pub width: f32,
+    /// Gap between the element's bounds and the ring.
+    pub offset: f32,
+}
+
+impl Default for FocusIndication {
+    fn default() -> Self {
+        Self {
+            color: Color(0.2, 0.5, 1.0, 1.0),
+            width: 2.0,
+            offset: 1.0,
+        }
+    }
+}
+
+/// Focus ring of a focus target, shared with its draw closures.
+#[derive(Clone)]
+pub(crate) struct FocusRing {
+    focus_state: Rc<Cell<FocusState>>,
+    style: FocusIndication,
+}
+
+impl FocusRing {
+    /// Returns a closure drawing the ring, following `shape` if there is one.
+    ///
+    /// Focus is checked at draw time, so the closure survives focus changes.
+    pub(crate) fn draw_closure(
+        &self,
+        shape: Option<RoundedCornerShape>,
+    ) -> Rc<dyn Fn(Size) -> Vec<DrawPrimitive>> {
+        let ring = self.clone();
+        Rc::new(move |size| {
+            if ring.focus_state.get().is_focused() && active_focus_source().shows_indication() {
+                focus_ring_primitives(size, ring.style, shape)
+            } else {
+                Vec::new()
+            }
+        })
+    }
+}
+
+/// Strokes a ring of `style` around bounds of `size`, outside of them.
+fn focus_ring_primitives(
+    size: Size,
+    style: FocusIndication,
+    shape: Option<RoundedCornerShape>,
+) -> Vec<DrawPrimitive> {
+    if style.width <= 0.0 {
+        return Vec::new();
+    }
+    let outset = style.offset + style.width;
+    let rect = Rect {
+        x: -outset,
+        y: -outset,
+        width: size.width + outset * 2.0,
+        height: size.height + outset * 2.0,
+    };
+    // Rounded corners grow with the ring so it stays parallel to the edge
+    let grow = |radius: f32| if radius > 0.0 { radius + outset } else { 0.0 };
+    let radii = shape
+        .map(|shape| shape.resolve(size.width, size.height))
+        .map(|radii| CornerRadii {
+            top_left: grow(radii.top_left),
+            top_right: grow(radii.top_right),
+            bottom_right: grow(radii.bottom_right),
+            bottom_left: grow(radii.bottom_left),
+        })
+        .unwrap_or_default();
+    stroke_round_rect(rect, radii, style.width, Brush::solid(style.color))
+}
+
+/// Strokes the inside of the rounded rect `rect` with a band of `width`.
+///
+/// Straight edges become rectangles and rounded corners quarter arcs.
+fn stroke_round_rect(
+    rect: Rect,
+    radii: CornerRadii,
+    width: f32,
+    brush: Brush,
+) -> Vec<DrawPrimitive> {
+    let right = rect.x + rect.width;
+    let bottom = rect.y + rect.height;
+    // Square corners are covered by the horizontal edges
+    let vertical_inset = |radius: f32| if radius > 0.0 { radius } else { width };
+    let edges = [
+        Rect {
+            x: rect.x + radii.top_left,
+            y: rect.y,
+            width: rect.width - radii.top_left - radii.top_right,
+            height: width,
+        },
+        Rect {
+            x: right - width,
+            y: rect.y + vertical_inset(radii.top_right),
+            width,
+            height: rect.height
+                - vertical_inset(radii.top_right)
+                - vertical_inset(radii.bottom_right),
+        },
+        Rect {
+            x: rect.x + radii.bottom_left,
+            y: bottom - width,
+            width: rect.width - radii.bottom_left - radii.bottom_right,
+            height: width,
+        },
+        Rect {
+            x: rect.x,
+            y: rect.y + vertical_inset(radii.top_left),
+            width,
+            height: rect.height
+                - vertical_inset(radii.top_left)
+                - vertical_inset(radii.bottom_left),
+        },
+    ];
+    // (radius, corner square origin, start angle) clockwise from the top left
+    let corners = [
+        (radii.top_left, rect.x, rect.y, 180.0),
+        (
+            radii.top_right,
+            right - radii.top_right * 2.0,
+            rect.y,
+            270.0,
+        ),
+        (
+            radii.bottom_right,
+            right - radii.bottom_right * 2.0,
+            bottom - radii.bottom_right * 2.0,
+            0.0,
+        ),
+        (
+            radii.bottom_left,
+            rect.x,
+            bottom - radii.bottom_left * 2.0,
+            90.0,
+        ),
+    ];
+
+    let mut primitives: Vec<DrawPrimitive> = edges
+        .into_iter()
+        .filter(|edge| edge.width > 0.0 && edge.height > 0.0)
+        .map(|rect| DrawPrimitive::Rect {
+            rect,
+            brush: brush.clone(),
+        })
+        .collect();
+    for (radius, x, y, start_angle) in corners {
+        if radius > 0.0 {
+            primitives.push(DrawPrimitive::Arc {
+                rect: Rect {
+                    x,
+                    y,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                },
+                brush: brush.clone(),
+                arc: ArcShape::new(start_angle, 90.0, width.min(radius)),
+            });
+        }
+    }
+    primitives
+}
+
 /// A focus target node that can receive focus.
 ///
 /// This is the core building block for focusable components. Each focus target
 /// tracks its own focus state and participates in the focus traversal system.
+/// With an indication it also draws a focus ring while focused.
 pub struct FocusTargetNode {
     state: NodeState,
-    focus_state: Cell<FocusState>,
+    focus_state: Rc<Cell<FocusState>>,
     on_focus_changed: Option<Rc<dyn Fn(FocusState)>>,
+    indication: Option<FocusIndication>,
 }
 
 impl FocusTargetNode {
     pub fn new() -> Self {
         Self {
             state: NodeState::new(),
-            focus_state: Cell::new(FocusState::Inactive),
+            focus_state: Rc::new(Cell::new(FocusState::Inactive)),
             on_focus_changed: None,
+            indication: None,
         }
     }
 
@@ -59,12 +241,19 @@ impl FocusTargetNode {
         F: Fn(FocusState) + 'static,
     {
         Self {
-            state: NodeState::new(),
-            focus_state: Cell::new(FocusState::Inactive),
             on_focus_changed: Some(Rc::new(callback)),
+            ..Self::new()
         }
     }
 
+    /// Returns the focus ring drawn by this node, if it has an indication.
+    pub(crate) fn focus_ring(&self) -> Option<FocusRing> {
+        self.indication.map(|style| FocusRing {
+            focus_state: self.focus_state.clone(),
+            style,
+        })
+    }
+
     /// Sets the focus state for this node.
     pub fn set_focus_state(&self, state: FocusState) {
         let old_state = self.focus_state.get();
@@ -113,6 +302,20 @@ impl ModifierNode for FocusTargetNode {
 
     // Capability-driven implementation using helper macro
     impl_focus_node!();
+
+    fn as_draw_node(&self) -> Option<&dyn DrawModifierNode> {
+        Some(self)
+    }
+
+    fn as_draw_node_mut(&mut self) -> Option<&mut dyn DrawModifierNode> {
+        Some(self)
+    }
+}
+
+impl DrawModifierNode for FocusTargetNode {
+    fn create_draw_closure(&self) -> Option<Rc<dyn Fn(Size) -> Vec<DrawPrimitive>>> {
+        self.focus_ring().map(|ring| ring.draw_closure(None))
+    }
 }
 
 impl FocusNode for FocusTargetNode {
@@ -131,12 +334,14 @@ impl FocusNode for FocusTargetNode {
 #[derive(Clone)]
 pub struct FocusTargetElement {
     on_focus_changed: Option<Rc<dyn Fn(FocusState)>>,
+    indication: Option<FocusIndication>,
 }
 
 impl FocusTargetElement {
     pub fn new() -> Self {
         Self {
             on_focus_changed: None,
+            indication: None,
         }
     }
 
@@ -146,8 +351,15 @@ impl FocusTargetElement {
     {
         Self {
             on_focus_changed: Some(Rc::new(callback)),
+            indication: None,
         }
     }
+
+    /// Draws a focus ring of `indication` while focused, or none if `None`.
+    pub fn with_indication(mut self, indication: Option<FocusIndication>) -> Self {
+        self.indication = indication;
+        self
+    }
 }
 
 impl Default for FocusTargetElement {
@@ -160,6 +372,7 @@ impl std::fmt::Debug for FocusTargetElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FocusTargetElement")
             .field("has_callback", &self.on_focus_changed.is_some())
+            .field("indication", &self.indication)
             .finish()
     }
 }
@@ -169,6 +382,7 @@ impl PartialEq for FocusTargetElement {
         // Type-based matching: compare only presence of callback, not pointer
         // Nodes are updated via update() method, preserving behavior
         self.on_focus_changed.is_some() == other.on_focus_changed.is_some()
+            && self.indication == other.indication
     }
 }
 
@@ -177,6 +391,14 @@ impl Hash for FocusTargetElement {
         // Consistent hash based on callback presence only
         "focus_target".hash(state);
         self.on_focus_changed.is_some().hash(state);
+        if let Some(indication) = self.indication {
+            indication.color.0.to_bits().hash(state);
+            indication.color.1.to_bits().hash(state);
+            indication.color.2.to_bits().hash(state);
+            indication.color.3.to_bits().hash(state);
+            indication.width.to_bits().hash(state);
+            indication.offset.to_bits().hash(state);
+        }
     }
 }
 
@@ -184,18 +406,21 @@ impl ModifierNodeElement for FocusTargetElement {
     type Node = FocusTargetNode;
 
     fn create(&self) -> Self::Node {
-        if let Some(callback) = &self.on_focus_changed {
+        let mut node = if let Some(callback) = &self.on_focus_changed {
             FocusTargetNode::with_callback({
                 let callback = callback.clone();
                 move |state| callback(state)
             })
         } else {
             FocusTargetNode::new()
-        }
+        };
+        node.indication = self.indication;
+        node
     }
 
     fn update(&self, node: &mut Self::Node) {
         node.on_focus_changed = self.on_focus_changed.clone();
+        node.indication = self.indication;
     }
 
     fn inspector_name(&self) -> &'static str {
@@ -203,7 +428,11 @@ impl ModifierNodeElement for FocusTargetElement {
     }
 
     fn capabilities(&self) -> NodeCapabilities {
-        NodeCapabilities::FOCUS
+        if self.indication.is_some() {
+            NodeCapabilities::FOCUS | NodeCapabilities::DRAW
+        } else {
+            NodeCapabilities::FOCUS
+        }
     }
 
     fn always_update(&self) -> bool {
@@ -288,6 +517,108 @@ impl ModifierNodeElement for FocusRequesterElement {
     }
 }
 
+/// Handles a key event, returning `true` to consume it.
+pub type KeyEventHandler = Rc<dyn Fn(&KeyEvent) -> bool>;
+
+/// A node receiving the key events of the focused element.
+///
+/// Key events start at the focused node and bubble to its ancestors until a
+/// handler consumes them (see [`crate::dispatch_focused_key_event`]).
+pub struct KeyInputNode {
+    state: NodeState,
+    on_key_event: KeyEventHandler,
+}
+
+impl KeyInputNode {
+    pub fn new(on_key_event: KeyEventHandler) -> Self {
+        Self {
+            state: NodeState::new(),
+            on_key_event,
+        }
+    }
+
+    pub fn handler(&self) -> KeyEventHandler {
+        self.on_key_event.clone()
+    }
+}
+
+impl DelegatableNode for KeyInputNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for KeyInputNode {
+    fn on_attach(&mut self, _context: &mut dyn ModifierNodeContext) {
+        self.state.set_attached(true);
+    }
+
+    fn on_detach(&mut self) {
+        self.state.set_attached(false);
+    }
+}
+
+/// Modifier element for key event handlers.
+#[derive(Clone)]
+pub struct KeyInputElement {
+    on_key_event: KeyEventHandler,
+}
+
+impl KeyInputElement {
+    pub fn new<F>(on_key_event: F) -> Self
+    where
+        F: Fn(&KeyEvent) -> bool + 'static,
+    {
+        Self {
+            on_key_event: Rc::new(on_key_event),
+        }
+    }
+}
+
+impl std::fmt::Debug for KeyInputElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyInputElement").finish()
+    }
+}
+
+impl PartialEq for KeyInputElement {
+    fn eq(&self, _other: &Self) -> bool {
+        // Type-based matching; update() swaps in the new handler
+        true
+    }
+}
+
+impl Hash for KeyInputElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        "key_input".hash(state);
+    }
+}
+
+impl ModifierNodeElement for KeyInputElement {
+    type Node = KeyInputNode;
+
+    fn create(&self) -> Self::Node {
+        KeyInputNode::new(self.on_key_event.clone())
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.on_key_event = self.on_key_event.clone();
+    }
+
+    fn inspector_name(&self) -> &'static str {
+        "onKeyEvent"
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::FOCUS
+    }
+
+    fn always_update(&self) -> bool {
+        // Always update to capture new closure if it changed
+        true
+    }
+}
+
 /// A handle for requesting focus programmatically.
 ///
 /// This mirrors Jetpack Compose's FocusRequester class and provides
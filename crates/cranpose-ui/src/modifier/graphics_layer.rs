@@ -1,5 +1,7 @@
 use super::{inspector_metadata, GraphicsLayer, Modifier};
-use crate::modifier_nodes::GraphicsLayerElement;
+use crate::composition_local::resolve_dp;
+use crate::modifier_nodes::{AlphaElement, BlurElement, GraphicsLayerElement};
+use cranpose_ui_graphics::Dp;
 
 impl Modifier {
     /// Apply a graphics layer with transformations and alpha.
@@ -13,7 +15,54 @@ impl Modifier {
                 info.add_property("scale", inspector_values.scale.to_string());
                 info.add_property("translationX", inspector_values.translation_x.to_string());
                 info.add_property("translationY", inspector_values.translation_y.to_string());
+                info.add_property("clip", inspector_values.clip.to_string());
+                info.add_property(
+                    "shadowElevation",
+                    inspector_values.shadow_elevation.to_string(),
+                );
             }));
         self.then(modifier)
     }
+
+    /// Draw this layout and its children with the given opacity.
+    ///
+    /// Alpha is multiplied into the color of every draw in the subtree, so
+    /// overlapping children blend individually rather than as one group.
+    ///
+    /// Matches Kotlin: `Modifier.alpha(alpha)`
+    ///
+    /// Example: `Modifier::empty().alpha(0.5)`
+    pub fn alpha(self, alpha: f32) -> Self {
+        let modifier = Self::with_element(AlphaElement::new(alpha)).with_inspector_metadata(
+            inspector_metadata("alpha", move |info| {
+                info.add_property("alpha", alpha.to_string());
+            }),
+        );
+        self.then(modifier)
+    }
+
+    /// Blur this layout and its children with a gaussian of the given radius.
+    ///
+    /// The radius is in dp. The subtree is rendered offscreen and blurred
+    /// before compositing. The radius is clamped to
+    /// [`MAX_BLUR_RADIUS`](crate::MAX_BLUR_RADIUS) logical pixels; radii below
+    /// half a pixel draw the content unchanged. Only the wgpu renderer blurs;
+    /// the pixels renderer draws the content unchanged at any radius.
+    ///
+    /// Blurs do not nest: a blurred subtree inside another one is blurred
+    /// only with the outermost radius, and the renderer logs a warning the
+    /// first time it drops an inner blur.
+    ///
+    /// Matches Kotlin: `Modifier.blur(radius: Dp)`
+    ///
+    /// Example: `Modifier::empty().blur(8.0)`
+    pub fn blur(self, radius: impl Into<Dp>) -> Self {
+        let radius = resolve_dp(radius.into());
+        let modifier = Self::with_element(BlurElement::new(radius)).with_inspector_metadata(
+            inspector_metadata("blur", move |info| {
+                info.add_property("radius", radius.to_string());
+            }),
+        );
+        self.then(modifier)
+    }
 }
@@ -0,0 +1,155 @@
+//! Hover tracking for mouse and pen pointers.
+//!
+//! The app shell hit-tests every move made without a button pressed and sends
+//! `Enter` to nodes the pointer moved onto and `Exit` to nodes it left. The
+//! hover node turns those into `on_hover(true)` / `on_hover(false)` calls.
+
+use super::{inspector_metadata, Modifier, PointerEvent, PointerEventKind};
+use cranpose_core::MutableState;
+use cranpose_foundation::{
+    impl_pointer_input_node, DelegatableNode, ModifierNode, ModifierNodeElement, NodeCapabilities,
+    NodeState, PointerInputNode,
+};
+use std::cell::{Cell, RefCell};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+type HoverCallback = Rc<dyn Fn(bool)>;
+
+impl Modifier {
+    /// Calls `on_hover(true)` when the pointer moves onto the element and
+    /// `on_hover(false)` when it leaves.
+    ///
+    /// Only pointers without a pressed button hover, so touch input never does.
+    ///
+    /// Example: `Modifier::empty().on_hover(move |hovered| highlighted.set(hovered))`
+    pub fn on_hover(self, on_hover: impl Fn(bool) + 'static) -> Self {
+        let modifier = Self::with_element(HoverElement::new(Rc::new(on_hover)))
+            .with_inspector_metadata(inspector_metadata("onHover", |info| {
+                info.add_property("onHover", "provided");
+            }));
+        self.then(modifier)
+    }
+
+    /// Keeps `hovered` in sync with whether the pointer is over the element.
+    ///
+    /// Matches Kotlin: `Modifier.hoverable(interactionSource)` together with
+    /// `interactionSource.collectIsHoveredAsState()`.
+    pub fn hoverable(self, hovered: MutableState<bool>) -> Self {
+        self.on_hover(move |is_hovered| {
+            if hovered.get() != is_hovered {
+                hovered.set(is_hovered);
+            }
+        })
+    }
+}
+
+#[derive(Clone)]
+struct HoverElement {
+    on_hover: HoverCallback,
+}
+
+impl HoverElement {
+    fn new(on_hover: HoverCallback) -> Self {
+        Self { on_hover }
+    }
+}
+
+impl std::fmt::Debug for HoverElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HoverElement").finish()
+    }
+}
+
+impl PartialEq for HoverElement {
+    fn eq(&self, _other: &Self) -> bool {
+        // The callback is swapped in update(), so the node and its hover
+        // state survive recomposition
+        true
+    }
+}
+
+impl Eq for HoverElement {}
+
+impl Hash for HoverElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        "onHover".hash(state);
+    }
+}
+
+impl ModifierNodeElement for HoverElement {
+    type Node = HoverNode;
+
+    fn create(&self) -> Self::Node {
+        HoverNode::new(self.on_hover.clone())
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        *node.on_hover.borrow_mut() = self.on_hover.clone();
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::POINTER_INPUT
+    }
+
+    fn always_update(&self) -> bool {
+        // Always update to capture the new closure
+        true
+    }
+}
+
+/// Node that reports pointer enter/exit to a callback.
+struct HoverNode {
+    state: NodeState,
+    on_hover: Rc<RefCell<HoverCallback>>,
+    hovered: Rc<Cell<bool>>,
+    handler: Rc<dyn Fn(PointerEvent)>,
+}
+
+impl HoverNode {
+    fn new(on_hover: HoverCallback) -> Self {
+        let on_hover = Rc::new(RefCell::new(on_hover));
+        let hovered = Rc::new(Cell::new(false));
+        let handler = {
+            let on_hover = on_hover.clone();
+            let hovered = hovered.clone();
+            Rc::new(move |event: PointerEvent| {
+                let is_hovered = match event.kind {
+                    PointerEventKind::Enter => true,
+                    PointerEventKind::Exit => false,
+                    _ => return,
+                };
+                if hovered.replace(is_hovered) != is_hovered {
+                    let callback = on_hover.borrow().clone();
+                    callback(is_hovered);
+                }
+            })
+        };
+        Self {
+            state: NodeState::new(),
+            on_hover,
+            hovered,
+            handler,
+        }
+    }
+}
+
+impl ModifierNode for HoverNode {
+    fn on_detach(&mut self) {
+        self.hovered.set(false);
+    }
+
+    impl_pointer_input_node!();
+}
+
+impl DelegatableNode for HoverNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl PointerInputNode for HoverNode {
+    fn pointer_input_handler(&self) -> Option<Rc<dyn Fn(PointerEvent)>> {
+        Some(self.handler.clone())
+    }
+}
@@ -11,55 +11,73 @@ use std::fmt;
 use std::rc::Rc;
 
 mod alignment;
+mod aspect_ratio;
 mod background;
+mod block_pointer_input;
+mod border;
 mod chain;
 mod clickable;
 mod draw_cache;
+mod fading_edges;
 mod fill;
 mod focus;
 mod graphics_layer;
+mod hoverable;
 mod local;
 mod offset;
 mod padding;
+mod pointer_cursor;
 mod pointer_input;
 mod scroll;
 mod semantics;
+mod shadow;
 mod size;
 mod slices;
+mod transformable;
 mod weight;
+mod z_index;
 
 pub use crate::draw::{DrawCacheBuilder, DrawCommand};
+pub use block_pointer_input::{BlockPointerInputElement, BlockPointerInputNode};
 #[allow(unused_imports)]
 pub use chain::{ModifierChainHandle, ModifierChainInspectorNode, ModifierLocalsHandle};
 use cranpose_foundation::ModifierNodeElement;
 pub use cranpose_foundation::{
-    modifier_element, AnyModifierElement, DynModifierElement, FocusState, PointerEvent,
-    PointerEventKind, SemanticsConfiguration,
+    modifier_element, AnyModifierElement, CursorIcon, DynModifierElement, FocusState, PointerEvent,
+    PointerEventKind, Role, SemanticsConfiguration,
 };
 pub use cranpose_ui_graphics::{
-    Brush, Color, CornerRadii, EdgeInsets, GraphicsLayer, Point, Rect, RoundedCornerShape, Size,
+    Brush, Color, ColorParseError, CornerRadii, EdgeInsets, GraphicsLayer, Point, Rect,
+    RoundedCornerShape, Size,
+};
+use cranpose_ui_layout::{
+    Alignment, AlignmentLine, HorizontalAlignment, IntrinsicSize, VerticalAlignment,
 };
-use cranpose_ui_layout::{Alignment, HorizontalAlignment, IntrinsicSize, VerticalAlignment};
 #[allow(unused_imports)]
-pub use focus::{FocusDirection, FocusRequester};
+pub use focus::{FocusDirection, FocusIndication, FocusRequester, KeyEventHandler};
 pub(crate) use local::{
     ModifierLocalAncestorResolver, ModifierLocalSource, ModifierLocalToken, ResolvedModifierLocal,
 };
 #[allow(unused_imports)]
 pub use local::{ModifierLocalKey, ModifierLocalReadScope};
+pub use pointer_cursor::{PointerCursorElement, PointerCursorNode};
 #[allow(unused_imports)]
 pub use pointer_input::{AwaitPointerEventScope, PointerInputScope};
 pub use semantics::{collect_semantics_from_chain, collect_semantics_from_modifier};
+pub use shadow::{ElevationCurve, ShadowStyle};
 pub use slices::{
     collect_modifier_slices, collect_modifier_slices_into, collect_slices_from_modifier,
     ModifierNodeSlices,
 };
+pub use transformable::{TransformChange, TransformableState};
 // Test accessibility for fling velocity (only with test-helpers feature)
 #[cfg(feature = "test-helpers")]
 pub use scroll::{last_fling_velocity, reset_last_fling_velocity};
 
+use crate::bring_into_view::{BringIntoViewRequester, BringIntoViewRequesterElement};
 use crate::modifier_nodes::ClipToBoundsElement;
-use focus::{FocusRequesterElement, FocusTargetElement};
+use crate::text_selection::SelectableTextElement;
+use focus::{FocusRequesterElement, FocusTargetElement, KeyInputElement};
 use local::{ModifierLocalConsumerElement, ModifierLocalProviderElement};
 use semantics::SemanticsElement;
 
@@ -385,6 +403,8 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Merges the semantics written by `recorder` (content description, role,
+    /// state description, ...) into the node's semantics.
     pub fn semantics<F>(self, recorder: F) -> Self
     where
         F: Fn(&mut SemanticsConfiguration) + 'static,
@@ -392,18 +412,30 @@ impl Modifier {
         let mut preview = SemanticsConfiguration::default();
         recorder(&mut preview);
         let description = preview.content_description.clone();
+        let role = preview.role;
+        let state = preview.state_description.clone();
         let is_button = preview.is_button;
         let is_clickable = preview.is_clickable;
+        let merge_descendants = preview.merge_descendants;
         let metadata = inspector_metadata("semantics", move |info| {
             if let Some(desc) = &description {
                 info.add_property("contentDescription", desc.clone());
             }
+            if let Some(role) = role {
+                info.add_property("role", format!("{role:?}"));
+            }
+            if let Some(state) = &state {
+                info.add_property("stateDescription", state.clone());
+            }
             if is_button {
                 info.add_property("isButton", "true");
             }
             if is_clickable {
                 info.add_property("isClickable", "true");
             }
+            if merge_descendants {
+                info.add_property("mergeDescendants", "true");
+            }
         });
         let element = SemanticsElement::new(recorder);
         let modifier =
@@ -411,6 +443,25 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Like [`Modifier::semantics`], and also merges the semantics of all
+    /// descendants into this node.
+    ///
+    /// The descendants' texts and content descriptions are combined into this
+    /// node's description and they leave the semantics tree, so a button
+    /// around a `Text` is announced as one element. Descendants that merge
+    /// their own descendants stay separate nodes.
+    ///
+    /// Matches Kotlin: `Modifier.semantics(mergeDescendants = true) { ... }`
+    pub fn merged_semantics<F>(self, recorder: F) -> Self
+    where
+        F: Fn(&mut SemanticsConfiguration) + 'static,
+    {
+        self.semantics(move |config| {
+            config.merge_descendants = true;
+            recorder(config);
+        })
+    }
+
     /// Makes this component focusable.
     ///
     /// This adds a focus target node that can receive focus and participate
@@ -422,6 +473,28 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Makes this component focusable.
+    ///
+    /// Focusable components are visited by Tab/Shift-Tab traversal in reading
+    /// order. While focused by the keyboard or from code, they draw the focus
+    /// ring of the nearest [`crate::local_focus_indication`].
+    ///
+    /// Matches Kotlin: `Modifier.focusable()`
+    pub fn focusable(self) -> Self {
+        let indication = crate::local_focus_indication().current_or_default();
+        self.focusable_with_indication(indication)
+    }
+
+    /// Makes this component focusable with its own focus ring.
+    ///
+    /// Pass `None` to draw no focus ring, e.g. when the component shows focus
+    /// itself through [`Modifier::on_focus_changed`].
+    pub fn focusable_with_indication(self, indication: Option<FocusIndication>) -> Self {
+        let element = FocusTargetElement::new().with_indication(indication);
+        let modifier = Modifier::from_parts(vec![modifier_element(element)]);
+        self.then(modifier)
+    }
+
     /// Makes this component focusable with a callback for focus changes.
     ///
     /// The callback is invoked whenever the focus state changes, allowing
@@ -435,6 +508,23 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Handles key events while this component or one of its descendants is
+    /// focused.
+    ///
+    /// Events reach the focused component first and then bubble to its
+    /// ancestors; returning `true` consumes the event so outer handlers and
+    /// the default Tab traversal don't see it.
+    ///
+    /// Matches Kotlin: `Modifier.onKeyEvent { event -> Boolean }`
+    pub fn on_key_event<F>(self, handler: F) -> Self
+    where
+        F: Fn(&crate::KeyEvent) -> bool + 'static,
+    {
+        let element = KeyInputElement::new(handler);
+        let modifier = Modifier::from_parts(vec![modifier_element(element)]);
+        self.then(modifier)
+    }
+
     /// Attaches a focus requester to this component.
     ///
     /// The requester can be used to programmatically request focus for
@@ -445,6 +535,35 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Attaches a bring-into-view requester to this component.
+    ///
+    /// [`BringIntoViewRequester::bring_into_view`] then scrolls the enclosing
+    /// scroll containers until this component is fully visible.
+    ///
+    /// Matches Kotlin: `Modifier.bringIntoViewRequester(requester)`
+    pub fn bring_into_view_requester(self, requester: &BringIntoViewRequester) -> Self {
+        let element = BringIntoViewRequesterElement::new(requester.clone());
+        let modifier = Modifier::from_parts(vec![modifier_element(element)]);
+        self.then(modifier)
+    }
+
+    /// Lets the user select this component's text by pressing and dragging.
+    ///
+    /// The selected range is highlighted behind the glyphs and can be copied
+    /// through the platform clipboard (see [`crate::copy_selected_text`]).
+    ///
+    /// The highlight uses the selection color of the nearest
+    /// [`crate::local_text_selection_colors`].
+    ///
+    /// Matches Kotlin: `SelectionContainer { Text(...) }`
+    pub fn selectable_text(self) -> Self {
+        let colors = crate::local_text_selection_colors().current_or_default();
+        let element = SelectableTextElement::new(colors.selection_color);
+        let modifier = Modifier::from_parts(vec![modifier_element(element)])
+            .with_inspector_metadata(inspector_metadata("selectableText", |_| {}));
+        self.then(modifier)
+    }
+
     /// Enables debug logging for this modifier chain.
     ///
     /// When enabled, logs the entire modifier chain structure including:
@@ -945,8 +1064,10 @@ pub struct LayoutProperties {
     max_height: Option<f32>,
     weight: Option<LayoutWeight>,
     box_alignment: Option<Alignment>,
+    match_parent_size: bool,
     column_alignment: Option<HorizontalAlignment>,
     row_alignment: Option<VerticalAlignment>,
+    align_by: Option<AlignmentLine>,
 }
 
 impl LayoutProperties {
@@ -986,6 +1107,10 @@ impl LayoutProperties {
         self.box_alignment
     }
 
+    pub fn match_parent_size(&self) -> bool {
+        self.match_parent_size
+    }
+
     pub fn column_alignment(&self) -> Option<HorizontalAlignment> {
         self.column_alignment
     }
@@ -993,6 +1118,10 @@ impl LayoutProperties {
     pub fn row_alignment(&self) -> Option<VerticalAlignment> {
         self.row_alignment
     }
+
+    pub fn align_by(&self) -> Option<AlignmentLine> {
+        self.align_by
+    }
 }
 
 #[cfg(test)]
@@ -3,7 +3,7 @@
 //! Reference: /media/huge/composerepo/compose/foundation/foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Offset.kt
 
 use super::{inspector_metadata, Modifier, Point};
-use crate::modifier_nodes::OffsetElement;
+use crate::modifier_nodes::{OffsetElement, VisualOffsetElement};
 
 impl Modifier {
     /// Offset the content by (x, y). The offsets can be positive or negative.
@@ -38,4 +38,21 @@ impl Modifier {
         );
         self.then(modifier)
     }
+
+    /// Offset where the content is drawn by (x, y) without moving its layout bounds.
+    ///
+    /// Unlike [`offset`](Self::offset), the node keeps its laid-out position, so
+    /// the parent's arrangement and sibling placement are unaffected.
+    ///
+    /// Matches Kotlin: `Modifier.graphicsLayer { translationX = x; translationY = y }`
+    ///
+    /// Example: `Modifier::empty().offset_visual(0.0, -4.0)`
+    pub fn offset_visual(self, x: f32, y: f32) -> Self {
+        let modifier = Self::with_element(VisualOffsetElement::new(x, y)).with_inspector_metadata(
+            inspector_metadata("offsetVisual", move |info| {
+                info.add_offset_components("translationX", "translationY", Point { x, y });
+            }),
+        );
+        self.then(modifier)
+    }
 }
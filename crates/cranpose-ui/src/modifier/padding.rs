@@ -1,12 +1,18 @@
 use super::{inspector_metadata, EdgeInsets, InspectorMetadata, Modifier};
+use crate::composition_local::resolve_dp;
 use crate::modifier_nodes::PaddingElement;
+use cranpose_ui_graphics::Dp;
 
 impl Modifier {
     /// Add uniform padding to all sides.
     ///
-    /// Example: `Modifier::empty().padding(16.0)`
-    pub fn padding(self, p: f32) -> Self {
-        let padding = EdgeInsets::uniform(p);
+    /// Padding is given in [`Dp`] and resolved under the nearest
+    /// [`local_density`](crate::local_density) when the modifier is created;
+    /// plain `f32` values are taken as dp.
+    ///
+    /// Example: `Modifier::empty().padding(16.dp())`
+    pub fn padding(self, p: impl Into<Dp>) -> Self {
+        let padding = EdgeInsets::uniform(resolve_dp(p.into()));
         let modifier = Self::with_element(PaddingElement::new(padding))
             .with_inspector_metadata(padding_metadata(padding));
         self.then(modifier)
@@ -15,8 +21,8 @@ impl Modifier {
     /// Add horizontal padding (left and right).
     ///
     /// Example: `Modifier::empty().padding_horizontal(16.0)`
-    pub fn padding_horizontal(self, horizontal: f32) -> Self {
-        let padding = EdgeInsets::horizontal(horizontal);
+    pub fn padding_horizontal(self, horizontal: impl Into<Dp>) -> Self {
+        let padding = EdgeInsets::horizontal(resolve_dp(horizontal.into()));
         let modifier = Self::with_element(PaddingElement::new(padding))
             .with_inspector_metadata(padding_metadata(padding));
         self.then(modifier)
@@ -25,8 +31,8 @@ impl Modifier {
     /// Add vertical padding (top and bottom).
     ///
     /// Example: `Modifier::empty().padding_vertical(8.0)`
-    pub fn padding_vertical(self, vertical: f32) -> Self {
-        let padding = EdgeInsets::vertical(vertical);
+    pub fn padding_vertical(self, vertical: impl Into<Dp>) -> Self {
+        let padding = EdgeInsets::vertical(resolve_dp(vertical.into()));
         let modifier = Self::with_element(PaddingElement::new(padding))
             .with_inspector_metadata(padding_metadata(padding));
         self.then(modifier)
@@ -35,8 +41,9 @@ impl Modifier {
     /// Add symmetric padding (horizontal and vertical).
     ///
     /// Example: `Modifier::empty().padding_symmetric(16.0, 8.0)`
-    pub fn padding_symmetric(self, horizontal: f32, vertical: f32) -> Self {
-        let padding = EdgeInsets::symmetric(horizontal, vertical);
+    pub fn padding_symmetric(self, horizontal: impl Into<Dp>, vertical: impl Into<Dp>) -> Self {
+        let padding =
+            EdgeInsets::symmetric(resolve_dp(horizontal.into()), resolve_dp(vertical.into()));
         let modifier = Self::with_element(PaddingElement::new(padding))
             .with_inspector_metadata(padding_metadata(padding));
         self.then(modifier)
@@ -45,8 +52,19 @@ impl Modifier {
     /// Add padding to each side individually.
     ///
     /// Example: `Modifier::empty().padding_each(8.0, 4.0, 8.0, 4.0)`
-    pub fn padding_each(self, left: f32, top: f32, right: f32, bottom: f32) -> Self {
-        let padding = EdgeInsets::from_components(left, top, right, bottom);
+    pub fn padding_each(
+        self,
+        left: impl Into<Dp>,
+        top: impl Into<Dp>,
+        right: impl Into<Dp>,
+        bottom: impl Into<Dp>,
+    ) -> Self {
+        let padding = EdgeInsets::from_components(
+            resolve_dp(left.into()),
+            resolve_dp(top.into()),
+            resolve_dp(right.into()),
+            resolve_dp(bottom.into()),
+        );
         let modifier = Self::with_element(PaddingElement::new(padding))
             .with_inspector_metadata(padding_metadata(padding));
         self.then(modifier)
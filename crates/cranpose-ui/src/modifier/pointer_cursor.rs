@@ -0,0 +1,84 @@
+//! Per-element mouse cursor.
+//!
+//! The app shell looks up the cursor of the topmost hovered element that asks
+//! for one and applies it to the window; the default arrow is used elsewhere.
+
+use super::{inspector_metadata, CursorIcon, Modifier, PointerEvent};
+use cranpose_foundation::{
+    impl_pointer_input_node, DelegatableNode, ModifierNode, ModifierNodeElement, NodeCapabilities,
+    NodeState, PointerInputNode,
+};
+use std::rc::Rc;
+
+impl Modifier {
+    /// Shows `icon` as the mouse cursor while the pointer is over the element.
+    ///
+    /// Nested elements win over their ancestors, and the rightmost
+    /// `pointer_cursor` on one element wins.
+    ///
+    /// Matches Kotlin: `Modifier.pointerHoverIcon(icon)`
+    ///
+    /// Example: `Modifier::empty().clickable(|_| {}).pointer_cursor(CursorIcon::Pointer)`
+    pub fn pointer_cursor(self, icon: CursorIcon) -> Self {
+        let modifier = Self::with_element(PointerCursorElement { icon }).with_inspector_metadata(
+            inspector_metadata("pointerCursor", move |info| {
+                info.add_property("icon", format!("{icon:?}"));
+            }),
+        );
+        self.then(modifier)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PointerCursorElement {
+    icon: CursorIcon,
+}
+
+impl ModifierNodeElement for PointerCursorElement {
+    type Node = PointerCursorNode;
+
+    fn create(&self) -> Self::Node {
+        PointerCursorNode {
+            state: NodeState::new(),
+            icon: self.icon,
+        }
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.icon = self.icon;
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::POINTER_INPUT
+    }
+}
+
+/// Node that records the cursor requested for its element.
+pub struct PointerCursorNode {
+    state: NodeState,
+    icon: CursorIcon,
+}
+
+impl PointerCursorNode {
+    pub fn icon(&self) -> CursorIcon {
+        self.icon
+    }
+}
+
+impl ModifierNode for PointerCursorNode {
+    impl_pointer_input_node!();
+}
+
+impl DelegatableNode for PointerCursorNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl PointerInputNode for PointerCursorNode {
+    fn pointer_input_handler(&self) -> Option<Rc<dyn Fn(PointerEvent)>> {
+        // Events are ignored; the handler only gives the element a hit region
+        // so the app shell finds it under the pointer
+        Some(Rc::new(|_event: PointerEvent| {}))
+    }
+}
@@ -18,7 +18,7 @@ use super::{inspector_metadata, Modifier, Point, PointerEventKind};
 use crate::current_density;
 use crate::fling_animation::FlingAnimation;
 use crate::fling_animation::MIN_FLING_VELOCITY;
-use crate::scroll::{ScrollElement, ScrollState};
+use crate::scroll::{LazyScrollElement, ScrollElement, ScrollState};
 use cranpose_core::current_runtime_handle;
 use cranpose_foundation::{
     velocity_tracker::ASSUME_STOPPED_MS, PointerButton, PointerButtons, VelocityTracker1D,
@@ -176,6 +176,11 @@ trait ScrollTarget: Clone {
 
     /// Get the current scroll offset.
     fn current_offset(&self) -> f32;
+
+    /// Smallest and largest offsets, when the target knows them.
+    fn offset_bounds(&self) -> Option<(f32, f32)> {
+        None
+    }
 }
 
 impl ScrollTarget for ScrollState {
@@ -195,6 +200,10 @@ impl ScrollTarget for ScrollState {
     fn current_offset(&self) -> f32 {
         self.value()
     }
+
+    fn offset_bounds(&self) -> Option<(f32, f32)> {
+        Some((0.0, self.max_value()))
+    }
 }
 
 impl ScrollTarget for LazyListState {
@@ -439,22 +448,34 @@ impl<S: ScrollTarget + 'static> ScrollGestureDetector<S> {
 
                 let scroll_target_for_fling = scroll_target.clone();
                 let scroll_target_for_end = scroll_target.clone();
-
-                fling.start_fling(
-                    initial_value,
-                    fling_velocity,
-                    current_density(),
-                    move |delta| {
-                        // Apply scroll delta during fling, return consumed amount
-                        let consumed = scroll_target_for_fling.apply_fling_delta(delta);
-                        scroll_target_for_fling.invalidate();
-                        consumed
-                    },
-                    move || {
-                        // Animation complete - invalidate to ensure final render
-                        scroll_target_for_end.invalidate();
-                    },
-                );
+                let on_scroll = move |delta| {
+                    // Apply scroll delta during fling, return consumed amount
+                    let consumed = scroll_target_for_fling.apply_fling_delta(delta);
+                    scroll_target_for_fling.invalidate();
+                    consumed
+                };
+                let on_end = move || {
+                    // Animation complete - invalidate to ensure final render
+                    scroll_target_for_end.invalidate();
+                };
+
+                match scroll_target.offset_bounds() {
+                    Some(bounds) => fling.start_fling_in_bounds(
+                        initial_value,
+                        fling_velocity,
+                        current_density(),
+                        bounds,
+                        on_scroll,
+                        on_end,
+                    ),
+                    None => fling.start_fling(
+                        initial_value,
+                        fling_velocity,
+                        current_density(),
+                        on_scroll,
+                        on_end,
+                    ),
+                }
 
                 let mut gs = self.gesture_state.borrow_mut();
                 gs.fling_animation = Some(fling);
@@ -560,6 +581,7 @@ fn scroll_impl(state: ScrollState, is_vertical: bool, reverse_scrolling: bool) -
                             }
                             PointerEventKind::Up => detector.on_up(),
                             PointerEventKind::Cancel => detector.on_cancel(),
+                            PointerEventKind::Enter | PointerEventKind::Exit => false,
                         };
 
                         if should_consume {
@@ -630,7 +652,7 @@ fn lazy_scroll_impl(state: LazyListState, is_vertical: bool, reverse_scrolling:
     let state_id = std::ptr::addr_of!(*state.inner_ptr()) as usize;
     let key = (state_id, is_vertical, reverse_scrolling);
 
-    Modifier::empty().pointer_input(key, move |scope| {
+    let pointer_input = Modifier::empty().pointer_input(key, move |scope| {
         // Use the same generic detector with LazyListState
         let detector = ScrollGestureDetector::new(
             gesture_state.clone(),
@@ -653,6 +675,7 @@ fn lazy_scroll_impl(state: LazyListState, is_vertical: bool, reverse_scrolling:
                             }
                             PointerEventKind::Up => detector.on_up(),
                             PointerEventKind::Cancel => detector.on_cancel(),
+                            PointerEventKind::Enter | PointerEventKind::Exit => false,
                         };
 
                         if should_consume {
@@ -662,5 +685,12 @@ fn lazy_scroll_impl(state: LazyListState, is_vertical: bool, reverse_scrolling:
                 })
                 .await;
         }
-    })
+    });
+
+    // Lets bring-into-view requests scroll the list through its state
+    pointer_input.then(Modifier::with_element(LazyScrollElement::new(
+        state,
+        is_vertical,
+        reverse_scrolling,
+    )))
 }
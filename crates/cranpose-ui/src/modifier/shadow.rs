@@ -0,0 +1,140 @@
+use super::{inspector_metadata, Modifier, RoundedCornerShape};
+use crate::composition_local::local_elevation_curve;
+use crate::modifier_nodes::ShadowElement;
+use cranpose_ui_graphics::Dp;
+use std::fmt;
+use std::rc::Rc;
+
+/// How a drop shadow is drawn: how far it spreads, how far it drops and how dark it is.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ShadowStyle {
+    /// Distance the shadow spreads beyond the shape's edges.
+    pub blur_radius: f32,
+    /// Downward offset of the shadow, as if lit from above the screen.
+    pub offset_y: f32,
+    /// Combined opacity of the shadow under the shape.
+    pub alpha: f32,
+}
+
+/// Maps an elevation to the [`ShadowStyle`] drawn for it.
+///
+/// Components describe depth as an elevation and leave the look of the shadow
+/// to the curve, so cards and buttons at the same elevation match across the
+/// app. Provide a different curve through [`local_elevation_curve`] to restyle
+/// every shadow below it.
+#[derive(Clone)]
+pub struct ElevationCurve {
+    map: Rc<dyn Fn(Dp) -> ShadowStyle>,
+}
+
+impl ElevationCurve {
+    pub fn new(map: impl Fn(Dp) -> ShadowStyle + 'static) -> Self {
+        Self { map: Rc::new(map) }
+    }
+
+    /// Returns the shadow drawn at `elevation`; non-positive elevations draw none.
+    pub fn shadow(&self, elevation: Dp) -> ShadowStyle {
+        if elevation.0 <= 0.0 {
+            return ShadowStyle::default();
+        }
+        (self.map)(elevation)
+    }
+}
+
+impl Default for ElevationCurve {
+    /// Spreads and drops the shadow in proportion to the elevation, while the
+    /// opacity rises quickly at low elevations and levels off below 0.3.
+    fn default() -> Self {
+        Self::new(|elevation| ShadowStyle {
+            blur_radius: elevation.0,
+            offset_y: elevation.0 / 2.0,
+            alpha: 0.3 * elevation.0 / (elevation.0 + 2.0),
+        })
+    }
+}
+
+impl fmt::Debug for ElevationCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElevationCurve").finish_non_exhaustive()
+    }
+}
+
+impl Modifier {
+    /// Draw a drop shadow in `shape` behind this layout.
+    ///
+    /// The shadow's look comes from the [`ElevationCurve`] provided through
+    /// [`local_elevation_curve`] where the modifier is created, or the default
+    /// curve outside a composition.
+    ///
+    /// Matches Kotlin: `Modifier.shadow(elevation, shape)`
+    ///
+    /// Example: `Modifier::empty().shadow(Dp(4.0), RoundedCornerShape::uniform(8.0))`
+    pub fn shadow(self, elevation: Dp, shape: RoundedCornerShape) -> Self {
+        let style = local_elevation_curve()
+            .current_or_default()
+            .shadow(elevation);
+        let modifier = Self::with_element(ShadowElement::new(style, shape))
+            .with_inspector_metadata(inspector_metadata("shadow", move |info| {
+                info.add_property("elevation", elevation.0.to_string());
+            }));
+        self.then(modifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modifier::{collect_slices_from_modifier, Size};
+    use crate::run_test_composition;
+    use cranpose_core::CompositionLocalProvider;
+    use cranpose_ui_graphics::DrawPrimitive;
+    use std::cell::Cell;
+
+    #[test]
+    fn default_curve_blurs_more_at_higher_elevations() {
+        let curve = ElevationCurve::default();
+        let low = curve.shadow(Dp(4.0));
+        let high = curve.shadow(Dp(8.0));
+        assert!(low.blur_radius > 0.0);
+        assert!(high.blur_radius > low.blur_radius);
+        assert!(high.offset_y > low.offset_y);
+        assert!(high.alpha >= low.alpha && high.alpha <= 0.3);
+        assert_eq!(curve.shadow(Dp(0.0)), ShadowStyle::default());
+    }
+
+    #[test]
+    fn shadow_uses_provided_curve_and_shape() {
+        let flat = ShadowStyle {
+            blur_radius: 2.0,
+            offset_y: 0.0,
+            alpha: 0.4,
+        };
+        let modifier = Cell::new(None);
+        run_test_composition(|| {
+            let curve = ElevationCurve::new(move |_| flat);
+            CompositionLocalProvider(vec![local_elevation_curve().provides(curve)], || {
+                modifier.set(Some(
+                    Modifier::empty().shadow(Dp(12.0), RoundedCornerShape::uniform(4.0)),
+                ));
+            });
+        });
+
+        let slices = collect_slices_from_modifier(&modifier.take().expect("modifier"));
+        let primitives = crate::draw::execute_draw_commands(
+            slices.draw_commands(),
+            Size {
+                width: 20.0,
+                height: 20.0,
+            },
+        );
+        let outermost = primitives.last().expect("shadow layers");
+        match outermost {
+            DrawPrimitive::RoundRect { rect, radii, .. } => {
+                assert_eq!(rect.x, -flat.blur_radius);
+                assert_eq!(rect.width, 20.0 + flat.blur_radius * 2.0);
+                assert_eq!(radii.top_left, 4.0 + flat.blur_radius);
+            }
+            other => panic!("unexpected shadow primitive {other:?}"),
+        }
+    }
+}
@@ -3,8 +3,12 @@
 //! Reference: /media/huge/composerepo/compose/foundation/foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
 
 use super::{inspector_metadata, DimensionConstraint, Modifier, Size};
-use crate::modifier_nodes::{IntrinsicSizeElement, SizeElement};
-use cranpose_ui_layout::IntrinsicSize;
+use crate::composition_local::resolve_dp;
+use crate::modifier_nodes::{
+    DefaultMinSizeElement, IntrinsicSizeElement, SizeElement, WrapContentElement,
+};
+use cranpose_ui_graphics::Dp;
+use cranpose_ui_layout::{Alignment, HorizontalAlignment, IntrinsicSize, VerticalAlignment};
 
 impl Modifier {
     /// Declare the preferred size of the content to be exactly [size].
@@ -16,8 +20,8 @@ impl Modifier {
     ///
     /// Example: `Modifier::empty().size(Size { width: 100.0, height: 200.0 })`
     pub fn size(self, size: Size) -> Self {
-        let width = size.width;
-        let height = size.height;
+        let width = resolve_dp(Dp(size.width));
+        let height = resolve_dp(Dp(size.height));
         let modifier = Self::with_element(SizeElement::new(Some(width), Some(height)))
             .with_inspector_metadata(inspector_metadata("size", move |info| {
                 info.add_dimension("width", DimensionConstraint::Points(width));
@@ -31,8 +35,11 @@ impl Modifier {
     /// Convenience method for `size(Size { width, height })`.
     ///
     /// Example: `Modifier::empty().size_points(100.0, 200.0)`
-    pub fn size_points(self, width: f32, height: f32) -> Self {
-        self.size(Size { width, height })
+    pub fn size_points(self, width: impl Into<Dp>, height: impl Into<Dp>) -> Self {
+        self.size(Size {
+            width: width.into().0,
+            height: height.into().0,
+        })
     }
 
     /// Declare the preferred width of the content to be exactly [width]dp.
@@ -43,7 +50,8 @@ impl Modifier {
     /// Matches Kotlin: `Modifier.width(width: Dp)`
     ///
     /// Example: `Modifier::empty().width(100.0).height(200.0)`
-    pub fn width(self, width: f32) -> Self {
+    pub fn width(self, width: impl Into<Dp>) -> Self {
+        let width = resolve_dp(width.into());
         let modifier = Self::with_element(SizeElement::new(Some(width), None))
             .with_inspector_metadata(inspector_metadata("width", move |info| {
                 info.add_dimension("width", DimensionConstraint::Points(width));
@@ -59,7 +67,8 @@ impl Modifier {
     /// Matches Kotlin: `Modifier.height(height: Dp)`
     ///
     /// Example: `Modifier::empty().width(100.0).height(200.0)`
-    pub fn height(self, height: f32) -> Self {
+    pub fn height(self, height: impl Into<Dp>) -> Self {
+        let height = resolve_dp(height.into());
         let modifier = Self::with_element(SizeElement::new(None, Some(height)))
             .with_inspector_metadata(inspector_metadata("height", move |info| {
                 info.add_dimension("height", DimensionConstraint::Points(height));
@@ -67,6 +76,108 @@ impl Modifier {
         self.then(modifier)
     }
 
+    /// Constrain the width of the content to be between [min]dp and [max]dp.
+    ///
+    /// The content picks its own width within the range. The incoming measurement
+    /// constraints may still override the range.
+    ///
+    /// Matches Kotlin: `Modifier.widthIn(min: Dp, max: Dp)`
+    ///
+    /// Example: `Modifier::empty().width_in(48.0, 200.0)`
+    pub fn width_in(self, min: impl Into<Dp>, max: impl Into<Dp>) -> Self {
+        let min = resolve_dp(min.into());
+        let max = resolve_dp(max.into());
+        let modifier = Self::with_element(SizeElement::with_constraints(
+            Some(min),
+            Some(max),
+            None,
+            None,
+            true,
+        ))
+        .with_inspector_metadata(inspector_metadata("widthIn", move |info| {
+            info.add_dimension("minWidth", DimensionConstraint::Points(min));
+            info.add_dimension("maxWidth", DimensionConstraint::Points(max));
+        }));
+        self.then(modifier)
+    }
+
+    /// Constrain the height of the content to be between [min]dp and [max]dp.
+    ///
+    /// The content picks its own height within the range. The incoming measurement
+    /// constraints may still override the range.
+    ///
+    /// Matches Kotlin: `Modifier.heightIn(min: Dp, max: Dp)`
+    ///
+    /// Example: `Modifier::empty().height_in(48.0, 200.0)`
+    pub fn height_in(self, min: impl Into<Dp>, max: impl Into<Dp>) -> Self {
+        let min = resolve_dp(min.into());
+        let max = resolve_dp(max.into());
+        let modifier = Self::with_element(SizeElement::with_constraints(
+            None,
+            None,
+            Some(min),
+            Some(max),
+            true,
+        ))
+        .with_inspector_metadata(inspector_metadata("heightIn", move |info| {
+            info.add_dimension("minHeight", DimensionConstraint::Points(min));
+            info.add_dimension("maxHeight", DimensionConstraint::Points(max));
+        }));
+        self.then(modifier)
+    }
+
+    /// Constrain the size of the content to be between [min] and [max].
+    ///
+    /// The content picks its own size within the range. The incoming measurement
+    /// constraints may still override the range.
+    ///
+    /// Matches Kotlin: `Modifier.sizeIn(minWidth, minHeight, maxWidth, maxHeight)`
+    ///
+    /// Example: `Modifier::empty().size_in(Size::new(48.0, 48.0), Size::new(200.0, 100.0))`
+    pub fn size_in(self, min: Size, max: Size) -> Self {
+        let min_width = resolve_dp(Dp(min.width));
+        let min_height = resolve_dp(Dp(min.height));
+        let max_width = resolve_dp(Dp(max.width));
+        let max_height = resolve_dp(Dp(max.height));
+        let modifier = Self::with_element(SizeElement::with_constraints(
+            Some(min_width),
+            Some(max_width),
+            Some(min_height),
+            Some(max_height),
+            true,
+        ))
+        .with_inspector_metadata(inspector_metadata("sizeIn", move |info| {
+            info.add_dimension("minWidth", DimensionConstraint::Points(min_width));
+            info.add_dimension("minHeight", DimensionConstraint::Points(min_height));
+            info.add_dimension("maxWidth", DimensionConstraint::Points(max_width));
+            info.add_dimension("maxHeight", DimensionConstraint::Points(max_height));
+        }));
+        self.then(modifier)
+    }
+
+    /// Give the content a minimum size of [min_width]dp by [min_height]dp unless
+    /// the incoming constraints already set a minimum.
+    ///
+    /// Unlike [`size_in`](Self::size_in), an explicit size from an outer modifier or
+    /// the parent wins. Widgets use this for default touch-target sizes.
+    ///
+    /// Matches Kotlin: `Modifier.defaultMinSize(minWidth: Dp, minHeight: Dp)`
+    ///
+    /// Example: `Modifier::empty().default_min_size(48.0, 48.0)`
+    pub fn default_min_size(self, min_width: impl Into<Dp>, min_height: impl Into<Dp>) -> Self {
+        let min_width = resolve_dp(min_width.into());
+        let min_height = resolve_dp(min_height.into());
+        let modifier = Self::with_element(DefaultMinSizeElement::new(
+            Some(min_width),
+            Some(min_height),
+        ))
+        .with_inspector_metadata(inspector_metadata("defaultMinSize", move |info| {
+            info.add_dimension("minWidth", DimensionConstraint::Points(min_width));
+            info.add_dimension("minHeight", DimensionConstraint::Points(min_height));
+        }));
+        self.then(modifier)
+    }
+
     /// Declare the width of the content based on its intrinsic size.
     ///
     /// Matches Kotlin: `Modifier.width(IntrinsicSize)`
@@ -97,13 +208,55 @@ impl Modifier {
     ///
     /// Matches Kotlin: `Modifier.requiredSize(size: Dp)`
     pub fn required_size(self, size: Size) -> Self {
+        let width = resolve_dp(Dp(size.width));
+        let height = resolve_dp(Dp(size.height));
         let modifier = Self::with_element(SizeElement::with_constraints(
-            Some(size.width),
-            Some(size.width),
-            Some(size.height),
-            Some(size.height),
+            Some(width),
+            Some(width),
+            Some(height),
+            Some(height),
             false,
         ));
         self.then(modifier)
     }
+
+    /// Allow the content to measure at its desired width without regard for the
+    /// incoming minimum width constraint, aligning it horizontally when the
+    /// enforced width is larger than the content.
+    ///
+    /// Matches Kotlin: `Modifier.wrapContentWidth(align: Alignment.Horizontal)`
+    pub fn wrap_content_width(self, alignment: HorizontalAlignment) -> Self {
+        let modifier = Self::with_element(WrapContentElement::width(alignment))
+            .with_inspector_metadata(inspector_metadata("wrapContentWidth", move |info| {
+                info.add_alignment("align", alignment);
+            }));
+        self.then(modifier)
+    }
+
+    /// Allow the content to measure at its desired height without regard for the
+    /// incoming minimum height constraint, aligning it vertically when the
+    /// enforced height is larger than the content.
+    ///
+    /// Matches Kotlin: `Modifier.wrapContentHeight(align: Alignment.Vertical)`
+    pub fn wrap_content_height(self, alignment: VerticalAlignment) -> Self {
+        let modifier = Self::with_element(WrapContentElement::height(alignment))
+            .with_inspector_metadata(inspector_metadata("wrapContentHeight", move |info| {
+                info.add_alignment("align", alignment);
+            }));
+        self.then(modifier)
+    }
+
+    /// Allow the content to measure at its desired size without regard for the
+    /// incoming minimum constraints, aligning it within the enforced size.
+    ///
+    /// Matches Kotlin: `Modifier.wrapContentSize(align: Alignment)`
+    ///
+    /// Example: `Modifier::empty().fill_max_size().wrap_content_size(Alignment::CENTER)`
+    pub fn wrap_content_size(self, alignment: Alignment) -> Self {
+        let modifier = Self::with_element(WrapContentElement::size(alignment))
+            .with_inspector_metadata(inspector_metadata("wrapContentSize", move |info| {
+                info.add_alignment("align", alignment);
+            }));
+        self.then(modifier)
+    }
 }
@@ -2,19 +2,24 @@ use std::fmt;
 use std::rc::Rc;
 
 use cranpose_foundation::{ModifierNodeChain, NodeCapabilities, PointerEvent};
-use cranpose_ui_graphics::GraphicsLayer;
+use cranpose_ui_graphics::{GraphicsLayer, TextStyle};
 
 use crate::draw::DrawCommand;
-use crate::modifier::Modifier;
+use crate::modifier::{
+    CursorIcon, ElevationCurve, Modifier, PointerCursorNode, RoundedCornerShape, ShadowStyle,
+};
 use crate::modifier_nodes::{
-    BackgroundNode, ClipToBoundsNode, CornerShapeNode, DrawCommandNode, GraphicsLayerNode,
-    PaddingNode,
+    AlphaNode, BackgroundNode, BlurNode, ClickableNode, ClipToBoundsNode, CornerShapeNode,
+    DrawCommandNode, GraphicsLayerNode, PaddingNode, ShadowNode, VisualOffsetNode, ZIndexNode,
 };
 use crate::text_field_modifier_node::TextFieldModifierNode;
 use crate::text_modifier_node::TextModifierNode;
+use crate::text_selection::SelectableTextNode;
+use cranpose_ui_graphics::Dp;
 use cranpose_ui_graphics::EdgeInsets;
 use std::cell::RefCell;
 
+use super::focus::FocusTargetNode;
 use super::{ModifierChainHandle, Point};
 
 /// Snapshot of modifier node slices that impact draw and pointer subsystems.
@@ -23,9 +28,13 @@ pub struct ModifierNodeSlices {
     draw_commands: Vec<DrawCommand>,
     pointer_inputs: Vec<Rc<dyn Fn(PointerEvent)>>,
     click_handlers: Vec<Rc<dyn Fn(Point)>>,
+    pointer_cursor: Option<CursorIcon>,
     clip_to_bounds: bool,
     text_content: Option<Rc<str>>,
+    text_style: TextStyle,
     graphics_layer: Option<GraphicsLayer>,
+    blur_radius: f32,
+    z_index: f32,
     chain_guard: Option<Rc<ChainGuard>>,
 }
 
@@ -39,9 +48,13 @@ impl Clone for ModifierNodeSlices {
             draw_commands: self.draw_commands.clone(),
             pointer_inputs: self.pointer_inputs.clone(),
             click_handlers: self.click_handlers.clone(),
+            pointer_cursor: self.pointer_cursor,
             clip_to_bounds: self.clip_to_bounds,
             text_content: self.text_content.clone(),
+            text_style: self.text_style.clone(),
             graphics_layer: self.graphics_layer,
+            blur_radius: self.blur_radius,
+            z_index: self.z_index,
             chain_guard: self.chain_guard.clone(),
         }
     }
@@ -60,6 +73,11 @@ impl ModifierNodeSlices {
         &self.click_handlers
     }
 
+    /// Cursor requested by a `pointer_cursor` modifier, if any.
+    pub fn pointer_cursor(&self) -> Option<CursorIcon> {
+        self.pointer_cursor
+    }
+
     pub fn clip_to_bounds(&self) -> bool {
         self.clip_to_bounds
     }
@@ -72,10 +90,25 @@ impl ModifierNodeSlices {
         self.text_content.clone()
     }
 
+    /// Style the text content is laid out with.
+    pub fn text_style(&self) -> &TextStyle {
+        &self.text_style
+    }
+
     pub fn graphics_layer(&self) -> Option<GraphicsLayer> {
         self.graphics_layer
     }
 
+    /// Radius of the blur applied to the node's subtree; `0.0` when no `blur` modifier is set.
+    pub fn blur_radius(&self) -> f32 {
+        self.blur_radius
+    }
+
+    /// Drawing order of the node among its siblings; `0.0` when no `z_index` modifier is set.
+    pub fn z_index(&self) -> f32 {
+        self.z_index
+    }
+
     pub fn with_chain_guard(mut self, handle: ModifierChainHandle) -> Self {
         self.chain_guard = Some(Rc::new(ChainGuard { _handle: handle }));
         self
@@ -86,9 +119,13 @@ impl ModifierNodeSlices {
         self.draw_commands.clear();
         self.pointer_inputs.clear();
         self.click_handlers.clear();
+        self.pointer_cursor = None;
         self.clip_to_bounds = false;
         self.text_content = None;
+        self.text_style = TextStyle::default();
         self.graphics_layer = None;
+        self.blur_radius = 0.0;
+        self.z_index = 0.0;
         self.chain_guard = None;
     }
 }
@@ -99,9 +136,13 @@ impl fmt::Debug for ModifierNodeSlices {
             .field("draw_commands", &self.draw_commands.len())
             .field("pointer_inputs", &self.pointer_inputs.len())
             .field("click_handlers", &self.click_handlers.len())
+            .field("pointer_cursor", &self.pointer_cursor)
             .field("clip_to_bounds", &self.clip_to_bounds)
             .field("text_content", &self.text_content)
+            .field("text_style", &self.text_style)
             .field("graphics_layer", &self.graphics_layer)
+            .field("blur_radius", &self.blur_radius)
+            .field("z_index", &self.z_index)
             .finish()
     }
 }
@@ -118,7 +159,7 @@ pub fn collect_modifier_slices_into(chain: &ModifierNodeChain, slices: &mut Modi
     slices.clear();
 
     chain.for_each_node_with_capability(NodeCapabilities::POINTER_INPUT, |_ref, node| {
-        let _any = node.as_any();
+        let any = node.as_any();
 
         // ClickableNode is now handled as a standard PointerInputNode
         // to support drag cancellation and proper click semantics (Up vs Down)
@@ -130,18 +171,30 @@ pub fn collect_modifier_slices_into(chain: &ModifierNodeChain, slices: &mut Modi
         {
             slices.pointer_inputs.push(handler);
         }
+
+        // Rightmost cursor modifier wins
+        if let Some(cursor_node) = any.downcast_ref::<PointerCursorNode>() {
+            slices.pointer_cursor = Some(cursor_node.icon());
+        }
     });
 
     // Track background and shape to combine them in draw commands
-    let background_color = RefCell::new(None);
+    let background_brush = RefCell::new(None);
     let corner_shape = RefCell::new(None);
+    let mut shadow = None;
+    let mut alpha = 1.0;
+    let mut visual_offset = Point::default();
+    // Bounded ripple overlays by draw command index, clipped to the shape once it is known
+    let mut ripples = Vec::new();
+    // Focus rings by draw command index, redrawn around the shape once it is known
+    let mut focus_rings = Vec::new();
 
     chain.for_each_node_with_capability(NodeCapabilities::DRAW, |_ref, node| {
         let any = node.as_any();
 
-        // Collect background color from BackgroundNode
+        // Collect background brush from BackgroundNode
         if let Some(bg_node) = any.downcast_ref::<BackgroundNode>() {
-            *background_color.borrow_mut() = Some(bg_node.color());
+            *background_brush.borrow_mut() = Some(bg_node.brush().clone());
             // Note: BackgroundNode can have an optional shape, but we primarily track
             // shape via CornerShapeNode for flexibility
             if bg_node.shape().is_some() {
@@ -166,6 +219,17 @@ pub fn collect_modifier_slices_into(chain: &ModifierNodeChain, slices: &mut Modi
         // Fallback to draw() for nodes with static content.
         if let Some(draw_node) = node.as_draw_node() {
             if let Some(closure) = draw_node.create_draw_closure() {
+                if let Some(ripple) = any.downcast_ref::<ClickableNode>().and_then(|n| n.ripple()) {
+                    if ripple.is_bounded() {
+                        ripples.push((slices.draw_commands.len(), closure.clone()));
+                    }
+                }
+                if let Some(ring) = any
+                    .downcast_ref::<FocusTargetNode>()
+                    .and_then(FocusTargetNode::focus_ring)
+                {
+                    focus_rings.push((slices.draw_commands.len(), ring));
+                }
                 // Deferred closure - evaluates at render time
                 slices.draw_commands.push(DrawCommand::Overlay(closure));
             } else {
@@ -189,11 +253,56 @@ pub fn collect_modifier_slices_into(chain: &ModifierNodeChain, slices: &mut Modi
             slices.graphics_layer = Some(layer_node.layer());
         }
 
+        // Alpha modifiers multiply into the node's graphics layer
+        if let Some(alpha_node) = any.downcast_ref::<AlphaNode>() {
+            alpha *= alpha_node.alpha();
+        }
+
+        // Visual offsets accumulate into the graphics layer translation
+        if let Some(offset_node) = any.downcast_ref::<VisualOffsetNode>() {
+            let delta = offset_node.offset();
+            visual_offset.x += delta.x;
+            visual_offset.y += delta.y;
+        }
+
+        // Rightmost shadow modifier wins
+        if let Some(shadow_node) = any.downcast_ref::<ShadowNode>() {
+            shadow = Some((shadow_node.style(), Some(shadow_node.shape())));
+        }
+
+        // Rightmost blur modifier wins, like graphics layers
+        if let Some(blur_node) = any.downcast_ref::<BlurNode>() {
+            slices.blur_radius = blur_node.radius();
+        }
+
         if any.is::<ClipToBoundsNode>() {
             slices.clip_to_bounds = true;
         }
+
+        // Multiple z_index modifiers on the same node accumulate
+        if let Some(z_node) = any.downcast_ref::<ZIndexNode>() {
+            slices.z_index += z_node.z_index();
+        }
     });
 
+    if alpha < 1.0 {
+        let layer = slices
+            .graphics_layer
+            .get_or_insert_with(GraphicsLayer::default);
+        layer.alpha *= alpha;
+    }
+    if visual_offset != Point::default() {
+        let layer = slices
+            .graphics_layer
+            .get_or_insert_with(GraphicsLayer::default);
+        layer.translation_x += visual_offset.x;
+        layer.translation_y += visual_offset.y;
+    }
+    let layer = slices.graphics_layer.unwrap_or_default();
+    if layer.clip {
+        slices.clip_to_bounds = true;
+    }
+
     // Collect padding from modifier chain for cursor positioning
     let mut padding = EdgeInsets::default();
     chain.for_each_node_with_capability(NodeCapabilities::LAYOUT, |_ref, node| {
@@ -213,11 +322,13 @@ pub fn collect_modifier_slices_into(chain: &ModifierNodeChain, slices: &mut Modi
         if let Some(text_node) = any.downcast_ref::<TextModifierNode>() {
             // Rightmost text modifier wins
             slices.text_content = Some(text_node.text_arc());
+            slices.text_style = text_node.style().clone();
         }
         // Also check for TextFieldModifierNode (editable text fields)
         if let Some(text_field_node) = any.downcast_ref::<TextFieldModifierNode>() {
             let text = text_field_node.text();
             slices.text_content = Some(Rc::from(text));
+            slices.text_style = TextStyle::default();
 
             // Update content offsets for cursor positioning in collect_draw_primitives()
             text_field_node.set_content_offset(padding.left);
@@ -228,15 +339,64 @@ pub fn collect_modifier_slices_into(chain: &ModifierNodeChain, slices: &mut Modi
         }
     });
 
-    // Convert background + shape into a draw command
-    if let Some(color) = background_color.into_inner() {
-        let shape = corner_shape.into_inner();
+    // Glyph renderers don't draw decorations, so they are drawn over the text here
+    if let Some(text) = slices
+        .text_content
+        .clone()
+        .filter(|_| slices.text_style.text_decoration.is_some())
+    {
+        let style = slices.text_style.clone();
+        let origin = Point::new(padding.left, padding.top);
+        let draw_cmd = Rc::new(move |_size: crate::modifier::Size| {
+            use crate::modifier::{Brush, Color, Rect};
+            use cranpose_ui_graphics::DrawPrimitive;
 
+            crate::text::text_decoration_rects(&text, &style)
+                .into_iter()
+                .map(|rect| DrawPrimitive::Rect {
+                    rect: Rect {
+                        x: rect.x + origin.x,
+                        y: rect.y + origin.y,
+                        ..rect
+                    },
+                    // Renderers draw text in white
+                    brush: Brush::solid(Color::WHITE),
+                })
+                .collect()
+        });
+        slices.draw_commands.push(DrawCommand::Overlay(draw_cmd));
+    }
+
+    // Feed text and padding to selectable text and draw its highlight behind the glyphs
+    chain.for_each_node_with_capability(NodeCapabilities::POINTER_INPUT, |_ref, node| {
+        if let Some(selectable) = node.as_any().downcast_ref::<SelectableTextNode>() {
+            selectable.set_text(slices.text_content.clone().unwrap_or_else(|| Rc::from("")));
+            selectable.set_content_offset(Point::new(padding.left, padding.top));
+            slices
+                .draw_commands
+                .push(DrawCommand::Behind(selectable.highlight_closure()));
+        }
+    });
+
+    let shape = corner_shape.into_inner();
+
+    // Bounded press ripples are clipped to the rounded corners instead of covering them
+    for (index, closure) in ripples {
+        slices.draw_commands[index] = DrawCommand::OverlayClipped(closure, shape);
+    }
+    if let Some(shape) = shape {
+        for (index, ring) in focus_rings {
+            slices.draw_commands[index] = DrawCommand::Overlay(ring.draw_closure(Some(shape)));
+        }
+    }
+
+    // Convert background + shape into a draw command
+    if let Some(brush) = background_brush.into_inner() {
         let draw_cmd = Rc::new(move |size: crate::modifier::Size| {
-            use crate::modifier::{Brush, Rect};
+            use crate::modifier::Rect;
             use cranpose_ui_graphics::DrawPrimitive;
 
-            let brush = Brush::solid(color);
+            let brush = brush.clone();
             let rect = Rect {
                 x: 0.0,
                 y: 0.0,
@@ -256,6 +416,72 @@ pub fn collect_modifier_slices_into(chain: &ModifierNodeChain, slices: &mut Modi
             .draw_commands
             .insert(0, DrawCommand::Behind(draw_cmd));
     }
+
+    // A graphics layer elevation draws like the default elevation curve, in the
+    // node's own shape; an explicit shadow modifier takes precedence.
+    let shadow = shadow.or_else(|| {
+        (layer.shadow_elevation > 0.0).then(|| {
+            let style = ElevationCurve::default().shadow(Dp(layer.shadow_elevation));
+            (style, shape)
+        })
+    });
+
+    // The shadow goes first so the background covers its inner part
+    if let Some((style, shadow_shape)) = shadow.filter(|(style, _)| style.alpha > 0.0) {
+        let draw_cmd = Rc::new(move |size: crate::modifier::Size| {
+            shadow_primitives(size, style, shadow_shape)
+        });
+        slices
+            .draw_commands
+            .insert(0, DrawCommand::Behind(draw_cmd));
+    }
+}
+
+/// Number of stacked translucent layers used to approximate a blurred shadow.
+const SHADOW_LAYERS: usize = 4;
+
+/// Approximates a drop shadow with layers that spread out by the style's blur radius.
+///
+/// The layers are offset downward by the style's offset, and each outer layer
+/// adds a little less darkness.
+fn shadow_primitives(
+    size: crate::modifier::Size,
+    style: ShadowStyle,
+    shape: Option<RoundedCornerShape>,
+) -> Vec<cranpose_ui_graphics::DrawPrimitive> {
+    use crate::modifier::{Brush, Color, Rect};
+    use cranpose_ui_graphics::DrawPrimitive;
+
+    let brush = Brush::solid(Color(0.0, 0.0, 0.0, style.alpha / SHADOW_LAYERS as f32));
+    let radii = shape.map(|shape| shape.resolve(size.width, size.height));
+    (1..=SHADOW_LAYERS)
+        .map(|layer| {
+            let spread = style.blur_radius * layer as f32 / SHADOW_LAYERS as f32;
+            let rect = Rect {
+                x: -spread,
+                y: style.offset_y - spread,
+                width: size.width + spread * 2.0,
+                height: size.height + spread * 2.0,
+            };
+            match radii {
+                Some(mut radii) => {
+                    radii.top_left += spread;
+                    radii.top_right += spread;
+                    radii.bottom_right += spread;
+                    radii.bottom_left += spread;
+                    DrawPrimitive::RoundRect {
+                        rect,
+                        brush: brush.clone(),
+                        radii,
+                    }
+                }
+                None => DrawPrimitive::Rect {
+                    rect,
+                    brush: brush.clone(),
+                },
+            }
+        })
+        .collect()
 }
 
 /// Collects modifier node slices by instantiating a temporary node chain from a [`Modifier`].
@@ -0,0 +1,119 @@
+use super::*;
+use crate::renderer::{HeadlessRenderer, PaintLayer};
+use crate::scroll::ScrollState;
+use crate::widgets::{Box, BoxSpec, Column, ColumnSpec};
+use crate::{composable, LayoutEngine, Size};
+use cranpose_core::{location_key, Composition, MemoryApplier};
+use cranpose_ui_graphics::DrawPrimitive;
+use std::cell::Cell;
+
+const EDGE: Color = Color(1.0, 1.0, 1.0, 1.0);
+
+thread_local! {
+    static BACKWARD_READS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[composable]
+fn faded_column(state: ScrollState) {
+    Column(
+        Modifier::empty()
+            .size_points(100.0, 100.0)
+            .vertical_scroll(state.clone(), false)
+            .fading_edges(&state, 20.0, 20.0, EDGE),
+        ColumnSpec::default(),
+        || {
+            Box(
+                Modifier::empty().size_points(100.0, 400.0),
+                BoxSpec::default(),
+                || {},
+            );
+        },
+    );
+}
+
+/// Overlay gradients as `(top, height)` in window space.
+fn fades(composition: &mut Composition<MemoryApplier>) -> Vec<(f32, f32)> {
+    let root = composition.root().expect("root");
+    let layout = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 100.0,
+                height: 100.0,
+            },
+        )
+        .expect("layout");
+    HeadlessRenderer::new()
+        .render(&layout)
+        .primitives_for(PaintLayer::Overlay)
+        .filter_map(|primitive| match primitive {
+            DrawPrimitive::Rect {
+                rect,
+                brush: Brush::LinearGradient { .. },
+            } => Some((rect.y, rect.height)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn settle(composition: &mut Composition<MemoryApplier>) {
+    while composition.should_render() {
+        composition.process_invalid_scopes().expect("recomposition");
+    }
+}
+
+#[test]
+fn leading_fade_appears_once_content_is_scrolled() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let state = ScrollState::new(0.0);
+    let content_state = state.clone();
+    composition
+        .render(key, move || faded_column(content_state.clone()))
+        .expect("initial render");
+    // The first layout measures the content, which makes the column scrollable
+    fades(&mut composition);
+    settle(&mut composition);
+
+    assert_eq!(fades(&mut composition), vec![(80.0, 20.0)]);
+
+    state.scroll_to(50.0);
+    settle(&mut composition);
+    assert_eq!(fades(&mut composition), vec![(0.0, 20.0), (80.0, 20.0)]);
+}
+
+#[composable]
+fn backward_reader(state: ScrollState) {
+    BACKWARD_READS.with(|reads| reads.set(reads.get() + 1));
+    let _ = state.can_scroll_backward();
+}
+
+#[test]
+fn can_scroll_backward_recomposes_only_when_it_flips() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let state = ScrollState::new(0.0);
+    state.set_max_value(100.0);
+    let content_state = state.clone();
+    BACKWARD_READS.with(|reads| reads.set(0));
+    composition
+        .render(key, move || backward_reader(content_state.clone()))
+        .expect("initial render");
+    assert_eq!(BACKWARD_READS.with(Cell::get), 1);
+
+    state.scroll_to(10.0);
+    settle(&mut composition);
+    assert!(state.can_scroll_backward());
+    assert_eq!(BACKWARD_READS.with(Cell::get), 2);
+
+    state.scroll_to(40.0);
+    state.dispatch_raw_delta(5.0);
+    settle(&mut composition);
+    assert_eq!(BACKWARD_READS.with(Cell::get), 2);
+
+    state.scroll_to(0.0);
+    settle(&mut composition);
+    assert!(!state.can_scroll_backward());
+    assert_eq!(BACKWARD_READS.with(Cell::get), 3);
+}
@@ -35,6 +35,34 @@ fn fill_max_size_sets_fraction_constraints() {
     assert_eq!(props.height(), DimensionConstraint::Fraction(0.75));
 }
 
+#[test]
+fn outer_size_constrains_inner_fill() {
+    let modifier = Modifier::empty()
+        .size(Size::new(100.0, 50.0))
+        .fill_max_width();
+    let props = modifier.resolved_modifiers().layout_properties();
+    assert_eq!(props.width(), DimensionConstraint::Points(100.0));
+    assert_eq!(props.height(), DimensionConstraint::Points(50.0));
+}
+
+#[test]
+fn outer_fill_overrides_inner_size() {
+    let modifier = Modifier::empty()
+        .fill_max_width()
+        .size(Size::new(100.0, 50.0));
+    let props = modifier.resolved_modifiers().layout_properties();
+    assert_eq!(props.width(), DimensionConstraint::Fraction(1.0));
+    assert_eq!(props.height(), DimensionConstraint::Points(50.0));
+}
+
+#[test]
+fn fixed_height_then_fill_width_keeps_both_axes() {
+    let modifier = Modifier::empty().height(50.0).fill_max_width();
+    let props = modifier.resolved_modifiers().layout_properties();
+    assert_eq!(props.width(), DimensionConstraint::Fraction(1.0));
+    assert_eq!(props.height(), DimensionConstraint::Points(50.0));
+}
+
 #[test]
 fn weight_tracks_fill_flag() {
     let modifier = Modifier::empty().weight_with_fill(2.0, false);
@@ -97,6 +125,27 @@ fn required_size_sets_explicit_constraints() {
     assert_eq!(props.max_height(), Some(18.0));
 }
 
+#[test]
+fn size_in_sets_size_range() {
+    let modifier = Modifier::empty()
+        .width_in(40.0, 120.0)
+        .height_in(10.0, 30.0);
+    let props = modifier.resolved_modifiers().layout_properties();
+    assert_eq!(props.width(), DimensionConstraint::Unspecified);
+    assert_eq!(props.min_width(), Some(40.0));
+    assert_eq!(props.max_width(), Some(120.0));
+    assert_eq!(props.min_height(), Some(10.0));
+    assert_eq!(props.max_height(), Some(30.0));
+
+    // An inner range is coerced into the outer one
+    let nested = Modifier::empty()
+        .size_in(Size::new(0.0, 0.0), Size::new(100.0, 100.0))
+        .width_in(150.0, 200.0);
+    let props = nested.resolved_modifiers().layout_properties();
+    assert_eq!(props.min_width(), Some(100.0));
+    assert_eq!(props.max_width(), Some(100.0));
+}
+
 #[test]
 fn alignment_modifiers_record_values() {
     let modifier = Modifier::empty()
@@ -321,3 +370,28 @@ impl ModifierNodeElement for TestDelegatingElement {
         NodeCapabilities::LAYOUT
     }
 }
+
+#[test]
+fn on_hover_reports_enter_and_exit_once() {
+    use super::{collect_slices_from_modifier, PointerEvent, PointerEventKind};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let sink = calls.clone();
+    let modifier = Modifier::empty().on_hover(move |hovered| sink.borrow_mut().push(hovered));
+    let slices = collect_slices_from_modifier(&modifier);
+    let handler = slices.pointer_inputs()[0].clone();
+
+    let origin = Point { x: 0.0, y: 0.0 };
+    for kind in [
+        PointerEventKind::Enter,
+        PointerEventKind::Move,
+        PointerEventKind::Enter,
+        PointerEventKind::Exit,
+        PointerEventKind::Exit,
+    ] {
+        handler(PointerEvent::new(kind, origin, origin));
+    }
+    assert_eq!(*calls.borrow(), vec![true, false]);
+}
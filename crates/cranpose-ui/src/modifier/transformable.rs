@@ -0,0 +1,420 @@
+//! Multi-pointer transform gestures (pinch to zoom, rotate, pan).
+//!
+//! `Modifier::transformable(state)` tracks every pointer by its id and turns
+//! their movement into incremental [`TransformChange`]s:
+//! - **Two or more pointers**: the first two pressed pointers define the
+//!   gesture. Zoom is the ratio of their distances, rotation is the change of
+//!   the angle between them, and pan is the movement of their centroid.
+//! - **One pointer**: pans once movement exceeds `DRAG_THRESHOLD`. When one
+//!   finger of a pinch lifts, the remaining finger keeps panning.
+//!
+//! Movement is measured in window coordinates, so content that moves with the
+//! gesture doesn't feed back into it. [`TransformableState::with_layer`]
+//! applies the changes to a [`GraphicsLayer`] that makes content follow the
+//! gesture; layers can't rotate, so rotation is reported but not drawn.
+
+use super::{inspector_metadata, GraphicsLayer, Modifier, Point, PointerEvent, PointerEventKind};
+use cranpose_core::MutableState;
+use cranpose_foundation::{PointerId, DRAG_THRESHOLD};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static NEXT_TRANSFORMABLE_STATE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Incremental transform reported for a single pointer move.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformChange {
+    /// Centroid of the active pointers, in the node's local coordinates.
+    pub centroid: Point,
+    /// Scale factor relative to the previous event (1.0 = unchanged).
+    pub zoom: f32,
+    /// Centroid movement since the previous event.
+    pub pan: Point,
+    /// Rotation since the previous event, in degrees (clockwise).
+    pub rotation: f32,
+}
+
+impl TransformChange {
+    /// Applies this change to the graphics layer of the transformed node.
+    ///
+    /// Zooms around the previous centroid and then pans, so the content under
+    /// the fingers stays under them. Rotation is dropped.
+    pub fn apply_to(self, layer: GraphicsLayer) -> GraphicsLayer {
+        let pivot_x = self.centroid.x - self.pan.x;
+        let pivot_y = self.centroid.y - self.pan.y;
+        GraphicsLayer {
+            scale: layer.scale * self.zoom,
+            translation_x: layer.translation_x + pivot_x * (1.0 - self.zoom) + self.pan.x,
+            translation_y: layer.translation_y + pivot_y * (1.0 - self.zoom) + self.pan.y,
+            ..layer
+        }
+    }
+}
+
+/// Receives transform changes from [`Modifier::transformable`].
+///
+/// Matches Kotlin: `TransformableState` in `androidx.compose.foundation.gestures`.
+#[derive(Clone)]
+pub struct TransformableState {
+    inner: Rc<TransformableStateInner>,
+}
+
+struct TransformableStateInner {
+    id: u64,
+    on_transformation: Box<dyn Fn(TransformChange)>,
+    in_progress: Cell<bool>,
+}
+
+impl TransformableState {
+    /// Creates a state that forwards every transform change to `on_transformation`.
+    pub fn new(on_transformation: impl Fn(TransformChange) + 'static) -> Self {
+        Self {
+            inner: Rc::new(TransformableStateInner {
+                id: NEXT_TRANSFORMABLE_STATE_ID.fetch_add(1, Ordering::Relaxed),
+                on_transformation: Box::new(on_transformation),
+                in_progress: Cell::new(false),
+            }),
+        }
+    }
+
+    /// Creates a state that applies every change to `layer`.
+    ///
+    /// Draw the transformable node with `graphics_layer(layer.value())` to
+    /// make its content follow the gesture.
+    pub fn with_layer(layer: MutableState<GraphicsLayer>) -> Self {
+        Self::new(move |change: TransformChange| {
+            layer.update(|current| *current = change.apply_to(*current));
+        })
+    }
+
+    /// Get the unique ID of this TransformableState
+    pub fn id(&self) -> u64 {
+        self.inner.id
+    }
+
+    /// Returns true while a transform gesture is being tracked.
+    pub fn is_transform_in_progress(&self) -> bool {
+        self.inner.in_progress.get()
+    }
+
+    /// Dispatches a transform change as if it came from a gesture.
+    pub fn transform_by(&self, change: TransformChange) {
+        (self.inner.on_transformation)(change);
+    }
+}
+
+/// Pointer tracking for one transformable modifier instance.
+#[derive(Default)]
+struct TransformGestureState {
+    /// Pressed pointers in press order, with their last window position.
+    pointers: Vec<(PointerId, Point)>,
+    /// Whether single-pointer movement crossed the pan threshold.
+    is_panning: bool,
+    /// Window position where the single-pointer pan started.
+    down_position: Option<Point>,
+}
+
+/// Converts pointer events into [`TransformChange`]s for a [`TransformableState`].
+struct TransformGestureDetector {
+    gesture_state: RefCell<TransformGestureState>,
+    state: TransformableState,
+}
+
+impl TransformGestureDetector {
+    fn new(state: TransformableState) -> Self {
+        Self {
+            gesture_state: RefCell::new(TransformGestureState::default()),
+            state,
+        }
+    }
+
+    /// Processes one pointer event. Returns true if it should be consumed.
+    fn on_event(&self, event: &PointerEvent) -> bool {
+        match event.kind {
+            PointerEventKind::Down => self.on_down(event.id, event.global_position),
+            PointerEventKind::Move => self.on_move(event),
+            PointerEventKind::Up | PointerEventKind::Cancel => self.on_up(event.id),
+            PointerEventKind::Enter | PointerEventKind::Exit => false,
+        }
+    }
+
+    fn on_down(&self, id: PointerId, position: Point) -> bool {
+        let mut gs = self.gesture_state.borrow_mut();
+        gs.pointers.retain(|(pointer, _)| *pointer != id);
+        gs.pointers.push((id, position));
+        if gs.pointers.len() == 1 {
+            gs.down_position = Some(position);
+            gs.is_panning = false;
+        }
+        self.state
+            .inner
+            .in_progress
+            .set(gs.pointers.len() > 1 || gs.is_panning);
+        // A second finger makes this a transform gesture; a lone press may still be a click
+        gs.pointers.len() > 1
+    }
+
+    fn on_move(&self, event: &PointerEvent) -> bool {
+        let (id, position) = (event.id, event.global_position);
+        let mut change = {
+            let mut gs = self.gesture_state.borrow_mut();
+            let Some(index) = gs.pointers.iter().position(|(pointer, _)| *pointer == id) else {
+                return false;
+            };
+            let previous = gs.pointers[index].1;
+            gs.pointers[index].1 = position;
+
+            if gs.pointers.len() >= 2 {
+                if index > 1 {
+                    // Only the first two pointers drive the gesture
+                    return true;
+                }
+                let mut before = [gs.pointers[0].1, gs.pointers[1].1];
+                before[index] = previous;
+                let after = [gs.pointers[0].1, gs.pointers[1].1];
+                two_pointer_change(before, after)
+            } else {
+                if !gs.is_panning {
+                    let start = gs.down_position.unwrap_or(previous);
+                    let dx = position.x - start.x;
+                    let dy = position.y - start.y;
+                    if (dx * dx + dy * dy).sqrt() <= DRAG_THRESHOLD {
+                        return false;
+                    }
+                    gs.is_panning = true;
+                }
+                TransformChange {
+                    centroid: position,
+                    zoom: 1.0,
+                    pan: Point::new(position.x - previous.x, position.y - previous.y),
+                    rotation: 0.0,
+                }
+            }
+        };
+
+        // Report the centroid relative to the node
+        change.centroid.x += event.position.x - event.global_position.x;
+        change.centroid.y += event.position.y - event.global_position.y;
+        self.state.inner.in_progress.set(true);
+        self.state.transform_by(change);
+        true
+    }
+
+    fn on_up(&self, id: PointerId) -> bool {
+        let mut gs = self.gesture_state.borrow_mut();
+        let was_transforming = gs.pointers.len() > 1 || gs.is_panning;
+        gs.pointers.retain(|(pointer, _)| *pointer != id);
+        match gs.pointers.len() {
+            0 => {
+                gs.is_panning = false;
+                gs.down_position = None;
+                self.state.inner.in_progress.set(false);
+            }
+            1 => {
+                // Fall back to panning with the finger that is still down
+                gs.is_panning = was_transforming;
+                gs.down_position = Some(gs.pointers[0].1);
+            }
+            _ => {}
+        }
+        was_transforming
+    }
+}
+
+fn two_pointer_change(before: [Point; 2], after: [Point; 2]) -> TransformChange {
+    let centroid = |points: [Point; 2]| {
+        Point::new(
+            (points[0].x + points[1].x) / 2.0,
+            (points[0].y + points[1].y) / 2.0,
+        )
+    };
+    let span = |points: [Point; 2]| {
+        let dx = points[1].x - points[0].x;
+        let dy = points[1].y - points[0].y;
+        ((dx * dx + dy * dy).sqrt(), dy.atan2(dx))
+    };
+
+    let (before_distance, before_angle) = span(before);
+    let (after_distance, after_angle) = span(after);
+    let before_centroid = centroid(before);
+    let after_centroid = centroid(after);
+
+    let zoom = if before_distance > 0.0 && after_distance > 0.0 {
+        after_distance / before_distance
+    } else {
+        1.0
+    };
+    let mut rotation = (after_angle - before_angle).to_degrees();
+    if rotation > 180.0 {
+        rotation -= 360.0;
+    } else if rotation < -180.0 {
+        rotation += 360.0;
+    }
+
+    TransformChange {
+        centroid: after_centroid,
+        zoom,
+        pan: Point::new(
+            after_centroid.x - before_centroid.x,
+            after_centroid.y - before_centroid.y,
+        ),
+        rotation,
+    }
+}
+
+impl Modifier {
+    /// Recognizes pinch-to-zoom, rotation and pan gestures and reports them to `state`.
+    ///
+    /// ```rust,ignore
+    /// let layer = useState(GraphicsLayer::default);
+    /// let modifier = Modifier::empty()
+    ///     .transformable(TransformableState::with_layer(layer))
+    ///     .graphics_layer(layer.value());
+    /// ```
+    ///
+    /// Matches Kotlin: `Modifier.transformable(state)`
+    pub fn transformable(self, state: TransformableState) -> Self {
+        let key = state.id();
+        let detector_state = state.clone();
+        let pointer_input = Modifier::empty().pointer_input(key, move |scope| {
+            let detector = TransformGestureDetector::new(detector_state.clone());
+            async move {
+                scope
+                    .await_pointer_event_scope(|await_scope| async move {
+                        loop {
+                            let event = await_scope.await_pointer_event().await;
+                            if detector.on_event(&event) {
+                                event.consume();
+                            }
+                        }
+                    })
+                    .await;
+            }
+        });
+        self.then(pointer_input.with_inspector_metadata(inspector_metadata(
+            "transformable",
+            move |info| {
+                info.add_property("stateId", key.to_string());
+            },
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modifier::collect_slices_from_modifier;
+    use cranpose_core::{DefaultScheduler, Runtime};
+    use cranpose_foundation::{BasicModifierNodeContext, ModifierNodeChain};
+    use std::sync::Arc;
+
+    fn pointer(kind: PointerEventKind, id: PointerId, x: f32, y: f32) -> PointerEvent {
+        let mut event = PointerEvent::new(kind, Point::new(x, y), Point::new(x, y));
+        event.id = id;
+        event
+    }
+
+    fn recording_detector() -> (TransformGestureDetector, Rc<RefCell<Vec<TransformChange>>>) {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let sink = changes.clone();
+        let state = TransformableState::new(move |change| sink.borrow_mut().push(change));
+        (TransformGestureDetector::new(state), changes)
+    }
+
+    #[test]
+    fn pointers_moving_apart_report_zoom_around_centroid() {
+        let (detector, changes) = recording_detector();
+
+        detector.on_event(&pointer(PointerEventKind::Down, 1, 40.0, 50.0));
+        assert!(detector.on_event(&pointer(PointerEventKind::Down, 2, 60.0, 50.0)));
+        assert!(detector.on_event(&pointer(PointerEventKind::Move, 1, 30.0, 50.0)));
+        assert!(detector.on_event(&pointer(PointerEventKind::Move, 2, 70.0, 50.0)));
+
+        let changes = changes.borrow();
+        assert_eq!(changes.len(), 2);
+        let total_zoom: f32 = changes.iter().map(|c| c.zoom).product();
+        assert!((total_zoom - 2.0).abs() < 1e-4, "zoom was {total_zoom}");
+        assert!(changes.iter().all(|c| c.zoom > 1.0));
+        assert_eq!(changes[1].centroid, Point::new(50.0, 50.0));
+        assert!(changes.iter().all(|c| c.rotation.abs() < 1e-4));
+    }
+
+    #[test]
+    fn rotating_pointers_report_rotation_in_degrees() {
+        let (detector, changes) = recording_detector();
+
+        detector.on_event(&pointer(PointerEventKind::Down, 1, 0.0, 0.0));
+        detector.on_event(&pointer(PointerEventKind::Down, 2, 10.0, 0.0));
+        detector.on_event(&pointer(PointerEventKind::Move, 2, 0.0, 10.0));
+
+        let change = changes.borrow()[0];
+        assert!((change.rotation - 90.0).abs() < 1e-3);
+        assert!((change.zoom - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn lifting_one_finger_falls_back_to_pan() {
+        let (detector, changes) = recording_detector();
+
+        detector.on_event(&pointer(PointerEventKind::Down, 1, 0.0, 0.0));
+        detector.on_event(&pointer(PointerEventKind::Down, 2, 10.0, 0.0));
+        detector.on_event(&pointer(PointerEventKind::Move, 2, 20.0, 0.0));
+        detector.on_event(&pointer(PointerEventKind::Up, 1, 0.0, 0.0));
+        assert!(detector.state.is_transform_in_progress());
+
+        // Small moves pan immediately since the gesture was already active
+        assert!(detector.on_event(&pointer(PointerEventKind::Move, 2, 23.0, 4.0)));
+        let last = *changes.borrow().last().unwrap();
+        assert_eq!(last.pan, Point::new(3.0, 4.0));
+        assert_eq!(last.zoom, 1.0);
+
+        detector.on_event(&pointer(PointerEventKind::Up, 2, 23.0, 4.0));
+        assert!(!detector.state.is_transform_in_progress());
+    }
+
+    #[test]
+    fn pinch_through_pointer_input_scales_layer_around_centroid() {
+        let runtime = Runtime::new(Arc::new(DefaultScheduler));
+        let layer = MutableState::with_runtime(GraphicsLayer::default(), runtime.handle());
+        let modifier = Modifier::empty().transformable(TransformableState::with_layer(layer));
+        let mut chain = ModifierNodeChain::new();
+        let mut context = BasicModifierNodeContext::new();
+        chain.update_from_slice(&modifier.elements(), &mut context);
+        let handler = collect_slices_from_modifier(&modifier).pointer_inputs()[0].clone();
+
+        // Positions are local to the node as drawn with the current layer, like hit testing
+        let send = |kind, id, x: f32, y: f32| {
+            let current = layer.get_non_reactive();
+            let global = Point::new(x, y);
+            let local = Point::new(x - current.translation_x, y - current.translation_y);
+            let mut event = PointerEvent::new(kind, local, global);
+            event.id = id;
+            handler(event);
+        };
+        send(PointerEventKind::Down, 1, 40.0, 50.0);
+        send(PointerEventKind::Down, 2, 60.0, 50.0);
+        send(PointerEventKind::Move, 1, 30.0, 50.0);
+        send(PointerEventKind::Move, 2, 70.0, 50.0);
+
+        // The content point under the unmoved centroid (50, 50) is still there at twice the size
+        let result = layer.get_non_reactive();
+        assert!(
+            (result.scale - 2.0).abs() < 1e-4,
+            "scale was {}",
+            result.scale
+        );
+        assert!((50.0 * result.scale + result.translation_x - 50.0).abs() < 1e-3);
+        assert!((50.0 * result.scale + result.translation_y - 50.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn single_pointer_below_threshold_is_not_consumed() {
+        let (detector, changes) = recording_detector();
+
+        assert!(!detector.on_event(&pointer(PointerEventKind::Down, 1, 0.0, 0.0)));
+        assert!(!detector.on_event(&pointer(PointerEventKind::Move, 1, 2.0, 0.0)));
+        assert!(!detector.on_event(&pointer(PointerEventKind::Up, 1, 2.0, 0.0)));
+        assert!(changes.borrow().is_empty());
+    }
+}
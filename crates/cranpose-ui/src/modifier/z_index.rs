@@ -0,0 +1,21 @@
+use super::{inspector_metadata, Modifier};
+use crate::modifier_nodes::ZIndexElement;
+
+impl Modifier {
+    /// Control the drawing order of this layout among its siblings.
+    ///
+    /// Siblings with a larger `z_index` are drawn on top of those with a smaller one;
+    /// siblings with the same value are drawn in composition order. The default is `0.0`.
+    ///
+    /// Matches Kotlin: `Modifier.zIndex(zIndex: Float)`
+    ///
+    /// Example: `Modifier::empty().z_index(1.0)`
+    pub fn z_index(self, z_index: f32) -> Self {
+        let modifier = Self::with_element(ZIndexElement::new(z_index)).with_inspector_metadata(
+            inspector_metadata("zIndex", move |info| {
+                info.add_property("zIndex", z_index.to_string());
+            }),
+        );
+        self.then(modifier)
+    }
+}
@@ -39,19 +39,27 @@
 //! - [`PaddingNode`] / [`PaddingElement`]: Adds padding around content
 //! - [`SizeNode`] / [`SizeElement`]: Enforces specific dimensions
 //! - [`FillNode`] / [`FillElement`]: Fills available space with optional fractions
+//! - [`WrapContentNode`] / [`WrapContentElement`]: Measures content at its desired size and aligns it
+//! - [`DefaultMinSizeNode`] / [`DefaultMinSizeElement`]: Minimum size when the parent sets none
 //! - [`OffsetNode`] / [`OffsetElement`]: Translates content by offset
 //! - [`WeightNode`] / [`WeightElement`]: Proportional sizing in flex containers
 //! - [`AlignmentNode`] / [`AlignmentElement`]: Alignment within parent
 //! - [`IntrinsicSizeNode`] / [`IntrinsicSizeElement`]: Intrinsic measurement
+//! - [`AspectRatioNode`] / [`AspectRatioElement`]: Sizes content to a width/height ratio
 //!
 //! ## Draw Modifiers
 //! - [`BackgroundNode`] / [`BackgroundElement`]: Draws a background color
+//! - [`BorderNode`] / [`BorderElement`]: Strokes a solid, dashed or dotted border
 //! - [`AlphaNode`] / [`AlphaElement`]: Applies alpha transparency
 //! - [`CornerShapeNode`] / [`CornerShapeElement`]: Rounded corner clipping
 //! - [`GraphicsLayerNode`] / [`GraphicsLayerElement`]: Advanced transformations
+//! - [`BlurNode`] / [`BlurElement`]: Blurs content in an offscreen layer
+//! - [`ShadowNode`] / [`ShadowElement`]: Draws an elevation shadow behind content
+//! - [`VisualOffsetNode`] / [`VisualOffsetElement`]: Shifts drawing without moving layout bounds
+//! - [`ZIndexNode`] / [`ZIndexElement`]: Draw order among siblings
 //!
 //! ## Input Modifiers
-//! - [`ClickableNode`] / [`ClickableElement`]: Handles click/tap interactions (pointer input)
+//! - [`ClickableNode`] / [`ClickableElement`]: Handles click/tap interactions and draws the ripple indication
 //!
 //! # Architecture Notes
 //!
@@ -65,18 +73,62 @@ use cranpose_foundation::{
     MeasurementProxy, ModifierNode, ModifierNodeContext, ModifierNodeElement, NodeCapabilities,
     NodeState, PointerEvent, PointerEventKind, PointerInputNode, Size,
 };
-use cranpose_ui_layout::{Alignment, HorizontalAlignment, IntrinsicSize, VerticalAlignment};
+use cranpose_ui_layout::{
+    Alignment, AlignmentLine, HorizontalAlignment, IntrinsicSize, VerticalAlignment,
+};
 
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
 use crate::draw::DrawCommand;
-use crate::modifier::{Color, EdgeInsets, GraphicsLayer, LayoutWeight, Point, RoundedCornerShape};
+use crate::modifier::{
+    Brush, Color, EdgeInsets, GraphicsLayer, LayoutWeight, Point, Rect, RoundedCornerShape,
+    ShadowStyle,
+};
 
 fn hash_f32_value<H: Hasher>(state: &mut H, value: f32) {
     state.write_u32(value.to_bits());
 }
 
+fn hash_color<H: Hasher>(state: &mut H, color: Color) {
+    hash_f32_value(state, color.0);
+    hash_f32_value(state, color.1);
+    hash_f32_value(state, color.2);
+    hash_f32_value(state, color.3);
+}
+
+fn hash_brush<H: Hasher>(state: &mut H, brush: &Brush) {
+    match brush {
+        Brush::Solid(color) => {
+            state.write_u8(0);
+            hash_color(state, *color);
+        }
+        Brush::LinearGradient {
+            colors,
+            color_space,
+        } => {
+            state.write_u8(1);
+            state.write_usize(colors.len());
+            colors.iter().for_each(|color| hash_color(state, *color));
+            color_space.hash(state);
+        }
+        Brush::RadialGradient {
+            colors,
+            center,
+            radius,
+            color_space,
+        } => {
+            state.write_u8(2);
+            state.write_usize(colors.len());
+            colors.iter().for_each(|color| hash_color(state, *color));
+            hash_f32_value(state, center.x);
+            hash_f32_value(state, center.y);
+            hash_f32_value(state, *radius);
+            color_space.hash(state);
+        }
+    }
+}
+
 fn hash_option_f32<H: Hasher>(state: &mut H, value: Option<f32>) {
     match value {
         Some(v) => {
@@ -92,6 +144,8 @@ fn hash_graphics_layer<H: Hasher>(state: &mut H, layer: GraphicsLayer) {
     hash_f32_value(state, layer.scale);
     hash_f32_value(state, layer.translation_x);
     hash_f32_value(state, layer.translation_y);
+    state.write_u8(layer.clip as u8);
+    hash_f32_value(state, layer.shadow_elevation);
 }
 
 fn hash_horizontal_alignment<H: Hasher>(state: &mut H, alignment: HorizontalAlignment) {
@@ -172,13 +226,8 @@ impl LayoutModifierNode for PaddingNode {
         let horizontal_padding = self.padding.horizontal_sum();
         let vertical_padding = self.padding.vertical_sum();
 
-        // Subtract padding from available space
-        let inner_constraints = Constraints {
-            min_width: (constraints.min_width - horizontal_padding).max(0.0),
-            max_width: (constraints.max_width - horizontal_padding).max(0.0),
-            min_height: (constraints.min_height - vertical_padding).max(0.0),
-            max_height: (constraints.max_height - vertical_padding).max(0.0),
-        };
+        // Subtract padding from available space, keeping 0 <= min <= max
+        let inner_constraints = constraints.deflate(horizontal_padding, vertical_padding);
 
         // Measure the wrapped content
         let inner_placeable = measurable.measure(inner_constraints);
@@ -253,13 +302,8 @@ impl MeasurementProxy for PaddingMeasurementProxy {
         let horizontal_padding = self.padding.horizontal_sum();
         let vertical_padding = self.padding.vertical_sum();
 
-        // Subtract padding from available space
-        let inner_constraints = Constraints {
-            min_width: (constraints.min_width - horizontal_padding).max(0.0),
-            max_width: (constraints.max_width - horizontal_padding).max(0.0),
-            min_height: (constraints.min_height - vertical_padding).max(0.0),
-            max_height: (constraints.max_height - vertical_padding).max(0.0),
-        };
+        // Subtract padding from available space, keeping 0 <= min <= max
+        let inner_constraints = constraints.deflate(horizontal_padding, vertical_padding);
 
         // Measure the wrapped content
         let inner_placeable = wrapped.measure(inner_constraints);
@@ -355,22 +399,22 @@ impl ModifierNodeElement for PaddingElement {
 /// Node that draws a background behind its content.
 #[derive(Debug)]
 pub struct BackgroundNode {
-    color: Color,
+    brush: Brush,
     shape: Option<RoundedCornerShape>,
     state: NodeState,
 }
 
 impl BackgroundNode {
-    pub fn new(color: Color) -> Self {
+    pub fn new(brush: Brush) -> Self {
         Self {
-            color,
+            brush,
             shape: None,
             state: NodeState::new(),
         }
     }
 
-    pub fn color(&self) -> Color {
-        self.color
+    pub fn brush(&self) -> &Brush {
+        &self.brush
     }
 
     pub fn shape(&self) -> Option<RoundedCornerShape> {
@@ -408,21 +452,21 @@ impl DrawModifierNode for BackgroundNode {
 /// Element that creates and updates background nodes.
 #[derive(Debug, Clone, PartialEq)]
 pub struct BackgroundElement {
-    color: Color,
+    brush: Brush,
 }
 
 impl BackgroundElement {
-    pub fn new(color: Color) -> Self {
-        Self { color }
+    /// Creates a background filled with `brush`; a [`Color`] fills it solid.
+    pub fn new(brush: impl Into<Brush>) -> Self {
+        Self {
+            brush: brush.into(),
+        }
     }
 }
 
 impl Hash for BackgroundElement {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        hash_f32_value(state, self.color.0);
-        hash_f32_value(state, self.color.1);
-        hash_f32_value(state, self.color.2);
-        hash_f32_value(state, self.color.3);
+        hash_brush(state, &self.brush);
     }
 }
 
@@ -430,12 +474,12 @@ impl ModifierNodeElement for BackgroundElement {
     type Node = BackgroundNode;
 
     fn create(&self) -> Self::Node {
-        BackgroundNode::new(self.color)
+        BackgroundNode::new(self.brush.clone())
     }
 
     fn update(&self, node: &mut Self::Node) {
-        if node.color != self.color {
-            node.color = self.color;
+        if node.brush != self.brush {
+            node.brush = self.brush.clone();
             // Note: In a full implementation, we would invalidate draw here
         }
     }
@@ -445,6 +489,225 @@ impl ModifierNodeElement for BackgroundElement {
     }
 }
 
+// ============================================================================
+// Border Modifier Node
+// ============================================================================
+
+/// Stroke pattern used by [`BorderNode`].
+///
+/// Matches Kotlin: `PathEffect.dashPathEffect(intervals)` applied to a border stroke.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BorderStyle {
+    /// Continuous stroke.
+    #[default]
+    Solid,
+    /// Dashes of length `dash` separated by `gap`, measured along the perimeter.
+    Dashed { dash: f32, gap: f32 },
+    /// Square dots as long as the stroke is wide, separated by the same distance.
+    Dotted,
+}
+
+impl BorderStyle {
+    /// Returns the `(on, off)` intervals for a stroke of `width`, or None when solid.
+    fn intervals(self, width: f32) -> Option<(f32, f32)> {
+        match self {
+            BorderStyle::Solid => None,
+            BorderStyle::Dashed { dash, gap } => Some((dash, gap)),
+            BorderStyle::Dotted => Some((width, width)),
+        }
+    }
+}
+
+/// Node that strokes a border inside the bounds of its content.
+#[derive(Debug)]
+pub struct BorderNode {
+    width: f32,
+    color: Color,
+    style: BorderStyle,
+    state: NodeState,
+}
+
+impl BorderNode {
+    pub fn new(width: f32, color: Color, style: BorderStyle) -> Self {
+        Self {
+            width,
+            color,
+            style,
+            state: NodeState::new(),
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn style(&self) -> BorderStyle {
+        self.style
+    }
+}
+
+impl DelegatableNode for BorderNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for BorderNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::Draw);
+    }
+
+    fn as_draw_node(&self) -> Option<&dyn DrawModifierNode> {
+        Some(self)
+    }
+
+    fn as_draw_node_mut(&mut self) -> Option<&mut dyn DrawModifierNode> {
+        Some(self)
+    }
+}
+
+impl DrawModifierNode for BorderNode {
+    fn create_draw_closure(
+        &self,
+    ) -> Option<Rc<dyn Fn(Size) -> Vec<cranpose_ui_graphics::DrawPrimitive>>> {
+        // The segments depend on the node size, which is only known at render time
+        let (width, color, style) = (self.width, self.color, self.style);
+        Some(Rc::new(move |size| {
+            let brush = cranpose_ui_graphics::Brush::solid(color);
+            border_segments(size, width, style)
+                .into_iter()
+                .map(|rect| cranpose_ui_graphics::DrawPrimitive::Rect {
+                    rect,
+                    brush: brush.clone(),
+                })
+                .collect()
+        }))
+    }
+}
+
+/// Splits the border band of `size` into the rectangles covered by `style`.
+///
+/// The band is walked clockwise from the top-left corner as four edges that
+/// tile it without overlap, so the dash pattern continues around corners.
+fn border_segments(size: Size, width: f32, style: BorderStyle) -> Vec<Rect> {
+    let width = width.min(size.width / 2.0).min(size.height / 2.0);
+    if width <= 0.0 {
+        return Vec::new();
+    }
+    let (w, h) = (size.width, size.height);
+    let edge_lengths = [w, h - width, w - width, h - 2.0 * width];
+    // Rect covering [start, end) measured along the given edge
+    let edge_span = |edge: usize, start: f32, end: f32| match edge {
+        0 => Rect {
+            x: start,
+            y: 0.0,
+            width: end - start,
+            height: width,
+        },
+        1 => Rect {
+            x: w - width,
+            y: width + start,
+            width,
+            height: end - start,
+        },
+        2 => Rect {
+            x: w - width - end,
+            y: h - width,
+            width: end - start,
+            height: width,
+        },
+        _ => Rect {
+            x: 0.0,
+            y: h - width - end,
+            width,
+            height: end - start,
+        },
+    };
+
+    let mut segments = Vec::new();
+    let mut emit = |from: f32, to: f32| {
+        let mut edge_start = 0.0;
+        for (edge, length) in edge_lengths.into_iter().enumerate() {
+            let start = from.max(edge_start);
+            let end = to.min(edge_start + length);
+            if end > start {
+                segments.push(edge_span(edge, start - edge_start, end - edge_start));
+            }
+            edge_start += length;
+        }
+    };
+
+    let perimeter: f32 = edge_lengths.iter().sum();
+    match style.intervals(width) {
+        Some((on, off)) if on > 0.0 && on + off > 0.0 => {
+            let mut position = 0.0;
+            while position < perimeter {
+                emit(position, (position + on).min(perimeter));
+                position += on + off.max(0.0);
+            }
+        }
+        Some(_) => {}
+        None => emit(0.0, perimeter),
+    }
+    segments
+}
+
+/// Element that creates and updates border nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorderElement {
+    width: f32,
+    color: Color,
+    style: BorderStyle,
+}
+
+impl BorderElement {
+    pub fn new(width: f32, color: Color, style: BorderStyle) -> Self {
+        Self {
+            width,
+            color,
+            style,
+        }
+    }
+}
+
+impl Hash for BorderElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32_value(state, self.width);
+        hash_color(state, self.color);
+        match self.style {
+            BorderStyle::Solid => state.write_u8(0),
+            BorderStyle::Dashed { dash, gap } => {
+                state.write_u8(1);
+                hash_f32_value(state, dash);
+                hash_f32_value(state, gap);
+            }
+            BorderStyle::Dotted => state.write_u8(2),
+        }
+    }
+}
+
+impl ModifierNodeElement for BorderElement {
+    type Node = BorderNode;
+
+    fn create(&self) -> Self::Node {
+        BorderNode::new(self.width, self.color, self.style)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.width = self.width;
+        node.color = self.color;
+        node.style = self.style;
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::DRAW
+    }
+}
+
 // ============================================================================
 // Size Modifier Node
 // ============================================================================
@@ -607,136 +870,393 @@ impl ModifierNodeElement for GraphicsLayerElement {
 }
 
 // ============================================================================
-// Size Modifier Node
+// Blur Modifier Node
 // ============================================================================
 
-/// Node that enforces size constraints on its content.
+/// Largest blur radius accepted by [`BlurElement`]; larger values are clamped.
+pub const MAX_BLUR_RADIUS: f32 = 64.0;
+
+/// Node that marks a layer boundary whose content is blurred before compositing.
 ///
-/// Matches Kotlin: `SizeNode` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
+/// Matches Kotlin: `Modifier.blur(radius)`, which sets a `BlurEffect` render effect
+/// on a graphics layer.
 #[derive(Debug)]
-pub struct SizeNode {
-    min_width: Option<f32>,
-    max_width: Option<f32>,
-    min_height: Option<f32>,
-    max_height: Option<f32>,
-    enforce_incoming: bool,
+pub struct BlurNode {
+    radius: f32,
     state: NodeState,
 }
 
-impl SizeNode {
-    pub fn new(
-        min_width: Option<f32>,
-        max_width: Option<f32>,
-        min_height: Option<f32>,
-        max_height: Option<f32>,
-        enforce_incoming: bool,
-    ) -> Self {
+impl BlurNode {
+    pub fn new(radius: f32) -> Self {
         Self {
-            min_width,
-            max_width,
-            min_height,
-            max_height,
-            enforce_incoming,
+            radius: radius.clamp(0.0, MAX_BLUR_RADIUS),
             state: NodeState::new(),
         }
     }
 
-    /// Helper to build target constraints from element parameters
-    fn target_constraints(&self) -> Constraints {
-        let max_width = self.max_width.map(|v| v.max(0.0)).unwrap_or(f32::INFINITY);
-        let max_height = self.max_height.map(|v| v.max(0.0)).unwrap_or(f32::INFINITY);
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+}
 
-        let min_width = self
-            .min_width
-            .map(|v| {
-                let clamped = v.clamp(0.0, max_width);
-                if clamped == f32::INFINITY {
-                    0.0
-                } else {
-                    clamped
-                }
-            })
-            .unwrap_or(0.0);
+impl DelegatableNode for BlurNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
 
-        let min_height = self
-            .min_height
-            .map(|v| {
-                let clamped = v.clamp(0.0, max_height);
-                if clamped == f32::INFINITY {
-                    0.0
-                } else {
-                    clamped
-                }
-            })
-            .unwrap_or(0.0);
+impl ModifierNode for BlurNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::Draw);
+    }
+}
 
-        Constraints {
-            min_width,
-            max_width,
-            min_height,
-            max_height,
+/// Element that creates and updates blur nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlurElement {
+    radius: f32,
+}
+
+impl BlurElement {
+    pub fn new(radius: f32) -> Self {
+        Self {
+            radius: radius.clamp(0.0, MAX_BLUR_RADIUS),
         }
     }
+}
 
-    pub fn min_width(&self) -> Option<f32> {
-        self.min_width
+impl Hash for BlurElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32_value(state, self.radius);
     }
+}
 
-    pub fn max_width(&self) -> Option<f32> {
-        self.max_width
+impl ModifierNodeElement for BlurElement {
+    type Node = BlurNode;
+
+    fn create(&self) -> Self::Node {
+        BlurNode::new(self.radius)
     }
 
-    pub fn min_height(&self) -> Option<f32> {
-        self.min_height
+    fn update(&self, node: &mut Self::Node) {
+        node.radius = self.radius;
     }
 
-    pub fn max_height(&self) -> Option<f32> {
-        self.max_height
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::DRAW
     }
+}
 
-    pub fn enforce_incoming(&self) -> bool {
-        self.enforce_incoming
+// ============================================================================
+// Shadow Modifier Node
+// ============================================================================
+
+/// Node that draws a drop shadow in its shape behind the layout's content.
+///
+/// The style is resolved from the elevation when the modifier is created, so
+/// the node only stores what to draw.
+///
+/// Matches Kotlin: `Modifier.shadow(elevation, shape)`
+#[derive(Debug)]
+pub struct ShadowNode {
+    style: ShadowStyle,
+    shape: RoundedCornerShape,
+    state: NodeState,
+}
+
+impl ShadowNode {
+    pub fn new(style: ShadowStyle, shape: RoundedCornerShape) -> Self {
+        Self {
+            style,
+            shape,
+            state: NodeState::new(),
+        }
+    }
+
+    pub fn style(&self) -> ShadowStyle {
+        self.style
+    }
+
+    pub fn shape(&self) -> RoundedCornerShape {
+        self.shape
     }
 }
 
-impl DelegatableNode for SizeNode {
+impl DelegatableNode for ShadowNode {
     fn node_state(&self) -> &NodeState {
         &self.state
     }
 }
 
-impl ModifierNode for SizeNode {
+impl ModifierNode for ShadowNode {
     fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
-        context.invalidate(cranpose_foundation::InvalidationKind::Layout);
+        context.invalidate(cranpose_foundation::InvalidationKind::Draw);
     }
+}
 
-    fn as_layout_node(&self) -> Option<&dyn LayoutModifierNode> {
-        Some(self)
+/// Element that creates and updates shadow nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadowElement {
+    style: ShadowStyle,
+    shape: RoundedCornerShape,
+}
+
+impl ShadowElement {
+    pub fn new(style: ShadowStyle, shape: RoundedCornerShape) -> Self {
+        Self { style, shape }
     }
+}
 
-    fn as_layout_node_mut(&mut self) -> Option<&mut dyn LayoutModifierNode> {
-        Some(self)
+impl Hash for ShadowElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32_value(state, self.style.blur_radius);
+        hash_f32_value(state, self.style.offset_y);
+        hash_f32_value(state, self.style.alpha);
+        let radii = self.shape.radii();
+        hash_f32_value(state, radii.top_left);
+        hash_f32_value(state, radii.top_right);
+        hash_f32_value(state, radii.bottom_right);
+        hash_f32_value(state, radii.bottom_left);
     }
 }
 
-impl LayoutModifierNode for SizeNode {
-    fn measure(
-        &self,
-        _context: &mut dyn ModifierNodeContext,
-        measurable: &dyn Measurable,
-        constraints: Constraints,
-    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
-        let target = self.target_constraints();
+impl ModifierNodeElement for ShadowElement {
+    type Node = ShadowNode;
 
-        let wrapped_constraints = if self.enforce_incoming {
-            // Constrain target constraints by incoming constraints
-            Constraints {
-                min_width: target
-                    .min_width
-                    .max(constraints.min_width)
-                    .min(constraints.max_width),
-                max_width: target
-                    .max_width
+    fn create(&self) -> Self::Node {
+        ShadowNode::new(self.style, self.shape)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.style = self.style;
+        node.shape = self.shape;
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::DRAW
+    }
+}
+
+// ============================================================================
+// ZIndex Modifier Node
+// ============================================================================
+
+/// Node that controls the drawing order of a layout among its siblings.
+///
+/// Siblings with a larger z index are drawn on top; siblings with equal z index
+/// keep their composition order.
+///
+/// Matches Kotlin: `ZIndexNode` in ui/src/commonMain/kotlin/androidx/compose/ui/ZIndexModifier.kt
+#[derive(Debug)]
+pub struct ZIndexNode {
+    z_index: f32,
+    state: NodeState,
+}
+
+impl ZIndexNode {
+    pub fn new(z_index: f32) -> Self {
+        Self {
+            z_index,
+            state: NodeState::new(),
+        }
+    }
+
+    pub fn z_index(&self) -> f32 {
+        self.z_index
+    }
+}
+
+impl DelegatableNode for ZIndexNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for ZIndexNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::Draw);
+    }
+
+    fn as_draw_node(&self) -> Option<&dyn DrawModifierNode> {
+        Some(self)
+    }
+
+    fn as_draw_node_mut(&mut self) -> Option<&mut dyn DrawModifierNode> {
+        Some(self)
+    }
+}
+
+impl DrawModifierNode for ZIndexNode {
+    fn draw(&self, _draw_scope: &mut dyn DrawScope) {
+        // The z index orders siblings through modifier slices; nothing is drawn here.
+    }
+}
+
+/// Element that creates and updates z index nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZIndexElement {
+    z_index: f32,
+}
+
+impl ZIndexElement {
+    pub fn new(z_index: f32) -> Self {
+        Self { z_index }
+    }
+}
+
+impl Hash for ZIndexElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32_value(state, self.z_index);
+    }
+}
+
+impl ModifierNodeElement for ZIndexElement {
+    type Node = ZIndexNode;
+
+    fn create(&self) -> Self::Node {
+        ZIndexNode::new(self.z_index)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.z_index = self.z_index;
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::DRAW
+    }
+}
+
+// ============================================================================
+// Size Modifier Node
+// ============================================================================
+
+/// Node that enforces size constraints on its content.
+///
+/// Matches Kotlin: `SizeNode` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
+#[derive(Debug)]
+pub struct SizeNode {
+    min_width: Option<f32>,
+    max_width: Option<f32>,
+    min_height: Option<f32>,
+    max_height: Option<f32>,
+    enforce_incoming: bool,
+    state: NodeState,
+}
+
+impl SizeNode {
+    pub fn new(
+        min_width: Option<f32>,
+        max_width: Option<f32>,
+        min_height: Option<f32>,
+        max_height: Option<f32>,
+        enforce_incoming: bool,
+    ) -> Self {
+        Self {
+            min_width,
+            max_width,
+            min_height,
+            max_height,
+            enforce_incoming,
+            state: NodeState::new(),
+        }
+    }
+
+    /// Helper to build target constraints from element parameters
+    fn target_constraints(&self) -> Constraints {
+        let max_width = self.max_width.map(|v| v.max(0.0)).unwrap_or(f32::INFINITY);
+        let max_height = self.max_height.map(|v| v.max(0.0)).unwrap_or(f32::INFINITY);
+
+        let min_width = self
+            .min_width
+            .map(|v| {
+                let clamped = v.clamp(0.0, max_width);
+                if clamped == f32::INFINITY {
+                    0.0
+                } else {
+                    clamped
+                }
+            })
+            .unwrap_or(0.0);
+
+        let min_height = self
+            .min_height
+            .map(|v| {
+                let clamped = v.clamp(0.0, max_height);
+                if clamped == f32::INFINITY {
+                    0.0
+                } else {
+                    clamped
+                }
+            })
+            .unwrap_or(0.0);
+
+        Constraints {
+            min_width,
+            max_width,
+            min_height,
+            max_height,
+        }
+    }
+
+    pub fn min_width(&self) -> Option<f32> {
+        self.min_width
+    }
+
+    pub fn max_width(&self) -> Option<f32> {
+        self.max_width
+    }
+
+    pub fn min_height(&self) -> Option<f32> {
+        self.min_height
+    }
+
+    pub fn max_height(&self) -> Option<f32> {
+        self.max_height
+    }
+
+    pub fn enforce_incoming(&self) -> bool {
+        self.enforce_incoming
+    }
+}
+
+impl DelegatableNode for SizeNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for SizeNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::Layout);
+    }
+
+    fn as_layout_node(&self) -> Option<&dyn LayoutModifierNode> {
+        Some(self)
+    }
+
+    fn as_layout_node_mut(&mut self) -> Option<&mut dyn LayoutModifierNode> {
+        Some(self)
+    }
+}
+
+impl LayoutModifierNode for SizeNode {
+    fn measure(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        measurable: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        let target = self.target_constraints();
+
+        let wrapped_constraints = if self.enforce_incoming {
+            // Constrain target constraints by incoming constraints
+            Constraints {
+                min_width: target
+                    .min_width
+                    .max(constraints.min_width)
+                    .min(constraints.max_width),
+                max_width: target
+                    .max_width
                     .min(constraints.max_width)
                     .max(constraints.min_width),
                 min_height: target
@@ -779,12 +1299,14 @@ impl LayoutModifierNode for SizeNode {
             }
         };
 
+        let wrapped_constraints = wrapped_constraints.normalized();
         let placeable = measurable.measure(wrapped_constraints);
         let measured_width = placeable.width();
         let measured_height = placeable.height();
 
         // Return the target size when both min==max (fixed size), but only if it satisfies
-        // the wrapped constraints we passed down. Otherwise return measured size.
+        // the wrapped constraints we passed down. Otherwise return measured size, coerced
+        // into those constraints like any placeable.
         // This handles the case where enforce_incoming=true and incoming constraints are tighter.
         let result_width = if self.min_width.is_some()
             && self.max_width.is_some()
@@ -794,7 +1316,7 @@ impl LayoutModifierNode for SizeNode {
         {
             target.min_width
         } else {
-            measured_width
+            measured_width.clamp(wrapped_constraints.min_width, wrapped_constraints.max_width)
         };
 
         let result_height = if self.min_height.is_some()
@@ -805,7 +1327,10 @@ impl LayoutModifierNode for SizeNode {
         {
             target.min_height
         } else {
-            measured_height
+            measured_height.clamp(
+                wrapped_constraints.min_height,
+                wrapped_constraints.max_height,
+            )
         };
 
         // SizeNode doesn't offset placement - child is placed at (0, 0) relative to this node
@@ -1003,12 +1528,14 @@ impl MeasurementProxy for SizeMeasurementProxy {
             }
         };
 
+        let wrapped_constraints = wrapped_constraints.normalized();
         let placeable = wrapped.measure(wrapped_constraints);
         let measured_width = placeable.width();
         let measured_height = placeable.height();
 
         // Return the target size when both min==max (fixed size), but only if it satisfies
-        // the wrapped constraints we passed down. Otherwise return measured size.
+        // the wrapped constraints we passed down. Otherwise return measured size, coerced
+        // into those constraints like any placeable.
         let result_width = if self.min_width.is_some()
             && self.max_width.is_some()
             && self.min_width == self.max_width
@@ -1017,7 +1544,7 @@ impl MeasurementProxy for SizeMeasurementProxy {
         {
             target.min_width
         } else {
-            measured_width
+            measured_width.clamp(wrapped_constraints.min_width, wrapped_constraints.max_width)
         };
 
         let result_height = if self.min_height.is_some()
@@ -1028,7 +1555,10 @@ impl MeasurementProxy for SizeMeasurementProxy {
         {
             target.min_height
         } else {
-            measured_height
+            measured_height.clamp(
+                wrapped_constraints.min_height,
+                wrapped_constraints.max_height,
+            )
         };
 
         // SizeNode doesn't offset placement - child is placed at (0, 0) relative to this node
@@ -1194,6 +1724,9 @@ impl ModifierNodeElement for SizeElement {
 // Drag threshold is now shared via cranpose_foundation::DRAG_THRESHOLD
 use cranpose_foundation::DRAG_THRESHOLD;
 
+use crate::ripple::{Ripple, RippleState};
+use cranpose_core::current_runtime_handle;
+
 use std::cell::RefCell;
 
 // Press position is stored per-node via Rc<RefCell> for sharing with handler closure
@@ -1205,6 +1738,10 @@ pub struct ClickableNode {
     state: NodeState,
     /// Shared press position for drag detection (per-node state, accessible by handler closure)
     press_position: Rc<RefCell<Option<Point>>>,
+    /// Ripple indication state, shared with the handler and draw closures
+    ripple: Option<RippleState>,
+    /// Disabled nodes ignore pointer input and never show the ripple
+    enabled: bool,
     /// Cached handler closure - created once, returned on every pointer_input_handler() call
     cached_handler: Rc<dyn Fn(PointerEvent)>,
 }
@@ -1216,30 +1753,85 @@ impl std::fmt::Debug for ClickableNode {
 }
 
 impl ClickableNode {
+    /// Creates a clickable without an indication; `Modifier::clickable` adds the
+    /// default ripple.
     pub fn new(on_click: impl Fn(Point) + 'static) -> Self {
         Self::with_handler(Rc::new(on_click))
     }
 
     pub fn with_handler(on_click: Rc<dyn Fn(Point)>) -> Self {
+        Self::with_indication(on_click, None)
+    }
+
+    pub fn with_indication(on_click: Rc<dyn Fn(Point)>, indication: Option<Ripple>) -> Self {
         let press_position = Rc::new(RefCell::new(None));
-        let cached_handler = Self::create_handler(on_click.clone(), press_position.clone());
+        let ripple = indication.map(RippleState::new);
+        let cached_handler = Self::create_handler(
+            on_click.clone(),
+            press_position.clone(),
+            ripple.clone(),
+            true,
+        );
         Self {
             on_click,
             state: NodeState::new(),
             press_position,
+            ripple,
+            enabled: true,
             cached_handler,
         }
     }
 
+    /// Enables or disables click handling for this node.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.set_enabled(enabled);
+        self
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            *self.press_position.borrow_mut() = None;
+            if let Some(ripple) = &self.ripple {
+                ripple.release();
+            }
+        }
+        self.cached_handler = Self::create_handler(
+            self.on_click.clone(),
+            self.press_position.clone(),
+            self.ripple.clone(),
+            enabled,
+        );
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Press ripples of this node, if it shows an indication.
+    pub(crate) fn ripple(&self) -> Option<&RippleState> {
+        self.ripple.as_ref()
+    }
+
     fn create_handler(
         handler: Rc<dyn Fn(Point)>,
         press_position: Rc<RefCell<Option<Point>>>,
+        ripple: Option<RippleState>,
+        enabled: bool,
     ) -> Rc<dyn Fn(PointerEvent)> {
         Rc::new(move |event: PointerEvent| {
+            // Disabled clickables let events pass through untouched
+            if !enabled {
+                return;
+            }
+
             // Check if event was consumed by scroll or other gesture handlers
             if event.is_consumed() {
                 // Clear press state if event was consumed
                 *press_position.borrow_mut() = None;
+                if let Some(ripple) = &ripple {
+                    ripple.release();
+                }
                 return;
             }
 
@@ -1250,28 +1842,33 @@ impl ClickableNode {
                         x: event.global_position.x,
                         y: event.global_position.y,
                     });
+                    // Ripples are drawn in local coordinates and animate on the frame clock
+                    if let (Some(ripple), Some(runtime)) = (&ripple, current_runtime_handle()) {
+                        ripple.press(event.position, runtime);
+                    }
                 }
                 PointerEventKind::Move => {
-                    // Move events are tracked via press_position for drag detection
+                    // Leaving the bounds cancels the press, like a real button
+                    if !event.is_within_target() && press_position.borrow_mut().take().is_some() {
+                        if let Some(ripple) = &ripple {
+                            ripple.release();
+                        }
+                    }
                 }
                 PointerEventKind::Up => {
-                    // Check if this is a click (Up near Down) or a drag (Up far from Down)
-                    let press_pos_value = *press_position.borrow();
-
-                    let should_click = if let Some(press_pos) = press_pos_value {
-                        let dx = event.global_position.x - press_pos.x;
-                        let dy = event.global_position.y - press_pos.y;
-                        let distance = (dx * dx + dy * dy).sqrt();
-                        distance <= DRAG_THRESHOLD
-                    } else {
-                        // No Down was tracked - fire click anyway
-                        // This preserves the original behavior for cases where Down
-                        // was handled by a different mechanism
-                        true
-                    };
-
-                    // Reset press position
-                    *press_position.borrow_mut() = None;
+                    // Only a press released inside the bounds, near where it
+                    // started, is a click; anything else was a drag or cancelled
+                    let should_click =
+                        press_position.borrow_mut().take().is_some_and(|press_pos| {
+                            let dx = event.global_position.x - press_pos.x;
+                            let dy = event.global_position.y - press_pos.y;
+                            let distance = (dx * dx + dy * dy).sqrt();
+                            distance <= DRAG_THRESHOLD && event.is_within_target()
+                        });
+
+                    if let Some(ripple) = &ripple {
+                        ripple.release();
+                    }
 
                     if should_click {
                         handler(Point {
@@ -1284,7 +1881,11 @@ impl ClickableNode {
                 PointerEventKind::Cancel => {
                     // Clear press state on cancel
                     *press_position.borrow_mut() = None;
+                    if let Some(ripple) = &ripple {
+                        ripple.release();
+                    }
                 }
+                PointerEventKind::Enter | PointerEventKind::Exit => {}
             }
         })
     }
@@ -1305,6 +1906,20 @@ impl ModifierNode for ClickableNode {
         context.invalidate(cranpose_foundation::InvalidationKind::PointerInput);
     }
 
+    fn on_detach(&mut self) {
+        if let Some(ripple) = &self.ripple {
+            ripple.clear();
+        }
+    }
+
+    fn as_draw_node(&self) -> Option<&dyn DrawModifierNode> {
+        Some(self)
+    }
+
+    fn as_draw_node_mut(&mut self) -> Option<&mut dyn DrawModifierNode> {
+        Some(self)
+    }
+
     fn as_pointer_input_node(&self) -> Option<&dyn PointerInputNode> {
         Some(self)
     }
@@ -1314,6 +1929,15 @@ impl ModifierNode for ClickableNode {
     }
 }
 
+impl DrawModifierNode for ClickableNode {
+    fn create_draw_closure(
+        &self,
+    ) -> Option<Rc<dyn Fn(Size) -> Vec<cranpose_ui_graphics::DrawPrimitive>>> {
+        // Ripples change every frame, so they are evaluated at render time
+        self.ripple.as_ref().map(RippleState::draw_closure)
+    }
+}
+
 impl PointerInputNode for ClickableNode {
     fn on_pointer_event(
         &mut self,
@@ -1327,8 +1951,8 @@ impl PointerInputNode for ClickableNode {
     }
 
     fn hit_test(&self, _x: f32, _y: f32) -> bool {
-        // Always participate in hit testing
-        true
+        // Only enabled clickables participate in hit testing
+        self.enabled
     }
 
     fn pointer_input_handler(&self) -> Option<Rc<dyn Fn(PointerEvent)>> {
@@ -1342,17 +1966,34 @@ impl PointerInputNode for ClickableNode {
 #[derive(Clone)]
 pub struct ClickableElement {
     on_click: Rc<dyn Fn(Point)>,
+    indication: Option<Ripple>,
+    enabled: bool,
 }
 
 impl ClickableElement {
+    /// Creates a clickable without an indication; `Modifier::clickable` adds the
+    /// default ripple.
     pub fn new(on_click: impl Fn(Point) + 'static) -> Self {
+        Self::with_handler(Rc::new(on_click))
+    }
+
+    pub fn with_handler(on_click: Rc<dyn Fn(Point)>) -> Self {
+        Self::with_indication(on_click, None)
+    }
+
+    /// Creates a clickable element with a custom indication, or none at all.
+    pub fn with_indication(on_click: Rc<dyn Fn(Point)>, indication: Option<Ripple>) -> Self {
         Self {
-            on_click: Rc::new(on_click),
+            on_click,
+            indication,
+            enabled: true,
         }
     }
 
-    pub fn with_handler(on_click: Rc<dyn Fn(Point)>) -> Self {
-        Self { on_click }
+    /// Enables or disables the clickable; disabled elements ignore pointer input.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
     }
 }
 
@@ -1384,7 +2025,8 @@ impl ModifierNodeElement for ClickableElement {
     type Node = ClickableNode;
 
     fn create(&self) -> Self::Node {
-        ClickableNode::with_handler(self.on_click.clone())
+        ClickableNode::with_indication(self.on_click.clone(), self.indication)
+            .with_enabled(self.enabled)
     }
 
     // Note: key() is deliberately NOT implemented (returns None by default)
@@ -1396,13 +2038,21 @@ impl ModifierNodeElement for ClickableElement {
         // Update the handler - the cached_handler needs to be recreated
         // with the new on_click while preserving press_position
         node.on_click = self.on_click.clone();
+        // Keep running ripples when only the indication config changes
+        match (&node.ripple, self.indication) {
+            (Some(ripple), Some(config)) => ripple.set_config(config),
+            (_, indication) => node.ripple = indication.map(RippleState::new),
+        }
         // Recreate the cached handler with the same press_position but new click handler
-        node.cached_handler =
-            ClickableNode::create_handler(node.on_click.clone(), node.press_position.clone());
+        node.set_enabled(self.enabled);
     }
 
     fn capabilities(&self) -> NodeCapabilities {
-        NodeCapabilities::POINTER_INPUT
+        if self.indication.is_some() {
+            NodeCapabilities::POINTER_INPUT | NodeCapabilities::DRAW
+        } else {
+            NodeCapabilities::POINTER_INPUT
+        }
     }
 
     fn always_update(&self) -> bool {
@@ -1548,6 +2198,10 @@ impl AlphaNode {
             state: NodeState::new(),
         }
     }
+
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
 }
 
 impl DelegatableNode for AlphaNode {
@@ -1572,13 +2226,8 @@ impl ModifierNode for AlphaNode {
 
 impl DrawModifierNode for AlphaNode {
     fn draw(&self, _draw_scope: &mut dyn DrawScope) {
-        // In a full implementation, this would:
-        // 1. Save the current alpha/layer state
-        // 2. Apply the alpha value to the graphics context
-        // 3. Draw content via draw_scope.draw_content()
-        // 4. Restore previous state
-        //
-        // For now this is a placeholder showing the structure
+        // Alpha is folded into the node's graphics layer in modifier slices,
+        // and renderers multiply it into every draw of the subtree.
     }
 }
 
@@ -1739,6 +2388,7 @@ fn draw_command_tag(cmd: &DrawCommand) -> u8 {
     match cmd {
         DrawCommand::Behind(_) => 0,
         DrawCommand::Overlay(_) => 1,
+        DrawCommand::OverlayClipped(..) => 2,
     }
 }
 
@@ -2014,6 +2664,379 @@ impl ModifierNodeElement for OffsetElement {
     }
 }
 
+/// Node that shifts where its content is drawn without moving its layout bounds.
+///
+/// Matches Kotlin: `Modifier.graphicsLayer { translationX; translationY }`
+#[derive(Debug)]
+pub struct VisualOffsetNode {
+    x: f32,
+    y: f32,
+    state: NodeState,
+}
+
+impl VisualOffsetNode {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x,
+            y,
+            state: NodeState::new(),
+        }
+    }
+
+    pub fn offset(&self) -> Point {
+        Point {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl DelegatableNode for VisualOffsetNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for VisualOffsetNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::Draw);
+    }
+
+    fn as_draw_node(&self) -> Option<&dyn DrawModifierNode> {
+        Some(self)
+    }
+
+    fn as_draw_node_mut(&mut self) -> Option<&mut dyn DrawModifierNode> {
+        Some(self)
+    }
+}
+
+impl DrawModifierNode for VisualOffsetNode {
+    fn draw(&self, _draw_scope: &mut dyn DrawScope) {
+        // The offset is folded into the node's graphics layer translation in modifier slices.
+    }
+}
+
+/// Element that creates and updates visual offset nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisualOffsetElement {
+    x: f32,
+    y: f32,
+}
+
+impl VisualOffsetElement {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Hash for VisualOffsetElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32_value(state, self.x);
+        hash_f32_value(state, self.y);
+    }
+}
+
+impl ModifierNodeElement for VisualOffsetElement {
+    type Node = VisualOffsetNode;
+
+    fn create(&self) -> Self::Node {
+        VisualOffsetNode::new(self.x, self.y)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.x = self.x;
+        node.y = self.y;
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::DRAW
+    }
+}
+
+// ============================================================================
+// Aspect Ratio Modifier Node
+// ============================================================================
+
+/// Node that sizes its content to a width/height ratio.
+///
+/// Matches Kotlin: `AspectRatioNode` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/AspectRatio.kt
+#[derive(Debug)]
+pub struct AspectRatioNode {
+    ratio: f32,
+    match_height_constraints_first: bool,
+    state: NodeState,
+}
+
+impl AspectRatioNode {
+    pub fn new(ratio: f32, match_height_constraints_first: bool) -> Self {
+        Self {
+            ratio,
+            match_height_constraints_first,
+            state: NodeState::new(),
+        }
+    }
+
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    pub fn match_height_constraints_first(&self) -> bool {
+        self.match_height_constraints_first
+    }
+}
+
+impl DelegatableNode for AspectRatioNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for AspectRatioNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::Layout);
+    }
+
+    fn as_layout_node(&self) -> Option<&dyn LayoutModifierNode> {
+        Some(self)
+    }
+
+    fn as_layout_node_mut(&mut self) -> Option<&mut dyn LayoutModifierNode> {
+        Some(self)
+    }
+}
+
+impl LayoutModifierNode for AspectRatioNode {
+    fn measure(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        measurable: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        measure_aspect_ratio(
+            self.ratio,
+            self.match_height_constraints_first,
+            measurable,
+            constraints,
+        )
+    }
+
+    fn min_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        aspect_ratio_width(self.ratio, height)
+            .unwrap_or_else(|| measurable.min_intrinsic_width(height))
+    }
+
+    fn max_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        aspect_ratio_width(self.ratio, height)
+            .unwrap_or_else(|| measurable.max_intrinsic_width(height))
+    }
+
+    fn min_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        aspect_ratio_height(self.ratio, width)
+            .unwrap_or_else(|| measurable.min_intrinsic_height(width))
+    }
+
+    fn max_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        aspect_ratio_height(self.ratio, width)
+            .unwrap_or_else(|| measurable.max_intrinsic_height(width))
+    }
+
+    fn create_measurement_proxy(&self) -> Option<Box<dyn MeasurementProxy>> {
+        Some(Box::new(AspectRatioMeasurementProxy {
+            ratio: self.ratio,
+            match_height_constraints_first: self.match_height_constraints_first,
+        }))
+    }
+}
+
+/// Measurement proxy for AspectRatioNode that snapshots live state.
+struct AspectRatioMeasurementProxy {
+    ratio: f32,
+    match_height_constraints_first: bool,
+}
+
+impl MeasurementProxy for AspectRatioMeasurementProxy {
+    fn measure_proxy(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        wrapped: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        measure_aspect_ratio(
+            self.ratio,
+            self.match_height_constraints_first,
+            wrapped,
+            constraints,
+        )
+    }
+
+    fn min_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
+        aspect_ratio_width(self.ratio, height)
+            .unwrap_or_else(|| wrapped.min_intrinsic_width(height))
+    }
+
+    fn max_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
+        aspect_ratio_width(self.ratio, height)
+            .unwrap_or_else(|| wrapped.max_intrinsic_width(height))
+    }
+
+    fn min_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
+        aspect_ratio_height(self.ratio, width)
+            .unwrap_or_else(|| wrapped.min_intrinsic_height(width))
+    }
+
+    fn max_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
+        aspect_ratio_height(self.ratio, width)
+            .unwrap_or_else(|| wrapped.max_intrinsic_height(width))
+    }
+}
+
+/// Intrinsic width implied by `height`, when the height is known.
+fn aspect_ratio_width(ratio: f32, height: f32) -> Option<f32> {
+    height.is_finite().then_some(height * ratio)
+}
+
+/// Intrinsic height implied by `width`, when the width is known.
+fn aspect_ratio_height(ratio: f32, width: f32) -> Option<f32> {
+    width.is_finite().then_some(width / ratio)
+}
+
+/// Measures `measurable` at the first size with the requested ratio that fits
+/// `constraints`, or with the incoming constraints when no such size exists.
+fn measure_aspect_ratio(
+    ratio: f32,
+    match_height_constraints_first: bool,
+    measurable: &dyn Measurable,
+    constraints: Constraints,
+) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+    let constraints = constraints.normalized();
+    let wrapped_constraints =
+        find_aspect_ratio_size(ratio, match_height_constraints_first, constraints)
+            .map_or(constraints, |size| {
+                Constraints::tight(size.width, size.height)
+            });
+    let placeable = measurable.measure(wrapped_constraints);
+    cranpose_ui_layout::LayoutModifierMeasureResult::with_size(Size {
+        width: placeable.width(),
+        height: placeable.height(),
+    })
+}
+
+/// Candidate sizes tried in order, first respecting both constraint axes and
+/// then only the axis the candidate was derived from.
+///
+/// Matches Kotlin: `AspectRatioNode.findSize` and its `tryMaxWidth`/`tryMaxHeight`/
+/// `tryMinWidth`/`tryMinHeight` helpers.
+fn find_aspect_ratio_size(
+    ratio: f32,
+    match_height_constraints_first: bool,
+    constraints: Constraints,
+) -> Option<Size> {
+    #[derive(Clone, Copy)]
+    enum Candidate {
+        MaxWidth,
+        MaxHeight,
+        MinWidth,
+        MinHeight,
+    }
+
+    let try_candidate = |candidate: Candidate, enforce_constraints: bool| -> Option<Size> {
+        let size = match candidate {
+            Candidate::MaxWidth => Size {
+                width: constraints.max_width,
+                height: constraints.max_width / ratio,
+            },
+            Candidate::MaxHeight => Size {
+                width: constraints.max_height * ratio,
+                height: constraints.max_height,
+            },
+            Candidate::MinWidth => Size {
+                width: constraints.min_width,
+                height: constraints.min_width / ratio,
+            },
+            Candidate::MinHeight => Size {
+                width: constraints.min_height * ratio,
+                height: constraints.min_height,
+            },
+        };
+        let usable = size.width.is_finite()
+            && size.height.is_finite()
+            && size.width > 0.0
+            && size.height > 0.0;
+        let satisfied = (constraints.min_width..=constraints.max_width).contains(&size.width)
+            && (constraints.min_height..=constraints.max_height).contains(&size.height);
+        (usable && (!enforce_constraints || satisfied)).then_some(size)
+    };
+
+    let order = if match_height_constraints_first {
+        [
+            Candidate::MaxHeight,
+            Candidate::MaxWidth,
+            Candidate::MinHeight,
+            Candidate::MinWidth,
+        ]
+    } else {
+        [
+            Candidate::MaxWidth,
+            Candidate::MaxHeight,
+            Candidate::MinWidth,
+            Candidate::MinHeight,
+        ]
+    };
+    [true, false].into_iter().find_map(|enforce_constraints| {
+        order
+            .into_iter()
+            .find_map(|candidate| try_candidate(candidate, enforce_constraints))
+    })
+}
+
+/// Element that creates and updates aspect ratio nodes.
+///
+/// Matches Kotlin: `AspectRatioElement` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/AspectRatio.kt
+#[derive(Debug, Clone, PartialEq)]
+pub struct AspectRatioElement {
+    ratio: f32,
+    match_height_constraints_first: bool,
+}
+
+impl AspectRatioElement {
+    /// Creates an aspect ratio element; `ratio` is width divided by height and must be positive.
+    pub fn new(ratio: f32, match_height_constraints_first: bool) -> Self {
+        assert!(
+            ratio > 0.0,
+            "aspect ratio must be greater than zero, was {ratio}"
+        );
+        Self {
+            ratio,
+            match_height_constraints_first,
+        }
+    }
+}
+
+impl Hash for AspectRatioElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32_value(state, self.ratio);
+        self.match_height_constraints_first.hash(state);
+    }
+}
+
+impl ModifierNodeElement for AspectRatioElement {
+    type Node = AspectRatioNode;
+
+    fn create(&self) -> Self::Node {
+        AspectRatioNode::new(self.ratio, self.match_height_constraints_first)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.ratio = self.ratio;
+        node.match_height_constraints_first = self.match_height_constraints_first;
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::LAYOUT
+    }
+}
+
 // ============================================================================
 // Fill Modifier Node
 // ============================================================================
@@ -2331,72 +3354,501 @@ impl ModifierNodeElement for FillElement {
 }
 
 // ============================================================================
-// Weight Modifier Node
+// Wrap Content Modifier Node
 // ============================================================================
 
-/// Node that records flex weight data for Row/Column parents.
+/// Node that lets its content measure at its desired size, ignoring incoming
+/// minimum constraints, and aligns it within the space the parent enforces.
+///
+/// Matches Kotlin: `WrapContentNode` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
 #[derive(Debug)]
-pub struct WeightNode {
-    weight: f32,
-    fill: bool,
+pub struct WrapContentNode {
+    direction: FillDirection,
+    alignment: Alignment,
     state: NodeState,
 }
 
-impl WeightNode {
-    pub fn new(weight: f32, fill: bool) -> Self {
+impl WrapContentNode {
+    pub fn new(direction: FillDirection, alignment: Alignment) -> Self {
         Self {
-            weight,
-            fill,
+            direction,
+            alignment,
             state: NodeState::new(),
         }
     }
 
-    pub fn layout_weight(&self) -> LayoutWeight {
-        LayoutWeight {
-            weight: self.weight,
-            fill: self.fill,
-        }
+    pub fn direction(&self) -> FillDirection {
+        self.direction
+    }
+
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
     }
 }
 
-impl DelegatableNode for WeightNode {
+impl DelegatableNode for WrapContentNode {
     fn node_state(&self) -> &NodeState {
         &self.state
     }
 }
 
-impl ModifierNode for WeightNode {
+impl ModifierNode for WrapContentNode {
     fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
         context.invalidate(cranpose_foundation::InvalidationKind::Layout);
     }
-}
-
-/// Element that creates and updates weight nodes.
-#[derive(Debug, Clone, PartialEq)]
-pub struct WeightElement {
-    weight: f32,
-    fill: bool,
-}
 
-impl WeightElement {
-    pub fn new(weight: f32, fill: bool) -> Self {
-        Self { weight, fill }
+    fn as_layout_node(&self) -> Option<&dyn LayoutModifierNode> {
+        Some(self)
     }
-}
 
-impl Hash for WeightElement {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        hash_f32_value(state, self.weight);
-        self.fill.hash(state);
+    fn as_layout_node_mut(&mut self) -> Option<&mut dyn LayoutModifierNode> {
+        Some(self)
     }
 }
 
-impl ModifierNodeElement for WeightElement {
-    type Node = WeightNode;
+/// Measures `measurable` with the minimum constraint dropped on the wrapped
+/// axes, then sizes the wrapper to the content clamped into the incoming
+/// constraints and aligns the content inside it.
+fn measure_wrap_content(
+    direction: FillDirection,
+    alignment: Alignment,
+    measurable: &dyn Measurable,
+    constraints: Constraints,
+) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+    let wrap_width = direction != FillDirection::Vertical;
+    let wrap_height = direction != FillDirection::Horizontal;
+    let wrapped_constraints = Constraints {
+        min_width: if wrap_width {
+            0.0
+        } else {
+            constraints.min_width
+        },
+        max_width: constraints.max_width,
+        min_height: if wrap_height {
+            0.0
+        } else {
+            constraints.min_height
+        },
+        max_height: constraints.max_height,
+    };
 
-    fn create(&self) -> Self::Node {
-        WeightNode::new(self.weight, self.fill)
-    }
+    let placeable = measurable.measure(wrapped_constraints);
+    let width = placeable
+        .width()
+        .clamp(constraints.min_width, constraints.max_width);
+    let height = placeable
+        .height()
+        .clamp(constraints.min_height, constraints.max_height);
+
+    let offset_x = alignment.horizontal.align(width, placeable.width());
+    let offset_y = alignment.vertical.align(height, placeable.height());
+
+    cranpose_ui_layout::LayoutModifierMeasureResult::new(Size { width, height }, offset_x, offset_y)
+}
+
+impl LayoutModifierNode for WrapContentNode {
+    fn measure(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        measurable: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        measure_wrap_content(self.direction, self.alignment, measurable, constraints)
+    }
+
+    fn min_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        measurable.min_intrinsic_width(height)
+    }
+
+    fn max_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        measurable.max_intrinsic_width(height)
+    }
+
+    fn min_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        measurable.min_intrinsic_height(width)
+    }
+
+    fn max_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        measurable.max_intrinsic_height(width)
+    }
+
+    fn create_measurement_proxy(&self) -> Option<Box<dyn MeasurementProxy>> {
+        Some(Box::new(WrapContentMeasurementProxy {
+            direction: self.direction,
+            alignment: self.alignment,
+        }))
+    }
+}
+
+/// Measurement proxy for WrapContentNode that snapshots live state.
+struct WrapContentMeasurementProxy {
+    direction: FillDirection,
+    alignment: Alignment,
+}
+
+impl MeasurementProxy for WrapContentMeasurementProxy {
+    fn measure_proxy(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        wrapped: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        measure_wrap_content(self.direction, self.alignment, wrapped, constraints)
+    }
+
+    fn min_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
+        wrapped.min_intrinsic_width(height)
+    }
+
+    fn max_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
+        wrapped.max_intrinsic_width(height)
+    }
+
+    fn min_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
+        wrapped.min_intrinsic_height(width)
+    }
+
+    fn max_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
+        wrapped.max_intrinsic_height(width)
+    }
+}
+
+/// Element that creates and updates wrap content nodes.
+///
+/// Matches Kotlin: `WrapContentElement` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrapContentElement {
+    direction: FillDirection,
+    alignment: Alignment,
+}
+
+impl WrapContentElement {
+    pub fn width(alignment: HorizontalAlignment) -> Self {
+        Self {
+            direction: FillDirection::Horizontal,
+            alignment: Alignment::new(alignment, VerticalAlignment::CenterVertically),
+        }
+    }
+
+    pub fn height(alignment: VerticalAlignment) -> Self {
+        Self {
+            direction: FillDirection::Vertical,
+            alignment: Alignment::new(HorizontalAlignment::CenterHorizontally, alignment),
+        }
+    }
+
+    pub fn size(alignment: Alignment) -> Self {
+        Self {
+            direction: FillDirection::Both,
+            alignment,
+        }
+    }
+}
+
+impl Hash for WrapContentElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.direction.hash(state);
+        hash_alignment(state, self.alignment);
+    }
+}
+
+impl ModifierNodeElement for WrapContentElement {
+    type Node = WrapContentNode;
+
+    fn create(&self) -> Self::Node {
+        WrapContentNode::new(self.direction, self.alignment)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        if node.direction != self.direction || node.alignment != self.alignment {
+            node.direction = self.direction;
+            node.alignment = self.alignment;
+        }
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::LAYOUT
+    }
+}
+
+// ============================================================================
+// Default Min Size Modifier Node
+// ============================================================================
+
+/// Node that gives its content a minimum size when the incoming constraints
+/// leave the minimum unspecified (zero).
+///
+/// A minimum set by the parent, such as an explicit size, is kept as is.
+///
+/// Matches Kotlin: `UnspecifiedConstraintsNode` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
+#[derive(Debug)]
+pub struct DefaultMinSizeNode {
+    min_width: Option<f32>,
+    min_height: Option<f32>,
+    state: NodeState,
+}
+
+impl DefaultMinSizeNode {
+    pub fn new(min_width: Option<f32>, min_height: Option<f32>) -> Self {
+        Self {
+            min_width,
+            min_height,
+            state: NodeState::new(),
+        }
+    }
+
+    pub fn min_width(&self) -> Option<f32> {
+        self.min_width
+    }
+
+    pub fn min_height(&self) -> Option<f32> {
+        self.min_height
+    }
+}
+
+impl DelegatableNode for DefaultMinSizeNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for DefaultMinSizeNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::Layout);
+    }
+
+    fn as_layout_node(&self) -> Option<&dyn LayoutModifierNode> {
+        Some(self)
+    }
+
+    fn as_layout_node_mut(&mut self) -> Option<&mut dyn LayoutModifierNode> {
+        Some(self)
+    }
+}
+
+/// Replaces a zero incoming minimum with `default`, capped at the incoming maximum.
+fn default_min_bound(default: Option<f32>, min: f32, max: f32) -> f32 {
+    match default {
+        Some(default) if min == 0.0 => default.max(0.0).min(max),
+        _ => min,
+    }
+}
+
+fn measure_default_min_size(
+    min_width: Option<f32>,
+    min_height: Option<f32>,
+    measurable: &dyn Measurable,
+    constraints: Constraints,
+) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+    let wrapped_constraints = Constraints {
+        min_width: default_min_bound(min_width, constraints.min_width, constraints.max_width),
+        max_width: constraints.max_width,
+        min_height: default_min_bound(min_height, constraints.min_height, constraints.max_height),
+        max_height: constraints.max_height,
+    };
+    let placeable = measurable.measure(wrapped_constraints);
+    let width = placeable
+        .width()
+        .clamp(wrapped_constraints.min_width, wrapped_constraints.max_width);
+    let height = placeable.height().clamp(
+        wrapped_constraints.min_height,
+        wrapped_constraints.max_height,
+    );
+    cranpose_ui_layout::LayoutModifierMeasureResult::with_size(Size { width, height })
+}
+
+impl LayoutModifierNode for DefaultMinSizeNode {
+    fn measure(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        measurable: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        measure_default_min_size(self.min_width, self.min_height, measurable, constraints)
+    }
+
+    fn min_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        measurable
+            .min_intrinsic_width(height)
+            .max(self.min_width.unwrap_or(0.0))
+    }
+
+    fn max_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        measurable
+            .max_intrinsic_width(height)
+            .max(self.min_width.unwrap_or(0.0))
+    }
+
+    fn min_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        measurable
+            .min_intrinsic_height(width)
+            .max(self.min_height.unwrap_or(0.0))
+    }
+
+    fn max_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        measurable
+            .max_intrinsic_height(width)
+            .max(self.min_height.unwrap_or(0.0))
+    }
+
+    fn create_measurement_proxy(&self) -> Option<Box<dyn MeasurementProxy>> {
+        Some(Box::new(DefaultMinSizeMeasurementProxy {
+            min_width: self.min_width,
+            min_height: self.min_height,
+        }))
+    }
+}
+
+/// Measurement proxy for DefaultMinSizeNode that snapshots live state.
+struct DefaultMinSizeMeasurementProxy {
+    min_width: Option<f32>,
+    min_height: Option<f32>,
+}
+
+impl MeasurementProxy for DefaultMinSizeMeasurementProxy {
+    fn measure_proxy(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        wrapped: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        measure_default_min_size(self.min_width, self.min_height, wrapped, constraints)
+    }
+
+    fn min_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
+        wrapped
+            .min_intrinsic_width(height)
+            .max(self.min_width.unwrap_or(0.0))
+    }
+
+    fn max_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
+        wrapped
+            .max_intrinsic_width(height)
+            .max(self.min_width.unwrap_or(0.0))
+    }
+
+    fn min_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
+        wrapped
+            .min_intrinsic_height(width)
+            .max(self.min_height.unwrap_or(0.0))
+    }
+
+    fn max_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
+        wrapped
+            .max_intrinsic_height(width)
+            .max(self.min_height.unwrap_or(0.0))
+    }
+}
+
+/// Element that creates and updates default min size nodes.
+///
+/// Matches Kotlin: `UnspecifiedConstraintsElement` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Size.kt
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefaultMinSizeElement {
+    min_width: Option<f32>,
+    min_height: Option<f32>,
+}
+
+impl DefaultMinSizeElement {
+    pub fn new(min_width: Option<f32>, min_height: Option<f32>) -> Self {
+        Self {
+            min_width,
+            min_height,
+        }
+    }
+}
+
+impl Hash for DefaultMinSizeElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_option_f32(state, self.min_width);
+        hash_option_f32(state, self.min_height);
+    }
+}
+
+impl ModifierNodeElement for DefaultMinSizeElement {
+    type Node = DefaultMinSizeNode;
+
+    fn create(&self) -> Self::Node {
+        DefaultMinSizeNode::new(self.min_width, self.min_height)
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.min_width = self.min_width;
+        node.min_height = self.min_height;
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::LAYOUT
+    }
+}
+
+// ============================================================================
+// Weight Modifier Node
+// ============================================================================
+
+/// Node that records flex weight data for Row/Column parents.
+#[derive(Debug)]
+pub struct WeightNode {
+    weight: f32,
+    fill: bool,
+    state: NodeState,
+}
+
+impl WeightNode {
+    pub fn new(weight: f32, fill: bool) -> Self {
+        Self {
+            weight,
+            fill,
+            state: NodeState::new(),
+        }
+    }
+
+    pub fn layout_weight(&self) -> LayoutWeight {
+        LayoutWeight {
+            weight: self.weight,
+            fill: self.fill,
+        }
+    }
+}
+
+impl DelegatableNode for WeightNode {
+    fn node_state(&self) -> &NodeState {
+        &self.state
+    }
+}
+
+impl ModifierNode for WeightNode {
+    fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
+        context.invalidate(cranpose_foundation::InvalidationKind::Layout);
+    }
+}
+
+/// Element that creates and updates weight nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightElement {
+    weight: f32,
+    fill: bool,
+}
+
+impl WeightElement {
+    pub fn new(weight: f32, fill: bool) -> Self {
+        Self { weight, fill }
+    }
+}
+
+impl Hash for WeightElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f32_value(state, self.weight);
+        self.fill.hash(state);
+    }
+}
+
+impl ModifierNodeElement for WeightElement {
+    type Node = WeightNode;
+
+    fn create(&self) -> Self::Node {
+        WeightNode::new(self.weight, self.fill)
+    }
 
     fn update(&self, node: &mut Self::Node) {
         if node.weight != self.weight || node.fill != self.fill {
@@ -2418,21 +3870,27 @@ impl ModifierNodeElement for WeightElement {
 #[derive(Debug)]
 pub struct AlignmentNode {
     box_alignment: Option<Alignment>,
+    match_parent_size: bool,
     column_alignment: Option<HorizontalAlignment>,
     row_alignment: Option<VerticalAlignment>,
+    align_by: Option<AlignmentLine>,
     state: NodeState,
 }
 
 impl AlignmentNode {
     pub fn new(
         box_alignment: Option<Alignment>,
+        match_parent_size: bool,
         column_alignment: Option<HorizontalAlignment>,
         row_alignment: Option<VerticalAlignment>,
+        align_by: Option<AlignmentLine>,
     ) -> Self {
         Self {
             box_alignment,
+            match_parent_size,
             column_alignment,
             row_alignment,
+            align_by,
             state: NodeState::new(),
         }
     }
@@ -2441,6 +3899,10 @@ impl AlignmentNode {
         self.box_alignment
     }
 
+    pub fn match_parent_size(&self) -> bool {
+        self.match_parent_size
+    }
+
     pub fn column_alignment(&self) -> Option<HorizontalAlignment> {
         self.column_alignment
     }
@@ -2448,6 +3910,10 @@ impl AlignmentNode {
     pub fn row_alignment(&self) -> Option<VerticalAlignment> {
         self.row_alignment
     }
+
+    pub fn align_by(&self) -> Option<AlignmentLine> {
+        self.align_by
+    }
 }
 
 impl DelegatableNode for AlignmentNode {
@@ -2466,32 +3932,62 @@ impl ModifierNode for AlignmentNode {
 #[derive(Debug, Clone, PartialEq)]
 pub struct AlignmentElement {
     box_alignment: Option<Alignment>,
+    match_parent_size: bool,
     column_alignment: Option<HorizontalAlignment>,
     row_alignment: Option<VerticalAlignment>,
+    align_by: Option<AlignmentLine>,
 }
 
 impl AlignmentElement {
     pub fn box_alignment(alignment: Alignment) -> Self {
         Self {
             box_alignment: Some(alignment),
+            match_parent_size: false,
             column_alignment: None,
             row_alignment: None,
+            align_by: None,
+        }
+    }
+
+    /// Box child data that sizes the child to the Box, centered like Kotlin's
+    /// `matchParentSize`.
+    pub fn match_parent_size() -> Self {
+        Self {
+            box_alignment: Some(Alignment::CENTER),
+            match_parent_size: true,
+            column_alignment: None,
+            row_alignment: None,
+            align_by: None,
         }
     }
 
     pub fn column_alignment(alignment: HorizontalAlignment) -> Self {
         Self {
             box_alignment: None,
+            match_parent_size: false,
             column_alignment: Some(alignment),
             row_alignment: None,
+            align_by: None,
         }
     }
 
     pub fn row_alignment(alignment: VerticalAlignment) -> Self {
         Self {
             box_alignment: None,
+            match_parent_size: false,
             column_alignment: None,
             row_alignment: Some(alignment),
+            align_by: None,
+        }
+    }
+
+    pub fn align_by(line: AlignmentLine) -> Self {
+        Self {
+            box_alignment: None,
+            match_parent_size: false,
+            column_alignment: None,
+            row_alignment: None,
+            align_by: Some(line),
         }
     }
 }
@@ -2504,6 +4000,7 @@ impl Hash for AlignmentElement {
         } else {
             state.write_u8(0);
         }
+        self.match_parent_size.hash(state);
         if let Some(alignment) = self.column_alignment {
             state.write_u8(1);
             hash_horizontal_alignment(state, alignment);
@@ -2516,6 +4013,7 @@ impl Hash for AlignmentElement {
         } else {
             state.write_u8(0);
         }
+        self.align_by.hash(state);
     }
 }
 
@@ -2525,8 +4023,10 @@ impl ModifierNodeElement for AlignmentElement {
     fn create(&self) -> Self::Node {
         AlignmentNode::new(
             self.box_alignment,
+            self.match_parent_size,
             self.column_alignment,
             self.row_alignment,
+            self.align_by,
         )
     }
 
@@ -2534,12 +4034,18 @@ impl ModifierNodeElement for AlignmentElement {
         if node.box_alignment != self.box_alignment {
             node.box_alignment = self.box_alignment;
         }
+        if node.match_parent_size != self.match_parent_size {
+            node.match_parent_size = self.match_parent_size;
+        }
         if node.column_alignment != self.column_alignment {
             node.column_alignment = self.column_alignment;
         }
         if node.row_alignment != self.row_alignment {
             node.row_alignment = self.row_alignment;
         }
+        if node.align_by != self.align_by {
+            node.align_by = self.align_by;
+        }
     }
 
     fn capabilities(&self) -> NodeCapabilities {
@@ -2557,7 +4063,9 @@ pub enum IntrinsicAxis {
     Height,
 }
 
-/// Node that records intrinsic sizing requests.
+/// Node that sizes its content to the content's min or max intrinsic size on one axis.
+///
+/// Matches Kotlin: `IntrinsicWidthNode`/`IntrinsicHeightNode` in foundation-layout/src/commonMain/kotlin/androidx/compose/foundation/layout/Intrinsic.kt
 #[derive(Debug)]
 pub struct IntrinsicSizeNode {
     axis: IntrinsicAxis,
@@ -2593,6 +4101,158 @@ impl ModifierNode for IntrinsicSizeNode {
     fn on_attach(&mut self, context: &mut dyn ModifierNodeContext) {
         context.invalidate(cranpose_foundation::InvalidationKind::Layout);
     }
+
+    fn as_layout_node(&self) -> Option<&dyn LayoutModifierNode> {
+        Some(self)
+    }
+
+    fn as_layout_node_mut(&mut self) -> Option<&mut dyn LayoutModifierNode> {
+        Some(self)
+    }
+}
+
+impl LayoutModifierNode for IntrinsicSizeNode {
+    fn measure(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        measurable: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        measure_intrinsic_size(self.axis, self.size, measurable, constraints)
+    }
+
+    fn min_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        intrinsic_width(self.axis, self.size, measurable, height, false)
+    }
+
+    fn max_intrinsic_width(&self, measurable: &dyn Measurable, height: f32) -> f32 {
+        intrinsic_width(self.axis, self.size, measurable, height, true)
+    }
+
+    fn min_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        intrinsic_height(self.axis, self.size, measurable, width, false)
+    }
+
+    fn max_intrinsic_height(&self, measurable: &dyn Measurable, width: f32) -> f32 {
+        intrinsic_height(self.axis, self.size, measurable, width, true)
+    }
+
+    fn create_measurement_proxy(&self) -> Option<Box<dyn MeasurementProxy>> {
+        Some(Box::new(IntrinsicSizeMeasurementProxy {
+            axis: self.axis,
+            size: self.size,
+        }))
+    }
+}
+
+/// Measurement proxy for IntrinsicSizeNode that snapshots live state.
+struct IntrinsicSizeMeasurementProxy {
+    axis: IntrinsicAxis,
+    size: IntrinsicSize,
+}
+
+impl MeasurementProxy for IntrinsicSizeMeasurementProxy {
+    fn measure_proxy(
+        &self,
+        _context: &mut dyn ModifierNodeContext,
+        wrapped: &dyn Measurable,
+        constraints: Constraints,
+    ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+        measure_intrinsic_size(self.axis, self.size, wrapped, constraints)
+    }
+
+    fn min_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
+        intrinsic_width(self.axis, self.size, wrapped, height, false)
+    }
+
+    fn max_intrinsic_width_proxy(&self, wrapped: &dyn Measurable, height: f32) -> f32 {
+        intrinsic_width(self.axis, self.size, wrapped, height, true)
+    }
+
+    fn min_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
+        intrinsic_height(self.axis, self.size, wrapped, width, false)
+    }
+
+    fn max_intrinsic_height_proxy(&self, wrapped: &dyn Measurable, width: f32) -> f32 {
+        intrinsic_height(self.axis, self.size, wrapped, width, true)
+    }
+}
+
+/// Fixes the requested axis to the content's intrinsic size (coerced into the
+/// incoming constraints) and measures the content with it.
+fn measure_intrinsic_size(
+    axis: IntrinsicAxis,
+    size: IntrinsicSize,
+    measurable: &dyn Measurable,
+    constraints: Constraints,
+) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+    let mut child_constraints = constraints;
+    match axis {
+        IntrinsicAxis::Width => {
+            let width = match size {
+                IntrinsicSize::Min => measurable.min_intrinsic_width(constraints.max_height),
+                IntrinsicSize::Max => measurable.max_intrinsic_width(constraints.max_height),
+            }
+            .clamp(constraints.min_width, constraints.max_width);
+            child_constraints.min_width = width;
+            child_constraints.max_width = width;
+        }
+        IntrinsicAxis::Height => {
+            let height = match size {
+                IntrinsicSize::Min => measurable.min_intrinsic_height(constraints.max_width),
+                IntrinsicSize::Max => measurable.max_intrinsic_height(constraints.max_width),
+            }
+            .clamp(constraints.min_height, constraints.max_height);
+            child_constraints.min_height = height;
+            child_constraints.max_height = height;
+        }
+    }
+    let placeable = measurable.measure(child_constraints);
+    cranpose_ui_layout::LayoutModifierMeasureResult::with_size(Size {
+        width: placeable.width(),
+        height: placeable.height(),
+    })
+}
+
+/// Intrinsic width through an intrinsic size node: the sized axis always reports
+/// the requested intrinsic, the other axis passes through.
+fn intrinsic_width(
+    axis: IntrinsicAxis,
+    size: IntrinsicSize,
+    measurable: &dyn Measurable,
+    height: f32,
+    max: bool,
+) -> f32 {
+    let use_max = if axis == IntrinsicAxis::Width {
+        size == IntrinsicSize::Max
+    } else {
+        max
+    };
+    if use_max {
+        measurable.max_intrinsic_width(height)
+    } else {
+        measurable.min_intrinsic_width(height)
+    }
+}
+
+/// Height counterpart of [`intrinsic_width`].
+fn intrinsic_height(
+    axis: IntrinsicAxis,
+    size: IntrinsicSize,
+    measurable: &dyn Measurable,
+    width: f32,
+    max: bool,
+) -> f32 {
+    let use_max = if axis == IntrinsicAxis::Height {
+        size == IntrinsicSize::Max
+    } else {
+        max
+    };
+    if use_max {
+        measurable.max_intrinsic_height(width)
+    } else {
+        measurable.min_intrinsic_height(width)
+    }
 }
 
 /// Element that creates and updates intrinsic size nodes.
@@ -132,15 +132,21 @@ static POINTER_INVALIDATED: AtomicBool = AtomicBool::new(false);
 static FOCUS_INVALIDATED: AtomicBool = AtomicBool::new(false);
 static DENSITY_BITS: AtomicU32 = AtomicU32::new(f32::to_bits(1.0));
 
-/// Returns the current density scale factor (logical px per dp).
+/// Returns the platform density: physical pixels per logical pixel.
+///
+/// Layout, hit testing and pointer events all use logical pixels, one per
+/// `Dp`. Only renderers apply this factor, through their root scale, when
+/// drawing into the physical-pixel surface.
 pub fn current_density() -> f32 {
     f32::from_bits(DENSITY_BITS.load(Ordering::Relaxed))
 }
 
 /// Updates the current density scale factor.
 ///
-/// This triggers a global layout invalidation when the value changes because
-/// density impacts layout, text measurement, and input thresholds.
+/// Platforms call this together with the renderer's root scale and pass
+/// pointer positions already divided by the same factor. This triggers a
+/// global layout invalidation when the value changes because densities
+/// provided through [`crate::local_density`] are resolved relative to it.
 pub fn set_density(density: f32) {
     let normalized = if density.is_finite() && density > 0.0 {
         density
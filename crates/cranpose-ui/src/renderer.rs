@@ -1,5 +1,6 @@
-use crate::layout::{LayoutBox, LayoutNodeData, LayoutTree};
-use crate::modifier::{DrawCommand as ModifierDrawCommand, Point, Rect, Size};
+use crate::layout::{LayoutBox, LayoutTree};
+use crate::modifier::{DrawCommand as ModifierDrawCommand, ModifierNodeSlices, Point, Rect, Size};
+use crate::subcompose_layout::SubcomposeLayoutNode;
 use crate::widgets::LayoutNode;
 use cranpose_core::{MemoryApplier, NodeId};
 use cranpose_ui_graphics::DrawPrimitive;
@@ -12,6 +13,14 @@ pub enum PaintLayer {
     Overlay,
 }
 
+/// Orders sibling nodes for drawing by their `z_index` modifier value.
+///
+/// Lower z draws first so higher z ends up on top. The sort is stable, so
+/// siblings sharing a z index keep their composition order.
+pub fn sort_by_z_index<T>(siblings: &mut [(T, f32)]) {
+    siblings.sort_by(|a, b| a.1.total_cmp(&b.1));
+}
+
 /// A rendered operation emitted by the headless renderer stub.
 #[derive(Clone, Debug, PartialEq)]
 pub enum RenderOp {
@@ -72,14 +81,20 @@ impl HeadlessRenderer {
 
     pub fn render(&self, tree: &LayoutTree) -> RecordedRenderScene {
         let mut operations = Vec::new();
-        self.render_box(tree.root(), &mut operations);
+        self.render_box(tree.root(), 1.0, &mut operations);
         RecordedRenderScene::new(operations)
     }
 
     #[allow(clippy::only_used_in_recursion)]
-    fn render_box(&self, layout: &LayoutBox, operations: &mut Vec<RenderOp>) {
+    fn render_box(&self, layout: &LayoutBox, parent_alpha: f32, operations: &mut Vec<RenderOp>) {
         let rect = layout.rect;
-        let (mut behind, mut overlay) = evaluate_modifier(layout.node_id, &layout.node_data, rect);
+        let alpha = parent_alpha * layer_alpha(layout.node_data.modifier_slices());
+        let (mut behind, mut overlay) = evaluate_draw_commands(
+            layout.node_id,
+            layout.node_data.modifier_slices(),
+            rect,
+            alpha,
+        );
 
         operations.append(&mut behind);
 
@@ -94,19 +109,32 @@ impl HeadlessRenderer {
             });
         }
 
-        // Render children
-        for child in &layout.children {
-            self.render_box(child, operations);
+        // Render children in z order
+        let mut children: Vec<(&LayoutBox, f32)> = layout
+            .children
+            .iter()
+            .map(|child| (child, child.node_data.modifier_slices().z_index()))
+            .collect();
+        sort_by_z_index(&mut children);
+        for (child, _) in children {
+            self.render_box(child, alpha, operations);
         }
 
         operations.append(&mut overlay);
     }
 }
 
-fn evaluate_modifier(
+/// Opacity of the node's graphics layer, including any `alpha` modifiers.
+fn layer_alpha(slices: &ModifierNodeSlices) -> f32 {
+    slices.graphics_layer().map_or(1.0, |layer| layer.alpha)
+}
+
+/// Runs the node's draw commands and places the primitives at `rect` with `alpha` applied.
+fn evaluate_draw_commands(
     node_id: NodeId,
-    data: &LayoutNodeData,
+    slices: &ModifierNodeSlices,
     rect: Rect,
+    alpha: f32,
 ) -> (Vec<RenderOp>, Vec<RenderOp>) {
     let mut behind = Vec::new();
     let mut overlay = Vec::new();
@@ -118,32 +146,54 @@ fn evaluate_modifier(
 
     // Render via modifier slices - all drawing now goes through draw commands
     // collected from the modifier node chain, including backgrounds, borders, etc.
-    for command in data.modifier_slices().draw_commands() {
-        match command {
-            ModifierDrawCommand::Behind(func) => {
-                for primitive in func(size) {
-                    behind.push(RenderOp::Primitive {
-                        node_id,
-                        layer: PaintLayer::Behind,
-                        primitive: translate_primitive(primitive, rect.x, rect.y),
-                    });
-                }
-            }
-            ModifierDrawCommand::Overlay(func) => {
-                for primitive in func(size) {
-                    overlay.push(RenderOp::Primitive {
-                        node_id,
-                        layer: PaintLayer::Overlay,
-                        primitive: translate_primitive(primitive, rect.x, rect.y),
-                    });
-                }
+    for command in slices.draw_commands() {
+        let (func, layer, target) = match command {
+            ModifierDrawCommand::Behind(func) => (func, PaintLayer::Behind, &mut behind),
+            // Recorded operations carry no clips
+            ModifierDrawCommand::Overlay(func) | ModifierDrawCommand::OverlayClipped(func, _) => {
+                (func, PaintLayer::Overlay, &mut overlay)
             }
+        };
+        for primitive in func(size) {
+            let primitive = translate_primitive(primitive, rect.x, rect.y);
+            target.push(RenderOp::Primitive {
+                node_id,
+                layer,
+                primitive: fade_primitive(primitive, alpha),
+            });
         }
     }
 
     (behind, overlay)
 }
 
+fn fade_primitive(primitive: DrawPrimitive, alpha: f32) -> DrawPrimitive {
+    if alpha >= 1.0 {
+        return primitive;
+    }
+    match primitive {
+        DrawPrimitive::Rect { rect, brush } => DrawPrimitive::Rect {
+            rect,
+            brush: brush.multiply_alpha(alpha),
+        },
+        DrawPrimitive::RoundRect { rect, brush, radii } => DrawPrimitive::RoundRect {
+            rect,
+            brush: brush.multiply_alpha(alpha),
+            radii,
+        },
+        DrawPrimitive::Arc { rect, brush, arc } => DrawPrimitive::Arc {
+            rect,
+            brush: brush.multiply_alpha(alpha),
+            arc,
+        },
+        DrawPrimitive::Line { rect, brush, line } => DrawPrimitive::Line {
+            rect,
+            brush: brush.multiply_alpha(alpha),
+            line,
+        },
+    }
+}
+
 fn translate_primitive(primitive: DrawPrimitive, dx: f32, dy: f32) -> DrawPrimitive {
     match primitive {
         DrawPrimitive::Rect { rect, brush } => DrawPrimitive::Rect {
@@ -155,6 +205,16 @@ fn translate_primitive(primitive: DrawPrimitive, dx: f32, dy: f32) -> DrawPrimit
             brush,
             radii,
         },
+        DrawPrimitive::Arc { rect, brush, arc } => DrawPrimitive::Arc {
+            rect: rect.translate(dx, dy),
+            brush,
+            arc,
+        },
+        DrawPrimitive::Line { rect, brush, line } => DrawPrimitive::Line {
+            rect: rect.translate(dx, dy),
+            brush,
+            line,
+        },
     }
 }
 
@@ -171,7 +231,7 @@ impl HeadlessRenderer {
         root: NodeId,
     ) -> RecordedRenderScene {
         let mut operations = Vec::new();
-        self.render_node_from_applier(applier, root, Point::default(), &mut operations);
+        self.render_node_from_applier(applier, root, Point::default(), 1.0, &mut operations);
         RecordedRenderScene::new(operations)
     }
 
@@ -181,6 +241,7 @@ impl HeadlessRenderer {
         applier: &mut MemoryApplier,
         node_id: NodeId,
         parent_offset: Point,
+        parent_alpha: f32,
         operations: &mut Vec<RenderOp>,
     ) {
         // Read layout state and node data from LayoutNode
@@ -212,37 +273,10 @@ impl HeadlessRenderer {
             height: layout_state.size.height,
         };
 
-        let size = Size {
-            width: rect.width,
-            height: rect.height,
-        };
-
         // Collect draw commands from modifier slices
-        let mut behind = Vec::new();
-        let mut overlay = Vec::new();
-
-        for command in modifier_slices.draw_commands() {
-            match command {
-                ModifierDrawCommand::Behind(func) => {
-                    for primitive in func(size) {
-                        behind.push(RenderOp::Primitive {
-                            node_id,
-                            layer: PaintLayer::Behind,
-                            primitive: translate_primitive(primitive, rect.x, rect.y),
-                        });
-                    }
-                }
-                ModifierDrawCommand::Overlay(func) => {
-                    for primitive in func(size) {
-                        overlay.push(RenderOp::Primitive {
-                            node_id,
-                            layer: PaintLayer::Overlay,
-                            primitive: translate_primitive(primitive, rect.x, rect.y),
-                        });
-                    }
-                }
-            }
-        }
+        let alpha = parent_alpha * layer_alpha(&modifier_slices);
+        let (mut behind, mut overlay) =
+            evaluate_draw_commands(node_id, &modifier_slices, rect, alpha);
 
         operations.append(&mut behind);
 
@@ -261,9 +295,26 @@ impl HeadlessRenderer {
             y: abs_y + layout_state.content_offset.y,
         };
 
-        // Render children
-        for child_id in children {
-            self.render_node_from_applier(applier, child_id, child_offset, operations);
+        // Render children in z order
+        let mut children: Vec<(NodeId, f32)> = children
+            .into_iter()
+            .map(|child_id| {
+                let z_index = applier
+                    .with_node::<LayoutNode, _>(child_id, |node| {
+                        node.modifier_slices_snapshot().z_index()
+                    })
+                    .or_else(|_| {
+                        applier.with_node::<SubcomposeLayoutNode, _>(child_id, |node| {
+                            node.modifier_slices_snapshot().z_index()
+                        })
+                    })
+                    .unwrap_or(0.0);
+                (child_id, z_index)
+            })
+            .collect();
+        sort_by_z_index(&mut children);
+        for (child_id, _) in children {
+            self.render_node_from_applier(applier, child_id, child_offset, alpha, operations);
         }
 
         operations.append(&mut overlay);
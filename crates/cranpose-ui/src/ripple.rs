@@ -0,0 +1,402 @@
+//! Ripple indication for clickable elements.
+//!
+//! This follows Material's `ripple()` indication:
+//! - A press spawns a circle that grows from the press position while fading in
+//! - Bounded ripples drift towards the center and are clipped to the element's shape
+//! - Releasing (or cancelling) the press fades the ripple out once it has expanded
+//!
+//! Ripple radius and alpha are `Animatable`s driven by tween specs. A repaint
+//! callback runs on the frame clock while ripples are visible, so each
+//! animation frame only invalidates the render pass.
+
+use crate::render_state::request_render_invalidation;
+use cranpose_animation::{Animatable, AnimationSpec, AnimationType, Easing};
+use cranpose_core::{FrameCallbackRegistration, RuntimeHandle};
+use cranpose_ui_graphics::{Brush, Color, CornerRadii, DrawPrimitive, Point, Rect, Size};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+/// Duration of the radius expansion (Material: 225ms).
+const RIPPLE_EXPAND_MILLIS: u64 = 225;
+/// Duration of the initial fade in (Material: 75ms).
+const RIPPLE_FADE_IN_MILLIS: u64 = 75;
+/// Duration of the fade out after release (Material: 150ms).
+const RIPPLE_FADE_OUT_MILLIS: u64 = 150;
+/// Starting radius as a fraction of the largest bounds dimension.
+const RIPPLE_START_RADIUS_FRACTION: f32 = 0.3;
+
+/// Default ripple color: translucent white, matching the default dark content.
+pub const DEFAULT_RIPPLE_COLOR: Color = Color(1.0, 1.0, 1.0, 0.24);
+
+/// Configuration for the ripple indication shown by `Modifier::clickable`.
+///
+/// Matches Kotlin: `ripple(bounded: Boolean, radius: Dp, color: Color)`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ripple {
+    /// Whether the ripple is clipped to the element bounds.
+    pub bounded: bool,
+    /// Final ripple radius; `None` reaches the farthest corner of the bounds.
+    pub radius: Option<f32>,
+    /// Ripple color; its alpha is the peak alpha of the ripple.
+    pub color: Color,
+}
+
+impl Ripple {
+    /// A ripple clipped to the element bounds that starts at the press position.
+    pub const fn bounded() -> Self {
+        Self {
+            bounded: true,
+            radius: None,
+            color: DEFAULT_RIPPLE_COLOR,
+        }
+    }
+
+    /// A ripple centered on the element that may draw outside its bounds.
+    pub const fn unbounded() -> Self {
+        Self {
+            bounded: false,
+            radius: None,
+            color: DEFAULT_RIPPLE_COLOR,
+        }
+    }
+
+    pub const fn with_color(self, color: Color) -> Self {
+        Self { color, ..self }
+    }
+
+    pub const fn with_radius(self, radius: f32) -> Self {
+        Self {
+            radius: Some(radius),
+            ..self
+        }
+    }
+}
+
+impl Default for Ripple {
+    fn default() -> Self {
+        Self::bounded()
+    }
+}
+
+/// A single press ripple and its animated values.
+struct RippleAnimation {
+    origin: Point,
+    /// Expansion from the start radius (0) to the end radius (1).
+    radius_progress: Animatable<f32>,
+    alpha: Animatable<f32>,
+    released: bool,
+    fading_out: bool,
+}
+
+impl RippleAnimation {
+    fn new(origin: Point, runtime: RuntimeHandle) -> Self {
+        let mut radius_progress = Animatable::new(0.0, runtime.clone());
+        radius_progress.animateTo(
+            1.0,
+            AnimationType::Tween(AnimationSpec::tween(
+                RIPPLE_EXPAND_MILLIS,
+                Easing::FastOutSlowInEasing,
+            )),
+        );
+        let mut alpha = Animatable::new(0.0, runtime);
+        alpha.animateTo(
+            1.0,
+            AnimationType::Tween(AnimationSpec::linear(RIPPLE_FADE_IN_MILLIS)),
+        );
+        Self {
+            origin,
+            radius_progress,
+            alpha,
+            released: false,
+            fading_out: false,
+        }
+    }
+
+    /// Starts the fade out once released and expanded, and returns false once
+    /// it has fully faded out.
+    fn update(&mut self) -> bool {
+        // The fade out waits for the expansion so quick taps still show a full ripple.
+        if self.released && !self.fading_out && !self.radius_progress.is_running() {
+            self.fading_out = true;
+            self.alpha.animateTo(
+                0.0,
+                AnimationType::Tween(AnimationSpec::linear(RIPPLE_FADE_OUT_MILLIS)),
+            );
+        }
+        !self.fading_out || self.alpha.is_running()
+    }
+}
+
+fn lerp(start: f32, end: f32, t: f32) -> f32 {
+    start + (end - start) * t
+}
+
+struct RippleStateInner {
+    config: Ripple,
+    ripples: Vec<RippleAnimation>,
+    runtime: Option<RuntimeHandle>,
+    /// Repaint callback for the next frame while ripples are visible.
+    registration: Option<FrameCallbackRegistration>,
+}
+
+/// Shared ripple state owned by a clickable node.
+///
+/// Cloning is cheap; the pointer handler and the draw closure share the same
+/// ripples through the inner `Rc`.
+#[derive(Clone)]
+pub(crate) struct RippleState {
+    inner: Rc<RefCell<RippleStateInner>>,
+}
+
+impl RippleState {
+    pub(crate) fn new(config: Ripple) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(RippleStateInner {
+                config,
+                ripples: Vec::new(),
+                runtime: None,
+                registration: None,
+            })),
+        }
+    }
+
+    pub(crate) fn set_config(&self, config: Ripple) {
+        self.inner.borrow_mut().config = config;
+    }
+
+    /// Spawns a ripple at `origin` (local coordinates) and starts repainting.
+    pub(crate) fn press(&self, origin: Point, runtime: RuntimeHandle) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner
+                .ripples
+                .push(RippleAnimation::new(origin, runtime.clone()));
+            inner.runtime = Some(runtime);
+        }
+        schedule_next_frame(&self.inner);
+    }
+
+    /// Releases all active ripples so they fade out.
+    pub(crate) fn release(&self) {
+        for ripple in &mut self.inner.borrow_mut().ripples {
+            ripple.released = true;
+        }
+    }
+
+    /// Drops all ripples immediately and stops repainting.
+    pub(crate) fn clear(&self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.ripples.clear();
+        inner.registration = None;
+    }
+
+    /// Whether the ripples are drawn clipped to the element bounds.
+    pub(crate) fn is_bounded(&self) -> bool {
+        self.inner.borrow().config.bounded
+    }
+
+    /// Creates the deferred draw closure that renders the current ripples.
+    ///
+    /// Bounded ripples are drawn as whole circles; the draw command clips them
+    /// to the element's bounds and shape.
+    pub(crate) fn draw_closure(&self) -> Rc<dyn Fn(Size) -> Vec<DrawPrimitive>> {
+        let inner = self.inner.clone();
+        Rc::new(move |size| inner.borrow().primitives(size))
+    }
+}
+
+impl RippleStateInner {
+    fn update(&mut self) {
+        self.ripples.retain_mut(RippleAnimation::update);
+    }
+
+    fn primitives(&self, size: Size) -> Vec<DrawPrimitive> {
+        if size.width <= 0.0 || size.height <= 0.0 {
+            return Vec::new();
+        }
+        let center = Point::new(size.width / 2.0, size.height / 2.0);
+        let start_radius = size.width.max(size.height) * RIPPLE_START_RADIUS_FRACTION;
+        let end_radius = self
+            .config
+            .radius
+            .unwrap_or_else(|| (size.width * size.width + size.height * size.height).sqrt() / 2.0);
+
+        self.ripples
+            .iter()
+            .filter_map(|ripple| {
+                let alpha = ripple.alpha.value();
+                if alpha <= 0.0 {
+                    return None;
+                }
+                let t = ripple.radius_progress.value();
+                let radius = lerp(start_radius, end_radius, t);
+                // Bounded ripples drift from the press position towards the center
+                let ripple_center = if self.config.bounded {
+                    Point::new(
+                        lerp(ripple.origin.x, center.x, t),
+                        lerp(ripple.origin.y, center.y, t),
+                    )
+                } else {
+                    center
+                };
+                let color = self
+                    .config
+                    .color
+                    .with_alpha(self.config.color.a() * alpha);
+                Some(DrawPrimitive::RoundRect {
+                    rect: Rect {
+                        x: ripple_center.x - radius,
+                        y: ripple_center.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    brush: Brush::solid(color),
+                    radii: CornerRadii::uniform(radius),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Registers the next repaint callback unless one is already pending.
+fn schedule_next_frame(state: &Rc<RefCell<RippleStateInner>>) {
+    let runtime = {
+        let inner = state.borrow();
+        if inner.registration.is_some() || inner.ripples.is_empty() {
+            return;
+        }
+        match inner.runtime.clone() {
+            Some(runtime) => runtime,
+            None => return,
+        }
+    };
+
+    let weak: Weak<RefCell<RippleStateInner>> = Rc::downgrade(state);
+    let registration = runtime.frame_clock().with_frame_nanos(move |_| {
+        let Some(state) = weak.upgrade() else {
+            return;
+        };
+        {
+            let mut inner = state.borrow_mut();
+            inner.registration = None;
+            inner.update();
+        }
+        request_render_invalidation();
+        schedule_next_frame(&state);
+    });
+    state.borrow_mut().registration = Some(registration);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cranpose_core::{DefaultScheduler, Runtime};
+    use std::sync::Arc;
+
+    const FRAME_NANOS: u64 = 16_000_000;
+    const RIPPLE_EXPAND_NANOS: u64 = RIPPLE_EXPAND_MILLIS * 1_000_000;
+    const RIPPLE_FADE_OUT_NANOS: u64 = RIPPLE_FADE_OUT_MILLIS * 1_000_000;
+
+    fn ripple_rect(state: &RippleState, size: Size) -> Option<(Rect, f32)> {
+        ripple_primitive(state, size).map(|(rect, alpha, _)| (rect, alpha))
+    }
+
+    fn ripple_primitive(state: &RippleState, size: Size) -> Option<(Rect, f32, CornerRadii)> {
+        let primitives = (state.draw_closure())(size);
+        assert!(primitives.len() <= 1);
+        primitives
+            .into_iter()
+            .next()
+            .map(|primitive| match primitive {
+                DrawPrimitive::RoundRect {
+                    rect,
+                    brush: Brush::Solid(color),
+                    radii,
+                } => (rect, color.a(), radii),
+                other => panic!("unexpected ripple primitive: {other:?}"),
+            })
+    }
+
+    #[test]
+    fn ripple_radius_grows_then_fades_after_release() {
+        let runtime = Runtime::new(Arc::new(DefaultScheduler));
+        let handle = runtime.handle();
+        let size = Size::new(100.0, 40.0);
+        let state = RippleState::new(Ripple::unbounded());
+
+        state.press(Point::new(10.0, 10.0), handle.clone());
+        assert!(ripple_rect(&state, size).is_none());
+
+        let mut frame_time = 0;
+        handle.drain_frame_callbacks(frame_time);
+        let mut widths = Vec::new();
+        while frame_time < RIPPLE_EXPAND_NANOS {
+            frame_time += FRAME_NANOS;
+            handle.drain_frame_callbacks(frame_time);
+            widths.push(ripple_rect(&state, size).expect("ripple visible").0.width);
+        }
+        assert!(widths.windows(2).all(|pair| pair[1] >= pair[0]));
+        assert!(widths[0] < widths[widths.len() - 1]);
+        let (full, full_alpha) = ripple_rect(&state, size).unwrap();
+        let half_diagonal = (100.0f32 * 100.0 + 40.0 * 40.0).sqrt() / 2.0;
+        assert!((full.width - half_diagonal * 2.0).abs() < 0.01);
+        assert!((full_alpha - DEFAULT_RIPPLE_COLOR.a()).abs() < 1e-6);
+
+        state.release();
+        let mut alphas = Vec::new();
+        while let Some((_, alpha)) = ripple_rect(&state, size) {
+            frame_time += FRAME_NANOS;
+            handle.drain_frame_callbacks(frame_time);
+            alphas.push(alpha);
+        }
+        assert!(alphas.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert!(frame_time >= RIPPLE_EXPAND_NANOS + RIPPLE_FADE_OUT_NANOS);
+        assert!(!handle.has_frame_callbacks());
+    }
+
+    #[test]
+    fn bounded_ripple_drifts_to_the_center_and_covers_the_bounds() {
+        let runtime = Runtime::new(Arc::new(DefaultScheduler));
+        let handle = runtime.handle();
+        let size = Size::new(100.0, 40.0);
+        let state = RippleState::new(Ripple::bounded().with_color(Color::RED));
+        assert!(state.is_bounded());
+
+        state.press(Point::new(5.0, 5.0), handle.clone());
+        handle.drain_frame_callbacks(0);
+        handle.drain_frame_callbacks(FRAME_NANOS);
+        let (early, _, _) = ripple_primitive(&state, size).expect("ripple visible");
+        let early_center = (early.x + early.width / 2.0, early.y + early.height / 2.0);
+        assert!(early_center.0 < 50.0 && early_center.1 < 20.0);
+
+        for frame in 2..20 {
+            handle.drain_frame_callbacks(frame * FRAME_NANOS);
+        }
+        let (circle, alpha, radii) = ripple_primitive(&state, size).unwrap();
+        let half_diagonal = (100.0f32 * 100.0 + 40.0 * 40.0).sqrt() / 2.0;
+        assert!((circle.x + circle.width / 2.0 - 50.0).abs() < 1e-4);
+        assert!((circle.y + circle.height / 2.0 - 20.0).abs() < 1e-4);
+        assert!((circle.width - half_diagonal * 2.0).abs() < 1e-3);
+        assert_eq!(radii, CornerRadii::uniform(circle.width / 2.0));
+        assert_eq!(alpha, 1.0);
+    }
+
+    #[test]
+    fn quick_tap_keeps_ripple_until_expanded() {
+        let runtime = Runtime::new(Arc::new(DefaultScheduler));
+        let handle = runtime.handle();
+        let size = Size::new(50.0, 50.0);
+        let state = RippleState::new(Ripple::default());
+
+        state.press(Point::new(25.0, 25.0), handle.clone());
+        handle.drain_frame_callbacks(0);
+        state.release();
+        handle.drain_frame_callbacks(RIPPLE_FADE_OUT_NANOS);
+        let (_, alpha) = ripple_rect(&state, size).expect("ripple still expanding");
+        assert!((alpha - DEFAULT_RIPPLE_COLOR.a()).abs() < 1e-6);
+
+        state.clear();
+        assert!(ripple_rect(&state, size).is_none());
+        assert!(!handle.has_frame_callbacks());
+    }
+}
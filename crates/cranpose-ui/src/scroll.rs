@@ -4,11 +4,17 @@
 //! - `ScrollState`: Holds scroll position and provides scroll control methods
 //! - `ScrollNode`: Layout modifier that applies scroll offset to content
 //! - `ScrollElement`: Element for creating ScrollNode instances
+//! - `LazyScrollNode`: Marks the LazyListState scrolling a lazy list
 //!
 //! The actual `Modifier.horizontal_scroll()` and `Modifier.vertical_scroll()`
 //! extension methods are defined in `modifier/scroll.rs`.
 
-use cranpose_core::{mutableStateOf, MutableState, NodeId};
+use cranpose_animation::AnimationSpec;
+use cranpose_core::{
+    current_runtime_handle, mutableStateOf, FrameCallbackRegistration, FrameClock, MutableState,
+    NodeId,
+};
+use cranpose_foundation::lazy::LazyListState;
 use cranpose_foundation::{
     Constraints, DelegatableNode, LayoutModifierNode, Measurable, ModifierNode,
     ModifierNodeContext, ModifierNodeElement, NodeCapabilities, NodeState,
@@ -34,6 +40,14 @@ pub struct ScrollState {
     inner: Rc<ScrollStateInner>,
 }
 
+// Implement PartialEq by comparing inner pointers for identity.
+// This allows ScrollState to be used as a composable function parameter.
+impl PartialEq for ScrollState {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
 pub(crate) struct ScrollStateInner {
     /// Unique ID for debugging
     id: u64,
@@ -44,11 +58,19 @@ pub(crate) struct ScrollStateInner {
     /// Maximum scroll value (content_size - viewport_size)
     /// Using RefCell instead of MutableState to avoid snapshot isolation issues
     max_value: RefCell<f32>,
+    /// Whether `value` is above zero; reactive so composables can show scroll
+    /// affordances without recomposing on every scroll delta.
+    can_scroll_backward: MutableState<bool>,
+    /// Whether `value` is below `max_value`; reactive so composables can show
+    /// scroll affordances.
+    can_scroll_forward: MutableState<bool>,
     /// Callbacks to invalidate layout when scroll value changes
     /// Using HashMap to allow multiple listeners (e.g. real node + clones)
     invalidate_callbacks: RefCell<std::collections::HashMap<u64, Box<dyn Fn()>>>,
     /// Tracks whether we need to invalidate once a callback is registered.
     pending_invalidation: Cell<bool>,
+    /// Next frame of a running `animate_scroll_to`; dropping it stops the animation.
+    scroll_animation: RefCell<Option<FrameCallbackRegistration>>,
 }
 
 impl ScrollState {
@@ -61,8 +83,11 @@ impl ScrollState {
                 id,
                 value: mutableStateOf(initial),
                 max_value: RefCell::new(0.0),
+                can_scroll_backward: mutableStateOf(initial > 0.0),
+                can_scroll_forward: mutableStateOf(false),
                 invalidate_callbacks: RefCell::new(std::collections::HashMap::new()),
                 pending_invalidation: Cell::new(false),
+                scroll_animation: RefCell::new(None),
             }),
         }
     }
@@ -93,9 +118,43 @@ impl ScrollState {
         *self.inner.max_value.borrow()
     }
 
+    /// Returns whether the content can scroll toward its start.
+    ///
+    /// Reactive: reading it during composition recomposes on changes.
+    pub fn can_scroll_backward(&self) -> bool {
+        self.inner.can_scroll_backward.get()
+    }
+
+    /// Returns whether the content can scroll toward its end.
+    ///
+    /// Reactive: reading it during composition recomposes on changes.
+    pub fn can_scroll_forward(&self) -> bool {
+        self.inner.can_scroll_forward.get()
+    }
+
+    /// Updates the reactive can-scroll flags after the value or maximum changed.
+    ///
+    /// Each flag is only written when it flips, so readers recompose at the
+    /// scroll bounds rather than on every delta.
+    fn update_can_scroll(&self) {
+        let value = self.value_non_reactive();
+        let can_scroll_backward = value > 0.0;
+        if self.inner.can_scroll_backward.get_non_reactive() != can_scroll_backward {
+            self.inner.can_scroll_backward.set(can_scroll_backward);
+        }
+        let can_scroll_forward = value < self.max_value();
+        if self.inner.can_scroll_forward.get_non_reactive() != can_scroll_forward {
+            self.inner.can_scroll_forward.set(can_scroll_forward);
+        }
+    }
+
     /// Scrolls by the given delta, clamping to valid range [0, max_value].
     /// Returns the actual amount scrolled.
+    ///
+    /// Stops a running [`animate_scroll_to`](Self::animate_scroll_to), so user
+    /// scrolling takes over from programmatic animations.
     pub fn dispatch_raw_delta(&self, delta: f32) -> f32 {
+        self.inner.scroll_animation.borrow_mut().take();
         let current = self.value();
         let max = self.max_value();
         let new_value = (current + delta).clamp(0.0, max);
@@ -104,6 +163,7 @@ impl ScrollState {
         if actual_delta.abs() > 0.001 {
             // Use MutableState::set which triggers snapshot observers for reactive updates
             self.inner.value.set(new_value);
+            self.update_can_scroll();
 
             // Trigger layout invalidation callbacks
             let callbacks = self.inner.invalidate_callbacks.borrow();
@@ -123,6 +183,7 @@ impl ScrollState {
     /// Sets the maximum scroll value (internal use by ScrollNode).
     pub(crate) fn set_max_value(&self, max: f32) {
         *self.inner.max_value.borrow_mut() = max;
+        self.update_can_scroll();
     }
 
     /// Scrolls to the given position immediately.
@@ -131,6 +192,7 @@ impl ScrollState {
         let clamped = position.clamp(0.0, max);
 
         self.inner.value.set(clamped);
+        self.update_can_scroll();
 
         // Trigger layout invalidation callbacks
         let callbacks = self.inner.invalidate_callbacks.borrow();
@@ -143,6 +205,54 @@ impl ScrollState {
         }
     }
 
+    /// Animates the scroll position to `position` with the default tween.
+    ///
+    /// Jumps right away when no runtime is available to drive frames.
+    pub fn animate_scroll_to(&self, position: f32) {
+        let target = position.clamp(0.0, self.max_value());
+        let Some(runtime) = current_runtime_handle() else {
+            self.scroll_to(target);
+            return;
+        };
+        let start = self.value_non_reactive();
+        self.schedule_scroll_animation(
+            runtime.frame_clock(),
+            start,
+            target,
+            AnimationSpec::default(),
+            None,
+        );
+    }
+
+    fn schedule_scroll_animation(
+        &self,
+        frame_clock: FrameClock,
+        start: f32,
+        target: f32,
+        spec: AnimationSpec,
+        start_time_nanos: Option<u64>,
+    ) {
+        let state = self.clone();
+        let next_clock = frame_clock.clone();
+        let registration = frame_clock.with_frame_nanos(move |frame_time_nanos| {
+            let start_time = start_time_nanos.unwrap_or(frame_time_nanos);
+            let duration_nanos = spec.duration_millis * 1_000_000;
+            let elapsed = frame_time_nanos.saturating_sub(start_time);
+            let fraction = if elapsed >= duration_nanos {
+                1.0
+            } else {
+                elapsed as f32 / duration_nanos as f32
+            };
+            state.scroll_to(start + (target - start) * spec.easing.transform(fraction));
+            if fraction < 1.0 {
+                state.schedule_scroll_animation(next_clock, start, target, spec, Some(start_time));
+            } else {
+                state.inner.scroll_animation.borrow_mut().take();
+            }
+        });
+        *self.inner.scroll_animation.borrow_mut() = Some(registration);
+    }
+
     /// Adds an invalidation callback and returns its ID
     pub(crate) fn add_invalidate_callback(&self, callback: Box<dyn Fn()>) -> u64 {
         static NEXT_CALLBACK_ID: std::sync::atomic::AtomicU64 =
@@ -166,6 +276,40 @@ impl ScrollState {
     }
 }
 
+/// Scroll state that knows whether its content can scroll further.
+///
+/// Implemented by [`ScrollState`] and [`LazyListState`]; both answer
+/// reactively, so reading them during composition recomposes when
+/// scrollability changes.
+///
+/// Matches Kotlin: `ScrollableState.canScrollForward` / `canScrollBackward`
+pub trait ScrollableState {
+    /// Whether the content can scroll toward its start.
+    fn can_scroll_backward(&self) -> bool;
+    /// Whether the content can scroll toward its end.
+    fn can_scroll_forward(&self) -> bool;
+}
+
+impl ScrollableState for ScrollState {
+    fn can_scroll_backward(&self) -> bool {
+        ScrollState::can_scroll_backward(self)
+    }
+
+    fn can_scroll_forward(&self) -> bool {
+        ScrollState::can_scroll_forward(self)
+    }
+}
+
+impl ScrollableState for LazyListState {
+    fn can_scroll_backward(&self) -> bool {
+        LazyListState::can_scroll_backward(self)
+    }
+
+    fn can_scroll_forward(&self) -> bool {
+        LazyListState::can_scroll_forward(self)
+    }
+}
+
 /// Element for creating a ScrollNode.
 #[derive(Clone)]
 pub struct ScrollElement {
@@ -274,6 +418,16 @@ impl ScrollNode {
     pub fn state(&self) -> &ScrollState {
         &self.state
     }
+
+    /// Returns whether this node scrolls its content vertically.
+    pub fn is_vertical(&self) -> bool {
+        self.is_vertical
+    }
+
+    /// Returns whether increasing the scroll value moves content toward its start.
+    pub fn reverse_scrolling(&self) -> bool {
+        self.reverse_scrolling
+    }
 }
 
 impl DelegatableNode for ScrollNode {
@@ -409,6 +563,98 @@ impl LayoutModifierNode for ScrollNode {
     }
 }
 
+/// Element for creating a LazyScrollNode.
+#[derive(Clone, Copy, PartialEq)]
+pub struct LazyScrollElement {
+    state: LazyListState,
+    is_vertical: bool,
+    reverse_scrolling: bool,
+}
+
+impl LazyScrollElement {
+    pub fn new(state: LazyListState, is_vertical: bool, reverse_scrolling: bool) -> Self {
+        Self {
+            state,
+            is_vertical,
+            reverse_scrolling,
+        }
+    }
+}
+
+impl std::fmt::Debug for LazyScrollElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyScrollElement")
+            .field("is_vertical", &self.is_vertical)
+            .field("reverse_scrolling", &self.reverse_scrolling)
+            .finish()
+    }
+}
+
+impl Eq for LazyScrollElement {}
+
+impl Hash for LazyScrollElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.state.inner_ptr() as usize).hash(state);
+        self.is_vertical.hash(state);
+        self.reverse_scrolling.hash(state);
+    }
+}
+
+impl ModifierNodeElement for LazyScrollElement {
+    type Node = LazyScrollNode;
+
+    fn create(&self) -> Self::Node {
+        LazyScrollNode {
+            state: self.state,
+            is_vertical: self.is_vertical,
+            reverse_scrolling: self.reverse_scrolling,
+            node_state: NodeState::default(),
+        }
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.state = self.state;
+        node.is_vertical = self.is_vertical;
+        node.reverse_scrolling = self.reverse_scrolling;
+    }
+}
+
+/// Marks a lazy list node as scrolled by a [`LazyListState`].
+///
+/// The list positions its items itself, so this node does no layout; it lets
+/// bring-into-view requests find the state that scrolls the list.
+pub struct LazyScrollNode {
+    state: LazyListState,
+    is_vertical: bool,
+    reverse_scrolling: bool,
+    node_state: NodeState,
+}
+
+impl LazyScrollNode {
+    /// Returns the LazyListState that scrolls the list.
+    pub fn state(&self) -> LazyListState {
+        self.state
+    }
+
+    /// Returns whether the list scrolls vertically.
+    pub fn is_vertical(&self) -> bool {
+        self.is_vertical
+    }
+
+    /// Returns whether the list lays out its items from the end.
+    pub fn reverse_scrolling(&self) -> bool {
+        self.reverse_scrolling
+    }
+}
+
+impl DelegatableNode for LazyScrollNode {
+    fn node_state(&self) -> &NodeState {
+        &self.node_state
+    }
+}
+
+impl ModifierNode for LazyScrollNode {}
+
 /// Creates a remembered ScrollState.
 ///
 /// This is a convenience function for use in composable functions.
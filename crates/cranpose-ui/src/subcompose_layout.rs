@@ -3,7 +3,8 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use cranpose_core::{
-    Composer, NodeError, NodeId, Phase, SlotBackend, SlotId, SlotsHost, SubcomposeState,
+    Composer, NodeError, NodeId, Phase, SlotBackend, SlotId, SlotReuseLimits, SlotsHost,
+    SubcomposeState,
 };
 use indexmap::IndexSet;
 
@@ -224,6 +225,7 @@ impl<'a> SubcomposeMeasureScopeImpl<'a> {
         // CRITICAL FIX: Clear children of reused virtual nodes BEFORE subcomposing new content.
         // Without this, old children remain attached when the node is reused for different items,
         // causing items from different scroll positions to interleave (e.g., [1,16,31,2,17,32...]).
+        // Content recycled from another slot re-attaches its existing nodes during subcompose.
         if is_reused {
             self.composer.clear_node_children(virtual_node_id);
         }
@@ -314,6 +316,18 @@ impl<'a> SubcomposeMeasureScopeImpl<'a> {
         self.state.reusable_slots_count()
     }
 
+    /// Sets the reusable pool sizes.
+    ///
+    /// Lazy layouts forward their configured limits here before subcomposing items.
+    pub fn set_reuse_limits(&mut self, limits: SlotReuseLimits) {
+        self.state.set_reuse_limits(limits);
+    }
+
+    /// Returns how many nodes were disposed so far because their pool was full.
+    pub fn pool_overflow_count(&self) -> usize {
+        self.state.pool_overflow_count()
+    }
+
     /// Registers the content type for a slot.
     ///
     /// Call this before `subcompose()` to enable content-type-aware slot reuse.
@@ -518,6 +532,19 @@ impl SubcomposeLayoutNode {
         self.inner.borrow().children.iter().copied().collect()
     }
 
+    /// Returns this node's key event handlers, innermost first.
+    pub(crate) fn key_event_handlers(&self) -> Vec<crate::KeyEventHandler> {
+        self.inner.borrow().modifier_chain.key_event_handlers()
+    }
+
+    /// Applies `f` to the lazy list scroll nodes that make this node a scroll container.
+    pub(crate) fn for_each_lazy_scroll_node(&self, f: impl FnMut(&crate::scroll::LazyScrollNode)) {
+        self.inner
+            .borrow()
+            .modifier_chain
+            .for_each_lazy_scroll_node(f);
+    }
+
     /// Mark this node as needing measure. Also marks it as needing layout.
     pub fn mark_needs_measure(&self) {
         self.needs_measure.set(true);
@@ -652,6 +679,9 @@ impl SubcomposeLayoutNode {
                         || prev_caps.contains(NodeCapabilities::LAYOUT)
                     {
                         self.mark_needs_measure();
+                        if let Some(id) = self.id.get() {
+                            crate::schedule_layout_repass(id);
+                        }
                     }
                 }
                 InvalidationKind::Draw => {
@@ -138,10 +138,10 @@ fn async_runtime_full_layout(
             // Title Text
             Text("Async Runtime Demo", Modifier::empty().padding(12.0));
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             // Get snapshots for this render
             let animation_snapshot = animation.get();
@@ -158,10 +158,10 @@ fn async_runtime_full_layout(
                         Modifier::empty().padding(6.0),
                     );
 
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 0.0,
                         height: 8.0,
-                    });
+                    }));
 
                     // Outer container Row
                     Row(
@@ -199,10 +199,10 @@ fn async_runtime_full_layout(
                 },
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 12.0,
-            });
+            }));
 
             // Stats Text - THIS SHOULD UPDATE BUT FREEZES
             Text(
@@ -218,10 +218,10 @@ fn async_runtime_full_layout(
                 Modifier::empty().padding(8.0),
             );
 
-            Spacer(Size {
+            Spacer(Modifier::empty().size(Size {
                 width: 0.0,
                 height: 16.0,
-            });
+            }));
 
             // Button Row
             {
@@ -0,0 +1,196 @@
+use super::*;
+use crate::focus_dispatch::set_active_focus_target;
+use crate::focus_traversal::request_focus;
+use crate::modifier::Modifier;
+use crate::primitives::{Box, BoxSpec, Column, ColumnSpec};
+use crate::widgets::lazy_list::{LazyColumn, LazyColumnSpec};
+use crate::{layout::LayoutEngine, Composition};
+use cranpose_core::location_key;
+use cranpose_foundation::lazy::{remember_lazy_list_state, LazyListScope};
+
+type Holder<T> = Rc<RefCell<Option<T>>>;
+
+/// A 100px tall vertical scroll container holding a 300px spacer followed by
+/// a 50px box carrying `modifier`.
+fn compose_scrolled_box(modifier: Modifier) -> (Composition<MemoryApplier>, ScrollState, NodeId) {
+    let state_holder: Holder<ScrollState> = Rc::new(RefCell::new(None));
+    let node_holder: Holder<NodeId> = Rc::new(RefCell::new(None));
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let (state_out, node_out) = (state_holder.clone(), node_holder.clone());
+    composition
+        .render(key, move || {
+            let state = cranpose_core::remember(|| ScrollState::new(0.0)).with(|s| s.clone());
+            *state_out.borrow_mut() = Some(state.clone());
+            let modifier = modifier.clone();
+            let node_out = node_out.clone();
+            Column(
+                Modifier::empty()
+                    .size(Size {
+                        width: 100.0,
+                        height: 100.0,
+                    })
+                    .vertical_scroll(state, false),
+                ColumnSpec::default(),
+                move || {
+                    Box(
+                        Modifier::empty().size(Size {
+                            width: 100.0,
+                            height: 300.0,
+                        }),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                    let node = Box(
+                        modifier.clone().size(Size {
+                            width: 100.0,
+                            height: 50.0,
+                        }),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                    *node_out.borrow_mut() = Some(node);
+                },
+            );
+        })
+        .expect("initial render");
+    layout(&mut composition);
+
+    let state = state_holder.borrow_mut().take().expect("scroll state");
+    let node = node_holder.borrow_mut().take().expect("box node");
+    (composition, state, node)
+}
+
+fn layout(composition: &mut Composition<MemoryApplier>) {
+    let root = composition.root().expect("column root");
+    composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 400.0,
+                height: 400.0,
+            },
+        )
+        .expect("layout");
+}
+
+/// Serves queued requests and runs the scroll animation to its end.
+fn settle(composition: &mut Composition<MemoryApplier>) {
+    let root = composition.root().expect("column root");
+    process_bring_into_view_requests(&mut composition.applier_mut(), root);
+    let runtime = composition.runtime_handle();
+    runtime.drain_frame_callbacks(0);
+    runtime.drain_frame_callbacks(1_000_000_000);
+}
+
+#[test]
+fn delta_aligns_the_closer_edge_and_keeps_visible_content_still() {
+    assert_eq!(bring_into_view_delta(10.0, 40.0, 100.0), 0.0);
+    assert_eq!(bring_into_view_delta(300.0, 350.0, 100.0), 250.0);
+    assert_eq!(bring_into_view_delta(-30.0, 20.0, 100.0), -30.0);
+    // Taller than the viewport: show its start
+    assert_eq!(bring_into_view_delta(50.0, 250.0, 100.0), 50.0);
+    assert_eq!(bring_into_view_delta(-50.0, 150.0, 100.0), 0.0);
+}
+
+#[test]
+fn requester_scrolls_a_box_below_the_viewport_into_view() {
+    let requester = BringIntoViewRequester::new();
+    let (mut composition, state, _) =
+        compose_scrolled_box(Modifier::empty().bring_into_view_requester(&requester));
+    assert_eq!(state.value_non_reactive(), 0.0);
+
+    assert!(requester.bring_into_view());
+    settle(&mut composition);
+
+    // The box spans 300..350, so its bottom meets the 100px viewport's bottom
+    assert_eq!(state.value_non_reactive(), 250.0);
+}
+
+#[test]
+fn focusing_a_node_scrolls_it_into_view() {
+    set_active_focus_target(None);
+    let (mut composition, state, node) = compose_scrolled_box(Modifier::empty().focusable());
+
+    assert!(request_focus(&mut composition.applier_mut(), node));
+    settle(&mut composition);
+
+    assert_eq!(state.value_non_reactive(), 250.0);
+    set_active_focus_target(None);
+}
+
+#[test]
+fn focusing_a_lazy_list_item_outside_the_viewport_scrolls_the_list() {
+    set_active_focus_target(None);
+    let state_holder: Holder<LazyListState> = Rc::new(RefCell::new(None));
+    let node_holder: Holder<NodeId> = Rc::new(RefCell::new(None));
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let (state_out, node_out) = (state_holder.clone(), node_holder.clone());
+    composition
+        .render(key, move || {
+            let state = remember_lazy_list_state();
+            *state_out.borrow_mut() = Some(state);
+            let node_out = node_out.clone();
+            LazyColumn(
+                Modifier::empty().size(Size {
+                    width: 100.0,
+                    height: 100.0,
+                }),
+                state,
+                LazyColumnSpec::default(),
+                move |scope| {
+                    let node_out = node_out.clone();
+                    scope.items(
+                        20,
+                        None::<fn(usize) -> u64>,
+                        None::<fn(usize) -> u64>,
+                        move |index| {
+                            let node = Box(
+                                Modifier::empty().focusable().size(Size {
+                                    width: 100.0,
+                                    height: 50.0,
+                                }),
+                                BoxSpec::default(),
+                                || {},
+                            );
+                            if index == 3 {
+                                *node_out.borrow_mut() = Some(node);
+                            }
+                        },
+                    );
+                },
+            );
+        })
+        .expect("initial render");
+    let handle = composition.runtime_handle();
+    composition.applier_mut().set_runtime_handle(handle);
+    layout(&mut composition);
+    let state = state_holder.borrow_mut().take().expect("lazy list state");
+    // Item 3 spans 150..200, composed beyond the 100px viewport
+    let node = node_holder.borrow().expect("item 3 is composed");
+    assert_eq!(state.first_visible_item_index(), 0);
+
+    assert!(request_focus(&mut composition.applier_mut(), node));
+    settle(&mut composition);
+    relayout_lists(&mut composition);
+
+    // Its bottom now meets the viewport's bottom
+    assert_eq!(state.first_visible_item_index(), 2);
+    assert_eq!(state.first_visible_item_scroll_offset(), 0.0);
+    set_active_focus_target(None);
+}
+
+/// Relayouts after lazy lists scroll, bubbling their scheduled repasses the
+/// way the app shell does.
+fn relayout_lists(composition: &mut Composition<MemoryApplier>) {
+    {
+        let mut applier = composition.applier_mut();
+        for node_id in crate::take_layout_repass_nodes() {
+            cranpose_core::bubble_measure_dirty(&mut *applier, node_id);
+            cranpose_core::bubble_layout_dirty(&mut *applier, node_id);
+        }
+    }
+    layout(composition);
+}
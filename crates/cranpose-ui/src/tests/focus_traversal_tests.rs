@@ -0,0 +1,188 @@
+use super::*;
+use crate::modifier::{Modifier, Size};
+use crate::primitives::{Box, BoxSpec, Column, ColumnSpec, Row, RowSpec, Text};
+use crate::{layout::LayoutEngine, Composition};
+use cranpose_core::location_key;
+use cranpose_ui_layout::VerticalAlignment;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type FocusLog = Rc<RefCell<Vec<&'static str>>>;
+
+fn focusable(focused: &FocusLog, name: &'static str) -> Modifier {
+    let focused = focused.clone();
+    Modifier::empty()
+        .focusable()
+        .on_focus_changed(move |state| {
+            if state.is_focused() {
+                focused.borrow_mut().push(name);
+            }
+        })
+}
+
+fn compose_focusables(focused: FocusLog) -> Composition<MemoryApplier> {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, move || {
+            let focused = focused.clone();
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                let row_focused = focused.clone();
+                Row(Modifier::empty(), RowSpec::default(), move || {
+                    Text("first".to_string(), focusable(&row_focused, "first"));
+                    Text("second".to_string(), focusable(&row_focused, "second"));
+                });
+                Text("third".to_string(), focusable(&focused, "third"));
+            });
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("column root");
+    composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 400.0,
+                height: 400.0,
+            },
+        )
+        .expect("layout");
+    composition
+}
+
+#[test]
+fn tab_advances_focus_in_reading_order_and_wraps() {
+    set_active_focus_target(None);
+    let focused = Rc::new(RefCell::new(Vec::new()));
+    let mut composition = compose_focusables(focused.clone());
+    let root = composition.root().expect("column root");
+    let mut applier = composition.applier_mut();
+
+    for _ in 0..4 {
+        assert!(move_focus(&mut applier, root, FocusDirection::Next));
+    }
+
+    assert_eq!(*focused.borrow(), vec!["first", "second", "third", "first"]);
+    set_active_focus_target(None);
+}
+
+#[test]
+fn shift_tab_moves_focus_backwards_and_wraps() {
+    set_active_focus_target(None);
+    let focused = Rc::new(RefCell::new(Vec::new()));
+    let mut composition = compose_focusables(focused.clone());
+    let root = composition.root().expect("column root");
+    let mut applier = composition.applier_mut();
+
+    for _ in 0..3 {
+        assert!(move_focus(&mut applier, root, FocusDirection::Previous));
+    }
+
+    assert_eq!(*focused.borrow(), vec!["third", "second", "first"]);
+    let order = focus_traversal_order(&mut applier, root);
+    assert_eq!(active_focus_target(), order.first().copied());
+    set_active_focus_target(None);
+}
+
+#[test]
+fn bottom_aligned_row_is_traversed_left_to_right() {
+    set_active_focus_target(None);
+    let focused: FocusLog = Rc::new(RefCell::new(Vec::new()));
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let render_focused = focused.clone();
+    composition
+        .render(key, move || {
+            let focused = render_focused.clone();
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                let row_focused = focused.clone();
+                Row(
+                    Modifier::empty(),
+                    RowSpec::new().vertical_alignment(VerticalAlignment::Bottom),
+                    move || {
+                        // The short item sits lower than the tall one to its right
+                        Box(
+                            focusable(&row_focused, "short").size(Size::new(20.0, 10.0)),
+                            BoxSpec::default(),
+                            || {},
+                        );
+                        Box(
+                            focusable(&row_focused, "tall").size(Size::new(20.0, 40.0)),
+                            BoxSpec::default(),
+                            || {},
+                        );
+                    },
+                );
+                Text("below".to_string(), focusable(&focused, "below"));
+            });
+        })
+        .expect("initial render");
+    let root = composition.root().expect("column root");
+    let mut applier = composition.applier_mut();
+    applier
+        .compute_layout(root, Size::new(400.0, 400.0))
+        .expect("layout");
+
+    for _ in 0..3 {
+        assert!(move_focus(&mut applier, root, FocusDirection::Next));
+    }
+
+    assert_eq!(*focused.borrow(), vec!["short", "tall", "below"]);
+    set_active_focus_target(None);
+}
+
+#[test]
+fn key_event_consumed_by_focused_node_does_not_reach_parent() {
+    use crate::{KeyCode, KeyEvent};
+
+    set_active_focus_target(None);
+    let handled: FocusLog = Rc::new(RefCell::new(Vec::new()));
+    let target = Rc::new(RefCell::new(None));
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let render_handled = handled.clone();
+    let render_target = target.clone();
+    composition
+        .render(key, move || {
+            let parent_handled = render_handled.clone();
+            let child_handled = render_handled.clone();
+            let target = render_target.clone();
+            Column(
+                Modifier::empty().on_key_event(move |_| {
+                    parent_handled.borrow_mut().push("parent");
+                    true
+                }),
+                ColumnSpec::default(),
+                move || {
+                    let child_handled = child_handled.clone();
+                    let child = Text(
+                        "child".to_string(),
+                        Modifier::empty().focusable().on_key_event(move |event| {
+                            if event.key_code != KeyCode::Enter {
+                                return false;
+                            }
+                            child_handled.borrow_mut().push("child");
+                            true
+                        }),
+                    );
+                    *target.borrow_mut() = Some(child);
+                },
+            );
+        })
+        .expect("initial render");
+
+    let child = target.borrow().expect("child node");
+    let mut applier = composition.applier_mut();
+    assert!(request_focus(&mut applier, child));
+
+    let enter = KeyEvent::key_down(KeyCode::Enter, "\n");
+    assert!(dispatch_focused_key_event(&mut applier, &enter));
+    assert_eq!(*handled.borrow(), vec!["child"]);
+
+    // Keys the child ignores bubble up to the parent
+    let space = KeyEvent::key_down(KeyCode::Space, " ");
+    assert!(dispatch_focused_key_event(&mut applier, &space));
+    assert_eq!(*handled.borrow(), vec!["child", "parent"]);
+    set_active_focus_target(None);
+}
@@ -115,6 +115,97 @@ fn padding_node_clamps_to_constraints() {
     assert_eq!(result.size.height, 16.0);
 }
 
+/// Measurable that records the constraints it was measured with.
+#[derive(Default)]
+struct RecordingMeasurable {
+    constraints: Cell<Option<Constraints>>,
+}
+
+impl RecordingMeasurable {
+    fn assert_consistent(&self) {
+        let constraints = self.constraints.get().expect("measured");
+        assert!(constraints.min_width >= 0.0 && constraints.min_height >= 0.0);
+        assert!(constraints.min_width <= constraints.max_width);
+        assert!(constraints.min_height <= constraints.max_height);
+    }
+}
+
+impl Measurable for RecordingMeasurable {
+    fn measure(&self, constraints: Constraints) -> Box<dyn Placeable> {
+        self.constraints.set(Some(constraints));
+        Box::new(TestPlaceable {
+            width: constraints.min_width,
+            height: constraints.min_height,
+            node_id: 0,
+        })
+    }
+
+    fn min_intrinsic_width(&self, _height: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_width(&self, _height: f32) -> f32 {
+        0.0
+    }
+
+    fn min_intrinsic_height(&self, _width: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_height(&self, _width: f32) -> f32 {
+        0.0
+    }
+}
+
+#[test]
+fn padding_larger_than_max_keeps_inner_constraints_consistent() {
+    let mut context = BasicModifierNodeContext::new();
+    let node = PaddingNode::new(EdgeInsets::uniform(100.0));
+    let constraints = Constraints {
+        min_width: 50.0,
+        max_width: 60.0,
+        min_height: 50.0,
+        max_height: 60.0,
+    };
+
+    let measurable = RecordingMeasurable::default();
+    let result = node.measure(&mut context, &measurable, constraints);
+    measurable.assert_consistent();
+    assert_eq!(
+        measurable.constraints.get(),
+        Some(Constraints::tight(0.0, 0.0))
+    );
+    assert_eq!(result.size.width, 60.0);
+    assert_eq!(result.size.height, 60.0);
+
+    let proxy = node.create_measurement_proxy().expect("padding proxy");
+    let measurable = RecordingMeasurable::default();
+    proxy.measure_proxy(&mut context, &measurable, constraints);
+    measurable.assert_consistent();
+}
+
+#[test]
+fn inverted_incoming_constraints_are_normalized() {
+    let mut context = BasicModifierNodeContext::new();
+    let inverted = Constraints {
+        min_width: 80.0,
+        max_width: 60.0,
+        min_height: -10.0,
+        max_height: -20.0,
+    };
+
+    let padding = PaddingNode::new(EdgeInsets::uniform(4.0));
+    let measurable = RecordingMeasurable::default();
+    let result = padding.measure(&mut context, &measurable, inverted);
+    measurable.assert_consistent();
+    assert!(result.size.width >= 0.0 && result.size.height >= 0.0);
+
+    let size = SizeElement::new(Some(100.0), None).create();
+    let measurable = RecordingMeasurable::default();
+    size.measure(&mut context, &measurable, inverted);
+    measurable.assert_consistent();
+}
+
 #[test]
 fn padding_node_respects_intrinsics() {
     let padding = EdgeInsets::uniform(10.0);
@@ -161,6 +252,94 @@ fn corner_shape_node_is_draw_only() {
     assert!(!chain.has_nodes_for_invalidation(cranpose_foundation::InvalidationKind::Layout));
 }
 
+fn border_rects(modifier: Modifier, size: Size) -> Vec<Rect> {
+    let slices = collect_slices_from_modifier(&modifier);
+    crate::draw::execute_draw_commands(slices.draw_commands(), size)
+        .into_iter()
+        .map(|primitive| match primitive {
+            cranpose_ui_graphics::DrawPrimitive::Rect { rect, .. } => rect,
+            other => panic!("unexpected border primitive {other:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn solid_border_tiles_band_without_overlap() {
+    let size = Size {
+        width: 100.0,
+        height: 50.0,
+    };
+    let rects = border_rects(
+        Modifier::empty().border(2.0, Color(0.0, 0.0, 0.0, 1.0)),
+        size,
+    );
+
+    assert_eq!(rects.len(), 4);
+    let area: f32 = rects.iter().map(|r| r.width * r.height).sum();
+    assert_eq!(area, 100.0 * 50.0 - 96.0 * 46.0);
+}
+
+#[test]
+fn dashed_border_emits_dash_segments() {
+    let size = Size {
+        width: 100.0,
+        height: 50.0,
+    };
+    let style = BorderStyle::Dashed {
+        dash: 10.0,
+        gap: 5.0,
+    };
+    let rects = border_rects(
+        Modifier::empty().border_with_style(2.0, Color(0.0, 0.0, 0.0, 1.0), style),
+        size,
+    );
+
+    // Top edge alone holds dashes at 0, 15, 30, 45, 60, 75 and 90
+    let top: Vec<_> = rects.iter().filter(|r| r.y == 0.0).collect();
+    assert_eq!(top.len(), 7);
+    assert_eq!((top[1].x, top[1].width), (15.0, 10.0));
+    // The pattern continues around the corner onto the right edge
+    let first_right = rects.iter().find(|r| r.x == 98.0).expect("right edge dash");
+    assert_eq!((first_right.y, first_right.height), (7.0, 10.0));
+    assert!(rects.len() > 12, "expected dashes on every edge");
+    let area: f32 = rects.iter().map(|r| r.width * r.height).sum();
+    assert!(area < 100.0 * 50.0 - 96.0 * 46.0);
+
+    let dotted = border_rects(
+        Modifier::empty().border_with_style(2.0, Color(0.0, 0.0, 0.0, 1.0), BorderStyle::Dotted),
+        size,
+    );
+    assert!(dotted.iter().all(|r| r.width <= 2.0 && r.height <= 2.0));
+    assert!(dotted.len() > rects.len());
+}
+
+#[test]
+fn blur_radius_is_clamped_and_reported_in_slices() {
+    let slices = collect_slices_from_modifier(&Modifier::empty().blur(8.0));
+    assert_eq!(slices.blur_radius(), 8.0);
+
+    let slices = collect_slices_from_modifier(&Modifier::empty().blur(1000.0));
+    assert_eq!(slices.blur_radius(), MAX_BLUR_RADIUS);
+
+    let slices = collect_slices_from_modifier(&Modifier::empty().padding(4.0));
+    assert_eq!(slices.blur_radius(), 0.0);
+}
+
+#[test]
+fn blur_radius_is_in_dp() {
+    use crate::{local_density, run_test_composition, Density};
+    use cranpose_core::CompositionLocalProvider;
+
+    let radius = Cell::new(0.0);
+    run_test_composition(|| {
+        CompositionLocalProvider(vec![local_density().provides(Density::new(2.0))], || {
+            let modifier = Modifier::empty().blur(crate::Dp(8.0));
+            radius.set(collect_slices_from_modifier(&modifier).blur_radius());
+        });
+    });
+    assert_eq!(radius.get(), 16.0);
+}
+
 #[test]
 fn modifier_chain_reuses_padding_nodes() {
     let mut chain = ModifierNodeChain::new();
@@ -221,6 +400,182 @@ fn size_node_enforces_dimensions() {
     assert_eq!(result.size.height, 200.0);
 }
 
+/// Width a `width_in(50, 150)` node gives content `content_width` wide.
+fn width_in_result(content_width: f32) -> f32 {
+    let mut chain = ModifierNodeChain::new();
+    let mut context = BasicModifierNodeContext::new();
+    let elements = vec![modifier_element(SizeElement::with_constraints(
+        Some(50.0),
+        Some(150.0),
+        None,
+        None,
+        true,
+    ))];
+    chain.update_from_slice(&elements, &mut context);
+
+    let node = chain.node_mut::<SizeNode>(0).unwrap();
+    let measurable = TestMeasurable {
+        intrinsic_width: content_width,
+        intrinsic_height: 50.0,
+    };
+    let constraints = Constraints::loose(500.0, 500.0);
+    let result = node.measure(&mut context, &measurable, constraints);
+
+    let proxy = node.create_measurement_proxy().expect("size proxy");
+    let proxy_result = proxy.measure_proxy(&mut context, &measurable, constraints);
+    assert_eq!(proxy_result.size, result.size);
+    assert_eq!(result.size.height, 50.0);
+    result.size.width
+}
+
+#[test]
+fn width_in_clamps_content_into_range() {
+    // Smaller content grows to the minimum, larger content shrinks to the maximum
+    assert_eq!(width_in_result(20.0), 50.0);
+    assert_eq!(width_in_result(300.0), 150.0);
+    // Content within the range keeps its own width
+    assert_eq!(width_in_result(100.0), 100.0);
+}
+
+#[test]
+fn default_min_size_applies_only_without_incoming_minimum() {
+    let mut context = BasicModifierNodeContext::new();
+    let node = DefaultMinSizeNode::new(Some(48.0), Some(48.0));
+    let measurable = TestMeasurable {
+        intrinsic_width: 10.0,
+        intrinsic_height: 100.0,
+    };
+
+    // Small content grows to the default; larger content keeps its size
+    let result = node.measure(&mut context, &measurable, Constraints::loose(500.0, 500.0));
+    assert_eq!(result.size, Size::new(48.0, 100.0));
+
+    // A minimum from the parent wins, and the default never exceeds the maximum
+    let constraints = Constraints {
+        min_width: 20.0,
+        max_width: 500.0,
+        min_height: 0.0,
+        max_height: 30.0,
+    };
+    let result = node.measure(&mut context, &measurable, constraints);
+    assert_eq!(result.size, Size::new(20.0, 30.0));
+    assert_eq!(node.min_intrinsic_width(&measurable, 0.0), 48.0);
+}
+
+/// Size an aspect ratio node measures its content at within `constraints`.
+fn aspect_ratio_size(ratio: f32, match_height_first: bool, constraints: Constraints) -> Size {
+    let mut chain = ModifierNodeChain::new();
+    let mut context = BasicModifierNodeContext::new();
+    let elements = vec![modifier_element(AspectRatioElement::new(
+        ratio,
+        match_height_first,
+    ))];
+    chain.update_from_slice(&elements, &mut context);
+
+    let node = chain.node_mut::<AspectRatioNode>(0).unwrap();
+    let measurable = RecordingMeasurable::default();
+    let result = node.measure(&mut context, &measurable, constraints);
+
+    let proxy = node.create_measurement_proxy().expect("aspect ratio proxy");
+    let proxy_result = proxy.measure_proxy(&mut context, &measurable, constraints);
+    assert_eq!(proxy_result.size, result.size);
+    result.size
+}
+
+#[test]
+fn aspect_ratio_square_in_tall_narrow_box_uses_max_width() {
+    let size = aspect_ratio_size(1.0, false, Constraints::loose(50.0, 200.0));
+    assert_eq!(size, Size::new(50.0, 50.0));
+}
+
+#[test]
+fn aspect_ratio_square_in_wide_short_box_falls_back_to_max_height() {
+    let size = aspect_ratio_size(1.0, false, Constraints::loose(200.0, 50.0));
+    assert_eq!(size, Size::new(50.0, 50.0));
+}
+
+#[test]
+fn aspect_ratio_satisfies_min_constraints_when_max_candidates_do_not_fit() {
+    let constraints = Constraints {
+        min_width: 0.0,
+        max_width: 300.0,
+        min_height: 120.0,
+        max_height: 120.0,
+    };
+    assert_eq!(
+        aspect_ratio_size(2.0, false, constraints),
+        Size::new(240.0, 120.0)
+    );
+}
+
+#[test]
+fn aspect_ratio_breaks_constraints_only_when_no_candidate_fits() {
+    let constraints = Constraints {
+        min_width: 0.0,
+        max_width: 50.0,
+        min_height: 100.0,
+        max_height: 200.0,
+    };
+    // No square fits, so the first candidate keeps the ratio on its own axis
+    assert_eq!(
+        aspect_ratio_size(1.0, false, constraints),
+        Size::new(50.0, 50.0)
+    );
+    assert_eq!(
+        aspect_ratio_size(1.0, true, constraints),
+        Size::new(200.0, 200.0)
+    );
+}
+
+#[test]
+fn wrap_content_node_centers_child_in_tight_constraints() {
+    let mut chain = ModifierNodeChain::new();
+    let mut context = BasicModifierNodeContext::new();
+
+    let elements = vec![modifier_element(WrapContentElement::size(
+        Alignment::CENTER,
+    ))];
+    chain.update_from_slice(&elements, &mut context);
+
+    let node = chain.node_mut::<WrapContentNode>(0).unwrap();
+    let measurable = TestMeasurable {
+        intrinsic_width: 50.0,
+        intrinsic_height: 50.0,
+    };
+    let constraints = Constraints::tight(200.0, 200.0);
+
+    let result = node.measure(&mut context, &measurable, constraints);
+    assert_eq!(result.size.width, 200.0);
+    assert_eq!(result.size.height, 200.0);
+    assert_eq!(result.placement_offset_x, 75.0);
+    assert_eq!(result.placement_offset_y, 75.0);
+}
+
+#[test]
+fn wrap_content_width_keeps_vertical_min_constraint() {
+    let mut context = BasicModifierNodeContext::new();
+    let node = WrapContentNode::new(
+        FillDirection::Horizontal,
+        Alignment::new(HorizontalAlignment::End, VerticalAlignment::Top),
+    );
+    let measurable = TestMeasurable {
+        intrinsic_width: 50.0,
+        intrinsic_height: 50.0,
+    };
+    let constraints = Constraints {
+        min_width: 200.0,
+        max_width: 200.0,
+        min_height: 0.0,
+        max_height: 30.0,
+    };
+
+    let result = node.measure(&mut context, &measurable, constraints);
+    assert_eq!(result.size.width, 200.0);
+    assert_eq!(result.size.height, 30.0);
+    assert_eq!(result.placement_offset_x, 150.0);
+    assert_eq!(result.placement_offset_y, 0.0);
+}
+
 #[test]
 fn clickable_node_handles_pointer_events() {
     let mut chain = ModifierNodeChain::new();
@@ -309,6 +664,225 @@ fn clickable_node_cancels_click_on_drag() {
     assert!(!clicked.get()); // Click should NOT fire because we dragged
 }
 
+/// Pointer event dispatched to a 100x40 hit region at the window origin.
+fn event_in_button(kind: PointerEventKind, x: f32, y: f32) -> PointerEvent {
+    PointerEvent::new(kind, Point { x, y }, Point { x, y }).with_target_size(Size {
+        width: 100.0,
+        height: 40.0,
+    })
+}
+
+fn counting_clickable(clicks: &Rc<Cell<u32>>) -> ModifierNodeChain {
+    let mut chain = ModifierNodeChain::new();
+    let mut context = BasicModifierNodeContext::new();
+    let clicks = clicks.clone();
+    let elements = vec![modifier_element(ClickableElement::new(move |_point| {
+        clicks.set(clicks.get() + 1)
+    }))];
+    chain.update_from_slice(&elements, &mut context);
+    chain
+}
+
+#[test]
+fn clickable_release_outside_bounds_does_not_click() {
+    let clicks = Rc::new(Cell::new(0));
+    let chain = counting_clickable(&clicks);
+    let mut context = BasicModifierNodeContext::new();
+    let mut node = chain.node_mut::<ClickableNode>(0).unwrap();
+
+    // Stays within the drag threshold but crosses the right edge
+    for event in [
+        event_in_button(PointerEventKind::Down, 95.0, 20.0),
+        event_in_button(PointerEventKind::Move, 101.0, 20.0),
+        event_in_button(PointerEventKind::Up, 101.0, 20.0),
+    ] {
+        node.on_pointer_event(&mut context, &event);
+    }
+    assert_eq!(clicks.get(), 0);
+}
+
+#[test]
+fn clickable_press_stays_cancelled_after_pointer_returns() {
+    let clicks = Rc::new(Cell::new(0));
+    let chain = counting_clickable(&clicks);
+    let mut context = BasicModifierNodeContext::new();
+    let mut node = chain.node_mut::<ClickableNode>(0).unwrap();
+
+    for event in [
+        event_in_button(PointerEventKind::Down, 95.0, 20.0),
+        event_in_button(PointerEventKind::Move, 101.0, 20.0),
+        event_in_button(PointerEventKind::Move, 97.0, 20.0),
+        event_in_button(PointerEventKind::Up, 97.0, 20.0),
+    ] {
+        node.on_pointer_event(&mut context, &event);
+    }
+    assert_eq!(clicks.get(), 0);
+}
+
+#[test]
+fn clickable_release_inside_bounds_clicks_once() {
+    let clicks = Rc::new(Cell::new(0));
+    let chain = counting_clickable(&clicks);
+    let mut context = BasicModifierNodeContext::new();
+    let mut node = chain.node_mut::<ClickableNode>(0).unwrap();
+
+    node.on_pointer_event(
+        &mut context,
+        &event_in_button(PointerEventKind::Down, 50.0, 20.0),
+    );
+    assert_eq!(clicks.get(), 0);
+    assert!(node.on_pointer_event(
+        &mut context,
+        &event_in_button(PointerEventKind::Up, 52.0, 21.0),
+    ));
+    assert_eq!(clicks.get(), 1);
+
+    // A stray release without a press is not a click
+    node.on_pointer_event(
+        &mut context,
+        &event_in_button(PointerEventKind::Up, 52.0, 21.0),
+    );
+    assert_eq!(clicks.get(), 1);
+}
+
+#[test]
+fn disabled_clickable_ignores_pointer_events() {
+    let mut chain = ModifierNodeChain::new();
+    let mut context = BasicModifierNodeContext::new();
+
+    let clicked = Rc::new(Cell::new(false));
+    let clicked_clone = clicked.clone();
+
+    let elements = vec![modifier_element(
+        ClickableElement::new(move |_point| {
+            clicked_clone.set(true);
+        })
+        .with_enabled(false),
+    )];
+    chain.update_from_slice(&elements, &mut context);
+
+    let mut node = chain.node_mut::<ClickableNode>(0).unwrap();
+    assert!(!node.hit_test(10.0, 20.0));
+
+    for kind in [PointerEventKind::Down, PointerEventKind::Up] {
+        let mut event =
+            PointerEvent::new(kind, Point { x: 10.0, y: 20.0 }, Point { x: 10.0, y: 20.0 });
+        event.buttons = PointerButtons::new().with(PointerButton::Primary);
+        assert!(!node.on_pointer_event(&mut context, &event));
+    }
+    assert!(!clicked.get());
+}
+
+#[test]
+fn disabled_clickable_does_not_show_ripple() {
+    let composition = crate::run_test_composition(|| {});
+    let runtime = composition.runtime_handle();
+
+    let modifier = Modifier::empty().clickable_enabled(false, |_| {});
+    let slices = collect_slices_from_modifier(&modifier);
+    let Some(DrawCommand::OverlayClipped(draw, None)) = slices.draw_commands().first().cloned()
+    else {
+        panic!("bounded ripple should draw as an overlay clipped to the bounds");
+    };
+    let handler = slices.pointer_inputs()[0].clone();
+
+    handler(PointerEvent::new(
+        PointerEventKind::Down,
+        Point { x: 10.0, y: 10.0 },
+        Point { x: 10.0, y: 10.0 },
+    ));
+    runtime.drain_frame_callbacks(16_000_000);
+    assert!(draw(Size {
+        width: 80.0,
+        height: 40.0,
+    })
+    .is_empty());
+}
+
+#[test]
+fn clickable_press_draws_ripple_that_grows_and_fades() {
+    // Rendering once registers the composition runtime as the current frame clock source
+    let composition = crate::run_test_composition(|| {});
+    let runtime = composition.runtime_handle();
+
+    let modifier = Modifier::empty().clickable(|_| {});
+    let slices = collect_slices_from_modifier(&modifier);
+    assert_eq!(slices.draw_commands().len(), 1);
+    let Some(DrawCommand::OverlayClipped(draw, None)) = slices.draw_commands().first().cloned()
+    else {
+        panic!("bounded ripple should draw as an overlay clipped to the bounds");
+    };
+    let handler = slices.pointer_inputs()[0].clone();
+    let size = Size {
+        width: 80.0,
+        height: 40.0,
+    };
+    let ripple_width = |size: Size| match draw(size).as_slice() {
+        [] => None,
+        [cranpose_ui_graphics::DrawPrimitive::RoundRect { rect, .. }] => Some(rect.width),
+        other => panic!("unexpected ripple primitives: {other:?}"),
+    };
+
+    handler(PointerEvent::new(
+        PointerEventKind::Down,
+        Point { x: 10.0, y: 10.0 },
+        Point { x: 10.0, y: 10.0 },
+    ));
+    runtime.drain_frame_callbacks(0);
+    runtime.drain_frame_callbacks(16_000_000);
+    let early = ripple_width(size).expect("ripple visible after press");
+    runtime.drain_frame_callbacks(64_000_000);
+    let later = ripple_width(size).expect("ripple still visible while pressed");
+    assert!(later > early);
+
+    handler(PointerEvent::new(
+        PointerEventKind::Up,
+        Point { x: 10.0, y: 10.0 },
+        Point { x: 10.0, y: 10.0 },
+    ));
+    for frame in 5..40 {
+        runtime.drain_frame_callbacks(frame * 16_000_000);
+    }
+    assert_eq!(ripple_width(size), None);
+    assert!(!runtime.has_frame_callbacks());
+}
+
+#[test]
+fn ripple_on_rounded_clickable_is_clipped_to_its_shape() {
+    let shape = RoundedCornerShape::uniform(12.0);
+    let modifier = Modifier::empty()
+        .rounded_corner_shape(shape)
+        .clickable_with_indication(Some(Ripple::bounded()), |_| {});
+    let slices = collect_slices_from_modifier(&modifier);
+    let clips: Vec<_> = slices
+        .draw_commands()
+        .iter()
+        .filter_map(|command| match command {
+            DrawCommand::OverlayClipped(_, clip) => Some(*clip),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(clips, vec![Some(shape)]);
+
+    // Unbounded ripples may draw outside the element
+    let modifier = Modifier::empty()
+        .rounded_corner_shape(shape)
+        .clickable_with_indication(Some(Ripple::unbounded()), |_| {});
+    let slices = collect_slices_from_modifier(&modifier);
+    assert!(slices
+        .draw_commands()
+        .iter()
+        .all(|command| !matches!(command, DrawCommand::OverlayClipped(..))));
+}
+
+#[test]
+fn clickable_without_indication_does_not_draw() {
+    let modifier = Modifier::empty().clickable_with_indication(None, |_| {});
+    let slices = collect_slices_from_modifier(&modifier);
+    assert!(slices.draw_commands().is_empty());
+    assert_eq!(slices.pointer_inputs().len(), 1);
+}
+
 #[test]
 fn alpha_node_clamps_values() {
     let mut chain = ModifierNodeChain::new();
@@ -347,6 +921,32 @@ fn alpha_node_is_draw_only() {
     assert!(!chain.has_nodes_for_invalidation(cranpose_foundation::InvalidationKind::Layout));
 }
 
+#[test]
+fn z_index_node_invalidates_draw_order_when_attached_or_changed() {
+    use cranpose_foundation::InvalidationKind;
+
+    let mut chain = ModifierNodeChain::new();
+    let mut context = BasicModifierNodeContext::new();
+    let draw_invalidated = |context: &BasicModifierNodeContext| {
+        context
+            .invalidations()
+            .iter()
+            .any(|invalidation| invalidation.kind() == InvalidationKind::Draw)
+    };
+
+    chain.update_from_slice(&[modifier_element(ZIndexElement::new(1.0))], &mut context);
+    assert!(draw_invalidated(&context));
+    assert!(!chain.has_nodes_for_invalidation(InvalidationKind::Layout));
+
+    context.clear_invalidations();
+    chain.update_from_slice(&[modifier_element(ZIndexElement::new(1.0))], &mut context);
+    assert!(!draw_invalidated(&context));
+
+    chain.update_from_slice(&[modifier_element(ZIndexElement::new(2.0))], &mut context);
+    assert!(draw_invalidated(&context));
+    assert_eq!(chain.node::<ZIndexNode>(0).unwrap().z_index(), 2.0);
+}
+
 #[test]
 fn mixed_modifier_chain_tracks_all_capabilities() {
     let mut chain = ModifierNodeChain::new();
@@ -424,7 +1024,7 @@ fn toggling_background_color_reuses_node() {
     // Verify color was updated
     {
         let node_ref = chain.node::<BackgroundNode>(0).unwrap();
-        assert_eq!(node_ref.color, blue);
+        assert_eq!(node_ref.brush(), &Brush::solid(blue));
     }
 }
 
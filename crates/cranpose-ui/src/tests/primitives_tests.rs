@@ -2,17 +2,20 @@ use super::*;
 use crate::composable;
 use crate::layout::LayoutBox;
 use crate::modifier::{Modifier, Size};
-use crate::subcompose_layout::{Constraints, SubcomposeLayoutNode};
+use crate::renderer::{HeadlessRenderer, PaintLayer};
+use crate::subcompose_layout::{Constraints, MeasureResult, Placement, SubcomposeLayoutNode};
 use crate::widgets::nodes::LayoutNode;
 use crate::widgets::{
-    BoxWithConstraints, Column, ColumnSpec, DynamicTextSource, Row, RowSpec, Spacer, Text,
+    BasicText, BoxWithConstraints, Column, ColumnSpec, DynamicTextSource, Row, RowSpec, Spacer,
+    Text,
 };
-use crate::{run_test_composition, LayoutEngine};
+use crate::{run_test_composition, LayoutEngine, Sp, TextDecoration, TextStyle};
 use cranpose_core::{
     self, location_key, Applier, Composer, Composition, ConcreteApplierHost, MemoryApplier, NodeId,
     Phase, SlotBackend, SlotStorage, SlotsHost, SnapshotStateObserver, State,
 };
-use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement, VerticalAlignment};
+use cranpose_ui_graphics::{DrawPrimitive, Rect};
+use cranpose_ui_layout::{Alignment, HorizontalAlignment, LinearArrangement, VerticalAlignment};
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
@@ -176,10 +179,10 @@ fn layout_column_produces_expected_measurements() {
                 move || {
                     let id = Text("Hello", Modifier::empty());
                     *text_id_capture.borrow_mut() = Some(id);
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 0.0,
                         height: 30.0,
-                    });
+                    }));
                 },
             );
         })
@@ -260,6 +263,61 @@ fn modifier_offset_translates_layout() {
     assert!((text_layout.rect.y - 17.5).abs() < 1e-3);
 }
 
+/// Lays out a Column of two 20-high spacers, the first carrying `modifier`.
+///
+/// Returns the Column's height and the y positions of both spacers.
+fn column_with_offset_child(modifier: Modifier) -> (f32, f32, f32) {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, move || {
+            let modifier = modifier.clone();
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                Spacer(modifier.clone().size(Size::new(10.0, 20.0)));
+                Spacer(Modifier::empty().size(Size::new(10.0, 20.0)));
+            });
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(root, Size::new(200.0, 200.0))
+        .expect("compute layout");
+    let column = layout_tree.root();
+    (
+        column.rect.height,
+        column.children[0].rect.y,
+        column.children[1].rect.y,
+    )
+}
+
+#[test]
+fn visual_offset_leaves_layout_untouched() {
+    let (plain_height, _, plain_sibling_y) = column_with_offset_child(Modifier::empty());
+
+    let (height, offset_y, sibling_y) =
+        column_with_offset_child(Modifier::empty().offset(0.0, 30.0));
+    assert!((offset_y - 30.0).abs() < 1e-3);
+    assert_eq!(height, plain_height);
+    assert_eq!(sibling_y, plain_sibling_y);
+
+    let (height, visual_y, sibling_y) =
+        column_with_offset_child(Modifier::empty().offset_visual(0.0, 30.0));
+    assert_eq!(visual_y, 0.0);
+    assert_eq!(height, plain_height);
+    assert_eq!(sibling_y, plain_sibling_y);
+
+    let slices = crate::modifier::collect_slices_from_modifier(
+        &Modifier::empty().alpha(0.5).offset_visual(0.0, 30.0),
+    );
+    let layer = slices
+        .graphics_layer()
+        .expect("visual offset graphics layer");
+    assert_eq!((layer.translation_x, layer.translation_y), (0.0, 30.0));
+    assert_eq!(layer.alpha, 0.5);
+}
+
 #[test]
 fn box_with_constraints_composes_different_content() {
     let mut composition = Composition::new(MemoryApplier::new());
@@ -660,14 +718,14 @@ fn test_fill_max_width_should_not_propagate_to_wrapping_parent() {
                                 RowSpec::default(),
                                 move || {
                                     // Fixed width content: 100px + 100px = 200px
-                                    Spacer(Size {
+                                    Spacer(Modifier::empty().size(Size {
                                         width: 100.0,
                                         height: 20.0,
-                                    });
-                                    Spacer(Size {
+                                    }));
+                                    Spacer(Modifier::empty().size(Size {
                                         width: 100.0,
                                         height: 20.0,
-                                    });
+                                    }));
                                 },
                             ));
                         },
@@ -782,14 +840,16 @@ fn wrap_column_with_fill_child_uses_content_width() {
                         Modifier::empty().fill_max_width(),
                         RowSpec::default(),
                         move || {
-                            *first_inner.borrow_mut() = Some(Spacer(Size {
-                                width: 80.0,
-                                height: 24.0,
-                            }));
-                            *second_inner.borrow_mut() = Some(Spacer(Size {
-                                width: 40.0,
-                                height: 24.0,
-                            }));
+                            *first_inner.borrow_mut() =
+                                Some(Spacer(Modifier::empty().size(Size {
+                                    width: 80.0,
+                                    height: 24.0,
+                                })));
+                            *second_inner.borrow_mut() =
+                                Some(Spacer(Modifier::empty().size(Size {
+                                    width: 40.0,
+                                    height: 24.0,
+                                })));
                         },
                     ));
                 },
@@ -904,14 +964,14 @@ fn fill_child_respects_explicit_parent_width() {
                         Modifier::empty().fill_max_width(),
                         RowSpec::default(),
                         move || {
-                            Spacer(Size {
+                            Spacer(Modifier::empty().size(Size {
                                 width: 60.0,
                                 height: 32.0,
-                            });
-                            Spacer(Size {
+                            }));
+                            Spacer(Modifier::empty().size(Size {
                                 width: 40.0,
                                 height: 32.0,
-                            });
+                            }));
                         },
                     ));
                 },
@@ -1002,10 +1062,10 @@ fn fill_max_height_child_clamps_to_parent() {
                         Modifier::empty().fill_max_height(),
                         ColumnSpec::default(),
                         move || {
-                            *leaf_inner.borrow_mut() = Some(Spacer(Size {
+                            *leaf_inner.borrow_mut() = Some(Spacer(Modifier::empty().size(Size {
                                 width: 60.0,
                                 height: 40.0,
-                            }));
+                            })));
                         },
                     ));
                 },
@@ -1072,6 +1132,213 @@ fn fill_max_height_child_clamps_to_parent() {
     );
 }
 
+#[test]
+fn row_with_min_intrinsic_height_sizes_to_tallest_child() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+
+    let row_id: Rc<RefCell<Option<NodeId>>> = Rc::new(RefCell::new(None));
+    let column_ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let row_capture = Rc::clone(&row_id);
+    let columns_capture = Rc::clone(&column_ids);
+
+    composition
+        .render(key, move || {
+            let columns_capture = Rc::clone(&columns_capture);
+            *row_capture.borrow_mut() = Some(Row(
+                Modifier::empty().height_intrinsic(cranpose_ui_layout::IntrinsicSize::Min),
+                RowSpec::default(),
+                move || {
+                    for height in [30.0, 70.0] {
+                        let id = Column(
+                            Modifier::empty().fill_max_height(),
+                            ColumnSpec::default(),
+                            move || {
+                                Spacer(Modifier::empty().size(Size {
+                                    width: 20.0,
+                                    height,
+                                }));
+                            },
+                        );
+                        columns_capture.borrow_mut().push(id);
+                    }
+                },
+            ));
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 400.0,
+                height: 400.0,
+            },
+        )
+        .expect("compute layout");
+
+    let row_layout =
+        find_node_layout(layout_tree.root(), row_id.borrow().expect("row id")).expect("row layout");
+    assert_eq!(row_layout.rect.height, 70.0);
+    for &column in column_ids.borrow().iter() {
+        let column_layout = find_node_layout(layout_tree.root(), column).expect("column layout");
+        assert_eq!(column_layout.rect.height, 70.0);
+    }
+}
+
+#[test]
+fn row_aligns_children_by_text_baseline() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+
+    let text_ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let texts_capture = Rc::clone(&text_ids);
+
+    composition
+        .render(key, move || {
+            let texts_capture = Rc::clone(&texts_capture);
+            Row(Modifier::empty(), RowSpec::default(), move || {
+                let padded = Text(
+                    "Small",
+                    Modifier::empty()
+                        .padding_each(0.0, 12.0, 0.0, 0.0)
+                        .align_by_baseline(),
+                );
+                let two_lines = Text("Two\nlines", Modifier::empty().align_by_baseline());
+                texts_capture.borrow_mut().extend([padded, two_lines]);
+            });
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 400.0,
+                height: 400.0,
+            },
+        )
+        .expect("compute layout");
+
+    let ids = text_ids.borrow();
+    let padded = find_node_layout(layout_tree.root(), ids[0]).expect("padded text layout");
+    let two_lines = find_node_layout(layout_tree.root(), ids[1]).expect("two-line text layout");
+    assert_ne!(padded.rect.height, two_lines.rect.height);
+
+    // The monospaced test measurer puts the first baseline 15 below the top of the text
+    let padded_baseline = padded.rect.y + 12.0 + 15.0;
+    let two_lines_baseline = two_lines.rect.y + 15.0;
+    assert!((padded_baseline - two_lines_baseline).abs() < 1e-3);
+    assert!((two_lines.rect.y - 12.0).abs() < 1e-3);
+    assert!((layout_tree.root().rect.height - 52.0).abs() < 1e-3);
+}
+
+/// Size of a two-line [`BasicText`] laid out with `style`.
+fn two_line_text_size(style: TextStyle) -> Size {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, move || {
+            BasicText("First\nSecond", Modifier::empty(), style.clone());
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 400.0,
+                height: 400.0,
+            },
+        )
+        .expect("compute layout");
+    let rect = layout_tree.root().rect;
+    Size {
+        width: rect.width,
+        height: rect.height,
+    }
+}
+
+#[test]
+fn text_line_height_scales_multiline_height() {
+    let with_line_height = |line_height: f32| TextStyle {
+        line_height: Some(Sp(line_height)),
+        ..TextStyle::default()
+    };
+
+    let default = two_line_text_size(TextStyle::default());
+    let regular = two_line_text_size(with_line_height(20.0));
+    let doubled = two_line_text_size(with_line_height(40.0));
+
+    assert_eq!(regular, default);
+    assert!((doubled.height - 2.0 * regular.height).abs() < 1e-3);
+    assert_eq!(doubled.width, regular.width);
+}
+
+#[test]
+fn text_letter_spacing_widens_text() {
+    let plain = two_line_text_size(TextStyle::default());
+    let spaced = two_line_text_size(TextStyle {
+        letter_spacing: Some(Sp(2.0)),
+        ..TextStyle::default()
+    });
+
+    // The widest line, "Second", gains spacing after each of its six characters
+    assert!((spaced.width - plain.width - 12.0).abs() < 1e-3);
+    assert_eq!(spaced.height, plain.height);
+}
+
+#[test]
+fn underlined_text_draws_a_line_below_its_baseline() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let style = TextStyle {
+        text_decoration: Some(TextDecoration::Underline),
+        ..TextStyle::default()
+    };
+    composition
+        .render(key, move || {
+            BasicText("Hello", Modifier::empty().padding(10.0), style.clone());
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 400.0,
+                height: 400.0,
+            },
+        )
+        .expect("compute layout");
+    let scene = HeadlessRenderer::new().render(&layout_tree);
+    let lines: Vec<Rect> = scene
+        .primitives_for(PaintLayer::Overlay)
+        .filter_map(|primitive| match primitive {
+            DrawPrimitive::Rect { rect, .. } => Some(*rect),
+            _ => None,
+        })
+        .collect();
+
+    let metrics = crate::text::measure_text("Hello");
+    let [line] = lines.as_slice() else {
+        panic!("expected one underline, got {lines:?}");
+    };
+    assert_eq!(line.x, 10.0);
+    assert_eq!(line.width, metrics.width);
+    assert!(line.y > 10.0 + metrics.first_baseline);
+    assert!(line.y + line.height <= 10.0 + metrics.height);
+}
+
 #[test]
 fn modifier_chain_text_with_padding() {
     // Verify that text with padding modifier measures correctly
@@ -1137,10 +1404,10 @@ fn modifier_chain_size_enforcement() {
                 }),
                 BoxSpec::default(),
                 || {
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 100.0,
                         height: 100.0,
-                    });
+                    }));
                 },
             ));
         })
@@ -1195,10 +1462,10 @@ fn modifier_chain_padding_then_size() {
                 }),
                 BoxSpec::default(),
                 || {
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 200.0,
                         height: 150.0,
-                    });
+                    }));
                 },
             ));
         })
@@ -1255,10 +1522,10 @@ fn modifier_chain_size_then_padding() {
                     .padding(10.0),
                 BoxSpec::default(),
                 || {
-                    Spacer(Size {
+                    Spacer(Modifier::empty().size(Size {
                         width: 200.0,
                         height: 150.0,
-                    });
+                    }));
                 },
             ));
         })
@@ -1293,6 +1560,323 @@ fn modifier_chain_size_then_padding() {
     );
 }
 
+#[test]
+fn weighted_spacer_pushes_trailing_text_to_row_end() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_render = Rc::clone(&ids);
+
+    composition
+        .render(key, move || {
+            let ids_render = Rc::clone(&ids_render);
+            Row(
+                Modifier::empty().width(200.0),
+                RowSpec::default(),
+                move || {
+                    let mut ids = ids_render.borrow_mut();
+                    ids.clear();
+                    ids.push(Text("Left", Modifier::empty()));
+                    ids.push(Spacer(Modifier::empty().weight(1.0)));
+                    ids.push(Text("Right", Modifier::empty()));
+                },
+            );
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+
+    let ids = ids.borrow();
+    let left = find_node_layout(layout_tree.root(), ids[0]).expect("left layout");
+    let spacer = find_node_layout(layout_tree.root(), ids[1]).expect("spacer layout");
+    let right = find_node_layout(layout_tree.root(), ids[2]).expect("right layout");
+
+    assert_eq!(left.rect.x, 0.0);
+    assert_eq!(right.rect.x + right.rect.width, 200.0);
+    assert_eq!(spacer.rect.x, left.rect.width);
+    assert_eq!(
+        spacer.rect.width,
+        200.0 - left.rect.width - right.rect.width
+    );
+    assert_eq!(spacer.rect.height, 0.0);
+}
+
+/// Lays out a 20x20 child inside a 100x100 box and returns the child's rect.
+fn aligned_child_rect(content_alignment: Alignment, child_modifier: Modifier) -> LayoutBox {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+
+    let child_id: Rc<RefCell<Option<NodeId>>> = Rc::new(RefCell::new(None));
+    let child_id_render = Rc::clone(&child_id);
+
+    composition
+        .render(key, move || {
+            let child_modifier = child_modifier.clone();
+            let child_id_render = Rc::clone(&child_id_render);
+            Box(
+                Modifier::empty().size(Size {
+                    width: 100.0,
+                    height: 100.0,
+                }),
+                BoxSpec::default().content_alignment(content_alignment),
+                move || {
+                    *child_id_render.borrow_mut() = Some(Box(
+                        child_modifier.clone().size(Size {
+                            width: 20.0,
+                            height: 20.0,
+                        }),
+                        BoxSpec::default(),
+                        || {},
+                    ));
+                },
+            );
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+
+    let child_node_id = child_id.borrow().expect("child node id");
+    find_node_layout(layout_tree.root(), child_node_id).expect("child layout")
+}
+
+#[test]
+fn box_child_align_overrides_content_alignment() {
+    let child = aligned_child_rect(
+        Alignment::TOP_START,
+        Modifier::empty().align(Alignment::BOTTOM_END),
+    );
+    assert_eq!((child.rect.x, child.rect.y), (80.0, 80.0));
+}
+
+#[test]
+fn box_content_alignment_applies_without_child_align() {
+    let child = aligned_child_rect(Alignment::CENTER, Modifier::empty());
+    assert_eq!((child.rect.x, child.rect.y), (40.0, 40.0));
+}
+
+#[test]
+fn match_parent_size_child_fills_the_box_without_growing_it() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_render = Rc::clone(&ids);
+
+    composition
+        .render(key, move || {
+            let ids_render = Rc::clone(&ids_render);
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                let children_ids = Rc::clone(&ids_render);
+                let stack = Box(Modifier::empty(), BoxSpec::default(), move || {
+                    let content = Box(
+                        Modifier::empty().size(Size {
+                            width: 60.0,
+                            height: 40.0,
+                        }),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                    // Asks for more than the box; must not grow it
+                    let overlay = Box(
+                        Modifier::empty().match_parent_size().size(Size {
+                            width: 200.0,
+                            height: 200.0,
+                        }),
+                        BoxSpec::default(),
+                        || {},
+                    );
+                    children_ids.borrow_mut().extend([content, overlay]);
+                });
+                ids_render.borrow_mut().push(stack);
+            });
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+
+    let ids = ids.borrow();
+    let overlay = find_node_layout(layout_tree.root(), ids[1]).expect("overlay layout");
+    let stack = find_node_layout(layout_tree.root(), ids[2]).expect("box layout");
+
+    assert_eq!((stack.rect.width, stack.rect.height), (60.0, 40.0));
+    assert_eq!(
+        (
+            overlay.rect.x,
+            overlay.rect.y,
+            overlay.rect.width,
+            overlay.rect.height
+        ),
+        (0.0, 0.0, 60.0, 40.0)
+    );
+}
+
+/// Places each child at the bottom-right corner of the previous one.
+fn diagonal_policy() -> FnMeasurePolicy {
+    FnMeasurePolicy::new(|measurables, constraints| {
+        let mut offset = Size::default();
+        let mut placements = Vec::new();
+        for measurable in measurables {
+            let placeable = measurable.measure(constraints.loosen());
+            placements.push(Placement::new(
+                placeable.node_id(),
+                offset.width,
+                offset.height,
+                0,
+            ));
+            offset.width += placeable.width();
+            offset.height += placeable.height();
+        }
+        MeasureResult::new(offset, placements)
+    })
+}
+
+#[test]
+fn custom_layout_places_children_diagonally() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_render = Rc::clone(&ids);
+
+    composition
+        .render(key, move || {
+            let ids_render = Rc::clone(&ids_render);
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                let children_ids = Rc::clone(&ids_render);
+                let layout = Layout(
+                    Modifier::empty().width_intrinsic(cranpose_ui_layout::IntrinsicSize::Max),
+                    diagonal_policy(),
+                    move || {
+                        for (width, height) in [(60.0, 40.0), (30.0, 20.0), (10.0, 50.0)] {
+                            let child = Box(
+                                Modifier::empty().size(Size { width, height }),
+                                BoxSpec::default(),
+                                || {},
+                            );
+                            children_ids.borrow_mut().push(child);
+                        }
+                    },
+                );
+                ids_render.borrow_mut().push(layout);
+            });
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+
+    let ids = ids.borrow();
+    let rect = |id: NodeId| {
+        let rect = find_node_layout(layout_tree.root(), id)
+            .expect("node layout")
+            .rect;
+        (rect.x, rect.y, rect.width, rect.height)
+    };
+    assert_eq!(rect(ids[0]), (0.0, 0.0, 60.0, 40.0));
+    assert_eq!(rect(ids[1]), (60.0, 40.0, 30.0, 20.0));
+    assert_eq!(rect(ids[2]), (90.0, 60.0, 10.0, 50.0));
+    // The intrinsic width runs the same policy over the children's intrinsic widths
+    assert_eq!(rect(ids[3]), (0.0, 0.0, 100.0, 110.0));
+}
+
+#[test]
+fn button_has_minimum_size_unless_sized_explicitly() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+
+    let ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+    let ids_render = Rc::clone(&ids);
+
+    composition
+        .render(key, move || {
+            let ids_render = Rc::clone(&ids_render);
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                let small = crate::widgets::Button(
+                    Modifier::empty(),
+                    || {},
+                    || {
+                        Text("x", Modifier::empty());
+                    },
+                );
+                let sized = crate::widgets::Button(
+                    Modifier::empty().size(Size {
+                        width: 120.0,
+                        height: 60.0,
+                    }),
+                    || {},
+                    || {
+                        Text("x", Modifier::empty());
+                    },
+                );
+                ids_render.borrow_mut().extend([small, sized]);
+            });
+        })
+        .expect("render");
+
+    let root = composition.root().expect("root node");
+    let layout_tree = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 640.0,
+                height: 480.0,
+            },
+        )
+        .expect("compute layout");
+
+    let ids = ids.borrow();
+    let size = |id: NodeId| {
+        let rect = find_node_layout(layout_tree.root(), id)
+            .expect("node layout")
+            .rect;
+        (rect.width, rect.height)
+    };
+    let min = crate::widgets::BUTTON_MIN_SIZE;
+    assert_eq!(size(ids[0]), (min, min));
+    assert_eq!(size(ids[1]), (120.0, 60.0));
+}
+
 fn find_node_layout(tree: &LayoutBox, target: NodeId) -> Option<LayoutBox> {
     if tree.node_id == target {
         return Some(tree.clone());
@@ -90,6 +90,70 @@ fn renderer_honors_resolved_background_shape() {
     );
 }
 
+#[test]
+fn gradient_background_emits_its_stops() {
+    let stops = vec![Color(1.0, 0.0, 0.0, 1.0), Color(0.0, 0.0, 1.0, 1.0)];
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, {
+            let stops = stops.clone();
+            move || {
+                Text(
+                    "Gradient".to_string(),
+                    Modifier::empty().background(Brush::linear_gradient(stops.clone())),
+                );
+            }
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("text root");
+    let layout = compute_layout(&mut composition, root);
+    let renderer = HeadlessRenderer::new();
+    let scene = renderer.render(&layout);
+
+    match &scene.operations()[0] {
+        RenderOp::Primitive {
+            primitive: DrawPrimitive::Rect { brush, .. },
+            layer: PaintLayer::Behind,
+            ..
+        } => assert_eq!(brush, &Brush::linear_gradient(stops)),
+        other => panic!("expected gradient background primitive, got {other:?}"),
+    }
+}
+
+#[test]
+fn rounded_corners_each_keeps_bottom_corners_square() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, || {
+            Text(
+                "Sheet".to_string(),
+                Modifier::empty()
+                    .background(Color(0.5, 0.2, 0.2, 1.0))
+                    .rounded_corners_each(6.0, 4.0, 0.0, 0.0),
+            );
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("text root");
+    let layout = compute_layout(&mut composition, root);
+    let renderer = HeadlessRenderer::new();
+    let scene = renderer.render(&layout);
+
+    match &scene.operations()[0] {
+        RenderOp::Primitive {
+            primitive: DrawPrimitive::RoundRect { radii, .. },
+            ..
+        } => {
+            assert_eq!((radii.top_left, radii.top_right), (6.0, 4.0));
+            assert_eq!((radii.bottom_right, radii.bottom_left), (0.0, 0.0));
+        }
+        other => panic!("expected rounded rect background primitive, got {other:?}"),
+    }
+}
+
 #[test]
 fn renderer_translates_draw_commands() {
     let mut composition = Composition::new(MemoryApplier::new());
@@ -135,7 +199,9 @@ fn renderer_translates_draw_commands() {
                     saw_translated = true;
                 }
             }
-            DrawPrimitive::RoundRect { rect, .. } => {
+            DrawPrimitive::RoundRect { rect, .. }
+            | DrawPrimitive::Arc { rect, .. }
+            | DrawPrimitive::Line { rect, .. } => {
                 if rect.x >= 10.0 && rect.y >= 10.0 {
                     saw_translated = true;
                 }
@@ -163,7 +229,10 @@ fn renderer_translates_draw_commands() {
     assert_eq!(overlay_ops.len(), 1);
     if let RenderOp::Primitive { primitive, .. } = overlay_ops[0] {
         match primitive {
-            DrawPrimitive::Rect { rect, .. } | DrawPrimitive::RoundRect { rect, .. } => {
+            DrawPrimitive::Rect { rect, .. }
+            | DrawPrimitive::RoundRect { rect, .. }
+            | DrawPrimitive::Arc { rect, .. }
+            | DrawPrimitive::Line { rect, .. } => {
                 assert!(rect.x >= 10.0);
                 assert!(rect.y >= 10.0);
             }
@@ -209,3 +278,209 @@ fn renderer_renders_subcompose_background() {
         .iter()
         .any(|op| matches!(op, RenderOp::Text { value, .. } if value == "Subcomposed")));
 }
+
+#[test]
+fn renderer_draws_siblings_in_z_index_order() {
+    let front = Color(1.0, 0.0, 0.0, 1.0);
+    let back = Color(0.0, 0.0, 1.0, 1.0);
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, move || {
+            Column(Modifier::empty(), ColumnSpec::default(), move || {
+                Text(
+                    "Front".to_string(),
+                    Modifier::empty().background(front).z_index(1.0),
+                );
+                Text("Back".to_string(), Modifier::empty().background(back));
+            });
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("column root");
+    let layout = compute_layout(&mut composition, root);
+    let renderer = HeadlessRenderer::new();
+
+    let background_colors = |scene: &RecordedRenderScene| -> Vec<Color> {
+        scene
+            .primitives_for(PaintLayer::Behind)
+            .filter_map(|primitive| match primitive {
+                DrawPrimitive::Rect {
+                    brush: Brush::Solid(color),
+                    ..
+                } => Some(*color),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let scene = renderer.render(&layout);
+    assert_eq!(background_colors(&scene), vec![back, front]);
+
+    let scene = renderer.render_from_applier(&mut composition.applier_mut(), root);
+    assert_eq!(background_colors(&scene), vec![back, front]);
+}
+
+#[test]
+fn divider_emits_thin_rect_spanning_width() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let color = Color(0.5, 0.5, 0.5, 1.0);
+    composition
+        .render(key, move || {
+            Column(
+                Modifier::empty().fill_max_width(),
+                ColumnSpec::default(),
+                move || {
+                    Text("Above".to_string(), Modifier::empty());
+                    crate::Divider(Modifier::empty(), 2.0, color);
+                },
+            );
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("column root");
+    let layout = compute_layout(&mut composition, root);
+    let renderer = HeadlessRenderer::new();
+    let scene = renderer.render(&layout);
+
+    let lines: Vec<_> = scene.primitives_for(PaintLayer::Behind).collect();
+    match lines.as_slice() {
+        [DrawPrimitive::Rect { rect, brush }] => {
+            assert_eq!((rect.x, rect.width, rect.height), (0.0, 200.0, 2.0));
+            assert!(rect.y > 0.0, "divider should sit below the text");
+            assert_eq!(*brush, Brush::solid(color));
+        }
+        other => panic!("expected a single divider rect, got {other:?}"),
+    }
+}
+
+#[test]
+fn diagonal_line_is_one_line_primitive() {
+    use cranpose_ui_graphics::{DrawScope as _, DrawScopeDefault};
+
+    let mut scope = DrawScopeDefault::new(Size {
+        width: 100.0,
+        height: 100.0,
+    });
+    scope.draw_line(
+        crate::modifier::Point::new(10.0, 10.0),
+        crate::modifier::Point::new(40.0, 50.0),
+        2.0,
+        Brush::solid(Color(0.0, 0.0, 0.0, 1.0)),
+    );
+
+    match scope.into_primitives().as_slice() {
+        [DrawPrimitive::Line { rect, line, .. }] => {
+            // Direction (0.6, 0.8): the stroke reaches 0.8 beyond the ends in x, 0.6 in y
+            assert!((rect.x - 9.2).abs() < 1e-4);
+            assert!((rect.y - 9.4).abs() < 1e-4);
+            assert!((rect.width - 31.6).abs() < 1e-4);
+            assert!((rect.height - 41.2).abs() < 1e-4);
+            assert!((rect.x + line.start.x - 10.0).abs() < 1e-4);
+            assert!((rect.y + line.end.y - 50.0).abs() < 1e-4);
+            assert_eq!(line.stroke_width, 2.0);
+            // On the stroke near the middle, off it at the bounds' corner
+            assert!(line.contains(*rect, 25.5, 30.0));
+            assert!(!line.contains(*rect, rect.x + 0.5, rect.y + rect.height - 0.5));
+        }
+        other => panic!("expected a single line primitive, got {other:?}"),
+    }
+}
+
+#[test]
+fn circular_progress_sweep_matches_progress() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let color = Color(0.2, 0.4, 0.9, 1.0);
+    composition
+        .render(key, move || {
+            crate::CircularProgressIndicator(0.25, Modifier::empty(), color, 4.0);
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("indicator root");
+    let layout = compute_layout(&mut composition, root);
+    let renderer = HeadlessRenderer::new();
+    let scene = renderer.render(&layout);
+
+    let arcs: Vec<_> = scene.primitives_for(PaintLayer::Behind).collect();
+    match arcs.as_slice() {
+        [DrawPrimitive::Arc { rect, brush, arc }] => {
+            assert_eq!((rect.width, rect.height), (40.0, 40.0));
+            assert_eq!(arc.start_angle, -90.0);
+            assert_eq!(arc.sweep_angle, 90.0);
+            assert_eq!(arc.stroke_width, 4.0);
+            assert_eq!(*brush, Brush::solid(color));
+            // Top-right quadrant of the ring is filled, top-left is not
+            assert!(arc.contains(*rect, 30.0, 5.0));
+            assert!(!arc.contains(*rect, 10.0, 5.0));
+        }
+        other => panic!("expected a single arc, got {other:?}"),
+    }
+}
+
+#[test]
+fn alpha_modifier_halves_child_background_alpha() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    composition
+        .render(key, || {
+            Column(Modifier::empty().alpha(0.5), ColumnSpec::default(), || {
+                Column(
+                    Modifier::empty()
+                        .size_points(20.0, 20.0)
+                        .background(Color(1.0, 0.0, 0.0, 0.8)),
+                    ColumnSpec::default(),
+                    || {},
+                );
+            });
+        })
+        .expect("initial render");
+
+    let root = composition.root().expect("column root");
+    let layout = compute_layout(&mut composition, root);
+    let scene = HeadlessRenderer::new().render(&layout);
+
+    let behind: Vec<_> = scene.primitives_for(PaintLayer::Behind).collect();
+    match behind.as_slice() {
+        [DrawPrimitive::Rect { brush, .. }] => {
+            assert_eq!(*brush, Brush::solid(Color(1.0, 0.0, 0.0, 0.4)));
+        }
+        other => panic!("expected a single background rect, got {other:?}"),
+    }
+}
+
+#[test]
+fn graphics_layer_shadow_draws_behind_background() {
+    let layer = crate::modifier::GraphicsLayer {
+        clip: true,
+        shadow_elevation: 8.0,
+        ..Default::default()
+    };
+    let slices = crate::modifier::collect_slices_from_modifier(
+        &Modifier::empty()
+            .graphics_layer(layer)
+            .background(Color(1.0, 1.0, 1.0, 1.0)),
+    );
+    assert!(slices.clip_to_bounds());
+
+    let primitives = crate::draw::execute_draw_commands(
+        slices.draw_commands(),
+        Size {
+            width: 50.0,
+            height: 50.0,
+        },
+    );
+    let (background, shadow) = primitives.split_last().expect("background");
+    assert!(matches!(background, DrawPrimitive::Rect { rect, .. } if rect.width == 50.0));
+    assert!(!shadow.is_empty());
+    for primitive in shadow {
+        match primitive {
+            DrawPrimitive::Rect { rect, .. } => {
+                assert!(rect.width > 50.0 && rect.y + rect.height > 50.0);
+            }
+            other => panic!("unexpected shadow primitive {other:?}"),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 use cranpose_core::{
@@ -207,6 +207,32 @@ fn handle_reports_modifier_capabilities() {
     assert!(!handle.has_draw_modifier_nodes());
 }
 
+#[test]
+fn modifier_changes_queue_draw_and_layout_repasses() {
+    use cranpose_core::Node;
+
+    let policy: Rc<MeasurePolicy> =
+        Rc::new(|scope, _constraints| scope.layout(0.0, 0.0, Vec::new()));
+    let mut node = SubcomposeLayoutNode::new(
+        crate::modifier::Modifier::empty().background(crate::modifier::Color(0.1, 0.2, 0.3, 1.0)),
+        Rc::clone(&policy),
+    );
+    node.set_node_id(7);
+    crate::take_draw_repass_nodes();
+    crate::take_layout_repass_nodes();
+    crate::take_render_invalidation();
+
+    node.set_modifier(
+        crate::modifier::Modifier::empty().background(crate::modifier::Color(0.9, 0.2, 0.3, 1.0)),
+    );
+    assert!(node.needs_redraw());
+    assert!(crate::take_draw_repass_nodes().contains(&7));
+    assert!(crate::take_render_invalidation());
+
+    node.set_modifier(crate::modifier::Modifier::empty().padding(4.0));
+    assert!(crate::take_layout_repass_nodes().contains(&7));
+}
+
 #[test]
 fn inactive_slots_move_to_reusable_pool() {
     let (handle, _composition) = runtime_handle();
@@ -256,3 +282,76 @@ fn inactive_slots_move_to_reusable_pool() {
         assert!(!typed.state().reusable().is_empty());
     }
 }
+
+struct ComposedItem {
+    slot: u64,
+    node: cranpose_core::NodeId,
+    remembered: u64,
+    reused: bool,
+}
+
+#[test]
+fn scrolled_in_slot_recycles_nodes_of_matching_content_type() {
+    let (handle, _composition) = runtime_handle();
+    let mut slots = SlotBackend::default();
+    let mut applier = cranpose_core::MemoryApplier::new();
+    let visible = MutableState::with_runtime(vec![1u64, 2, 3], handle.clone());
+    let recorded: Rc<RefCell<Vec<ComposedItem>>> = Rc::new(RefCell::new(Vec::new()));
+    let recorded_capture = Rc::clone(&recorded);
+    let policy: Rc<MeasurePolicy> = Rc::new(move |scope, _constraints| {
+        for slot in visible.value() {
+            let slot_id = SlotId::new(slot);
+            scope.register_content_type(slot_id, 7);
+            let remembered = Rc::new(Cell::new(0));
+            let remembered_capture = Rc::clone(&remembered);
+            let measurables = scope.subcompose(slot_id, move || {
+                cranpose_core::with_current_composer(|composer| {
+                    let index = composer.use_value_slot(|| slot);
+                    remembered_capture.set(composer.with_slot_value::<u64, _>(index, |v| *v));
+                    composer.emit_node(|| DummyNode);
+                });
+            });
+            let reused = scope.was_last_slot_reused().unwrap_or(false);
+            for measurable in measurables {
+                recorded_capture.borrow_mut().push(ComposedItem {
+                    slot,
+                    node: measurable.node_id(),
+                    remembered: remembered.get(),
+                    reused,
+                });
+            }
+        }
+        scope.layout(0.0, 0.0, Vec::new())
+    });
+    let node_id = applier.create(Box::new(SubcomposeLayoutNode::with_content_type_policy(
+        crate::modifier::Modifier::empty(),
+        Rc::clone(&policy),
+    )));
+
+    // Scroll slot 1 out of view, then scroll slot 4 in
+    for window in [vec![1, 2, 3], vec![2, 3], vec![2, 3, 4]] {
+        visible.set(window);
+        slots.reset();
+        let _ = measure_once(
+            &mut slots,
+            &mut applier,
+            &handle,
+            node_id,
+            Constraints::loose(50.0, 50.0),
+        );
+    }
+
+    let recorded = recorded.borrow();
+    let first = recorded.iter().find(|item| item.slot == 1).expect("slot 1");
+    let scrolled_in = recorded.iter().find(|item| item.slot == 4).expect("slot 4");
+    assert_eq!(
+        scrolled_in.node, first.node,
+        "slot 4 should recycle slot 1's node"
+    );
+    assert!(
+        scrolled_in.reused,
+        "recycled slot should be reported as reused"
+    );
+    // Remembered state is reset rather than carried over from slot 1
+    assert_eq!(scrolled_in.remembered, 4);
+}
@@ -0,0 +1,109 @@
+use super::*;
+use cranpose_core::NodeId;
+use cranpose_ui_layout::Placeable;
+
+/// Text has no wrapped content, so the measurable is never consulted.
+struct EmptyMeasurable;
+
+struct EmptyPlaceable;
+
+impl Placeable for EmptyPlaceable {
+    fn place(&self, _x: f32, _y: f32) {}
+
+    fn width(&self) -> f32 {
+        0.0
+    }
+
+    fn height(&self) -> f32 {
+        0.0
+    }
+
+    fn node_id(&self) -> NodeId {
+        0
+    }
+}
+
+impl Measurable for EmptyMeasurable {
+    fn measure(&self, _constraints: Constraints) -> Box<dyn Placeable> {
+        Box::new(EmptyPlaceable)
+    }
+
+    fn min_intrinsic_width(&self, _height: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_width(&self, _height: f32) -> f32 {
+        0.0
+    }
+
+    fn min_intrinsic_height(&self, _width: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_height(&self, _width: f32) -> f32 {
+        0.0
+    }
+}
+
+const LONG_TEXT: &str = "the quick brown fox jumps over the lazy dog again and again";
+
+#[test]
+fn intrinsic_height_grows_as_the_width_shrinks() {
+    let node = TextModifierElement::new(Rc::from(LONG_TEXT)).create();
+
+    let narrow = node.max_intrinsic_height(&EmptyMeasurable, 100.0);
+    let wide = node.max_intrinsic_height(&EmptyMeasurable, 300.0);
+    assert!(narrow > wide, "narrow {narrow} should exceed wide {wide}");
+    assert_eq!(node.min_intrinsic_height(&EmptyMeasurable, 100.0), narrow);
+
+    let proxy = node.create_measurement_proxy().expect("text proxy");
+    assert_eq!(
+        proxy.max_intrinsic_height_proxy(&EmptyMeasurable, 100.0),
+        narrow
+    );
+}
+
+#[test]
+fn intrinsic_height_is_capped_by_max_lines() {
+    let node = TextModifierElement::new(Rc::from(LONG_TEXT))
+        .with_max_lines(2)
+        .create();
+    let line_height = crate::text::measure_text(LONG_TEXT).line_height;
+
+    assert_eq!(
+        node.max_intrinsic_height(&EmptyMeasurable, 100.0),
+        2.0 * line_height
+    );
+    assert_eq!(
+        node.max_intrinsic_height(&EmptyMeasurable, f32::INFINITY),
+        line_height
+    );
+}
+
+#[test]
+fn measure_wraps_like_the_intrinsic_height() {
+    let node = TextModifierElement::new(Rc::from(LONG_TEXT)).create();
+
+    let result = measure_text_layout(
+        LONG_TEXT,
+        &TextStyle::default(),
+        None,
+        Constraints::loose(100.0, f32::INFINITY),
+    );
+
+    assert!(result.size.width <= 100.0, "width {}", result.size.width);
+    assert_eq!(
+        result.size.height,
+        node.max_intrinsic_height(&EmptyMeasurable, 100.0)
+    );
+}
+
+#[test]
+fn intrinsic_widths_range_from_the_widest_word_to_one_line() {
+    let node = TextModifierElement::new(Rc::from(LONG_TEXT)).create();
+    let one_line = crate::text::measure_text(LONG_TEXT).width;
+    let widest_word = crate::text::measure_text("quick").width;
+
+    assert_eq!(node.max_intrinsic_width(&EmptyMeasurable, 0.0), one_line);
+    assert_eq!(node.min_intrinsic_width(&EmptyMeasurable, 0.0), widest_word);
+}
@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 
 use crate::text_layout_result::TextLayoutResult;
+use cranpose_ui_graphics::{Rect, TextDecoration, TextStyle};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct TextMetrics {
@@ -10,10 +11,20 @@ pub struct TextMetrics {
     pub line_height: f32,
     /// Number of lines in the text
     pub line_count: usize,
+    /// Distance from the top of the text to the baseline of its first line
+    pub first_baseline: f32,
+}
+
+impl TextMetrics {
+    /// Distance from the top of the text to the baseline of its last line.
+    pub fn last_baseline(&self) -> f32 {
+        self.first_baseline + self.line_count.saturating_sub(1) as f32 * self.line_height
+    }
 }
 
 pub trait TextMeasurer: 'static {
-    fn measure(&self, text: &str) -> TextMetrics;
+    /// Measures `text` laid out with the line height and letter spacing of `style`.
+    fn measure(&self, text: &str, style: &TextStyle) -> TextMetrics;
 
     /// Returns byte offset in text for given x position.
     /// Used for cursor positioning on click.
@@ -28,6 +39,55 @@ pub trait TextMeasurer: 'static {
     /// Computes full text layout with cached glyph positions.
     /// Returns TextLayoutResult for O(1) position lookups.
     fn layout(&self, text: &str) -> TextLayoutResult;
+
+    /// Measures `text` wrapped at spaces so no line is wider than `max_width`,
+    /// keeping at most `max_lines` lines.
+    ///
+    /// Words wider than `max_width` get a line of their own. The default
+    /// measures candidate lines with [`measure`](Self::measure), so measurers
+    /// only override it when they can shape wrapped text directly.
+    fn measure_wrapped(
+        &self,
+        text: &str,
+        style: &TextStyle,
+        max_width: f32,
+        max_lines: Option<usize>,
+    ) -> TextMetrics {
+        let unwrapped = self.measure(text, style);
+        if unwrapped.width <= max_width && max_lines.is_none_or(|max| unwrapped.line_count <= max) {
+            return unwrapped;
+        }
+        let mut width = 0.0_f32;
+        let mut line_count = 0;
+        for paragraph in text.split('\n') {
+            let mut line = String::new();
+            for word in paragraph.split(' ') {
+                let candidate = if line.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{line} {word}")
+                };
+                let candidate_width = self.measure(&candidate, style).width;
+                if candidate_width > max_width && !line.is_empty() {
+                    width = width.max(self.measure(&line, style).width);
+                    line_count += 1;
+                    line = word.to_string();
+                } else {
+                    line = candidate;
+                }
+            }
+            width = width.max(self.measure(&line, style).width);
+            line_count += 1;
+        }
+        let line_count = max_lines.map_or(line_count, |max| line_count.min(max.max(1)));
+
+        TextMetrics {
+            width,
+            height: line_count as f32 * unwrapped.line_height,
+            line_count,
+            ..unwrapped
+        }
+    }
 }
 
 #[derive(Default)]
@@ -36,25 +96,30 @@ struct MonospacedTextMeasurer;
 impl MonospacedTextMeasurer {
     const CHAR_WIDTH: f32 = 8.0;
     const LINE_HEIGHT: f32 = 20.0;
+    const BASELINE: f32 = 15.0;
 }
 
 impl TextMeasurer for MonospacedTextMeasurer {
-    fn measure(&self, text: &str) -> TextMetrics {
+    fn measure(&self, text: &str, style: &TextStyle) -> TextMetrics {
         // Split by newlines to handle multiline
         let lines: Vec<&str> = text.split('\n').collect();
         let line_count = lines.len().max(1);
+        let line_height = style.line_height.map_or(Self::LINE_HEIGHT, |sp| sp.0);
+        let char_width = Self::CHAR_WIDTH + style.letter_spacing.map_or(0.0, |sp| sp.0);
 
         // Width is the max width of any line
         let width = lines
             .iter()
-            .map(|line| line.chars().count() as f32 * Self::CHAR_WIDTH)
+            .map(|line| line.chars().count() as f32 * char_width)
             .fold(0.0_f32, f32::max);
 
         TextMetrics {
             width,
-            height: line_count as f32 * Self::LINE_HEIGHT,
-            line_height: Self::LINE_HEIGHT,
+            height: line_count as f32 * line_height,
+            line_height,
             line_count,
+            // Extra line height is split above and below the glyphs
+            first_baseline: Self::BASELINE + (line_height - Self::LINE_HEIGHT) / 2.0,
         }
     }
 
@@ -113,7 +178,60 @@ pub fn set_text_measurer<M: TextMeasurer>(measurer: M) {
 }
 
 pub fn measure_text(text: &str) -> TextMetrics {
-    TEXT_MEASURER.with(|m| m.borrow().measure(text))
+    measure_text_with_style(text, &TextStyle::default())
+}
+
+/// Measures `text` with the line height and letter spacing of `style`.
+pub fn measure_text_with_style(text: &str, style: &TextStyle) -> TextMetrics {
+    TEXT_MEASURER.with(|m| m.borrow().measure(text, style))
+}
+
+/// Measures `text` wrapped to `max_width`, keeping at most `max_lines` lines.
+pub fn measure_text_wrapped(
+    text: &str,
+    style: &TextStyle,
+    max_width: f32,
+    max_lines: Option<usize>,
+) -> TextMetrics {
+    TEXT_MEASURER.with(|m| {
+        m.borrow()
+            .measure_wrapped(text, style, max_width, max_lines)
+    })
+}
+
+/// Rectangles of the line `style.text_decoration` draws along `text`.
+///
+/// Each non-empty line gets one rect spanning its width, positioned from the
+/// line's baseline: underlines just below it, line-throughs about half the
+/// height of lowercase letters above it. Thickness follows the font's natural
+/// line height, so extra `line_height` spacing doesn't thicken the line.
+pub fn text_decoration_rects(text: &str, style: &TextStyle) -> Vec<Rect> {
+    let Some(decoration) = style.text_decoration else {
+        return Vec::new();
+    };
+    let metrics = measure_text_with_style(text, style);
+    let natural = measure_text_with_style(
+        "",
+        &TextStyle {
+            line_height: None,
+            ..style.clone()
+        },
+    );
+    let thickness = (natural.line_height / 16.0).max(1.0);
+    let offset = match decoration {
+        TextDecoration::Underline => thickness,
+        TextDecoration::LineThrough => -natural.line_height / 4.0 - thickness / 2.0,
+    };
+    text.split('\n')
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| Rect {
+            x: 0.0,
+            y: metrics.first_baseline + index as f32 * metrics.line_height + offset,
+            width: measure_text_with_style(line, style).width,
+            height: thickness,
+        })
+        .collect()
 }
 
 /// Returns byte offset in text for given x position.
@@ -42,7 +42,7 @@ thread_local! {
     static FOCUSED_HANDLER: RefCell<Option<Rc<dyn FocusedTextFieldHandler>>> = const { RefCell::new(None) };
 }
 
-/// Requests focus for a text field.
+/// Requests focus for a text field and shows the soft keyboard.
 ///
 /// If another text field was previously focused, it will be unfocused first.
 /// The provided `is_focused` handle should be the field's focus state.
@@ -70,14 +70,14 @@ pub fn request_focus(is_focused: Rc<RefCell<bool>>, handler: Rc<dyn FocusedTextF
 
     // Start cursor blink animation (timer-based, not continuous redraw)
     crate::cursor_animation::start_cursor_blink();
+    crate::ime::show_keyboard();
 
     // Only render invalidation needed - cursor is drawn via create_draw_closure()
     // which checks focus at draw time. No layout change occurs on focus.
     crate::request_render_invalidation();
 }
 
-/// Clears focus from the currently focused text field.
-#[allow(dead_code)]
+/// Clears focus from the currently focused text field, hiding the soft keyboard.
 pub fn clear_focus() {
     FOCUSED_FIELD.with(|current| {
         let mut current = current.borrow_mut();
@@ -98,6 +98,7 @@ pub fn clear_focus() {
 
     // Stop cursor blink animation
     crate::cursor_animation::stop_cursor_blink();
+    crate::ime::hide_keyboard();
 
     crate::request_render_invalidation();
 }
@@ -117,8 +118,9 @@ pub fn has_focused_field() -> bool {
             FOCUSED_HANDLER.with(|h| {
                 *h.borrow_mut() = None;
             });
-            // Also stop cursor blink since focus is lost
+            // Also stop cursor blink and hide the keyboard since focus is lost
             crate::cursor_animation::stop_cursor_blink();
+            crate::ime::hide_keyboard();
         }
         false
     })
@@ -236,3 +236,72 @@ pub(crate) fn handle_key_event_impl(
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key_event::{KeyEventType, Modifiers};
+    use cranpose_core::{DefaultScheduler, Runtime};
+    use std::sync::Arc;
+
+    fn with_test_runtime<T>(f: impl FnOnce() -> T) -> T {
+        let _runtime = Runtime::new(Arc::new(DefaultScheduler));
+        f()
+    }
+
+    fn press(state: &TextFieldState, key_code: KeyCode, text: &str) -> bool {
+        let event = KeyEvent::new(key_code, text, Modifiers::NONE, KeyEventType::KeyDown);
+        handle_key_event_impl(state, &event, TextFieldLineLimits::default())
+    }
+
+    #[test]
+    fn typed_characters_insert_at_cursor() {
+        with_test_runtime(|| {
+            let state = TextFieldState::new("");
+            assert!(press(&state, KeyCode::H, "h"));
+            assert!(press(&state, KeyCode::I, "i"));
+            assert_eq!(state.text(), "hi");
+            assert_eq!(state.selection(), TextRange::cursor(2));
+
+            state.edit(|buffer| buffer.place_cursor_before_char(1));
+            assert!(press(&state, KeyCode::A, "a"));
+            assert_eq!(state.text(), "hai");
+            assert_eq!(state.selection(), TextRange::cursor(2));
+        });
+    }
+
+    #[test]
+    fn backspace_deletes_character_before_cursor() {
+        with_test_runtime(|| {
+            let state = TextFieldState::new("abcd");
+            state.edit(|buffer| buffer.place_cursor_before_char(2));
+
+            assert!(press(&state, KeyCode::Backspace, ""));
+            assert_eq!(state.text(), "acd");
+            assert_eq!(state.selection(), TextRange::cursor(1));
+
+            state.edit(|buffer| buffer.place_cursor_before_char(0));
+            assert!(press(&state, KeyCode::Backspace, ""));
+            assert_eq!(state.text(), "acd");
+        });
+    }
+
+    #[test]
+    fn arrow_keys_move_cursor_by_character() {
+        with_test_runtime(|| {
+            let state = TextFieldState::new("héllo");
+            state.edit(|buffer| buffer.place_cursor_before_char(0));
+
+            assert!(press(&state, KeyCode::ArrowRight, ""));
+            assert_eq!(state.selection(), TextRange::cursor(1));
+            // 'é' is two bytes wide; the cursor skips the whole character
+            assert!(press(&state, KeyCode::ArrowRight, ""));
+            assert_eq!(state.selection(), TextRange::cursor(3));
+            assert!(press(&state, KeyCode::ArrowLeft, ""));
+            assert_eq!(state.selection(), TextRange::cursor(1));
+            assert!(press(&state, KeyCode::ArrowLeft, ""));
+            assert!(press(&state, KeyCode::ArrowLeft, ""));
+            assert_eq!(state.selection(), TextRange::cursor(0));
+        });
+    }
+}
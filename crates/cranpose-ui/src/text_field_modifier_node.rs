@@ -16,6 +16,7 @@
 //! - Tracks focus state for cursor visibility
 //! - Handles pointer events for cursor positioning
 
+use crate::text_selection::{selection_brush, selection_highlight_rects, DEFAULT_SELECTION_COLOR};
 use cranpose_foundation::text::{TextFieldLineLimits, TextFieldState, TextRange};
 use cranpose_foundation::{
     Constraints, DelegatableNode, DrawModifierNode, DrawScope, InvalidationKind,
@@ -31,9 +32,6 @@ use std::rc::Rc;
 /// Default cursor color (white - visible on dark backgrounds)
 const DEFAULT_CURSOR_COLOR: Color = Color(1.0, 1.0, 1.0, 1.0);
 
-/// Default selection highlight color (light blue with transparency)
-const DEFAULT_SELECTION_COLOR: Color = Color(0.0, 0.5, 1.0, 0.3);
-
 /// Double-click timeout in milliseconds
 const DOUBLE_CLICK_MS: u128 = 500;
 
@@ -43,6 +41,18 @@ const DEFAULT_LINE_HEIGHT: f32 = 20.0;
 /// Cursor width in pixels
 const CURSOR_WIDTH: f32 = 2.0;
 
+/// Returns the cursor's x offset and line index for a byte `cursor` in `text`.
+///
+/// The x offset is the measured width of the cursor's line up to the cursor,
+/// so it tracks the real glyph advances rather than a fixed character width.
+fn cursor_position(text: &str, cursor: usize) -> (f32, usize) {
+    let text_before = &text[..cursor.min(text.len())];
+    let line_index = text_before.matches('\n').count();
+    let line_start = text_before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let x = crate::text::measure_text(&text_before[line_start..]).width;
+    (x, line_index)
+}
+
 /// Shared references for text field input handling.
 ///
 /// This struct bundles the shared state references passed to the pointer input handler,
@@ -134,7 +144,7 @@ impl TextFieldModifierNode {
             state,
             refs,
             cursor_brush: Brush::solid(DEFAULT_CURSOR_COLOR),
-            selection_brush: Brush::solid(DEFAULT_SELECTION_COLOR),
+            selection_brush: selection_brush(DEFAULT_SELECTION_COLOR),
             line_limits,
             cached_text: value.text,
             cached_selection: value.selection,
@@ -178,6 +188,9 @@ impl TextFieldModifierNode {
                     let handler =
                         TextFieldHandler::new(state.clone(), refs.node_id.get(), line_limits);
                     crate::text_field_focus::request_focus(refs.is_focused.clone(), handler);
+                    if let Some(node_id) = refs.node_id.get() {
+                        crate::request_bring_into_view(node_id);
+                    }
 
                     let now = web_time::Instant::now();
                     let text = state.text();
@@ -256,6 +269,14 @@ impl TextFieldModifierNode {
         self
     }
 
+    /// Creates a node with custom selection highlight color.
+    ///
+    /// Opaque colors are drawn translucent so selected text stays legible.
+    pub fn with_selection_color(mut self, color: Color) -> Self {
+        self.selection_brush = selection_brush(color);
+        self
+    }
+
     /// Sets the focus state.
     pub fn set_focused(&mut self, focused: bool) {
         let current = *self.refs.is_focused.borrow();
@@ -531,43 +552,12 @@ impl DrawModifierNode for TextFieldModifierNode {
             let line_height = crate::text::measure_text(&text).line_height;
 
             // Draw selection highlight
-            if !selection.collapsed() {
-                let sel_start = selection.min();
-                let sel_end = selection.max();
-
-                let lines: Vec<&str> = text.split('\n').collect();
-                let mut byte_offset: usize = 0;
-
-                for (line_idx, line) in lines.iter().enumerate() {
-                    let line_start = byte_offset;
-                    let line_end = byte_offset + line.len();
-
-                    if sel_end > line_start && sel_start < line_end {
-                        let sel_start_in_line = sel_start.saturating_sub(line_start);
-                        let sel_end_in_line = (sel_end - line_start).min(line.len());
-
-                        let sel_start_x = crate::text::measure_text(&line[..sel_start_in_line])
-                            .width
-                            + padding_left;
-                        let sel_end_x = crate::text::measure_text(&line[..sel_end_in_line]).width
-                            + padding_left;
-                        let sel_width = sel_end_x - sel_start_x;
-
-                        if sel_width > 0.0 {
-                            let sel_rect = cranpose_ui_graphics::Rect {
-                                x: sel_start_x,
-                                y: padding_top + line_idx as f32 * line_height,
-                                width: sel_width,
-                                height: line_height,
-                            };
-                            primitives.push(DrawPrimitive::Rect {
-                                rect: sel_rect,
-                                brush: selection_brush.clone(),
-                            });
-                        }
-                    }
-                    byte_offset = line_end + 1;
-                }
+            let origin = cranpose_ui_graphics::Point::new(padding_left, padding_top);
+            for rect in selection_highlight_rects(&text, selection, origin) {
+                primitives.push(DrawPrimitive::Rect {
+                    rect,
+                    brush: selection_brush.clone(),
+                });
             }
 
             // Draw composition (IME preedit) underline
@@ -637,16 +627,11 @@ impl DrawModifierNode for TextFieldModifierNode {
 
             // Draw cursor - check visibility at DRAW time for blinking
             if crate::cursor_animation::is_cursor_visible() {
-                let pos = selection.start.min(text.len());
-                let text_before = &text[..pos];
-                let line_index = text_before.matches('\n').count();
-                let line_start = text_before.rfind('\n').map(|i| i + 1).unwrap_or(0);
-                let cursor_x =
-                    crate::text::measure_text(&text_before[line_start..]).width + padding_left;
+                let (cursor_x, line_index) = cursor_position(&text, selection.start);
                 let cursor_y = padding_top + line_index as f32 * line_height;
 
                 let cursor_rect = cranpose_ui_graphics::Rect {
-                    x: cursor_x,
+                    x: cursor_x + padding_left,
                     y: cursor_y,
                     width: CURSOR_WIDTH,
                     height: line_height,
@@ -720,6 +705,8 @@ pub struct TextFieldElement {
     state: TextFieldState,
     /// Cursor color
     cursor_color: Color,
+    /// Selection highlight color
+    selection_color: Color,
     /// Line limits configuration
     line_limits: TextFieldLineLimits,
 }
@@ -730,6 +717,7 @@ impl TextFieldElement {
         Self {
             state,
             cursor_color: DEFAULT_CURSOR_COLOR,
+            selection_color: DEFAULT_SELECTION_COLOR,
             line_limits: TextFieldLineLimits::default(),
         }
     }
@@ -740,6 +728,12 @@ impl TextFieldElement {
         self
     }
 
+    /// Creates an element with custom selection highlight color.
+    pub fn with_selection_color(mut self, color: Color) -> Self {
+        self.selection_color = color;
+        self
+    }
+
     /// Creates an element with custom line limits.
     pub fn with_line_limits(mut self, line_limits: TextFieldLineLimits) -> Self {
         self.line_limits = line_limits;
@@ -752,6 +746,7 @@ impl std::fmt::Debug for TextFieldElement {
         f.debug_struct("TextFieldElement")
             .field("text", &self.state.text())
             .field("cursor_color", &self.cursor_color)
+            .field("selection_color", &self.selection_color)
             .finish()
     }
 }
@@ -766,16 +761,22 @@ impl Hash for TextFieldElement {
         self.cursor_color.1.to_bits().hash(state);
         self.cursor_color.2.to_bits().hash(state);
         self.cursor_color.3.to_bits().hash(state);
+        // Hash selection color
+        self.selection_color.0.to_bits().hash(state);
+        self.selection_color.1.to_bits().hash(state);
+        self.selection_color.2.to_bits().hash(state);
+        self.selection_color.3.to_bits().hash(state);
     }
 }
 
 impl PartialEq for TextFieldElement {
     fn eq(&self, other: &Self) -> bool {
-        // Compare by state identity (same Rc), cursor color, and line limits
+        // Compare by state identity (same Rc), colors, and line limits
         // This ensures node reuse when same state is passed, while detecting
         // actual changes that require updates
         self.state == other.state
             && self.cursor_color == other.cursor_color
+            && self.selection_color == other.selection_color
             && self.line_limits == other.line_limits
     }
 }
@@ -788,6 +789,7 @@ impl ModifierNodeElement for TextFieldElement {
     fn create(&self) -> Self::Node {
         TextFieldModifierNode::new(self.state.clone())
             .with_cursor_color(self.cursor_color)
+            .with_selection_color(self.selection_color)
             .with_line_limits(self.line_limits)
     }
 
@@ -795,6 +797,7 @@ impl ModifierNodeElement for TextFieldElement {
         // Update the state reference
         node.state = self.state.clone();
         node.cursor_brush = Brush::solid(self.cursor_color);
+        node.selection_brush = selection_brush(self.selection_color);
         node.line_limits = self.line_limits;
 
         // Recreate the cached handler with the new state but same refs
@@ -889,8 +892,6 @@ mod tests {
         });
     }
 
-    /// Test that cursor draw command position is calculated correctly.
-    ///
     /// This test verifies that when we measure text width for cursor position:
     /// 1. The cursor x position = width of text before cursor
     /// 2. For text at cursor end, x = full text width
@@ -950,6 +951,23 @@ mod tests {
         });
     }
 
+    /// Cursor x is the measured width of the text before it on the cursor's line.
+    #[test]
+    fn test_cursor_position_helper() {
+        let hi_width = crate::text::measure_text("Hi").width;
+        let h_width = crate::text::measure_text("H").width;
+        assert!(h_width > 0.0 && h_width < hi_width);
+
+        assert_eq!(cursor_position("Hi", 0), (0.0, 0));
+        assert_eq!(cursor_position("Hi", 1), (h_width, 0));
+        assert_eq!(cursor_position("Hi", 2), (hi_width, 0));
+        // Out-of-range cursors clamp to the end of the text
+        assert_eq!(cursor_position("Hi", 10), (hi_width, 0));
+        // Only the prefix on the cursor's own line contributes to x
+        assert_eq!(cursor_position("Hello\nHi", 8), (hi_width, 1));
+        assert_eq!(cursor_position("Hello\n", 6), (0.0, 1));
+    }
+
     /// Test cursor is created when focused node is in slices.
     #[test]
     fn test_focused_node_creates_cursor() {
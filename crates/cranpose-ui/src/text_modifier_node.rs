@@ -24,6 +24,8 @@ use cranpose_foundation::{
     LayoutModifierNode, Measurable, MeasurementProxy, ModifierNode, ModifierNodeContext,
     ModifierNodeElement, NodeCapabilities, NodeState, SemanticsConfiguration, SemanticsNode, Size,
 };
+use cranpose_ui_graphics::TextStyle;
+use cranpose_ui_layout::AlignmentLines;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
@@ -39,13 +41,17 @@ use std::rc::Rc;
 #[derive(Debug)]
 pub struct TextModifierNode {
     text: Rc<str>,
+    style: TextStyle,
+    max_lines: Option<usize>,
     state: NodeState,
 }
 
 impl TextModifierNode {
-    pub fn new(text: Rc<str>) -> Self {
+    pub fn new(text: Rc<str>, style: TextStyle) -> Self {
         Self {
             text,
+            style,
+            max_lines: None,
             state: NodeState::new(),
         }
     }
@@ -58,13 +64,12 @@ impl TextModifierNode {
         self.text.clone()
     }
 
-    /// Helper to measure text content size.
-    fn measure_text_content(&self) -> Size {
-        let metrics = crate::text::measure_text(&self.text);
-        Size {
-            width: metrics.width,
-            height: metrics.height,
-        }
+    pub fn style(&self) -> &TextStyle {
+        &self.style
+    }
+
+    pub fn max_lines(&self) -> Option<usize> {
+        self.max_lines
     }
 }
 
@@ -114,64 +119,94 @@ impl LayoutModifierNode for TextModifierNode {
         _measurable: &dyn Measurable,
         constraints: Constraints,
     ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
-        // Measure the text content
-        let text_size = self.measure_text_content();
-
-        // Constrain text size to the provided constraints
-        let width = text_size
-            .width
-            .clamp(constraints.min_width, constraints.max_width);
-        let height = text_size
-            .height
-            .clamp(constraints.min_height, constraints.max_height);
-
-        // Text is a leaf node - return the text size directly with no offset
         // We don't call measurable.measure() because there's no wrapped content
         // (Text uses EmptyMeasurePolicy which has no children)
-        cranpose_ui_layout::LayoutModifierMeasureResult::with_size(Size { width, height })
+        measure_text_layout(&self.text, &self.style, self.max_lines, constraints)
     }
 
     fn min_intrinsic_width(&self, _measurable: &dyn Measurable, _height: f32) -> f32 {
-        self.measure_text_content().width
+        min_intrinsic_text_width(&self.text, &self.style, self.max_lines)
     }
 
     fn max_intrinsic_width(&self, _measurable: &dyn Measurable, _height: f32) -> f32 {
-        self.measure_text_content().width
+        max_intrinsic_text_width(&self.text, &self.style, self.max_lines)
     }
 
-    fn min_intrinsic_height(&self, _measurable: &dyn Measurable, _width: f32) -> f32 {
-        self.measure_text_content().height
+    fn min_intrinsic_height(&self, _measurable: &dyn Measurable, width: f32) -> f32 {
+        intrinsic_text_height(&self.text, &self.style, width, self.max_lines)
     }
 
-    fn max_intrinsic_height(&self, _measurable: &dyn Measurable, _width: f32) -> f32 {
-        self.measure_text_content().height
+    fn max_intrinsic_height(&self, _measurable: &dyn Measurable, width: f32) -> f32 {
+        intrinsic_text_height(&self.text, &self.style, width, self.max_lines)
     }
 
     fn create_measurement_proxy(&self) -> Option<Box<dyn MeasurementProxy>> {
         Some(Box::new(TextMeasurementProxy {
             text: self.text.clone(),
+            style: self.style.clone(),
+            max_lines: self.max_lines,
         }))
     }
 }
 
+/// Measures `text` as a leaf within `constraints`, exposing its baselines as alignment lines.
+///
+/// The text wraps to the maximum width, the same way its intrinsic heights are
+/// measured. Text has no wrapped content, so the result carries no placement offset.
+fn measure_text_layout(
+    text: &str,
+    style: &TextStyle,
+    max_lines: Option<usize>,
+    constraints: Constraints,
+) -> cranpose_ui_layout::LayoutModifierMeasureResult {
+    let metrics = crate::text::measure_text_wrapped(text, style, constraints.max_width, max_lines);
+
+    // Constrain text size to the provided constraints
+    let width = metrics
+        .width
+        .clamp(constraints.min_width, constraints.max_width);
+    let height = metrics
+        .height
+        .clamp(constraints.min_height, constraints.max_height);
+
+    cranpose_ui_layout::LayoutModifierMeasureResult::with_size(Size { width, height })
+        .with_alignment_lines(AlignmentLines::baselines(
+            metrics.first_baseline,
+            metrics.last_baseline(),
+        ))
+}
+
+/// Width of the widest word of `text`, the narrowest it can be laid out.
+fn min_intrinsic_text_width(text: &str, style: &TextStyle, max_lines: Option<usize>) -> f32 {
+    crate::text::measure_text_wrapped(text, style, 0.0, max_lines).width
+}
+
+/// Width of `text` without wrapping.
+fn max_intrinsic_text_width(text: &str, style: &TextStyle, max_lines: Option<usize>) -> f32 {
+    crate::text::measure_text_wrapped(text, style, f32::INFINITY, max_lines).width
+}
+
+/// Height of `text` wrapped to `width`, keeping at most `max_lines` lines.
+///
+/// Both intrinsic heights are this height: text can't get shorter at a given
+/// width than its wrapped lines.
+fn intrinsic_text_height(
+    text: &str,
+    style: &TextStyle,
+    width: f32,
+    max_lines: Option<usize>,
+) -> f32 {
+    crate::text::measure_text_wrapped(text, style, width, max_lines).height
+}
+
 /// Measurement proxy for TextModifierNode that snapshots live state.
 ///
 /// Phase 2: Instead of reconstructing nodes via `TextModifierNode::new()`, this proxy
 /// directly implements measurement logic using the snapshotted text content.
 struct TextMeasurementProxy {
     text: Rc<str>,
-}
-
-impl TextMeasurementProxy {
-    /// Measure the text content dimensions.
-    /// Matches TextModifierNode::measure_text_content() logic.
-    fn measure_text_content(&self) -> Size {
-        let metrics = crate::text::measure_text(&self.text);
-        Size {
-            width: metrics.width,
-            height: metrics.height,
-        }
-    }
+    style: TextStyle,
+    max_lines: Option<usize>,
 }
 
 impl MeasurementProxy for TextMeasurementProxy {
@@ -182,34 +217,23 @@ impl MeasurementProxy for TextMeasurementProxy {
         constraints: Constraints,
     ) -> cranpose_ui_layout::LayoutModifierMeasureResult {
         // Directly implement text measurement logic (no node reconstruction)
-        let text_size = self.measure_text_content();
-
-        // Constrain text size to the provided constraints
-        let width = text_size
-            .width
-            .clamp(constraints.min_width, constraints.max_width);
-        let height = text_size
-            .height
-            .clamp(constraints.min_height, constraints.max_height);
-
-        // Text is a leaf node - return the text size directly with no offset
-        cranpose_ui_layout::LayoutModifierMeasureResult::with_size(Size { width, height })
+        measure_text_layout(&self.text, &self.style, self.max_lines, constraints)
     }
 
     fn min_intrinsic_width_proxy(&self, _measurable: &dyn Measurable, _height: f32) -> f32 {
-        self.measure_text_content().width
+        min_intrinsic_text_width(&self.text, &self.style, self.max_lines)
     }
 
     fn max_intrinsic_width_proxy(&self, _measurable: &dyn Measurable, _height: f32) -> f32 {
-        self.measure_text_content().width
+        max_intrinsic_text_width(&self.text, &self.style, self.max_lines)
     }
 
-    fn min_intrinsic_height_proxy(&self, _measurable: &dyn Measurable, _width: f32) -> f32 {
-        self.measure_text_content().height
+    fn min_intrinsic_height_proxy(&self, _measurable: &dyn Measurable, width: f32) -> f32 {
+        intrinsic_text_height(&self.text, &self.style, width, self.max_lines)
     }
 
-    fn max_intrinsic_height_proxy(&self, _measurable: &dyn Measurable, _width: f32) -> f32 {
-        self.measure_text_content().height
+    fn max_intrinsic_height_proxy(&self, _measurable: &dyn Measurable, width: f32) -> f32 {
+        intrinsic_text_height(&self.text, &self.style, width, self.max_lines)
     }
 }
 
@@ -241,20 +265,46 @@ impl SemanticsNode for TextModifierNode {
 /// - Declaring capabilities (LAYOUT | DRAW | SEMANTICS)
 ///
 /// Matches Jetpack Compose: `TextStringSimpleElement` in BasicText.kt
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TextModifierElement {
     text: Rc<str>,
+    style: TextStyle,
+    max_lines: Option<usize>,
 }
 
 impl TextModifierElement {
     pub fn new(text: Rc<str>) -> Self {
-        Self { text }
+        Self {
+            text,
+            style: TextStyle::default(),
+            max_lines: None,
+        }
+    }
+
+    /// Lays the text out with the line height and letter spacing of `style`.
+    pub fn with_style(mut self, style: TextStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Caps the intrinsic height at `max_lines` wrapped lines.
+    ///
+    /// Matches Kotlin: `BasicText(maxLines = max_lines)`
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
     }
 }
 
 impl Hash for TextModifierElement {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.text.hash(state);
+        let spacing = [self.style.line_height, self.style.letter_spacing];
+        for value in spacing {
+            value.map(|sp| sp.0.to_bits()).hash(state);
+        }
+        self.style.text_decoration.hash(state);
+        self.max_lines.hash(state);
     }
 }
 
@@ -262,7 +312,9 @@ impl ModifierNodeElement for TextModifierElement {
     type Node = TextModifierNode;
 
     fn create(&self) -> Self::Node {
-        TextModifierNode::new(self.text.clone())
+        let mut node = TextModifierNode::new(self.text.clone(), self.style.clone());
+        node.max_lines = self.max_lines;
+        node
     }
 
     fn update(&self, node: &mut Self::Node) {
@@ -274,6 +326,10 @@ impl ModifierNodeElement for TextModifierElement {
             // The invalidation will happen on the next recomposition when the node
             // is reconciled.
         }
+        if node.style != self.style {
+            node.style = self.style.clone();
+        }
+        node.max_lines = self.max_lines;
     }
 
     fn capabilities(&self) -> NodeCapabilities {
@@ -281,3 +337,7 @@ impl ModifierNodeElement for TextModifierElement {
         NodeCapabilities::LAYOUT | NodeCapabilities::DRAW | NodeCapabilities::SEMANTICS
     }
 }
+
+#[cfg(test)]
+#[path = "tests/text_modifier_node_tests.rs"]
+mod tests;
@@ -0,0 +1,454 @@
+//! Pointer-driven selection for read-only text.
+//!
+//! `Modifier::selectable_text()` attaches a [`SelectableTextNode`] next to a
+//! text modifier. Pressing sets the selection anchor, dragging extends the
+//! selection, and the selected range is highlighted behind the glyphs.
+//!
+//! Only one text node owns a selection at a time. The owner is tracked in a
+//! thread-local so the platform can copy it via [`copy_selected_text`], the
+//! same way focused text fields are reached through `text_field_focus`.
+
+use cranpose_foundation::text::TextRange;
+use cranpose_foundation::{
+    DelegatableNode, ModifierNode, ModifierNodeContext, ModifierNodeElement, NodeCapabilities,
+    NodeState, PointerEvent, PointerEventKind, PointerInputNode,
+};
+use cranpose_ui_graphics::{Brush, Color, DrawPrimitive, Point, Rect, Size};
+use std::cell::{Cell, RefCell};
+use std::hash::{Hash, Hasher};
+use std::rc::{Rc, Weak};
+
+/// Default selection highlight color (light blue with transparency)
+pub(crate) const DEFAULT_SELECTION_COLOR: Color = Color(0.0, 0.5, 1.0, 0.3);
+
+/// Alpha applied to opaque selection colors so highlighted text stays legible.
+const OPAQUE_SELECTION_ALPHA: f32 = 0.4;
+
+/// Colors used by text components for the cursor and the selection highlight.
+///
+/// Provided through [`crate::local_text_selection_colors`]; text fields can
+/// still override either color per field.
+///
+/// Matches Kotlin: `TextSelectionColors` in
+/// `androidx.compose.foundation.text.selection`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextSelectionColors {
+    /// Color of the text field cursor.
+    pub cursor_color: Color,
+    /// Color of the selection highlight. Opaque colors are drawn translucent.
+    pub selection_color: Color,
+}
+
+impl Default for TextSelectionColors {
+    fn default() -> Self {
+        Self {
+            cursor_color: Color::BLACK,
+            selection_color: DEFAULT_SELECTION_COLOR,
+        }
+    }
+}
+
+/// Brush painting a selection highlight in `color`.
+///
+/// Selections are drawn over (text fields) or right behind (selectable text)
+/// the glyphs, so an opaque color is made translucent to keep text readable.
+pub(crate) fn selection_brush(color: Color) -> Brush {
+    if color.a() >= 1.0 {
+        Brush::solid(color.with_alpha(OPAQUE_SELECTION_ALPHA))
+    } else {
+        Brush::solid(color)
+    }
+}
+
+thread_local! {
+    static ACTIVE_SELECTION: RefCell<Option<Weak<SelectionState>>> = const { RefCell::new(None) };
+}
+
+/// Returns the text currently selected in a selectable text node, if any.
+///
+/// The app shell calls this for copy requests when no text field is focused.
+pub fn copy_selected_text() -> Option<String> {
+    ACTIVE_SELECTION.with(|active| {
+        active
+            .borrow()
+            .as_ref()
+            .and_then(Weak::upgrade)
+            .and_then(|state| state.selected_text())
+    })
+}
+
+/// Computes the highlight rectangles covering `selection` within `text`.
+///
+/// One rectangle is produced per line touched by the selection, offset by
+/// `origin` (the content padding of the text node).
+pub(crate) fn selection_highlight_rects(
+    text: &str,
+    selection: TextRange,
+    origin: Point,
+) -> Vec<Rect> {
+    if selection.collapsed() {
+        return Vec::new();
+    }
+    let sel_start = selection.min();
+    let sel_end = selection.max();
+    let line_height = crate::text::measure_text(text).line_height;
+
+    let mut rects = Vec::new();
+    let mut line_start = 0;
+    for (line_idx, line) in text.split('\n').enumerate() {
+        let line_end = line_start + line.len();
+        if sel_end > line_start && sel_start < line_end {
+            let start_in_line = sel_start.saturating_sub(line_start);
+            let end_in_line = (sel_end - line_start).min(line.len());
+            let start_x = crate::text::measure_text(&line[..start_in_line]).width;
+            let end_x = crate::text::measure_text(&line[..end_in_line]).width;
+            if end_x > start_x {
+                rects.push(Rect {
+                    x: origin.x + start_x,
+                    y: origin.y + line_idx as f32 * line_height,
+                    width: end_x - start_x,
+                    height: line_height,
+                });
+            }
+        }
+        line_start = line_end + 1;
+    }
+    rects
+}
+
+/// Selection state shared between the node, its pointer handler, and its
+/// highlight closure.
+#[derive(Default)]
+struct SelectionState {
+    text: RefCell<Rc<str>>,
+    selection: Cell<TextRange>,
+    anchor: Cell<Option<usize>>,
+    content_offset: Cell<Point>,
+}
+
+impl SelectionState {
+    fn offset_at(&self, position: Point) -> usize {
+        let origin = self.content_offset.get();
+        let text = self.text.borrow();
+        crate::text::get_offset_for_position(
+            &text,
+            (position.x - origin.x).max(0.0),
+            (position.y - origin.y).max(0.0),
+        )
+    }
+
+    fn selected_text(&self) -> Option<String> {
+        let selection = self.selection.get();
+        if selection.collapsed() {
+            return None;
+        }
+        let text = self.text.borrow();
+        text.get(selection.min()..selection.max().min(text.len()))
+            .map(str::to_owned)
+    }
+
+    fn clear(&self) {
+        self.anchor.set(None);
+        if !self.selection.get().collapsed() {
+            self.selection.set(TextRange::zero());
+            crate::request_render_invalidation();
+        }
+    }
+}
+
+/// Makes `state` the sole owner of the text selection, clearing any other.
+fn activate(state: &Rc<SelectionState>) {
+    ACTIVE_SELECTION.with(|active| {
+        let mut active = active.borrow_mut();
+        if let Some(previous) = active.as_ref().and_then(Weak::upgrade) {
+            if !Rc::ptr_eq(&previous, state) {
+                previous.clear();
+            }
+        }
+        *active = Some(Rc::downgrade(state));
+    });
+}
+
+/// Modifier node that lets the user select read-only text with the pointer.
+///
+/// Matches Kotlin: `SelectionContainer` / `SelectionManager` in
+/// `androidx.compose.foundation.text.selection`.
+pub struct SelectableTextNode {
+    node_state: NodeState,
+    state: Rc<SelectionState>,
+    handler: Rc<dyn Fn(PointerEvent)>,
+    selection_brush: Brush,
+}
+
+impl SelectableTextNode {
+    pub fn new() -> Self {
+        let state = Rc::new(SelectionState::default());
+        Self {
+            node_state: NodeState::new(),
+            handler: Self::create_handler(state.clone()),
+            state,
+            selection_brush: selection_brush(DEFAULT_SELECTION_COLOR),
+        }
+    }
+
+    /// Sets the color of the selection highlight.
+    pub(crate) fn set_selection_color(&mut self, color: Color) {
+        self.selection_brush = selection_brush(color);
+    }
+
+    fn create_handler(state: Rc<SelectionState>) -> Rc<dyn Fn(PointerEvent)> {
+        Rc::new(move |event: PointerEvent| match event.kind {
+            PointerEventKind::Down => {
+                activate(&state);
+                let offset = state.offset_at(event.position);
+                state.anchor.set(Some(offset));
+                state.selection.set(TextRange::cursor(offset));
+                crate::request_render_invalidation();
+                event.consume();
+            }
+            PointerEventKind::Move => {
+                if let Some(anchor) = state.anchor.get() {
+                    let offset = state.offset_at(event.position);
+                    state.selection.set(TextRange::new(anchor, offset));
+                    crate::request_render_invalidation();
+                    event.consume();
+                }
+            }
+            PointerEventKind::Up | PointerEventKind::Cancel => {
+                state.anchor.set(None);
+            }
+            PointerEventKind::Enter | PointerEventKind::Exit => {}
+        })
+    }
+
+    /// Updates the text being selected. A changed text drops the selection.
+    pub(crate) fn set_text(&self, text: Rc<str>) {
+        if *self.state.text.borrow() != text {
+            *self.state.text.borrow_mut() = text;
+            self.state.clear();
+        }
+    }
+
+    /// Sets the offset of the text origin (content padding) inside the node.
+    pub(crate) fn set_content_offset(&self, offset: Point) {
+        self.state.content_offset.set(offset);
+    }
+
+    /// Returns the text byte offset closest to `position` in local coordinates.
+    pub fn offset_at(&self, position: Point) -> usize {
+        self.state.offset_at(position)
+    }
+
+    /// Returns the current selection range.
+    pub fn selection(&self) -> TextRange {
+        self.state.selection.get()
+    }
+
+    /// Returns the selected text, or None if the selection is collapsed.
+    pub fn selected_text(&self) -> Option<String> {
+        self.state.selected_text()
+    }
+
+    /// Returns a closure drawing the selection highlight, evaluated at render time.
+    pub(crate) fn highlight_closure(&self) -> Rc<dyn Fn(Size) -> Vec<DrawPrimitive>> {
+        let state = self.state.clone();
+        let brush = self.selection_brush.clone();
+        Rc::new(move |_size| {
+            let text = state.text.borrow();
+            selection_highlight_rects(&text, state.selection.get(), state.content_offset.get())
+                .into_iter()
+                .map(|rect| DrawPrimitive::Rect {
+                    rect,
+                    brush: brush.clone(),
+                })
+                .collect()
+        })
+    }
+}
+
+impl Default for SelectableTextNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DelegatableNode for SelectableTextNode {
+    fn node_state(&self) -> &NodeState {
+        &self.node_state
+    }
+}
+
+impl ModifierNode for SelectableTextNode {
+    fn on_detach(&mut self) {
+        self.state.clear();
+    }
+
+    fn as_pointer_input_node(&self) -> Option<&dyn PointerInputNode> {
+        Some(self)
+    }
+
+    fn as_pointer_input_node_mut(&mut self) -> Option<&mut dyn PointerInputNode> {
+        Some(self)
+    }
+}
+
+impl PointerInputNode for SelectableTextNode {
+    fn on_pointer_event(
+        &mut self,
+        _context: &mut dyn ModifierNodeContext,
+        _event: &PointerEvent,
+    ) -> bool {
+        // Handled by the cached pointer_input_handler() closure.
+        false
+    }
+
+    fn pointer_input_handler(&self) -> Option<Rc<dyn Fn(PointerEvent)>> {
+        Some(self.handler.clone())
+    }
+}
+
+/// Element that creates [`SelectableTextNode`] instances.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectableTextElement {
+    selection_color: Color,
+}
+
+impl SelectableTextElement {
+    /// Creates an element highlighting selections in `selection_color`.
+    pub fn new(selection_color: Color) -> Self {
+        Self { selection_color }
+    }
+}
+
+impl Default for SelectableTextElement {
+    fn default() -> Self {
+        Self::new(DEFAULT_SELECTION_COLOR)
+    }
+}
+
+impl Hash for SelectableTextElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        "selectable_text".hash(state);
+        self.selection_color.0.to_bits().hash(state);
+        self.selection_color.1.to_bits().hash(state);
+        self.selection_color.2.to_bits().hash(state);
+        self.selection_color.3.to_bits().hash(state);
+    }
+}
+
+impl ModifierNodeElement for SelectableTextElement {
+    type Node = SelectableTextNode;
+
+    fn create(&self) -> Self::Node {
+        let mut node = SelectableTextNode::new();
+        node.set_selection_color(self.selection_color);
+        node
+    }
+
+    fn update(&self, node: &mut Self::Node) {
+        node.set_selection_color(self.selection_color);
+    }
+
+    fn capabilities(&self) -> NodeCapabilities {
+        NodeCapabilities::POINTER_INPUT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pointer(kind: PointerEventKind, x: f32, y: f32) -> PointerEvent {
+        PointerEvent::new(kind, Point::new(x, y), Point::new(x, y))
+    }
+
+    #[test]
+    fn x_coordinate_maps_to_character_index() {
+        let node = SelectableTextNode::new();
+        node.set_text(Rc::from("Hello"));
+
+        // Monospaced measurer: 8px per char, nearest boundary wins.
+        assert_eq!(node.offset_at(Point::new(19.0, 5.0)), 2);
+        assert_eq!(node.offset_at(Point::new(21.0, 5.0)), 3);
+        assert_eq!(node.offset_at(Point::new(500.0, 5.0)), 5);
+
+        node.set_content_offset(Point::new(16.0, 0.0));
+        assert_eq!(node.offset_at(Point::new(19.0, 5.0)), 0);
+    }
+
+    #[test]
+    fn drag_selects_range_and_copies_it() {
+        let node = SelectableTextNode::new();
+        node.set_text(Rc::from("Hello world"));
+        let handler = node.pointer_input_handler().expect("pointer handler");
+
+        handler(pointer(PointerEventKind::Down, 48.0, 5.0));
+        assert!(node.selection().collapsed());
+        handler(pointer(PointerEventKind::Move, 88.0, 5.0));
+        handler(pointer(PointerEventKind::Up, 88.0, 5.0));
+
+        assert_eq!(node.selection(), TextRange::new(6, 11));
+        assert_eq!(copy_selected_text().as_deref(), Some("world"));
+
+        let rects = selection_highlight_rects("Hello world", node.selection(), Point::default());
+        assert_eq!(rects.len(), 1);
+        assert_eq!((rects[0].x, rects[0].width), (48.0, 40.0));
+
+        // Moving after release does not extend the selection.
+        handler(pointer(PointerEventKind::Move, 0.0, 5.0));
+        assert_eq!(node.selection(), TextRange::new(6, 11));
+
+        node.set_text(Rc::from("Changed"));
+        assert_eq!(copy_selected_text(), None);
+    }
+
+    #[test]
+    fn selection_highlight_uses_provided_selection_color() {
+        use crate::draw::DrawCommand;
+        use crate::modifier::{collect_slices_from_modifier, Modifier};
+        use crate::text_modifier_node::TextModifierElement;
+        use crate::{local_text_selection_colors, run_test_composition};
+        use cranpose_core::CompositionLocalProvider;
+        use cranpose_foundation::modifier_element;
+
+        let configured = Color(1.0, 0.5, 0.0, 0.25);
+        let colors = TextSelectionColors {
+            cursor_color: Color::WHITE,
+            selection_color: configured,
+        };
+        let selectable = RefCell::new(None);
+        run_test_composition(|| {
+            CompositionLocalProvider(vec![local_text_selection_colors().provides(colors)], || {
+                *selectable.borrow_mut() = Some(Modifier::empty().selectable_text());
+            });
+        });
+        let modifier = Modifier::from_parts(vec![modifier_element(TextModifierElement::new(
+            Rc::from("Hello"),
+        ))])
+        .then(selectable.take().expect("selectable modifier"));
+
+        let slices = collect_slices_from_modifier(&modifier);
+        let handler = slices.pointer_inputs()[0].clone();
+        handler(pointer(PointerEventKind::Down, 0.0, 5.0));
+        handler(pointer(PointerEventKind::Move, 24.0, 5.0));
+
+        let highlight = slices
+            .draw_commands()
+            .iter()
+            .find_map(|command| match command {
+                DrawCommand::Behind(draw) => Some(draw(Size::new(100.0, 20.0))),
+                _ => None,
+            })
+            .expect("selection highlight");
+        assert!(matches!(
+            highlight.as_slice(),
+            [DrawPrimitive::Rect { brush: Brush::Solid(color), .. }] if *color == configured
+        ));
+
+        // Opaque colors are made translucent so the text stays readable.
+        assert_eq!(
+            selection_brush(Color::BLUE),
+            Brush::solid(Color(0.0, 0.0, 1.0, OPAQUE_SELECTION_ALPHA))
+        );
+    }
+}
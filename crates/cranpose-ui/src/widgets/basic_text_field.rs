@@ -6,6 +6,7 @@
 #![allow(non_snake_case)]
 
 use crate::composable;
+use crate::composition_local::local_text_selection_colors;
 use crate::layout::policies::EmptyMeasurePolicy;
 use crate::modifier::Modifier;
 use crate::text_field_modifier_node::TextFieldElement;
@@ -38,23 +39,19 @@ pub fn BasicTextField(state: TextFieldState, modifier: Modifier) -> NodeId {
 }
 
 /// Options for customizing BasicTextField appearance and behavior.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Colors left as `None` come from the nearest
+/// [`local_text_selection_colors`](crate::local_text_selection_colors).
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct BasicTextFieldOptions {
-    /// Cursor color
-    pub cursor_color: Color,
+    /// Cursor color override
+    pub cursor_color: Option<Color>,
+    /// Selection highlight color override
+    pub selection_color: Option<Color>,
     /// Line limits: SingleLine or MultiLine with optional min/max
     pub line_limits: TextFieldLineLimits,
 }
 
-impl Default for BasicTextFieldOptions {
-    fn default() -> Self {
-        Self {
-            cursor_color: Color(0.0, 0.0, 0.0, 1.0), // Black
-            line_limits: TextFieldLineLimits::default(),
-        }
-    }
-}
-
 /// Creates an editable text field with custom options.
 ///
 /// This is the full version of `BasicTextField` with all configuration options.
@@ -69,9 +66,12 @@ pub fn BasicTextFieldWithOptions(
     // automatically creates composition dependency via the snapshot system.
     let _text = state.text();
 
+    let colors = local_text_selection_colors().current();
+
     // Build the text field element with line limits
     let text_field_element = TextFieldElement::new(state)
-        .with_cursor_color(options.cursor_color)
+        .with_cursor_color(options.cursor_color.unwrap_or(colors.cursor_color))
+        .with_selection_color(options.selection_color.unwrap_or(colors.selection_color))
         .with_line_limits(options.line_limits);
 
     // Wrap it in a modifier
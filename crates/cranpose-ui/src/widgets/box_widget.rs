@@ -12,7 +12,9 @@ use cranpose_ui_layout::Alignment;
 /// Specification for Box layout behavior.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BoxSpec {
+    /// Default alignment for children without a `Modifier::align`.
     pub content_alignment: Alignment,
+    /// Whether the incoming min constraints are passed on to children.
     pub propagate_min_constraints: bool,
 }
 
@@ -21,11 +23,16 @@ impl BoxSpec {
         Self::default()
     }
 
+    /// Sets the default child alignment (Kotlin: `contentAlignment`).
+    ///
+    /// A child's own `Modifier::align` takes precedence over this value.
     pub fn content_alignment(mut self, alignment: Alignment) -> Self {
         self.content_alignment = alignment;
         self
     }
 
+    /// Passes the incoming min constraints on to children (Kotlin:
+    /// `propagateMinConstraints`), e.g. so a single child fills a sized box.
     pub fn propagate_min_constraints(mut self, propagate: bool) -> Self {
         self.propagate_min_constraints = propagate;
         self
@@ -45,21 +52,25 @@ impl Default for BoxSpec {
 ///
 /// Use `Box` to:
 /// - Overlay elements (e.g., text over an image).
-/// - Size a child to match its parent.
+/// - Size a child to match its parent (`Modifier::match_parent_size`).
 /// - Apply a background or border to a single child.
 ///
 /// # Arguments
 ///
 /// * `modifier` - Modifiers to apply to the box layout.
 /// * `spec` - Configuration for content alignment.
-/// * `content` - The children composables to layout (z-order is first-to-last).
+/// * `content` - The children composables to layout (z-order is first-to-last,
+///   unless a child sets `Modifier::z_index`).
+///
+/// Each child is positioned by its `Modifier::align(alignment)` if present,
+/// otherwise by `spec.content_alignment`.
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// Box(
 ///     Modifier::size(100.0, 100.0).background(Color::Blue),
-///     BoxSpec::default().content_alignment(Alignment::Center),
+///     BoxSpec::default().content_alignment(Alignment::CENTER),
 ///     || {
 ///         Text("Centered", Modifier::empty());
 ///     }
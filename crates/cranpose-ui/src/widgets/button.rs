@@ -4,13 +4,21 @@
 
 use crate::composable;
 use crate::layout::policies::FlexMeasurePolicy;
-use crate::modifier::Modifier;
+use crate::modifier::{Modifier, Role};
 use crate::widgets::Layout;
 use cranpose_core::NodeId;
 use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement};
 
+/// Minimum width and height of a button, the smallest comfortable touch target.
+///
+/// Applies when the parent sets no minimum; an explicit size still wins.
+pub const BUTTON_MIN_SIZE: f32 = 48.0;
+
 /// A clickable button with a background and content.
 ///
+/// The button is a single accessibility node with the button role, labelled
+/// with the text of its content.
+///
 /// # When to use
 /// Use this to trigger an action when clicked. The button serves as a container
 /// for other composables (typically `Text`).
@@ -18,6 +26,7 @@ use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement};
 /// # Arguments
 ///
 /// * `modifier` - Modifiers to apply to the button container (e.g., size, padding).
+///   Without an explicit size the button is at least [`BUTTON_MIN_SIZE`] square.
 /// * `on_click` - The callback to execute when the button is clicked.
 /// * `content` - The content to display inside the button (e.g., `Text` or `Icon`).
 ///
@@ -32,6 +41,36 @@ use cranpose_ui_layout::{HorizontalAlignment, LinearArrangement};
 /// ```
 #[composable]
 pub fn Button<F, G>(modifier: Modifier, on_click: F, content: G) -> NodeId
+where
+    F: FnMut() + 'static,
+    G: FnMut() + 'static,
+{
+    ButtonWithOptions(modifier, ButtonOptions::default(), on_click, content)
+}
+
+/// Options for customizing Button behavior.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ButtonOptions {
+    /// Whether the button reacts to clicks; disabled buttons show no ripple
+    pub enabled: bool,
+}
+
+impl Default for ButtonOptions {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Creates a button with custom options.
+///
+/// This is the full version of `Button` with all configuration options.
+#[composable]
+pub fn ButtonWithOptions<F, G>(
+    modifier: Modifier,
+    options: ButtonOptions,
+    on_click: F,
+    content: G,
+) -> NodeId
 where
     F: FnMut() + 'static,
     G: FnMut() + 'static,
@@ -43,9 +82,12 @@ where
     let on_click_rc: Rc<RefCell<dyn FnMut()>> = Rc::new(RefCell::new(on_click));
 
     // Add clickable modifier to handle click events
-    let clickable_modifier = modifier.clickable(move |_point| {
-        (on_click_rc.borrow_mut())();
-    });
+    let clickable_modifier = modifier
+        .clickable_enabled(options.enabled, move |_point| {
+            (on_click_rc.borrow_mut())();
+        })
+        .merged_semantics(|config| config.role = Some(Role::Button))
+        .default_min_size(BUTTON_MIN_SIZE, BUTTON_MIN_SIZE);
 
     // Use Layout with FlexMeasurePolicy (column) to arrange button content
     // This matches how Button is implemented in Jetpack Compose
@@ -0,0 +1,68 @@
+//! Dialog widget implementation
+
+#![allow(non_snake_case)]
+
+use crate::composable;
+use crate::modifier::Color;
+use crate::widgets::popup::{show_popup, PopupCallback, PopupPlacement};
+use cranpose_ui_layout::Alignment;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Color laid over the window behind a dialog.
+const DIALOG_SCRIM: Color = Color(0.0, 0.0, 0.0, 0.32);
+
+/// Shows `content` centered in the window, above a scrim that dims the rest
+/// of the app and keeps pointer input from reaching it.
+///
+/// The dialog fades in when it enters the composition and fades out after it
+/// leaves.
+///
+/// # When to use
+/// Use `Dialog` for a decision or message the user must deal with before
+/// going back to the app. `content` draws the dialog's own surface.
+///
+/// # Arguments
+///
+/// * `on_dismiss` - Called when the user clicks the scrim or presses Escape.
+///   Close the dialog from it by no longer composing it.
+/// * `content` - The dialog's content.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// if show_dialog.get() {
+///     Dialog(move || show_dialog.set(false), || {
+///         Column(Modifier::empty().background(Color::WHITE).padding(24.0), ColumnSpec::default(), || {
+///             Text("Discard draft?", Modifier::empty());
+///         });
+///     });
+/// }
+/// ```
+///
+/// Matches Kotlin: `Dialog(onDismissRequest = onDismiss) { content() }`
+#[composable]
+pub fn Dialog<D, F>(on_dismiss: D, content: F)
+where
+    D: FnMut() + 'static,
+    F: FnMut() + 'static,
+{
+    let content: PopupCallback = Rc::new(RefCell::new(content));
+    let placement = PopupPlacement {
+        anchor_bounds: None,
+        alignment: Alignment::CENTER,
+        scrim: Some(DIALOG_SCRIM),
+    };
+    if !show_popup(
+        placement,
+        Rc::new(RefCell::new(on_dismiss)),
+        Rc::clone(&content),
+    ) {
+        // Without a host the content can only be composed in place
+        (content.borrow_mut())();
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/dialog_tests.rs"]
+mod tests;
@@ -0,0 +1,53 @@
+//! Divider widget implementation
+
+#![allow(non_snake_case)]
+
+use crate::composable;
+use crate::layout::policies::LeafMeasurePolicy;
+use crate::modifier::{Brush, Color, Modifier, Point, Size};
+use crate::widgets::Layout;
+use cranpose_core::NodeId;
+
+/// A thin horizontal line that separates content.
+///
+/// # When to use
+/// Use `Divider` between items of a `Column` or list instead of hand-rolling a
+/// thin background rect.
+///
+/// # Arguments
+///
+/// * `modifier` - Additional modifiers. The divider fills the available width.
+/// * `thickness` - Height of the line.
+/// * `color` - Color of the line.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Column(Modifier::empty(), ColumnSpec::default(), || {
+///     Text("Above", Modifier::empty());
+///     Divider(Modifier::empty(), 1.0, Color(0.8, 0.8, 0.8, 1.0));
+///     Text("Below", Modifier::empty());
+/// });
+/// ```
+///
+/// Matches Kotlin: `HorizontalDivider(modifier, thickness, color)`
+#[composable]
+pub fn Divider(modifier: Modifier, thickness: f32, color: Color) -> NodeId {
+    Layout(
+        modifier
+            .fill_max_width()
+            .height(thickness)
+            .draw_behind(move |scope| {
+                let size = scope.size();
+                let y = size.height / 2.0;
+                scope.draw_line(
+                    Point::new(0.0, y),
+                    Point::new(size.width, y),
+                    size.height,
+                    Brush::solid(color),
+                );
+            }),
+        LeafMeasurePolicy::new(Size::ZERO),
+        || {}, // No children
+    )
+}
@@ -0,0 +1,156 @@
+//! FlowRow and FlowColumn widget implementations
+
+#![allow(non_snake_case)]
+
+use super::layout::Layout;
+use crate::composable;
+use crate::layout::policies::FlowMeasurePolicy;
+use crate::modifier::Modifier;
+use cranpose_core::NodeId;
+use cranpose_ui_layout::{Axis, LinearArrangement};
+
+/// Specification for FlowRow layout behavior.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlowRowSpec {
+    /// Arrangement of the children within each row.
+    pub horizontal_arrangement: LinearArrangement,
+    /// Arrangement of the rows.
+    pub vertical_arrangement: LinearArrangement,
+    pub max_items_in_each_row: usize,
+}
+
+impl FlowRowSpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn horizontal_arrangement(mut self, arrangement: LinearArrangement) -> Self {
+        self.horizontal_arrangement = arrangement;
+        self
+    }
+
+    pub fn vertical_arrangement(mut self, arrangement: LinearArrangement) -> Self {
+        self.vertical_arrangement = arrangement;
+        self
+    }
+
+    pub fn max_items_in_each_row(mut self, max_items: usize) -> Self {
+        self.max_items_in_each_row = max_items;
+        self
+    }
+}
+
+impl Default for FlowRowSpec {
+    fn default() -> Self {
+        Self {
+            horizontal_arrangement: LinearArrangement::Start,
+            vertical_arrangement: LinearArrangement::Start,
+            max_items_in_each_row: usize::MAX,
+        }
+    }
+}
+
+/// Specification for FlowColumn layout behavior.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlowColumnSpec {
+    /// Arrangement of the children within each column.
+    pub vertical_arrangement: LinearArrangement,
+    /// Arrangement of the columns.
+    pub horizontal_arrangement: LinearArrangement,
+    pub max_items_in_each_column: usize,
+}
+
+impl FlowColumnSpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn vertical_arrangement(mut self, arrangement: LinearArrangement) -> Self {
+        self.vertical_arrangement = arrangement;
+        self
+    }
+
+    pub fn horizontal_arrangement(mut self, arrangement: LinearArrangement) -> Self {
+        self.horizontal_arrangement = arrangement;
+        self
+    }
+
+    pub fn max_items_in_each_column(mut self, max_items: usize) -> Self {
+        self.max_items_in_each_column = max_items;
+        self
+    }
+}
+
+impl Default for FlowColumnSpec {
+    fn default() -> Self {
+        Self {
+            vertical_arrangement: LinearArrangement::Start,
+            horizontal_arrangement: LinearArrangement::Start,
+            max_items_in_each_column: usize::MAX,
+        }
+    }
+}
+
+/// A layout composable that places its children in rows, wrapping onto a new
+/// row when the next child does not fit in the available width.
+///
+/// # When to use
+/// Use `FlowRow` for chips, tags and other items whose count or size varies.
+/// For a single line that never wraps, use [`Row`](crate::widgets::Row).
+///
+/// # Arguments
+///
+/// * `modifier` - Modifiers to apply to the flow layout.
+/// * `spec` - Arrangement of children and rows, and how many children a row holds.
+/// * `content` - The children composables to layout.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// FlowRow(
+///     Modifier::fill_max_width(),
+///     FlowRowSpec::default()
+///         .horizontal_arrangement(LinearArrangement::SpacedBy(8.0))
+///         .vertical_arrangement(LinearArrangement::SpacedBy(8.0)),
+///     || {
+///         for tag in ["rust", "compose", "ui"] {
+///             Text(tag, Modifier::empty());
+///         }
+///     }
+/// );
+/// ```
+#[composable]
+pub fn FlowRow<F>(modifier: Modifier, spec: FlowRowSpec, content: F) -> NodeId
+where
+    F: FnMut() + 'static,
+{
+    let policy = FlowMeasurePolicy::new(
+        Axis::Horizontal,
+        spec.horizontal_arrangement,
+        spec.vertical_arrangement,
+        spec.max_items_in_each_row,
+    );
+    Layout(modifier, policy, content)
+}
+
+/// A layout composable that places its children in columns, wrapping onto a
+/// new column when the next child does not fit in the available height.
+///
+/// # Arguments
+///
+/// * `modifier` - Modifiers to apply to the flow layout.
+/// * `spec` - Arrangement of children and columns, and how many children a column holds.
+/// * `content` - The children composables to layout.
+#[composable]
+pub fn FlowColumn<F>(modifier: Modifier, spec: FlowColumnSpec, content: F) -> NodeId
+where
+    F: FnMut() + 'static,
+{
+    let policy = FlowMeasurePolicy::new(
+        Axis::Vertical,
+        spec.vertical_arrangement,
+        spec.horizontal_arrangement,
+        spec.max_items_in_each_column,
+    );
+    Layout(modifier, policy, content)
+}
@@ -5,16 +5,204 @@
 use super::nodes::LayoutNode;
 use super::scopes::{BoxWithConstraintsScope, BoxWithConstraintsScopeImpl};
 use crate::composable;
-use crate::modifier::Modifier;
+use crate::modifier::{Modifier, Size};
 use crate::subcompose_layout::{
     Constraints, MeasurePolicy as SubcomposeMeasurePolicy, MeasureResult, SubcomposeLayoutNode,
     SubcomposeLayoutScope, SubcomposeMeasureScope, SubcomposeMeasureScopeImpl,
 };
 use cranpose_core::{NodeId, SlotId};
-use cranpose_ui_layout::{MeasurePolicy, Placement};
+use cranpose_ui_layout::{Measurable, MeasurePolicy, Placeable, Placement};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+type MeasureFn = dyn Fn(&[Box<dyn Measurable>], Constraints) -> MeasureResult;
+
+/// [`MeasurePolicy`] running a closure, for custom layouts built with [`Layout`].
+///
+/// The closure measures the children within `constraints` and returns the
+/// layout's size with their placements. Intrinsic sizes run it with every
+/// child standing in at its own intrinsic size, as Compose does for layouts
+/// that do not define intrinsics.
+///
+/// ```text
+/// Layout(
+///     modifier,
+///     FnMeasurePolicy::new(|measurables, constraints| {
+///         let mut placements = Vec::new();
+///         for measurable in measurables {
+///             let placeable = measurable.measure(constraints);
+///             placements.push(Placement::new(placeable.node_id(), 0.0, 0.0, 0));
+///         }
+///         MeasureResult::new(Size { width: 100.0, height: 100.0 }, placements)
+///     }),
+///     content,
+/// );
+/// ```
+///
+/// Matches Kotlin: `Layout(content, modifier) { measurables, constraints -> ... }`
+#[derive(Clone)]
+pub struct FnMeasurePolicy(Rc<MeasureFn>);
+
+impl FnMeasurePolicy {
+    pub fn new(
+        measure: impl Fn(&[Box<dyn Measurable>], Constraints) -> MeasureResult + 'static,
+    ) -> Self {
+        Self(Rc::new(measure))
+    }
+
+    /// Runs the closure with the children replaced by `placeholder`s built
+    /// from each child.
+    fn measure_placeholders(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        constraints: Constraints,
+        placeholder: impl Fn(&dyn Measurable) -> IntrinsicPlaceholder,
+    ) -> Size {
+        let placeholders: Vec<Box<dyn Measurable>> = measurables
+            .iter()
+            .map(|measurable| Box::new(placeholder(measurable.as_ref())) as Box<dyn Measurable>)
+            .collect();
+        (self.0)(&placeholders, constraints).size
+    }
+}
+
+impl PartialEq for FnMeasurePolicy {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl MeasurePolicy for FnMeasurePolicy {
+    fn measure(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        constraints: Constraints,
+    ) -> MeasureResult {
+        (self.0)(measurables, constraints)
+    }
+
+    fn min_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
+        self.measure_placeholders(measurables, height_constraints(height), |child| {
+            IntrinsicPlaceholder::width(child.min_intrinsic_width(height))
+        })
+        .width
+    }
+
+    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
+        self.measure_placeholders(measurables, height_constraints(height), |child| {
+            IntrinsicPlaceholder::width(child.max_intrinsic_width(height))
+        })
+        .width
+    }
+
+    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        self.measure_placeholders(measurables, width_constraints(width), |child| {
+            IntrinsicPlaceholder::height(child.min_intrinsic_height(width))
+        })
+        .height
+    }
+
+    fn max_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        self.measure_placeholders(measurables, width_constraints(width), |child| {
+            IntrinsicPlaceholder::height(child.max_intrinsic_height(width))
+        })
+        .height
+    }
+}
+
+fn height_constraints(height: f32) -> Constraints {
+    Constraints::loose(f32::INFINITY, height)
+}
+
+fn width_constraints(width: f32) -> Constraints {
+    Constraints::loose(width, f32::INFINITY)
+}
+
+/// A child reduced to one intrinsic dimension; the other takes the bounded
+/// maximum of the constraints it is measured with, or zero.
+///
+/// Its placements are discarded, so it is never placed.
+#[derive(Clone, Copy)]
+struct IntrinsicPlaceholder {
+    width: Option<f32>,
+    height: Option<f32>,
+}
+
+impl IntrinsicPlaceholder {
+    fn width(width: f32) -> Self {
+        Self {
+            width: Some(width),
+            height: None,
+        }
+    }
+
+    fn height(height: f32) -> Self {
+        Self {
+            width: None,
+            height: Some(height),
+        }
+    }
+}
+
+fn bounded_or_zero(max: f32) -> f32 {
+    if max.is_finite() {
+        max
+    } else {
+        0.0
+    }
+}
+
+impl Measurable for IntrinsicPlaceholder {
+    fn measure(&self, constraints: Constraints) -> Box<dyn Placeable> {
+        Box::new(PlaceholderPlaceable(Size {
+            width: self
+                .width
+                .unwrap_or_else(|| bounded_or_zero(constraints.max_width)),
+            height: self
+                .height
+                .unwrap_or_else(|| bounded_or_zero(constraints.max_height)),
+        }))
+    }
+
+    fn min_intrinsic_width(&self, _height: f32) -> f32 {
+        self.width.unwrap_or(0.0)
+    }
+
+    fn max_intrinsic_width(&self, _height: f32) -> f32 {
+        self.width.unwrap_or(0.0)
+    }
+
+    fn min_intrinsic_height(&self, _width: f32) -> f32 {
+        self.height.unwrap_or(0.0)
+    }
+
+    fn max_intrinsic_height(&self, _width: f32) -> f32 {
+        self.height.unwrap_or(0.0)
+    }
+}
+
+struct PlaceholderPlaceable(Size);
+
+impl Placeable for PlaceholderPlaceable {
+    fn place(&self, _x: f32, _y: f32) {}
+
+    fn width(&self) -> f32 {
+        self.0.width
+    }
+
+    fn height(&self) -> f32 {
+        self.0.height
+    }
+
+    fn node_id(&self) -> NodeId {
+        0
+    }
+}
+
+/// Lays out `content` with `measure_policy`.
+///
+/// Built-in containers are layouts with their own policies; implement
+/// [`MeasurePolicy`] or use [`FnMeasurePolicy`] for custom ones.
 #[composable]
 pub fn Layout<F, P>(modifier: Modifier, measure_policy: P, content: F) -> NodeId
 where
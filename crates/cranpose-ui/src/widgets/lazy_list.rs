@@ -6,9 +6,12 @@
 #![allow(non_snake_case)]
 #![allow(dead_code)] // Widget API is WIP
 
+use std::fmt;
+use std::ops::Range;
 use std::rc::Rc;
 
 use crate::modifier::Modifier;
+use crate::scroll::ScrollState;
 use crate::subcompose_layout::{
     Placement, SubcomposeLayoutNode, SubcomposeLayoutScope, SubcomposeMeasureScope,
     SubcomposeMeasureScopeImpl,
@@ -23,8 +26,48 @@ use cranpose_ui_layout::{Constraints, LinearArrangement, MeasureResult, Placeabl
 use smallvec::SmallVec;
 
 // Re-export from foundation - single source of truth
+pub use cranpose_core::SlotReuseLimits;
 pub use cranpose_foundation::lazy::{LazyListItemInfo, LazyListLayoutInfo};
 
+/// On-screen part of a lazy list nested in a scrolling parent.
+///
+/// The parent's scroll offset is read while the list measures, so scrolling
+/// the parent remeasures the list without recomposing it.
+#[derive(Clone)]
+pub struct KnownViewport {
+    scroll: ScrollState,
+    list_offset: f32,
+    viewport_size: f32,
+}
+
+impl KnownViewport {
+    /// Creates a viewport for a list placed `list_offset` into the content
+    /// scrolled by `scroll`, shown through a parent `viewport_size` long.
+    pub fn new(scroll: ScrollState, list_offset: f32, viewport_size: f32) -> Self {
+        Self {
+            scroll,
+            list_offset,
+            viewport_size,
+        }
+    }
+
+    /// The window of the list the parent currently shows, in the list's coordinates.
+    pub fn window(&self) -> Range<f32> {
+        let start = self.scroll.value_non_reactive() - self.list_offset;
+        start..start + self.viewport_size
+    }
+}
+
+impl fmt::Debug for KnownViewport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KnownViewport")
+            .field("scroll", &self.scroll.id())
+            .field("list_offset", &self.list_offset)
+            .field("viewport_size", &self.viewport_size)
+            .finish()
+    }
+}
+
 /// Specification for LazyColumn layout behavior.
 #[derive(Clone, Debug)]
 pub struct LazyColumnSpec {
@@ -39,6 +82,10 @@ pub struct LazyColumnSpec {
     pub beyond_bounds_item_count: usize,
     /// Whether to reverse the layout direction (bottom-to-top).
     pub reverse_layout: bool,
+    /// How many scrolled-out items are kept for reuse.
+    pub reuse_policy: SlotReuseLimits,
+    /// On-screen part of the list when it is nested in a scrolling parent.
+    pub known_viewport: Option<KnownViewport>,
 }
 
 impl Default for LazyColumnSpec {
@@ -49,6 +96,8 @@ impl Default for LazyColumnSpec {
             content_padding_bottom: 0.0,
             beyond_bounds_item_count: 2,
             reverse_layout: false,
+            reuse_policy: SlotReuseLimits::default(),
+            known_viewport: None,
         }
     }
 }
@@ -80,6 +129,25 @@ impl LazyColumnSpec {
         self.reverse_layout = reverse;
         self
     }
+
+    /// Sets how many scrolled-out items are kept for reuse.
+    ///
+    /// Each pool should hold at least `beyond_bounds_item_count * 2` plus the
+    /// visible item count, otherwise items are recomposed while scrolling.
+    pub fn reuse_policy(mut self, policy: SlotReuseLimits) -> Self {
+        self.reuse_policy = policy;
+        self
+    }
+
+    /// Sets the scrolling parent that decides which part of the list is on screen.
+    ///
+    /// Used when the list has an unbounded viewport, e.g. inside a vertically
+    /// scrolling `Column`, so only the items inside the parent's window are
+    /// composed. Ignored when the list has a bounded viewport.
+    pub fn known_viewport(mut self, viewport: KnownViewport) -> Self {
+        self.known_viewport = Some(viewport);
+        self
+    }
 }
 
 /// Specification for LazyRow layout behavior.
@@ -95,6 +163,10 @@ pub struct LazyRowSpec {
     pub beyond_bounds_item_count: usize,
     /// Whether to reverse the layout direction (end-to-start).
     pub reverse_layout: bool,
+    /// How many scrolled-out items are kept for reuse.
+    pub reuse_policy: SlotReuseLimits,
+    /// On-screen part of the list when it is nested in a scrolling parent.
+    pub known_viewport: Option<KnownViewport>,
 }
 
 impl Default for LazyRowSpec {
@@ -105,6 +177,8 @@ impl Default for LazyRowSpec {
             content_padding_end: 0.0,
             beyond_bounds_item_count: 2,
             reverse_layout: false,
+            reuse_policy: SlotReuseLimits::default(),
+            known_viewport: None,
         }
     }
 }
@@ -136,6 +210,25 @@ impl LazyRowSpec {
         self.reverse_layout = reverse;
         self
     }
+
+    /// Sets how many scrolled-out items are kept for reuse.
+    ///
+    /// Each pool should hold at least `beyond_bounds_item_count * 2` plus the
+    /// visible item count, otherwise items are recomposed while scrolling.
+    pub fn reuse_policy(mut self, policy: SlotReuseLimits) -> Self {
+        self.reuse_policy = policy;
+        self
+    }
+
+    /// Sets the scrolling parent that decides which part of the list is on screen.
+    ///
+    /// Used when the list has an unbounded viewport, e.g. inside a vertically
+    /// scrolling `Column`, so only the items inside the parent's window are
+    /// composed. Ignored when the list has a bounded viewport.
+    pub fn known_viewport(mut self, viewport: KnownViewport) -> Self {
+        self.known_viewport = Some(viewport);
+        self
+    }
 }
 
 /// Internal helper to create a lazy list measure policy.
@@ -146,6 +239,7 @@ fn measure_lazy_list_internal(
     content: &LazyListIntervalContent,
     state: &LazyListState,
     config: &LazyListMeasureConfig,
+    reuse_policy: SlotReuseLimits,
 ) -> MeasureResult {
     let viewport_size = if is_vertical {
         constraints.max_height
@@ -158,108 +252,43 @@ fn measure_lazy_list_internal(
         constraints.max_height
     };
 
+    // Pools are trimmed to the new limits when this pass finishes
+    scope.set_reuse_limits(reuse_policy);
+
     let items_count = content.item_count();
     // Scroll position stability: if items were added/removed before the first visible,
     // find the item by key and adjust scroll position (JC's updateScrollPositionIfTheFirstItemWasMoved)
     if items_count > 0 {
-        // Scroll position stability: try O(1) range search first, fall back to O(N) global search
-        // This matches the performance-optimal pattern: most items are found within the range
+        // Scroll position stability: search the nearest range first, then widen outward.
+        // Most moved items are found close to the range, so huge lists avoid a full scan
         let range = state.nearest_range();
         state.update_scroll_position_if_item_moved(items_count, |slot_id| {
-            content
-                .get_index_by_slot_id_in_range(slot_id, range.clone())
-                .or_else(|| content.get_index_by_slot_id(slot_id))
+            content.get_index_by_slot_id_near(slot_id, range.clone())
         });
         // Note: nearest range is automatically updated by scroll_position when index changes
     }
 
-    // Measure function that subcomposes and measures each item
-    let measure_item = |index: usize| -> LazyListMeasuredItem {
-        let key = content.get_key(index);
-        let key_slot_id = key.to_slot_id();
-        let content_type = content.get_content_type(index);
-
-        // Subcompose the item content with its own slot ID
-        // The Composer handles node reuse internally via slot ID matching
-        let slot_id = SlotId(key_slot_id);
-
-        // Update content type for policy-based reuse matching
-        // Uses update_content_type to handle both Some and None cases,
-        // ensuring stale types don't drive incorrect reuse after transitions
-        scope.update_content_type(slot_id, content_type);
-
-        let children = scope.subcompose(slot_id, || {
-            content.invoke_content(index);
-        });
-
-        // Record composition statistics for diagnostics
-        let was_reused = scope.was_last_slot_reused().unwrap_or(false);
-        state.record_composition(was_reused);
-
-        // NOTE: Composer::subcompose_measurement now returns only root nodes directly,
-        // so we no longer need the O(N) filter here.
-        let root_children = children;
-
-        // Measure the children using constraints
-        // For LazyColumn (vertical): width is constrained (max = cross_axis_size), height is unbounded (INFINITY)
-        // For LazyRow (horizontal): height is constrained, width is unbounded
-        let child_constraints = if is_vertical {
-            Constraints {
-                min_width: 0.0,
-                max_width: cross_axis_size,
-                min_height: 0.0,
-                max_height: f32::INFINITY,
-            }
-        } else {
-            Constraints {
-                min_width: 0.0,
-                max_width: f32::INFINITY,
-                min_height: 0.0,
-                max_height: cross_axis_size,
-            }
-        };
-
-        // Measure only ROOT nodes returned by subcompose.
-        // Each root node handles its children's layout internally - we should NOT
-        // iterate over all descendants or they'll be placed separately in the list.
-        //
-        // subcompose() returns only direct children (root nodes) of the slot composition.
-        // If you compose `Row { Text(...); Text(...); }`, subcompose returns just [Row],
-        // not [Row, Text, Text]. The Row measures its own children during its layout.
-        let mut total_main_size: f32 = 0.0;
-        let mut max_cross_size: f32 = 0.0;
-        let mut node_ids: SmallNodeVec = SmallVec::new();
-        let mut child_offsets: SmallOffsetVec = SmallVec::new();
-
-        // Measure each ROOT node (typically just one per item)
-        for child in root_children {
-            let placeable = scope.measure(child, child_constraints);
-            let (main, cross) = if is_vertical {
-                (placeable.height(), placeable.width())
-            } else {
-                (placeable.width(), placeable.height())
-            };
-
-            // Track offset of this root node within the item
-            child_offsets.push(total_main_size);
-            node_ids.push(child.node_id() as u64);
-
-            total_main_size += main;
-            max_cross_size = max_cross_size.max(cross);
+    // For LazyColumn (vertical): width is constrained (max = cross_axis_size), height is unbounded (INFINITY)
+    // For LazyRow (horizontal): height is constrained, width is unbounded
+    let child_constraints = if is_vertical {
+        Constraints {
+            min_width: 0.0,
+            max_width: cross_axis_size,
+            min_height: 0.0,
+            max_height: f32::INFINITY,
         }
+    } else {
+        Constraints {
+            min_width: 0.0,
+            max_width: f32::INFINITY,
+            min_height: 0.0,
+            max_height: cross_axis_size,
+        }
+    };
 
-        let mut item = LazyListMeasuredItem::new(
-            index,
-            key_slot_id,
-            content_type,
-            total_main_size,
-            max_cross_size,
-        );
-
-        item.node_ids = node_ids;
-        item.child_offsets = child_offsets;
-
-        item
+    // Measure function that subcomposes and measures each item
+    let measure_item = |index: usize| -> LazyListMeasuredItem {
+        measure_lazy_item(scope, content, state, index, is_vertical, child_constraints)
     };
 
     // Capture scroll delta for direction inference BEFORE measurement consumes it.
@@ -275,12 +304,13 @@ fn measure_lazy_list_internal(
         viewport_size,
         cross_axis_size,
         config,
+        |index| content.get_key(index).to_slot_id(),
         measure_item,
     );
 
     // Cache measured item sizes for better scroll estimation
     for item in &result.visible_items {
-        state.cache_item_size(item.index, item.main_axis_size);
+        state.cache_item_size(item.key, item.main_axis_size);
     }
 
     // Update stats: count only items WITHIN viewport, not beyond-bounds buffer
@@ -297,6 +327,20 @@ fn measure_lazy_list_internal(
     let in_pool = scope.reusable_slots_count();
     state.update_stats(truly_visible_count, in_pool);
 
+    // Items were dropped from a full pool: scrolling back will recompose them
+    if scope.pool_overflow_count() > 0 && state.record_pool_overflow() {
+        let typed = result
+            .visible_items
+            .first()
+            .is_some_and(|item| item.content_type.is_some());
+        warn_reuse_pool_overflow(
+            reuse_policy,
+            typed,
+            config.beyond_bounds_item_count,
+            truly_visible_count,
+        );
+    }
+
     // Prefetching: pre-compose items before they become visible
     // Direction is inferred from actual scroll delta (more accurate than index comparison)
 
@@ -330,7 +374,7 @@ fn measure_lazy_list_internal(
 
                 // Use cached size if available, otherwise use average
                 let estimated_size = state
-                    .get_cached_size(idx)
+                    .get_cached_size(key_slot_id)
                     .unwrap_or(average_size.max(DEFAULT_ITEM_SIZE_ESTIMATE));
 
                 let prefetch_idx = idx;
@@ -363,11 +407,12 @@ fn measure_lazy_list_internal(
 
     // Create placements from measured items - place only ROOT nodes
     // JC Pattern (LazyListMeasure.kt:calculateItemsOffsets)
+    // The measured viewport differs from constraints when the list is unbounded
     let placements = create_lazy_list_placements(
         &result.visible_items,
         items_count,
         is_vertical,
-        viewport_size,
+        result.viewport_size,
         config,
     );
 
@@ -393,6 +438,106 @@ fn measure_lazy_list_internal(
     scope.layout(width, height, placements)
 }
 
+/// Subcomposes the item at `index` and measures its root nodes with
+/// `constraints`, stacking them along the main axis.
+pub(super) fn measure_lazy_item(
+    scope: &mut SubcomposeMeasureScopeImpl<'_>,
+    content: &LazyListIntervalContent,
+    state: &LazyListState,
+    index: usize,
+    is_vertical: bool,
+    constraints: Constraints,
+) -> LazyListMeasuredItem {
+    let key = content.get_key(index);
+    let key_slot_id = key.to_slot_id();
+    let content_type = content.get_content_type(index);
+
+    // Subcompose the item content with its own slot ID
+    // The Composer handles node reuse internally via slot ID matching
+    let slot_id = SlotId(key_slot_id);
+
+    // Update content type for policy-based reuse matching
+    // Uses update_content_type to handle both Some and None cases,
+    // ensuring stale types don't drive incorrect reuse after transitions
+    scope.update_content_type(slot_id, content_type);
+
+    let children = scope.subcompose(slot_id, || {
+        content.invoke_content(index);
+    });
+
+    // Record composition statistics for diagnostics
+    let was_reused = scope.was_last_slot_reused().unwrap_or(false);
+    state.record_composition(was_reused);
+
+    // Measure only ROOT nodes returned by subcompose.
+    // Each root node handles its children's layout internally - we should NOT
+    // iterate over all descendants or they'll be placed separately in the list.
+    //
+    // subcompose() returns only direct children (root nodes) of the slot composition.
+    // If you compose `Row { Text(...); Text(...); }`, subcompose returns just [Row],
+    // not [Row, Text, Text]. The Row measures its own children during its layout.
+    let mut total_main_size: f32 = 0.0;
+    let mut max_cross_size: f32 = 0.0;
+    let mut node_ids: SmallNodeVec = SmallVec::new();
+    let mut child_offsets: SmallOffsetVec = SmallVec::new();
+
+    // Measure each ROOT node (typically just one per item)
+    for child in children {
+        let placeable = scope.measure(child, constraints);
+        let (main, cross) = if is_vertical {
+            (placeable.height(), placeable.width())
+        } else {
+            (placeable.width(), placeable.height())
+        };
+
+        // Track offset of this root node within the item
+        child_offsets.push(total_main_size);
+        node_ids.push(child.node_id() as u64);
+
+        total_main_size += main;
+        max_cross_size = max_cross_size.max(cross);
+    }
+
+    let mut item = LazyListMeasuredItem::new(
+        index,
+        key_slot_id,
+        content_type,
+        total_main_size,
+        max_cross_size,
+    );
+
+    item.node_ids = node_ids;
+    item.child_offsets = child_offsets;
+
+    item
+}
+
+/// Warns when the pool used by the list's items cannot hold the beyond-bounds
+/// items on both sides plus one screen of items.
+///
+/// Smaller pools dispose nodes that the next scroll frame composes again.
+fn warn_reuse_pool_overflow(
+    policy: SlotReuseLimits,
+    typed: bool,
+    beyond_bounds_item_count: usize,
+    visible_count: usize,
+) {
+    let pool_size = if typed {
+        policy.max_slots_per_type
+    } else {
+        policy.max_untyped_slots
+    };
+    let required = beyond_bounds_item_count * 2 + visible_count;
+    log::warn!(
+        "Lazy list reuse pool overflowed at {} items; {} are needed to avoid recomposing \
+         while scrolling ({} beyond bounds on each side, {} visible)",
+        pool_size,
+        required,
+        beyond_bounds_item_count,
+        visible_count
+    );
+}
+
 fn get_spacing(arrangement: LinearArrangement) -> f32 {
     match arrangement {
         LinearArrangement::SpacedBy(spacing) => spacing,
@@ -400,116 +545,94 @@ fn get_spacing(arrangement: LinearArrangement) -> f32 {
     }
 }
 
-/// Creates placements for measured lazy list items.
+/// Computes the main-axis start offset of each measured item.
 ///
-/// This helper encapsulates the logic for:
-/// - Applying arrangement when all items fit (hasSpareSpace in JC)
-/// - Using sequential positioning during scrolling
-fn create_lazy_list_placements(
+/// Offsets are measured from the leading edge of the viewport and already
+/// include `before_content_padding`:
+/// - When every item fits and the arrangement distributes space (hasSpareSpace
+///   in JC), the arrangement positions items inside the padded area.
+/// - Otherwise (scrolling, `Start`, `SpacedBy`) the sequential offsets from
+///   measurement are used, which advance by `config.spacing` between items.
+fn lazy_list_item_offsets(
     visible_items: &[LazyListMeasuredItem],
     items_count: usize,
-    is_vertical: bool,
     viewport_size: f32,
     config: &LazyListMeasureConfig,
-) -> Vec<Placement> {
+) -> Vec<f32> {
     use cranpose_ui_layout::Arrangement;
 
-    let arrangement = if is_vertical {
-        config
-            .vertical_arrangement
-            .unwrap_or(LinearArrangement::Start)
+    let arrangement = if config.is_vertical {
+        config.vertical_arrangement
     } else {
-        config
-            .horizontal_arrangement
-            .unwrap_or(LinearArrangement::Start)
-    };
+        config.horizontal_arrangement
+    }
+    .unwrap_or(LinearArrangement::Start);
+
+    // Sequential arrangements are fully described by config.spacing
+    if matches!(
+        arrangement,
+        LinearArrangement::Start | LinearArrangement::SpacedBy(_)
+    ) {
+        return visible_items.iter().map(|item| item.offset).collect();
+    }
 
-    // Check if we should apply arrangement:
-    // 1. All items are visible (visible_items.len() == total items)
-    // 2. Content is smaller than viewport (hasSpareSpace)
-    // 3. Arrangement is not sequential (Start or SpacedBy)
-    let spacing = get_spacing(arrangement);
-    let total_item_size: f32 = visible_items.iter().map(|i| i.main_axis_size).sum::<f32>()
-        + (items_count.saturating_sub(1) as f32) * spacing;
     // Account for content padding when checking spare space (JC pattern)
     // Clamp to 0.0 to handle edge case where padding exceeds viewport
     let available_main_axis =
         (viewport_size - config.before_content_padding - config.after_content_padding).max(0.0);
+    let total_item_size: f32 = visible_items.iter().map(|i| i.main_axis_size).sum::<f32>()
+        + (items_count.saturating_sub(1) as f32) * config.spacing;
     let has_spare_space =
         total_item_size < available_main_axis && visible_items.len() == items_count;
-    let should_apply_arrangement = has_spare_space
-        && !matches!(
-            arrangement,
-            LinearArrangement::Start | LinearArrangement::SpacedBy(_)
-        );
-
-    if should_apply_arrangement {
-        // Apply arrangement to compute final positions
-        // JC: density.arrange(mainAxisLayoutSize, sizes, offsets)
-        let content_offset = config.before_content_padding;
-
-        let sizes: Vec<f32> = visible_items.iter().map(|i| i.main_axis_size).collect();
-        let mut positions = vec![0.0; sizes.len()];
-        arrangement.arrange(available_main_axis, &sizes, &mut positions);
+    if !has_spare_space {
+        return visible_items.iter().map(|item| item.offset).collect();
+    }
 
-        visible_items
-            .iter()
-            .zip(positions.iter())
-            .flat_map(|(item, &pos)| {
-                item.node_ids.iter().zip(item.child_offsets.iter()).map(
-                    move |(&nid, &child_offset)| {
-                        let node_id: NodeId = nid as NodeId;
-                        let item_size = item.main_axis_size;
+    // JC: density.arrange(mainAxisLayoutSize, sizes, offsets)
+    let sizes: Vec<f32> = visible_items.iter().map(|i| i.main_axis_size).collect();
+    let mut positions = vec![0.0; sizes.len()];
+    arrangement.arrange(available_main_axis, &sizes, &mut positions);
+    positions
+        .into_iter()
+        .map(|pos| config.before_content_padding + pos)
+        .collect()
+}
 
-                        if is_vertical {
-                            let y = if config.reverse_layout {
-                                viewport_size - (content_offset + pos) - item_size + child_offset
-                            } else {
-                                content_offset + pos + child_offset
-                            };
-                            Placement::new(node_id, 0.0, y, 0)
-                        } else {
-                            let x = if config.reverse_layout {
-                                viewport_size - (content_offset + pos) - item_size + child_offset
-                            } else {
-                                content_offset + pos + child_offset
-                            };
-                            Placement::new(node_id, x, 0.0, 0)
-                        }
-                    },
-                )
-            })
-            .collect()
-    } else {
-        // Use sequential offsets from measurement (scrolling case)
-        visible_items
-            .iter()
-            .flat_map(|item| {
-                item.node_ids.iter().zip(item.child_offsets.iter()).map(
-                    move |(&nid, &child_offset)| {
-                        let node_id: NodeId = nid as NodeId;
-                        let item_size = item.main_axis_size;
+/// Creates placements for measured lazy list items.
+///
+/// Item offsets come from [`lazy_list_item_offsets`]; this helper places each
+/// item's root nodes along the main axis, mirroring them for `reverse_layout`.
+fn create_lazy_list_placements(
+    visible_items: &[LazyListMeasuredItem],
+    items_count: usize,
+    is_vertical: bool,
+    viewport_size: f32,
+    config: &LazyListMeasureConfig,
+) -> Vec<Placement> {
+    let offsets = lazy_list_item_offsets(visible_items, items_count, viewport_size, config);
 
-                        if is_vertical {
-                            let y = if config.reverse_layout {
-                                viewport_size - item.offset - item_size + child_offset
-                            } else {
-                                item.offset + child_offset
-                            };
-                            Placement::new(node_id, 0.0, y, 0)
-                        } else {
-                            let x = if config.reverse_layout {
-                                viewport_size - item.offset - item_size + child_offset
-                            } else {
-                                item.offset + child_offset
-                            };
-                            Placement::new(node_id, x, 0.0, 0)
-                        }
-                    },
-                )
-            })
-            .collect()
-    }
+    visible_items
+        .iter()
+        .zip(offsets)
+        .flat_map(|(item, item_offset)| {
+            item.node_ids
+                .iter()
+                .zip(item.child_offsets.iter())
+                .map(move |(&nid, &child_offset)| {
+                    let node_id: NodeId = nid as NodeId;
+                    let main_axis = if config.reverse_layout {
+                        viewport_size - item_offset - item.main_axis_size + child_offset
+                    } else {
+                        item_offset + child_offset
+                    };
+                    if is_vertical {
+                        Placement::new(node_id, 0.0, main_axis, 0)
+                    } else {
+                        Placement::new(node_id, main_axis, 0.0, 0)
+                    }
+                })
+        })
+        .collect()
 }
 
 /// Internal implementation for LazyColumn that takes pre-built content.
@@ -542,21 +665,35 @@ fn LazyColumnImpl(
         beyond_bounds_item_count: spec.beyond_bounds_item_count,
         vertical_arrangement: Some(spec.vertical_arrangement),
         horizontal_arrangement: None,
+        known_viewport: None,
     };
     let config_state = cranpose_core::rememberUpdatedState(config);
+    let policy_state = cranpose_core::rememberUpdatedState(spec.reuse_policy);
+    let viewport_state = cranpose_core::rememberUpdatedState(spec.known_viewport.clone());
 
     // Create measure policy with stable identity using remember.
     // The policy reads latest values via state references, so it can be memoized.
     let content_for_policy = content_cell.clone();
     let policy = cranpose_core::remember(move || {
         let cfg = config_state;
+        let reuse_policy = policy_state;
+        let known_viewport = viewport_state;
         let content_ref = content_for_policy.clone();
         let state_ref = state;
         Rc::new(
             move |scope: &mut SubcomposeMeasureScopeImpl<'_>, constraints: Constraints| {
                 let content = content_ref.borrow();
-                let config = cfg.value();
-                measure_lazy_list_internal(scope, constraints, true, &content, &state_ref, &config)
+                let mut config = cfg.value();
+                config.known_viewport = known_viewport.value().map(|viewport| viewport.window());
+                measure_lazy_list_internal(
+                    scope,
+                    constraints,
+                    true,
+                    &content,
+                    &state_ref,
+                    &config,
+                    reuse_policy.value(),
+                )
             },
         )
     })
@@ -579,6 +716,7 @@ fn LazyColumnImpl(
     state.try_register_layout_callback(Rc::new(move || {
         crate::schedule_layout_repass(node_id);
     }));
+    remeasure_on_parent_scroll(spec.known_viewport.as_ref(), node_id);
 
     node_id
 }
@@ -611,20 +749,34 @@ fn LazyRowImpl(
         beyond_bounds_item_count: spec.beyond_bounds_item_count,
         vertical_arrangement: None,
         horizontal_arrangement: Some(spec.horizontal_arrangement),
+        known_viewport: None,
     };
     let config_state = cranpose_core::rememberUpdatedState(config);
+    let policy_state = cranpose_core::rememberUpdatedState(spec.reuse_policy);
+    let viewport_state = cranpose_core::rememberUpdatedState(spec.known_viewport.clone());
 
     // Create measure policy with stable identity using remember.
     let content_for_policy = content_cell.clone();
     let policy = cranpose_core::remember(move || {
         let cfg = config_state;
+        let reuse_policy = policy_state;
+        let known_viewport = viewport_state;
         let content_ref = content_for_policy.clone();
         let state_ref = state;
         Rc::new(
             move |scope: &mut SubcomposeMeasureScopeImpl<'_>, constraints: Constraints| {
                 let content = content_ref.borrow();
-                let config = cfg.value();
-                measure_lazy_list_internal(scope, constraints, false, &content, &state_ref, &config)
+                let mut config = cfg.value();
+                config.known_viewport = known_viewport.value().map(|viewport| viewport.window());
+                measure_lazy_list_internal(
+                    scope,
+                    constraints,
+                    false,
+                    &content,
+                    &state_ref,
+                    &config,
+                    reuse_policy.value(),
+                )
             },
         )
     })
@@ -646,10 +798,26 @@ fn LazyRowImpl(
     state.try_register_layout_callback(Rc::new(move || {
         crate::schedule_layout_repass(node_id);
     }));
+    remeasure_on_parent_scroll(spec.known_viewport.as_ref(), node_id);
 
     node_id
 }
 
+/// Remeasures the list when the scrolling parent of its known viewport scrolls.
+fn remeasure_on_parent_scroll(viewport: Option<&KnownViewport>, node_id: NodeId) {
+    let scroll = viewport.map(|viewport| viewport.scroll.clone());
+    let scroll_id = scroll.as_ref().map(ScrollState::id);
+    cranpose_core::DisposableEffect!((scroll_id, node_id), move |scope| {
+        let Some(scroll) = scroll else {
+            return cranpose_core::DisposableEffectResult::default();
+        };
+        let callback_id = scroll.add_invalidate_callback(Box::new(move || {
+            crate::schedule_layout_repass(node_id);
+        }));
+        scope.on_dispose(move || scroll.remove_invalidate_callback(callback_id))
+    });
+}
+
 /// A vertically scrolling list that only composes visible items.
 ///
 /// Matches Jetpack Compose's `LazyColumn` API. The closure receives
@@ -712,6 +880,31 @@ fn LazyRowImpl(
 ///     }
 /// );
 /// ```
+///
+/// # Inside a scrolling `Column`
+///
+/// A vertically scrolling parent measures its children with unbounded height,
+/// so the list cannot tell which items are on screen. Either give the list a
+/// fixed height, which makes it a normal bounded list, or tell it which part
+/// of it the parent currently shows:
+///
+/// ```rust,ignore
+/// let scroll = rememberScrollState!(0.0);
+/// Column(Modifier::empty().fill_max_size().vertical_scroll(scroll.clone(), false), ColumnSpec::default(), move || {
+///     Header(); // HEADER_HEIGHT tall
+///     // The list starts below the header; the parent is VIEWPORT_HEIGHT tall
+///     let viewport = KnownViewport::new(scroll.clone(), HEADER_HEIGHT, VIEWPORT_HEIGHT);
+///     LazyColumn(
+///         Modifier::empty(),
+///         state,
+///         LazyColumnSpec::default().known_viewport(viewport),
+///         content,
+///     );
+/// });
+/// ```
+///
+/// Without either, the list measures an estimated window of 20 items and logs
+/// a warning once.
 pub fn LazyColumn<F>(
     modifier: Modifier,
     state: LazyListState,
@@ -723,9 +916,22 @@ where
 {
     let mut interval_content = LazyListIntervalContent::new();
     content(&mut interval_content);
+    warn_on_duplicate_key(&state, &interval_content);
     LazyColumnImpl(modifier, state, spec, interval_content)
 }
 
+/// Logs a user key shared by two items, once per state that opted into the check.
+pub(super) fn warn_on_duplicate_key(state: &LazyListState, content: &LazyListIntervalContent) {
+    if let Some(duplicate) = state.report_duplicate_key(content) {
+        log::warn!(
+            "Lazy list key {} is used by items {} and {}; keys must be unique or items lose their state when the list changes",
+            duplicate.key,
+            duplicate.first_index,
+            duplicate.duplicate_index
+        );
+    }
+}
+
 /// A horizontally scrolling list that only composes visible items.
 ///
 /// Matches Jetpack Compose's `LazyRow` API. The closure receives
@@ -747,12 +953,15 @@ where
 {
     let mut interval_content = LazyListIntervalContent::new();
     content(&mut interval_content);
+    warn_on_duplicate_key(&state, &interval_content);
     LazyRowImpl(modifier, state, spec, interval_content)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use cranpose_core::{location_key, Composition, MemoryApplier};
+    use std::cell::{Cell, RefCell};
 
     #[test]
     fn test_lazy_column_spec_default() {
@@ -782,6 +991,374 @@ mod tests {
         assert_eq!(get_spacing(LinearArrangement::SpacedBy(12.0)), 12.0);
     }
 
+    fn measured_items(sizes: &[f32], config: &LazyListMeasureConfig) -> Vec<LazyListMeasuredItem> {
+        let mut offset = config.before_content_padding;
+        sizes
+            .iter()
+            .enumerate()
+            .map(|(index, &size)| {
+                let mut item = LazyListMeasuredItem::new(index, index as u64, None, size, 100.0);
+                item.offset = offset;
+                item.node_ids.push(index as u64 + 1);
+                item.child_offsets.push(0.0);
+                offset += size + config.spacing;
+                item
+            })
+            .collect()
+    }
+
+    fn column_config(arrangement: LinearArrangement) -> LazyListMeasureConfig {
+        LazyListMeasureConfig {
+            before_content_padding: 16.0,
+            after_content_padding: 16.0,
+            spacing: get_spacing(arrangement),
+            vertical_arrangement: Some(arrangement),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn spaced_by_placements_in_scrolling_list_match_cumulative_offsets() {
+        let mut composition = Composition::new(MemoryApplier::new());
+        let state_slot: Rc<RefCell<Option<LazyListState>>> = Rc::new(RefCell::new(None));
+        let state_render = Rc::clone(&state_slot);
+        composition
+            .render(location_key(file!(), line!(), column!()), move || {
+                *state_render.borrow_mut() =
+                    Some(cranpose_foundation::lazy::remember_lazy_list_state());
+            })
+            .expect("render");
+        let state = state_slot.borrow().expect("lazy list state");
+
+        let config = column_config(LinearArrangement::SpacedBy(8.0));
+        let sizes = [40.0, 60.0, 40.0, 60.0, 40.0, 60.0, 40.0, 60.0, 40.0, 60.0];
+        let viewport_size = 200.0;
+        let measure = |scroll: f32| {
+            // Negative deltas scroll toward the end of the list
+            state.dispatch_scroll_delta(-scroll);
+            let result = measure_lazy_list(
+                sizes.len(),
+                &state,
+                viewport_size,
+                100.0,
+                &config,
+                |i| i as u64,
+                |i| {
+                    let mut item = LazyListMeasuredItem::new(i, i as u64, None, sizes[i], 100.0);
+                    item.node_ids.push(i as u64 + 1);
+                    item.child_offsets.push(0.0);
+                    item
+                },
+            );
+            create_lazy_list_placements(
+                &result.visible_items,
+                sizes.len(),
+                true,
+                viewport_size,
+                &config,
+            )
+        };
+
+        // Expected start of item k: padding + sum of (size + spacing) before it
+        let expected_offset = |index: usize, scrolled: f32| {
+            16.0 + sizes[..index].iter().map(|size| size + 8.0).sum::<f32>() - scrolled
+        };
+
+        for (scroll, scrolled) in [(0.0, 0.0), (130.0, 130.0), (75.0, 205.0)] {
+            let placements = measure(scroll);
+            assert!(!placements.is_empty());
+            for placement in placements {
+                let index = placement.node_id - 1;
+                assert!(
+                    (placement.y - expected_offset(index, scrolled)).abs() < 0.001,
+                    "item {index} placed at {} after scrolling {scrolled}",
+                    placement.y
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn scrolling_recycles_item_nodes_with_matching_content_type() {
+        use crate::layout::LayoutEngine;
+        use crate::modifier::Size;
+        use cranpose_foundation::lazy::LazyListScope;
+        use std::collections::HashSet;
+
+        let mut composition = Composition::new(MemoryApplier::new());
+        let state_slot: Rc<RefCell<Option<LazyListState>>> = Rc::new(RefCell::new(None));
+        let text_nodes: Rc<RefCell<HashSet<NodeId>>> = Rc::new(RefCell::new(HashSet::new()));
+        let items_shown: Rc<RefCell<HashSet<usize>>> = Rc::new(RefCell::new(HashSet::new()));
+        let state_render = Rc::clone(&state_slot);
+        let nodes_render = Rc::clone(&text_nodes);
+        let shown_render = Rc::clone(&items_shown);
+        composition
+            .render(location_key(file!(), line!(), column!()), move || {
+                let state = cranpose_foundation::lazy::remember_lazy_list_state();
+                *state_render.borrow_mut() = Some(state);
+                let nodes = Rc::clone(&nodes_render);
+                let shown = Rc::clone(&shown_render);
+                LazyColumn(
+                    Modifier::empty().size(Size {
+                        width: 200.0,
+                        height: 100.0,
+                    }),
+                    state,
+                    LazyColumnSpec::default(),
+                    move |scope| {
+                        let nodes = Rc::clone(&nodes);
+                        let shown = Rc::clone(&shown);
+                        scope.items(200, None::<fn(usize) -> u64>, Some(|_| 1), move |index| {
+                            shown.borrow_mut().insert(index);
+                            nodes
+                                .borrow_mut()
+                                .insert(crate::Text(format!("Item {index}"), Modifier::empty()));
+                        });
+                    },
+                );
+            })
+            .expect("render");
+        let state = state_slot.borrow().expect("lazy list state");
+        let root = composition.root().expect("root node");
+        let viewport = Size {
+            width: 200.0,
+            height: 100.0,
+        };
+        let handle = composition.runtime_handle();
+        composition.applier_mut().set_runtime_handle(handle);
+
+        composition
+            .applier_mut()
+            .compute_layout(root, viewport)
+            .expect("initial layout");
+        for _ in 0..20 {
+            state.dispatch_scroll_delta(-60.0);
+            composition
+                .applier_mut()
+                .compute_layout(root, viewport)
+                .expect("scrolled layout");
+        }
+
+        let shown = items_shown.borrow().len();
+        let distinct_nodes = text_nodes.borrow().len();
+        assert!(state.first_visible_item_index() > 30, "list did not scroll");
+        assert!(state.stats().reuse_count > 0);
+        assert!(
+            distinct_nodes * 2 < shown,
+            "{distinct_nodes} text nodes created for {shown} items"
+        );
+    }
+
+    #[test]
+    fn known_viewport_follows_parent_scroll_without_recomposing() {
+        use crate::layout::LayoutEngine;
+        use crate::modifier::Size;
+        use crate::widgets::{Column, ColumnSpec};
+        use cranpose_foundation::lazy::LazyListScope;
+
+        let scroll_slot: Rc<RefCell<Option<ScrollState>>> = Rc::new(RefCell::new(None));
+        let state_slot: Rc<Cell<Option<LazyListState>>> = Rc::new(Cell::new(None));
+        let list_slot: Rc<Cell<Option<NodeId>>> = Rc::new(Cell::new(None));
+        let compositions = Rc::new(Cell::new(0));
+        let scroll_render = Rc::clone(&scroll_slot);
+        let list_render = Rc::clone(&list_slot);
+        let state_render = Rc::clone(&state_slot);
+        let compositions_render = Rc::clone(&compositions);
+        let mut composition = Composition::new(MemoryApplier::new());
+        composition
+            .render(location_key(file!(), line!(), column!()), move || {
+                compositions_render.set(compositions_render.get() + 1);
+                let state = cranpose_foundation::lazy::remember_lazy_list_state();
+                state_render.set(Some(state));
+                let scroll = cranpose_core::remember(|| ScrollState::new(0.0)).with(|s| s.clone());
+                *scroll_render.borrow_mut() = Some(scroll.clone());
+                let list_render = Rc::clone(&list_render);
+                Column(
+                    Modifier::empty()
+                        .fill_max_size()
+                        .vertical_scroll(scroll.clone(), false),
+                    ColumnSpec::default(),
+                    move || {
+                        let list = LazyColumn(
+                            Modifier::empty(),
+                            state,
+                            LazyColumnSpec::default().known_viewport(KnownViewport::new(
+                                scroll.clone(),
+                                0.0,
+                                100.0,
+                            )),
+                            |scope| {
+                                scope.items(
+                                    1_000,
+                                    None::<fn(usize) -> u64>,
+                                    None::<fn(usize) -> u64>,
+                                    |index| {
+                                        crate::Text(format!("Item {index}"), Modifier::empty());
+                                    },
+                                );
+                            },
+                        );
+                        list_render.set(Some(list));
+                    },
+                );
+            })
+            .expect("render");
+        let state = state_slot.get().expect("lazy list state");
+        let scroll = scroll_slot.borrow().clone().expect("scroll state");
+        let root = composition.root().expect("root node");
+        let handle = composition.runtime_handle();
+        composition.applier_mut().set_runtime_handle(handle);
+        let viewport = Size {
+            width: 200.0,
+            height: 100.0,
+        };
+
+        composition
+            .applier_mut()
+            .compute_layout(root, viewport)
+            .expect("initial layout");
+        assert_eq!(state.first_visible_item_index(), 0);
+
+        crate::take_layout_repass_nodes();
+        scroll.scroll_to(scroll.max_value() / 2.0);
+        // Bubble the scheduled repasses the way the app shell does
+        let repass_nodes = crate::take_layout_repass_nodes();
+        let list = list_slot.get().expect("lazy column node");
+        assert!(repass_nodes.contains(&list));
+        {
+            let mut applier = composition.applier_mut();
+            for node_id in repass_nodes {
+                cranpose_core::bubble_measure_dirty(&mut *applier, node_id);
+                cranpose_core::bubble_layout_dirty(&mut *applier, node_id);
+            }
+        }
+        composition
+            .applier_mut()
+            .compute_layout(root, viewport)
+            .expect("scrolled layout");
+
+        assert!(
+            state.first_visible_item_index() > 100,
+            "list stayed at item {}",
+            state.first_visible_item_index()
+        );
+        assert_eq!(compositions.get(), 1);
+    }
+
+    /// Lays out a 200-item column at a tall viewport, shrinks it, and returns
+    /// how many items the subcompose state kept for reuse with the list stats.
+    fn pool_after_shrinking_viewport(
+        policy: SlotReuseLimits,
+    ) -> (usize, cranpose_foundation::lazy::LazyLayoutStats) {
+        use crate::layout::LayoutEngine;
+        use crate::modifier::Size;
+        use cranpose_core::Applier;
+        use cranpose_foundation::lazy::LazyListScope;
+
+        let list_state = Rc::new(Cell::new(None));
+        let captured_state = list_state.clone();
+        let mut composition = Composition::new(MemoryApplier::new());
+        composition
+            .render(location_key(file!(), line!(), column!()), move || {
+                let state = cranpose_foundation::lazy::remember_lazy_list_state();
+                captured_state.set(Some(state));
+                LazyColumn(
+                    Modifier::empty().fill_max_size(),
+                    state,
+                    LazyColumnSpec::new().reuse_policy(policy),
+                    |scope| {
+                        scope.items(200, None::<fn(usize) -> u64>, Some(|_| 1), |index| {
+                            crate::Text(format!("Item {index}"), Modifier::empty());
+                        });
+                    },
+                );
+            })
+            .expect("render");
+        let root = composition.root().expect("root node");
+        let handle = composition.runtime_handle();
+        composition.applier_mut().set_runtime_handle(handle);
+
+        for height in [1000.0, 100.0, 100.0] {
+            composition
+                .applier_mut()
+                .compute_layout(
+                    root,
+                    Size {
+                        width: 200.0,
+                        height,
+                    },
+                )
+                .expect("layout");
+        }
+
+        let mut applier = composition.applier_mut();
+        let node = applier.get_mut(root).expect("lazy column node");
+        let list = node
+            .as_any_mut()
+            .downcast_mut::<SubcomposeLayoutNode>()
+            .expect("subcompose layout node");
+        let pool = list.state().reusable_slots_count();
+        let stats = list_state.get().expect("list state").stats();
+        (pool, stats)
+    }
+
+    #[test]
+    fn reuse_policy_caps_items_kept_in_pool() {
+        let (small, _) = pool_after_shrinking_viewport(SlotReuseLimits::new(2, 2));
+        let (default, _) = pool_after_shrinking_viewport(SlotReuseLimits::default());
+        let (large, _) = pool_after_shrinking_viewport(SlotReuseLimits::new(40, 40));
+
+        assert_eq!(small, 2);
+        assert_eq!(default, SlotReuseLimits::default().max_slots_per_type);
+        assert!(large > default, "large pool kept only {large} items");
+    }
+
+    #[test]
+    fn reuse_pool_overflow_is_reported_only_when_items_are_dropped() {
+        let (_, small) = pool_after_shrinking_viewport(SlotReuseLimits::new(2, 2));
+        let (_, large) = pool_after_shrinking_viewport(SlotReuseLimits::new(200, 200));
+
+        assert!(small.warned_pool_overflow);
+        assert!(!large.warned_pool_overflow);
+    }
+
+    #[test]
+    fn reversed_spaced_by_placements_mirror_from_viewport_end() {
+        let config = LazyListMeasureConfig {
+            reverse_layout: true,
+            ..column_config(LinearArrangement::SpacedBy(8.0))
+        };
+        let items = measured_items(&[40.0, 40.0, 40.0], &config);
+
+        let placements = create_lazy_list_placements(&items, 10, true, 200.0, &config);
+
+        let ys: Vec<f32> = placements.iter().map(|p| p.y).collect();
+        assert_eq!(ys, vec![144.0, 96.0, 48.0]);
+    }
+
+    #[test]
+    fn space_between_with_spare_space_respects_content_padding() {
+        let config = column_config(LinearArrangement::SpaceBetween);
+        let items = measured_items(&[40.0, 40.0, 40.0], &config);
+
+        let placements = create_lazy_list_placements(&items, 3, true, 200.0, &config);
+
+        let ys: Vec<f32> = placements.iter().map(|p| p.y).collect();
+        assert_eq!(ys, vec![16.0, 80.0, 144.0]);
+    }
+
+    #[test]
+    fn space_evenly_falls_back_to_measured_offsets_when_scrolling() {
+        let config = column_config(LinearArrangement::SpaceEvenly);
+        let items = measured_items(&[40.0, 40.0, 40.0], &config);
+
+        // Only 3 of 10 items are measured, so the list scrolls and keeps sequential offsets
+        let placements = create_lazy_list_placements(&items, 10, true, 200.0, &config);
+
+        let ys: Vec<f32> = placements.iter().map(|p| p.y).collect();
+        assert_eq!(ys, vec![16.0, 56.0, 96.0]);
+    }
+
     #[test]
     fn test_content_padding_all() {
         let spec = LazyColumnSpec::new().content_padding_all(24.0);
@@ -0,0 +1,263 @@
+//! LazyVerticalStaggeredGrid widget implementation.
+//!
+//! Provides a virtualized vertically scrolling grid whose columns stack items
+//! of different heights independently, matching Jetpack Compose's
+//! `LazyVerticalStaggeredGrid` API.
+
+#![allow(non_snake_case)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::lazy_list::{measure_lazy_item, warn_on_duplicate_key};
+use crate::modifier::Modifier;
+use crate::subcompose_layout::{
+    Placement, SubcomposeLayoutNode, SubcomposeLayoutScope, SubcomposeMeasureScopeImpl,
+};
+use crate::widgets::nodes::cranpose_node;
+use cranpose_core::{NodeId, SlotReuseLimits};
+use cranpose_foundation::lazy::{
+    measure_lazy_staggered_grid, LazyListIntervalContent, LazyListState,
+    LazyStaggeredGridMeasureConfig,
+};
+use cranpose_ui_layout::{Constraints, MeasureResult};
+
+/// How a staggered grid divides its width into columns.
+///
+/// Matches Kotlin: `StaggeredGridCells`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StaggeredGridCells {
+    /// Exactly this many columns.
+    Fixed(usize),
+    /// As many columns as fit with each at least this wide.
+    Adaptive(f32),
+}
+
+impl StaggeredGridCells {
+    /// Number of columns in `available` width with `spacing` between them.
+    fn count(self, available: f32, spacing: f32) -> usize {
+        match self {
+            Self::Fixed(count) => count.max(1),
+            Self::Adaptive(min_size) if min_size > 0.0 && available.is_finite() => {
+                ((available + spacing) / (min_size + spacing))
+                    .floor()
+                    .max(1.0) as usize
+            }
+            Self::Adaptive(_) => 1,
+        }
+    }
+}
+
+/// Specification for LazyVerticalStaggeredGrid layout behavior.
+#[derive(Clone, Debug)]
+pub struct LazyVerticalStaggeredGridSpec {
+    /// How the width is divided into columns.
+    pub columns: StaggeredGridCells,
+    /// Spacing between consecutive items of a column.
+    pub vertical_item_spacing: f32,
+    /// Spacing between columns.
+    pub horizontal_spacing: f32,
+    /// Content padding before the first items.
+    pub content_padding_top: f32,
+    /// Content padding after the last items.
+    pub content_padding_bottom: f32,
+    /// How many scrolled-out items are kept for reuse.
+    pub reuse_policy: SlotReuseLimits,
+}
+
+impl Default for LazyVerticalStaggeredGridSpec {
+    fn default() -> Self {
+        Self {
+            columns: StaggeredGridCells::Fixed(2),
+            vertical_item_spacing: 0.0,
+            horizontal_spacing: 0.0,
+            content_padding_top: 0.0,
+            content_padding_bottom: 0.0,
+            reuse_policy: SlotReuseLimits::default(),
+        }
+    }
+}
+
+impl LazyVerticalStaggeredGridSpec {
+    pub fn new(columns: StaggeredGridCells) -> Self {
+        Self {
+            columns,
+            ..Self::default()
+        }
+    }
+
+    pub fn vertical_item_spacing(mut self, spacing: f32) -> Self {
+        self.vertical_item_spacing = spacing;
+        self
+    }
+
+    pub fn horizontal_spacing(mut self, spacing: f32) -> Self {
+        self.horizontal_spacing = spacing;
+        self
+    }
+
+    pub fn content_padding(mut self, top: f32, bottom: f32) -> Self {
+        self.content_padding_top = top;
+        self.content_padding_bottom = bottom;
+        self
+    }
+
+    /// Sets how many scrolled-out items are kept for reuse.
+    pub fn reuse_policy(mut self, policy: SlotReuseLimits) -> Self {
+        self.reuse_policy = policy;
+        self
+    }
+}
+
+fn measure_staggered_grid_internal(
+    scope: &mut SubcomposeMeasureScopeImpl<'_>,
+    constraints: Constraints,
+    content: &LazyListIntervalContent,
+    state: &LazyListState,
+    spec: &LazyVerticalStaggeredGridSpec,
+) -> MeasureResult {
+    let width = constraints.max_width;
+    let viewport_size = constraints.max_height;
+    scope.set_reuse_limits(spec.reuse_policy);
+
+    let items_count = content.item_count();
+    // Keep the first visible item in place when items are added or removed before it
+    if items_count > 0 {
+        let range = state.nearest_range();
+        state.update_scroll_position_if_item_moved(items_count, |slot_id| {
+            content.get_index_by_slot_id_near(slot_id, range.clone())
+        });
+    }
+
+    let spacing = spec.horizontal_spacing;
+    let lanes = spec.columns.count(width, spacing);
+    let lane_width = ((width - spacing * (lanes - 1) as f32) / lanes as f32).max(0.0);
+    let child_constraints = Constraints {
+        min_width: lane_width,
+        max_width: lane_width,
+        min_height: 0.0,
+        max_height: f32::INFINITY,
+    };
+    let config = LazyStaggeredGridMeasureConfig {
+        lanes,
+        main_axis_spacing: spec.vertical_item_spacing,
+        before_content_padding: spec.content_padding_top,
+        after_content_padding: spec.content_padding_bottom,
+    };
+
+    let result = measure_lazy_staggered_grid(
+        items_count,
+        state,
+        viewport_size,
+        &config,
+        |index| content.get_key(index).to_slot_id(),
+        |index| measure_lazy_item(scope, content, state, index, true, child_constraints),
+    );
+
+    let in_pool = scope.reusable_slots_count();
+    state.update_stats(result.visible_items.len(), in_pool);
+
+    let placements: Vec<Placement> = result
+        .visible_items
+        .iter()
+        .flat_map(|visible| {
+            let x = visible.lane as f32 * (lane_width + spacing);
+            let item = &visible.item;
+            item.node_ids.iter().zip(item.child_offsets.iter()).map(
+                move |(&node_id, &child_offset)| {
+                    Placement::new(node_id as NodeId, x, item.offset + child_offset, 0)
+                },
+            )
+        })
+        .collect();
+
+    let height = result
+        .total_content_size
+        .clamp(constraints.min_height, constraints.max_height);
+    scope.layout(width, height, placements)
+}
+
+/// A vertically scrolling grid that only composes visible items, stacking
+/// items of different heights in independent columns.
+///
+/// Each item goes to the column whose items currently end highest, so columns
+/// stay balanced like a Pinterest-style feed. Items are measured with their
+/// column's exact width. The grid needs a bounded height.
+///
+/// The closure receives a [`LazyListIntervalContent`] which implements
+/// [`LazyListScope`](cranpose_foundation::lazy::LazyListScope) for defining
+/// items, and scrolling is driven by a [`LazyListState`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let state = remember_lazy_list_state();
+/// LazyVerticalStaggeredGrid(
+///     Modifier::empty().fill_max_size(),
+///     state,
+///     LazyVerticalStaggeredGridSpec::new(StaggeredGridCells::Adaptive(120.0))
+///         .vertical_item_spacing(8.0)
+///         .horizontal_spacing(8.0),
+///     |scope| {
+///         scope.items(photos.len(), None::<fn(usize) -> u64>, None::<fn(usize) -> u64>, |i| {
+///             PhotoCard(&photos[i]);
+///         });
+///     },
+/// );
+/// ```
+///
+/// Matches Kotlin: `LazyVerticalStaggeredGrid(columns, modifier, state) { items(...) }`
+pub fn LazyVerticalStaggeredGrid<F>(
+    modifier: Modifier,
+    state: LazyListState,
+    spec: LazyVerticalStaggeredGridSpec,
+    content: F,
+) -> NodeId
+where
+    F: FnOnce(&mut LazyListIntervalContent),
+{
+    let mut interval_content = LazyListIntervalContent::new();
+    content(&mut interval_content);
+    warn_on_duplicate_key(&state, &interval_content);
+
+    // Shared with the remembered policy so recompositions update its content
+    let content_cell =
+        cranpose_core::remember(|| Rc::new(RefCell::new(LazyListIntervalContent::new())))
+            .with(|cell| cell.clone());
+    *content_cell.borrow_mut() = interval_content;
+
+    let spec_state = cranpose_core::rememberUpdatedState(spec);
+    let content_for_policy = content_cell.clone();
+    let policy = cranpose_core::remember(move || {
+        let content_ref = content_for_policy.clone();
+        Rc::new(
+            move |scope: &mut SubcomposeMeasureScopeImpl<'_>, constraints: Constraints| {
+                let content = content_ref.borrow();
+                measure_staggered_grid_internal(
+                    scope,
+                    constraints,
+                    &content,
+                    &state,
+                    &spec_state.value(),
+                )
+            },
+        )
+    })
+    .with(|p| p.clone());
+
+    let scroll_modifier = modifier.clip_to_bounds().lazy_vertical_scroll(state, false);
+    let node_id = cranpose_node(move || {
+        SubcomposeLayoutNode::with_content_type_policy(scroll_modifier, policy)
+    });
+
+    // Scroll deltas and scroll_to_item() remeasure just this grid
+    state.try_register_layout_callback(Rc::new(move || {
+        crate::schedule_layout_repass(node_id);
+    }));
+
+    node_id
+}
+
+#[cfg(test)]
+#[path = "tests/lazy_staggered_grid_tests.rs"]
+mod tests;
@@ -4,24 +4,38 @@ pub mod basic_text_field;
 pub mod box_widget;
 pub mod button;
 pub mod column;
+pub mod dialog;
+pub mod divider;
+pub mod flow;
 pub mod foreach;
 pub mod layout;
 pub mod lazy_list;
+pub mod lazy_staggered_grid;
 pub mod nodes;
+pub mod popup;
+pub mod progress_indicator;
 pub mod row;
 pub mod scopes;
 pub mod spacer;
+pub mod tab_row;
 pub mod text;
 
 pub use basic_text_field::*;
 pub use box_widget::*;
 pub use button::*;
 pub use column::*;
+pub use dialog::*;
+pub use divider::*;
+pub use flow::*;
 pub use foreach::*;
 pub use layout::*;
 pub use lazy_list::*;
+pub use lazy_staggered_grid::*;
 pub use nodes::*;
+pub use popup::*;
+pub use progress_indicator::*;
 pub use row::*;
 pub use scopes::*;
 pub use spacer::*;
+pub use tab_row::*;
 pub use text::*;
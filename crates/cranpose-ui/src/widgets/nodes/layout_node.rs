@@ -8,7 +8,7 @@ use crate::{
 };
 use cranpose_core::{Node, NodeId};
 use cranpose_foundation::{
-    InvalidationKind, ModifierInvalidation, NodeCapabilities, SemanticsConfiguration,
+    FocusState, InvalidationKind, ModifierInvalidation, NodeCapabilities, SemanticsConfiguration,
 };
 use cranpose_ui_layout::{Constraints, MeasurePolicy};
 use indexmap::IndexSet;
@@ -574,6 +574,34 @@ impl LayoutNode {
         self.modifier_capabilities.contains(NodeCapabilities::FOCUS)
     }
 
+    /// Returns true when the modifier chain contains a focus target.
+    pub fn is_focus_target(&self) -> bool {
+        self.modifier_chain.for_each_focus_target(|_| {})
+    }
+
+    /// Returns this node's key event handlers, innermost first.
+    pub(crate) fn key_event_handlers(&self) -> Vec<crate::KeyEventHandler> {
+        self.modifier_chain.key_event_handlers()
+    }
+
+    /// Applies `f` to the scroll nodes that make this node a scroll container.
+    pub(crate) fn for_each_scroll_node(&self, f: impl FnMut(&crate::scroll::ScrollNode)) {
+        self.modifier_chain.for_each_scroll_node(f);
+    }
+
+    /// Applies `f` to the lazy list scroll nodes in this node's modifier chain.
+    pub(crate) fn for_each_lazy_scroll_node(&self, f: impl FnMut(&crate::scroll::LazyScrollNode)) {
+        self.modifier_chain.for_each_lazy_scroll_node(f);
+    }
+
+    /// Updates the state of this node's focus targets.
+    ///
+    /// Returns `false` when the node has no focus target.
+    pub fn set_focus_state(&self, state: FocusState) -> bool {
+        self.modifier_chain
+            .for_each_focus_target(|target| target.set_focus_state(state))
+    }
+
     fn refresh_registry_state(&self) {
         if let Some(id) = self.id.get() {
             let parent = self.parent();
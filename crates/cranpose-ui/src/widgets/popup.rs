@@ -0,0 +1,635 @@
+//! Popup widget implementation
+//!
+//! [`PopupHost`] composes the app and, after it, a layer holding every open
+//! [`Popup`]. The layer is the host's last child, so popups draw above all of
+//! the app's content and no ancestor of the `Popup` call clips them. A `Popup`
+//! only registers its content with the nearest host; the host's layer composes
+//! it.
+//!
+//! Modal popups, such as [`Dialog`](crate::Dialog)s, also dim the window behind
+//! them, keep pointer input from reaching it, and fade in and out.
+
+#![allow(non_snake_case)]
+
+use crate::composable;
+use crate::composition_local::{local_popup_layer, local_popup_stack};
+use crate::layout::policies::FlexMeasurePolicy;
+use crate::modifier::{Color, Modifier, Rect, Size};
+use crate::widgets::Layout;
+use cranpose_animation::{animateFloatAsStateWithSpec, AnimationSpec, AnimationType, Easing};
+use cranpose_core::{
+    remember, useState, with_key, CompositionLocalProvider, MutableState, NodeId, SideEffect,
+};
+use cranpose_ui_layout::{
+    Alignment, Constraints, HorizontalAlignment, LinearArrangement, Measurable, MeasurePolicy,
+    MeasureResult, Placement, VerticalAlignment,
+};
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+/// Duration of the fade in and out of modal popups.
+const MODAL_FADE_MILLIS: u64 = 150;
+
+pub(crate) type PopupCallback = Rc<RefCell<dyn FnMut()>>;
+
+/// Where a popup goes and how it treats the rest of the window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct PopupPlacement {
+    /// Window-space bounds the popup is aligned against; `None` for the
+    /// whole window.
+    pub(crate) anchor_bounds: Option<Rect>,
+    pub(crate) alignment: Alignment,
+    /// Makes the popup modal, dimming the window behind it with this color.
+    pub(crate) scrim: Option<Color>,
+}
+
+#[derive(Clone)]
+struct PopupEntry {
+    id: u64,
+    placement: PopupPlacement,
+    on_dismiss: PopupCallback,
+    content: PopupCallback,
+    /// Set once the popup left the composition while it fades out.
+    closing: bool,
+}
+
+impl PartialEq for PopupEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.placement == other.placement
+            && Rc::ptr_eq(&self.on_dismiss, &other.on_dismiss)
+            && Rc::ptr_eq(&self.content, &other.content)
+            && self.closing == other.closing
+    }
+}
+
+#[derive(Default)]
+struct PopupLayerInner {
+    entries: RefCell<Vec<PopupEntry>>,
+    next_id: Cell<u64>,
+    /// Set while the layer composes its popups.
+    composing: Cell<bool>,
+}
+
+impl PopupLayerInner {
+    fn dismiss_topmost(&self) -> bool {
+        let on_dismiss = self
+            .entries
+            .borrow()
+            .iter()
+            .rev()
+            .find(|entry| !entry.closing)
+            .map(|entry| Rc::clone(&entry.on_dismiss));
+        // Called without the entries borrowed; it usually closes the popup
+        on_dismiss.is_some_and(|on_dismiss| {
+            (on_dismiss.borrow_mut())();
+            true
+        })
+    }
+}
+
+/// Popup layers of one app, the innermost [`PopupHost`] last.
+///
+/// The app host creates one and provides it through [`local_popup_stack`];
+/// every host composed under it registers its layer, so the app host can close
+/// the topmost popup when the user presses Escape or back.
+#[derive(Clone, Default)]
+pub struct PopupStack {
+    layers: Rc<RefCell<Vec<Weak<PopupLayerInner>>>>,
+}
+
+impl PartialEq for PopupStack {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.layers, &other.layers)
+    }
+}
+
+impl PopupStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks the topmost open popup to close, as the Escape or back key does.
+    ///
+    /// Calls its `on_dismiss` and returns true, or returns false when no popup
+    /// is open.
+    pub fn dismiss_topmost(&self) -> bool {
+        let layers: Vec<Rc<PopupLayerInner>> = {
+            let mut layers = self.layers.borrow_mut();
+            layers.retain(|layer| layer.strong_count() > 0);
+            layers.iter().filter_map(Weak::upgrade).collect()
+        };
+        layers.iter().rev().any(|layer| layer.dismiss_topmost())
+    }
+
+    fn register(&self, layer: &Rc<PopupLayerInner>) {
+        self.layers.borrow_mut().push(Rc::downgrade(layer));
+    }
+}
+
+/// Open popups of a [`PopupHost`], provided to its content through
+/// [`local_popup_layer`].
+#[derive(Clone)]
+pub struct PopupLayer {
+    inner: Rc<PopupLayerInner>,
+    version: MutableState<u64>,
+}
+
+impl PartialEq for PopupLayer {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl PopupLayer {
+    fn new(version: MutableState<u64>, stack: Option<&PopupStack>) -> Self {
+        let inner = Rc::<PopupLayerInner>::default();
+        if let Some(stack) = stack {
+            stack.register(&inner);
+        }
+        Self { inner, version }
+    }
+
+    /// Number of popups currently shown.
+    pub fn len(&self) -> usize {
+        self.inner.entries.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn next_id(&self) -> u64 {
+        let id = self.inner.next_id.get();
+        self.inner.next_id.set(id + 1);
+        id
+    }
+
+    fn show(&self, entry: PopupEntry) {
+        let changed = {
+            let mut entries = self.inner.entries.borrow_mut();
+            match entries.iter_mut().find(|existing| existing.id == entry.id) {
+                Some(existing) => {
+                    let moved = existing.placement != entry.placement;
+                    *existing = entry;
+                    moved
+                }
+                None => {
+                    entries.push(entry);
+                    true
+                }
+            }
+        };
+        // New content is picked up by recomposing the layer, except from a
+        // popup opened by another popup's content: the layer is composing it
+        // right now, and asking again would recompose the layer forever
+        if changed || !self.inner.composing.get() {
+            self.invalidate();
+        }
+    }
+
+    fn dismissed(&self, id: u64) {
+        // Modal popups stay in the layer until they have faded out
+        self.inner.entries.borrow_mut().retain_mut(|entry| {
+            if entry.id != id {
+                return true;
+            }
+            entry.closing = true;
+            entry.placement.scrim.is_some()
+        });
+        self.invalidate();
+    }
+
+    fn faded_out(&self, id: u64) {
+        self.inner
+            .entries
+            .borrow_mut()
+            .retain(|entry| entry.id != id);
+        self.invalidate();
+    }
+
+    fn invalidate(&self) {
+        self.version
+            .set(self.version.get_non_reactive().wrapping_add(1));
+    }
+}
+
+/// Keeps a popup registered with its layer while the `Popup` call stays in
+/// the composition.
+struct PopupRegistration {
+    layer: PopupLayer,
+    id: u64,
+}
+
+impl Drop for PopupRegistration {
+    fn drop(&mut self) {
+        self.layer.dismissed(self.id);
+    }
+}
+
+/// Hosts the layer that [`Popup`]s are drawn in, above `content`.
+///
+/// `AppShell` wraps every app in one, so apps only need it when they compose
+/// outside the shell. The host registers its layer with the nearest
+/// [`local_popup_stack`], if any. The host fills the window and measures `content` as if
+/// it were the root.
+#[composable]
+pub fn PopupHost<F>(content: F) -> NodeId
+where
+    F: FnMut() + 'static,
+{
+    let version = useState(|| 0u64);
+    let stack = local_popup_stack().current();
+    let layer = remember(|| PopupLayer::new(version, stack.as_ref())).with(|layer| layer.clone());
+    let content = RefCell::new(content);
+    // Fills the window so the popup layer can cover it
+    Layout(
+        Modifier::empty().fill_max_size(),
+        PopupHostMeasurePolicy,
+        move || {
+            CompositionLocalProvider([local_popup_layer().provides(Some(layer.clone()))], || {
+                (content.borrow_mut())()
+            });
+            PopupLayerContent(layer.clone());
+        },
+    )
+}
+
+/// Shows `content` above everything else, placed against `anchor_bounds`.
+///
+/// # When to use
+/// Use `Popup` for dropdown menus, tooltips and dialogs that must not be
+/// clipped by the layout they are opened from. Compose it while the popup is
+/// open; leaving the composition closes it.
+///
+/// # Arguments
+///
+/// * `anchor_bounds` - Window-space bounds the popup is placed against.
+/// * `alignment` - The point of `anchor_bounds` and the point of the popup
+///   that are put together; `BOTTOM_END` puts the popup's bottom-end corner
+///   on the anchor's bottom-end corner.
+/// * `on_dismiss` - Called when the user clicks outside the popup.
+/// * `content` - The popup's content.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// if expanded.get() {
+///     Popup(anchor, Alignment::TOP_START, move || expanded.set(false), || {
+///         Text("Menu item", Modifier::empty().padding(8.0));
+///     });
+/// }
+/// ```
+///
+/// Matches Kotlin: `Popup(alignment, onDismissRequest = onDismiss) { content() }`
+#[composable]
+pub fn Popup<D, F>(anchor_bounds: Rect, alignment: Alignment, on_dismiss: D, content: F)
+where
+    D: FnMut() + 'static,
+    F: FnMut() + 'static,
+{
+    let content: PopupCallback = Rc::new(RefCell::new(content));
+    let placement = PopupPlacement {
+        anchor_bounds: Some(anchor_bounds),
+        alignment,
+        scrim: None,
+    };
+    if !show_popup(
+        placement,
+        Rc::new(RefCell::new(on_dismiss)),
+        Rc::clone(&content),
+    ) {
+        // Without a host the content can only be composed in place
+        (content.borrow_mut())();
+    }
+}
+
+/// Shows a popup in the nearest host's layer until the calling composable
+/// leaves the composition.
+///
+/// Returns false, showing nothing, when there is no host.
+pub(crate) fn show_popup(
+    placement: PopupPlacement,
+    on_dismiss: PopupCallback,
+    content: PopupCallback,
+) -> bool {
+    let Some(layer) = local_popup_layer().current() else {
+        return false;
+    };
+    let id = remember(|| PopupRegistration {
+        id: layer.next_id(),
+        layer: layer.clone(),
+    })
+    .with(|registration| registration.id);
+    layer.show(PopupEntry {
+        id,
+        placement,
+        on_dismiss,
+        content,
+        closing: false,
+    });
+    true
+}
+
+#[composable]
+fn PopupLayerContent(layer: PopupLayer) {
+    // Read to recompose whenever a popup opens, moves or closes
+    let _ = layer.version.get();
+    let entries = layer.inner.entries.borrow().clone();
+    layer.inner.composing.set(true);
+    let window_layer = layer.clone();
+    Layout(Modifier::empty(), PopupLayerMeasurePolicy, move || {
+        for entry in &entries {
+            with_key(&entry.id, || {
+                PopupWindow(window_layer.clone(), entry.clone())
+            });
+        }
+    });
+    layer.inner.composing.set(false);
+}
+
+/// Opacity of a modal popup, fading in when it opens and out while closing.
+fn modal_alpha(closing: bool) -> f32 {
+    let opened = useState(|| false);
+    if !opened.get_non_reactive() {
+        SideEffect(move || opened.set(true));
+    }
+    let target = if opened.get() && !closing { 1.0 } else { 0.0 };
+    animateFloatAsStateWithSpec(
+        target,
+        AnimationType::Tween(AnimationSpec::tween(
+            MODAL_FADE_MILLIS,
+            Easing::LinearEasing,
+        )),
+        "modalPopupAlpha",
+    )
+    .value()
+}
+
+/// One popup of the layer. Composable rather than a plain function so the
+/// fade has a scope of its own to restart.
+#[composable]
+fn PopupWindow(layer: PopupLayer, entry: PopupEntry) {
+    let PopupEntry {
+        id,
+        placement,
+        on_dismiss,
+        content,
+        closing,
+    } = entry;
+    let mut modifier = Modifier::empty();
+    if placement.scrim.is_some() {
+        let alpha = modal_alpha(closing);
+        if closing && alpha <= 0.0 {
+            let layer = layer.clone();
+            SideEffect(move || layer.faded_out(id));
+        }
+        modifier = modifier.alpha(alpha);
+    }
+    Layout(
+        modifier,
+        PopupMeasurePolicy {
+            anchor_bounds: placement.anchor_bounds,
+            alignment: placement.alignment,
+        },
+        move || {
+            // Full-window scrim under the content; clicks the content doesn't
+            // take fall through to it
+            let mut scrim = Modifier::empty();
+            if let Some(color) = placement.scrim {
+                scrim = scrim.background(color);
+            }
+            if !closing {
+                let on_dismiss = Rc::clone(&on_dismiss);
+                scrim = scrim
+                    .clickable_with_indication(None, move |_point| (on_dismiss.borrow_mut())());
+                if placement.scrim.is_some() {
+                    scrim = scrim.block_pointer_input();
+                }
+            }
+            Layout(scrim, crate::layout::policies::EmptyMeasurePolicy, || {});
+            let content = Rc::clone(&content);
+            Layout(
+                // Takes clicks inside the popup so they don't dismiss it
+                Modifier::empty().clickable_with_indication(None, |_point| {}),
+                FlexMeasurePolicy::column(LinearArrangement::Start, HorizontalAlignment::Start),
+                move || (content.borrow_mut())(),
+            );
+        },
+    );
+}
+
+/// Window size for the layer: the root's max constraints, or `fallback` when
+/// they are unbounded.
+fn window_size(constraints: Constraints, fallback: Size) -> Size {
+    let bounded = |max: f32, fallback: f32| if max.is_finite() { max } else { fallback };
+    Size {
+        width: bounded(constraints.max_width, fallback.width),
+        height: bounded(constraints.max_height, fallback.height),
+    }
+}
+
+fn measure_all_at_origin(
+    measurables: &[Box<dyn Measurable>],
+    constraints: Constraints,
+) -> (Size, Vec<Placement>) {
+    let mut size = Size::ZERO;
+    let mut placements = Vec::with_capacity(measurables.len());
+    for measurable in measurables {
+        let placeable = measurable.measure(constraints);
+        size.width = size.width.max(placeable.width());
+        size.height = size.height.max(placeable.height());
+        placeable.place(0.0, 0.0);
+        placements.push(Placement::new(placeable.node_id(), 0.0, 0.0, 0));
+    }
+    (size, placements)
+}
+
+/// Measures the app loosely within the window and the popup layer, its last
+/// child, over the whole window.
+#[derive(Clone, Debug, PartialEq)]
+struct PopupHostMeasurePolicy;
+
+impl MeasurePolicy for PopupHostMeasurePolicy {
+    fn measure(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        constraints: Constraints,
+    ) -> MeasureResult {
+        let Some((layer, content)) = measurables.split_last() else {
+            let (width, height) = constraints.constrain(0.0, 0.0);
+            return MeasureResult::new(Size { width, height }, Vec::new());
+        };
+        // The host fills the window; its content wraps as the root would
+        let (content_size, mut placements) = measure_all_at_origin(content, constraints.loosen());
+        let window = window_size(constraints, content_size);
+        let layer = layer.measure(Constraints::tight(window.width, window.height));
+        layer.place(0.0, 0.0);
+        placements.push(Placement::new(layer.node_id(), 0.0, 0.0, 0));
+
+        let (width, height) = constraints.constrain(content_size.width, content_size.height);
+        MeasureResult::new(Size { width, height }, placements)
+    }
+
+    fn min_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
+        content_of(measurables)
+            .iter()
+            .map(|m| m.min_intrinsic_width(height))
+            .fold(0.0, f32::max)
+    }
+
+    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
+        content_of(measurables)
+            .iter()
+            .map(|m| m.max_intrinsic_width(height))
+            .fold(0.0, f32::max)
+    }
+
+    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        content_of(measurables)
+            .iter()
+            .map(|m| m.min_intrinsic_height(width))
+            .fold(0.0, f32::max)
+    }
+
+    fn max_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        content_of(measurables)
+            .iter()
+            .map(|m| m.max_intrinsic_height(width))
+            .fold(0.0, f32::max)
+    }
+}
+
+/// The host's children without the trailing popup layer.
+fn content_of(measurables: &[Box<dyn Measurable>]) -> &[Box<dyn Measurable>] {
+    measurables.split_last().map_or(&[], |(_, content)| content)
+}
+
+/// Stacks the open popups over the whole window.
+#[derive(Clone, Debug, PartialEq)]
+struct PopupLayerMeasurePolicy;
+
+impl MeasurePolicy for PopupLayerMeasurePolicy {
+    fn measure(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        constraints: Constraints,
+    ) -> MeasureResult {
+        let (_, placements) = measure_all_at_origin(measurables, constraints);
+        let (width, height) = constraints.constrain(constraints.max_width, constraints.max_height);
+        MeasureResult::new(Size { width, height }, placements)
+    }
+
+    fn min_intrinsic_width(&self, _measurables: &[Box<dyn Measurable>], _height: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_width(&self, _measurables: &[Box<dyn Measurable>], _height: f32) -> f32 {
+        0.0
+    }
+
+    fn min_intrinsic_height(&self, _measurables: &[Box<dyn Measurable>], _width: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_height(&self, _measurables: &[Box<dyn Measurable>], _width: f32) -> f32 {
+        0.0
+    }
+}
+
+/// Offset of the point `alignment` picks within `size`.
+fn alignment_point(alignment: Alignment, size: Size) -> (f32, f32) {
+    let x = match alignment.horizontal {
+        HorizontalAlignment::Start => 0.0,
+        HorizontalAlignment::CenterHorizontally => size.width / 2.0,
+        HorizontalAlignment::End => size.width,
+    };
+    let y = match alignment.vertical {
+        VerticalAlignment::Top => 0.0,
+        VerticalAlignment::CenterVertically => size.height / 2.0,
+        VerticalAlignment::Bottom => size.height,
+    };
+    (x, y)
+}
+
+/// Places a popup's content against its anchor, kept inside the window, over
+/// a scrim covering the window.
+#[derive(Clone, Debug, PartialEq)]
+struct PopupMeasurePolicy {
+    anchor_bounds: Option<Rect>,
+    alignment: Alignment,
+}
+
+impl PopupMeasurePolicy {
+    fn content_position(&self, content: Size, window: Size) -> (f32, f32) {
+        let anchor = self.anchor_bounds.unwrap_or(Rect {
+            x: 0.0,
+            y: 0.0,
+            width: window.width,
+            height: window.height,
+        });
+        let (anchor_x, anchor_y) = alignment_point(
+            self.alignment,
+            Size {
+                width: anchor.width,
+                height: anchor.height,
+            },
+        );
+        let (content_x, content_y) = alignment_point(self.alignment, content);
+        let x = anchor.x + anchor_x - content_x;
+        let y = anchor.y + anchor_y - content_y;
+        (
+            x.min(window.width - content.width).max(0.0),
+            y.min(window.height - content.height).max(0.0),
+        )
+    }
+}
+
+impl MeasurePolicy for PopupMeasurePolicy {
+    fn measure(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        constraints: Constraints,
+    ) -> MeasureResult {
+        let (width, height) = constraints.constrain(constraints.max_width, constraints.max_height);
+        let window = Size { width, height };
+        let mut placements = Vec::with_capacity(measurables.len());
+        if let [scrim, content] = measurables {
+            let scrim = scrim.measure(Constraints::tight(width, height));
+            scrim.place(0.0, 0.0);
+            placements.push(Placement::new(scrim.node_id(), 0.0, 0.0, 0));
+
+            let content = content.measure(Constraints::loose(width, height));
+            let (x, y) = self.content_position(
+                Size {
+                    width: content.width(),
+                    height: content.height(),
+                },
+                window,
+            );
+            content.place(x, y);
+            placements.push(Placement::new(content.node_id(), x, y, 0));
+        }
+        MeasureResult::new(window, placements)
+    }
+
+    fn min_intrinsic_width(&self, _measurables: &[Box<dyn Measurable>], _height: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_width(&self, _measurables: &[Box<dyn Measurable>], _height: f32) -> f32 {
+        0.0
+    }
+
+    fn min_intrinsic_height(&self, _measurables: &[Box<dyn Measurable>], _width: f32) -> f32 {
+        0.0
+    }
+
+    fn max_intrinsic_height(&self, _measurables: &[Box<dyn Measurable>], _width: f32) -> f32 {
+        0.0
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/popup_tests.rs"]
+mod tests;
@@ -0,0 +1,64 @@
+//! Progress indicator widget implementation
+
+#![allow(non_snake_case)]
+
+use crate::composable;
+use crate::layout::policies::LeafMeasurePolicy;
+use crate::modifier::{Brush, Color, Modifier, Point, Size};
+use crate::widgets::Layout;
+use cranpose_animation::animateFloatAsState;
+use cranpose_core::NodeId;
+
+/// Default diameter of a circular progress indicator.
+const INDICATOR_SIZE: f32 = 40.0;
+
+/// A ring that fills clockwise from 12 o'clock as `progress` goes from 0 to 1.
+///
+/// # When to use
+/// Use `CircularProgressIndicator` to show the completion of a task with a
+/// known duration. Changes to `progress` animate the sweep.
+///
+/// # Arguments
+///
+/// * `progress` - Completion fraction, clamped to `0.0..=1.0`.
+/// * `modifier` - Additional modifiers. The indicator defaults to 40x40.
+/// * `color` - Color of the filled arc.
+/// * `stroke_width` - Thickness of the ring.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// CircularProgressIndicator(0.75, Modifier::empty(), Color(0.2, 0.4, 0.9, 1.0), 4.0);
+/// ```
+///
+/// Matches Kotlin: `CircularProgressIndicator(progress, modifier, color, strokeWidth)`
+#[composable]
+pub fn CircularProgressIndicator(
+    progress: f32,
+    modifier: Modifier,
+    color: Color,
+    stroke_width: f32,
+) -> NodeId {
+    let sweep_fraction = animateFloatAsState(progress.clamp(0.0, 1.0), "progress").value();
+    Layout(
+        modifier
+            .size(Size {
+                width: INDICATOR_SIZE,
+                height: INDICATOR_SIZE,
+            })
+            .draw_behind(move |scope| {
+                let size = scope.size();
+                let radius = ((size.width.min(size.height) - stroke_width) / 2.0).max(0.0);
+                scope.draw_arc(
+                    Point::new(size.width / 2.0, size.height / 2.0),
+                    radius,
+                    -90.0,
+                    sweep_fraction * 360.0,
+                    stroke_width,
+                    Brush::solid(color),
+                );
+            }),
+        LeafMeasurePolicy::new(Size::ZERO),
+        || {}, // No children
+    )
+}
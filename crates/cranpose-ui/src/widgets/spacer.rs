@@ -16,22 +16,25 @@ use cranpose_core::NodeId;
 ///
 /// # Arguments
 ///
-/// * `size` - The explicit size of the spacer.
+/// * `modifier` - Sizes the spacer (`size`, `width`, `fillMax*`, `weight`).
+///   Without one, the spacer takes the minimum size its constraints allow.
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// Row(..., || {
 ///     Text("Left", Modifier::empty());
-///     Spacer(Size::new(16.0, 0.0)); // 16dp gap
+///     Spacer(Modifier::empty().width(16.0)); // 16dp gap
+///     Text("Middle", Modifier::empty());
+///     Spacer(Modifier::empty().weight(1.0)); // pushes "Right" to the end
 ///     Text("Right", Modifier::empty());
 /// });
 /// ```
 #[composable]
-pub fn Spacer(size: Size) -> NodeId {
+pub fn Spacer(modifier: Modifier) -> NodeId {
     Layout(
-        Modifier::empty(),
-        LeafMeasurePolicy::new(size),
+        modifier,
+        LeafMeasurePolicy::new(Size::ZERO),
         || {}, // No children
     )
 }
@@ -0,0 +1,293 @@
+//! Tab row widget implementation
+
+#![allow(non_snake_case)]
+
+use crate::composable;
+use crate::layout::policies::FlexMeasurePolicy;
+use crate::modifier::{Color, Modifier, Size};
+use crate::widgets::Layout;
+use cranpose_animation::animateFloatAsState;
+use cranpose_core::NodeId;
+use cranpose_foundation::Role;
+use cranpose_ui_layout::{
+    Constraints, HorizontalAlignment, LinearArrangement, Measurable, MeasurePolicy, MeasureResult,
+    Placement,
+};
+use std::cell::RefCell;
+
+/// Height of the line under the selected tab.
+const INDICATOR_HEIGHT: f32 = 3.0;
+
+/// Narrowest tab of a [`ScrollableTabRow`].
+const MIN_SCROLLABLE_TAB_WIDTH: f32 = 90.0;
+
+/// A row of tabs with a line under the selected one.
+///
+/// # When to use
+/// Use `TabRow` to switch between a few views of equal importance. Tabs share
+/// the width evenly; use [`ScrollableTabRow`] when they don't fit.
+///
+/// # Arguments
+///
+/// * `selected_index` - Index of the selected tab. The indicator slides to it
+///   when it changes.
+/// * `modifier` - Additional modifiers. The row fills the available width.
+/// * `indicator_color` - Color of the line under the selected tab.
+/// * `tabs` - Emits the tabs, usually one [`Tab`] each.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let selected = useState(|| 0usize);
+/// TabRow(selected.get(), Modifier::empty(), Color(0.2, 0.4, 0.9, 1.0), move || {
+///     for (index, title) in ["Home", "Search", "Profile"].into_iter().enumerate() {
+///         Tab(selected.get() == index, Modifier::empty(), move || selected.set(index), move || {
+///             Text(title, Modifier::empty());
+///         });
+///     }
+/// });
+/// ```
+///
+/// Matches Kotlin: `TabRow(selectedTabIndex, modifier) { tabs() }`
+#[composable]
+pub fn TabRow<F>(
+    selected_index: usize,
+    modifier: Modifier,
+    indicator_color: Color,
+    tabs: F,
+) -> NodeId
+where
+    F: FnMut() + 'static,
+{
+    tab_row(
+        selected_index,
+        modifier.fill_max_width(),
+        indicator_color,
+        false,
+        tabs,
+    )
+}
+
+/// A [`TabRow`] whose tabs keep their own width and scroll horizontally when
+/// they don't fit.
+///
+/// Matches Kotlin: `ScrollableTabRow(selectedTabIndex, modifier) { tabs() }`
+#[composable]
+pub fn ScrollableTabRow<F>(
+    selected_index: usize,
+    modifier: Modifier,
+    indicator_color: Color,
+    tabs: F,
+) -> NodeId
+where
+    F: FnMut() + 'static,
+{
+    let scroll_state = crate::rememberScrollState!(0.0);
+    tab_row(
+        selected_index,
+        modifier.horizontal_scroll(scroll_state, false),
+        indicator_color,
+        true,
+        tabs,
+    )
+}
+
+fn tab_row<F>(
+    selected_index: usize,
+    modifier: Modifier,
+    indicator_color: Color,
+    scrollable: bool,
+    mut tabs: F,
+) -> NodeId
+where
+    F: FnMut() + 'static,
+{
+    let indicator_index = animateFloatAsState(selected_index as f32, "tabIndicator").value();
+    Layout(
+        modifier,
+        TabRowMeasurePolicy {
+            scrollable,
+            indicator_index,
+        },
+        move || {
+            tabs();
+            // The policy places the last child under the selected tab
+            Layout(
+                Modifier::empty()
+                    .height(INDICATOR_HEIGHT)
+                    .background(indicator_color),
+                crate::layout::policies::LeafMeasurePolicy::new(Size::ZERO),
+                || {},
+            );
+        },
+    )
+}
+
+/// A single tab of a [`TabRow`].
+///
+/// Calls `on_click` when tapped and centers `content`, usually a `Text`.
+///
+/// Matches Kotlin: `Tab(selected, onClick, modifier) { content() }`
+#[composable]
+pub fn Tab<F, G>(selected: bool, modifier: Modifier, on_click: F, content: G) -> NodeId
+where
+    F: FnMut() + 'static,
+    G: FnMut() + 'static,
+{
+    let on_click = RefCell::new(on_click);
+    Layout(
+        modifier
+            .clickable(move |_point| (on_click.borrow_mut())())
+            .merged_semantics(move |config| {
+                config.role = Some(Role::Tab);
+                if selected {
+                    config.state_description = Some("Selected".into());
+                }
+            })
+            .padding_symmetric(16.0, 12.0),
+        FlexMeasurePolicy::column(
+            LinearArrangement::Center,
+            HorizontalAlignment::CenterHorizontally,
+        ),
+        content,
+    )
+}
+
+/// Horizontal extent of a tab within its row.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct TabPosition {
+    left: f32,
+    width: f32,
+}
+
+/// Where the indicator sits for a possibly fractional `index`, blending the
+/// two tabs it is between while it slides.
+fn indicator_position(positions: &[TabPosition], index: f32) -> TabPosition {
+    let Some(last) = positions.len().checked_sub(1) else {
+        return TabPosition::default();
+    };
+    let index = index.clamp(0.0, last as f32);
+    let from = positions[index.floor() as usize];
+    let to = positions[index.ceil() as usize];
+    let fraction = index.fract();
+    TabPosition {
+        left: from.left + (to.left - from.left) * fraction,
+        width: from.width + (to.width - from.width) * fraction,
+    }
+}
+
+/// Lays the tabs out side by side and the last child, the indicator, along the
+/// bottom under the tab at `indicator_index`.
+#[derive(Clone, Debug, PartialEq)]
+struct TabRowMeasurePolicy {
+    scrollable: bool,
+    indicator_index: f32,
+}
+
+impl TabRowMeasurePolicy {
+    fn tab_widths(&self, tabs: &[Box<dyn Measurable>], max_width: f32) -> Vec<f32> {
+        if self.scrollable {
+            return tabs
+                .iter()
+                .map(|tab| {
+                    tab.max_intrinsic_width(f32::INFINITY)
+                        .max(MIN_SCROLLABLE_TAB_WIDTH)
+                })
+                .collect();
+        }
+        let row_width = if max_width.is_finite() {
+            max_width
+        } else {
+            // Without a width to share, every tab gets the widest tab's width
+            let widest = tabs
+                .iter()
+                .map(|tab| tab.max_intrinsic_width(f32::INFINITY))
+                .fold(0.0, f32::max);
+            widest * tabs.len() as f32
+        };
+        vec![row_width / tabs.len().max(1) as f32; tabs.len()]
+    }
+}
+
+impl MeasurePolicy for TabRowMeasurePolicy {
+    fn measure(
+        &self,
+        measurables: &[Box<dyn Measurable>],
+        constraints: Constraints,
+    ) -> MeasureResult {
+        let Some((indicator, tabs)) = measurables.split_last() else {
+            let (width, height) = constraints.constrain(0.0, 0.0);
+            return MeasureResult::new(Size { width, height }, Vec::new());
+        };
+
+        let widths = self.tab_widths(tabs, constraints.max_width);
+        let mut positions = Vec::with_capacity(tabs.len());
+        let mut placeables = Vec::with_capacity(tabs.len());
+        let mut left = 0.0;
+        for (tab, &width) in tabs.iter().zip(&widths) {
+            placeables.push(tab.measure(Constraints {
+                min_width: width,
+                max_width: width,
+                min_height: 0.0,
+                max_height: constraints.max_height,
+            }));
+            positions.push(TabPosition { left, width });
+            left += width;
+        }
+        let tab_height = placeables
+            .iter()
+            .map(|placeable| placeable.height())
+            .fold(0.0, f32::max);
+        let (width, height) = constraints.constrain(left, tab_height);
+
+        let mut placements = Vec::with_capacity(measurables.len());
+        for (placeable, position) in placeables.iter().zip(&positions) {
+            placeable.place(position.left, 0.0);
+            placements.push(Placement::new(placeable.node_id(), position.left, 0.0, 0));
+        }
+
+        let target = indicator_position(&positions, self.indicator_index);
+        let indicator = indicator.measure(Constraints {
+            min_width: target.width,
+            max_width: target.width,
+            min_height: 0.0,
+            max_height: height,
+        });
+        let indicator_y = height - indicator.height();
+        indicator.place(target.left, indicator_y);
+        placements.push(Placement::new(
+            indicator.node_id(),
+            target.left,
+            indicator_y,
+            0,
+        ));
+
+        MeasureResult::new(Size { width, height }, placements)
+    }
+
+    fn min_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], height: f32) -> f32 {
+        self.max_intrinsic_width(measurables, height)
+    }
+
+    fn max_intrinsic_width(&self, measurables: &[Box<dyn Measurable>], _height: f32) -> f32 {
+        let tabs = measurables.split_last().map_or(&[][..], |(_, tabs)| tabs);
+        self.tab_widths(tabs, f32::INFINITY).iter().sum()
+    }
+
+    fn min_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        self.max_intrinsic_height(measurables, width)
+    }
+
+    fn max_intrinsic_height(&self, measurables: &[Box<dyn Measurable>], width: f32) -> f32 {
+        let tabs = measurables.split_last().map_or(&[][..], |(_, tabs)| tabs);
+        let widths = self.tab_widths(tabs, width);
+        tabs.iter()
+            .zip(widths)
+            .map(|(tab, width)| tab.max_intrinsic_height(width))
+            .fold(0.0, f32::max)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/tab_row_tests.rs"]
+mod tests;
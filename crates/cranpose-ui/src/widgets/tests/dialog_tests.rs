@@ -0,0 +1,91 @@
+use super::*;
+use crate::modifier::{Brush, Modifier, Size};
+use crate::renderer::{HeadlessRenderer, PaintLayer};
+use crate::widgets::{Box, BoxSpec, PopupHost};
+use crate::LayoutEngine;
+use cranpose_core::{location_key, Composition, MemoryApplier, MutableState};
+use cranpose_ui_graphics::DrawPrimitive;
+
+const FRAME_NANOS: u64 = 16_000_000;
+
+#[composable]
+fn dialog_app(open: MutableState<bool>) {
+    if open.get() {
+        Dialog(
+            || {},
+            || {
+                Box(
+                    Modifier::empty().size_points(100.0, 50.0),
+                    BoxSpec::default(),
+                    || {},
+                );
+            },
+        );
+    }
+}
+
+/// Opacity the full-window scrim is drawn with, if it is drawn.
+fn scrim_alpha(composition: &mut Composition<MemoryApplier>) -> Option<f32> {
+    let root = composition.root().expect("popup host root");
+    let window = Size {
+        width: 300.0,
+        height: 200.0,
+    };
+    let layout = composition
+        .applier_mut()
+        .compute_layout(root, window)
+        .expect("layout");
+    HeadlessRenderer::new()
+        .render(&layout)
+        .primitives_for(PaintLayer::Behind)
+        .find_map(|primitive| match primitive {
+            DrawPrimitive::Rect {
+                rect,
+                brush: Brush::Solid(color),
+            } if rect.width == window.width && rect.height == window.height => Some(color.3),
+            _ => None,
+        })
+}
+
+fn run_frames(composition: &mut Composition<MemoryApplier>, frame_time: &mut u64, frames: usize) {
+    let runtime = composition.runtime_handle();
+    for _ in 0..frames {
+        *frame_time += FRAME_NANOS;
+        runtime.drain_frame_callbacks(*frame_time);
+        composition
+            .process_invalid_scopes()
+            .expect("process animation frame");
+    }
+}
+
+#[test]
+fn dialog_fades_in_and_out_around_its_time_in_the_composition() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let open = MutableState::with_runtime(true, composition.runtime_handle());
+    composition
+        .render(key, move || {
+            PopupHost(move || dialog_app(open));
+        })
+        .expect("initial render");
+    let mut frame_time = 0;
+    run_frames(&mut composition, &mut frame_time, 1);
+    let entering = scrim_alpha(&mut composition).expect("scrim while fading in");
+    assert!(entering < DIALOG_SCRIM.3, "scrim starts faded out");
+
+    run_frames(&mut composition, &mut frame_time, 20);
+    let shown = scrim_alpha(&mut composition).expect("scrim once shown");
+    assert!((shown - DIALOG_SCRIM.3).abs() < 1e-4);
+
+    open.set(false);
+    run_frames(&mut composition, &mut frame_time, 5);
+    let leaving = scrim_alpha(&mut composition).expect("scrim while fading out");
+    assert!(leaving > 0.0 && leaving < DIALOG_SCRIM.3);
+
+    run_frames(&mut composition, &mut frame_time, 20);
+    assert_eq!(
+        scrim_alpha(&mut composition),
+        None,
+        "faded out dialog is gone"
+    );
+}
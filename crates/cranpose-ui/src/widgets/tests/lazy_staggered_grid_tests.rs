@@ -0,0 +1,82 @@
+use super::*;
+use crate::layout::{LayoutBox, LayoutEngine};
+use crate::modifier::Size;
+use crate::primitives::BoxSpec;
+use cranpose_core::{location_key, Composition, MemoryApplier};
+use cranpose_foundation::lazy::LazyListScope;
+use std::collections::HashMap;
+
+fn find_rect(tree: &LayoutBox, target: NodeId) -> Option<(f32, f32, f32, f32)> {
+    if tree.node_id == target {
+        let rect = tree.rect;
+        return Some((rect.x, rect.y, rect.width, rect.height));
+    }
+    tree.children
+        .iter()
+        .find_map(|child| find_rect(child, target))
+}
+
+#[test]
+fn items_are_placed_in_the_shortest_column() {
+    const HEIGHTS: [f32; 6] = [100.0, 50.0, 80.0, 30.0, 60.0, 40.0];
+    let nodes: Rc<RefCell<HashMap<usize, NodeId>>> = Rc::default();
+    let nodes_render = Rc::clone(&nodes);
+
+    let mut composition = Composition::new(MemoryApplier::new());
+    composition
+        .render(location_key(file!(), line!(), column!()), move || {
+            let state = cranpose_foundation::lazy::remember_lazy_list_state();
+            let nodes = Rc::clone(&nodes_render);
+            LazyVerticalStaggeredGrid(
+                Modifier::empty().fill_max_size(),
+                state,
+                LazyVerticalStaggeredGridSpec::new(StaggeredGridCells::Fixed(3))
+                    .vertical_item_spacing(10.0)
+                    .horizontal_spacing(15.0),
+                move |scope| {
+                    scope.items(
+                        HEIGHTS.len(),
+                        None::<fn(usize) -> u64>,
+                        None::<fn(usize) -> u64>,
+                        move |index| {
+                            let node = crate::Box(
+                                Modifier::empty().height(HEIGHTS[index]),
+                                BoxSpec::default(),
+                                || {},
+                            );
+                            nodes.borrow_mut().insert(index, node);
+                        },
+                    );
+                },
+            );
+        })
+        .expect("render");
+    let root = composition.root().expect("root node");
+    let handle = composition.runtime_handle();
+    composition.applier_mut().set_runtime_handle(handle);
+    let layout = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 330.0,
+                height: 400.0,
+            },
+        )
+        .expect("layout");
+
+    // Columns are 100 wide, 115 apart; each item starts where its column ends
+    let expected = [
+        (0.0, 0.0),
+        (115.0, 0.0),
+        (230.0, 0.0),
+        (115.0, 60.0),
+        (230.0, 90.0),
+        (115.0, 100.0),
+    ];
+    let nodes = nodes.borrow();
+    for (index, (x, y)) in expected.into_iter().enumerate() {
+        let rect = find_rect(layout.root(), nodes[&index]).expect("item layout");
+        assert_eq!(rect, (x, y, 100.0, HEIGHTS[index]), "item {index}");
+    }
+}
@@ -0,0 +1,168 @@
+use super::*;
+use crate::modifier::{Brush, Color};
+use crate::renderer::{HeadlessRenderer, PaintLayer, RecordedRenderScene};
+use crate::widgets::{Box, BoxSpec, Column, ColumnSpec};
+use crate::LayoutEngine;
+use cranpose_core::{location_key, Composition, MemoryApplier};
+use cranpose_ui_graphics::DrawPrimitive;
+
+const MAIN: Color = Color(0.0, 0.0, 1.0, 1.0);
+const POPUP: Color = Color(1.0, 0.0, 0.0, 1.0);
+const ANCHOR: Rect = Rect {
+    x: 20.0,
+    y: 30.0,
+    width: 50.0,
+    height: 10.0,
+};
+
+#[composable]
+fn popup_app(open: MutableState<bool>, alignment: Alignment) {
+    Column(Modifier::empty(), ColumnSpec::default(), move || {
+        // A clipped parent that the popup still escapes
+        Box(
+            Modifier::empty().size_points(10.0, 10.0).clip_to_bounds(),
+            BoxSpec::default(),
+            move || {
+                if open.get() {
+                    Popup(
+                        ANCHOR,
+                        alignment,
+                        || {},
+                        || {
+                            Box(
+                                Modifier::empty().size_points(40.0, 20.0).background(POPUP),
+                                BoxSpec::default(),
+                                || {},
+                            );
+                        },
+                    );
+                }
+            },
+        );
+        Box(
+            Modifier::empty().size_points(200.0, 100.0).background(MAIN),
+            BoxSpec::default(),
+            || {},
+        );
+    });
+}
+
+fn render_popup_app(alignment: Alignment) -> (Composition<MemoryApplier>, MutableState<bool>) {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let open = MutableState::with_runtime(true, composition.runtime_handle());
+    composition
+        .render(key, move || {
+            PopupHost(move || popup_app(open, alignment));
+        })
+        .expect("initial render");
+    settle(&mut composition);
+    (composition, open)
+}
+
+fn settle(composition: &mut Composition<MemoryApplier>) {
+    while composition.should_render() {
+        composition.process_invalid_scopes().expect("recomposition");
+    }
+}
+
+fn draw(composition: &mut Composition<MemoryApplier>) -> RecordedRenderScene {
+    let root = composition.root().expect("popup host root");
+    let layout = composition
+        .applier_mut()
+        .compute_layout(
+            root,
+            Size {
+                width: 300.0,
+                height: 200.0,
+            },
+        )
+        .expect("layout");
+    HeadlessRenderer::new().render(&layout)
+}
+
+/// Backgrounds in draw order with their window-space bounds.
+fn backgrounds(scene: &RecordedRenderScene) -> Vec<(Color, Rect)> {
+    scene
+        .primitives_for(PaintLayer::Behind)
+        .filter_map(|primitive| match primitive {
+            DrawPrimitive::Rect {
+                rect,
+                brush: Brush::Solid(color),
+            } => Some((*color, *rect)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn popup_draws_above_later_content_at_its_anchor() {
+    let (mut composition, _open) = render_popup_app(Alignment::TOP_START);
+
+    let drawn = backgrounds(&draw(&mut composition));
+    let colors: Vec<Color> = drawn.iter().map(|(color, _)| *color).collect();
+    assert_eq!(colors, vec![MAIN, POPUP]);
+    assert_eq!(
+        drawn[1].1,
+        Rect {
+            x: 20.0,
+            y: 30.0,
+            width: 40.0,
+            height: 20.0,
+        }
+    );
+}
+
+#[test]
+fn popup_alignment_matches_the_same_corner_of_the_anchor() {
+    let (mut composition, _open) = render_popup_app(Alignment::BOTTOM_END);
+
+    let drawn = backgrounds(&draw(&mut composition));
+    let (_, popup) = drawn.last().expect("popup background");
+    // Bottom-end corners meet at (70, 40)
+    assert_eq!((popup.x, popup.y), (30.0, 20.0));
+}
+
+#[test]
+fn popup_closes_when_it_leaves_the_composition() {
+    let (mut composition, open) = render_popup_app(Alignment::TOP_START);
+    assert_eq!(backgrounds(&draw(&mut composition)).len(), 2);
+
+    open.set(false);
+    settle(&mut composition);
+
+    let colors: Vec<Color> = backgrounds(&draw(&mut composition))
+        .into_iter()
+        .map(|(color, _)| color)
+        .collect();
+    assert_eq!(colors, vec![MAIN]);
+}
+
+#[test]
+fn popup_stack_dismisses_the_topmost_popup_of_its_hosts() {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let open = MutableState::with_runtime(true, composition.runtime_handle());
+    let stack = PopupStack::new();
+    let provided = stack.clone();
+    composition
+        .render(key, move || {
+            CompositionLocalProvider(
+                [local_popup_stack().provides(Some(provided.clone()))],
+                || {
+                    PopupHost(move || {
+                        if open.get() {
+                            Popup(ANCHOR, Alignment::TOP_START, move || open.set(false), || {});
+                        }
+                    });
+                },
+            );
+        })
+        .expect("initial render");
+    settle(&mut composition);
+
+    assert!(stack.dismiss_topmost());
+    assert!(!open.get());
+    settle(&mut composition);
+    assert!(!stack.dismiss_topmost());
+}
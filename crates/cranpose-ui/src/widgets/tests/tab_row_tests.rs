@@ -0,0 +1,168 @@
+use super::*;
+use crate::layout::LayoutBox;
+use crate::widgets::Text;
+use crate::LayoutEngine;
+use cranpose_core::{location_key, Composition, MemoryApplier, MutableState};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const FRAME_NANOS: u64 = 16_000_000;
+
+struct TabRowFixture {
+    composition: Composition<MemoryApplier>,
+    selected: MutableState<usize>,
+    tab_ids: Rc<RefCell<Vec<NodeId>>>,
+}
+
+#[composable]
+fn tabs_app(
+    scrollable: bool,
+    titles: &'static [&'static str],
+    selected: MutableState<usize>,
+    tab_ids: Rc<RefCell<Vec<NodeId>>>,
+) {
+    let tabs = move || {
+        tab_ids.borrow_mut().clear();
+        for (index, title) in titles.iter().enumerate() {
+            let id = Tab(
+                selected.get() == index,
+                Modifier::empty(),
+                move || selected.set(index),
+                move || {
+                    Text(*title, Modifier::empty());
+                },
+            );
+            tab_ids.borrow_mut().push(id);
+        }
+    };
+    let color = Color(0.2, 0.4, 0.9, 1.0);
+    if scrollable {
+        ScrollableTabRow(selected.get(), Modifier::empty(), color, tabs);
+    } else {
+        TabRow(selected.get(), Modifier::empty(), color, tabs);
+    }
+}
+
+fn tab_row_fixture(scrollable: bool, titles: &'static [&'static str]) -> TabRowFixture {
+    let mut composition = Composition::new(MemoryApplier::new());
+    let key = location_key(file!(), line!(), column!());
+    let selected = MutableState::with_runtime(0usize, composition.runtime_handle());
+    let tab_ids: Rc<RefCell<Vec<NodeId>>> = Rc::new(RefCell::new(Vec::new()));
+
+    let tabs_capture = Rc::clone(&tab_ids);
+    composition
+        .render(key, move || {
+            tabs_app(scrollable, titles, selected, Rc::clone(&tabs_capture))
+        })
+        .expect("initial render");
+
+    TabRowFixture {
+        composition,
+        selected,
+        tab_ids,
+    }
+}
+
+impl TabRowFixture {
+    fn layout(&mut self) -> LayoutBox {
+        let root = self.composition.root().expect("root node");
+        self.composition
+            .applier_mut()
+            .compute_layout(
+                root,
+                Size {
+                    width: 300.0,
+                    height: 200.0,
+                },
+            )
+            .expect("compute layout")
+            .root()
+            .clone()
+    }
+
+    fn settle(&mut self) {
+        self.composition
+            .process_invalid_scopes()
+            .expect("recompose after selection");
+        let runtime = self.composition.runtime_handle();
+        let mut frame_time = 0;
+        for _ in 0..120 {
+            if !self.composition.should_render() {
+                break;
+            }
+            frame_time += FRAME_NANOS;
+            runtime.drain_frame_callbacks(frame_time);
+            self.composition
+                .process_invalid_scopes()
+                .expect("process animation frame");
+        }
+    }
+
+    fn tab_rect(&self, layout: &LayoutBox, index: usize) -> crate::layout::LayoutBox {
+        let id = self.tab_ids.borrow()[index];
+        layout
+            .children
+            .iter()
+            .find(|child| child.node_id == id)
+            .cloned()
+            .expect("tab layout")
+    }
+}
+
+fn indicator(layout: &LayoutBox) -> &LayoutBox {
+    layout.children.last().expect("indicator layout")
+}
+
+#[test]
+fn selecting_a_tab_slides_the_indicator_under_it() {
+    let mut fixture = tab_row_fixture(false, &["One", "Two", "Three"]);
+    let layout = fixture.layout();
+    assert_eq!(layout.rect.width, 300.0);
+    assert_eq!(indicator(&layout).rect.x, 0.0);
+    assert_eq!(indicator(&layout).rect.width, 100.0);
+
+    // Clicking a tab is wired to the state, so set it the same way
+    fixture.selected.set(2);
+    fixture.settle();
+
+    let layout = fixture.layout();
+    let tab = fixture.tab_rect(&layout, 2);
+    assert_eq!(tab.rect.x, 200.0);
+    assert_eq!(indicator(&layout).rect.x, tab.rect.x);
+    assert_eq!(indicator(&layout).rect.width, tab.rect.width);
+    assert_eq!(
+        indicator(&layout).rect.y + indicator(&layout).rect.height,
+        layout.rect.height
+    );
+}
+
+#[test]
+fn indicator_blends_neighbouring_tabs_while_sliding() {
+    let positions = [
+        TabPosition {
+            left: 0.0,
+            width: 100.0,
+        },
+        TabPosition {
+            left: 100.0,
+            width: 60.0,
+        },
+    ];
+    assert_eq!(
+        indicator_position(&positions, 0.5),
+        TabPosition {
+            left: 50.0,
+            width: 80.0,
+        }
+    );
+    assert_eq!(indicator_position(&positions, 4.0), positions[1]);
+}
+
+#[test]
+fn scrollable_tab_row_keeps_tab_widths_past_the_viewport() {
+    let mut fixture = tab_row_fixture(true, &["One", "Two", "Three", "Four", "Five"]);
+    let layout = fixture.layout();
+    let last = fixture.tab_rect(&layout, 4);
+    assert!(last.rect.width >= MIN_SCROLLABLE_TAB_WIDTH);
+    assert!(last.rect.x + last.rect.width > 300.0);
+}
@@ -14,6 +14,7 @@ use crate::text_modifier_node::TextModifierElement;
 use crate::widgets::Layout;
 use cranpose_core::{MutableState, NodeId, State};
 use cranpose_foundation::modifier_element;
+use cranpose_ui_graphics::TextStyle;
 use std::rc::Rc;
 
 #[derive(Clone)]
@@ -121,7 +122,8 @@ impl IntoTextSource for DynamicTextSource {
 /// * `value` - The string to display. Can be a `&str`, `String`, or `State<String>`.
 /// * `modifier` - Modifiers to apply (e.g., padding, background, layout instructions).
 ///   Note: Text styling (color, font size) is typically applied via the
-///   `text_style` modifier (coming soon) or specific style modifiers.
+///   `text_style` modifier (coming soon) or specific style modifiers. Line height
+///   and letter spacing are set through [`BasicText`].
 ///
 /// # Example
 ///
@@ -130,6 +132,29 @@ impl IntoTextSource for DynamicTextSource {
 /// ```
 #[composable]
 pub fn Text<S>(value: S, modifier: Modifier) -> NodeId
+where
+    S: IntoTextSource + Clone + PartialEq + 'static,
+{
+    BasicText(value, modifier, TextStyle::default())
+}
+
+/// Displays text laid out with the given [`TextStyle`].
+///
+/// The style's `line_height` and `letter_spacing` are honored by measurement and
+/// rendering, and its `text_decoration` draws an underline or line-through
+/// along each line; [`Text`] is this with the default style.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let style = TextStyle {
+///     line_height: Some(Sp(24.0)),
+///     ..TextStyle::default()
+/// };
+/// BasicText("Spaced\nlines", Modifier::empty(), style);
+/// ```
+#[composable]
+pub fn BasicText<S>(value: S, modifier: Modifier, style: TextStyle) -> NodeId
 where
     S: IntoTextSource + Clone + PartialEq + 'static,
 {
@@ -137,7 +162,7 @@ where
 
     // Create a text modifier element that will add TextModifierNode to the chain
     // TextModifierNode handles measurement, drawing, and semantics
-    let text_element = modifier_element(TextModifierElement::new(current));
+    let text_element = modifier_element(TextModifierElement::new(current).with_style(style));
     let final_modifier = Modifier::from_parts(vec![text_element]);
     let combined_modifier = modifier.then(final_modifier);
 
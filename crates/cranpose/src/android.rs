@@ -4,7 +4,9 @@
 //! lifecycle management, input handling, and rendering coordination.
 
 use crate::launcher::AppSettings;
-use cranpose_app_shell::{default_root_key, AppShell};
+use cranpose_app_shell::{
+    default_root_key, AppShell, ImeEvent, KeyCode, KeyEvent, KeyEventType, Modifiers,
+};
 use cranpose_platform_android::AndroidPlatform;
 use cranpose_render_wgpu::WgpuRenderer;
 use std::sync::{
@@ -25,6 +27,55 @@ enum PendingInput {
     PointerDown(f32, f32),
     PointerUp(f32, f32),
     PointerMove(f32, f32),
+    /// Editing key pressed on the soft or hardware keyboard.
+    Key(KeyCode),
+    /// Text typed on the soft or hardware keyboard.
+    Text(String),
+}
+
+/// Shows and hides the activity's soft keyboard for focused text fields.
+///
+/// With `NativeActivity` the system's default `InputConnection` turns soft
+/// keyboard input into key events, which are translated in the input loop.
+struct AndroidIme {
+    app: android_activity::AndroidApp,
+}
+
+impl cranpose_ui::ImeConnection for AndroidIme {
+    fn show_keyboard(&self) {
+        self.app.show_soft_input(true);
+    }
+
+    fn hide_keyboard(&self) {
+        self.app.hide_soft_input(true);
+    }
+}
+
+/// Translates a key press into text field input; other keys are left to the system.
+fn key_input(
+    app: &android_activity::AndroidApp,
+    key_event: &android_activity::input::KeyEvent<'_>,
+) -> Option<PendingInput> {
+    use android_activity::input::{KeyAction, KeyMapChar, Keycode};
+
+    if !matches!(key_event.action(), KeyAction::Down) {
+        return None;
+    }
+    match key_event.key_code() {
+        Keycode::Del => Some(PendingInput::Key(KeyCode::Backspace)),
+        Keycode::ForwardDel => Some(PendingInput::Key(KeyCode::Delete)),
+        Keycode::Enter | Keycode::NumpadEnter => Some(PendingInput::Key(KeyCode::Enter)),
+        key_code => {
+            // Typed characters come from the device's key map
+            let typed = app
+                .device_key_character_map(key_event.device_id())
+                .and_then(|map| map.get(key_code, key_event.meta_state()));
+            match typed {
+                Ok(KeyMapChar::Unicode(ch)) => Some(PendingInput::Text(ch.to_string())),
+                _ => None,
+            }
+        }
+    }
 }
 
 /// Get display density from Android NDK Configuration.
@@ -50,6 +101,9 @@ fn get_display_density(app: &android_activity::AndroidApp) -> f32 {
 /// Renders a single frame. Returns true if out of memory (should exit).
 fn render_once(resources: &mut GpuResources, shell: &mut AppShell<WgpuRenderer>) -> bool {
     shell.update();
+    if !shell.needs_present() {
+        return false;
+    }
 
     match resources.surface.get_current_texture() {
         Ok(frame) => {
@@ -63,6 +117,7 @@ fn render_once(resources: &mut GpuResources, shell: &mut AppShell<WgpuRenderer>)
             }
 
             frame.present();
+            shell.mark_presented();
             false
         }
         Err(wgpu::SurfaceError::Lost) | Err(wgpu::SurfaceError::Outdated) => {
@@ -243,7 +298,12 @@ pub fn run(
                             let (device, queue) = pollster::block_on(
                                 adapter.request_device(&wgpu::DeviceDescriptor {
                                     label: Some("Android Device"),
-                                    required_features: wgpu::Features::empty(),
+                                    // Timestamp queries let the renderer report GPU frame
+                                    // times; adapter format features allow MSAA sample
+                                    // counts beyond the guaranteed 4x
+                                    required_features: adapter.features()
+                                        & (wgpu::Features::TIMESTAMP_QUERY
+                                            | wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES),
                                     required_limits: wgpu::Limits::downlevel_defaults()
                                         .using_resolution(adapter.limits()),
                                     memory_hints: wgpu::MemoryHints::default(),
@@ -294,9 +354,14 @@ pub fn run(
                                 } else {
                                     WgpuRenderer::new()
                                 };
-                                renderer.init_gpu(device.clone(), queue.clone(), surface_format);
+                                renderer.init_gpu(&adapter, device.clone(), queue.clone(), surface_format);
                                 renderer.set_root_scale(density);
 
+                                // Focused text fields show the soft keyboard
+                                cranpose_ui::set_platform_ime(std::rc::Rc::new(AndroidIme {
+                                    app: app.clone(),
+                                }));
+
                                 // Create app shell with content closure
                                 let content_clone = content.clone();
                                 let shell =
@@ -319,6 +384,7 @@ pub fn run(
                                 // Window recreated - reinitialize GPU resources
                                 if let Some(shell) = &mut app_shell {
                                     shell.renderer().init_gpu(
+                                        &adapter,
                                         device.clone(),
                                         queue.clone(),
                                         surface_format,
@@ -481,6 +547,15 @@ pub fn run(
                                         }
                                         true
                                     }
+                                    android_activity::input::InputEvent::KeyEvent(key_event) => {
+                                        match key_input(&app, key_event) {
+                                            Some(input) => {
+                                                pending_inputs.push(input);
+                                                true
+                                            }
+                                            None => false,
+                                        }
+                                    }
                                     _ => false,
                                 };
 
@@ -521,6 +596,17 @@ pub fn run(
                         PendingInput::PointerMove(x, y) => {
                             shell.set_cursor(x, y);
                         }
+                        PendingInput::Key(key_code) => {
+                            shell.on_key_event(&KeyEvent::new(
+                                key_code,
+                                String::new(),
+                                Modifiers::default(),
+                                KeyEventType::KeyDown,
+                            ));
+                        }
+                        PendingInput::Text(text) => {
+                            shell.on_ime_event(&ImeEvent::Commit(text));
+                        }
                     }
                 }
             }
@@ -4,8 +4,9 @@
 
 use crate::launcher::AppSettings;
 use cranpose_app_shell::{default_root_key, AppShell};
-use cranpose_platform_desktop_winit::DesktopWinitPlatform;
+use cranpose_platform_desktop_winit::{winit_cursor_icon, DesktopWinitPlatform};
 use cranpose_render_wgpu::WgpuRenderer;
+use cranpose_ui::CursorIcon;
 use std::sync::Arc;
 use winit::application::ApplicationHandler;
 use winit::dpi::LogicalSize;
@@ -571,6 +572,8 @@ struct App {
     platform: Option<DesktopWinitPlatform>,
     /// Current keyboard modifiers (shift, ctrl, alt, meta)
     current_modifiers: winit::keyboard::ModifiersState,
+    /// Cursor icon last applied to the window
+    cursor_icon: CursorIcon,
     /// Robot controller
     #[cfg(feature = "robot")]
     robot_controller: Option<RobotController>,
@@ -595,6 +598,7 @@ impl App {
             app: None,
             platform: None,
             current_modifiers: winit::keyboard::ModifiersState::empty(),
+            cursor_icon: CursorIcon::Default,
             #[cfg(feature = "robot")]
             robot_controller: None,
             recorder,
@@ -711,7 +715,11 @@ impl ApplicationHandler for App {
 
         let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
             label: Some("Main Device"),
-            required_features: wgpu::Features::empty(),
+            // Timestamp queries let the renderer report GPU frame times; adapter
+            // format features allow MSAA sample counts beyond the guaranteed 4x
+            required_features: adapter.features()
+                & (wgpu::Features::TIMESTAMP_QUERY
+                    | wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES),
             required_limits: wgpu::Limits::default(),
             memory_hints: wgpu::MemoryHints::default(),
             trace: wgpu::Trace::Off,
@@ -747,7 +755,7 @@ impl ApplicationHandler for App {
         } else {
             WgpuRenderer::new()
         };
-        renderer.init_gpu(Arc::new(device), Arc::new(queue), surface_format);
+        renderer.init_gpu(&adapter, Arc::new(device), Arc::new(queue), surface_format);
         let initial_scale = window.scale_factor();
         renderer.set_root_scale(initial_scale as f32);
         cranpose_ui::set_density(initial_scale as f32);
@@ -850,6 +858,11 @@ impl ApplicationHandler for App {
                 if primary {
                     let logical = platform.pointer_position(position);
                     app.set_cursor(logical.x, logical.y);
+                    let cursor_icon = app.cursor_icon();
+                    if cursor_icon != self.cursor_icon {
+                        self.cursor_icon = cursor_icon;
+                        window.set_cursor(winit_cursor_icon(cursor_icon).into());
+                    }
                     // Record mouse move
                     if let Some(recorder) = &mut self.recorder {
                         recorder.record_mouse_move(logical.x, logical.y);
@@ -1059,6 +1072,9 @@ impl ApplicationHandler for App {
             }
             WindowEvent::RedrawRequested => {
                 app.update();
+                if !app.needs_present() {
+                    return;
+                }
 
                 let output = match surface.get_current_texture() {
                     Ok(output) => output,
@@ -1101,6 +1117,7 @@ impl ApplicationHandler for App {
                 }
 
                 output.present();
+                app.mark_presented();
             }
             _ => {}
         }
@@ -1429,6 +1446,19 @@ fn extract_semantics(app: &AppShell<WgpuRenderer>) -> Vec<SemanticElement> {
     }
 }
 
+/// Finds the layout box of `node_id` among `boxes` and their descendants.
+#[cfg(feature = "robot")]
+fn find_layout_box(boxes: &[LayoutBox], node_id: cranpose_core::NodeId) -> Option<&LayoutBox> {
+    boxes
+        .iter()
+        .find(|layout_box| layout_box.node_id == node_id)
+        .or_else(|| {
+            boxes
+                .iter()
+                .find_map(|layout_box| find_layout_box(&layout_box.children, node_id))
+        })
+}
+
 /// Recursively combine SemanticsNode + LayoutBox into SemanticElement
 #[cfg(feature = "robot")]
 fn combine_trees(sem_node: &SemanticsNode, layout_box: &LayoutBox) -> SemanticElement {
@@ -1439,6 +1469,11 @@ fn combine_trees(sem_node: &SemanticsNode, layout_box: &LayoutBox) -> SemanticEl
         SemanticsRole::Layout => "Layout",
         SemanticsRole::Subcompose => "Subcompose",
         SemanticsRole::Spacer => "Spacer",
+        SemanticsRole::Checkbox => "Checkbox",
+        SemanticsRole::Switch => "Switch",
+        SemanticsRole::RadioButton => "RadioButton",
+        SemanticsRole::Tab => "Tab",
+        SemanticsRole::Image => "Image",
         SemanticsRole::Unknown => "Unknown",
     }
     .to_string();
@@ -1463,12 +1498,15 @@ fn combine_trees(sem_node: &SemanticsNode, layout_box: &LayoutBox) -> SemanticEl
         height: layout_box.rect.height,
     };
 
-    // Recursively process children
+    // Recursively process children; merged semantics skip layout levels, so
+    // pair them by node id rather than position
     let children = sem_node
         .children
         .iter()
-        .zip(layout_box.children.iter())
-        .map(|(sem_child, layout_child)| combine_trees(sem_child, layout_child))
+        .filter_map(|sem_child| {
+            find_layout_box(&layout_box.children, sem_child.node_id)
+                .map(|layout_child| combine_trees(sem_child, layout_child))
+        })
         .collect();
 
     SemanticElement {
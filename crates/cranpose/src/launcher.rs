@@ -138,6 +138,8 @@ impl AppLauncher {
 
     /// Set fonts to use for text rendering.
     ///
+    /// If no font can be loaded, text falls back to an embedded Roboto font.
+    ///
     /// # Arguments
     ///
     /// * `fonts` - A slice of static byte slices, each representing a font file (e.g., `.ttf` or `.otf`).
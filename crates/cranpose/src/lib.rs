@@ -19,7 +19,9 @@ mod present_mode;
 pub use cranpose_ui::*;
 
 /// Core runtime helpers commonly used by applications.
-pub use cranpose_core::{mutableStateOf, remember, rememberUpdatedState, useState};
+pub use cranpose_core::{
+    mutableStateOf, remember, rememberUpdatedState, remember_saveable, useState,
+};
 
 #[doc(hidden)]
 pub use cranpose_core::{
@@ -29,7 +31,9 @@ pub use cranpose_core::{
 /// Convenience imports for Cranpose applications.
 pub mod prelude {
     pub use crate::{AppLauncher, AppSettings};
-    pub use cranpose_core::{mutableStateOf, remember, rememberUpdatedState, useState};
+    pub use cranpose_core::{
+        mutableStateOf, remember, rememberUpdatedState, remember_saveable, useState,
+    };
     pub use cranpose_ui::*;
 }
 
@@ -4,14 +4,18 @@
 
 use crate::launcher::AppSettings;
 use cranpose_app_shell::{default_root_key, AppShell};
-use cranpose_platform_web::WebPlatform;
+use cranpose_platform_web::{
+    before_input, composition_input, WebEditKey, WebPlatform, WebTextInput,
+};
 use cranpose_render_wgpu::WgpuRenderer;
+use cranpose_ui::{Clipboard, CursorIcon, ImeConnection, ImeEvent};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::{HtmlCanvasElement, MouseEvent, PointerEvent};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Document, HtmlCanvasElement, HtmlElement, MouseEvent, PointerEvent};
 
 /// Runs a web Compose application with wgpu rendering.
 ///
@@ -84,7 +88,11 @@ pub async fn run(
     let (device, queue) = adapter
         .request_device(&wgpu::DeviceDescriptor {
             label: Some("Main Device"),
-            required_features: wgpu::Features::empty(),
+            // Timestamp queries let the renderer report GPU frame times; adapter
+            // format features allow MSAA sample counts beyond the guaranteed 4x
+            required_features: adapter.features()
+                & (wgpu::Features::TIMESTAMP_QUERY
+                    | wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES),
             required_limits: wgpu::Limits::downlevel_webgl2_defaults(),
             memory_hints: wgpu::MemoryHints::default(),
             trace: wgpu::Trace::Off,
@@ -120,10 +128,18 @@ pub async fn run(
     } else {
         WgpuRenderer::new()
     };
-    renderer.init_gpu(Arc::new(device), Arc::new(queue), surface_format);
+    renderer.init_gpu(&adapter, Arc::new(device), Arc::new(queue), surface_format);
     renderer.set_root_scale(scale_factor as f32);
     cranpose_ui::set_density(scale_factor as f32);
 
+    // Install the browser clipboard before composing so `local_clipboard()` sees it
+    let clipboard = Rc::new(WebClipboard::new());
+    cranpose_ui::set_platform_clipboard(clipboard.clone());
+
+    // Focused text fields focus a hidden editable element to bring up the soft keyboard
+    let ime = Rc::new(WebIme::new(&document)?);
+    cranpose_ui::set_platform_ime(ime.clone());
+
     let app = Rc::new(RefCell::new(AppShell::new(
         renderer,
         default_root_key(),
@@ -131,6 +147,8 @@ pub async fn run(
     )));
     let platform = Rc::new(RefCell::new(WebPlatform::default()));
     platform.borrow_mut().set_scale_factor(scale_factor);
+    // Browsers fire pointermove far more often than frames are drawn
+    app.borrow_mut().set_coalesce_pointer_moves(true);
 
     // Set buffer_size to physical pixels and viewport to logical dp
     app.borrow_mut()
@@ -217,6 +235,21 @@ pub async fn run(
         closure.forget();
     }
 
+    // Track canvas resizes (browser window, zoom, phone rotation); the render
+    // loop applies the latest one at the start of the next frame
+    {
+        let platform = platform.clone();
+        let canvas = canvas.clone();
+        let resize_window = window.clone();
+        let closure = Closure::wrap(Box::new(move || {
+            let mut platform = platform.borrow_mut();
+            platform.set_scale_factor(resize_window.device_pixel_ratio());
+            platform.request_resize(canvas.client_width() as f64, canvas.client_height() as f64);
+        }) as Box<dyn FnMut()>);
+        window.add_event_listener_with_callback("resize", closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
     // Set up keyboard event handlers
     // Note: We need to listen on document (not canvas) for keyboard events
     // unless the canvas has tabindex set and is focused
@@ -225,6 +258,11 @@ pub async fn run(
         let closure = Closure::wrap(Box::new(move |event: web_sys::KeyboardEvent| {
             use cranpose_app_shell::{KeyCode, KeyEvent, KeyEventType, Modifiers};
 
+            // Keys typed into an IME composition arrive as composition events
+            if event.is_composing() {
+                return;
+            }
+
             // Convert web key code to our KeyCode
             let key_code = match event.code().as_str() {
                 // Letters
@@ -411,11 +449,13 @@ pub async fn run(
     // Set up paste event handler for clipboard paste
     {
         let app = app.clone();
+        let clipboard = clipboard.clone();
         let closure = Closure::wrap(Box::new(move |event: web_sys::ClipboardEvent| {
             // Get pasted text from clipboardData (synchronous!)
             if let Some(data) = event.clipboard_data() {
                 if let Ok(text) = data.get_data("text/plain") {
                     if !text.is_empty() {
+                        clipboard.update_text(&text);
                         if let Ok(mut app_mut) = app.try_borrow_mut() {
                             if app_mut.on_paste(&text) {
                                 event.prevent_default();
@@ -432,9 +472,11 @@ pub async fn run(
     // Set up copy event handler for clipboard copy
     {
         let app = app.clone();
+        let clipboard = clipboard.clone();
         let closure = Closure::wrap(Box::new(move |event: web_sys::ClipboardEvent| {
             if let Ok(mut app_mut) = app.try_borrow_mut() {
                 if let Some(text) = app_mut.on_copy() {
+                    clipboard.update_text(&text);
                     // Put copied text into the clipboard via the event
                     if let Some(data) = event.clipboard_data() {
                         let _ = data.set_data("text/plain", &text);
@@ -450,9 +492,11 @@ pub async fn run(
     // Set up cut event handler for clipboard cut
     {
         let app = app.clone();
+        let clipboard = clipboard.clone();
         let closure = Closure::wrap(Box::new(move |event: web_sys::ClipboardEvent| {
             if let Ok(mut app_mut) = app.try_borrow_mut() {
                 if let Some(text) = app_mut.on_cut() {
+                    clipboard.update_text(&text);
                     // Put cut text into the clipboard via the event
                     if let Some(data) = event.clipboard_data() {
                         let _ = data.set_data("text/plain", &text);
@@ -465,49 +509,123 @@ pub async fn run(
         closure.forget();
     }
 
+    // Set up soft keyboard input typed into the hidden IME element
+    {
+        let app = app.clone();
+        let ime_element = ime.clone();
+        let closure = Closure::wrap(Box::new(move |event: web_sys::InputEvent| {
+            let Some(input) = before_input(&event.input_type(), event.data()) else {
+                return;
+            };
+            // Keep the text out of the element; the focused field owns it
+            event.prevent_default();
+            ime_element.clear();
+            if let Ok(mut app_mut) = app.try_borrow_mut() {
+                deliver_text_input(&mut app_mut, input);
+            }
+        }) as Box<dyn FnMut(_)>);
+        ime.element()
+            .add_event_listener_with_callback("beforeinput", closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    // Set up IME composition handlers
+    for event_type in ["compositionupdate", "compositionend"] {
+        let app = app.clone();
+        let ime_element = ime.clone();
+        let closure = Closure::wrap(Box::new(move |event: web_sys::CompositionEvent| {
+            let data = event.data().unwrap_or_default();
+            let Some(input) = composition_input(&event.type_(), data) else {
+                return;
+            };
+            if matches!(input, WebTextInput::Commit(_)) {
+                ime_element.clear();
+            }
+            if let Ok(mut app_mut) = app.try_borrow_mut() {
+                deliver_text_input(&mut app_mut, input);
+            }
+        }) as Box<dyn FnMut(_)>);
+        ime.element()
+            .add_event_listener_with_callback(event_type, closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
     // Render loop
     let render_loop = Rc::new(RefCell::new(None));
     let render_loop_clone = render_loop.clone();
 
     let surface = Rc::new(surface);
     let surface_config = Rc::new(RefCell::new(surface_config));
+    let canvas_style = canvas.style();
+    let mut cursor_icon = CursorIcon::Default;
 
     *render_loop.borrow_mut() = Some(Closure::wrap(Box::new(move || {
+        let resize = platform.borrow_mut().take_resize();
+        if let Some(size) = resize {
+            canvas.set_width(size.physical_width);
+            canvas.set_height(size.physical_height);
+            let mut config = surface_config.borrow_mut();
+            config.width = size.physical_width;
+            config.height = size.physical_height;
+
+            let mut app_mut = app.borrow_mut();
+            let device = app_mut.renderer().device();
+            surface.configure(device, &*config);
+            app_mut.renderer().set_root_scale(size.scale_factor as f32);
+            cranpose_ui::set_density(size.scale_factor as f32);
+            app_mut.set_buffer_size(size.physical_width, size.physical_height);
+            app_mut.set_viewport(size.logical_width, size.logical_height);
+        }
+
         app.borrow_mut().update();
 
+        // Moves are coalesced, so the hovered element is only known after update()
+        let requested_cursor = app.borrow().cursor_icon();
+        if requested_cursor != cursor_icon {
+            cursor_icon = requested_cursor;
+            if let Err(err) = canvas_style.set_property("cursor", cursor_icon.css_name()) {
+                log::warn!("failed to set cursor: {:?}", err);
+            }
+        }
+
         let config = surface_config.borrow();
-        match surface.get_current_texture() {
-            Ok(output) => {
-                let view = output
-                    .texture
-                    .create_view(&wgpu::TextureViewDescriptor::default());
+        // Skip frames whose scene and surface are unchanged since the last present
+        if app.borrow().needs_present() {
+            match surface.get_current_texture() {
+                Ok(output) => {
+                    let view = output
+                        .texture
+                        .create_view(&wgpu::TextureViewDescriptor::default());
 
-                {
-                    let mut app_mut = app.borrow_mut();
-                    if let Err(err) = app_mut
-                        .renderer()
-                        .render(&view, config.width, config.height)
                     {
-                        log::error!("render failed: {:?}", err);
+                        let mut app_mut = app.borrow_mut();
+                        if let Err(err) =
+                            app_mut
+                                .renderer()
+                                .render(&view, config.width, config.height)
+                        {
+                            log::error!("render failed: {:?}", err);
+                        }
                     }
-                }
 
-                output.present();
-            }
-            Err(wgpu::SurfaceError::Lost) | Err(wgpu::SurfaceError::Outdated) => {
-                // Reconfigure surface
-                let mut app_mut = app.borrow_mut();
-                let device = app_mut.renderer().device();
-                surface.configure(device, &*config);
-            }
-            Err(wgpu::SurfaceError::OutOfMemory) => {
-                log::error!("Out of memory");
-            }
-            Err(wgpu::SurfaceError::Timeout) => {
-                log::debug!("Surface timeout, skipping frame");
-            }
-            Err(wgpu::SurfaceError::Other) => {
-                log::error!("Surface other error, skipping frame");
+                    output.present();
+                    app.borrow_mut().mark_presented();
+                }
+                Err(wgpu::SurfaceError::Lost) | Err(wgpu::SurfaceError::Outdated) => {
+                    // Reconfigure surface
+                    let mut app_mut = app.borrow_mut();
+                    let device = app_mut.renderer().device();
+                    surface.configure(device, &*config);
+                }
+                Err(wgpu::SurfaceError::OutOfMemory) => {
+                    log::error!("Out of memory");
+                }
+                Err(wgpu::SurfaceError::Timeout) => {
+                    log::debug!("Surface timeout, skipping frame");
+                }
+                Err(wgpu::SurfaceError::Other) => {
+                    log::error!("Surface other error, skipping frame");
+                }
             }
         }
 
@@ -527,3 +645,129 @@ fn request_animation_frame(f: &Closure<dyn FnMut()>) {
         .request_animation_frame(f.as_ref().unchecked_ref())
         .expect("should register `requestAnimationFrame` OK");
 }
+
+/// Clipboard backed by `navigator.clipboard`.
+///
+/// The Clipboard API is promise-based, while [`Clipboard`] is synchronous.
+/// Writes are fired off and a rejection (missing permission, insecure context)
+/// is logged as a warning. Reads return the last text seen by the app, which is
+/// refreshed from `paste` events via [`WebClipboard::update_text`].
+#[derive(Debug, Default)]
+struct WebClipboard {
+    last_text: RefCell<Option<String>>,
+}
+
+impl WebClipboard {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records text received from a DOM clipboard event.
+    fn update_text(&self, text: &str) {
+        *self.last_text.borrow_mut() = Some(text.to_owned());
+    }
+}
+
+impl Clipboard for WebClipboard {
+    fn get_text(&self) -> Option<String> {
+        self.last_text.borrow().clone()
+    }
+
+    fn set_text(&self, text: &str) {
+        self.update_text(text);
+        let Some(window) = web_sys::window() else {
+            log::warn!("Clipboard write skipped: no window");
+            return;
+        };
+        let promise = window.navigator().clipboard().write_text(text);
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(err) = JsFuture::from(promise).await {
+                log::warn!("Clipboard write rejected: {:?}", err);
+            }
+        });
+    }
+}
+
+/// Input method backend for browsers.
+///
+/// Browsers only show the on-screen keyboard for a focused editable element,
+/// so a transparent `contenteditable` element is focused along with text
+/// fields. Its `beforeinput` and composition events are translated with
+/// [`before_input`] and [`composition_input`]; the element itself never keeps
+/// the typed text.
+struct WebIme {
+    element: HtmlElement,
+}
+
+impl WebIme {
+    /// Creates the hidden editable element and adds it to `document`'s body.
+    fn new(document: &Document) -> Result<Self, JsValue> {
+        let element: HtmlElement = document.create_element("div")?.dyn_into()?;
+        element.set_attribute("contenteditable", "true")?;
+        element.set_attribute("autocapitalize", "off")?;
+        element.set_attribute(
+            "style",
+            "position:fixed;left:0;bottom:0;width:1px;height:1px;opacity:0;overflow:hidden",
+        )?;
+        document
+            .body()
+            .ok_or_else(|| JsValue::from_str("document has no body"))?
+            .append_child(&element)?;
+        Ok(Self { element })
+    }
+
+    /// The element to listen to for `beforeinput` and composition events.
+    fn element(&self) -> &HtmlElement {
+        &self.element
+    }
+
+    /// Drops whatever text the browser put into the element.
+    fn clear(&self) {
+        self.element.set_text_content(None);
+    }
+}
+
+impl ImeConnection for WebIme {
+    fn show_keyboard(&self) {
+        if let Err(err) = self.element.focus() {
+            log::debug!("Focusing the IME element failed: {:?}", err);
+        }
+    }
+
+    fn hide_keyboard(&self) {
+        if let Err(err) = self.element.blur() {
+            log::debug!("Blurring the IME element failed: {:?}", err);
+        }
+    }
+}
+
+/// Hands text input from the hidden IME element to the focused text field.
+fn deliver_text_input(app: &mut AppShell<WgpuRenderer>, input: WebTextInput) {
+    use cranpose_app_shell::{KeyCode, KeyEvent, KeyEventType, Modifiers};
+
+    match input {
+        WebTextInput::Preedit(text) => {
+            let end = text.len();
+            app.on_ime_event(&ImeEvent::Preedit {
+                text,
+                cursor: Some((end, end)),
+            });
+        }
+        WebTextInput::Commit(text) => {
+            app.on_ime_event(&ImeEvent::Commit(text));
+        }
+        WebTextInput::Key(key) => {
+            let key_code = match key {
+                WebEditKey::Enter => KeyCode::Enter,
+                WebEditKey::Backspace => KeyCode::Backspace,
+                WebEditKey::Delete => KeyCode::Delete,
+            };
+            app.on_key_event(&KeyEvent::new(
+                key_code,
+                String::new(),
+                Modifiers::default(),
+                KeyEventType::KeyDown,
+            ));
+        }
+    }
+}